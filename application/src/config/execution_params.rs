@@ -26,14 +26,47 @@ pub struct ExecutionParams {
     pub max_iterations: usize,
     /// Maximum tool use turns in a single Native Tool Use loop.
     pub max_tool_turns: usize,
+    /// Tool turn limit for Ask's read-only loop; `None` follows `max_tool_turns`.
+    ///
+    /// Read via [`ask_max_tool_turns()`](Self::ask_max_tool_turns).
+    #[serde(default)]
+    pub ask_max_tool_turns: Option<usize>,
     /// Maximum retries for tool validation errors.
     pub max_tool_retries: usize,
+    /// Retries per task when review rejects its actions; the task fails when
+    /// the attempt after the last retry is rejected too. Separate from
+    /// `max_plan_revisions`.
+    #[serde(default = "default_max_action_retries")]
+    pub max_action_retries: usize,
     /// Working directory for tool execution.
     pub working_dir: Option<String>,
     /// Timeout for each ensemble session's plan generation.
     pub ensemble_session_timeout: Option<Duration>,
+    /// Time each reviewer gets to answer a plan or action review; a reviewer
+    /// that runs out of time is recorded as a model error.
+    #[serde(default = "default_review_timeout")]
+    pub review_timeout: Option<Duration>,
     /// Budget for task result context accumulation.
     pub context_budget: ContextBudget,
+    /// Maximum number of independent plan tasks executed concurrently.
+    #[serde(default = "default_max_parallel_tasks")]
+    pub max_parallel_tasks: usize,
+    /// Cumulative token usage (prompt + completion) at which an agent run
+    /// stops gracefully; `None` means no limit.
+    #[serde(default)]
+    pub max_cost_tokens: Option<u64>,
+}
+
+fn default_max_action_retries() -> usize {
+    1
+}
+
+fn default_review_timeout() -> Option<Duration> {
+    Some(Duration::from_secs(180))
+}
+
+fn default_max_parallel_tasks() -> usize {
+    4
 }
 
 impl Default for ExecutionParams {
@@ -41,10 +74,15 @@ impl Default for ExecutionParams {
         Self {
             max_iterations: 50,
             max_tool_turns: 10,
+            ask_max_tool_turns: None,
             max_tool_retries: 2,
+            max_action_retries: default_max_action_retries(),
             working_dir: None,
             ensemble_session_timeout: Some(Duration::from_secs(180)),
+            review_timeout: default_review_timeout(),
             context_budget: ContextBudget::default(),
+            max_parallel_tasks: default_max_parallel_tasks(),
+            max_cost_tokens: None,
         }
     }
 }
@@ -62,11 +100,21 @@ impl ExecutionParams {
         self
     }
 
+    pub fn with_ask_max_tool_turns(mut self, max: usize) -> Self {
+        self.ask_max_tool_turns = Some(max);
+        self
+    }
+
     pub fn with_max_tool_retries(mut self, max: usize) -> Self {
         self.max_tool_retries = max;
         self
     }
 
+    pub fn with_max_action_retries(mut self, max: usize) -> Self {
+        self.max_action_retries = max;
+        self
+    }
+
     pub fn with_working_dir(mut self, dir: impl Into<String>) -> Self {
         self.working_dir = Some(dir.into());
         self
@@ -81,6 +129,24 @@ impl ExecutionParams {
         self.context_budget = budget;
         self
     }
+
+    pub fn with_max_parallel_tasks(mut self, max: usize) -> Self {
+        self.max_parallel_tasks = max;
+        self
+    }
+
+    pub fn with_max_cost_tokens(mut self, max: u64) -> Self {
+        self.max_cost_tokens = Some(max);
+        self
+    }
+
+    // ==================== Accessors ====================
+
+    /// Effective tool turn limit for Ask: `ask_max_tool_turns` if set,
+    /// otherwise `max_tool_turns`.
+    pub fn ask_max_tool_turns(&self) -> usize {
+        self.ask_max_tool_turns.unwrap_or(self.max_tool_turns)
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +159,9 @@ mod tests {
         assert_eq!(params.max_iterations, 50);
         assert_eq!(params.max_tool_turns, 10);
         assert_eq!(params.max_tool_retries, 2);
+        assert_eq!(params.max_parallel_tasks, 4);
+        assert_eq!(params.max_action_retries, 1);
+        assert_eq!(params.ask_max_tool_turns(), 10);
         assert!(params.working_dir.is_none());
         assert!(params.ensemble_session_timeout.is_some());
         assert_eq!(params.context_budget, ContextBudget::default());
@@ -109,4 +178,14 @@ mod tests {
         assert_eq!(params.max_tool_turns, 20);
         assert_eq!(params.working_dir, Some("/tmp/test".to_string()));
     }
+
+    #[test]
+    fn test_ask_max_tool_turns_follows_max_tool_turns_until_set() {
+        let params = ExecutionParams::default().with_max_tool_turns(20);
+        assert_eq!(params.ask_max_tool_turns(), 20);
+
+        let params = params.with_ask_max_tool_turns(4);
+        assert_eq!(params.ask_max_tool_turns(), 4);
+        assert_eq!(params.max_tool_turns, 20);
+    }
 }
@@ -3,10 +3,16 @@
 //! This module provides configuration types that control how use cases behave:
 //!
 //! - [`ExecutionParams`] — execution loop control (iterations, tool turns, timeouts)
+//! - [`ConfigProfiles`] — named overrides applied with `--profile`
 //! - [`QuorumConfig`] — 4-type container for buffer controller propagation
+//! - [`RetryPolicy`] — backoff for transient LLM request failures
 
 pub mod execution_params;
+pub mod profile;
 pub mod quorum_config;
+pub mod retry_policy;
 
 pub use execution_params::ExecutionParams;
+pub use profile::{ConfigProfiles, ProfileError, apply_profile};
 pub use quorum_config::QuorumConfig;
+pub use retry_policy::RetryPolicy;
@@ -0,0 +1,179 @@
+//! Named config profiles selected with `--profile <name>`.
+//!
+//! A profile is a set of key → value overrides defined in Lua
+//! (`quorum.config.profile(name, { ... })`). Applying one sets only the keys
+//! it lists, after init.lua and plugins and before CLI flags:
+//!
+//! ```text
+//! Rust defaults → init.lua → plugins/*.lua → --profile → CLI flags
+//! ```
+
+use crate::ports::config_accessor::{ConfigAccessError, ConfigAccessorPort, ConfigValue};
+use quorum_domain::agent::validation::ConfigIssue;
+use std::collections::BTreeMap;
+
+/// Profiles by name, each mapping dotted config keys to override values.
+pub type ConfigProfiles = BTreeMap<String, BTreeMap<String, ConfigValue>>;
+
+/// Failure to apply a profile.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProfileError {
+    /// No profile with this name was defined.
+    Unknown {
+        name: String,
+        available: Vec<String>,
+    },
+    /// One of the profile's overrides was rejected.
+    Invalid {
+        name: String,
+        error: ConfigAccessError,
+    },
+}
+
+impl std::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileError::Unknown { name, available } if available.is_empty() => {
+                write!(f, "unknown profile '{}' (no profiles are defined)", name)
+            }
+            ProfileError::Unknown { name, available } => write!(
+                f,
+                "unknown profile '{}' (available: {})",
+                name,
+                available.join(", ")
+            ),
+            ProfileError::Invalid { name, error } => write!(f, "profile '{}': {}", name, error),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+/// Apply profile `name` over `config`, one key at a time.
+///
+/// Keys the profile does not list keep their current value. Returns the
+/// validation issues reported by the individual `config_set` calls.
+pub fn apply_profile(
+    config: &mut dyn ConfigAccessorPort,
+    profiles: &ConfigProfiles,
+    name: &str,
+) -> Result<Vec<ConfigIssue>, ProfileError> {
+    let overrides = profiles.get(name).ok_or_else(|| ProfileError::Unknown {
+        name: name.to_string(),
+        available: profiles.keys().cloned().collect(),
+    })?;
+
+    let mut issues = Vec::new();
+    for (key, value) in overrides {
+        let set = config
+            .config_set(key, value.clone())
+            .map_err(|error| ProfileError::Invalid {
+                name: name.to_string(),
+                error,
+            })?;
+        issues.extend(set);
+    }
+    Ok(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::QuorumConfig;
+
+    fn profiles() -> ConfigProfiles {
+        let mut profiles = ConfigProfiles::new();
+        profiles.insert(
+            "careful".to_string(),
+            BTreeMap::from([
+                (
+                    "agent.consensus_level".to_string(),
+                    ConfigValue::String("ensemble".into()),
+                ),
+                (
+                    "agent.max_plan_revisions".to_string(),
+                    ConfigValue::Integer(5),
+                ),
+            ]),
+        );
+        profiles.insert(
+            "fast".to_string(),
+            BTreeMap::from([(
+                "agent.phase_scope".to_string(),
+                ConfigValue::String("fast".into()),
+            )]),
+        );
+        profiles
+    }
+
+    #[test]
+    fn test_profile_overrides_only_its_keys() {
+        let mut config = QuorumConfig::default();
+        // Base config (as init.lua would set it)
+        config
+            .config_set("agent.max_plan_revisions", ConfigValue::Integer(2))
+            .unwrap();
+        config
+            .config_set("agent.hil_mode", ConfigValue::String("auto_reject".into()))
+            .unwrap();
+
+        apply_profile(&mut config, &profiles(), "careful").unwrap();
+
+        // Profile wins over the base for the keys it sets...
+        assert_eq!(
+            config.config_get("agent.consensus_level").unwrap(),
+            ConfigValue::String("ensemble".into())
+        );
+        assert_eq!(
+            config.config_get("agent.max_plan_revisions").unwrap(),
+            ConfigValue::Integer(5)
+        );
+        // ...and leaves everything else alone
+        assert_eq!(
+            config.config_get("agent.hil_mode").unwrap(),
+            ConfigValue::String("auto_reject".into())
+        );
+        assert_eq!(
+            config.config_get("agent.phase_scope").unwrap(),
+            ConfigValue::String("full".into())
+        );
+    }
+
+    #[test]
+    fn test_unknown_profile_lists_available() {
+        let mut config = QuorumConfig::default();
+        let err = apply_profile(&mut config, &profiles(), "local").unwrap_err();
+        assert_eq!(
+            err,
+            ProfileError::Unknown {
+                name: "local".to_string(),
+                available: vec!["careful".to_string(), "fast".to_string()],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unknown profile 'local' (available: careful, fast)"
+        );
+
+        let err = apply_profile(&mut config, &ConfigProfiles::new(), "local").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown profile 'local' (no profiles are defined)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_override_is_an_error() {
+        let mut profiles = profiles();
+        profiles.insert(
+            "broken".to_string(),
+            BTreeMap::from([(
+                "agent.consensus_level".to_string(),
+                ConfigValue::String("committee".into()),
+            )]),
+        );
+        let mut config = QuorumConfig::default();
+        let err = apply_profile(&mut config, &profiles, "broken").unwrap_err();
+        assert!(matches!(err, ProfileError::Invalid { ref name, .. } if name == "broken"));
+    }
+}
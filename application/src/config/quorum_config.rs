@@ -25,13 +25,20 @@ use crate::ports::config_accessor::{ConfigAccessError, ConfigAccessorPort, Confi
 use crate::use_cases::run_agent::RunAgentInput;
 use crate::use_cases::run_ask::RunAskInput;
 use crate::use_cases::run_quorum::RunQuorumInput;
-use quorum_domain::agent::validation::{ConfigIssue, Severity};
-use quorum_domain::config::config_key::lookup_key;
+use quorum_domain::agent::validation::{ConfigIssue, ConfigIssueCode, Severity};
+use quorum_domain::config::config_key::{lookup_deprecated, lookup_key};
+use quorum_domain::context::ContextMode;
 use quorum_domain::{
-    AgentPolicy, ConsensusLevel, DebateConfig, DebateIntensity, HilMode, Model, ModelConfig,
-    OrchestrationStrategy, OutputFormat, PhaseScope, ProviderConfig, SessionMode,
-    SupervisorReporterMode,
+    AgentPolicy, ConsensusLevel, ConsensusMode, ContextFileLimits, ContextModeOverrides,
+    DEFAULT_MAX_NESTING_DEPTH, DebateConfig, DebateIntensity, EnsembleSelection, FailurePolicy,
+    HilMode, HilTimeoutDefault, IntentRouting, InteractionForm, Model, ModelConfig,
+    OrchestrationStrategy, OutputFormat, PhaseScope, ProviderConfig, QuorumRule, RiskLevel,
+    SessionMode, SupervisorReporterMode, ToolTimeouts,
 };
+use std::collections::BTreeMap;
+
+/// Default `context.reference_cache_ttl_secs`: reuse resolved references for 5 minutes.
+const DEFAULT_REFERENCE_CACHE_TTL_SECS: u64 = 300;
 
 /// Configuration container for buffer controllers.
 ///
@@ -53,6 +60,18 @@ pub struct QuorumConfig {
     show_progress: bool,
     history_file: Option<String>,
     provider_config: ProviderConfig,
+    // Context file size caps (applied by the context loader at DI time)
+    context_limits: ContextFileLimits,
+    // Files always loaded into the project context (`--context-file` appends)
+    pinned_context_files: Vec<String>,
+    // Text appended to every agent run's context (`--context`)
+    user_context: Vec<String>,
+    // How long resolved references are reused (applied by the CLI at DI time)
+    reference_cache_ttl_secs: u64,
+    // Record approved plans in .quorum/decisions.jsonl (`context.decision_log`)
+    decision_log: bool,
+    // Tool execution time limits (applied by the tool executor at DI time)
+    tool_timeouts: ToolTimeouts,
     // TUI input settings
     tui_submit_key: String,
     tui_newline_key: String,
@@ -64,8 +83,18 @@ pub struct QuorumConfig {
     // TUI layout settings
     tui_layout_preset: String,
     tui_flex_threshold: u16,
+    tui_layout_discuss_preset: String,
+    // TUI accessibility
+    tui_ascii_mode: bool,
+    // TUI intent routing for untyped input
+    tui_intent_routing: IntentRouting,
+    // Per-form context mode overrides (`interaction.<form>.context_mode`)
+    interaction_context_modes: ContextModeOverrides,
+    interaction_max_depth: usize,
     // Supervisor reporting (Issue #309)
     supervisor_reporter: SupervisorReporterMode,
+    // Extra conversation-log redaction regexes (compiled by the logger at DI time)
+    redact_patterns: Vec<String>,
 }
 
 impl Default for QuorumConfig {
@@ -81,6 +110,12 @@ impl Default for QuorumConfig {
             show_progress: true,
             history_file: None,
             provider_config: ProviderConfig::default(),
+            context_limits: ContextFileLimits::default(),
+            pinned_context_files: Vec::new(),
+            user_context: Vec::new(),
+            reference_cache_ttl_secs: DEFAULT_REFERENCE_CACHE_TTL_SECS,
+            decision_log: true,
+            tool_timeouts: ToolTimeouts::default(),
             tui_submit_key: "enter".to_string(),
             tui_newline_key: "shift+enter".to_string(),
             tui_editor_key: "I".to_string(),
@@ -90,7 +125,13 @@ impl Default for QuorumConfig {
             tui_context_header: true,
             tui_layout_preset: "default".to_string(),
             tui_flex_threshold: 120,
+            tui_layout_discuss_preset: String::new(),
+            tui_ascii_mode: false,
+            tui_intent_routing: IntentRouting::default(),
+            interaction_context_modes: ContextModeOverrides::new(),
+            interaction_max_depth: DEFAULT_MAX_NESTING_DEPTH,
             supervisor_reporter: SupervisorReporterMode::default(),
+            redact_patterns: Vec::new(),
         }
     }
 }
@@ -114,6 +155,12 @@ impl QuorumConfig {
             show_progress: true,
             history_file: None,
             provider_config: ProviderConfig::default(),
+            context_limits: ContextFileLimits::default(),
+            pinned_context_files: Vec::new(),
+            user_context: Vec::new(),
+            reference_cache_ttl_secs: DEFAULT_REFERENCE_CACHE_TTL_SECS,
+            decision_log: true,
+            tool_timeouts: ToolTimeouts::default(),
             tui_submit_key: "enter".to_string(),
             tui_newline_key: "shift+enter".to_string(),
             tui_editor_key: "I".to_string(),
@@ -123,7 +170,13 @@ impl QuorumConfig {
             tui_context_header: true,
             tui_layout_preset: "default".to_string(),
             tui_flex_threshold: 120,
+            tui_layout_discuss_preset: String::new(),
+            tui_ascii_mode: false,
+            tui_intent_routing: IntentRouting::default(),
+            interaction_context_modes: ContextModeOverrides::new(),
+            interaction_max_depth: DEFAULT_MAX_NESTING_DEPTH,
             supervisor_reporter: SupervisorReporterMode::default(),
+            redact_patterns: Vec::new(),
         }
     }
 
@@ -227,6 +280,56 @@ impl QuorumConfig {
         &self.provider_config
     }
 
+    /// Per-file and total size caps for context file loading (`context.*`).
+    pub fn context_limits(&self) -> &ContextFileLimits {
+        &self.context_limits
+    }
+
+    /// Files always loaded into the project context (`context.pinned_files`).
+    pub fn pinned_context_files(&self) -> &[String] {
+        &self.pinned_context_files
+    }
+
+    pub fn pinned_context_files_mut(&mut self) -> &mut Vec<String> {
+        &mut self.pinned_context_files
+    }
+
+    /// Text added to each agent run's context without a file (`--context`).
+    pub fn user_context(&self) -> &[String] {
+        &self.user_context
+    }
+
+    pub fn user_context_mut(&mut self) -> &mut Vec<String> {
+        &mut self.user_context
+    }
+
+    /// How long a resolved reference is reused (`context.reference_cache_ttl_secs`).
+    pub fn reference_cache_ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.reference_cache_ttl_secs)
+    }
+
+    /// Whether approved plans are recorded in and read back from
+    /// `.quorum/decisions.jsonl` (`context.decision_log`).
+    pub fn decision_log_enabled(&self) -> bool {
+        self.decision_log
+    }
+
+    /// Per-tool execution time limits (`tools.default_timeout_secs` / `tools.timeouts`).
+    pub fn tool_timeouts(&self) -> &ToolTimeouts {
+        &self.tool_timeouts
+    }
+
+    /// Per-form context mode overrides (`interaction.<form>.context_mode`);
+    /// resolve with [`InteractionForm::context_mode_with`].
+    pub fn interaction_context_modes(&self) -> &ContextModeOverrides {
+        &self.interaction_context_modes
+    }
+
+    /// How deep child interactions may nest (`interaction.max_nesting_depth`).
+    pub fn interaction_max_depth(&self) -> usize {
+        self.interaction_max_depth
+    }
+
     /// Supervisor status reporting policy (`auto` | `none`; see Issue #309).
     /// Whether a reporting backend actually activates under `auto` is up to
     /// the concrete adapter (e.g. it may require a supervisor env var).
@@ -234,6 +337,11 @@ impl QuorumConfig {
         self.supervisor_reporter
     }
 
+    /// Extra regexes redacted from conversation logs (`logging.redact_patterns`).
+    pub fn redact_patterns(&self) -> &[String] {
+        &self.redact_patterns
+    }
+
     /// Provider configuration (mutable).
     pub fn provider_config_mut(&mut self) -> &mut ProviderConfig {
         &mut self.provider_config
@@ -279,6 +387,19 @@ impl QuorumConfig {
         self.tui_flex_threshold
     }
 
+    /// Preset to switch to while a Discuss interaction runs (empty: none).
+    pub fn tui_layout_discuss_preset(&self) -> &str {
+        &self.tui_layout_discuss_preset
+    }
+
+    pub fn tui_ascii_mode(&self) -> bool {
+        self.tui_ascii_mode
+    }
+
+    pub fn tui_intent_routing(&self) -> IntentRouting {
+        self.tui_intent_routing
+    }
+
     // ==================== Builder Methods (init-time) ====================
 
     /// Set the working directory.
@@ -333,9 +454,12 @@ impl QuorumConfig {
 
     /// Validate the configuration combination.
     ///
-    /// Delegates to [`SessionMode::validate_combination()`].
+    /// Combines [`SessionMode::validate_combination()`] with
+    /// [`ModelConfig::validate_roles()`].
     pub fn validate(&self) -> Vec<ConfigIssue> {
-        self.mode.validate_combination()
+        let mut issues = self.mode.validate_combination();
+        issues.extend(self.models.validate_roles());
+        issues
     }
 
     /// Check whether any issues are errors (i.e. fatal).
@@ -354,6 +478,7 @@ impl QuorumConfig {
             self.policy.clone(),
             self.execution.clone(),
         )
+        .with_user_context(self.user_context.clone())
     }
 
     /// Build a [`RunAskInput`] for a lightweight Q&A interaction.
@@ -379,6 +504,9 @@ impl QuorumConfig {
 
 impl ConfigAccessorPort for QuorumConfig {
     fn config_get(&self, key: &str) -> Result<ConfigValue, ConfigAccessError> {
+        if let Some(deprecated) = lookup_deprecated(key) {
+            return self.config_get(deprecated.replacement);
+        }
         match key {
             // ---- agent.* ----
             "agent.consensus_level" => {
@@ -393,9 +521,49 @@ impl ConfigAccessorPort for QuorumConfig {
                 Ok(ConfigValue::String(name.to_string()))
             }
             "agent.hil_mode" => Ok(ConfigValue::String(self.policy.hil_mode.to_string())),
+            "agent.hil_timeout_secs" => Ok(ConfigValue::Integer(
+                self.policy.hil_timeout_secs.unwrap_or(0) as i64,
+            )),
+            "agent.hil_timeout_default" => Ok(ConfigValue::String(
+                self.policy.hil_timeout_default.to_string(),
+            )),
+            "agent.gather_context" => Ok(ConfigValue::Boolean(self.policy.gather_context)),
             "agent.max_plan_revisions" => {
                 Ok(ConfigValue::Integer(self.policy.max_plan_revisions as i64))
             }
+            "agent.max_tasks_per_plan" => Ok(ConfigValue::Integer(
+                self.policy.max_tasks_per_plan.unwrap_or(0) as i64,
+            )),
+            "agent.ensemble_selection" => Ok(ConfigValue::String(
+                self.policy.ensemble_selection.to_string(),
+            )),
+            "agent.on_task_failure" => {
+                Ok(ConfigValue::String(self.policy.on_task_failure.to_string()))
+            }
+            "agent.max_action_retries" => Ok(ConfigValue::Integer(
+                self.execution.max_action_retries as i64,
+            )),
+            "agent.max_cost_tokens" => Ok(ConfigValue::Integer(
+                self.execution.max_cost_tokens.unwrap_or(0) as i64,
+            )),
+            "agent.max_final_review_fixes" => Ok(ConfigValue::Integer(
+                self.policy.max_final_review_fixes as i64,
+            )),
+            "agent.quorum_rule" => Ok(ConfigValue::String(
+                self.policy.quorum_rule.to_config_string(),
+            )),
+            "agent.consensus_mode" => Ok(ConfigValue::String(
+                self.policy.consensus_mode.to_config_string(),
+            )),
+            "agent.min_reviewers_high_risk" => Ok(ConfigValue::Integer(
+                self.policy.min_reviewers(RiskLevel::High) as i64,
+            )),
+            "agent.command_allowlist" => Ok(ConfigValue::StringList(
+                self.policy.command_allowlist.clone(),
+            )),
+            "agent.command_denylist" => Ok(ConfigValue::StringList(
+                self.policy.command_denylist.clone(),
+            )),
             // ---- debate.* ----
             "debate.models" => Ok(ConfigValue::StringList(
                 self.debate_config
@@ -417,6 +585,13 @@ impl ConfigAccessorPort for QuorumConfig {
             "models.review" => Ok(ConfigValue::StringList(
                 self.models.review.iter().map(|m| m.to_string()).collect(),
             )),
+            "models.tie_breaker" => Ok(ConfigValue::String(
+                self.models
+                    .tie_breaker
+                    .as_ref()
+                    .map(|m| m.to_string())
+                    .unwrap_or_default(),
+            )),
             "models.participants" => Ok(ConfigValue::StringList(
                 self.models
                     .participants
@@ -426,6 +601,20 @@ impl ConfigAccessorPort for QuorumConfig {
             )),
             "models.moderator" => Ok(ConfigValue::String(self.models.moderator.to_string())),
             "models.ask" => Ok(ConfigValue::String(self.models.ask.to_string())),
+            "models.prompts" => Ok(ConfigValue::StringMap(
+                self.models
+                    .system_prompt_overrides
+                    .iter()
+                    .map(|(m, p)| (m.to_string(), p.clone()))
+                    .collect(),
+            )),
+            "models.aliases" => Ok(ConfigValue::StringMap(
+                self.models
+                    .aliases
+                    .iter()
+                    .map(|(a, m)| (a.clone(), m.to_string()))
+                    .collect(),
+            )),
             // ---- execution.* ----
             "execution.max_iterations" => {
                 Ok(ConfigValue::Integer(self.execution.max_iterations as i64))
@@ -433,6 +622,12 @@ impl ConfigAccessorPort for QuorumConfig {
             "execution.max_tool_turns" => {
                 Ok(ConfigValue::Integer(self.execution.max_tool_turns as i64))
             }
+            "execution.ask_max_tool_turns" => Ok(ConfigValue::Integer(
+                self.execution.ask_max_tool_turns() as i64,
+            )),
+            "execution.max_parallel_tasks" => Ok(ConfigValue::Integer(
+                self.execution.max_parallel_tasks as i64,
+            )),
             // ---- output.* ----
             "output.format" => Ok(ConfigValue::String(self.output_format.to_string())),
             "output.color" => Ok(ConfigValue::Boolean(self.color)),
@@ -448,9 +643,26 @@ impl ConfigAccessorPort for QuorumConfig {
             "context_budget.max_total_bytes" => Ok(ConfigValue::Integer(
                 self.execution.context_budget.max_total_bytes() as i64,
             )),
+            "context_budget.per_tool_output_chars" => Ok(ConfigValue::Integer(
+                self.execution.context_budget.per_tool_output_chars() as i64,
+            )),
             "context_budget.recent_full_count" => Ok(ConfigValue::Integer(
                 self.execution.context_budget.recent_full_count() as i64,
             )),
+            // ---- context.* ----
+            "context.max_file_bytes" => Ok(ConfigValue::Integer(
+                self.context_limits.max_file_bytes() as i64,
+            )),
+            "context.max_total_bytes" => Ok(ConfigValue::Integer(
+                self.context_limits.max_total_bytes() as i64,
+            )),
+            "context.pinned_files" => {
+                Ok(ConfigValue::StringList(self.pinned_context_files.clone()))
+            }
+            "context.reference_cache_ttl_secs" => {
+                Ok(ConfigValue::Integer(self.reference_cache_ttl_secs as i64))
+            }
+            "context.decision_log" => Ok(ConfigValue::Boolean(self.decision_log)),
             // ---- tui.input.* ----
             "tui.input.submit_key" => Ok(ConfigValue::String(self.tui_submit_key.clone())),
             "tui.input.newline_key" => Ok(ConfigValue::String(self.tui_newline_key.clone())),
@@ -462,8 +674,45 @@ impl ConfigAccessorPort for QuorumConfig {
             // ---- tui.layout.* ----
             "tui.layout.preset" => Ok(ConfigValue::String(self.tui_layout_preset.clone())),
             "tui.layout.flex_threshold" => Ok(ConfigValue::Integer(self.tui_flex_threshold as i64)),
+            "tui.layout.discuss_preset" => {
+                Ok(ConfigValue::String(self.tui_layout_discuss_preset.clone()))
+            }
+            "tui.ascii_mode" => Ok(ConfigValue::Boolean(self.tui_ascii_mode)),
+            "tui.intent_routing" => Ok(ConfigValue::String(self.tui_intent_routing.to_string())),
+            // ---- tools.* ----
+            "tools.risk_overrides" => Ok(ConfigValue::StringMap(
+                self.policy
+                    .command_risk_overrides
+                    .iter()
+                    .map(|(prefix, risk)| (prefix.clone(), risk.to_string()))
+                    .collect(),
+            )),
+            "tools.default_timeout_secs" => Ok(ConfigValue::Integer(
+                self.tool_timeouts.default_secs() as i64,
+            )),
+            "tools.timeouts" => Ok(ConfigValue::StringMap(
+                self.tool_timeouts
+                    .per_tool()
+                    .iter()
+                    .map(|(tool, secs)| (tool.clone(), secs.to_string()))
+                    .collect(),
+            )),
+            // ---- interaction.* ----
+            "interaction.max_nesting_depth" => {
+                Ok(ConfigValue::Integer(self.interaction_max_depth as i64))
+            }
+            "interaction.agent.context_mode"
+            | "interaction.ask.context_mode"
+            | "interaction.discuss.context_mode"
+            | "interaction.review.context_mode" => {
+                let mode =
+                    interaction_form_of(key).context_mode_with(&self.interaction_context_modes);
+                Ok(ConfigValue::String(mode.to_string()))
+            }
             // ---- supervisor.* ----
             "supervisor.reporter" => Ok(ConfigValue::String(self.supervisor_reporter.to_string())),
+            // ---- logging.* ----
+            "logging.redact_patterns" => Ok(ConfigValue::StringList(self.redact_patterns.clone())),
             _ => Err(ConfigAccessError::UnknownKey {
                 key: key.to_string(),
             }),
@@ -475,11 +724,48 @@ impl ConfigAccessorPort for QuorumConfig {
         key: &str,
         value: ConfigValue,
     ) -> Result<Vec<ConfigIssue>, ConfigAccessError> {
+        // Renamed keys: apply the value under the new name and say so
+        if let Some(deprecated) = lookup_deprecated(key) {
+            let mut issues = vec![ConfigIssue {
+                severity: Severity::Warning,
+                code: ConfigIssueCode::DeprecatedKey {
+                    key: key.to_string(),
+                    replacement: deprecated.replacement.to_string(),
+                },
+                message: format!(
+                    "'{}' is deprecated: rename it to '{}'",
+                    key, deprecated.replacement
+                ),
+            }];
+            issues.extend(self.config_set(deprecated.replacement, value)?);
+            return Ok(issues);
+        }
+
         // Check key exists (all keys are Mutable in Phase 1.5)
         let _info = lookup_key(key).ok_or_else(|| ConfigAccessError::UnknownKey {
             key: key.to_string(),
         })?;
 
+        // `${VAR}` / `${VAR:-default}` in string values
+        let value = match value {
+            ConfigValue::String(s) => {
+                match quorum_domain::config::interpolate_env(&s, |var| std::env::var(var).ok()) {
+                    Ok(expanded) => ConfigValue::String(expanded),
+                    Err(var) => {
+                        return Err(ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: format!(
+                                "environment variable '{}' is not set \
+                                 (use ${{{}:-default}} for a fallback)",
+                                var, var
+                            ),
+                        });
+                    }
+                }
+            }
+            other => other,
+        };
+
         match key {
             // ---- agent.* (SessionMode + AgentPolicy) ----
             "agent.consensus_level" => {
@@ -529,18 +815,114 @@ impl ConfigAccessorPort for QuorumConfig {
                 self.policy.hil_mode = mode;
                 Ok(vec![])
             }
+            "agent.hil_timeout_secs" => {
+                let n = extract_positive_int(key, value)?;
+                self.policy.hil_timeout_secs = (n > 0).then_some(n as u64);
+                Ok(vec![])
+            }
+            "agent.hil_timeout_default" => {
+                let s = extract_string(key, value)?;
+                self.policy.hil_timeout_default = s.parse::<HilTimeoutDefault>().map_err(|e| {
+                    ConfigAccessError::InvalidValue {
+                        key: key.to_string(),
+                        message: e,
+                    }
+                })?;
+                Ok(vec![])
+            }
+            "agent.gather_context" => {
+                self.policy.gather_context = extract_bool(key, value)?;
+                Ok(vec![])
+            }
             "agent.max_plan_revisions" => {
                 let n = extract_positive_int(key, value)?;
                 self.policy.max_plan_revisions = n;
                 Ok(vec![])
             }
+            "agent.max_tasks_per_plan" => {
+                let n = extract_positive_int(key, value)?;
+                self.policy.max_tasks_per_plan = (n > 0).then_some(n);
+                Ok(vec![])
+            }
+            "agent.ensemble_selection" => {
+                let s = extract_string(key, value)?;
+                self.policy.ensemble_selection = s.parse::<EnsembleSelection>().map_err(|e| {
+                    ConfigAccessError::InvalidValue {
+                        key: key.to_string(),
+                        message: e,
+                    }
+                })?;
+                Ok(vec![])
+            }
+            "agent.on_task_failure" => {
+                let s = extract_string(key, value)?;
+                self.policy.on_task_failure =
+                    s.parse::<FailurePolicy>()
+                        .map_err(|e| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: e,
+                        })?;
+                Ok(vec![])
+            }
+            "agent.max_action_retries" => {
+                let n = extract_positive_int(key, value)?;
+                self.execution.max_action_retries = n;
+                Ok(vec![])
+            }
+            "agent.max_cost_tokens" => {
+                let n = extract_positive_int(key, value)?;
+                self.execution.max_cost_tokens = (n > 0).then_some(n as u64);
+                Ok(vec![])
+            }
+            "agent.max_final_review_fixes" => {
+                let n = extract_positive_int(key, value)?;
+                self.policy.max_final_review_fixes = n;
+                Ok(vec![])
+            }
+            "agent.quorum_rule" => {
+                let s = extract_string(key, value)?;
+                let rule =
+                    s.parse::<QuorumRule>()
+                        .map_err(|e| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: e,
+                        })?;
+                self.policy.quorum_rule = rule;
+                Ok(vec![])
+            }
+            "agent.consensus_mode" => {
+                let s = extract_string(key, value)?;
+                let mode =
+                    s.parse::<ConsensusMode>()
+                        .map_err(|e| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: e,
+                        })?;
+                self.policy.consensus_mode = mode;
+                Ok(vec![])
+            }
+            "agent.min_reviewers_high_risk" => {
+                let n = extract_positive_int(key, value)?;
+                self.policy
+                    .min_reviewers_for_risk
+                    .insert(RiskLevel::High, n);
+                Ok(vec![])
+            }
+            "agent.command_allowlist" => {
+                self.policy.command_allowlist = extract_string_list(key, value)?;
+                Ok(vec![])
+            }
+            "agent.command_denylist" => {
+                self.policy.command_denylist = extract_string_list(key, value)?;
+                Ok(vec![])
+            }
             // ---- debate.* (DebateConfig) ----
             "debate.models" => {
                 let list = extract_string_list(key, value)?;
                 self.debate_config.models = list
                     .into_iter()
-                    .map(|s| s.parse::<Model>().unwrap())
-                    .collect();
+                    .map(|s| parse_model(key, &self.models, &s))
+                    .collect::<Result<_, _>>()?;
                 self.sync_debate_strategy();
                 Ok(vec![])
             }
@@ -571,38 +953,80 @@ impl ConfigAccessorPort for QuorumConfig {
             // ---- models.* (ModelConfig) ----
             "models.exploration" => {
                 let s = extract_string(key, value)?;
-                self.models.exploration = s.parse::<Model>().unwrap();
-                Ok(vec![])
+                self.models.exploration = parse_model(key, &self.models, &s)?;
+                Ok(self.models.validate_roles())
             }
             "models.decision" => {
                 let s = extract_string(key, value)?;
-                self.models.decision = s.parse::<Model>().unwrap();
-                Ok(vec![])
+                self.models.decision = parse_model(key, &self.models, &s)?;
+                Ok(self.models.validate_roles())
             }
             "models.review" => {
                 let list = extract_string_list(key, value)?;
                 self.models.review = list
                     .into_iter()
-                    .map(|s| s.parse::<Model>().unwrap())
-                    .collect();
+                    .map(|s| parse_model(key, &self.models, &s))
+                    .collect::<Result<_, _>>()?;
+                Ok(self.models.validate_roles())
+            }
+            "models.tie_breaker" => {
+                let s = extract_string(key, value)?;
+                self.models.tie_breaker = if s.is_empty() {
+                    None
+                } else {
+                    Some(parse_model(key, &self.models, &s)?)
+                };
                 Ok(vec![])
             }
             "models.participants" => {
                 let list = extract_string_list(key, value)?;
                 self.models.participants = list
                     .into_iter()
-                    .map(|s| s.parse::<Model>().unwrap())
-                    .collect();
+                    .map(|s| parse_model(key, &self.models, &s))
+                    .collect::<Result<_, _>>()?;
                 Ok(vec![])
             }
             "models.moderator" => {
                 let s = extract_string(key, value)?;
-                self.models.moderator = s.parse::<Model>().unwrap();
-                Ok(vec![])
+                self.models.moderator = parse_model(key, &self.models, &s)?;
+                Ok(self.models.validate_roles())
             }
             "models.ask" => {
                 let s = extract_string(key, value)?;
-                self.models.ask = s.parse::<Model>().unwrap();
+                self.models.ask = parse_model(key, &self.models, &s)?;
+                Ok(vec![])
+            }
+            "models.prompts" => {
+                let map = extract_string_map(key, value)?;
+                self.models.system_prompt_overrides = map
+                    .into_iter()
+                    .map(|(m, p)| Ok((parse_model(key, &self.models, &m)?, p)))
+                    .collect::<Result<_, ConfigAccessError>>()?;
+                Ok(vec![])
+            }
+            "models.aliases" => {
+                let map = extract_string_map(key, value)?;
+                let mut aliases = BTreeMap::new();
+                for (alias, target) in map {
+                    let alias = alias.trim().to_ascii_lowercase();
+                    if alias.is_empty() {
+                        return Err(ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: "alias name must not be empty".to_string(),
+                        });
+                    }
+                    // Targets resolve against built-ins only, so aliases
+                    // cannot chain through each other.
+                    let model =
+                        target
+                            .parse::<Model>()
+                            .map_err(|e| ConfigAccessError::InvalidValue {
+                                key: key.to_string(),
+                                message: e.to_string(),
+                            })?;
+                    aliases.insert(alias, model);
+                }
+                self.models.aliases = aliases;
                 Ok(vec![])
             }
             // ---- execution.* ----
@@ -616,6 +1040,16 @@ impl ConfigAccessorPort for QuorumConfig {
                 self.execution.max_tool_turns = n;
                 Ok(vec![])
             }
+            "execution.ask_max_tool_turns" => {
+                let n = extract_positive_int(key, value)?;
+                self.execution.ask_max_tool_turns = Some(n);
+                Ok(vec![])
+            }
+            "execution.max_parallel_tasks" => {
+                let n = extract_positive_int(key, value)?;
+                self.execution.max_parallel_tasks = n;
+                Ok(vec![])
+            }
             // ---- output.* ----
             "output.format" => {
                 let s = extract_string(key, value)?;
@@ -656,7 +1090,9 @@ impl ConfigAccessorPort for QuorumConfig {
                     key: key.to_string(),
                     message: errors.join("; "),
                 })?;
-                self.execution.context_budget = budget;
+                self.execution.context_budget = budget.with_per_tool_output_chars(
+                    self.execution.context_budget.per_tool_output_chars(),
+                );
                 Ok(vec![])
             }
             "context_budget.max_total_bytes" => {
@@ -670,7 +1106,9 @@ impl ConfigAccessorPort for QuorumConfig {
                     key: key.to_string(),
                     message: errors.join("; "),
                 })?;
-                self.execution.context_budget = budget;
+                self.execution.context_budget = budget.with_per_tool_output_chars(
+                    self.execution.context_budget.per_tool_output_chars(),
+                );
                 Ok(vec![])
             }
             "context_budget.recent_full_count" => {
@@ -684,9 +1122,63 @@ impl ConfigAccessorPort for QuorumConfig {
                     key: key.to_string(),
                     message: errors.join("; "),
                 })?;
+                self.execution.context_budget = budget.with_per_tool_output_chars(
+                    self.execution.context_budget.per_tool_output_chars(),
+                );
+                Ok(vec![])
+            }
+            "context_budget.per_tool_output_chars" => {
+                let n = extract_positive_int(key, value)?;
+                let budget = self
+                    .execution
+                    .context_budget
+                    .clone()
+                    .with_per_tool_output_chars(n);
+                let issues = budget.validate();
+                if !issues.is_empty() {
+                    return Err(ConfigAccessError::InvalidValue {
+                        key: key.to_string(),
+                        message: issues.join("; "),
+                    });
+                }
                 self.execution.context_budget = budget;
                 Ok(vec![])
             }
+            // ---- context.* ----
+            "context.max_file_bytes" => {
+                let n = extract_positive_int(key, value)?;
+                self.context_limits =
+                    ContextFileLimits::try_new(n, self.context_limits.max_total_bytes()).map_err(
+                        |errors| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: errors.join("; "),
+                        },
+                    )?;
+                Ok(vec![])
+            }
+            "context.max_total_bytes" => {
+                let n = extract_positive_int(key, value)?;
+                self.context_limits =
+                    ContextFileLimits::try_new(self.context_limits.max_file_bytes(), n).map_err(
+                        |errors| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: errors.join("; "),
+                        },
+                    )?;
+                Ok(vec![])
+            }
+            "context.pinned_files" => {
+                self.pinned_context_files = extract_string_list(key, value)?;
+                Ok(vec![])
+            }
+            "context.reference_cache_ttl_secs" => {
+                self.reference_cache_ttl_secs = extract_positive_int(key, value)? as u64;
+                Ok(vec![])
+            }
+            "context.decision_log" => {
+                self.decision_log = extract_bool(key, value)?;
+                Ok(vec![])
+            }
             // ---- tui.input.* ----
             "tui.input.submit_key" => {
                 let s = extract_string(key, value)?;
@@ -756,6 +1248,103 @@ impl ConfigAccessorPort for QuorumConfig {
                 self.tui_flex_threshold = n as u16;
                 Ok(vec![])
             }
+            "tui.layout.discuss_preset" => {
+                let s = extract_string(key, value)?;
+                match s.as_str() {
+                    "" | "default" | "minimal" | "wide" | "stacked" => {
+                        self.tui_layout_discuss_preset = s;
+                        Ok(vec![])
+                    }
+                    _ => Err(ConfigAccessError::InvalidValue {
+                        key: key.to_string(),
+                        message: format!(
+                            "unknown preset '{}', valid: \"\" (none), default, minimal, wide, stacked",
+                            s
+                        ),
+                    }),
+                }
+            }
+            "tui.ascii_mode" => {
+                let b = extract_bool(key, value)?;
+                self.tui_ascii_mode = b;
+                Ok(vec![])
+            }
+            "tui.intent_routing" => {
+                let s = extract_string(key, value)?;
+                self.tui_intent_routing =
+                    s.parse::<IntentRouting>()
+                        .map_err(|e| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: e,
+                        })?;
+                Ok(vec![])
+            }
+            // ---- tools.* ----
+            "tools.risk_overrides" => {
+                let map = extract_string_map(key, value)?;
+                let overrides = map
+                    .into_iter()
+                    .map(|(prefix, risk)| {
+                        risk.parse::<RiskLevel>()
+                            .map(|risk| (prefix, risk))
+                            .map_err(|e| ConfigAccessError::InvalidValue {
+                                key: key.to_string(),
+                                message: e,
+                            })
+                    })
+                    .collect::<Result<_, _>>()?;
+                self.policy.command_risk_overrides = overrides;
+                Ok(vec![])
+            }
+            "tools.default_timeout_secs" => {
+                let n = extract_positive_int(key, value)?;
+                if n == 0 {
+                    return Err(ConfigAccessError::InvalidValue {
+                        key: key.to_string(),
+                        message: "timeout must be > 0".to_string(),
+                    });
+                }
+                self.tool_timeouts.set_default_secs(n as u64);
+                Ok(vec![])
+            }
+            "tools.timeouts" => {
+                let map = extract_string_map(key, value)?;
+                let per_tool = map
+                    .into_iter()
+                    .map(|(tool, secs)| match secs.trim().parse::<u64>() {
+                        Ok(n) if n > 0 => Ok((tool, n)),
+                        _ => Err(ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: format!(
+                                "timeout for '{}' must be a positive number of seconds, got '{}'",
+                                tool, secs
+                            ),
+                        }),
+                    })
+                    .collect::<Result<_, _>>()?;
+                self.tool_timeouts.set_per_tool(per_tool);
+                Ok(vec![])
+            }
+            // ---- interaction.* ----
+            "interaction.max_nesting_depth" => {
+                self.interaction_max_depth = extract_positive_int(key, value)?;
+                Ok(vec![])
+            }
+            "interaction.agent.context_mode"
+            | "interaction.ask.context_mode"
+            | "interaction.discuss.context_mode"
+            | "interaction.review.context_mode" => {
+                let s = extract_string(key, value)?;
+                let mode =
+                    s.parse::<ContextMode>()
+                        .map_err(|e| ConfigAccessError::InvalidValue {
+                            key: key.to_string(),
+                            message: e,
+                        })?;
+                self.interaction_context_modes
+                    .insert(interaction_form_of(key), mode);
+                Ok(vec![])
+            }
             // ---- supervisor.* ----
             "supervisor.reporter" => {
                 let s = extract_string(key, value)?;
@@ -768,6 +1357,11 @@ impl ConfigAccessorPort for QuorumConfig {
                 self.supervisor_reporter = mode;
                 Ok(vec![])
             }
+            // ---- logging.* ----
+            "logging.redact_patterns" => {
+                self.redact_patterns = extract_string_list(key, value)?;
+                Ok(vec![])
+            }
             _ => Err(ConfigAccessError::UnknownKey {
                 key: key.to_string(),
             }),
@@ -782,6 +1376,14 @@ impl ConfigAccessorPort for QuorumConfig {
     }
 }
 
+/// The form named by an `interaction.<form>.*` key.
+fn interaction_form_of(key: &str) -> InteractionForm {
+    key.split('.')
+        .nth(1)
+        .and_then(|form| form.parse().ok())
+        .expect("interaction.* keys name a valid form")
+}
+
 // ==================== Value Extraction Helpers ====================
 
 fn extract_string(key: &str, value: ConfigValue) -> Result<String, ConfigAccessError> {
@@ -818,6 +1420,15 @@ fn extract_positive_int(key: &str, value: ConfigValue) -> Result<usize, ConfigAc
     }
 }
 
+fn parse_model(key: &str, models: &ModelConfig, name: &str) -> Result<Model, ConfigAccessError> {
+    models
+        .resolve(name)
+        .map_err(|e| ConfigAccessError::InvalidValue {
+            key: key.to_string(),
+            message: e.to_string(),
+        })
+}
+
 fn extract_string_list(key: &str, value: ConfigValue) -> Result<Vec<String>, ConfigAccessError> {
     match value {
         ConfigValue::StringList(list) => Ok(list),
@@ -828,10 +1439,25 @@ fn extract_string_list(key: &str, value: ConfigValue) -> Result<Vec<String>, Con
     }
 }
 
+/// Lua cannot tell an empty map from an empty list, so `{}` arrives as an
+/// empty [`ConfigValue::StringList`]; it clears the map.
+fn extract_string_map(
+    key: &str,
+    value: ConfigValue,
+) -> Result<BTreeMap<String, String>, ConfigAccessError> {
+    match value {
+        ConfigValue::StringMap(map) => Ok(map),
+        ConfigValue::StringList(list) if list.is_empty() => Ok(BTreeMap::new()),
+        _ => Err(ConfigAccessError::InvalidValue {
+            key: key.to_string(),
+            message: "expected a table of key = value".to_string(),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use quorum_domain::agent::validation::ConfigIssueCode;
     use quorum_domain::{ConsensusLevel, HilMode, Model, OutputFormat, PhaseScope};
 
     #[test]
@@ -925,18 +1551,119 @@ mod tests {
         assert!(!QuorumConfig::has_errors(&issues)); // Warning only
     }
 
-    // ==================== ConfigAccessorPort Tests ====================
-
     #[test]
-    fn test_config_get_consensus_level() {
-        let config = QuorumConfig::default();
-        let val = config.config_get("agent.consensus_level").unwrap();
-        assert_eq!(val, ConfigValue::String("solo".to_string()));
-    }
+    fn test_config_set_deprecated_key_migrates_value() {
+        let mut config = QuorumConfig::default();
+        let issues = config
+            .config_set("quorum.rule", ConfigValue::String("unanimous".into()))
+            .unwrap();
 
-    #[test]
-    fn test_config_get_unknown_key() {
-        let config = QuorumConfig::default();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert_eq!(
+            issues[0].code,
+            ConfigIssueCode::DeprecatedKey {
+                key: "quorum.rule".to_string(),
+                replacement: "agent.quorum_rule".to_string(),
+            }
+        );
+        assert_eq!(
+            issues[0].message,
+            "'quorum.rule' is deprecated: rename it to 'agent.quorum_rule'"
+        );
+
+        // The value is honored under the new key, and readable under both
+        let current = config.config_get("agent.quorum_rule").unwrap();
+        assert_eq!(current, ConfigValue::String("unanimous".into()));
+        assert_eq!(config.config_get("quorum.rule").unwrap(), current);
+    }
+
+    #[test]
+    fn test_config_set_deprecated_key_still_validates_value() {
+        let mut config = QuorumConfig::default();
+        assert!(
+            config
+                .config_set("quorum.rule", ConfigValue::String("bogus".into()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_interpolates_env_vars() {
+        // Cargo sets CARGO_PKG_NAME for the test process
+        let mut config = QuorumConfig::default();
+        let issues = config
+            .config_set(
+                "repl.history_file",
+                ConfigValue::String("/tmp/${CARGO_PKG_NAME}/history".into()),
+            )
+            .unwrap();
+        assert!(issues.is_empty());
+        let expected = format!("/tmp/{}/history", env!("CARGO_PKG_NAME"));
+        assert_eq!(config.history_file(), Some(expected.as_str()));
+
+        config
+            .config_set(
+                "repl.history_file",
+                ConfigValue::String("${QUORUM_TEST_UNSET_DIR:-/var/quorum}/history".into()),
+            )
+            .unwrap();
+        assert_eq!(config.history_file(), Some("/var/quorum/history"));
+    }
+
+    #[test]
+    fn test_config_set_unset_env_var_is_rejected() {
+        let mut config = QuorumConfig::default();
+        let err = config
+            .config_set(
+                "repl.history_file",
+                ConfigValue::String("${QUORUM_TEST_NEVER_SET}/history".into()),
+            )
+            .unwrap_err();
+
+        match err {
+            ConfigAccessError::InvalidValue { key, message } => {
+                assert_eq!(key, "repl.history_file");
+                assert!(message.contains("QUORUM_TEST_NEVER_SET"), "{}", message);
+            }
+            other => panic!("expected InvalidValue, got {other:?}"),
+        }
+        // Not applied as an empty or half-expanded path
+        assert_eq!(config.history_file(), None);
+    }
+
+    #[test]
+    fn test_validate_includes_model_role_warnings() {
+        let mut config = QuorumConfig::default();
+        let issues = config
+            .config_set(
+                "models.review",
+                ConfigValue::StringList(vec!["gpt-5.3-codex".into(), "gpt-5.3-codex".into()]),
+            )
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            issues[0].code,
+            ConfigIssueCode::RedundantModelRole { .. }
+        ));
+
+        let issues = config.validate();
+        assert!(!issues.is_empty());
+        assert!(!QuorumConfig::has_errors(&issues));
+    }
+
+    // ==================== ConfigAccessorPort Tests ====================
+
+    #[test]
+    fn test_config_get_consensus_level() {
+        let config = QuorumConfig::default();
+        let val = config.config_get("agent.consensus_level").unwrap();
+        assert_eq!(val, ConfigValue::String("solo".to_string()));
+    }
+
+    #[test]
+    fn test_config_get_unknown_key() {
+        let config = QuorumConfig::default();
         let err = config.config_get("nonexistent").unwrap_err();
         assert!(matches!(err, ConfigAccessError::UnknownKey { .. }));
     }
@@ -976,6 +1703,94 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_config_set_models_prompts() {
+        let mut config = QuorumConfig::default();
+        config
+            .config_set(
+                "models.prompts",
+                ConfigValue::StringMap(BTreeMap::from([(
+                    "gpt-5.3-codex".to_string(),
+                    "Be terse.".to_string(),
+                )])),
+            )
+            .unwrap();
+        assert_eq!(
+            config
+                .models
+                .system_prompt_overrides
+                .get(&Model::Gpt53Codex),
+            Some(&"Be terse.".to_string())
+        );
+        assert_eq!(
+            config.config_get("models.prompts").unwrap(),
+            ConfigValue::StringMap(BTreeMap::from([(
+                "gpt-5.3-codex".to_string(),
+                "Be terse.".to_string()
+            )]))
+        );
+        assert!(
+            config
+                .config_set("models.prompts", ConfigValue::String("x".to_string()))
+                .is_err()
+        );
+
+        // `{}` from Lua arrives as an empty list and clears the overrides
+        config
+            .config_set("models.prompts", ConfigValue::StringList(vec![]))
+            .unwrap();
+        assert!(config.models.system_prompt_overrides.is_empty());
+        assert!(
+            config
+                .config_set(
+                    "models.prompts",
+                    ConfigValue::StringList(vec!["x".to_string()])
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_models_aliases_override_builtin() {
+        let mut config = QuorumConfig::default();
+        config
+            .config_set("models.decision", ConfigValue::String("sonnet".to_string()))
+            .unwrap();
+        assert_eq!(config.models.decision, Model::ClaudeSonnet46);
+
+        config
+            .config_set(
+                "models.aliases",
+                ConfigValue::StringMap(BTreeMap::from([(
+                    "Sonnet".to_string(),
+                    "claude-sonnet-4.5".to_string(),
+                )])),
+            )
+            .unwrap();
+        config
+            .config_set("models.decision", ConfigValue::String("sonnet".to_string()))
+            .unwrap();
+        assert_eq!(config.models.decision, Model::ClaudeSonnet45);
+        assert_eq!(
+            config.config_get("models.aliases").unwrap(),
+            ConfigValue::StringMap(BTreeMap::from([(
+                "sonnet".to_string(),
+                "claude-sonnet-4.5".to_string()
+            )]))
+        );
+        assert!(
+            config
+                .config_set(
+                    "models.aliases",
+                    ConfigValue::StringMap(BTreeMap::from([(
+                        "fast".to_string(),
+                        "claude-haku-4.5".to_string()
+                    )])),
+                )
+                .is_err()
+        );
+    }
+
     // ==================== debate.* Tests (#325) ====================
 
     #[test]
@@ -1137,6 +1952,55 @@ mod tests {
         assert_eq!(config.policy().hil_mode, HilMode::AutoApprove);
     }
 
+    #[test]
+    fn test_config_set_hil_timeout() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.hil_timeout_secs").unwrap(),
+            ConfigValue::Integer(0)
+        );
+        config
+            .config_set("agent.hil_timeout_secs", ConfigValue::Integer(45))
+            .unwrap();
+        config
+            .config_set(
+                "agent.hil_timeout_default",
+                ConfigValue::String("approve".into()),
+            )
+            .unwrap();
+        assert_eq!(config.policy().hil_timeout_secs, Some(45));
+        assert_eq!(
+            config.policy().hil_timeout_default,
+            HilTimeoutDefault::Approve
+        );
+
+        config
+            .config_set("agent.hil_timeout_secs", ConfigValue::Integer(0))
+            .unwrap();
+        assert_eq!(config.policy().hil_timeout_secs, None);
+        assert!(
+            config
+                .config_set(
+                    "agent.hil_timeout_default",
+                    ConfigValue::String("skip".into())
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_gather_context() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.gather_context").unwrap(),
+            ConfigValue::Boolean(true)
+        );
+        config
+            .config_set("agent.gather_context", ConfigValue::Boolean(false))
+            .unwrap();
+        assert!(!config.policy().gather_context);
+    }
+
     #[test]
     fn test_config_set_max_plan_revisions() {
         let mut config = QuorumConfig::default();
@@ -1146,6 +2010,311 @@ mod tests {
         assert_eq!(config.policy().max_plan_revisions, 5);
     }
 
+    #[test]
+    fn test_config_set_max_tasks_per_plan() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.max_tasks_per_plan").unwrap(),
+            ConfigValue::Integer(0)
+        );
+        config
+            .config_set("agent.max_tasks_per_plan", ConfigValue::Integer(12))
+            .unwrap();
+        assert_eq!(config.policy().max_tasks_per_plan, Some(12));
+        config
+            .config_set("agent.max_tasks_per_plan", ConfigValue::Integer(0))
+            .unwrap();
+        assert_eq!(config.policy().max_tasks_per_plan, None);
+    }
+
+    #[test]
+    fn test_config_set_ensemble_selection() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.ensemble_selection").unwrap(),
+            ConfigValue::String("winner".to_string())
+        );
+        config
+            .config_set(
+                "agent.ensemble_selection",
+                ConfigValue::String("merge".to_string()),
+            )
+            .unwrap();
+        assert_eq!(config.policy().ensemble_selection, EnsembleSelection::Merge);
+        assert!(
+            config
+                .config_set(
+                    "agent.ensemble_selection",
+                    ConfigValue::String("vote".into())
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_on_task_failure() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.on_task_failure").unwrap(),
+            ConfigValue::String("continue_all".to_string())
+        );
+        config
+            .config_set(
+                "agent.on_task_failure",
+                ConfigValue::String("continue_independent".to_string()),
+            )
+            .unwrap();
+        assert_eq!(
+            config.policy().on_task_failure,
+            FailurePolicy::ContinueIndependent
+        );
+        assert!(
+            config
+                .config_set("agent.on_task_failure", ConfigValue::String("retry".into()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_max_final_review_fixes() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.max_final_review_fixes").unwrap(),
+            ConfigValue::Integer(1)
+        );
+        config
+            .config_set("agent.max_final_review_fixes", ConfigValue::Integer(0))
+            .unwrap();
+        assert_eq!(config.policy().max_final_review_fixes, 0);
+        assert!(
+            config
+                .config_set("agent.max_final_review_fixes", ConfigValue::Integer(-1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_max_action_retries() {
+        let mut config = QuorumConfig::default();
+        config
+            .config_set("agent.max_action_retries", ConfigValue::Integer(4))
+            .unwrap();
+        assert_eq!(config.execution().max_action_retries, 4);
+        // Independent of the plan revision budget
+        assert_eq!(config.policy().max_plan_revisions, 3);
+        assert!(
+            config
+                .config_set("agent.max_action_retries", ConfigValue::Integer(-1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_max_cost_tokens() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.max_cost_tokens").unwrap(),
+            ConfigValue::Integer(0)
+        );
+        config
+            .config_set("agent.max_cost_tokens", ConfigValue::Integer(50_000))
+            .unwrap();
+        assert_eq!(config.execution().max_cost_tokens, Some(50_000));
+        config
+            .config_set("agent.max_cost_tokens", ConfigValue::Integer(0))
+            .unwrap();
+        assert_eq!(config.execution().max_cost_tokens, None);
+    }
+
+    #[test]
+    fn test_config_set_consensus_mode() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.consensus_mode").unwrap(),
+            ConfigValue::String("binary".to_string())
+        );
+        config
+            .config_set(
+                "agent.consensus_mode",
+                ConfigValue::String("score".to_string()),
+            )
+            .unwrap();
+        assert_eq!(
+            config.policy().consensus_mode,
+            ConsensusMode::ScoreThreshold(6.0)
+        );
+        config
+            .config_set(
+                "agent.consensus_mode",
+                ConfigValue::String("score:7.5".to_string()),
+            )
+            .unwrap();
+        assert_eq!(
+            config.config_get("agent.consensus_mode").unwrap(),
+            ConfigValue::String("score:7.5".to_string())
+        );
+        assert!(matches!(
+            config.config_set(
+                "agent.consensus_mode",
+                ConfigValue::String("weighted".to_string())
+            ),
+            Err(ConfigAccessError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_config_set_quorum_rule() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.quorum_rule").unwrap(),
+            ConfigValue::String("majority".to_string())
+        );
+        config
+            .config_set(
+                "agent.quorum_rule",
+                ConfigValue::String("supermajority:3/4".to_string()),
+            )
+            .unwrap();
+        assert_eq!(config.policy().quorum_rule, QuorumRule::SuperMajority(3, 4));
+        assert_eq!(
+            config.config_get("agent.quorum_rule").unwrap(),
+            ConfigValue::String("supermajority:3/4".to_string())
+        );
+        let result = config.config_set(
+            "agent.quorum_rule",
+            ConfigValue::String("plurality".to_string()),
+        );
+        assert!(matches!(
+            result,
+            Err(ConfigAccessError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_config_set_min_reviewers_high_risk() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.min_reviewers_high_risk").unwrap(),
+            ConfigValue::Integer(0)
+        );
+        config
+            .config_set("agent.min_reviewers_high_risk", ConfigValue::Integer(2))
+            .unwrap();
+        assert_eq!(config.policy().min_reviewers(RiskLevel::High), 2);
+        assert!(
+            config
+                .config_set("agent.min_reviewers_high_risk", ConfigValue::Integer(-1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_tools_risk_overrides() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("tools.risk_overrides").unwrap(),
+            ConfigValue::StringMap(BTreeMap::new())
+        );
+        config
+            .config_set(
+                "tools.risk_overrides",
+                ConfigValue::StringMap(BTreeMap::from([
+                    ("make".to_string(), "low".to_string()),
+                    ("git log".to_string(), "HIGH".to_string()),
+                ])),
+            )
+            .unwrap();
+        assert_eq!(config.policy().command_risk("make build"), RiskLevel::Low);
+        assert_eq!(config.policy().command_risk("git log -1"), RiskLevel::High);
+        assert_eq!(
+            config.config_get("tools.risk_overrides").unwrap(),
+            ConfigValue::StringMap(BTreeMap::from([
+                ("git log".to_string(), "high".to_string()),
+                ("make".to_string(), "low".to_string()),
+            ]))
+        );
+        assert!(matches!(
+            config.config_set(
+                "tools.risk_overrides",
+                ConfigValue::StringMap(BTreeMap::from([(
+                    "make".to_string(),
+                    "medium".to_string()
+                )])),
+            ),
+            Err(ConfigAccessError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_config_set_tool_timeouts() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("tools.default_timeout_secs").unwrap(),
+            ConfigValue::Integer(60)
+        );
+        config
+            .config_set("tools.default_timeout_secs", ConfigValue::Integer(20))
+            .unwrap();
+        config
+            .config_set(
+                "tools.timeouts",
+                ConfigValue::StringMap(BTreeMap::from([(
+                    "run_command".to_string(),
+                    "300".to_string(),
+                )])),
+            )
+            .unwrap();
+        assert_eq!(config.tool_timeouts().secs_for("run_command"), 300);
+        assert_eq!(config.tool_timeouts().secs_for("grep_search"), 20);
+        assert_eq!(
+            config.config_get("tools.timeouts").unwrap(),
+            ConfigValue::StringMap(BTreeMap::from([(
+                "run_command".to_string(),
+                "300".to_string()
+            )]))
+        );
+        assert!(
+            config
+                .config_set("tools.default_timeout_secs", ConfigValue::Integer(0))
+                .is_err()
+        );
+        assert!(
+            config
+                .config_set(
+                    "tools.timeouts",
+                    ConfigValue::StringMap(BTreeMap::from([(
+                        "read_file".to_string(),
+                        "soon".to_string(),
+                    )])),
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_command_lists() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("agent.command_denylist").unwrap(),
+            ConfigValue::StringList(vec![])
+        );
+        config
+            .config_set(
+                "agent.command_denylist",
+                ConfigValue::StringList(vec!["rm -rf *".to_string()]),
+            )
+            .unwrap();
+        config
+            .config_set(
+                "agent.command_allowlist",
+                ConfigValue::StringList(vec!["cargo *".to_string()]),
+            )
+            .unwrap();
+        assert_eq!(config.policy().command_denylist, vec!["rm -rf *"]);
+        assert!(config.policy().check_command("cargo build").is_ok());
+        assert!(config.policy().check_command("make").is_err());
+    }
+
     #[test]
     fn test_config_set_model_exploration() {
         let mut config = QuorumConfig::default();
@@ -1158,6 +2327,28 @@ mod tests {
         assert_eq!(config.models().exploration, Model::ClaudeOpus45);
     }
 
+    #[test]
+    fn test_config_set_model_rejects_near_miss_name() {
+        let mut config = QuorumConfig::default();
+        let err = config
+            .config_set(
+                "models.decision",
+                ConfigValue::String("claude-sonet-4.5".to_string()),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("claude-sonnet-4.5"));
+        assert_eq!(config.models().decision, ModelConfig::default().decision);
+
+        // Provider-style dash IDs are accepted as the built-in model
+        config
+            .config_set(
+                "models.decision",
+                ConfigValue::String("claude-sonnet-4-5".to_string()),
+            )
+            .unwrap();
+        assert_eq!(config.models().decision, Model::ClaudeSonnet45);
+    }
+
     #[test]
     fn test_config_set_model_participants() {
         let mut config = QuorumConfig::default();
@@ -1176,6 +2367,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_set_model_tie_breaker() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(
+            config.config_get("models.tie_breaker").unwrap(),
+            ConfigValue::String(String::new())
+        );
+        config
+            .config_set(
+                "models.tie_breaker",
+                ConfigValue::String("claude-opus-4.5".to_string()),
+            )
+            .unwrap();
+        assert_eq!(config.models().tie_breaker, Some(Model::ClaudeOpus45));
+        assert_eq!(
+            config.config_get("models.tie_breaker").unwrap(),
+            ConfigValue::String("claude-opus-4.5".to_string())
+        );
+        config
+            .config_set("models.tie_breaker", ConfigValue::String(String::new()))
+            .unwrap();
+        assert_eq!(config.models().tie_breaker, None);
+    }
+
     #[test]
     fn test_config_set_model_moderator() {
         let mut config = QuorumConfig::default();
@@ -1213,6 +2428,28 @@ mod tests {
         assert_eq!(config.execution().max_tool_turns, 20);
     }
 
+    #[test]
+    fn test_config_ask_max_tool_turns_defaults_to_max_tool_turns() {
+        let mut config = QuorumConfig::default();
+        config
+            .config_set("execution.max_tool_turns", ConfigValue::Integer(20))
+            .unwrap();
+        assert_eq!(
+            config.config_get("execution.ask_max_tool_turns").unwrap(),
+            ConfigValue::Integer(20)
+        );
+
+        config
+            .config_set("execution.ask_max_tool_turns", ConfigValue::Integer(3))
+            .unwrap();
+        assert_eq!(config.execution().ask_max_tool_turns(), 3);
+        assert!(
+            config
+                .config_set("execution.ask_max_tool_turns", ConfigValue::Integer(-1))
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_config_set_output_format() {
         let mut config = QuorumConfig::default();
@@ -1241,6 +2478,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_set_redact_patterns() {
+        let mut config = QuorumConfig::default();
+        assert!(config.redact_patterns().is_empty());
+        config
+            .config_set(
+                "logging.redact_patterns",
+                ConfigValue::StringList(vec!["corp-[0-9]{6}".to_string()]),
+            )
+            .unwrap();
+        assert_eq!(config.redact_patterns(), ["corp-[0-9]{6}"]);
+        assert_eq!(
+            config.config_get("logging.redact_patterns").unwrap(),
+            ConfigValue::StringList(vec!["corp-[0-9]{6}".to_string()])
+        );
+    }
+
     #[test]
     fn test_config_set_supervisor_reporter() {
         let mut config = QuorumConfig::default();
@@ -1309,6 +2563,36 @@ mod tests {
         assert_eq!(config.execution().context_budget.recent_full_count(), 5);
     }
 
+    #[test]
+    fn test_config_set_per_tool_output_chars() {
+        let mut config = QuorumConfig::default();
+        config
+            .config_set(
+                "context_budget.per_tool_output_chars",
+                ConfigValue::Integer(2_000),
+            )
+            .unwrap();
+        // Changing another budget knob keeps the tool output limit
+        config
+            .config_set("context_budget.recent_full_count", ConfigValue::Integer(2))
+            .unwrap();
+        assert_eq!(
+            config
+                .config_get("context_budget.per_tool_output_chars")
+                .unwrap(),
+            ConfigValue::Integer(2_000)
+        );
+
+        assert!(
+            config
+                .config_set(
+                    "context_budget.per_tool_output_chars",
+                    ConfigValue::Integer(0)
+                )
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_config_set_context_budget_validation() {
         let mut config = QuorumConfig::default();
@@ -1322,6 +2606,50 @@ mod tests {
         assert!(matches!(err, ConfigAccessError::InvalidValue { .. }));
     }
 
+    #[test]
+    fn test_config_set_reference_cache_ttl() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(config.reference_cache_ttl().as_secs(), 300);
+        config
+            .config_set("context.reference_cache_ttl_secs", ConfigValue::Integer(0))
+            .unwrap();
+        assert!(config.reference_cache_ttl().is_zero());
+        assert_eq!(
+            config
+                .config_get("context.reference_cache_ttl_secs")
+                .unwrap(),
+            ConfigValue::Integer(0)
+        );
+        assert!(
+            config
+                .config_set("context.reference_cache_ttl_secs", ConfigValue::Integer(-1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_config_set_context_limits() {
+        let mut config = QuorumConfig::default();
+        config
+            .config_set("context.max_file_bytes", ConfigValue::Integer(8_000))
+            .unwrap();
+        config
+            .config_set("context.max_total_bytes", ConfigValue::Integer(20_000))
+            .unwrap();
+        assert_eq!(config.context_limits().max_file_bytes(), 8_000);
+        assert_eq!(
+            config.config_get("context.max_total_bytes").unwrap(),
+            ConfigValue::Integer(20_000)
+        );
+
+        // Total below the per-file cap is rejected
+        let result = config.config_set("context.max_total_bytes", ConfigValue::Integer(1_000));
+        assert!(matches!(
+            result,
+            Err(ConfigAccessError::InvalidValue { .. })
+        ));
+    }
+
     #[test]
     fn test_config_set_negative_int_rejected() {
         let mut config = QuorumConfig::default();
@@ -1374,10 +2702,10 @@ mod tests {
     }
 
     #[test]
-    fn test_config_keys_returns_all_34() {
+    fn test_config_keys_returns_all_71() {
         let config = QuorumConfig::default();
         let keys = config.config_keys();
-        assert_eq!(keys.len(), 34);
+        assert_eq!(keys.len(), 71);
         // Spot-check existing keys
         assert!(keys.contains(&"models.participants".to_string()));
         assert!(keys.contains(&"output.format".to_string()));
@@ -1388,6 +2716,7 @@ mod tests {
         assert!(keys.contains(&"tui.input.max_height".to_string()));
         assert!(keys.contains(&"tui.layout.preset".to_string()));
         assert!(keys.contains(&"tui.layout.flex_threshold".to_string()));
+        assert!(keys.contains(&"tui.layout.discuss_preset".to_string()));
         // Spot-check supervisor key
         assert!(keys.contains(&"supervisor.reporter".to_string()));
         // Spot-check debate keys (#325)
@@ -1560,6 +2889,27 @@ mod tests {
         assert!(matches!(err, ConfigAccessError::InvalidValue { .. }));
     }
 
+    #[test]
+    fn test_config_set_tui_layout_discuss_preset() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(config.tui_layout_discuss_preset(), "");
+        config
+            .config_set(
+                "tui.layout.discuss_preset",
+                ConfigValue::String("wide".to_string()),
+            )
+            .unwrap();
+        assert_eq!(config.tui_layout_discuss_preset(), "wide");
+
+        let err = config
+            .config_set(
+                "tui.layout.discuss_preset",
+                ConfigValue::String("sideways".to_string()),
+            )
+            .unwrap_err();
+        assert!(matches!(err, ConfigAccessError::InvalidValue { .. }));
+    }
+
     #[test]
     fn test_config_set_tui_flex_threshold() {
         let mut config = QuorumConfig::default();
@@ -1568,4 +2918,70 @@ mod tests {
             .unwrap();
         assert_eq!(config.tui_flex_threshold(), 160);
     }
+
+    #[test]
+    fn test_config_set_tui_intent_routing() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(config.tui_intent_routing(), IntentRouting::Suggest);
+        config
+            .config_set(
+                "tui.intent_routing",
+                ConfigValue::String("auto".to_string()),
+            )
+            .unwrap();
+        assert_eq!(config.tui_intent_routing(), IntentRouting::Auto);
+        let err = config
+            .config_set(
+                "tui.intent_routing",
+                ConfigValue::String("always".to_string()),
+            )
+            .unwrap_err();
+        assert!(matches!(err, ConfigAccessError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn test_config_set_decision_log() {
+        let mut config = QuorumConfig::default();
+        assert!(config.decision_log_enabled());
+        config
+            .config_set("context.decision_log", ConfigValue::Boolean(false))
+            .unwrap();
+        assert!(!config.decision_log_enabled());
+        assert_eq!(
+            config.config_get("context.decision_log").unwrap(),
+            ConfigValue::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn test_config_set_tui_ascii_mode() {
+        let mut config = QuorumConfig::default();
+        assert!(!config.tui_ascii_mode());
+        config
+            .config_set("tui.ascii_mode", ConfigValue::Boolean(true))
+            .unwrap();
+        assert!(config.tui_ascii_mode());
+        assert_eq!(
+            config.config_get("tui.ascii_mode").unwrap(),
+            ConfigValue::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn test_config_set_interaction_max_nesting_depth() {
+        let mut config = QuorumConfig::default();
+        assert_eq!(config.interaction_max_depth(), DEFAULT_MAX_NESTING_DEPTH);
+        config
+            .config_set("interaction.max_nesting_depth", ConfigValue::Integer(6))
+            .unwrap();
+        assert_eq!(config.interaction_max_depth(), 6);
+        assert_eq!(
+            config.config_get("interaction.max_nesting_depth").unwrap(),
+            ConfigValue::Integer(6)
+        );
+        let err = config
+            .config_set("interaction.max_nesting_depth", ConfigValue::Integer(-1))
+            .unwrap_err();
+        assert!(matches!(err, ConfigAccessError::InvalidValue { .. }));
+    }
 }
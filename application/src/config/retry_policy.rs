@@ -0,0 +1,114 @@
+//! Retry policy — backoff for transient LLM request failures.
+//!
+//! [`RetryPolicy`] controls how many times a retryable
+//! [`GatewayError`](crate::ports::llm_gateway::GatewayError) is retried and
+//! how long to wait between attempts.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Exponential backoff policy for retryable gateway errors.
+///
+/// The wait before retry `n` (1-based) is `base_delay * 2^(n-1)` plus a
+/// random extra of up to `jitter`, so parallel sessions don't retry in
+/// lockstep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one (`1` disables retries).
+    pub max_attempts: usize,
+    /// Delay before the first retry; doubled for every later retry.
+    pub base_delay: Duration,
+    /// Upper bound of the random delay added to each backoff.
+    pub jitter: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            jitter: Duration::from_millis(250),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that sends each request exactly once.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::ZERO,
+            jitter: Duration::ZERO,
+        }
+    }
+
+    /// Whether another attempt is allowed after `attempt` (1-based) failed.
+    pub fn should_retry(&self, attempt: usize) -> bool {
+        attempt < self.max_attempts
+    }
+
+    /// Delay to wait after `attempt` (1-based) failed.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16) as u32;
+        let delay = self.base_delay.saturating_mul(1 << exponent);
+        delay.saturating_add(random_up_to(self.jitter))
+    }
+}
+
+/// A pseudo-random duration in `[0, max)`, seeded from the std hasher's
+/// per-instance random keys (good enough for spreading retries).
+fn random_up_to(max: Duration) -> Duration {
+    let max_nanos = max.as_nanos() as u64;
+    if max_nanos == 0 {
+        return Duration::ZERO;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_nanos(random % max_nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_three_attempts_with_500ms_base() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_attempts, 3);
+        assert_eq!(policy.base_delay, Duration::from_millis(500));
+        assert!(policy.should_retry(1));
+        assert!(policy.should_retry(2));
+        assert!(!policy.should_retry(3));
+    }
+
+    #[test]
+    fn test_none_never_retries() {
+        assert!(!RetryPolicy::none().should_retry(1));
+    }
+
+    #[test]
+    fn test_backoff_doubles_per_attempt() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+            jitter: Duration::ZERO,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_bound() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            jitter: Duration::from_millis(50),
+        };
+        for _ in 0..20 {
+            let delay = policy.backoff(1);
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay < Duration::from_millis(150));
+        }
+    }
+}
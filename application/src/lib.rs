@@ -11,10 +11,15 @@ pub mod use_cases;
 // Re-export commonly used types
 pub use config::ExecutionParams;
 pub use config::QuorumConfig;
+pub use config::RetryPolicy;
+pub use config::{ConfigProfiles, ProfileError, apply_profile};
 pub use ports::agent_progress::{AgentProgressNotifier, NoAgentProgress};
 pub use ports::clipboard::{ClipboardError, ClipboardPort, NoClipboard};
 pub use ports::config_accessor::{ConfigAccessError, ConfigAccessorPort, ConfigValue};
-pub use ports::conversation_logger::{ConversationEvent, ConversationLogger, NoConversationLogger};
+pub use ports::conversation_logger::{
+    ConversationEvent, ConversationLogger, ConversationRecord, NoConversationLogger,
+};
+pub use ports::decision_log::DecisionLogPort;
 pub use ports::event_publisher::{
     AppEvent, CompositeEventPublisher, ConversationLogEventPublisher, EventPublisher,
     NoEventPublisher, ScriptEventPublisher,
@@ -40,12 +45,25 @@ pub use ports::{
     tool_schema::ToolSchemaPort,
 };
 pub use status_tracker::{BlockedGuard, StatusTracker, WorkingGuard};
+pub use use_cases::about::{
+    AboutReport, BuildFeatures, ExternalClis, FeatureStatus, about_report, feature_statuses,
+};
+pub use use_cases::check_models::{
+    ModelCheckEntry, ModelCheckReport, check_models, diff_configured_models,
+};
 pub use use_cases::init_context::{
     InitContextError, InitContextInput, InitContextOutput, InitContextProgressNotifier,
     InitContextUseCase, NoInitContextProgress,
 };
-pub use use_cases::run_agent::{RunAgentError, RunAgentInput, RunAgentOutput, RunAgentUseCase};
-pub use use_cases::run_ask::{RunAskError, RunAskInput, RunAskUseCase};
+pub use use_cases::project_context::{
+    ContextSummarizer, GatewayContextSummarizer, project_context,
+};
+pub use use_cases::run_agent::{
+    EnsembleBranches, PhaseReplay, RunAgentError, RunAgentInput, RunAgentOutput, RunAgentUseCase,
+};
+pub use use_cases::run_ask::{
+    ESCALATE_TOOL, RunAskError, RunAskInput, RunAskOutput, RunAskUseCase,
+};
 // Re-export ErrorCategory from domain (was previously in run_agent)
 pub use quorum_domain::ErrorCategory;
 pub use use_cases::run_quorum::{
@@ -55,6 +73,9 @@ pub use use_cases::run_quorum::{
 pub use use_cases::run_review::{
     RunReviewError, RunReviewInput, RunReviewOutput, RunReviewUseCase,
 };
+pub use use_cases::summarize_session::{
+    SummarizeSessionError, SummarizeSessionUseCase, build_summary_prompt,
+};
 
 // Extracted use cases (Phase 1 + Phase 4)
 pub use ports::action_reviewer::{ActionReviewer, ReviewDecision};
@@ -72,5 +93,5 @@ pub use ports::ui_event::{
 
 // Agent controller
 pub use use_cases::agent_controller::{
-    AgentController, CommandAction, build_partial_context_prefix,
+    AgentController, CommandAction, CompactionOutcome, build_partial_context_prefix,
 };
@@ -19,6 +19,9 @@ pub enum ReviewDecision {
     Rejected(String),
     /// No review needed (e.g., no review models configured)
     SkipReview,
+    /// Fewer reviewers voted than the policy's reviewer floor requires
+    /// (see `AgentPolicy::min_reviewers_for_risk`); a human has to decide.
+    Escalate(String),
 }
 
 /// Port for reviewing high-risk tool calls before execution.
@@ -31,7 +31,8 @@
 //! ```
 
 use quorum_domain::{
-    AgentPhase, ErrorCategory, Model, Plan, ReviewRound, StreamContext, Task, Thought, Vote,
+    AgentPhase, ErrorCategory, Model, Plan, PlanImpact, ReviewRound, StreamContext, Task, Thought,
+    Vote,
 };
 
 /// Progress notifier for agent execution.
@@ -83,6 +84,14 @@ pub trait AgentProgressNotifier: Send + Sync {
     /// Called when an unknown tool name has been resolved to a valid tool
     fn on_tool_resolved(&self, _original_name: &str, _resolved_name: &str) {}
 
+    /// Called for each file read while gathering context (Phase 1)
+    ///
+    /// # Arguments
+    /// * `path` - Path of the file that was read
+    /// * `source` - `"known_file"` for files loaded directly in Stage 1,
+    ///   `"exploration"` for files the exploration agent read in Stage 2
+    fn on_context_file_loaded(&self, _path: &str, _source: &str) {}
+
     // ==================== Tool Execution Lifecycle Callbacks ====================
     // These track individual tool executions within a task (parent-child via task_id).
 
@@ -111,6 +120,10 @@ pub trait AgentProgressNotifier: Send + Sync {
     ) {
     }
 
+    /// Called after a completed tool execution that changed a file, with the
+    /// unified diff from its result metadata.
+    fn on_tool_execution_diff(&self, _task_id: &str, _execution_id: &str, _diff: &str) {}
+
     /// Called when a tool execution fails.
     fn on_tool_execution_failed(
         &self,
@@ -139,6 +152,13 @@ pub trait AgentProgressNotifier: Send + Sync {
     /// Used by ScriptProgressBridge to emit `PlanCreated` events.
     fn on_plan_created(&self, _plan: &Plan) {}
 
+    /// Called when the quorum plan review concludes, with the reviewed plan.
+    ///
+    /// `round` counts review rounds across revisions, starting at 1 (each
+    /// revision is a new [`Plan`], so its own `review_history` restarts).
+    /// Used by ScriptProgressBridge to emit `PlanReviewed` events.
+    fn on_plan_reviewed(&self, _plan: &Plan, _round: usize, _approved: bool, _votes: &[Vote]) {}
+
     /// Called when a plan revision is triggered after rejection
     fn on_plan_revision(&self, _revision: usize, _feedback: &str) {}
 
@@ -154,6 +174,11 @@ pub trait AgentProgressNotifier: Send + Sync {
     /// * `model_count` - Number of models participating in the vote
     fn on_quorum_start(&self, _phase: &str, _model_count: usize) {}
 
+    /// Called for each chunk of a reviewer's reasoning as it streams in,
+    /// before [`on_quorum_model_complete`](Self::on_quorum_model_complete)
+    /// reports its vote. Only plan review streams.
+    fn on_quorum_model_chunk(&self, _model: &Model, _chunk: &str) {}
+
     /// Called when a single model completes its vote
     fn on_quorum_model_complete(&self, _model: &Model, _approved: bool) {}
 
@@ -182,8 +207,15 @@ pub trait AgentProgressNotifier: Send + Sync {
 
     /// Called when execution confirmation is required before task execution.
     ///
-    /// Only triggered when `PhaseScope::Full` is active.
-    fn on_execution_confirmation_required(&self, _request: &str, _plan: &Plan) {}
+    /// Only triggered when `PhaseScope::Full` is active. `impact` is
+    /// [`Plan::impact_summary`] of `plan`.
+    fn on_execution_confirmation_required(
+        &self,
+        _request: &str,
+        _plan: &Plan,
+        _impact: &PlanImpact,
+    ) {
+    }
 
     // ==================== Ensemble Planning Callbacks ====================
 
@@ -209,12 +241,27 @@ pub trait AgentProgressNotifier: Send + Sync {
     /// * `plan_count` - Number of plans to be voted on
     fn on_ensemble_voting_start(&self, _plan_count: usize) {}
 
+    /// Called for each chunk of a model's planning output as it streams in,
+    /// before [`on_ensemble_plan_generated`](Self::on_ensemble_plan_generated).
+    ///
+    /// Models plan concurrently, so chunks from different models interleave.
+    /// The default forwards to [`on_model_stream_chunk`](Self::on_model_stream_chunk).
+    fn on_ensemble_model_chunk(&self, model: &Model, chunk: &str) {
+        self.on_model_stream_chunk(&model.to_string(), chunk);
+    }
+
     /// Called when a model fails to generate a plan during ensemble planning
     ///
     /// Called for each model that returns an error or text-only response.
     /// Useful for showing per-model failure status to the user.
     fn on_ensemble_model_failed(&self, _model: &Model, _error: &str) {}
 
+    /// Called when a model does not produce a plan within the ensemble session timeout
+    ///
+    /// The model is skipped (not retried); planning continues with the
+    /// models that did complete.
+    fn on_ensemble_model_timeout(&self, _model: &Model) {}
+
     /// Called when ensemble planning completes with the selected plan
     ///
     /// # Arguments
@@ -222,6 +269,17 @@ pub trait AgentProgressNotifier: Send + Sync {
     /// * `score` - The average score (1-10) the selected plan received
     fn on_ensemble_complete(&self, _selected_model: &Model, _score: f64) {}
 
+    /// Called when the moderator merges the top candidates into one plan
+    /// (`agent.ensemble_selection = "merge"`)
+    ///
+    /// Sent instead of [`on_ensemble_complete`](Self::on_ensemble_complete):
+    /// nobody voted on the merged plan, so there is no score to report.
+    ///
+    /// # Arguments
+    /// * `moderator` - The model that merged the plans
+    /// * `source_count` - How many candidates were merged
+    fn on_ensemble_merged(&self, _moderator: &Model, _source_count: usize) {}
+
     /// Called when ensemble planning fails and falls back to solo planning
     ///
     /// This happens when all models fail to generate plans in ensemble mode.
@@ -5,7 +5,7 @@
 
 use super::agent_progress::AgentProgressNotifier;
 use quorum_domain::{
-    AgentPhase, ErrorCategory, Model, Plan, ReviewRound, StreamContext, Task, Thought,
+    AgentPhase, ErrorCategory, Model, Plan, PlanImpact, ReviewRound, StreamContext, Task, Thought,
 };
 
 /// A progress notifier that delegates to multiple inner notifiers.
@@ -81,6 +81,10 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         delegate!(self, on_tool_resolved, original_name, resolved_name);
     }
 
+    fn on_context_file_loaded(&self, path: &str, source: &str) {
+        delegate!(self, on_context_file_loaded, path, source);
+    }
+
     fn on_tool_execution_created(
         &self,
         task_id: &str,
@@ -129,6 +133,10 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         );
     }
 
+    fn on_tool_execution_diff(&self, task_id: &str, execution_id: &str, diff: &str) {
+        delegate!(self, on_tool_execution_diff, task_id, execution_id, diff);
+    }
+
     fn on_tool_execution_failed(
         &self,
         task_id: &str,
@@ -162,6 +170,16 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         delegate!(self, on_plan_created, plan);
     }
 
+    fn on_plan_reviewed(
+        &self,
+        plan: &Plan,
+        round: usize,
+        approved: bool,
+        votes: &[quorum_domain::Vote],
+    ) {
+        delegate!(self, on_plan_reviewed, plan, round, approved, votes);
+    }
+
     fn on_plan_revision(&self, revision: usize, feedback: &str) {
         delegate!(self, on_plan_revision, revision, feedback);
     }
@@ -174,6 +192,10 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         delegate!(self, on_quorum_start, phase, model_count);
     }
 
+    fn on_quorum_model_chunk(&self, model: &Model, chunk: &str) {
+        delegate!(self, on_quorum_model_chunk, model, chunk);
+    }
+
     fn on_quorum_model_complete(&self, model: &Model, approved: bool) {
         delegate!(self, on_quorum_model_complete, model, approved);
     }
@@ -216,8 +238,14 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         );
     }
 
-    fn on_execution_confirmation_required(&self, request: &str, plan: &Plan) {
-        delegate!(self, on_execution_confirmation_required, request, plan);
+    fn on_execution_confirmation_required(&self, request: &str, plan: &Plan, impact: &PlanImpact) {
+        delegate!(
+            self,
+            on_execution_confirmation_required,
+            request,
+            plan,
+            impact
+        );
     }
 
     fn on_ensemble_start(&self, model_count: usize) {
@@ -228,6 +256,10 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         delegate!(self, on_ensemble_plan_generated, model);
     }
 
+    fn on_ensemble_model_chunk(&self, model: &Model, chunk: &str) {
+        delegate!(self, on_ensemble_model_chunk, model, chunk);
+    }
+
     fn on_ensemble_voting_start(&self, plan_count: usize) {
         delegate!(self, on_ensemble_voting_start, plan_count);
     }
@@ -236,10 +268,18 @@ impl AgentProgressNotifier for CompositeProgressNotifier<'_> {
         delegate!(self, on_ensemble_model_failed, model, error);
     }
 
+    fn on_ensemble_model_timeout(&self, model: &Model) {
+        delegate!(self, on_ensemble_model_timeout, model);
+    }
+
     fn on_ensemble_complete(&self, selected_model: &Model, score: f64) {
         delegate!(self, on_ensemble_complete, selected_model, score);
     }
 
+    fn on_ensemble_merged(&self, moderator: &Model, source_count: usize) {
+        delegate!(self, on_ensemble_merged, moderator, source_count);
+    }
+
     fn on_ensemble_fallback(&self, reason: &str) {
         delegate!(self, on_ensemble_fallback, reason);
     }
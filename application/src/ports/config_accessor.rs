@@ -4,6 +4,7 @@
 //! Used by future TUI `:config` commands and Lua `quorum.config` API.
 
 use quorum_domain::agent::validation::ConfigIssue;
+use std::collections::BTreeMap;
 
 /// A dynamically-typed configuration value.
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +13,7 @@ pub enum ConfigValue {
     Integer(i64),
     Boolean(bool),
     StringList(Vec<String>),
+    StringMap(BTreeMap<String, String>),
 }
 
 impl std::fmt::Display for ConfigValue {
@@ -23,6 +25,11 @@ impl std::fmt::Display for ConfigValue {
             ConfigValue::StringList(list) => {
                 write!(f, "[{}]", list.join(", "))
             }
+            ConfigValue::StringMap(map) => {
+                let entries: Vec<String> =
+                    map.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+                write!(f, "{{{}}}", entries.join(", "))
+            }
         }
     }
 }
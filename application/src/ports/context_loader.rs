@@ -82,9 +82,29 @@ pub trait ContextLoaderPort: Send + Sync {
     /// 3. `~/.claude/CLAUDE.md` - Global Claude config
     /// 4. `README.md` - Project readme
     /// 5. `docs/**/*.md` - Documentation files
-    /// 6. `Cargo.toml`, `package.json`, `pyproject.toml` - Build configs
+    /// 6. `Cargo.toml`, `package.json`, `pyproject.toml`, `go.mod`, `pom.xml`,
+    ///    `build.gradle`, `Gemfile`, `composer.json` - Build configs
     fn load_known_files(&self, project_root: &Path) -> Vec<LoadedContextFile>;
 
+    /// Loads known context files like [`load_known_files`](Self::load_known_files),
+    /// calling `on_loaded` as each file becomes available so callers can
+    /// report progress while loading is still under way.
+    ///
+    /// The default implementation loads everything first and then reports
+    /// each file that was not skipped by the size limits. Implementors that
+    /// read files one by one should override it.
+    fn load_known_files_with_progress(
+        &self,
+        project_root: &Path,
+        on_loaded: &mut dyn FnMut(&LoadedContextFile),
+    ) -> Vec<LoadedContextFile> {
+        let files = self.load_known_files(project_root);
+        for file in files.iter().filter(|f| !f.is_skipped()) {
+            on_loaded(file);
+        }
+        files
+    }
+
     /// Checks if the quorum context file exists.
     ///
     /// The quorum context file (`.quorum/context.md`) is generated by
@@ -7,6 +7,7 @@
 //! human-readable diagnostic messages, while this port captures the full
 //! conversation transcript in a machine-readable format (JSONL).
 
+use quorum_domain::AgentPhase;
 use serde_json::Value;
 
 /// A structured conversation event for logging.
@@ -28,6 +29,20 @@ impl ConversationEvent {
             payload,
         }
     }
+
+    /// `phase_timing` event: wall-clock time spent in one span of `phase`.
+    ///
+    /// Emitted at every phase boundary, so a phase entered more than once
+    /// (plan revisions, corrective execution) produces one event per span.
+    pub fn phase_timing(phase: &AgentPhase, duration_ms: u64) -> Self {
+        Self::new(
+            "phase_timing",
+            serde_json::json!({
+                "phase": phase.as_str(),
+                "duration_ms": duration_ms,
+            }),
+        )
+    }
 }
 
 /// Port for logging conversation events to a structured log.
@@ -46,3 +61,24 @@ pub struct NoConversationLogger;
 impl ConversationLogger for NoConversationLogger {
     fn log(&self, _event: ConversationEvent) {}
 }
+
+/// A conversation event read back from a log (see `--replay`).
+///
+/// Owned counterpart of [`ConversationEvent`]: `payload` holds the event's
+/// fields without the `type` and `timestamp` the logger adds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversationRecord {
+    pub event_type: String,
+    pub timestamp: Option<String>,
+    pub payload: Value,
+}
+
+impl From<ConversationEvent> for ConversationRecord {
+    fn from(event: ConversationEvent) -> Self {
+        Self {
+            event_type: event.event_type.to_string(),
+            timestamp: None,
+            payload: event.payload,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Decision log port — append-only store of approved plans.
+//!
+//! Each approved plan is appended as a [`DecisionRecord`]; later runs read
+//! the most recent records back as prior decisions for planning. The
+//! infrastructure adapter is `DecisionLog` (`.quorum/decisions.jsonl`).
+
+use quorum_domain::DecisionRecord;
+
+/// Port for the cross-session decision log.
+pub trait DecisionLogPort: Send + Sync {
+    /// Append a record to the end of the log.
+    fn append(&self, record: &DecisionRecord) -> std::io::Result<()>;
+
+    /// The last `n` records, oldest first.
+    ///
+    /// A missing log yields an empty list; unreadable entries are skipped.
+    fn recent(&self, n: usize) -> Vec<DecisionRecord>;
+}
@@ -33,7 +33,7 @@
 //! For interactive use, see `InteractiveHumanIntervention` in the presentation layer.
 
 use async_trait::async_trait;
-use quorum_domain::{HumanDecision, Plan, ReviewRound};
+use quorum_domain::{HumanDecision, Plan, QueuedAction, ReviewRound, Task};
 
 /// Error type for human intervention operations.
 ///
@@ -47,6 +47,12 @@ pub enum HumanInterventionError {
     IoError(String),
     /// Invalid user input (e.g., unrecognized command).
     InvalidInput(String),
+    /// No answer within `agent.hil_timeout_secs`.
+    ///
+    /// The agent takes `agent.hil_timeout_default` instead of failing.
+    /// `answered` holds the decisions already given before the deadline, in
+    /// order (batch review); the default applies only to the rest.
+    TimedOut { answered: Vec<HumanDecision> },
 }
 
 impl std::fmt::Display for HumanInterventionError {
@@ -55,6 +61,9 @@ impl std::fmt::Display for HumanInterventionError {
             HumanInterventionError::Cancelled => write!(f, "Operation cancelled"),
             HumanInterventionError::IoError(msg) => write!(f, "I/O error: {}", msg),
             HumanInterventionError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+            HumanInterventionError::TimedOut { .. } => {
+                write!(f, "Timed out waiting for a decision")
+            }
         }
     }
 }
@@ -75,6 +84,15 @@ impl std::error::Error for HumanInterventionError {}
 /// - **Auto-reject**: [`AutoRejectIntervention`]
 /// - **Auto-approve**: [`AutoApproveIntervention`]
 ///
+/// # Timeouts
+///
+/// Interactive implementations built with `agent.hil_timeout_secs` return
+/// [`HumanInterventionError::TimedOut`] when the user does not answer in
+/// time. The agent then takes `agent.hil_timeout_default`; for
+/// [`request_batch_approval`](Self::request_batch_approval) it applies to
+/// every action not yet answered, which implementations report in
+/// `TimedOut::answered`.
+///
 /// # Example
 ///
 /// ```ignore
@@ -113,7 +131,7 @@ pub trait HumanInterventionPort: Send + Sync {
     ///
     /// * `Ok(HumanDecision::Approve)` - Execute the current plan
     /// * `Ok(HumanDecision::Reject)` - Abort the agent
-    /// * `Ok(HumanDecision::Edit(plan))` - Use an edited plan (future)
+    /// * `Ok(HumanDecision::Edit(plan))` - Re-review the human-edited plan
     /// * `Err(HumanInterventionError)` - Error during intervention
     async fn request_intervention(
         &self,
@@ -181,6 +199,48 @@ pub trait HumanInterventionPort: Send + Sync {
     ) -> Result<HumanDecision, HumanInterventionError> {
         Ok(HumanDecision::Reject)
     }
+
+    /// Request a human decision on a high-risk tool call that was reviewed
+    /// by fewer reviewers than `AgentPolicy::min_reviewers_for_risk` requires.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task that triggered the tool call
+    /// * `tool_call_json` - JSON representation of the tool call
+    /// * `reason` - Why the review quorum was too thin
+    ///
+    /// # Default
+    ///
+    /// Defaults to `Reject` (fail-secure): an action that could not get
+    /// enough reviewers must not run unless a human says so.
+    async fn request_action_approval(
+        &self,
+        _task: &Task,
+        _tool_call_json: &str,
+        _reason: &str,
+    ) -> Result<HumanDecision, HumanInterventionError> {
+        Ok(HumanDecision::Reject)
+    }
+
+    /// Request a decision on each high-risk action queued under
+    /// `HilMode::BatchReview`, once every task has run.
+    ///
+    /// Returns one decision per action, in the order of `actions`; only
+    /// `Approve` runs the action. A missing decision counts as `Reject`.
+    /// Rejecting an action also skips the later actions that depend on it
+    /// (see [`quorum_domain::ActionBatch`]).
+    ///
+    /// # Default
+    ///
+    /// Defaults to rejecting every action (fail-secure), like
+    /// [`request_action_approval`](Self::request_action_approval).
+    async fn request_batch_approval(
+        &self,
+        _request: &str,
+        actions: &[QueuedAction],
+    ) -> Result<Vec<HumanDecision>, HumanInterventionError> {
+        Ok(vec![HumanDecision::Reject; actions.len()])
+    }
 }
 
 /// Auto-reject implementation for `HilMode::AutoReject`.
@@ -243,6 +303,27 @@ impl HumanInterventionPort for AutoApproveIntervention {
     ) -> Result<HumanDecision, HumanInterventionError> {
         Ok(HumanDecision::Approve)
     }
+
+    /// # Warning
+    ///
+    /// **Use with caution!** This runs a high-risk action that too few
+    /// reviewers looked at.
+    async fn request_action_approval(
+        &self,
+        _task: &Task,
+        _tool_call_json: &str,
+        _reason: &str,
+    ) -> Result<HumanDecision, HumanInterventionError> {
+        Ok(HumanDecision::Approve)
+    }
+
+    async fn request_batch_approval(
+        &self,
+        _request: &str,
+        actions: &[QueuedAction],
+    ) -> Result<Vec<HumanDecision>, HumanInterventionError> {
+        Ok(vec![HumanDecision::Approve; actions.len()])
+    }
 }
 
 #[cfg(test)]
@@ -331,4 +412,37 @@ mod tests {
             .unwrap();
         assert!(matches!(result, HumanDecision::Approve));
     }
+
+    #[tokio::test]
+    async fn test_action_approval_defaults_to_reject() {
+        let task = Task::new("1", "Clean build");
+        let result = AutoRejectIntervention
+            .request_action_approval(&task, "{}", "too few reviewers")
+            .await
+            .unwrap();
+        assert!(matches!(result, HumanDecision::Reject));
+    }
+
+    #[tokio::test]
+    async fn test_auto_approve_action_approval_returns_approve() {
+        let task = Task::new("1", "Clean build");
+        let result = AutoApproveIntervention
+            .request_action_approval(&task, "{}", "too few reviewers")
+            .await
+            .unwrap();
+        assert!(matches!(result, HumanDecision::Approve));
+    }
+
+    #[tokio::test]
+    async fn test_batch_approval_defaults_to_reject_all() {
+        let mut batch = quorum_domain::ActionBatch::new();
+        batch.push("1".into(), quorum_domain::ToolCall::new("write_file"));
+        batch.push("2".into(), quorum_domain::ToolCall::new("run_command"));
+        let decisions = AutoRejectIntervention
+            .request_batch_approval("req", batch.actions())
+            .await
+            .unwrap();
+        assert_eq!(decisions.len(), 2);
+        assert!(decisions.iter().all(|d| matches!(d, HumanDecision::Reject)));
+    }
 }
@@ -6,6 +6,7 @@ use async_trait::async_trait;
 use quorum_domain::session::response::LlmResponse;
 use quorum_domain::{Model, StreamEvent};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::mpsc;
 
@@ -36,16 +37,53 @@ pub enum GatewayError {
     #[error("Request failed: {0}")]
     RequestFailed(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// The backend throttled the request; `retry_after` is the wait it asked
+    /// for, when it said.
+    #[error("Rate limited (retry after: {retry_after:?})")]
+    RateLimited { retry_after: Option<Duration> },
+
     #[error("Timeout")]
     Timeout,
 
     #[error("Transport closed")]
     TransportClosed,
 
+    #[error("Operation cancelled")]
+    Cancelled,
+
     #[error("Other error: {0}")]
     Other(String),
 }
 
+impl GatewayError {
+    /// Whether the failure is transient and the request may be retried
+    /// (see [`RetryPolicy`](crate::config::RetryPolicy)).
+    ///
+    /// Transient kinds qualify: [`RequestFailed`](Self::RequestFailed)
+    /// (unclassified), [`RateLimited`](Self::RateLimited) and
+    /// [`Timeout`](Self::Timeout). Auth failures, missing models, closed
+    /// transports and cancellation won't fix themselves.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            GatewayError::RequestFailed(_)
+                | GatewayError::RateLimited { .. }
+                | GatewayError::Timeout
+        )
+    }
+
+    /// Minimum wait the backend asked for before retrying, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            GatewayError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
 /// Gateway for LLM communication
 ///
 /// This port defines how the application layer communicates with LLM providers.
@@ -3,6 +3,7 @@
 //! Defines the interface for reporting progress during Quorum execution.
 
 use quorum_domain::{Model, Phase, StreamContext};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Callback for progress updates during Quorum execution
 ///
@@ -28,6 +29,20 @@ pub trait ProgressNotifier: Send + Sync {
 
     /// Called when a model finishes streaming.
     fn on_model_stream_end(&self, _model: &str) {}
+
+    /// Called for each chunk of the moderator's synthesis as it streams in,
+    /// in addition to `on_model_stream_chunk` — lets a UI grow the final
+    /// answer in place instead of waiting for the complete result.
+    fn on_synthesis_chunk(&self, _chunk: &str) {}
+
+    /// Called once the synthesis stream ends (successfully or not).
+    fn on_synthesis_end(&self) {}
+
+    // ==================== Debate Callbacks ====================
+
+    /// Called when every debate participant's position held steady in
+    /// `round`, so the debate settles at this round instead of `max_rounds`.
+    fn on_debate_converged(&self, _round: usize) {}
 }
 
 /// No-op progress notifier for when progress reporting is not needed
@@ -44,13 +59,27 @@ impl ProgressNotifier for NoProgress {
 /// Maps Quorum Discussion phase events to the existing quorum progress
 /// callbacks on `AgentProgressNotifier`, enabling TUI progress display
 /// during `:discuss` commands.
+///
+/// The moderator's synthesis chunks are also forwarded as
+/// `on_llm_chunk`/`on_llm_stream_end`, so the synthesis streams into the
+/// conversation itself; [`streamed_synthesis`](Self::streamed_synthesis)
+/// tells the caller the final answer is already on screen.
 pub struct QuorumProgressAdapter<'a> {
     inner: &'a dyn super::agent_progress::AgentProgressNotifier,
+    streamed_synthesis: AtomicBool,
 }
 
 impl<'a> QuorumProgressAdapter<'a> {
     pub fn new(inner: &'a dyn super::agent_progress::AgentProgressNotifier) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            streamed_synthesis: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether any synthesis chunk was streamed through this adapter.
+    pub fn streamed_synthesis(&self) -> bool {
+        self.streamed_synthesis.load(Ordering::Relaxed)
     }
 }
 
@@ -79,4 +108,17 @@ impl ProgressNotifier for QuorumProgressAdapter<'_> {
     fn on_model_stream_end(&self, model: &str) {
         self.inner.on_model_stream_end(model);
     }
+
+    fn on_synthesis_chunk(&self, chunk: &str) {
+        if !self.streamed_synthesis.swap(true, Ordering::Relaxed) {
+            self.inner.on_llm_stream_start("Quorum synthesis");
+        }
+        self.inner.on_llm_chunk(chunk);
+    }
+
+    fn on_synthesis_end(&self) {
+        if self.streamed_synthesis() {
+            self.inner.on_llm_stream_end();
+        }
+    }
 }
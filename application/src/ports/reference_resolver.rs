@@ -19,7 +19,7 @@ pub struct ResolvedReference {
 }
 
 /// Errors that can occur during reference resolution.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ReferenceError {
     /// The reference type is not supported (e.g., Discussions)
     Unsupported(String),
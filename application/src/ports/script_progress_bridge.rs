@@ -9,6 +9,7 @@
 //! |-------------------------------|-----------------|
 //! | `on_phase_change(phase)` | `PhaseChanged` { phase } |
 //! | `on_plan_created(plan)` | `PlanCreated` { objective, task_count } |
+//! | `on_plan_reviewed(plan, round, approved, votes)` | `PlanReviewed` { objective, task_count, approved, approve_count, reject_count, round, votes[{model, approved}] } |
 //! | `on_tool_execution_completed(...)` | `ToolCallAfter` { tool_name, success: true, ... } |
 //! | `on_tool_execution_failed(...)` | `ToolCallAfter` { tool_name, success: false, ... } |
 //!
@@ -18,7 +19,7 @@
 use super::agent_progress::AgentProgressNotifier;
 use super::scripting_engine::ScriptingEnginePort;
 use quorum_domain::scripting::{ScriptEventData, ScriptEventType, ScriptValue};
-use quorum_domain::{AgentPhase, Plan};
+use quorum_domain::{AgentPhase, Plan, Vote};
 use std::sync::Arc;
 
 /// Bridge that translates agent progress callbacks into scripting events.
@@ -46,6 +47,33 @@ impl AgentProgressNotifier for ScriptProgressBridge {
         let _ = self.engine.emit_event(ScriptEventType::PlanCreated, data);
     }
 
+    fn on_plan_reviewed(&self, plan: &Plan, round: usize, approved: bool, votes: &[Vote]) {
+        let approve_count = votes.iter().filter(|v| v.is_approve()).count();
+        let reject_count = votes.iter().filter(|v| v.is_reject()).count();
+        let data = ScriptEventData::new()
+            .with_field("objective", ScriptValue::String(plan.objective.clone()))
+            .with_field("task_count", ScriptValue::Integer(plan.tasks.len() as i64))
+            .with_field("approved", ScriptValue::Boolean(approved))
+            .with_field("approve_count", ScriptValue::Integer(approve_count as i64))
+            .with_field("reject_count", ScriptValue::Integer(reject_count as i64))
+            .with_field("round", ScriptValue::Integer(round as i64))
+            .with_field(
+                "votes",
+                ScriptValue::Array(
+                    votes
+                        .iter()
+                        .map(|v| {
+                            ScriptValue::table([
+                                ("model", ScriptValue::String(v.model.clone())),
+                                ("approved", ScriptValue::Boolean(v.is_approve())),
+                            ])
+                        })
+                        .collect(),
+                ),
+            );
+        let _ = self.engine.emit_event(ScriptEventType::PlanReviewed, data);
+    }
+
     fn on_tool_execution_completed(
         &self,
         _task_id: &str,
@@ -166,6 +194,35 @@ mod tests {
         assert!(events[0].1.contains(&"task_count".to_string()));
     }
 
+    #[test]
+    fn test_bridge_emits_plan_reviewed() {
+        let engine = Arc::new(RecordingEngine::new());
+        let bridge = ScriptProgressBridge::new(engine.clone());
+
+        let plan = Plan::new("Test objective", "reasoning");
+        let votes = vec![
+            Vote::approve("gpt", "looks good"),
+            Vote::reject("claude", "missing tests"),
+        ];
+        bridge.on_plan_reviewed(&plan, 1, false, &votes);
+
+        let events = engine.recorded_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, ScriptEventType::PlanReviewed);
+        assert_eq!(
+            events[0].1,
+            vec![
+                "approve_count",
+                "approved",
+                "objective",
+                "reject_count",
+                "round",
+                "task_count",
+                "votes"
+            ]
+        );
+    }
+
     #[test]
     fn test_bridge_emits_tool_call_after_success() {
         let engine = Arc::new(RecordingEngine::new());
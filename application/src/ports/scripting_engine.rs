@@ -7,6 +7,7 @@
 
 use quorum_domain::scripting::{ScriptEventData, ScriptEventType};
 use std::path::Path;
+use std::time::Duration;
 
 /// Custom tool definition registered via Lua scripting.
 #[derive(Debug, Clone)]
@@ -16,6 +17,9 @@ pub struct CustomToolDef {
     pub command: String,
     pub risk_level: String, // "low" or "high"
     pub parameters: Vec<CustomToolParam>,
+    /// Dispatched to a Lua `handler` function (via
+    /// [`ScriptingEnginePort::call_tool_handler`]) instead of running `command`.
+    pub handler: bool,
 }
 
 /// Parameter definition for a custom tool.
@@ -116,12 +120,37 @@ pub trait ScriptingEnginePort: Send + Sync {
         Vec::new()
     }
 
+    /// Run the Lua handler of a custom tool registered with `handler = function(args)`.
+    ///
+    /// `args_json` is the tool call's arguments as a JSON object; the handler's
+    /// return value is the tool output. A handler still running after
+    /// `timeout` is aborted with an error, so it cannot keep the scripting
+    /// runtime busy past the tool timeout.
+    fn call_tool_handler(
+        &self,
+        tool_name: &str,
+        args_json: &str,
+        timeout: Duration,
+    ) -> Result<String, ScriptError> {
+        let _ = (args_json, timeout);
+        Err(ScriptError {
+            message: format!("no Lua handler for tool: {}", tool_name),
+        })
+    }
+
     /// Retrieve provider configuration set via Lua scripting.
     ///
     /// Returns the provider config snapshot after init.lua and plugins have run.
     fn provider_config(&self) -> Option<quorum_domain::ProviderConfig> {
         None
     }
+
+    /// Retrieve config profiles defined via `quorum.config.profile(name, { ... })`.
+    ///
+    /// The profile selected with `--profile` is applied over the loaded config.
+    fn config_profiles(&self) -> crate::config::ConfigProfiles {
+        crate::config::ConfigProfiles::new()
+    }
 }
 
 /// Action bound to a custom keymap entry.
@@ -52,12 +52,15 @@ impl std::error::Error for TuiAccessError {}
 // ---------------------------------------------------------------------------
 
 /// Configuration for a custom layout preset registered from Lua.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CustomPresetConfig {
     /// Percentage split for each pane (must sum to ~100).
     pub splits: Vec<u16>,
     /// Split direction: `"horizontal"` or `"vertical"`.
     pub direction: String,
+    /// Content slot name shown in each pane (e.g. `"conversation"`), in
+    /// `splits` order. Empty keeps the default routes.
+    pub slots: Vec<String>,
 }
 
 /// Pending changes accumulated by the port for the TUI to consume.
@@ -281,6 +281,7 @@ mod tests {
         let config = CustomPresetConfig {
             splits: vec![40, 30, 30],
             direction: "horizontal".to_string(),
+            ..Default::default()
         };
         state.layout_register_preset("my_layout", config).unwrap();
 
@@ -298,6 +299,7 @@ mod tests {
         let config = CustomPresetConfig {
             splits: vec![50, 50],
             direction: "horizontal".to_string(),
+            ..Default::default()
         };
         let err = state.layout_register_preset("default", config).unwrap_err();
         assert!(matches!(err, TuiAccessError::DuplicatePreset { .. }));
@@ -309,6 +311,7 @@ mod tests {
         let config = CustomPresetConfig {
             splits: vec![50, 50],
             direction: "diagonal".to_string(),
+            ..Default::default()
         };
         let err = state.layout_register_preset("broken", config).unwrap_err();
         assert!(matches!(err, TuiAccessError::InvalidConfig { .. }));
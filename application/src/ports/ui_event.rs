@@ -6,7 +6,7 @@
 
 use quorum_domain::{
     AgentState, ConsensusLevel, InteractionForm, InteractionId, InteractionResult, Model,
-    OutputFormat, PhaseScope, Thought,
+    ModelResponse, OutputFormat, PhaseScope, SynthesisResult, Thought,
 };
 
 /// Events emitted by AgentController for presentation layer to render
@@ -56,6 +56,8 @@ pub enum UiEvent {
     QuorumResult(QuorumResultEvent),
     /// Quorum discussion failed
     QuorumError { error: String },
+    /// Most recent discussion transcript written to `path` (`/discuss --save`)
+    TranscriptSaved { path: String },
 
     // === Context Initialization ===
     /// Context initialization starting
@@ -81,6 +83,18 @@ pub enum UiEvent {
     /// Review interaction failed (no quorum reached, gateway error, etc.)
     ReviewError { error: String },
 
+    // === Session Summary ===
+    /// `:summarize` finished: TL;DR of the interaction's transcript
+    SessionSummary {
+        interaction_id: InteractionId,
+        summary: String,
+    },
+    /// `:summarize` failed (nothing to summarize, gateway error, ...)
+    SessionSummaryError {
+        interaction_id: InteractionId,
+        error: String,
+    },
+
     // === Interaction Lifecycle ===
     /// Interaction spawned (root or child)
     InteractionSpawned(InteractionSpawnedEvent),
@@ -171,6 +185,13 @@ pub struct AgentErrorEvent {
 pub struct QuorumResultEvent {
     pub formatted_output: String,
     pub output_format: OutputFormat,
+    /// The synthesis already streamed into the conversation via
+    /// `on_llm_chunk`, so presenters needn't render `formatted_output` again.
+    pub synthesis_streamed: bool,
+    /// Phase 1 answers, one per participating model.
+    pub responses: Vec<ModelResponse>,
+    /// Moderator synthesis (Phase 3).
+    pub synthesis: SynthesisResult,
 }
 
 /// Context initialization result for display
@@ -179,6 +200,9 @@ pub struct ContextInitResultEvent {
     pub path: String,
     pub content: String,
     pub contributing_models: Vec<String>,
+    /// Set when the file could not be written; the context was still
+    /// generated and is used for the next agent run.
+    pub write_error: Option<String>,
 }
 
 /// Ask interaction result for display
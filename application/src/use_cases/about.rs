@@ -0,0 +1,170 @@
+//! About report use case.
+//!
+//! Summarizes what this build can do — compiled-in features, the configured
+//! default provider, and whether the external CLIs it relies on are on
+//! `PATH` (`--about`). Answers questions like "why isn't `web_search`
+//! available?" without reading Cargo features.
+
+use quorum_domain::ProviderConfig;
+
+/// Provider used when `quorum.providers.default` is unset
+/// (mirrors the gateway's routing fallback).
+const DEFAULT_PROVIDER: &str = "copilot";
+
+/// Compile-time feature flags of the binary.
+///
+/// Filled from `cfg!(feature = ...)` at the composition root so the
+/// reporting logic stays testable without `cfg`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BuildFeatures {
+    pub web_tools: bool,
+    pub scripting: bool,
+    pub bedrock: bool,
+    pub azure: bool,
+    pub gitlab: bool,
+    pub jira: bool,
+}
+
+/// One feature flag and what is missing when it is off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureStatus {
+    /// Cargo feature name (e.g. `"web-tools"`).
+    pub name: &'static str,
+    pub enabled: bool,
+    /// User-visible consequence of the feature being disabled.
+    pub disabled_effect: &'static str,
+}
+
+/// Which external CLIs were found on `PATH`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExternalClis {
+    /// `gh` — GitHub Issue/PR reference resolution.
+    pub gh: bool,
+    /// `copilot` — the Copilot provider.
+    pub copilot: bool,
+    /// `glab` — GitLab Issue/MR reference resolution.
+    pub glab: bool,
+    /// `jira` — Jira issue reference resolution.
+    pub jira: bool,
+}
+
+/// Everything `--about` prints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AboutReport {
+    pub version: String,
+    pub features: Vec<FeatureStatus>,
+    pub default_provider: String,
+    pub clis: ExternalClis,
+}
+
+/// Report each feature flag in a fixed order with its disabled effect.
+pub fn feature_statuses(features: &BuildFeatures) -> Vec<FeatureStatus> {
+    vec![
+        FeatureStatus {
+            name: "web-tools",
+            enabled: features.web_tools,
+            disabled_effect: "web_search and web_fetch tools are unavailable",
+        },
+        FeatureStatus {
+            name: "scripting",
+            enabled: features.scripting,
+            disabled_effect: "init.lua and plugins are not loaded",
+        },
+        FeatureStatus {
+            name: "bedrock",
+            enabled: features.bedrock,
+            disabled_effect: "the bedrock provider is unavailable",
+        },
+        FeatureStatus {
+            name: "azure",
+            enabled: features.azure,
+            disabled_effect: "the azure provider is unavailable",
+        },
+        FeatureStatus {
+            name: "gitlab",
+            enabled: features.gitlab,
+            disabled_effect: "GitLab issue/MR references are not resolved",
+        },
+        FeatureStatus {
+            name: "jira",
+            enabled: features.jira,
+            disabled_effect: "Jira issue references are not resolved",
+        },
+    ]
+}
+
+/// Build the `--about` report.
+pub fn about_report(
+    version: &str,
+    features: &BuildFeatures,
+    provider_config: &ProviderConfig,
+    clis: ExternalClis,
+) -> AboutReport {
+    AboutReport {
+        version: version.to_string(),
+        features: feature_statuses(features),
+        default_provider: provider_config
+            .default
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PROVIDER.to_string()),
+        clis,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feature_statuses_reflect_build_flags() {
+        let features = BuildFeatures {
+            web_tools: false,
+            scripting: true,
+            ..Default::default()
+        };
+
+        let statuses = feature_statuses(&features);
+
+        let names: Vec<_> = statuses.iter().map(|s| s.name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "web-tools",
+                "scripting",
+                "bedrock",
+                "azure",
+                "gitlab",
+                "jira"
+            ]
+        );
+        let web = &statuses[0];
+        assert!(!web.enabled);
+        assert!(web.disabled_effect.contains("web_search"));
+        assert!(statuses[1].enabled);
+        assert!(statuses[2..].iter().all(|s| !s.enabled));
+    }
+
+    #[test]
+    fn about_report_falls_back_to_copilot_provider() {
+        let report = about_report(
+            "1.2.3",
+            &BuildFeatures::default(),
+            &ProviderConfig::default(),
+            ExternalClis::default(),
+        );
+        assert_eq!(report.default_provider, "copilot");
+
+        let config = ProviderConfig {
+            default: Some("bedrock".to_string()),
+            ..Default::default()
+        };
+        let report = about_report(
+            "1.2.3",
+            &BuildFeatures::default(),
+            &config,
+            ExternalClis::default(),
+        );
+        assert_eq!(report.default_provider, "bedrock");
+        assert_eq!(report.version, "1.2.3");
+    }
+}
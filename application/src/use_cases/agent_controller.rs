@@ -11,6 +11,7 @@ use crate::ports::context_loader::ContextLoaderPort;
 use crate::ports::conversation_logger::{
     ConversationEvent, ConversationLogger, NoConversationLogger,
 };
+use crate::ports::decision_log::DecisionLogPort;
 use crate::ports::event_publisher::{
     CompositeEventPublisher, ConversationLogEventPublisher, EventPublisher, NoEventPublisher,
     ScriptEventPublisher,
@@ -23,24 +24,31 @@ use crate::ports::ui_event::{
     ContextInitResultEvent, InteractionCompletedEvent, InteractionSpawnedEvent, QuorumResultEvent,
     UiEvent, WelcomeInfo,
 };
+use crate::use_cases::gather_context::GatherContextUseCase;
 use crate::use_cases::init_context::{
     InitContextInput, InitContextProgressNotifier, InitContextUseCase,
 };
-use crate::use_cases::run_agent::{RunAgentError, RunAgentUseCase};
-use crate::use_cases::run_ask::RunAskUseCase;
+use crate::use_cases::project_context::{GatewayContextSummarizer, project_context};
+use crate::use_cases::run_agent::{EnsembleBranches, RunAgentError, RunAgentUseCase};
+use crate::use_cases::run_ask::{RunAskOutput, RunAskUseCase};
 use crate::use_cases::run_quorum::RunQuorumUseCase;
 use crate::use_cases::run_review::{RunReviewInput, RunReviewUseCase};
+use crate::use_cases::summarize_session::{SummarizeSessionError, SummarizeSessionUseCase};
 use quorum_domain::ContextMode;
 use quorum_domain::interaction::{
     InteractionForm, InteractionId, InteractionResult, InteractionTree,
 };
+use quorum_domain::session::entities::{Message, Role};
+use quorum_domain::session::trim::{split_for_budget, trim_to_budget};
 use quorum_domain::util::truncate_str;
 use quorum_domain::{
-    AgentPhase, AgentState, ConsensusLevel, Model, OutputFormat, PhaseScope, QuorumResult,
+    AgentContext, AgentPhase, AgentState, ConsensusLevel, Model, ModelParseError, OutputFormat,
+    PhaseScope, QuorumResult,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
@@ -61,6 +69,29 @@ struct HistoryEntry {
     summary: String,
 }
 
+/// LLM summary of history trimmed by [`split_for_budget`].
+#[derive(Debug, Clone)]
+struct HistorySummary {
+    /// Number of leading history messages folded into `text` (head included).
+    covers: usize,
+    text: String,
+}
+
+/// Upper bound on one history compaction request.
+const COMPACTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How a history compaction started by
+/// [`AgentController::history_compaction`] ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompactionOutcome {
+    /// The summary now covers the trimmed history.
+    Summarized,
+    /// The model failed or timed out; the rule-based digest stays in use.
+    Failed(String),
+    /// Stopped by shutdown (root cancellation).
+    Cancelled,
+}
+
 /// Result of handling a command
 pub enum CommandAction {
     /// Continue the REPL loop
@@ -92,6 +123,11 @@ pub struct AgentController {
     verbose: bool,
     /// Conversation history for /discuss context
     conversation_history: Vec<HistoryEntry>,
+    /// Exploration-model summary of history that no longer fits the
+    /// context budget (see [`Self::history_compaction`]). Shared with the
+    /// compaction running in the background; `/clear` swaps in a fresh one
+    /// so a compaction still in flight cannot restore the old summary.
+    history_summary: Arc<Mutex<Option<HistorySummary>>>,
     /// Root cancellation token for graceful shutdown (Ctrl+C).
     /// Per-interaction tokens are derived as children of this one, so a
     /// root cancel stops every interaction while a child cancel stops just one.
@@ -99,12 +135,23 @@ pub struct AgentController {
     /// Per-interaction cancellation tokens (children of `cancellation_token`).
     /// Lets a closed tab cancel only its own agent (issue #282).
     interaction_tokens: HashMap<InteractionId, CancellationToken>,
+    /// Tokens of `:summarize` runs on interactions with no running agent,
+    /// keyed by run so each removes only its own entry when it ends.
+    /// [`Self::cancel_interaction`] cancels them with their interaction.
+    summary_tokens: Arc<Mutex<HashMap<u64, (InteractionId, CancellationToken)>>>,
+    /// Key for the next entry in `summary_tokens`.
+    next_summary_key: u64,
+    /// Per-interaction ensemble planning branches, so a single model's
+    /// plan generation can be cancelled (see [`Self::cancel_ensemble_branch`]).
+    interaction_branches: HashMap<InteractionId, EnsembleBranches>,
     /// Channel sender for UI events
     tx: mpsc::UnboundedSender<UiEvent>,
     /// Conversation logger for structured event logging
     conversation_logger: Arc<dyn ConversationLogger>,
     /// Interaction tree for nesting management
     interaction_tree: InteractionTree,
+    /// Agent interactions created by an Ask escalation (see [`Self::prepare_escalation`]).
+    escalated_agents: HashSet<InteractionId>,
     /// Currently active interaction ID
     active_interaction_id: InteractionId,
     /// Scripting engine for Lua command dispatch
@@ -124,6 +171,17 @@ pub struct AgentController {
     /// built for `/discuss` (both inline and `SpawnContext`) so debate
     /// escalation checkpoints can prompt the user (issue #316).
     human_intervention: Arc<dyn HumanInterventionPort>,
+    /// Result of the most recent `/discuss`, written out by
+    /// `/discuss --save <path>`. Shared into `SpawnContext`, which fills it.
+    last_discussion: Arc<Mutex<Option<QuorumResult>>>,
+    /// Context gathered by each Agent interaction's latest run. Shared into
+    /// `SpawnContext`, which fills it; read when spawning children that
+    /// inherit context (see [`Self::inherited_agent_context`]).
+    agent_contexts: Arc<Mutex<HashMap<InteractionId, AgentContext>>>,
+    /// Context summarized by the last `/init`, waiting to seed the next
+    /// Agent run so it does not gather context again. Shared into
+    /// `SpawnContext`, which takes it.
+    init_context: Arc<Mutex<Option<AgentContext>>>,
 }
 
 impl AgentController {
@@ -143,9 +201,16 @@ impl AgentController {
                 .with_conversation_logger(conversation_logger.clone());
         let review_use_case = RunReviewUseCase::new(gateway.clone());
 
-        let mut interaction_tree = InteractionTree::default();
-        // Agent form is the default root interaction
-        let active_interaction_id = interaction_tree.create_root(InteractionForm::Agent);
+        let (mut interaction_tree, root_context_mode) = {
+            let config = config.lock().expect("config lock poisoned");
+            (
+                InteractionTree::default().with_max_depth(config.interaction_max_depth()),
+                // Agent form is the default root interaction
+                InteractionForm::Agent.context_mode_with(config.interaction_context_modes()),
+            )
+        };
+        let active_interaction_id =
+            interaction_tree.create_root_with_context(InteractionForm::Agent, root_context_mode);
         let status_tracker = StatusTracker::new();
 
         use crate::ports::scripting_engine::NoScriptingEngine;
@@ -166,17 +231,25 @@ impl AgentController {
             moderator: None,
             verbose: false,
             conversation_history: Vec::new(),
+            history_summary: Arc::default(),
             cancellation_token: None,
             interaction_tokens: HashMap::new(),
+            summary_tokens: Arc::default(),
+            next_summary_key: 0,
+            interaction_branches: HashMap::new(),
             tx,
             conversation_logger,
             interaction_tree,
+            escalated_agents: HashSet::new(),
             active_interaction_id,
             scripting_engine: Arc::new(NoScriptingEngine),
             status_tracker,
             extra_event_subscribers: Vec::new(),
             event_publisher: Arc::new(NoEventPublisher),
             human_intervention,
+            last_discussion: Arc::new(Mutex::new(None)),
+            agent_contexts: Arc::new(Mutex::new(HashMap::new())),
+            init_context: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -312,6 +385,11 @@ impl AgentController {
         self.use_case = self.use_case.clone().with_reference_resolver(resolver);
     }
 
+    /// Set the log approved plans are recorded in and prior decisions are read from
+    pub fn set_decision_log(&mut self, log: Arc<dyn DecisionLogPort>) {
+        self.use_case = self.use_case.clone().with_decision_log(log);
+    }
+
     /// Set scripting engine for Lua command dispatch
     pub fn set_scripting_engine(&mut self, engine: Arc<dyn ScriptingEnginePort>) {
         self.scripting_engine = engine.clone();
@@ -450,9 +528,12 @@ impl AgentController {
                 }
             }
             "/discuss" | "/council" => {
-                if args.is_empty() {
+                if let Some(path) = save_flag_path(args) {
+                    self.save_discussion_transcript(path);
+                    CommandAction::Continue
+                } else if args.is_empty() {
                     let _ = self.tx.send(UiEvent::CommandError {
-                        message: "Usage: /discuss <question>".to_string(),
+                        message: "Usage: /discuss <question> | /discuss --save <path>".to_string(),
                     });
                     CommandAction::Continue
                 } else {
@@ -522,6 +603,7 @@ impl AgentController {
             }
             "/clear" => {
                 self.conversation_history.clear();
+                self.history_summary = Arc::default();
                 let _ = self.tx.send(UiEvent::HistoryCleared);
                 CommandAction::Continue
             }
@@ -657,20 +739,165 @@ impl AgentController {
         }
     }
 
-    /// Build context string from conversation history
+    /// Conversation history as user/assistant message pairs.
+    fn history_messages(&self) -> Vec<Message> {
+        self.conversation_history
+            .iter()
+            .flat_map(|entry| {
+                [
+                    Message::user(entry.request.clone()),
+                    Message::assistant(entry.summary.clone()),
+                ]
+            })
+            .collect()
+    }
+
+    /// History trimmed to the configured context budget.
+    ///
+    /// Uses the exploration-model summary from [`Self::history_compaction`]
+    /// when it covers exactly the trimmed span, otherwise the rule-based
+    /// digest from [`trim_to_budget`].
+    fn trimmed_history(&self) -> Vec<Message> {
+        let messages = self.history_messages();
+        let budget = self.config().execution().context_budget.clone();
+        match split_for_budget(&messages, &budget) {
+            Some(split) => {
+                let covers = split.head.len() + split.dropped.len();
+                match &*self.history_summary.lock().unwrap() {
+                    Some(summary) if summary.covers == covers => split.into_messages(&summary.text),
+                    _ => trim_to_budget(&messages, &budget),
+                }
+            }
+            None => messages,
+        }
+    }
+
+    /// Summarize history that no longer fits the context budget with the
+    /// exploration model, so the next request carries a real summary
+    /// instead of the rule-based digest.
+    ///
+    /// Incremental: an earlier summary is extended with only the newly
+    /// trimmed messages. On failure the digest fallback stays in place.
+    pub async fn compact_history(&mut self) {
+        if let Some(compaction) = self.history_compaction() {
+            compaction.await;
+        }
+    }
+
+    /// The work of [`Self::compact_history`] as a future for the caller to
+    /// spawn, or `None` when the history fits the budget or is already
+    /// summarized.
+    ///
+    /// The future stores the summary itself, so the controller keeps
+    /// handling commands while the model works. It gives up after
+    /// [`COMPACTION_TIMEOUT`] and stops on shutdown (a child of the root
+    /// cancellation token).
+    pub fn history_compaction(
+        &mut self,
+    ) -> Option<impl Future<Output = CompactionOutcome> + Send + 'static> {
+        let messages = self.history_messages();
+        let budget = self.config().execution().context_budget.clone();
+        let split = split_for_budget(&messages, &budget)?;
+        let covers = split.head.len() + split.dropped.len();
+        let previous = self.history_summary.lock().unwrap().clone();
+        let (previous, new_messages) = match previous {
+            Some(summary) if summary.covers == covers => return None,
+            Some(summary) if summary.covers > split.head.len() && summary.covers < covers => {
+                (Some(summary.text), &messages[summary.covers..covers])
+            }
+            _ => (None, &split.dropped[..]),
+        };
+
+        let mut prompt = String::from(
+            "Summarize the earlier part of this conversation in a few sentences. \
+             Keep decisions, findings and open questions; drop pleasantries.\n\n",
+        );
+        if let Some(previous) = &previous {
+            prompt.push_str(&format!("## Summary so far\n{}\n\n", previous));
+        }
+        prompt.push_str("## Messages\n");
+        for message in new_messages {
+            let role = if message.role == Role::User {
+                "User"
+            } else {
+                "Assistant"
+            };
+            prompt.push_str(&format!(
+                "**{}**: {}\n",
+                role,
+                truncate_str(&message.content, budget.max_entry_bytes())
+            ));
+        }
+
+        let model = self.config().models().exploration.clone();
+        let gateway = self.gateway.clone();
+        let token = self
+            .cancellation_token
+            .as_ref()
+            .map_or_else(CancellationToken::new, |t| t.child_token());
+        let summary_slot = self.history_summary.clone();
+
+        Some(async move {
+            let request = async {
+                let session = gateway.create_session(&model).await?;
+                session.send(&prompt).await
+            };
+            let result = tokio::select! {
+                biased;
+                _ = token.cancelled() => return CompactionOutcome::Cancelled,
+                result = tokio::time::timeout(COMPACTION_TIMEOUT, request) => result,
+            };
+            let error = match result {
+                Ok(Ok(text)) if !text.trim().is_empty() => {
+                    let mut slot = summary_slot.lock().unwrap();
+                    // A compaction that finished first may already cover more
+                    if slot.as_ref().is_none_or(|s| s.covers < covers) {
+                        *slot = Some(HistorySummary {
+                            covers,
+                            text: text.trim().to_string(),
+                        });
+                    }
+                    return CompactionOutcome::Summarized;
+                }
+                Ok(Ok(_)) => "empty summary".to_string(),
+                Ok(Err(e)) => e.to_string(),
+                Err(_) => format!("timed out after {}s", COMPACTION_TIMEOUT.as_secs()),
+            };
+            tracing::warn!(
+                "History compaction with {} failed, using digest: {}",
+                model,
+                error
+            );
+            CompactionOutcome::Failed(error)
+        })
+    }
+
+    /// Build context string from conversation history, trimmed to the
+    /// context budget (the original request and the latest exchanges are
+    /// always kept).
     fn build_context_from_history(&self) -> String {
         if self.conversation_history.is_empty() {
             return String::new();
         }
 
         let mut context = String::from("## Previous Conversation Context\n\n");
-        for (i, entry) in self.conversation_history.iter().enumerate() {
-            context.push_str(&format!(
-                "### Exchange {}\n**User**: {}\n**Agent Summary**: {}\n\n",
-                i + 1,
-                entry.request,
-                entry.summary
-            ));
+        let mut exchange = 0;
+        for message in self.trimmed_history() {
+            match message.role {
+                Role::User => {
+                    exchange += 1;
+                    context.push_str(&format!(
+                        "### Exchange {}\n**User**: {}\n",
+                        exchange, message.content
+                    ));
+                }
+                Role::Assistant => {
+                    context.push_str(&format!("**Agent Summary**: {}\n\n", message.content));
+                }
+                Role::System => {
+                    context.push_str(&format!("### Earlier Exchanges\n{}\n\n", message.content));
+                }
+            }
         }
         context
     }
@@ -678,8 +905,8 @@ impl AgentController {
     /// Run Ask interaction — lightweight Q&A with read-only tool access (inline, no new tab)
     pub async fn run_ask(&mut self, question: &str, progress: &dyn AgentProgressNotifier) {
         let (clean_query, full_query) = self.prepare_inline(question);
-        let context = self.build_spawn_context();
-        let completion = context
+        let context = self.build_inline_context();
+        let mut completion = context
             .execute(
                 None,
                 InteractionForm::Ask,
@@ -689,13 +916,74 @@ impl AgentController {
                 progress,
             )
             .await;
+        let escalation = completion.escalation.take();
         self.finalize(completion);
+        if let Some(request) = escalation {
+            self.run_escalation(self.active_interaction_id, &request, progress)
+                .await;
+        }
+        self.compact_history().await;
+    }
+
+    /// Run the request an Ask escalated as an Agent child of `parent`.
+    async fn run_escalation(
+        &mut self,
+        parent: InteractionId,
+        request: &str,
+        progress: &dyn AgentProgressNotifier,
+    ) {
+        if let Ok((child_id, clean_query, full_query)) = self.prepare_escalation(parent, request) {
+            let completion = self
+                .build_spawn_context()
+                .execute(
+                    Some(child_id),
+                    InteractionForm::Agent,
+                    clean_query,
+                    full_query,
+                    None,
+                    progress,
+                )
+                .await;
+            self.finalize(completion);
+        }
+    }
+
+    /// Write the most recent discussion's transcript to `path` as Markdown
+    /// (`/discuss --save <path>`).
+    fn save_discussion_transcript(&self, path: &str) {
+        if path.is_empty() {
+            let _ = self.tx.send(UiEvent::CommandError {
+                message: "Usage: /discuss --save <path>".to_string(),
+            });
+            return;
+        }
+        let Some(markdown) = self
+            .last_discussion
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(QuorumResult::to_markdown)
+        else {
+            let _ = self.tx.send(UiEvent::CommandError {
+                message: "No discussion to save yet. Run /discuss <question> first.".to_string(),
+            });
+            return;
+        };
+        let event = match std::fs::write(path, markdown) {
+            Ok(()) => UiEvent::TranscriptSaved {
+                path: path.to_string(),
+            },
+            Err(e) => UiEvent::CommandError {
+                message: format!("Failed to save transcript to {}: {}", path, e),
+            },
+        };
+        let _ = self.tx.send(event);
     }
 
     /// Run Quorum Discussion with conversation context (inline, no new tab)
     pub async fn run_discuss(&mut self, question: &str, progress: &dyn AgentProgressNotifier) {
         let (clean_query, full_query) = self.prepare_inline(question);
-        let context = self.build_spawn_context();
+        let context = self.build_inline_context();
         let completion = context
             .execute(
                 None,
@@ -707,6 +995,7 @@ impl AgentController {
             )
             .await;
         self.finalize(completion);
+        self.compact_history().await;
     }
 
     /// Run context initialization
@@ -767,12 +1056,18 @@ impl AgentController {
 
         match result {
             Ok(output) => {
+                *self.init_context.lock().unwrap() =
+                    Some(GatherContextUseCase::context_from_project_ctx(
+                        output.project_context,
+                        Some(&working_dir),
+                    ));
                 let _ = self
                     .tx
                     .send(UiEvent::ContextInitResult(ContextInitResultEvent {
                         path: output.path,
                         content: output.content,
                         contributing_models: output.contributing_models,
+                        write_error: output.write_error,
                     }));
             }
             Err(e) => {
@@ -783,10 +1078,70 @@ impl AgentController {
         }
     }
 
+    /// Summarize an interaction's transcript (`:summarize`) with the decision
+    /// model, trimmed to the configured context budget.
+    ///
+    /// Returns a future for the caller to spawn, so the controller keeps
+    /// handling commands while the model works. The summary is cancelled
+    /// together with the interaction (its tab closes) or on shutdown, and
+    /// is reported as [`UiEvent::SessionSummary`] /
+    /// [`UiEvent::SessionSummaryError`].
+    pub fn summarize_session(
+        &mut self,
+        id: InteractionId,
+        messages: Vec<Message>,
+    ) -> impl Future<Output = ()> + Send + 'static {
+        // Reuse a running interaction's token so closing the tab stops both.
+        // Otherwise register one of our own; binding it as the interaction's
+        // token would leak it, since no completion ever comes to drop it.
+        let mut summary_key = None;
+        let token = match self.interaction_tokens.get(&id) {
+            Some(token) => Some(token.child_token()),
+            None => self.cancellation_token.as_ref().map(|root| {
+                let token = root.child_token();
+                let key = self.next_summary_key;
+                self.next_summary_key += 1;
+                self.summary_tokens
+                    .lock()
+                    .unwrap()
+                    .insert(key, (id, token.clone()));
+                summary_key = Some(key);
+                token
+            }),
+        };
+        let mut use_case = SummarizeSessionUseCase::new(self.gateway.clone());
+        if let Some(token) = token {
+            use_case = use_case.with_cancellation(token);
+        }
+        let model = self.config().models().decision.clone();
+        let budget = self.config().execution().context_budget.clone();
+        let tx = self.tx.clone();
+        let summary_tokens = self.summary_tokens.clone();
+
+        async move {
+            let result = use_case.execute(&messages, &model, &budget).await;
+            if let Some(key) = summary_key {
+                summary_tokens.lock().unwrap().remove(&key);
+            }
+            let event = match result {
+                Ok(summary) => UiEvent::SessionSummary {
+                    interaction_id: id,
+                    summary,
+                },
+                Err(SummarizeSessionError::Cancelled) => return,
+                Err(e) => UiEvent::SessionSummaryError {
+                    interaction_id: id,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(event);
+        }
+    }
+
     /// Process a user request (run agent, inline in current tab)
     pub async fn process_request(&mut self, request: &str, progress: &dyn AgentProgressNotifier) {
         let (clean_query, full_query) = self.prepare_inline(request);
-        let context = self.build_spawn_context();
+        let context = self.build_inline_context();
         let completion = context
             .execute(
                 None,
@@ -798,6 +1153,7 @@ impl AgentController {
             )
             .await;
         self.finalize(completion);
+        self.compact_history().await;
     }
 
     // =========================================================================
@@ -828,6 +1184,32 @@ impl AgentController {
         if let Some(token) = self.interaction_tokens.remove(&id) {
             token.cancel();
         }
+        self.summary_tokens
+            .lock()
+            .unwrap()
+            .retain(|_, (owner, token)| {
+                if *owner == id {
+                    token.cancel();
+                }
+                *owner != id
+            });
+    }
+
+    /// Cancel one model's plan generation in the ensemble planning running on
+    /// `id`; the other models keep going and the cancelled one is skipped.
+    /// `model` is resolved like `models.*` values (aliases included).
+    ///
+    /// Returns `Ok(false)` if that model isn't currently generating a plan there.
+    pub fn cancel_ensemble_branch(
+        &self,
+        id: InteractionId,
+        model: &str,
+    ) -> Result<bool, ModelParseError> {
+        let model = self.config().models().resolve(model)?;
+        Ok(self
+            .interaction_branches
+            .get(&id)
+            .is_some_and(|branches| branches.cancel(&model)))
     }
 
     /// Prepare context for an inline execution (no tree node).
@@ -861,20 +1243,66 @@ impl AgentController {
         form: InteractionForm,
         query: &str,
         context_mode_override: Option<ContextMode>,
+    ) -> Result<(InteractionId, String, String), String> {
+        self.prepare_spawn_under(
+            self.active_interaction_id,
+            form,
+            query,
+            context_mode_override,
+        )
+    }
+
+    /// Prepare an Agent child of `parent` for a request an Ask escalated
+    /// ([`TaskCompletion::escalation`]). Asks running under it will not
+    /// escalate again.
+    pub fn prepare_escalation(
+        &mut self,
+        parent: InteractionId,
+        request: &str,
+    ) -> Result<(InteractionId, String, String), String> {
+        let spawned = self.prepare_spawn_under(parent, InteractionForm::Agent, request, None)?;
+        self.escalated_agents.insert(spawned.0);
+        Ok(spawned)
+    }
+
+    /// Whether an Ask running on `id` may escalate: not when `id` or one of
+    /// its ancestors is an Agent created by an escalation.
+    fn escalation_allowed(&self, id: InteractionId) -> bool {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            if self.escalated_agents.contains(&id) {
+                return false;
+            }
+            current = self.interaction_tree.parent_of(id);
+        }
+        true
+    }
+
+    fn prepare_spawn_under(
+        &mut self,
+        parent: InteractionId,
+        form: InteractionForm,
+        query: &str,
+        context_mode_override: Option<ContextMode>,
     ) -> Result<(InteractionId, String, String), String> {
         let (ctx_override_flag, clean_query) = Self::parse_spawn_flags(query);
-        let ctx_override = context_mode_override.or(ctx_override_flag);
+        // Explicit override, then `--fresh`-style flag, then config
+        // (`interaction.<form>.context_mode`); the form default otherwise.
+        let ctx_override = context_mode_override.or(ctx_override_flag).or_else(|| {
+            self.config()
+                .interaction_context_modes()
+                .get(&form)
+                .copied()
+        });
 
-        // 1. Add node to InteractionTree
+        // 1. Add node to InteractionTree (nesting limit may have changed at runtime)
+        let max_depth = self.config().interaction_max_depth();
+        self.interaction_tree.set_max_depth(max_depth);
         let child_res = match ctx_override {
-            Some(mode) => self.interaction_tree.spawn_child_with_context(
-                self.active_interaction_id,
-                form,
-                mode,
-            ),
-            None => self
+            Some(mode) => self
                 .interaction_tree
-                .spawn_child(self.active_interaction_id, form),
+                .spawn_child_with_context(parent, form, mode),
+            None => self.interaction_tree.spawn_child(parent, form),
         };
 
         let child_id = match child_res {
@@ -947,7 +1375,10 @@ impl AgentController {
     ) -> (InteractionId, String, String) {
         let label = label.into();
         let material = material.into();
-        let id = self.interaction_tree.create_root(form);
+        let context_mode = form.context_mode_with(self.config().interaction_context_modes());
+        let id = self
+            .interaction_tree
+            .create_root_with_context(form, context_mode);
 
         let _ = self
             .tx
@@ -964,7 +1395,7 @@ impl AgentController {
                 "id": id.0,
                 "form": form.as_str(),
                 "parent_id": None::<usize>,
-                "context_mode": format!("{:?}", form.default_context_mode()),
+                "context_mode": format!("{:?}", context_mode),
             }),
         ));
 
@@ -985,6 +1416,14 @@ impl AgentController {
             status_tracker: self.status_tracker.clone(),
             event_publisher: self.event_publisher.clone(),
             human_intervention: self.human_intervention.clone(),
+            last_discussion: self.last_discussion.clone(),
+            child_results: Vec::new(),
+            allow_escalation: true,
+            agent_contexts: self.agent_contexts.clone(),
+            init_context: self.init_context.clone(),
+            interaction: None,
+            inherited_context: None,
+            context_mode: ContextMode::Full,
         }
     }
 
@@ -994,9 +1433,56 @@ impl AgentController {
     /// returned context, so closing the tab that owns `id` cancels just this
     /// agent (issue #282). Use this instead of [`Self::build_spawn_context`]
     /// for any execution bound to a tab.
+    ///
+    /// Also hands over the results of `id`'s children that completed since
+    /// its last request, so they reach the model on this execution.
     pub fn build_spawn_context_for(&mut self, id: InteractionId) -> SpawnContext {
         let token = self.bind_cancellation(id);
-        self.build_spawn_context().with_cancellation(token)
+        let branches = EnsembleBranches::new();
+        self.interaction_branches.insert(id, branches.clone());
+        let child_results = self.interaction_tree.take_child_results(id);
+        let mut context = self
+            .build_spawn_context()
+            .with_cancellation(token)
+            .with_child_results(child_results);
+        context.agent_use_case = context.agent_use_case.with_ensemble_branches(branches);
+        context.allow_escalation = self.escalation_allowed(id);
+        context.interaction = Some(id);
+        context.inherited_context = self.inherited_agent_context(id);
+        if let Some(interaction) = self.interaction_tree.get(id) {
+            context.context_mode = interaction.context_mode;
+        }
+        context
+    }
+
+    /// The Agent context a child interaction starts from: the one gathered
+    /// by its nearest ancestor Agent, unless the child is
+    /// [`ContextMode::Fresh`], which always starts from a clean slate.
+    fn inherited_agent_context(&self, id: InteractionId) -> Option<AgentContext> {
+        if self.interaction_tree.get(id)?.context_mode == ContextMode::Fresh {
+            return None;
+        }
+        let contexts = self.agent_contexts.lock().unwrap();
+        let mut current = self.interaction_tree.parent_of(id);
+        while let Some(ancestor) = current {
+            if let Some(context) = contexts.get(&ancestor) {
+                return Some(context.clone());
+            }
+            current = self.interaction_tree.parent_of(ancestor);
+        }
+        None
+    }
+
+    /// [`Self::build_spawn_context`] for the REPL's inline executions, which
+    /// run on the active interaction: carries over its children's results.
+    fn build_inline_context(&mut self) -> SpawnContext {
+        let child_results = self
+            .interaction_tree
+            .take_child_results(self.active_interaction_id);
+        let mut context = self.build_spawn_context().with_child_results(child_results);
+        context.allow_escalation = self.escalation_allowed(self.active_interaction_id);
+        context.interaction = Some(self.active_interaction_id);
+        context
     }
 
     /// Finalize a completed task (spawn or inline).
@@ -1009,6 +1495,7 @@ impl AgentController {
         // interaction's next request, so the map stays bounded either way.)
         if let Some(id) = completion.interaction_id {
             self.interaction_tokens.remove(&id);
+            self.interaction_branches.remove(&id);
         }
         if let Some(result) = &completion.result {
             self.conversation_history.push(HistoryEntry {
@@ -1018,11 +1505,12 @@ impl AgentController {
             });
         }
         // Spawn path: emit InteractionCompleted with a query-aware notification
-        // so the parent tab shows what the result is answering (issue #274).
+        // so the parent tab shows what the result is answering (issue #274),
+        // and queue the result for the parent's next request.
         if let Some(child_id) = completion.interaction_id
             && let Some(result) = &completion.result
         {
-            let parent_id = self.interaction_tree.get(child_id).and_then(|i| i.parent);
+            let parent_id = self.interaction_tree.record_result(child_id, result);
             let _ = self
                 .tx
                 .send(UiEvent::InteractionCompleted(InteractionCompletedEvent {
@@ -1046,8 +1534,14 @@ impl AgentController {
         if let Ok((child_id, clean_query, full_query)) =
             self.prepare_spawn(form, query, context_mode_override)
         {
-            let context = self.build_spawn_context();
-            let completion = context
+            let mut context = self.build_spawn_context();
+            context.allow_escalation = self.escalation_allowed(child_id);
+            context.interaction = Some(child_id);
+            context.inherited_context = self.inherited_agent_context(child_id);
+            if let Some(interaction) = self.interaction_tree.get(child_id) {
+                context.context_mode = interaction.context_mode;
+            }
+            let mut completion = context
                 .execute(
                     Some(child_id),
                     form,
@@ -1057,7 +1551,12 @@ impl AgentController {
                     progress,
                 )
                 .await;
+            let escalation = completion.escalation.take();
             self.finalize(completion);
+            if let Some(request) = escalation {
+                self.run_escalation(child_id, &request, progress).await;
+            }
+            self.compact_history().await;
         }
     }
 
@@ -1199,6 +1698,19 @@ fn split_bang(command: &str) -> (&str, bool) {
     }
 }
 
+/// The path given by a leading `--save <path>` or `--save=<path>` flag.
+///
+/// `"--save out.md"` → `Some("out.md")`, `"--save"` → `Some("")`,
+/// `"--saved state?"` → `None` (an ordinary question).
+fn save_flag_path(args: &str) -> Option<&str> {
+    let rest = args.strip_prefix("--save")?;
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim())
+    } else {
+        rest.strip_prefix('=').map(str::trim)
+    }
+}
+
 /// Collect unique config sections (key prefix before the last `.`) in registry order.
 ///
 /// `["agent.hil_mode", "tui.input.submit_key"]` → `["agent", "tui.input"]`
@@ -1264,6 +1776,17 @@ impl InitContextProgressNotifier for InitContextProgressBridge<'_> {
     }
 }
 
+/// Heading + one line per completed child result, or `None` when there are none.
+fn child_results_prefix(results: &[String]) -> Option<String> {
+    if results.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "## Child Interaction Results\n\n{}",
+        results.join("\n")
+    ))
+}
+
 fn format_quorum_output(result: &QuorumResult, format: OutputFormat) -> String {
     match format {
         OutputFormat::Json => serde_json::to_string_pretty(result).unwrap_or_default(),
@@ -1284,6 +1807,25 @@ pub struct SpawnContext {
     pub(crate) status_tracker: Arc<StatusTracker>,
     pub(crate) event_publisher: Arc<dyn EventPublisher>,
     pub(crate) human_intervention: Arc<dyn HumanInterventionPort>,
+    pub(crate) last_discussion: Arc<Mutex<Option<QuorumResult>>>,
+    /// Results of this interaction's completed children, injected ahead of
+    /// the query sent to the model (see [`Self::with_child_results`]).
+    pub(crate) child_results: Vec<String>,
+    /// Whether an Ask may escalate to an Agent (off under an escalated Agent).
+    pub(crate) allow_escalation: bool,
+    /// Gathered Agent contexts per interaction, filled by `execute_agent`.
+    pub(crate) agent_contexts: Arc<Mutex<HashMap<InteractionId, AgentContext>>>,
+    /// Context left by `/init`; the next Agent run without an inherited
+    /// context takes it instead of gathering.
+    pub(crate) init_context: Arc<Mutex<Option<AgentContext>>>,
+    /// The interaction this execution runs on, if any; its Agent context
+    /// is recorded under this id.
+    pub(crate) interaction: Option<InteractionId>,
+    /// Context handed down from an ancestor Agent (`None` for `Fresh`).
+    pub(crate) inherited_context: Option<AgentContext>,
+    /// How much of `inherited_context` this execution sees: an Ask in
+    /// `Projected` mode gets only the question-relevant excerpt.
+    pub(crate) context_mode: ContextMode,
 }
 
 /// Completion result of a task (spawn or inline execution)
@@ -1297,6 +1839,9 @@ pub struct TaskCompletion {
     /// (Agent form only). `None` for non-Agent forms or non-cancelled
     /// completions.
     pub cancelled_state: Option<Box<AgentState>>,
+    /// Request an Ask handed over to an Agent ([`RunAskOutput::EscalateToAgent`]);
+    /// the caller spawns it with [`AgentController::prepare_escalation`].
+    pub escalation: Option<String>,
 }
 
 impl SpawnContext {
@@ -1321,6 +1866,15 @@ impl SpawnContext {
         self
     }
 
+    /// Inject completed child interactions' results (their
+    /// [`InteractionResult::to_context_injection`] strings, in completion
+    /// order) ahead of the query sent to the model. Like `partial_context`,
+    /// they never reach [`TaskCompletion::query`].
+    pub fn with_child_results(mut self, results: Vec<String>) -> Self {
+        self.child_results = results;
+        self
+    }
+
     /// `partial_context`, when present, is a summary of a cancelled task's
     /// partial progress (built by [`build_partial_context_prefix`]) to prefix
     /// onto the query actually sent to the model for Agent-form executions
@@ -1352,8 +1906,27 @@ impl SpawnContext {
         let composite = CompositeProgressNotifier::new(vec![progress, &script_bridge]);
         let progress: &dyn AgentProgressNotifier = &composite;
 
+        let child_results = child_results_prefix(&self.child_results);
+        let full_query = match &child_results {
+            Some(prefix) => format!("{prefix}\n\n{full_query}"),
+            None => full_query,
+        };
+        let partial_context = match (child_results, partial_context) {
+            (Some(results), Some(partial)) => Some(format!("{results}\n\n{partial}")),
+            (results, partial) => results.or(partial),
+        };
+
+        let mut escalation = None;
         let (result, cancelled_state) = match form {
-            InteractionForm::Ask => (self.execute_ask(&full_query, progress).await, None),
+            InteractionForm::Ask => {
+                let full_query = match self.ask_context(&clean_query).await {
+                    Some(context) => format!("## Project Context\n\n{context}\n\n{full_query}"),
+                    None => full_query,
+                };
+                let (result, escalated) = self.execute_ask(&full_query, progress).await;
+                escalation = escalated;
+                (result, None)
+            }
             InteractionForm::Discuss => (self.execute_discuss(&full_query, progress).await, None),
             InteractionForm::Agent => {
                 self.execute_agent(&clean_query, partial_context.as_deref(), progress)
@@ -1368,35 +1941,64 @@ impl SpawnContext {
             query: clean_query,
             result,
             cancelled_state,
+            escalation,
         }
     }
 
+    /// The inherited Agent context to show an Ask, projected down to what is
+    /// relevant for `question` in [`ContextMode::Projected`].
+    async fn ask_context(&self, question: &str) -> Option<String> {
+        let inherited = self.inherited_context.as_ref()?;
+        let context = if self.context_mode == ContextMode::Projected {
+            let summarizer = GatewayContextSummarizer::new(
+                self.gateway.clone(),
+                self.config.models().exploration.clone(),
+            );
+            project_context(&summarizer, inherited, question).await
+        } else {
+            inherited.clone()
+        };
+        Some(context.to_prompt_context()).filter(|text| !text.is_empty())
+    }
+
+    /// Returns the result, plus the request when the Ask escalated to an Agent.
     async fn execute_ask(
         &self,
         query: &str,
         progress: &dyn AgentProgressNotifier,
-    ) -> Option<InteractionResult> {
+    ) -> (Option<InteractionResult>, Option<String>) {
         let _ = self.tx.send(UiEvent::AskStarting);
-        let input = crate::use_cases::run_ask::RunAskInput::new(
+        let mut input = crate::use_cases::run_ask::RunAskInput::new(
             query,
             self.config.models().clone(),
             self.config.execution().clone(),
         );
+        if !self.allow_escalation {
+            input = input.without_escalation();
+        }
 
         match self.ask_use_case.execute(input, progress).await {
-            Ok(result) => {
+            Ok(RunAskOutput::Answer(result)) => {
                 if let InteractionResult::AskResult { ref answer } = result {
                     let _ = self.tx.send(UiEvent::AskResult(AskResultEvent {
                         answer: answer.clone(),
                     }));
                 }
-                Some(result)
+                (Some(result), None)
+            }
+            Ok(RunAskOutput::EscalateToAgent { request }) => {
+                // Recorded as the Ask's answer so history shows the hand-over
+                let answer = format!("Escalated to an agent: {}", request);
+                let _ = self.tx.send(UiEvent::AskResult(AskResultEvent {
+                    answer: answer.clone(),
+                }));
+                (Some(InteractionResult::AskResult { answer }), Some(request))
             }
             Err(e) => {
                 let _ = self.tx.send(UiEvent::AskError {
                     error: e.to_string(),
                 });
-                None
+                (None, None)
             }
         }
     }
@@ -1415,13 +2017,20 @@ impl SpawnContext {
         match use_case.execute_with_progress(input, &adapter).await {
             Ok(output) => {
                 let formatted = format_quorum_output(&output, OutputFormat::Synthesis);
+                let participant_count = output.models.len();
+                let responses = output.responses.clone();
+                let synthesis = output.synthesis.clone();
+                *self.last_discussion.lock().unwrap() = Some(output);
                 let _ = self.tx.send(UiEvent::QuorumResult(QuorumResultEvent {
                     formatted_output: formatted.clone(),
                     output_format: OutputFormat::Synthesis,
+                    synthesis_streamed: adapter.streamed_synthesis(),
+                    responses,
+                    synthesis,
                 }));
                 Some(InteractionResult::DiscussResult {
                     synthesis: formatted,
-                    participant_count: output.models.len(),
+                    participant_count,
                 })
             }
             Err(e) => {
@@ -1451,7 +2060,12 @@ impl SpawnContext {
         };
 
         // Use the factory method from QuorumConfig
-        let input = self.config.to_agent_input(effective_query);
+        let mut input = self.config.to_agent_input(effective_query);
+        if let Some(context) = &self.inherited_context {
+            input = input.with_initial_context(context.clone());
+        } else if let Some(context) = self.init_context.lock().unwrap().take() {
+            input = input.with_initial_context(context);
+        }
 
         match self
             .agent_use_case
@@ -1459,6 +2073,12 @@ impl SpawnContext {
             .await
         {
             Ok(output) => {
+                if let Some(id) = self.interaction {
+                    self.agent_contexts
+                        .lock()
+                        .unwrap()
+                        .insert(id, output.state.context.clone());
+                }
                 let _ = self
                     .tx
                     .send(UiEvent::AgentResult(Box::new(AgentResultEvent {
@@ -2072,6 +2692,75 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_discuss_save_without_discussion_errors() {
+        let (mut controller, mut rx) = create_test_controller();
+
+        let action = controller
+            .handle_command("/discuss --save out.md", &NoAgentProgress)
+            .await;
+        assert!(matches!(action, CommandAction::Continue));
+        match rx.try_recv().unwrap() {
+            UiEvent::CommandError { message } => {
+                assert!(message.contains("No discussion to save"));
+            }
+            other => panic!("Expected CommandError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_save_flag_path_matches_whole_flag() {
+        assert_eq!(save_flag_path("--save out.md"), Some("out.md"));
+        assert_eq!(save_flag_path("--save=out.md"), Some("out.md"));
+        assert_eq!(save_flag_path("--save"), Some(""));
+        assert_eq!(save_flag_path("--saved state or not?"), None);
+        assert_eq!(save_flag_path("how to --save?"), None);
+    }
+
+    #[tokio::test]
+    async fn test_discuss_with_save_like_word_is_a_question() {
+        let (mut controller, _rx) = create_test_controller();
+
+        let action = controller
+            .handle_command("/discuss --savepoint vs transactions?", &NoAgentProgress)
+            .await;
+        assert!(matches!(
+            action,
+            CommandAction::Execute { form: InteractionForm::Discuss, query }
+                if query == "--savepoint vs transactions?"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_discuss_save_writes_last_transcript() {
+        use quorum_domain::{ModelResponse, SynthesisResult};
+
+        let (mut controller, mut rx) = create_test_controller();
+        *controller.last_discussion.lock().unwrap() = Some(QuorumResult::new(
+            "Cache strategy?",
+            vec!["gpt-5".to_string()],
+            vec![ModelResponse::success("gpt-5", "Write-through.")],
+            vec![],
+            SynthesisResult::new("gpt-5", "Write-through wins."),
+        ));
+        let path =
+            std::env::temp_dir().join(format!("quorum-transcript-{}.md", std::process::id()));
+
+        controller
+            .handle_command(
+                &format!("/discuss --save {}", path.display()),
+                &NoAgentProgress,
+            )
+            .await;
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            UiEvent::TranscriptSaved { .. }
+        ));
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(written.contains("### gpt-5\n\nWrite-through."));
+    }
+
     #[tokio::test]
     async fn test_ask_without_args_shows_usage() {
         let (mut controller, mut rx) = create_test_controller();
@@ -2118,6 +2807,57 @@ mod tests {
         controller.cancel_interaction(InteractionId(1));
     }
 
+    #[tokio::test]
+    async fn test_cancel_ensemble_branch_without_running_branch() {
+        let (mut controller, _rx) = create_test_controller();
+        let id = InteractionId(3);
+        let _context = controller.build_spawn_context_for(id);
+
+        // Nothing is planning yet, so there is no branch to cancel.
+        assert_eq!(
+            controller.cancel_ensemble_branch(id, "claude-haiku-4.5"),
+            Ok(false)
+        );
+        assert!(
+            controller
+                .cancel_ensemble_branch(id, "claude-haku-4.5")
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summarize_session_does_not_leak_tokens() {
+        let (mut controller, mut rx) = create_test_controller();
+        controller.set_cancellation(CancellationToken::new());
+        let id = InteractionId(5);
+
+        let summary = controller.summarize_session(id, vec![Message::user("hello")]);
+        assert!(!controller.interaction_tokens.contains_key(&id));
+        assert_eq!(controller.summary_tokens.lock().unwrap().len(), 1);
+
+        summary.await;
+        assert!(controller.summary_tokens.lock().unwrap().is_empty());
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            UiEvent::SessionSummary { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_interaction_cancels_pending_summary() {
+        let (mut controller, mut rx) = create_test_controller();
+        controller.set_cancellation(CancellationToken::new());
+        let id = InteractionId(5);
+
+        let summary = controller.summarize_session(id, vec![Message::user("hello")]);
+        controller.cancel_interaction(id);
+        assert!(controller.summary_tokens.lock().unwrap().is_empty());
+
+        summary.await;
+        // A cancelled summary reports nothing
+        assert!(rx.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_finalize_drops_interaction_token() {
         let (mut controller, _rx) = create_test_controller();
@@ -2131,6 +2871,7 @@ mod tests {
             query: "q".into(),
             result: None,
             cancelled_state: None,
+            escalation: None,
         });
         assert!(!controller.interaction_tokens.contains_key(&id));
     }
@@ -2390,6 +3131,108 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prepare_spawn_uses_configured_context_mode() {
+        let (mut controller, _rx) = create_test_controller();
+        controller
+            .config()
+            .config_set(
+                "interaction.ask.context_mode",
+                crate::ports::config_accessor::ConfigValue::String("full".into()),
+            )
+            .unwrap();
+
+        let (ask_id, _, _) = controller
+            .prepare_spawn(InteractionForm::Ask, "hello", None)
+            .unwrap();
+        let (review_id, _, _) =
+            controller.prepare_root_spawn(InteractionForm::Review, "Review", "diff");
+        let tree = &controller.interaction_tree;
+        assert_eq!(tree.get(ask_id).unwrap().context_mode, ContextMode::Full);
+        // Forms without an override keep their compiled default
+        assert_eq!(
+            tree.get(review_id).unwrap().context_mode,
+            InteractionForm::Review.default_context_mode()
+        );
+
+        // A `--fresh` flag still wins over the configured default
+        let (flagged_id, _, _) = controller
+            .prepare_spawn(InteractionForm::Ask, "--fresh hello", None)
+            .unwrap();
+        assert_eq!(
+            controller
+                .interaction_tree
+                .get(flagged_id)
+                .unwrap()
+                .context_mode,
+            ContextMode::Fresh
+        );
+    }
+
+    #[test]
+    fn test_fresh_child_does_not_inherit_parent_agent_context() {
+        let (mut controller, _rx) = create_test_controller();
+        let mut parent_context = AgentContext::new();
+        parent_context.set_structure_summary("parent structure");
+        controller
+            .agent_contexts
+            .lock()
+            .unwrap()
+            .insert(controller.active_interaction_id, parent_context);
+
+        let (shared_id, _, _) = controller
+            .prepare_spawn(InteractionForm::Agent, "inspect", None)
+            .unwrap();
+        let (fresh_id, _, _) = controller
+            .prepare_spawn(InteractionForm::Agent, "--fresh inspect", None)
+            .unwrap();
+
+        let shared = controller.build_spawn_context_for(shared_id);
+        assert_eq!(
+            shared
+                .inherited_context
+                .and_then(|context| context.structure_summary)
+                .as_deref(),
+            Some("parent structure")
+        );
+        let fresh = controller.build_spawn_context_for(fresh_id);
+        assert!(fresh.inherited_context.is_none());
+    }
+
+    #[test]
+    fn test_prepare_escalation_spawns_agent_once() {
+        let (mut controller, _rx) = create_test_controller();
+        let (ask_id, _, _) = controller
+            .prepare_spawn(InteractionForm::Ask, "how do I fix this?", None)
+            .unwrap();
+        assert!(controller.build_spawn_context_for(ask_id).allow_escalation);
+
+        let (agent_id, clean_query, _) = controller
+            .prepare_escalation(ask_id, "fix the failing test")
+            .unwrap();
+        let agent = controller.interaction_tree.get(agent_id).unwrap();
+        assert_eq!(agent.form, InteractionForm::Agent);
+        assert_eq!(agent.parent, Some(ask_id));
+        assert_eq!(clean_query, "fix the failing test");
+
+        // Neither the escalated Agent nor anything under it escalates again
+        assert!(
+            !controller
+                .build_spawn_context_for(agent_id)
+                .allow_escalation
+        );
+        let (nested_ask, _, _) = controller
+            .prepare_spawn_under(agent_id, InteractionForm::Ask, "why?", None)
+            .unwrap();
+        assert!(
+            !controller
+                .build_spawn_context_for(nested_ask)
+                .allow_escalation
+        );
+        // The original Ask's siblings are unaffected
+        assert!(controller.build_inline_context().allow_escalation);
+    }
+
     #[test]
     fn test_prepare_root_spawn_has_no_parent() {
         // Regression coverage for #300: headless review spawns a genuine root
@@ -2421,6 +3264,63 @@ mod tests {
         assert_ne!(id, InteractionId(0));
     }
 
+    #[test]
+    fn test_completed_children_are_injected_into_parent_context() {
+        let (mut controller, _rx) = create_test_controller();
+        let parent_id = controller.active_interaction_id();
+        let (first, first_query, _) = controller
+            .prepare_spawn(InteractionForm::Ask, "which port?", None)
+            .unwrap();
+        let (second, second_query, _) = controller
+            .prepare_spawn(InteractionForm::Discuss, "which db?", None)
+            .unwrap();
+
+        // The second child completes first
+        controller.finalize(TaskCompletion {
+            interaction_id: Some(second),
+            form: InteractionForm::Discuss,
+            query: second_query,
+            result: Some(InteractionResult::DiscussResult {
+                synthesis: "Postgres".to_string(),
+                participant_count: 3,
+            }),
+            cancelled_state: None,
+            escalation: None,
+        });
+        controller.finalize(TaskCompletion {
+            interaction_id: Some(first),
+            form: InteractionForm::Ask,
+            query: first_query,
+            result: Some(InteractionResult::AskResult {
+                answer: "8080".to_string(),
+            }),
+            cancelled_state: None,
+            escalation: None,
+        });
+
+        let context = controller.build_spawn_context_for(parent_id);
+        assert_eq!(
+            context.child_results,
+            vec![
+                "[Discuss Result (3 models)]: Postgres".to_string(),
+                "[Ask Result]: 8080".to_string(),
+            ]
+        );
+        assert_eq!(
+            child_results_prefix(&context.child_results).unwrap(),
+            "## Child Interaction Results\n\n\
+             [Discuss Result (3 models)]: Postgres\n\
+             [Ask Result]: 8080"
+        );
+        // Consumed by the parent's next request
+        assert!(
+            controller
+                .build_spawn_context_for(parent_id)
+                .child_results
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_finalize_with_spawn_emits_completion() {
         let (mut controller, mut rx) = create_test_controller();
@@ -2437,6 +3337,7 @@ mod tests {
                 answer: "done".to_string(),
             }),
             cancelled_state: None,
+            escalation: None,
         });
 
         let event = rx.try_recv().unwrap();
@@ -2466,6 +3367,7 @@ mod tests {
                 success: true,
             }),
             cancelled_state: None,
+            escalation: None,
         });
 
         // No InteractionCompleted event for inline
@@ -2496,6 +3398,93 @@ mod tests {
         assert!(full.contains("follow up"));
     }
 
+    /// Controller with a tight budget and a long history: exchange 0 is the
+    /// original request, exchanges 1..=8 are follow-ups.
+    fn create_controller_with_long_history() -> (AgentController, mpsc::UnboundedReceiver<UiEvent>)
+    {
+        let (mut controller, rx) = create_test_controller();
+        controller.config().execution_mut().context_budget =
+            quorum_domain::ContextBudget::new(100, 600, 2);
+        for i in 0..9 {
+            controller.conversation_history.push(HistoryEntry {
+                form: InteractionForm::Ask,
+                request: format!("request {} {}", i, "r".repeat(40)),
+                summary: format!("summary {} {}", i, "s".repeat(40)),
+            });
+        }
+        (controller, rx)
+    }
+
+    #[test]
+    fn test_history_context_trims_to_budget() {
+        let (controller, _rx) = create_controller_with_long_history();
+
+        let ctx = controller.build_context_from_history();
+        assert!(ctx.contains("request 0"), "original request must survive");
+        assert!(ctx.contains("### Earlier Exchanges"));
+        assert!(ctx.contains("request 8") && ctx.contains("summary 8"));
+        // Exchanges in the middle only appear as digest lines, not verbatim
+        assert!(!ctx.contains("**User**: request 3"));
+    }
+
+    #[tokio::test]
+    async fn test_compact_history_uses_exploration_summary() {
+        let (mut controller, _rx) = create_controller_with_long_history();
+
+        controller.compact_history().await;
+
+        let ctx = controller.build_context_from_history();
+        assert!(ctx.contains("### Earlier Exchanges"));
+        assert!(ctx.contains("mock response"), "{}", ctx);
+        assert!(ctx.contains("request 0"));
+        assert!(ctx.contains("request 8"));
+    }
+
+    #[tokio::test]
+    async fn test_history_compaction_runs_detached_and_stops_on_shutdown() {
+        let (mut controller, _rx) = create_controller_with_long_history();
+        let root = CancellationToken::new();
+        controller.set_cancellation(root.clone());
+
+        let compaction = controller.history_compaction().expect("over budget");
+        assert_eq!(
+            tokio::spawn(compaction).await.unwrap(),
+            CompactionOutcome::Summarized
+        );
+        assert!(
+            controller
+                .build_context_from_history()
+                .contains("mock response")
+        );
+        // Already summarized: nothing left to do
+        assert!(controller.history_compaction().is_none());
+
+        controller.conversation_history.push(HistoryEntry {
+            form: InteractionForm::Ask,
+            request: format!("request 9 {}", "r".repeat(40)),
+            summary: format!("summary 9 {}", "s".repeat(40)),
+        });
+        let compaction = controller
+            .history_compaction()
+            .expect("grew past the summary");
+        root.cancel();
+        assert_eq!(compaction.await, CompactionOutcome::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_compact_history_is_noop_within_budget() {
+        let (mut controller, _rx) = create_test_controller();
+        controller.conversation_history.push(HistoryEntry {
+            form: InteractionForm::Ask,
+            request: "What is X?".to_string(),
+            summary: "X is Y".to_string(),
+        });
+
+        controller.compact_history().await;
+        assert!(controller.history_compaction().is_none());
+        assert!(controller.history_summary.lock().unwrap().is_none());
+    }
+
     #[test]
     fn test_prepare_inline_fresh_flag() {
         let (mut controller, _rx) = create_test_controller();
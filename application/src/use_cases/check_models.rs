@@ -0,0 +1,144 @@
+//! Check models use case.
+//!
+//! Compares the models referenced by [`ModelConfig`] against what the
+//! gateway reports from [`LlmGateway::available_models`], so a misconfigured
+//! model is caught before a long run (`--check-models`).
+
+use crate::ports::llm_gateway::{GatewayError, LlmGateway};
+use quorum_domain::Model;
+use quorum_domain::agent::model_config::ModelConfig;
+use quorum_domain::util::edit_distance;
+
+/// Availability of one configured model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelCheckEntry {
+    /// Config role the model is used for (e.g. `"exploration"`, `"participants"`).
+    pub role: &'static str,
+    /// The configured model.
+    pub model: Model,
+    /// Whether any provider reports the model as available.
+    pub available: bool,
+    /// Closest available model name, when `available` is false.
+    pub suggestion: Option<Model>,
+}
+
+/// Result of checking every configured model.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelCheckReport {
+    pub entries: Vec<ModelCheckEntry>,
+}
+
+impl ModelCheckReport {
+    /// Entries whose model is not available.
+    pub fn missing(&self) -> impl Iterator<Item = &ModelCheckEntry> {
+        self.entries.iter().filter(|e| !e.available)
+    }
+
+    pub fn all_available(&self) -> bool {
+        self.missing().next().is_none()
+    }
+}
+
+/// Query the gateway and check every configured model against its answer.
+pub async fn check_models(
+    gateway: &dyn LlmGateway,
+    config: &ModelConfig,
+) -> Result<ModelCheckReport, GatewayError> {
+    let available = gateway.available_models().await?;
+    Ok(diff_configured_models(config, &available))
+}
+
+/// Compare the exploration, decision, review, participants and moderator
+/// models of `config` against `available`.
+pub fn diff_configured_models(config: &ModelConfig, available: &[Model]) -> ModelCheckReport {
+    let configured = std::iter::once(("exploration", &config.exploration))
+        .chain(std::iter::once(("decision", &config.decision)))
+        .chain(config.review.iter().map(|m| ("review", m)))
+        .chain(config.participants.iter().map(|m| ("participants", m)))
+        .chain(std::iter::once(("moderator", &config.moderator)));
+
+    let entries = configured
+        .map(|(role, model)| {
+            let is_available = available.contains(model);
+            ModelCheckEntry {
+                role,
+                model: model.clone(),
+                available: is_available,
+                suggestion: if is_available {
+                    None
+                } else {
+                    closest_model(model, available)
+                },
+            }
+        })
+        .collect();
+
+    ModelCheckReport { entries }
+}
+
+/// The available model whose name has the smallest edit distance to `model`.
+fn closest_model(model: &Model, available: &[Model]) -> Option<Model> {
+    available
+        .iter()
+        .min_by_key(|m| edit_distance(model.as_str(), m.as_str()))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ModelConfig {
+        ModelConfig {
+            exploration: Model::ClaudeHaiku45,
+            decision: Model::ClaudeSonnet46,
+            review: vec![Model::ClaudeSonnet46, Model::Gpt52Codex],
+            participants: vec![Model::Custom("gpt-5.4x".to_string())],
+            moderator: Model::ClaudeSonnet46,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_marks_missing_models_with_suggestion() {
+        let available = vec![
+            Model::ClaudeHaiku45,
+            Model::ClaudeSonnet46,
+            Model::Gpt53Codex,
+            Model::Gpt54,
+        ];
+        let report = diff_configured_models(&config(), &available);
+
+        assert_eq!(report.entries.len(), 6);
+        let missing: Vec<_> = report.missing().collect();
+        assert_eq!(missing.len(), 2);
+
+        assert_eq!(missing[0].role, "review");
+        assert_eq!(missing[0].model, Model::Gpt52Codex);
+        assert_eq!(missing[0].suggestion, Some(Model::Gpt53Codex));
+
+        assert_eq!(missing[1].role, "participants");
+        assert_eq!(missing[1].suggestion, Some(Model::Gpt54));
+        assert!(!report.all_available());
+    }
+
+    #[test]
+    fn test_diff_all_available() {
+        let available = vec![
+            Model::ClaudeHaiku45,
+            Model::ClaudeSonnet46,
+            Model::Gpt52Codex,
+            Model::Custom("gpt-5.4x".to_string()),
+        ];
+        let report = diff_configured_models(&config(), &available);
+        assert!(report.all_available());
+        assert!(report.entries.iter().all(|e| e.suggestion.is_none()));
+    }
+
+    #[test]
+    fn test_diff_no_suggestion_when_nothing_available() {
+        let report = diff_configured_models(&config(), &[]);
+        assert_eq!(report.missing().count(), 6);
+        assert!(report.entries.iter().all(|e| e.suggestion.is_none()));
+    }
+}
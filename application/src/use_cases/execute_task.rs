@@ -2,25 +2,38 @@
 //!
 //! Responsible for Phase 4 of the agent execution flow: executing tasks
 //! from the approved plan with dynamic model selection and action review.
+//!
+//! Under [`HilMode::BatchReview`] high-risk actions that pass review are
+//! queued instead of executed, and run after every task finished and the
+//! user approved them (see [`ExecuteTaskUseCase::run_batch_review`]).
 
+use crate::config::RetryPolicy;
 use crate::ports::action_reviewer::{ActionReviewer, ReviewDecision};
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::ports::conversation_logger::{ConversationEvent, ConversationLogger};
+use crate::ports::human_intervention::{HumanInterventionError, HumanInterventionPort};
 use crate::ports::llm_gateway::{LlmGateway, LlmSession, ToolResultMessage};
 use crate::ports::scripting_engine::ScriptingEnginePort;
 use crate::ports::tool_executor::ToolExecutorPort;
 use crate::ports::tool_schema::ToolSchemaPort;
+use crate::use_cases::run_agent::hil::resolve_intervention;
+use crate::use_cases::run_agent::usage::{UsageMeter, check_cost_limit};
 use crate::use_cases::run_agent::{RunAgentError, RunAgentInput};
 use crate::use_cases::shared::{check_cancelled, send_with_tools_cancellable};
-use crate::use_cases::tool_helpers::tool_args_preview;
+use crate::use_cases::tool_helpers::{
+    command_policy_error, dedupe_tool_calls, resolve_tool_names, tool_args_preview,
+};
+use quorum_domain::agent::agent_policy::HilAction;
 use quorum_domain::agent::model_config::ModelConfig;
 use quorum_domain::context::context_budget::ContextBudget;
 use quorum_domain::context::task_result_buffer::TaskResultBuffer;
 use quorum_domain::util::truncate_str;
 use quorum_domain::{
-    AgentPromptTemplate, AgentState, Model, Task, TaskId, ToolExecution, looks_like_tool_call_json,
+    ActionBatch, AgentPromptTemplate, AgentState, BatchActionOutcome, ErrorCategory, FailurePolicy,
+    HilMode, HumanDecision, Model, Plan, Task, TaskId, ToolExecution, looks_like_tool_call_json,
 };
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
@@ -58,6 +71,16 @@ fn extract_task_brief(output: &str, max_bytes: usize) -> Option<String> {
     })
 }
 
+/// Outcome of one task, including its rejected attempts.
+struct TaskRun {
+    result: Result<String, RunAgentError>,
+    /// Tool executions of the final attempt
+    executions: Vec<ToolExecution>,
+    /// Tool executions of attempts whose actions were rejected and retried
+    retried_executions: Vec<ToolExecution>,
+    retries: usize,
+}
+
 /// Use case for executing tasks from an approved plan (Phase 4).
 ///
 /// Handles dynamic model selection based on tool risk level,
@@ -71,8 +94,25 @@ pub struct ExecuteTaskUseCase {
     action_reviewer: Arc<dyn ActionReviewer>,
     conversation_logger: Arc<dyn ConversationLogger>,
     scripting_engine: Option<Arc<dyn ScriptingEnginePort>>,
+    human_intervention: Option<Arc<dyn HumanInterventionPort>>,
+    retry_policy: RetryPolicy,
+    usage_meter: Option<Arc<UsageMeter>>,
+    /// Actions queued under [`HilMode::BatchReview`] during the current
+    /// [`execute`](Self::execute) call.
+    pending_batch: Mutex<PendingBatch>,
 }
 
+/// High-risk actions held back for batch review, with the (still Pending)
+/// [`ToolExecution`] created for each: `executions[i]` belongs to action `i`.
+#[derive(Default)]
+struct PendingBatch {
+    batch: ActionBatch,
+    executions: Vec<ToolExecution>,
+}
+
+/// Tool result sent to the model for a call queued for batch review.
+const QUEUED_FOR_BATCH_REVIEW: &str = "Action queued for batch review: it will run only if the user approves it after all tasks finish. Continue as if it will succeed, but do not rely on its output.";
+
 impl ExecuteTaskUseCase {
     pub fn new(
         gateway: Arc<dyn LlmGateway>,
@@ -90,15 +130,78 @@ impl ExecuteTaskUseCase {
             action_reviewer,
             conversation_logger,
             scripting_engine: None,
+            human_intervention: None,
+            retry_policy: RetryPolicy::default(),
+            usage_meter: None,
+            pending_batch: Mutex::new(PendingBatch::default()),
         }
     }
 
+    /// Set the backoff policy for transient LLM request failures.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Set the scripting engine for ToolCallBefore events.
     pub fn with_scripting_engine(mut self, engine: Arc<dyn ScriptingEnginePort>) -> Self {
         self.scripting_engine = Some(engine);
         self
     }
 
+    /// Set the human intervention handler for actions below the reviewer floor.
+    pub fn with_human_intervention(mut self, intervention: Arc<dyn HumanInterventionPort>) -> Self {
+        self.human_intervention = Some(intervention);
+        self
+    }
+
+    /// Stop at round and turn boundaries once `meter` is over its token limit.
+    pub(crate) fn with_usage_meter(mut self, meter: Arc<UsageMeter>) -> Self {
+        self.usage_meter = Some(meter);
+        self
+    }
+
+    /// Resolve a [`ReviewDecision::Escalate`] through the policy's HiL mode.
+    ///
+    /// Returns `Approved` or `Rejected`. Without an intervention handler the
+    /// action is rejected (fail-secure).
+    async fn resolve_escalation(
+        &self,
+        reason: &str,
+        tool_call_json: &str,
+        task: &Task,
+        input: &RunAgentInput,
+    ) -> Result<ReviewDecision, RunAgentError> {
+        let decision = match input.policy.reviewer_shortfall_action() {
+            HilAction::ForceApprove => {
+                warn!("{} — auto-approving (HilMode::AutoApprove)", reason);
+                HumanDecision::Approve
+            }
+            // The user approves every queued action at the end anyway
+            HilAction::RequestIntervention if input.policy.hil_mode == HilMode::BatchReview => {
+                info!("{} — deferred to batch review", reason);
+                HumanDecision::Approve
+            }
+            HilAction::RequestIntervention => match &self.human_intervention {
+                Some(intervention) => {
+                    let result = intervention
+                        .request_action_approval(task, tool_call_json, reason)
+                        .await;
+                    resolve_intervention(result, &input.policy)?
+                }
+                None => {
+                    warn!("{} — no human intervention handler, rejecting", reason);
+                    HumanDecision::Reject
+                }
+            },
+            HilAction::Abort | HilAction::Continue => HumanDecision::Reject,
+        };
+        Ok(match decision {
+            HumanDecision::Approve => ReviewDecision::Approved,
+            _ => ReviewDecision::Rejected(reason.to_string()),
+        })
+    }
+
     /// Check ToolCallBefore: returns true if the tool call should proceed.
     fn check_tool_call_before(
         &self,
@@ -115,6 +218,13 @@ impl ExecuteTaskUseCase {
 
     /// Execute all tasks in the plan with dynamic model selection.
     ///
+    /// Tasks run in rounds over the `depends_on` graph: every ready task
+    /// that is safe to parallelize (see [`Self::select_round`]) runs
+    /// concurrently, up to `execution.max_parallel_tasks`; other tasks run
+    /// one at a time. A dependency cycle is reported as a planning error.
+    /// After a task fails, `agent.on_task_failure` ([`FailurePolicy`])
+    /// decides which remaining tasks are skipped.
+    ///
     /// Returns a summary string describing what was accomplished.
     pub async fn execute(
         &self,
@@ -123,233 +233,571 @@ impl ExecuteTaskUseCase {
         system_prompt: &str,
         progress: &dyn AgentProgressNotifier,
     ) -> Result<String, RunAgentError> {
+        if let Some(cycle) = state.plan.as_ref().and_then(|p| p.dependency_cycle()) {
+            let cycle: Vec<&str> = cycle.iter().map(|id| id.as_str()).collect();
+            return Err(RunAgentError::PlanningFailed(format!(
+                "Task dependency cycle: {}",
+                cycle.join(" → ")
+            )));
+        }
+
         let mut results = Vec::new();
         let mut result_buffer = TaskResultBuffer::new(input.execution.context_budget.clone());
 
         loop {
-            // Check for cancellation at the start of each task
+            // Check for cancellation and the token limit at the start of each round
             check_cancelled(&self.cancellation_token)?;
+            check_cost_limit(&self.usage_meter)?;
 
-            // Check iteration limit
-            if !state.increment_iteration() {
-                return Err(RunAgentError::MaxIterationsExceeded);
-            }
-
-            // Get next task and determine appropriate model
-            let (task_id, task_description, selected_model, task_index, task_total) = {
+            let round = {
                 let plan = state.plan.as_ref().ok_or_else(|| {
                     RunAgentError::TaskExecutionFailed("No plan available".to_string())
                 })?;
+                self.select_round(plan, input.execution.max_parallel_tasks)
+            };
+            if round.is_empty() {
+                break; // All tasks complete
+            }
 
-                match plan.next_task() {
-                    Some(task) => {
-                        let model = self.select_model_for_task(task, &input.models);
-                        let index =
-                            plan.tasks.iter().position(|t| t.id == task.id).unwrap_or(0) + 1;
-                        let total = plan.tasks.len();
-                        (
-                            task.id.clone(),
-                            task.description.clone(),
-                            model.clone(),
-                            index,
-                            total,
-                        )
-                    }
-                    None => break, // All tasks complete
+            // Start every task of the round, each with its own session
+            let mut running = Vec::with_capacity(round.len());
+            for task_id in round {
+                // Check iteration limit
+                if !state.increment_iteration() {
+                    return Err(RunAgentError::MaxIterationsExceeded);
                 }
-            };
 
-            // Create session with the selected model
-            let session = self
-                .gateway
-                .create_session_with_system_prompt(&selected_model, system_prompt)
-                .await?;
+                let (task_description, selected_model, task_index, task_total) = {
+                    let plan = state.plan.as_ref().ok_or_else(|| {
+                        RunAgentError::TaskExecutionFailed("No plan available".to_string())
+                    })?;
+                    let index = plan.tasks.iter().position(|t| t.id == task_id).unwrap_or(0);
+                    let task = &plan.tasks[index];
+                    (
+                        task.description.clone(),
+                        self.select_model_for_task(task, &input.models).clone(),
+                        index + 1,
+                        plan.tasks.len(),
+                    )
+                };
+
+                // Create session with the selected model
+                let session = self
+                    .gateway
+                    .create_session_with_system_prompt(&selected_model, system_prompt)
+                    .await?;
 
-            debug!(
-                "Task {} using model {} (risk-based selection)",
-                task_id, selected_model
-            );
+                debug!(
+                    "Task {} using model {} (risk-based selection)",
+                    task_id, selected_model
+                );
 
-            // Mark task as in progress
-            if let Some(plan) = &mut state.plan
-                && let Some(task) = plan.get_task_mut(&task_id)
-            {
-                if task.status.is_terminal() {
-                    warn!("Task {} already in terminal state, skipping", task_id);
-                    continue;
+                // Mark task as in progress
+                if let Some(plan) = &mut state.plan
+                    && let Some(task) = plan.get_task_mut(&task_id)
+                {
+                    task.mark_in_progress();
+                    progress.on_task_start(task, task_index, task_total);
                 }
-                task.mark_in_progress();
-                progress.on_task_start(task, task_index, task_total);
-            }
 
-            // Execute the task with action retry support
-            let max_action_retries = 2;
-            let mut action_attempts = 0;
-            let mut action_feedback: Option<String> = None;
-
-            let task_result = loop {
-                // Build context including any rejection feedback,
-                // with optional per-task ContextMode budget override
-                let task_budget = state
-                    .plan
-                    .as_ref()
-                    .and_then(|p| p.tasks.iter().find(|t| t.id == task_id))
-                    .and_then(|t| t.context_mode)
-                    .map(ContextBudget::for_context_mode);
-
-                let context_with_feedback = match &action_feedback {
-                    Some(feedback) => {
-                        result_buffer.render_with_feedback(feedback, task_budget.as_ref())
-                    }
-                    None => result_buffer.render_with_budget(task_budget.as_ref()),
-                };
+                running.push((task_id, task_description, task_index, task_total, session));
+            }
 
-                match self
-                    .execute_single_task(
+            // Run the round; tasks share the rejection cascade counter
+            let rejections = AtomicUsize::new(state.action_rejection_count);
+            let parallel = running.len() > 1;
+            let outcomes = {
+                let state = &*state;
+                let result_buffer = &result_buffer;
+                let rejections = &rejections;
+                futures::future::join_all(running.iter().map(|(task_id, _, _, _, session)| {
+                    self.execute_with_action_retries(
                         session.as_ref(),
                         input,
                         state,
-                        &task_id,
-                        &context_with_feedback,
+                        task_id,
+                        parallel,
+                        result_buffer,
+                        rejections,
                         progress,
                     )
-                    .await
+                }))
+                .await
+            };
+            state.action_rejection_count = rejections.into_inner();
+            let mut aborted_by: Option<TaskId> = None;
+
+            for ((task_id, task_description, task_index, task_total, _), run) in
+                running.into_iter().zip(outcomes)
+            {
+                state.record_task_retries(&task_id, run.retries);
+
+                // Tool stats count every call, including those of failed and
+                // rejected attempts; the task keeps its final attempt's calls
+                state.record_tool_executions(&run.retried_executions);
+                state.record_tool_executions(&run.executions);
+                if let Some(plan) = &mut state.plan
+                    && let Some(task) = plan.get_task_mut(&task_id)
                 {
-                    Err(RunAgentError::ActionRejected(feedback)) => {
-                        action_attempts += 1;
-
-                        // Track consecutive rejections for cascade detection
-                        let rejection_count = state.record_action_rejection();
-                        let cascade_action = input.policy.action_rejection_action(rejection_count);
-
-                        match cascade_action {
-                            quorum_domain::agent::agent_policy::HilAction::Abort => {
-                                warn!(
-                                    "Action rejection cascade detected ({} consecutive). Aborting.",
-                                    rejection_count
-                                );
-                                break Err(RunAgentError::ActionRejected(format!(
-                                    "Rejection cascade: {} consecutive rejections exceeded limit. \
-                                     Last feedback: {}",
-                                    rejection_count, feedback
-                                )));
-                            }
-                            quorum_domain::agent::agent_policy::HilAction::ForceApprove => {
-                                info!(
-                                    "Action rejection cascade ({} consecutive): auto-approve mode, \
-                                     skipping review for next attempt.",
-                                    rejection_count
-                                );
-                                // Reset and continue — next attempt won't be reviewed
-                                // (the tool-level review is still in place, but the cascade
-                                // count signals that we should let it through)
-                                action_feedback = Some(format!(
-                                    "{}\n[NOTE: Cascade limit reached. Proceeding without review.]",
-                                    feedback
-                                ));
-                                continue;
-                            }
-                            quorum_domain::agent::agent_policy::HilAction::RequestIntervention => {
-                                warn!(
-                                    "Action rejection cascade ({} consecutive): requesting human intervention.",
-                                    rejection_count
-                                );
-                                break Err(RunAgentError::ActionRejected(format!(
-                                    "Rejection cascade: {} consecutive rejections. \
-                                     Human intervention required. Last feedback: {}",
-                                    rejection_count, feedback
-                                )));
-                            }
-                            quorum_domain::agent::agent_policy::HilAction::Continue => {
-                                // Within retry limits — normal retry flow
+                    task.tool_executions = run.executions;
+                }
+
+                // Update task status
+                let (success, output) = match run.result {
+                    Ok(output) => (true, output),
+                    Err(e) => (false, e.to_string()),
+                };
+
+                if let Some(plan) = &mut state.plan
+                    && let Some(task) = plan.get_task_mut(&task_id)
+                    && !task.status.is_terminal()
+                {
+                    if success {
+                        task.mark_completed(quorum_domain::TaskResult::success(&output));
+                    } else {
+                        task.mark_failed(quorum_domain::TaskResult::failure(&output));
+                    }
+                    progress.on_task_complete(task, success, task_index, task_total);
+                }
+
+                let status = if success { "OK" } else { "FAILED" };
+                let brief = if success {
+                    extract_task_brief(&output, 150)
+                        .map(|b| format!(" — {}", b))
+                        .unwrap_or_default()
+                } else {
+                    // For failures, the output IS the error message
+                    let error_brief = truncate_str(&output, 150);
+                    if error_brief.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" — {}", error_brief)
+                    }
+                };
+                let retried = match run.retries {
+                    0 => String::new(),
+                    1 => " (1 retry)".to_string(),
+                    n => format!(" ({} retries)", n),
+                };
+                results.push(format!(
+                    "Task {} ({}): {}{}{}",
+                    task_id,
+                    truncate_str(&task_description, 60),
+                    status,
+                    retried,
+                    brief,
+                ));
+                result_buffer.push(task_id.as_str(), &output);
+
+                if !success {
+                    let (skipped, reason) =
+                        match (input.policy.on_task_failure, state.plan.as_mut()) {
+                            (FailurePolicy::ContinueIndependent, Some(plan)) => (
+                                plan.skip_dependents(&task_id),
+                                format!("depends on failed task {}", task_id),
+                            ),
+                            (FailurePolicy::Abort, Some(plan)) => {
+                                aborted_by.get_or_insert_with(|| task_id.clone());
+                                (
+                                    plan.skip_pending(),
+                                    format!("aborted after task {} failed", task_id),
+                                )
                             }
-                        }
+                            _ => (Vec::new(), String::new()),
+                        };
+                    for id in skipped {
+                        let description = state
+                            .plan
+                            .as_ref()
+                            .and_then(|p| p.tasks.iter().find(|t| t.id == id))
+                            .map(|t| t.description.clone())
+                            .unwrap_or_default();
+                        results.push(format!(
+                            "Task {} ({}): SKIPPED — {}",
+                            id,
+                            truncate_str(&description, 60),
+                            reason,
+                        ));
+                    }
+                }
+            }
 
-                        if action_attempts >= max_action_retries {
-                            break Err(RunAgentError::ActionRejected(format!(
-                                "Action rejected after {} attempts. Last feedback: {}",
-                                action_attempts, feedback
-                            )));
-                        }
+            if let Some(failed) = aborted_by {
+                // A cancelled (or over-budget) task fails too; report that instead
+                check_cancelled(&self.cancellation_token)?;
+                check_cost_limit(&self.usage_meter)?;
+                return Err(RunAgentError::TaskExecutionFailed(format!(
+                    "Task {} failed, aborting (agent.on_task_failure = abort).\n\n{}",
+                    failed,
+                    results.join("\n")
+                )));
+            }
+        }
 
-                        // Get task for notification
-                        if let Some(plan) = state.plan.as_ref()
-                            && let Some(task) = plan.tasks.iter().find(|t| t.id == task_id)
-                        {
-                            progress.on_action_retry(task, action_attempts, &feedback);
-                        }
+        results.extend(self.run_batch_review(input, state, progress).await?);
+
+        // Generate summary
+        let completed = state.plan.as_ref().map(|p| p.progress()).unwrap_or((0, 0));
+
+        Ok(format!(
+            "Completed {}/{} tasks.\n\n{}",
+            completed.0,
+            completed.1,
+            results.join("\n")
+        ))
+    }
+
+    /// Present the actions queued under [`HilMode::BatchReview`] to the user
+    /// and run the approved ones, in queue order.
+    ///
+    /// An action that is rejected, skipped or fails skips every later
+    /// action depending on it ([`ActionBatch::blocker`]). Without an
+    /// intervention handler every action is rejected (fail-secure). Each
+    /// action's execution is added to its task's `tool_executions`, and a
+    /// task with an action that did not execute is marked failed.
+    ///
+    /// Returns one summary line per queued action.
+    async fn run_batch_review(
+        &self,
+        input: &RunAgentInput,
+        state: &mut AgentState,
+        progress: &dyn AgentProgressNotifier,
+    ) -> Result<Vec<String>, RunAgentError> {
+        let PendingBatch { batch, executions } =
+            std::mem::take(&mut *self.pending_batch.lock().unwrap());
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
 
-                        info!(
-                            "Action rejected (attempt {}), retrying with feedback...",
-                            action_attempts
+        info!("Batch review: {} queued high-risk action(s)", batch.len());
+        let decisions = match &self.human_intervention {
+            Some(intervention) => {
+                match intervention
+                    .request_batch_approval(&input.request, batch.actions())
+                    .await
+                {
+                    Ok(decisions) => decisions,
+                    // Only a timeout resolves: answered actions keep their
+                    // decisions, the rest take the default
+                    Err(HumanInterventionError::TimedOut { mut answered }) => {
+                        warn!(
+                            "Batch review timed out after {}/{} decision(s) — the rest take the default ({})",
+                            answered.len(),
+                            batch.len(),
+                            input.policy.hil_timeout_default
                         );
-                        action_feedback = Some(feedback);
+                        answered.truncate(batch.len());
+                        answered.resize(batch.len(), input.policy.hil_timeout_default.decision());
+                        answered
+                    }
+                    // Cancellation and other failures abort the run
+                    Err(e) => {
+                        resolve_intervention(Err(e), &input.policy)?;
+                        Vec::new()
                     }
-                    other => break other,
                 }
-            };
+            }
+            None => {
+                warn!("No human intervention handler for batch review, rejecting all");
+                Vec::new()
+            }
+        };
 
-            // Update task status and store tool executions
-            let (success, output) = match task_result {
-                Ok((output, tool_executions)) => {
-                    // Store tool executions in the task
-                    if let Some(plan) = &mut state.plan
-                        && let Some(task) = plan.get_task_mut(&task_id)
-                    {
-                        task.tool_executions = tool_executions;
+        let plan = state.plan.clone().unwrap_or_else(|| Plan::new("", ""));
+        let output_limit = input.execution.context_budget.per_tool_output_chars();
+        let mut outcomes: Vec<BatchActionOutcome> = Vec::with_capacity(batch.len());
+        let mut lines = Vec::with_capacity(batch.len());
+
+        for (action, mut exec) in batch.actions().iter().zip(executions) {
+            let task_id_str = action.task_id.as_str();
+            let exec_id = exec.id.to_string();
+            let approved = matches!(decisions.get(action.index), Some(HumanDecision::Approve));
+
+            let outcome = if !approved {
+                BatchActionOutcome::Rejected
+            } else if let Some(blocked_by) = batch.blocker(&plan, action.index, &outcomes) {
+                BatchActionOutcome::Skipped { blocked_by }
+            } else {
+                check_cancelled(&self.cancellation_token)?;
+                exec.mark_running();
+                progress.on_tool_execution_started(task_id_str, &exec_id, &action.call.tool_name);
+                let result = self
+                    .tool_executor
+                    .execute(&action.call)
+                    .await
+                    .truncate_output(output_limit);
+                self.conversation_logger.log(ConversationEvent::new(
+                    "tool_result",
+                    serde_json::json!({
+                        "task_id": task_id_str,
+                        "tool": action.call.tool_name,
+                        "success": result.is_success(),
+                        "batched": true,
+                    }),
+                ));
+                if result.is_success() {
+                    exec.mark_completed(&result);
+                    let preview = result
+                        .output()
+                        .unwrap_or("")
+                        .chars()
+                        .take(100)
+                        .collect::<String>();
+                    progress.on_tool_execution_completed(
+                        task_id_str,
+                        &exec_id,
+                        &action.call.tool_name,
+                        exec.duration_ms().unwrap_or(0),
+                        &preview,
+                    );
+                    if let Some(diff) = &result.metadata.diff {
+                        progress.on_tool_execution_diff(task_id_str, &exec_id, diff);
                     }
-                    // Reset cascade counter on success
-                    state.reset_action_rejections();
-                    (true, output)
+                    BatchActionOutcome::Executed
+                } else {
+                    let message = result
+                        .error()
+                        .map(|e| e.message.clone())
+                        .unwrap_or_else(|| "Unknown error".to_string());
+                    exec.mark_error(&message);
+                    progress.on_tool_execution_failed(
+                        task_id_str,
+                        &exec_id,
+                        &action.call.tool_name,
+                        &message,
+                    );
+                    BatchActionOutcome::Failed(message)
                 }
-                Err(e) => (false, e.to_string()),
             };
 
+            let label = match &outcome {
+                BatchActionOutcome::Executed => "EXECUTED".to_string(),
+                BatchActionOutcome::Failed(error) => {
+                    format!("FAILED — {}", truncate_str(error, 150))
+                }
+                BatchActionOutcome::Rejected => "REJECTED in batch review".to_string(),
+                BatchActionOutcome::Skipped { blocked_by } => {
+                    format!("SKIPPED — depends on batched action {}", blocked_by + 1)
+                }
+            };
+            if matches!(
+                outcome,
+                BatchActionOutcome::Rejected | BatchActionOutcome::Skipped { .. }
+            ) {
+                exec.mark_running();
+                exec.mark_error(&label);
+                progress.on_tool_execution_failed(
+                    task_id_str,
+                    &exec_id,
+                    &action.call.tool_name,
+                    &label,
+                );
+            }
+            let line = format!(
+                "Batched action {} ({}, task {}): {}",
+                action.index + 1,
+                action.call.tool_name,
+                action.task_id,
+                label
+            );
+
+            state.record_tool_executions(std::slice::from_ref(&exec));
             if let Some(plan) = &mut state.plan
-                && let Some(task) = plan.get_task_mut(&task_id)
-                && !task.status.is_terminal()
+                && let Some(task) = plan.get_task_mut(&action.task_id)
             {
-                if success {
-                    task.mark_completed(quorum_domain::TaskResult::success(&output));
-                } else {
-                    task.mark_failed(quorum_domain::TaskResult::failure(&output));
+                task.tool_executions.push(exec);
+                if outcome != BatchActionOutcome::Executed
+                    && task.status != quorum_domain::TaskStatus::Failed
+                {
+                    task.mark_failed(quorum_domain::TaskResult::failure(&line));
                 }
-                progress.on_task_complete(task, success, task_index, task_total);
             }
+            lines.push(line);
+            outcomes.push(outcome);
+        }
 
-            let status = if success { "OK" } else { "FAILED" };
-            let brief = if success {
-                extract_task_brief(&output, 150)
-                    .map(|b| format!(" — {}", b))
-                    .unwrap_or_default()
-            } else {
-                // For failures, the output IS the error message
-                let error_brief = truncate_str(&output, 150);
-                if error_brief.is_empty() {
-                    String::new()
-                } else {
-                    format!(" — {}", error_brief)
+        Ok(lines)
+    }
+
+    /// Choose the tasks to run in the next round.
+    ///
+    /// All ready tasks that may run in parallel, up to `max_parallel`. If
+    /// the first ready task may not (it can perform high-risk actions, which
+    /// go through quorum review one at a time), it runs alone.
+    fn select_round(&self, plan: &Plan, max_parallel: usize) -> Vec<TaskId> {
+        let ready = plan.ready_tasks();
+        let Some(first) = ready.first() else {
+            return Vec::new();
+        };
+        if max_parallel <= 1 || !self.can_run_in_parallel(first) {
+            return vec![first.id.clone()];
+        }
+        ready
+            .iter()
+            .filter(|t| self.can_run_in_parallel(t))
+            .take(max_parallel)
+            .map(|t| t.id.clone())
+            .collect()
+    }
+
+    /// A task may share a round when it needs no review and its planned
+    /// tool is low-risk. Tasks without a planned tool leave the choice to
+    /// the model, so they are treated as high-risk. Tasks sharing a round
+    /// are offered low-risk tools only (see [`Self::execute_task_native`]).
+    fn can_run_in_parallel(&self, task: &Task) -> bool {
+        !task.requires_review
+            && task.tool_name.as_ref().is_some_and(|tool| {
+                !self
+                    .action_reviewer
+                    .is_high_risk_tool(tool, &std::collections::HashMap::new())
+            })
+    }
+
+    /// Execute one task, retrying with the reviewer's feedback when an
+    /// action is rejected (up to `execution.max_action_retries` retries, i.e.
+    /// one more attempt than that).
+    ///
+    /// Returns the task's result, the tool executions of every attempt, and
+    /// the number of retries it took. `rejections` counts consecutive
+    /// rejections across the tasks of a round for cascade detection; a
+    /// successful task resets it.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_with_action_retries(
+        &self,
+        session: &dyn LlmSession,
+        input: &RunAgentInput,
+        state: &AgentState,
+        task_id: &TaskId,
+        parallel: bool,
+        result_buffer: &TaskResultBuffer,
+        rejections: &AtomicUsize,
+        progress: &dyn AgentProgressNotifier,
+    ) -> TaskRun {
+        let max_action_retries = input.execution.max_action_retries;
+        let mut action_attempts = 0;
+        let mut retries = 0;
+        let mut action_feedback: Option<String> = None;
+        let mut executions = Vec::new();
+        let mut retried_executions = Vec::new();
+
+        let result = loop {
+            retried_executions.append(&mut executions);
+
+            // Build context including any rejection feedback,
+            // with optional per-task ContextMode budget override
+            let task_budget = state
+                .plan
+                .as_ref()
+                .and_then(|p| p.tasks.iter().find(|t| &t.id == task_id))
+                .and_then(|t| t.context_mode)
+                .map(ContextBudget::for_context_mode);
+
+            let context_with_feedback = match &action_feedback {
+                Some(feedback) => {
+                    result_buffer.render_with_feedback(feedback, task_budget.as_ref())
                 }
+                None => result_buffer.render_with_budget(task_budget.as_ref()),
             };
-            results.push(format!(
-                "Task {} ({}): {}{}",
-                task_id,
-                truncate_str(&task_description, 60),
-                status,
-                brief,
-            ));
-            result_buffer.push(task_id.as_str(), &output);
-        }
 
-        // Generate summary
-        let completed = state.plan.as_ref().map(|p| p.progress()).unwrap_or((0, 0));
+            match self
+                .execute_single_task(
+                    session,
+                    input,
+                    state,
+                    task_id,
+                    parallel,
+                    &context_with_feedback,
+                    &mut executions,
+                    progress,
+                )
+                .await
+            {
+                Err(RunAgentError::ActionRejected(feedback)) => {
+                    action_attempts += 1;
 
-        Ok(format!(
-            "Completed {}/{} tasks.\n\n{}",
-            completed.0,
-            completed.1,
-            results.join("\n")
-        ))
+                    // Track consecutive rejections for cascade detection
+                    let rejection_count = rejections.fetch_add(1, Ordering::SeqCst) + 1;
+                    let cascade_action = input.policy.action_rejection_action(rejection_count);
+
+                    match cascade_action {
+                        quorum_domain::agent::agent_policy::HilAction::Abort => {
+                            warn!(
+                                "Action rejection cascade detected ({} consecutive). Aborting.",
+                                rejection_count
+                            );
+                            break Err(RunAgentError::ActionRejected(format!(
+                                "Rejection cascade: {} consecutive rejections exceeded limit. \
+                                 Last feedback: {}",
+                                rejection_count, feedback
+                            )));
+                        }
+                        quorum_domain::agent::agent_policy::HilAction::ForceApprove => {
+                            info!(
+                                "Action rejection cascade ({} consecutive): auto-approve mode, \
+                                 skipping review for next attempt.",
+                                rejection_count
+                            );
+                            // Reset and continue — next attempt won't be reviewed
+                            // (the tool-level review is still in place, but the cascade
+                            // count signals that we should let it through)
+                            action_feedback = Some(format!(
+                                "{}\n[NOTE: Cascade limit reached. Proceeding without review.]",
+                                feedback
+                            ));
+                            retries += 1;
+                            continue;
+                        }
+                        quorum_domain::agent::agent_policy::HilAction::RequestIntervention => {
+                            warn!(
+                                "Action rejection cascade ({} consecutive): requesting human intervention.",
+                                rejection_count
+                            );
+                            break Err(RunAgentError::ActionRejected(format!(
+                                "Rejection cascade: {} consecutive rejections. \
+                                 Human intervention required. Last feedback: {}",
+                                rejection_count, feedback
+                            )));
+                        }
+                        quorum_domain::agent::agent_policy::HilAction::Continue => {
+                            // Within retry limits — normal retry flow
+                        }
+                    }
+
+                    if action_attempts > max_action_retries {
+                        break Err(RunAgentError::ActionRejected(format!(
+                            "Action rejected after {} attempts. Last feedback: {}",
+                            action_attempts, feedback
+                        )));
+                    }
+
+                    // Get task for notification
+                    if let Some(plan) = state.plan.as_ref()
+                        && let Some(task) = plan.tasks.iter().find(|t| &t.id == task_id)
+                    {
+                        progress.on_action_retry(task, action_attempts, &feedback);
+                    }
+
+                    info!(
+                        "Action rejected (attempt {}), retrying with feedback...",
+                        action_attempts
+                    );
+                    action_feedback = Some(feedback);
+                    retries += 1;
+                }
+                Ok(output) => {
+                    // Success breaks any rejection cascade
+                    rejections.store(0, Ordering::SeqCst);
+                    break Ok(output);
+                }
+                other => break other,
+            }
+        };
+        TaskRun {
+            result,
+            executions,
+            retried_executions,
+            retries,
+        }
     }
 
     /// Determine the appropriate model for a task based on tool risk level.
@@ -374,16 +822,20 @@ impl ExecuteTaskUseCase {
 
     /// Execute a single task using the Native Tool Use API.
     ///
-    /// Returns `(output_text, tool_executions)` on success.
+    /// Returns the output text on success; tool executions are appended to
+    /// `executions` either way.
+    #[allow(clippy::too_many_arguments)]
     async fn execute_single_task(
         &self,
         session: &dyn LlmSession,
         input: &RunAgentInput,
         state: &AgentState,
         task_id: &TaskId,
+        parallel: bool,
         previous_results: &str,
+        executions: &mut Vec<ToolExecution>,
         progress: &dyn AgentProgressNotifier,
-    ) -> Result<(String, Vec<ToolExecution>), RunAgentError> {
+    ) -> Result<String, RunAgentError> {
         let task = state
             .plan
             .as_ref()
@@ -392,24 +844,40 @@ impl ExecuteTaskUseCase {
 
         debug!("Executing task: {} - {}", task.id, task.description);
 
-        self.execute_task_native(session, input, state, task, previous_results, progress)
-            .await
+        self.execute_task_native(
+            session,
+            input,
+            state,
+            task,
+            parallel,
+            previous_results,
+            executions,
+            progress,
+        )
+        .await
     }
 
     /// Execute a task using the Native Tool Use API with multi-turn loop.
     ///
-    /// Returns `(output_text, tool_executions)` — the output text is the
-    /// joined LLM text blocks, and tool_executions tracks each tool call's
-    /// lifecycle for state tracking and UI display.
+    /// Returns the joined LLM text blocks. Each tool call's lifecycle is
+    /// tracked in `executions` for state tracking and UI display — including
+    /// when the task fails or its actions are rejected.
+    ///
+    /// A `parallel` task shares its round with others, so it is offered
+    /// low-risk tools only and any high-risk call it makes anyway is refused:
+    /// action review and human prompts must not run concurrently.
+    #[allow(clippy::too_many_arguments)]
     async fn execute_task_native(
         &self,
         session: &dyn LlmSession,
         input: &RunAgentInput,
         state: &AgentState,
         task: &Task,
+        parallel: bool,
         previous_results: &str,
+        executions: &mut Vec<ToolExecution>,
         progress: &dyn AgentProgressNotifier,
-    ) -> Result<(String, Vec<ToolExecution>), RunAgentError> {
+    ) -> Result<String, RunAgentError> {
         let task_id_str = task.id.as_str();
         let prompt = AgentPromptTemplate::task_execution(task, &state.context, previous_results);
 
@@ -423,16 +891,20 @@ impl ExecuteTaskUseCase {
             }),
         ));
 
-        let tools = self
-            .tool_schema
-            .all_tools_schema(self.tool_executor.tool_spec());
+        let tools = if parallel {
+            self.tool_schema
+                .low_risk_tools_schema(self.tool_executor.tool_spec())
+        } else {
+            self.tool_schema
+                .all_tools_schema(self.tool_executor.tool_spec())
+        };
         let max_turns = input.execution.max_tool_turns;
+        let output_limit = input.execution.context_budget.per_tool_output_chars();
         let mut turn_count = 0;
         // Retry budget for text-only / leaked-tool-call responses (#268)
         const MAX_TOOL_NUDGES: usize = 2;
         let mut nudge_count = 0;
         let mut all_outputs = Vec::new();
-        let mut all_executions: Vec<ToolExecution> = Vec::new();
         let mut exec_counter: usize = 0;
 
         // Initial request
@@ -442,6 +914,7 @@ impl ExecuteTaskUseCase {
             &tools,
             progress,
             &self.cancellation_token,
+            &self.retry_policy,
         )
         .await
         {
@@ -530,6 +1003,7 @@ impl ExecuteTaskUseCase {
                         &tools,
                         progress,
                         &self.cancellation_token,
+                        &self.retry_policy,
                     )
                     .await
                     {
@@ -593,8 +1067,23 @@ impl ExecuteTaskUseCase {
                 break;
             }
 
-            // Check cancellation
+            // Check cancellation and the token limit
             check_cancelled(&self.cancellation_token)?;
+            check_cost_limit(&self.usage_meter)?;
+
+            // Recover hallucinated near-miss tool names before risk routing
+            let mut tool_calls = tool_calls;
+            resolve_tool_names(&mut tool_calls, self.tool_executor.tool_spec(), progress);
+
+            // Identical read-only calls in one turn run once; duplicates reuse the result
+            let (tool_calls, duplicate_calls) = dedupe_tool_calls(tool_calls);
+            if !duplicate_calls.is_empty() {
+                debug!(
+                    "Task {}: skipping {} duplicate tool call(s)",
+                    task.id,
+                    duplicate_calls.len()
+                );
+            }
 
             // Execute tool calls and collect results
             let mut tool_result_messages = Vec::new();
@@ -604,35 +1093,98 @@ impl ExecuteTaskUseCase {
             let mut high_risk_calls = Vec::new();
 
             for call in &tool_calls {
-                if self
+                let high_risk = self
                     .action_reviewer
-                    .is_high_risk_tool(&call.tool_name, &call.arguments)
-                {
-                    high_risk_calls.push(call);
-                } else {
-                    low_risk_calls.push(call);
-                }
-            }
-
-            // Execute low-risk calls in parallel
-            if !low_risk_calls.is_empty() {
-                // Create ToolExecutions for all low-risk calls (Pending state)
-                let mut exec_indices = Vec::new();
-                let mut futures = Vec::new();
-                for call in &low_risk_calls {
-                    // ToolCallBefore check: Lua filter can cancel tool calls
-                    if !self.check_tool_call_before(&call.tool_name, &call.arguments) {
-                        debug!(
-                            "Tool call {} cancelled by ToolCallBefore listener",
-                            call.tool_name
-                        );
-                        if let Some(native_id) = call.native_id.clone() {
-                            tool_result_messages.push(ToolResultMessage {
-                                tool_use_id: native_id,
-                                tool_name: call.tool_name.clone(),
-                                output: "Tool call cancelled by ToolCallBefore listener"
-                                    .to_string(),
-                                is_error: false,
+                    .is_high_risk_tool(&call.tool_name, &call.arguments);
+                // Command allow/deny lists: blocked commands never run, whatever
+                // their risk level (`tools.risk_overrides` can make them Low)
+                let blocked = command_policy_error(&input.policy, call)
+                    .map(|error| error.to_string())
+                    .or_else(|| {
+                        (parallel && high_risk).then(|| {
+                            format!(
+                                "`{}` is high-risk and not available while other tasks run \
+                                 in parallel; use low-risk tools only",
+                                call.tool_name
+                            )
+                        })
+                    });
+                if let Some(message) = blocked {
+                    warn!("Tool call {} blocked: {}", call.tool_name, message);
+                    exec_counter += 1;
+                    let exec_id = format!("{}-exec-{}", task_id_str, exec_counter);
+                    let mut exec = ToolExecution::new(
+                        exec_id.clone(),
+                        &call.tool_name,
+                        call.arguments.clone(),
+                        call.native_id.clone(),
+                        turn_count,
+                    );
+                    progress.on_tool_execution_created(
+                        task_id_str,
+                        &exec_id,
+                        &call.tool_name,
+                        turn_count,
+                        &tool_args_preview(call),
+                    );
+                    exec.mark_running();
+                    exec.mark_error(&message);
+                    progress.on_tool_execution_failed(
+                        task_id_str,
+                        &exec_id,
+                        &call.tool_name,
+                        &message,
+                    );
+                    executions.push(exec);
+
+                    self.conversation_logger.log(ConversationEvent::new(
+                        "tool_result",
+                        serde_json::json!({
+                            "task_id": task_id_str,
+                            "tool": call.tool_name,
+                            "success": false,
+                            "error": message,
+                        }),
+                    ));
+
+                    if let Some(native_id) = call.native_id.clone() {
+                        tool_result_messages.push(ToolResultMessage {
+                            tool_use_id: native_id,
+                            tool_name: call.tool_name.clone(),
+                            output: message,
+                            is_error: true,
+                            is_rejected: false,
+                        });
+                    }
+                    continue;
+                }
+
+                if high_risk {
+                    high_risk_calls.push(call);
+                } else {
+                    low_risk_calls.push(call);
+                }
+            }
+
+            // Execute low-risk calls in parallel
+            if !low_risk_calls.is_empty() {
+                // Create ToolExecutions for all low-risk calls (Pending state)
+                let mut exec_indices = Vec::new();
+                let mut futures = Vec::new();
+                for call in &low_risk_calls {
+                    // ToolCallBefore check: Lua filter can cancel tool calls
+                    if !self.check_tool_call_before(&call.tool_name, &call.arguments) {
+                        debug!(
+                            "Tool call {} cancelled by ToolCallBefore listener",
+                            call.tool_name
+                        );
+                        if let Some(native_id) = call.native_id.clone() {
+                            tool_result_messages.push(ToolResultMessage {
+                                tool_use_id: native_id,
+                                tool_name: call.tool_name.clone(),
+                                output: "Tool call cancelled by ToolCallBefore listener"
+                                    .to_string(),
+                                is_error: false,
                                 is_rejected: true,
                             });
                         }
@@ -660,8 +1212,8 @@ impl ExecuteTaskUseCase {
                     exec.mark_running();
                     progress.on_tool_execution_started(task_id_str, &exec_id, &call.tool_name);
 
-                    all_executions.push(exec);
-                    exec_indices.push(all_executions.len() - 1);
+                    executions.push(exec);
+                    exec_indices.push(executions.len() - 1);
 
                     self.conversation_logger.log(ConversationEvent::new(
                         "tool_call",
@@ -675,7 +1227,11 @@ impl ExecuteTaskUseCase {
                     futures.push(self.tool_executor.execute(call));
                 }
 
-                let results: Vec<_> = futures::future::join_all(futures).await;
+                let results: Vec<_> = futures::future::join_all(futures)
+                    .await
+                    .into_iter()
+                    .map(|result| result.truncate_output(output_limit))
+                    .collect();
 
                 for ((call, result), &exec_idx) in
                     low_risk_calls.iter().zip(results).zip(&exec_indices)
@@ -704,7 +1260,7 @@ impl ExecuteTaskUseCase {
                         .log(ConversationEvent::new("tool_result", tool_result_payload));
 
                     // Update ToolExecution state
-                    let exec = &mut all_executions[exec_idx];
+                    let exec = &mut executions[exec_idx];
                     let exec_id = exec.id.to_string();
                     if is_error {
                         exec.mark_error(&output);
@@ -714,6 +1270,13 @@ impl ExecuteTaskUseCase {
                             &call.tool_name,
                             &output,
                         );
+                        if let Some(error) = result.error() {
+                            progress.on_tool_error(
+                                &call.tool_name,
+                                ErrorCategory::from_error_code(&error.code),
+                                &error.message,
+                            );
+                        }
                     } else {
                         exec.mark_completed(&result);
                         let duration = exec.duration_ms().unwrap_or(0);
@@ -730,6 +1293,9 @@ impl ExecuteTaskUseCase {
                             duration,
                             &preview,
                         );
+                        if let Some(diff) = &result.metadata.diff {
+                            progress.on_tool_execution_diff(task_id_str, &exec_id, diff);
+                        }
                     }
 
                     if !is_error {
@@ -793,6 +1359,7 @@ impl ExecuteTaskUseCase {
                 );
 
                 // Action review for high-risk operations
+                let mut escalation = None;
                 let review_decision = {
                     let tool_call_json = serde_json::to_string_pretty(&serde_json::json!({
                         "tool": call.tool_name,
@@ -800,13 +1367,25 @@ impl ExecuteTaskUseCase {
                     }))
                     .unwrap_or_default();
 
-                    self.action_reviewer
+                    match self
+                        .action_reviewer
                         .review_action(&tool_call_json, task, state, &input.models, progress)
                         .await?
+                    {
+                        ReviewDecision::Escalate(reason) => {
+                            let decision = self
+                                .resolve_escalation(&reason, &tool_call_json, task, input)
+                                .await?;
+                            escalation = Some(reason);
+                            decision
+                        }
+                        decision => decision,
+                    }
                 };
 
                 match review_decision {
-                    ReviewDecision::Rejected(_) => {
+                    // Escalations were resolved above; never run an unresolved one
+                    ReviewDecision::Rejected(_) | ReviewDecision::Escalate(_) => {
                         warn!("Tool call {} rejected by action review", call.tool_name);
                         high_risk_rejected_count += 1;
                         exec.mark_running();
@@ -817,7 +1396,7 @@ impl ExecuteTaskUseCase {
                             &call.tool_name,
                             "Action rejected by quorum review",
                         );
-                        all_executions.push(exec);
+                        executions.push(exec);
 
                         self.conversation_logger.log(ConversationEvent::new(
                             "tool_call",
@@ -861,6 +1440,40 @@ impl ExecuteTaskUseCase {
                     }
                 }
 
+                if input.policy.hil_mode == HilMode::BatchReview {
+                    debug!("Tool call {} queued for batch review", call.tool_name);
+                    {
+                        let mut pending = self.pending_batch.lock().unwrap();
+                        let (task_id, call) = (task.id.clone(), (*call).clone());
+                        match escalation {
+                            Some(reason) => pending.batch.push_escalated(task_id, call, reason),
+                            None => pending.batch.push(task_id, call),
+                        };
+                        pending.executions.push(exec);
+                    }
+                    self.conversation_logger.log(ConversationEvent::new(
+                        "tool_call",
+                        serde_json::json!({
+                            "task_id": task_id_str,
+                            "tool": call.tool_name,
+                            "args": call.arguments,
+                            "risk": "high",
+                            "queued": true,
+                        }),
+                    ));
+                    all_outputs.push(format!("[{}]: queued for batch review", call.tool_name));
+                    if let Some(native_id) = call.native_id.clone() {
+                        tool_result_messages.push(ToolResultMessage {
+                            tool_use_id: native_id,
+                            tool_name: call.tool_name.clone(),
+                            output: QUEUED_FOR_BATCH_REVIEW.to_string(),
+                            is_error: false,
+                            is_rejected: false,
+                        });
+                    }
+                    continue;
+                }
+
                 // Transition to Running
                 exec.mark_running();
                 progress.on_tool_execution_started(task_id_str, &exec_id, &call.tool_name);
@@ -875,7 +1488,11 @@ impl ExecuteTaskUseCase {
                     }),
                 ));
 
-                let result = self.tool_executor.execute(call).await;
+                let result = self
+                    .tool_executor
+                    .execute(call)
+                    .await
+                    .truncate_output(output_limit);
                 let is_error = !result.is_success();
                 let output = if is_error {
                     result
@@ -908,6 +1525,13 @@ impl ExecuteTaskUseCase {
                         &call.tool_name,
                         &output,
                     );
+                    if let Some(error) = result.error() {
+                        progress.on_tool_error(
+                            &call.tool_name,
+                            ErrorCategory::from_error_code(&error.code),
+                            &error.message,
+                        );
+                    }
                 } else {
                     exec.mark_completed(&result);
                     let duration = exec.duration_ms().unwrap_or(0);
@@ -924,8 +1548,11 @@ impl ExecuteTaskUseCase {
                         duration,
                         &preview,
                     );
+                    if let Some(diff) = &result.metadata.diff {
+                        progress.on_tool_execution_diff(task_id_str, &exec_id, diff);
+                    }
                 }
-                all_executions.push(exec);
+                executions.push(exec);
 
                 if !is_error {
                     all_outputs.push(format!("[{}]: {}", call.tool_name, output));
@@ -958,6 +1585,20 @@ impl ExecuteTaskUseCase {
                 ));
             }
 
+            // Every duplicate native ID still needs its own result message
+            for (duplicate_id, original_id) in &duplicate_calls {
+                if let Some(original) = tool_result_messages
+                    .iter()
+                    .find(|m| m.tool_use_id == *original_id)
+                    .cloned()
+                {
+                    tool_result_messages.push(ToolResultMessage {
+                        tool_use_id: duplicate_id.clone(),
+                        ..original
+                    });
+                }
+            }
+
             // Send tool results back to LLM for next turn
             debug!(
                 "Native tool use turn {}/{}: sending {} tool results",
@@ -972,7 +1613,7 @@ impl ExecuteTaskUseCase {
                 .map_err(RunAgentError::GatewayError)?;
         }
 
-        Ok((all_outputs.join("\n---\n"), all_executions))
+        Ok(all_outputs.join("\n---\n"))
     }
 }
 
@@ -1031,7 +1672,7 @@ mod tests {
     use quorum_domain::tool::entities::{
         RiskLevel, ToolCall, ToolDefinition, ToolParameter, ToolSpec,
     };
-    use quorum_domain::tool::value_objects::ToolResult;
+    use quorum_domain::tool::value_objects::{ToolError, ToolResult};
     use quorum_domain::{AgentPolicy, ConsensusLevel, PhaseScope, Plan, SessionMode};
     use std::collections::{HashMap, VecDeque};
     use std::sync::Mutex;
@@ -1040,6 +1681,7 @@ mod tests {
     struct QueueSession {
         model: Model,
         responses: Arc<Mutex<VecDeque<LlmResponse>>>,
+        sent_results: Arc<Mutex<Vec<ToolResultMessage>>>,
     }
 
     impl QueueSession {
@@ -1072,14 +1714,20 @@ mod tests {
 
         async fn send_tool_results(
             &self,
-            _results: &[ToolResultMessage],
+            results: &[ToolResultMessage],
         ) -> Result<LlmResponse, GatewayError> {
+            self.sent_results
+                .lock()
+                .unwrap()
+                .extend(results.iter().cloned());
             Ok(self.pop())
         }
     }
 
     struct QueueGateway {
         responses: Arc<Mutex<VecDeque<LlmResponse>>>,
+        /// Every tool result message sent back by any session
+        sent_results: Arc<Mutex<Vec<ToolResultMessage>>>,
     }
 
     #[async_trait]
@@ -1088,6 +1736,7 @@ mod tests {
             Ok(Box::new(QueueSession {
                 model: model.clone(),
                 responses: self.responses.clone(),
+                sent_results: self.sent_results.clone(),
             }))
         }
 
@@ -1105,20 +1754,33 @@ mod tests {
     }
 
     /// Executor whose spec has `run_command` (required param `command`),
-    /// matching the #268 reproduction. Records executed tool names.
+    /// matching the #268 reproduction, plus `read_file`. Records executed
+    /// tool names.
     struct RecordingToolExecutor {
         spec: ToolSpec,
         calls: Mutex<Vec<String>>,
+        /// Diff attached to every successful result's metadata
+        result_diff: Option<String>,
+        /// Error returned by `execute` instead of succeeding
+        fail_with: Option<ToolError>,
     }
 
     impl RecordingToolExecutor {
         fn new() -> Self {
             Self {
-                spec: ToolSpec::new().register(
-                    ToolDefinition::new("run_command", "Run a shell command", RiskLevel::High)
-                        .with_parameter(ToolParameter::new("command", "Command to run", true)),
-                ),
+                spec: ToolSpec::new()
+                    .register(
+                        ToolDefinition::new("run_command", "Run a shell command", RiskLevel::High)
+                            .with_parameter(ToolParameter::new("command", "Command to run", true)),
+                    )
+                    .register(ToolDefinition::new(
+                        "read_file",
+                        "Read a file",
+                        RiskLevel::Low,
+                    )),
                 calls: Mutex::new(Vec::new()),
+                result_diff: None,
+                fail_with: None,
             }
         }
     }
@@ -1131,7 +1793,12 @@ mod tests {
 
         async fn execute(&self, call: &ToolCall) -> ToolResult {
             self.calls.lock().unwrap().push(call.tool_name.clone());
-            ToolResult::success(&call.tool_name, "ok")
+            if let Some(error) = &self.fail_with {
+                return ToolResult::failure(&call.tool_name, error.clone());
+            }
+            let mut result = ToolResult::success(&call.tool_name, "ok");
+            result.metadata.diff = self.result_diff.clone();
+            result
         }
 
         fn execute_sync(&self, call: &ToolCall) -> ToolResult {
@@ -1183,22 +1850,108 @@ mod tests {
         }
     }
 
+    /// Reviewer that treats everything as high-risk and approves it.
+    struct ApprovingHighRiskReviewer;
+
+    #[async_trait]
+    impl ActionReviewer for ApprovingHighRiskReviewer {
+        async fn review_action(
+            &self,
+            _tool_call_json: &str,
+            _task: &Task,
+            _state: &AgentState,
+            _models: &ModelConfig,
+            _progress: &dyn AgentProgressNotifier,
+        ) -> Result<ReviewDecision, RunAgentError> {
+            Ok(ReviewDecision::Approved)
+        }
+
+        fn is_high_risk_tool(
+            &self,
+            _tool_name: &str,
+            _arguments: &HashMap<String, serde_json::Value>,
+        ) -> bool {
+            true
+        }
+    }
+
+    /// Reviewer that treats everything as high-risk and rejects it.
+    struct RejectingHighRiskReviewer;
+
+    #[async_trait]
+    impl ActionReviewer for RejectingHighRiskReviewer {
+        async fn review_action(
+            &self,
+            _tool_call_json: &str,
+            _task: &Task,
+            _state: &AgentState,
+            _models: &ModelConfig,
+            _progress: &dyn AgentProgressNotifier,
+        ) -> Result<ReviewDecision, RunAgentError> {
+            Ok(ReviewDecision::Rejected("too risky".to_string()))
+        }
+
+        fn is_high_risk_tool(
+            &self,
+            _tool_name: &str,
+            _arguments: &HashMap<String, serde_json::Value>,
+        ) -> bool {
+            true
+        }
+    }
+
     struct NoopProgress;
     impl AgentProgressNotifier for NoopProgress {}
 
+    /// Records diffs reported via `on_tool_execution_diff`.
+    #[derive(Default)]
+    struct DiffProgress {
+        diffs: Mutex<Vec<String>>,
+    }
+
+    impl AgentProgressNotifier for DiffProgress {
+        fn on_tool_execution_diff(&self, _task_id: &str, _execution_id: &str, diff: &str) {
+            self.diffs.lock().unwrap().push(diff.to_string());
+        }
+    }
+
+    /// Records categorized errors reported via `on_tool_error`.
+    #[derive(Default)]
+    struct ErrorProgress {
+        errors: Mutex<Vec<(String, ErrorCategory)>>,
+    }
+
+    impl AgentProgressNotifier for ErrorProgress {
+        fn on_tool_error(&self, tool_name: &str, category: ErrorCategory, _message: &str) {
+            self.errors
+                .lock()
+                .unwrap()
+                .push((tool_name.to_string(), category));
+        }
+    }
+
     fn make_use_case(
         responses: Vec<LlmResponse>,
         executor: Arc<RecordingToolExecutor>,
+    ) -> ExecuteTaskUseCase {
+        make_use_case_with_reviewer(responses, executor, Arc::new(LowRiskReviewer))
+    }
+
+    fn make_use_case_with_reviewer(
+        responses: Vec<LlmResponse>,
+        executor: Arc<RecordingToolExecutor>,
+        reviewer: Arc<dyn ActionReviewer>,
     ) -> ExecuteTaskUseCase {
         let gateway = Arc::new(QueueGateway {
             responses: Arc::new(Mutex::new(responses.into())),
+            sent_results: Arc::new(Mutex::new(Vec::new())),
         });
         ExecuteTaskUseCase::new(
             gateway,
             executor,
             Arc::new(StubToolSchema),
             None,
-            Arc::new(LowRiskReviewer),
+            reviewer,
             Arc::new(NoConversationLogger),
         )
     }
@@ -1216,10 +1969,15 @@ mod tests {
             ExecutionParams {
                 max_iterations: 10,
                 max_tool_turns: 5,
+                ask_max_tool_turns: None,
                 max_tool_retries: 2,
+                max_action_retries: 1,
                 working_dir: None,
                 ensemble_session_timeout: None,
+                review_timeout: None,
                 context_budget: ContextBudget::default(),
+                max_parallel_tasks: 4,
+                max_cost_tokens: None,
             },
         )
     }
@@ -1240,8 +1998,12 @@ mod tests {
     }
 
     fn tool_use_response() -> LlmResponse {
+        command_response("ls")
+    }
+
+    fn command_response(command: &str) -> LlmResponse {
         let mut arguments = HashMap::new();
-        arguments.insert("command".to_string(), serde_json::json!("ls"));
+        arguments.insert("command".to_string(), serde_json::json!(command));
         LlmResponse {
             content: vec![ContentBlock::ToolUse {
                 id: "toolu_1".to_string(),
@@ -1250,6 +2012,7 @@ mod tests {
             }],
             stop_reason: Some(StopReason::ToolUse),
             model: None,
+            usage: None,
         }
     }
 
@@ -1285,6 +2048,101 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn tool_result_diff_is_reported_to_progress() {
+        let mut executor = RecordingToolExecutor::new();
+        executor.result_diff = Some("--- a/x\n+++ b/x\n@@ -1,1 +1,1 @@\n-a\n+b\n".to_string());
+        let executor = Arc::new(executor);
+        let use_case = make_use_case(
+            vec![tool_use_response(), LlmResponse::from_text("Done.")],
+            executor,
+        );
+        let input = test_input();
+        let mut state = test_state(&input, Task::new("1", "Edit x"));
+        let progress = DiffProgress::default();
+
+        use_case
+            .execute(&input, &mut state, "system", &progress)
+            .await
+            .expect("should succeed");
+
+        assert_eq!(
+            progress.diffs.lock().unwrap().as_slice(),
+            &["--- a/x\n+++ b/x\n@@ -1,1 +1,1 @@\n-a\n+b\n".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_tool_arguments_are_reported_as_validation_errors() {
+        let mut executor = RecordingToolExecutor::new();
+        executor.fail_with = Some(ToolError::invalid_argument(
+            "Missing required parameter 'command' for tool 'run_command'",
+        ));
+        let use_case = make_use_case(
+            vec![tool_use_response(), LlmResponse::from_text("Done.")],
+            Arc::new(executor),
+        );
+        let input = test_input();
+        let mut state = test_state(&input, Task::new("1", "List crates"));
+        let progress = ErrorProgress::default();
+
+        use_case
+            .execute(&input, &mut state, "system", &progress)
+            .await
+            .expect("should succeed");
+
+        assert_eq!(
+            progress.errors.lock().unwrap().as_slice(),
+            &[("run_command".to_string(), ErrorCategory::ValidationError)]
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_tool_calls_run_once_but_each_gets_a_result() {
+        let mut arguments = HashMap::new();
+        arguments.insert("path".to_string(), serde_json::json!("Cargo.toml"));
+        let call = |id: &str| ContentBlock::ToolUse {
+            id: id.to_string(),
+            name: "read_file".to_string(),
+            input: arguments.clone(),
+        };
+        let duplicated = LlmResponse {
+            content: vec![call("toolu_1"), call("toolu_2")],
+            stop_reason: Some(StopReason::ToolUse),
+            model: None,
+            usage: None,
+        };
+        let sent_results = Arc::new(Mutex::new(Vec::new()));
+        let gateway = Arc::new(QueueGateway {
+            responses: Arc::new(Mutex::new(
+                vec![duplicated, LlmResponse::from_text("Read.")].into(),
+            )),
+            sent_results: sent_results.clone(),
+        });
+        let executor = Arc::new(RecordingToolExecutor::new());
+        let use_case = ExecuteTaskUseCase::new(
+            gateway,
+            executor.clone(),
+            Arc::new(StubToolSchema),
+            None,
+            Arc::new(LowRiskReviewer),
+            Arc::new(NoConversationLogger),
+        );
+        let input = test_input();
+        let mut state = test_state(&input, Task::new("1", "Read the manifest"));
+
+        use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await
+            .expect("should succeed");
+
+        assert_eq!(executor.calls.lock().unwrap().len(), 1);
+        let sent = sent_results.lock().unwrap();
+        let ids: Vec<_> = sent.iter().map(|m| m.tool_use_id.as_str()).collect();
+        assert_eq!(ids, vec!["toolu_1", "toolu_2"]);
+        assert!(sent.iter().all(|m| m.output == "ok" && !m.is_error));
+    }
+
     #[tokio::test]
     async fn leaked_json_fails_task_when_nudges_exhausted() {
         let executor = Arc::new(RecordingToolExecutor::new());
@@ -1363,4 +2221,522 @@ mod tests {
         assert!(summary.contains("Completed 1/1"), "summary: {}", summary);
         assert!(summary.contains("The workspace contains 5 crates"));
     }
+
+    // ==================== Command allow/deny lists ====================
+
+    /// Run one high-risk `run_command` call under `policy` and return the
+    /// executed tool names and the task output.
+    async fn run_command_under_policy(policy: AgentPolicy, command: &str) -> (Vec<String>, String) {
+        run_command_with_reviewer(policy, command, Arc::new(ApprovingHighRiskReviewer)).await
+    }
+
+    async fn run_command_with_reviewer(
+        policy: AgentPolicy,
+        command: &str,
+        reviewer: Arc<dyn ActionReviewer>,
+    ) -> (Vec<String>, String) {
+        let executor = Arc::new(RecordingToolExecutor::new());
+        let use_case = make_use_case_with_reviewer(
+            vec![command_response(command), LlmResponse::from_text("Done.")],
+            executor.clone(),
+            reviewer,
+        );
+        let mut input = test_input();
+        input.policy = policy;
+        let mut state = test_state(&input, Task::new("1", "Run a command"));
+
+        use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await
+            .expect("should succeed");
+
+        let task = &state.plan.as_ref().unwrap().tasks[0];
+        let calls = executor.calls.lock().unwrap().clone();
+        let exec_error = task
+            .tool_executions
+            .first()
+            .and_then(|e| match &e.state {
+                quorum_domain::ToolExecutionState::Error { error_message, .. } => {
+                    Some(error_message.clone())
+                }
+                _ => None,
+            })
+            .unwrap_or_default();
+        (calls, exec_error)
+    }
+
+    #[tokio::test]
+    async fn denylisted_command_is_never_executed() {
+        let policy = AgentPolicy::default().with_command_denylist(vec!["rm -rf *".to_string()]);
+
+        let (calls, error) = run_command_under_policy(policy, "rm -rf /").await;
+
+        assert!(calls.is_empty());
+        assert!(error.contains("PERMISSION_DENIED"), "error: {}", error);
+        assert!(error.contains("rm -rf *"), "error: {}", error);
+    }
+
+    #[tokio::test]
+    async fn denylist_applies_to_commands_overridden_to_low_risk() {
+        use crate::ports::event_publisher::NoEventPublisher;
+        use crate::use_cases::run_agent::review::QuorumActionReviewer;
+        use quorum_domain::tool::entities::RiskLevel;
+
+        let reviewer = QuorumActionReviewer::new(
+            Arc::new(QueueGateway {
+                responses: Arc::new(Mutex::new(VecDeque::new())),
+                sent_results: Arc::new(Mutex::new(Vec::new())),
+            }),
+            Arc::new(RecordingToolExecutor::new()),
+            None,
+            Arc::new(NoEventPublisher),
+        )
+        .with_risk_overrides(HashMap::from([("rm".to_string(), RiskLevel::Low)]));
+        let args = HashMap::from([("command".to_string(), serde_json::json!("rm -rf build"))]);
+        assert!(!reviewer.is_high_risk_tool("run_command", &args));
+        let policy = AgentPolicy::default().with_command_denylist(vec!["rm -rf *".to_string()]);
+
+        let (calls, error) =
+            run_command_with_reviewer(policy, "rm -rf build", Arc::new(reviewer)).await;
+
+        assert!(calls.is_empty());
+        assert!(error.contains("PERMISSION_DENIED"), "error: {}", error);
+    }
+
+    #[tokio::test]
+    async fn command_outside_allowlist_is_blocked() {
+        let policy = AgentPolicy::default().with_command_allowlist(vec!["cargo *".to_string()]);
+
+        let (calls, error) = run_command_under_policy(policy, "curl example.com").await;
+
+        assert!(calls.is_empty());
+        assert!(error.contains("allowlist"), "error: {}", error);
+    }
+
+    #[tokio::test]
+    async fn empty_command_lists_do_not_restrict() {
+        let (calls, error) = run_command_under_policy(AgentPolicy::default(), "rm -rf /").await;
+
+        assert_eq!(calls, vec!["run_command".to_string()]);
+        assert!(error.is_empty());
+    }
+
+    // ==================== Parallel execution over depends_on ====================
+
+    /// Records task start/completion order.
+    #[derive(Default)]
+    struct OrderProgress {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl AgentProgressNotifier for OrderProgress {
+        fn on_task_start(&self, task: &Task, _index: usize, _total: usize) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("start {}", task.id));
+        }
+
+        fn on_task_complete(&self, task: &Task, _success: bool, _index: usize, _total: usize) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("done {}", task.id));
+        }
+    }
+
+    /// 1 → {2, 3} → 4, every task planned with `read_file`.
+    fn diamond_state(input: &RunAgentInput) -> AgentState {
+        let mut state = input.to_agent_state("agent-test");
+        let plan = Plan::new("Diamond", "test")
+            .with_task(Task::new("1", "Root").with_tool("read_file"))
+            .with_task(
+                Task::new("2", "Left")
+                    .with_tool("read_file")
+                    .with_dependency("1"),
+            )
+            .with_task(
+                Task::new("3", "Right")
+                    .with_tool("read_file")
+                    .with_dependency("1"),
+            )
+            .with_task(
+                Task::new("4", "Join")
+                    .with_tool("read_file")
+                    .with_dependency("2")
+                    .with_dependency("3"),
+            );
+        state.set_plan(plan);
+        state
+    }
+
+    async fn run_diamond(reviewer: Arc<dyn ActionReviewer>) -> (String, Vec<String>) {
+        let use_case = make_use_case_with_reviewer(
+            (0..4).map(|_| LlmResponse::from_text("Done.")).collect(),
+            Arc::new(RecordingToolExecutor::new()),
+            reviewer,
+        );
+        let input = test_input();
+        let mut state = diamond_state(&input);
+        let progress = OrderProgress::default();
+
+        let summary = use_case
+            .execute(&input, &mut state, "system", &progress)
+            .await
+            .expect("should succeed");
+        let events = progress.events.into_inner().unwrap();
+        (summary, events)
+    }
+
+    #[tokio::test]
+    async fn diamond_runs_independent_branches_together() {
+        let (summary, events) = run_diamond(Arc::new(LowRiskReviewer)).await;
+
+        assert!(summary.contains("Completed 4/4"), "summary: {}", summary);
+        assert_eq!(
+            events,
+            vec![
+                "start 1", "done 1", // root alone
+                "start 2", "start 3", "done 2", "done 3", // branches together
+                "start 4", "done 4", // join after both
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn high_risk_tasks_run_one_at_a_time() {
+        let (summary, events) = run_diamond(Arc::new(ApprovingHighRiskReviewer)).await;
+
+        assert!(summary.contains("Completed 4/4"), "summary: {}", summary);
+        assert_eq!(
+            events,
+            vec![
+                "start 1", "done 1", "start 2", "done 2", "start 3", "done 3", "start 4", "done 4",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn parallel_task_cannot_call_high_risk_tools() {
+        use crate::ports::event_publisher::NoEventPublisher;
+        use crate::use_cases::run_agent::review::QuorumActionReviewer;
+
+        let executor = Arc::new(RecordingToolExecutor {
+            spec: ToolSpec::new()
+                .register(
+                    ToolDefinition::new("read_file", "Read a file", RiskLevel::Low)
+                        .with_parameter(ToolParameter::new("path", "File path", true)),
+                )
+                .register(
+                    ToolDefinition::new("write_file", "Write a file", RiskLevel::High)
+                        .with_parameter(ToolParameter::new("path", "File path", true)),
+                ),
+            ..RecordingToolExecutor::new()
+        });
+        let reviewer = QuorumActionReviewer::new(
+            Arc::new(QueueGateway {
+                responses: Arc::new(Mutex::new(VecDeque::new())),
+                sent_results: Arc::new(Mutex::new(Vec::new())),
+            }),
+            executor.clone(),
+            None,
+            Arc::new(NoEventPublisher),
+        );
+        let write = LlmResponse {
+            content: vec![ContentBlock::ToolUse {
+                id: "toolu_1".to_string(),
+                name: "write_file".to_string(),
+                input: HashMap::from([("path".to_string(), serde_json::json!("out.txt"))]),
+            }],
+            stop_reason: Some(StopReason::ToolUse),
+            model: None,
+            usage: None,
+        };
+        let sent_results = Arc::new(Mutex::new(Vec::new()));
+        let gateway = Arc::new(QueueGateway {
+            responses: Arc::new(Mutex::new(
+                vec![
+                    write,                              // task 1 tries to write
+                    LlmResponse::from_text("Read."),    // task 2
+                    LlmResponse::from_text("Gave up."), // task 1 after the refusal
+                ]
+                .into(),
+            )),
+            sent_results: sent_results.clone(),
+        });
+        let use_case = ExecuteTaskUseCase::new(
+            gateway,
+            executor.clone(),
+            Arc::new(StubToolSchema),
+            None,
+            Arc::new(reviewer),
+            Arc::new(NoConversationLogger),
+        );
+        let input = test_input();
+        let mut state = input.to_agent_state("agent-test");
+        state.set_plan(
+            Plan::new("Parallel", "test")
+                .with_task(Task::new("1", "Read a").with_tool("read_file"))
+                .with_task(Task::new("2", "Read b").with_tool("read_file")),
+        );
+        let progress = OrderProgress::default();
+
+        use_case
+            .execute(&input, &mut state, "system", &progress)
+            .await
+            .expect("should succeed");
+
+        // Both tasks shared a round, and write_file never ran
+        assert_eq!(
+            progress.events.into_inner().unwrap()[..2],
+            ["start 1", "start 2"]
+        );
+        assert!(executor.calls.lock().unwrap().is_empty());
+        let sent = sent_results.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].is_error);
+        assert!(sent[0].output.contains("in parallel"), "{}", sent[0].output);
+    }
+
+    #[tokio::test]
+    async fn dependency_cycle_is_a_planning_error() {
+        let use_case = make_use_case(vec![], Arc::new(RecordingToolExecutor::new()));
+        let input = test_input();
+        let mut state = input.to_agent_state("agent-test");
+        state.set_plan(
+            Plan::new("Cycle", "test")
+                .with_task(Task::new("1", "First").with_dependency("2"))
+                .with_task(Task::new("2", "Second").with_dependency("1")),
+        );
+
+        let err = use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(err, RunAgentError::PlanningFailed(ref msg) if msg.contains("1 → 2 → 1")),
+            "error: {}",
+            err
+        );
+        // Nothing was started
+        assert!(
+            state
+                .plan
+                .as_ref()
+                .unwrap()
+                .tasks
+                .iter()
+                .all(|t| t.status == quorum_domain::TaskStatus::Pending)
+        );
+    }
+
+    // ==================== Action retry budget ====================
+
+    #[tokio::test]
+    async fn configured_action_retries_are_honored_and_reported() {
+        let executor = Arc::new(RecordingToolExecutor::new());
+        let use_case = make_use_case_with_reviewer(
+            vec![
+                command_response("rm -rf build"), // attempt 1: rejected
+                command_response("rm -rf build"), // attempt 2: rejected
+                command_response("rm -rf build"), // attempt 3: rejected → failed
+                LlmResponse::from_text("Listed the crates."),
+            ],
+            executor.clone(),
+            Arc::new(RejectingHighRiskReviewer),
+        );
+        let mut input = test_input();
+        input.execution.max_action_retries = 2;
+        input.policy = AgentPolicy::default().with_max_action_rejections(10);
+        let mut state = input.to_agent_state("agent-test");
+        state.set_plan(
+            Plan::new("Clean and list", "test")
+                .with_task(Task::new("1", "Clean the build"))
+                .with_task(Task::new("2", "List crates")),
+        );
+
+        let summary = use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await
+            .expect("a failed task does not abort the plan");
+
+        // Three attempts, i.e. two retries, and nothing was executed
+        assert!(executor.calls.lock().unwrap().is_empty());
+        assert_eq!(state.task_retries(&TaskId::new("1")), 2);
+        assert_eq!(state.task_retries(&TaskId::new("2")), 0);
+        assert!(
+            summary.contains("Task 1 (Clean the build): FAILED (2 retries)"),
+            "summary: {}",
+            summary
+        );
+        assert!(
+            summary.contains("Task 2 (List crates): OK — Listed the crates."),
+            "summary: {}",
+            summary
+        );
+        let tasks = &state.plan.as_ref().unwrap().tasks;
+        assert_eq!(tasks[0].status, quorum_domain::TaskStatus::Failed);
+        assert_eq!(tasks[1].status, quorum_domain::TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn zero_action_retries_fails_on_first_rejection() {
+        let use_case = make_use_case_with_reviewer(
+            vec![
+                command_response("rm -rf build"), // attempt 1: rejected → failed
+                command_response("rm -rf build"),
+            ],
+            Arc::new(RecordingToolExecutor::new()),
+            Arc::new(RejectingHighRiskReviewer),
+        );
+        let mut input = test_input();
+        input.execution.max_action_retries = 0;
+        let mut state = test_state(&input, Task::new("1", "Clean the build"));
+
+        let summary = use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await
+            .expect("a failed task does not abort the plan");
+
+        assert_eq!(state.task_retries(&TaskId::new("1")), 0);
+        assert!(
+            summary.contains("Task 1 (Clean the build): FAILED — "),
+            "summary: {}",
+            summary
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_calls_are_counted_in_tool_stats() {
+        let use_case = make_use_case_with_reviewer(
+            vec![
+                command_response("rm -rf build"), // attempt 1: rejected
+                command_response("rm -rf build"), // attempt 2: rejected → failed
+            ],
+            Arc::new(RecordingToolExecutor::new()),
+            Arc::new(RejectingHighRiskReviewer),
+        );
+        let input = test_input();
+        let mut state = test_state(&input, Task::new("1", "Clean the build"));
+
+        use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await
+            .expect("a failed task does not abort the plan");
+
+        let task = &state.plan.as_ref().unwrap().tasks[0];
+        assert_eq!(task.status, quorum_domain::TaskStatus::Failed);
+        // The failed task keeps the calls of its last attempt
+        assert_eq!(task.tool_executions.len(), 1);
+        assert_eq!(
+            state.tool_stats["run_command"],
+            quorum_domain::ToolStat {
+                calls: 2,
+                successes: 0,
+                failures: 2,
+            }
+        );
+    }
+
+    // ==================== Task failure policy ====================
+
+    /// A (fails: every action rejected), B depends on A, C is independent.
+    async fn run_with_failure_policy(
+        policy: FailurePolicy,
+        responses: Vec<LlmResponse>,
+    ) -> (Result<String, RunAgentError>, AgentState) {
+        let use_case = make_use_case_with_reviewer(
+            responses,
+            Arc::new(RecordingToolExecutor::new()),
+            Arc::new(RejectingHighRiskReviewer),
+        );
+        let mut input = test_input();
+        input.policy = AgentPolicy::default()
+            .with_max_action_rejections(10)
+            .with_on_task_failure(policy);
+        let mut state = input.to_agent_state("agent-test");
+        state.set_plan(
+            Plan::new("Failure policy", "test")
+                .with_task(Task::new("A", "Delete the build dir"))
+                .with_task(Task::new("B", "Rebuild").with_dependency("A"))
+                .with_task(Task::new("C", "Update the README")),
+        );
+        let result = use_case
+            .execute(&input, &mut state, "system", &NoopProgress)
+            .await;
+        (result, state)
+    }
+
+    fn statuses(state: &AgentState) -> Vec<quorum_domain::TaskStatus> {
+        state
+            .plan
+            .as_ref()
+            .unwrap()
+            .tasks
+            .iter()
+            .map(|t| t.status.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn continue_all_runs_dependents_of_a_failed_task() {
+        use quorum_domain::TaskStatus::{Completed, Failed};
+        let (result, state) = run_with_failure_policy(
+            FailurePolicy::ContinueAll,
+            vec![
+                command_response("rm -rf build"),
+                command_response("rm -rf build"),
+                LlmResponse::from_text("Rebuilt."),
+                LlmResponse::from_text("README updated."),
+            ],
+        )
+        .await;
+
+        let summary = result.unwrap();
+        assert!(!summary.contains("SKIPPED"), "summary: {}", summary);
+        assert_eq!(statuses(&state), vec![Failed, Completed, Completed]);
+    }
+
+    #[tokio::test]
+    async fn continue_independent_skips_dependents_only() {
+        use quorum_domain::TaskStatus::{Completed, Failed, Skipped};
+        let (result, state) = run_with_failure_policy(
+            FailurePolicy::ContinueIndependent,
+            vec![
+                command_response("rm -rf build"),
+                command_response("rm -rf build"),
+                LlmResponse::from_text("README updated."),
+            ],
+        )
+        .await;
+
+        let summary = result.unwrap();
+        assert!(
+            summary.contains("Task B (Rebuild): SKIPPED — depends on failed task A"),
+            "summary: {}",
+            summary
+        );
+        assert!(summary.contains("Task C (Update the README): OK"));
+        assert_eq!(statuses(&state), vec![Failed, Skipped, Completed]);
+    }
+
+    #[tokio::test]
+    async fn abort_stops_after_the_failed_task() {
+        use quorum_domain::TaskStatus::{Failed, Skipped};
+        let (result, state) = run_with_failure_policy(
+            FailurePolicy::Abort,
+            vec![
+                command_response("rm -rf build"),
+                command_response("rm -rf build"),
+            ],
+        )
+        .await;
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Task A failed, aborting"), "error: {}", err);
+        assert!(err.contains("Task B (Rebuild): SKIPPED — aborted after task A failed"));
+        assert!(err.contains("Task C (Update the README): SKIPPED"));
+        assert_eq!(statuses(&state), vec![Failed, Skipped, Skipped]);
+    }
 }
@@ -6,8 +6,15 @@
 //! 1. **Stage 1** — Load known files directly (no LLM needed)
 //! 2. **Stage 2** — Run exploration agent with tool use
 //! 3. **Stage 3** — Proceed with minimal context
+//!
+//! Pinned files (`context.pinned_files` / `--context-file`) are loaded in
+//! Stage 1 and carried into the final context whichever stage wins.
+//!
+//! Every file read along the way — known files in Stage 1, `read_file` calls
+//! in Stage 2 — is reported through
+//! [`AgentProgressNotifier::on_context_file_loaded`].
 
-use crate::config::ExecutionParams;
+use crate::config::{ExecutionParams, RetryPolicy};
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::ports::context_loader::ContextLoaderPort;
 use crate::ports::llm_gateway::{LlmSession, ToolResultMessage};
@@ -18,12 +25,19 @@ use crate::use_cases::run_agent::RunAgentError;
 use crate::use_cases::shared::{check_cancelled, send_with_tools_cancellable};
 use crate::use_cases::tool_helpers::tool_args_preview;
 use quorum_domain::core::string::truncate;
-use quorum_domain::{AgentContext, AgentPromptTemplate, ProjectContext, extract_references};
+use quorum_domain::{
+    AgentContext, AgentPromptTemplate, ProjectContext, detect_project_type, extract_references,
+};
 use std::path::Path;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
+/// `source` passed to `on_context_file_loaded` for Stage 1 files.
+const CONTEXT_SOURCE_KNOWN_FILE: &str = "known_file";
+/// `source` passed to `on_context_file_loaded` for Stage 2 `read_file` calls.
+const CONTEXT_SOURCE_EXPLORATION: &str = "exploration";
+
 /// Use case for gathering project context (Phase 1).
 ///
 /// Uses a 3-stage fallback strategy:
@@ -36,6 +50,7 @@ pub struct GatherContextUseCase {
     context_loader: Option<Arc<dyn ContextLoaderPort>>,
     cancellation_token: Option<CancellationToken>,
     reference_resolver: Option<Arc<dyn ReferenceResolverPort>>,
+    retry_policy: RetryPolicy,
 }
 
 impl GatherContextUseCase {
@@ -51,6 +66,7 @@ impl GatherContextUseCase {
             context_loader,
             cancellation_token,
             reference_resolver: None,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
@@ -60,6 +76,12 @@ impl GatherContextUseCase {
         self
     }
 
+    /// Set the backoff policy for transient LLM request failures.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Gather context about the project using 3-stage fallback strategy.
     ///
     /// # Arguments
@@ -75,6 +97,7 @@ impl GatherContextUseCase {
         progress: &dyn AgentProgressNotifier,
     ) -> Result<AgentContext, RunAgentError> {
         let mut context = AgentContext::new();
+        let mut pinned = None;
 
         if let Some(working_dir) = &execution.working_dir {
             context = context.with_project_root(working_dir);
@@ -85,8 +108,11 @@ impl GatherContextUseCase {
             && let Some(ref working_dir) = execution.working_dir
         {
             let project_root = Path::new(working_dir);
-            let files = context_loader.load_known_files(project_root);
+            let files = context_loader.load_known_files_with_progress(project_root, &mut |file| {
+                progress.on_context_file_loaded(&file.path, CONTEXT_SOURCE_KNOWN_FILE)
+            });
             let project_ctx = context_loader.build_project_context(files);
+            pinned = project_ctx.pinned_summary();
 
             if project_ctx.has_sufficient_context() {
                 info!(
@@ -114,6 +140,7 @@ impl GatherContextUseCase {
         {
             Ok(enriched_ctx) => {
                 info!("Stage 2: Exploration agent succeeded");
+                let enriched_ctx = Self::with_pinned_summary(enriched_ctx, pinned);
                 return Ok(self.resolve_references(enriched_ctx, request).await);
             }
             Err(e) => {
@@ -128,7 +155,7 @@ impl GatherContextUseCase {
     }
 
     /// Convert ProjectContext to AgentContext
-    fn context_from_project_ctx(
+    pub(crate) fn context_from_project_ctx(
         project_ctx: ProjectContext,
         working_dir: Option<&str>,
     ) -> AgentContext {
@@ -168,6 +195,19 @@ impl GatherContextUseCase {
         context
     }
 
+    /// Prepend pinned files to the exploration agent's structure summary,
+    /// which otherwise replaces everything Stage 1 found.
+    fn with_pinned_summary(mut context: AgentContext, pinned: Option<String>) -> AgentContext {
+        if let Some(pinned) = pinned {
+            let summary = match context.structure_summary.take() {
+                Some(explored) => format!("{}\n\n{}", pinned, explored),
+                None => pinned,
+            };
+            context.set_structure_summary(summary);
+        }
+        context
+    }
+
     /// Resolve references found in the request text and add them to context.
     async fn resolve_references(&self, mut context: AgentContext, request: &str) -> AgentContext {
         let Some(resolver) = &self.reference_resolver else {
@@ -207,8 +247,10 @@ impl GatherContextUseCase {
             .tool_schema
             .all_tools_schema(self.tool_executor.tool_spec());
         let max_turns = execution.max_tool_turns;
+        let output_limit = execution.context_budget.per_tool_output_chars();
         let mut turn_count = 0;
         let mut results = Vec::new();
+        let mut seen_files: Vec<String> = Vec::new();
 
         let mut response = match send_with_tools_cancellable(
             session,
@@ -216,6 +258,7 @@ impl GatherContextUseCase {
             &tools,
             progress,
             &self.cancellation_token,
+            &self.retry_policy,
         )
         .await
         {
@@ -252,7 +295,11 @@ impl GatherContextUseCase {
                 );
                 progress.on_tool_execution_started("context", &exec_id, &call.tool_name);
 
-                let result = self.tool_executor.execute(call).await;
+                let result = self
+                    .tool_executor
+                    .execute(call)
+                    .await
+                    .truncate_output(output_limit);
                 let success = result.is_success();
 
                 if success {
@@ -270,6 +317,12 @@ impl GatherContextUseCase {
                         duration,
                         &preview,
                     );
+                    // Only a successful read puts the file into the context
+                    if call.tool_name == "read_file"
+                        && let Some(path) = call.get_string("path")
+                    {
+                        progress.on_context_file_loaded(path, CONTEXT_SOURCE_EXPLORATION);
+                    }
                 } else {
                     let error = result
                         .error()
@@ -282,15 +335,9 @@ impl GatherContextUseCase {
                     let output = result.output().unwrap_or("").to_string();
                     results.push((call.tool_name.clone(), output.clone()));
 
-                    // Try to detect project type from common files
+                    // Remember mentioned file names for project type detection
                     if call.tool_name == "glob_search" || call.tool_name == "read_file" {
-                        if output.contains("Cargo.toml") {
-                            context = context.with_project_type("rust");
-                        } else if output.contains("package.json") {
-                            context = context.with_project_type("nodejs");
-                        } else if output.contains("pyproject.toml") || output.contains("setup.py") {
-                            context = context.with_project_type("python");
-                        }
+                        seen_files.extend(output.split_whitespace().map(str::to_string));
                     }
 
                     (false, output)
@@ -324,11 +371,16 @@ impl GatherContextUseCase {
                 .map_err(|e| RunAgentError::ContextGatheringFailed(e.to_string()))?;
         }
 
+        let seen: Vec<&str> = seen_files.iter().map(String::as_str).collect();
+        if let Some(project_type) = detect_project_type(&seen) {
+            context = context.with_project_type(project_type);
+        }
+
         // Add gathered information to context
         if !results.is_empty() {
             let summary = results
                 .iter()
-                .map(|(tool, output)| format!("## {}\n{}", tool, truncate(output, 500)))
+                .map(|(tool, output)| format!("## {}\n{}", tool, output))
                 .collect::<Vec<_>>()
                 .join("\n\n");
             context.set_structure_summary(&summary);
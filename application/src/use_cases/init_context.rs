@@ -15,6 +15,8 @@
 //! 2. **Parallel analysis** - Query multiple models to analyze the project
 //! 3. **Synthesis** - Moderator combines analyses into a unified document
 //! 4. **Write output** - Save to `.quorum/context.md`
+//! 5. **Summarize** - Build a [`ProjectContext`] from the loaded files plus the
+//!    generated document, so the caller can reuse it without re-gathering
 //!
 //! # Usage
 //!
@@ -50,7 +52,9 @@
 use crate::ports::context_loader::ContextLoaderPort;
 use crate::ports::llm_gateway::{GatewayError, LlmGateway};
 use quorum_domain::core::string::truncate;
-use quorum_domain::{AgentPromptTemplate, Model};
+use quorum_domain::{
+    AgentPromptTemplate, KnownContextFile, LoadedContextFile, Model, ProjectContext,
+};
 use std::path::Path;
 use std::sync::Arc;
 use thiserror::Error;
@@ -90,10 +94,6 @@ pub enum InitContextError {
     #[error("Synthesis failed: {0}")]
     SynthesisFailed(String),
 
-    /// Failed to write the context file to disk.
-    #[error("Failed to write context file: {0}")]
-    WriteError(#[from] std::io::Error),
-
     /// An error occurred communicating with the LLM gateway.
     #[error("Gateway error: {0}")]
     GatewayError(#[from] GatewayError),
@@ -199,6 +199,19 @@ pub struct InitContextOutput {
     ///
     /// Only includes models that successfully completed their analysis.
     pub contributing_models: Vec<String>,
+
+    /// Project context built from the loaded files, with the generated
+    /// document as its primary context.
+    ///
+    /// Lets the caller seed the next agent run instead of gathering context
+    /// again. Populated even when writing the file failed.
+    pub project_context: ProjectContext,
+
+    /// Why writing the context file failed, if it did.
+    ///
+    /// `content` and `project_context` are still valid in that case; only
+    /// the file on disk is missing (or stale).
+    pub write_error: Option<String>,
 }
 
 /// Progress notifier for context initialization.
@@ -267,6 +280,7 @@ impl InitContextProgressNotifier for NoInitContextProgress {}
 /// 3. Queries multiple models in parallel for project analysis
 /// 4. Has a moderator model synthesize the analyses
 /// 5. Writes the result to `.quorum/context.md`
+/// 6. Builds a [`ProjectContext`] with the result as its primary context
 ///
 /// # Type Parameters
 ///
@@ -344,7 +358,6 @@ impl InitContextUseCase {
     /// - [`InitContextError::NoFilesFound`] - No project files to analyze
     /// - [`InitContextError::AllModelsFailed`] - All models failed to respond
     /// - [`InitContextError::SynthesisFailed`] - Moderator failed to synthesize
-    /// - [`InitContextError::WriteError`] - Could not write context file
     /// - [`InitContextError::GatewayError`] - LLM communication error
     pub async fn execute_with_progress(
         &self,
@@ -451,22 +464,46 @@ impl InitContextUseCase {
             .await
             .map_err(|e| InitContextError::SynthesisFailed(e.to_string()))?;
 
-        // Write the context file
-        self.context_loader
-            .write_context_file(project_root, &content)?;
-
         let path = self
             .context_loader
             .context_file_path(project_root)
             .to_string_lossy()
             .to_string();
 
-        progress.on_complete(&path);
+        // Write the context file. A failure here must not discard the
+        // synthesized document: it is still returned (and usable in memory).
+        let write_error = match self
+            .context_loader
+            .write_context_file(project_root, &content)
+        {
+            Ok(()) => {
+                progress.on_complete(&path);
+                None
+            }
+            Err(e) => {
+                warn!("Failed to write context file {}: {}", path, e);
+                Some(e.to_string())
+            }
+        };
+
+        // Any previously loaded `.quorum/context.md` (`--force`) is stale now
+        let mut context_files: Vec<LoadedContextFile> = files
+            .into_iter()
+            .filter(|f| f.file_type != KnownContextFile::QuorumContext)
+            .collect();
+        context_files.push(LoadedContextFile::new(
+            KnownContextFile::QuorumContext,
+            path.clone(),
+            content.clone(),
+        ));
+        let project_context = self.context_loader.build_project_context(context_files);
 
         Ok(InitContextOutput {
             path,
             content,
             contributing_models,
+            project_context,
+            write_error,
         })
     }
 
@@ -640,6 +677,97 @@ mod tests {
         }
     }
 
+    /// Gateway whose sessions answer every prompt with the same text.
+    struct FixedGateway(&'static str);
+
+    struct FixedSession(Model, &'static str);
+
+    #[async_trait::async_trait]
+    impl LlmSession for FixedSession {
+        fn model(&self) -> &Model {
+            &self.0
+        }
+
+        async fn send(&self, _content: &str) -> Result<String, GatewayError> {
+            Ok(self.1.to_string())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl LlmGateway for FixedGateway {
+        async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
+            Ok(Box::new(FixedSession(model.clone(), self.0)))
+        }
+
+        async fn create_session_with_system_prompt(
+            &self,
+            model: &Model,
+            _system_prompt: &str,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            self.create_session(model).await
+        }
+
+        async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
+            Ok(vec![])
+        }
+    }
+
+    /// [`StubLoader`] whose writes fail, as on a read-only checkout.
+    struct ReadOnlyLoader;
+
+    impl ContextLoaderPort for ReadOnlyLoader {
+        fn load_known_files(&self, project_root: &Path) -> Vec<LoadedContextFile> {
+            StubLoader.load_known_files(project_root)
+        }
+
+        fn context_file_exists(&self, _project_root: &Path) -> bool {
+            false
+        }
+
+        fn write_context_file(&self, _project_root: &Path, _content: &str) -> std::io::Result<()> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "read-only",
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_carries_project_context() {
+        let use_case = InitContextUseCase::new(
+            Arc::new(FixedGateway("# Demo\nA demo project.")),
+            Arc::new(StubLoader),
+        );
+        let input = InitContextInput::new("/project", vec![Model::default()]);
+
+        let output = use_case.execute(input).await.unwrap();
+
+        let ctx = &output.project_context;
+        assert!(ctx.has_sufficient_context());
+        assert_eq!(
+            ctx.primary_context.as_deref(),
+            Some(output.content.as_str())
+        );
+        assert_eq!(ctx.context_source, Some(KnownContextFile::QuorumContext));
+        assert_eq!(ctx.project_type.as_deref(), Some("rust"));
+        assert!(output.write_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_write_failure_still_returns_context() {
+        let use_case =
+            InitContextUseCase::new(Arc::new(FixedGateway("# Demo")), Arc::new(ReadOnlyLoader));
+        let input = InitContextInput::new("/project", vec![Model::default()]);
+
+        let output = use_case.execute(input).await.unwrap();
+
+        assert!(output.write_error.unwrap().contains("read-only"));
+        assert_eq!(
+            output.project_context.primary_context.as_deref(),
+            Some("# Demo")
+        );
+    }
+
     #[derive(Default)]
     struct RecordingProgress {
         failed: Mutex<Vec<String>>,
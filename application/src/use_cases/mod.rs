@@ -2,13 +2,18 @@
 //!
 //! Application-level operations that orchestrate domain logic.
 
+pub mod about;
 pub mod agent_controller;
+pub mod check_models;
 pub mod execute_task;
 pub mod gather_context;
 pub mod init_context;
+pub mod project_context;
+pub(crate) mod prompt_overrides;
 pub mod run_agent;
 pub mod run_ask;
 pub mod run_quorum;
 pub mod run_review;
 pub(crate) mod shared;
+pub mod summarize_session;
 pub(crate) mod tool_helpers;
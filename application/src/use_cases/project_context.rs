@@ -0,0 +1,142 @@
+//! Context projection for `ContextMode::Projected` interactions.
+//!
+//! An Ask child should not pay for the parent's whole [`AgentContext`]:
+//! [`project_context`] asks a cheap summarizer (the exploration model, via
+//! [`GatewayContextSummarizer`]) for just the parts relevant to the question
+//! and returns a much smaller context built from that excerpt.
+
+use crate::ports::llm_gateway::{GatewayError, LlmGateway};
+use async_trait::async_trait;
+use quorum_domain::{AgentContext, Model};
+use std::sync::Arc;
+use tracing::warn;
+
+/// Extracts the parts of a formatted context that matter for a query.
+#[async_trait]
+pub trait ContextSummarizer: Send + Sync {
+    /// Return the excerpt of `context` relevant to `query` (may be empty).
+    async fn summarize(&self, context: &str, query: &str) -> Result<String, GatewayError>;
+}
+
+/// [`ContextSummarizer`] backed by a plain session on `model`
+/// (normally the exploration model).
+pub struct GatewayContextSummarizer {
+    gateway: Arc<dyn LlmGateway>,
+    model: Model,
+}
+
+impl GatewayContextSummarizer {
+    pub fn new(gateway: Arc<dyn LlmGateway>, model: Model) -> Self {
+        Self { gateway, model }
+    }
+}
+
+#[async_trait]
+impl ContextSummarizer for GatewayContextSummarizer {
+    async fn summarize(&self, context: &str, query: &str) -> Result<String, GatewayError> {
+        let prompt = format!(
+            "Below is context gathered about a project, followed by a question. \
+             Copy only the lines of the context that help answer the question, \
+             unchanged. Reply with nothing if none are relevant.\n\n\
+             ## Context\n{}\n\n## Question\n{}",
+            context, query
+        );
+        let session = self.gateway.create_session(&self.model).await?;
+        session.send(&prompt).await
+    }
+}
+
+/// Project `full` down to what is relevant for `query`.
+///
+/// The project root and type are always kept; key files, the structure
+/// summary and additional entries are replaced by the summarizer's excerpt
+/// (stored as the structure summary). An empty `full` context is returned
+/// as-is without calling the summarizer. If the summarizer fails, the bulky
+/// parts are dropped rather than passed through whole.
+pub async fn project_context(
+    summarizer: &dyn ContextSummarizer,
+    full: &AgentContext,
+    query: &str,
+) -> AgentContext {
+    if full.is_empty() {
+        return AgentContext::default();
+    }
+
+    let mut projected = AgentContext {
+        project_root: full.project_root.clone(),
+        project_type: full.project_type.clone(),
+        ..AgentContext::default()
+    };
+    match summarizer.summarize(&full.to_prompt_context(), query).await {
+        Ok(excerpt) if !excerpt.trim().is_empty() => {
+            projected.set_structure_summary(excerpt.trim());
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Context projection failed, dropping parent context: {}", e),
+    }
+    projected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Keeps the context lines that mention a word of the query.
+    #[derive(Default)]
+    struct KeywordSummarizer {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ContextSummarizer for KeywordSummarizer {
+        async fn summarize(&self, context: &str, query: &str) -> Result<String, GatewayError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let words: Vec<String> = query.split_whitespace().map(|w| w.to_lowercase()).collect();
+            Ok(context
+                .lines()
+                .filter(|line| {
+                    let line = line.to_lowercase();
+                    words.iter().any(|w| line.contains(w.as_str()))
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+    }
+
+    fn parent_context() -> AgentContext {
+        let mut context = AgentContext::new()
+            .with_project_root("/project")
+            .with_project_type("rust");
+        context.set_structure_summary(
+            "src/auth.rs: login and token validation\n\
+             src/render.rs: terminal drawing\n\
+             docs/: user guides",
+        );
+        context
+    }
+
+    #[tokio::test]
+    async fn projection_keeps_relevant_lines_only() {
+        let summarizer = KeywordSummarizer::default();
+
+        let projected =
+            project_context(&summarizer, &parent_context(), "How does auth work?").await;
+
+        let summary = projected.structure_summary.unwrap();
+        assert!(summary.contains("src/auth.rs: login and token validation"));
+        assert!(!summary.contains("render.rs"));
+        assert!(!summary.contains("user guides"));
+        assert_eq!(projected.project_type.as_deref(), Some("rust"));
+    }
+
+    #[tokio::test]
+    async fn empty_context_projects_to_empty_without_summarizing() {
+        let summarizer = KeywordSummarizer::default();
+
+        let projected = project_context(&summarizer, &AgentContext::new(), "anything").await;
+
+        assert!(projected.is_empty());
+        assert_eq!(summarizer.calls.load(Ordering::SeqCst), 0);
+    }
+}
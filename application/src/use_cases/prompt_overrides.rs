@@ -0,0 +1,182 @@
+//! Per-model system prompt overrides.
+//!
+//! Use cases wrap their gateway with [`with_system_prompt_overrides`] at the
+//! start of a run, so every session they create — whatever the phase or
+//! strategy — gets the model's override from
+//! [`ModelConfig::system_prompt_overrides`] prepended to its system prompt.
+
+use crate::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession, StreamObserver};
+use async_trait::async_trait;
+use quorum_domain::{Model, ModelConfig};
+use std::sync::Arc;
+
+/// `gateway`, decorated to apply `models`' overrides.
+///
+/// Returns `gateway` unchanged when no overrides are configured.
+pub(crate) fn with_system_prompt_overrides(
+    gateway: Arc<dyn LlmGateway>,
+    models: &ModelConfig,
+) -> Arc<dyn LlmGateway> {
+    if models.system_prompt_overrides.is_empty() {
+        return gateway;
+    }
+    Arc::new(PromptOverrideGateway {
+        inner: gateway,
+        models: models.clone(),
+    })
+}
+
+/// [`LlmGateway`] decorator that prepends per-model system prompt overrides.
+struct PromptOverrideGateway {
+    inner: Arc<dyn LlmGateway>,
+    models: ModelConfig,
+}
+
+#[async_trait]
+impl LlmGateway for PromptOverrideGateway {
+    async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
+        match self.models.system_prompt_overrides.get(model) {
+            Some(prompt) => {
+                self.inner
+                    .create_session_with_system_prompt(model, prompt)
+                    .await
+            }
+            None => self.inner.create_session(model).await,
+        }
+    }
+
+    async fn create_session_with_system_prompt(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        let system_prompt = self.models.system_prompt_for(model, system_prompt);
+        self.inner
+            .create_session_with_system_prompt(model, &system_prompt)
+            .await
+    }
+
+    async fn create_text_only_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        let system_prompt = self.models.system_prompt_for(model, system_prompt);
+        self.inner
+            .create_text_only_session(model, &system_prompt)
+            .await
+    }
+
+    async fn create_streaming_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+        observer: StreamObserver,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        let system_prompt = self.models.system_prompt_for(model, system_prompt);
+        self.inner
+            .create_streaming_session(model, &system_prompt, observer)
+            .await
+    }
+
+    async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
+        self.inner.available_models().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quorum_domain::AgentPromptTemplate;
+    use std::sync::Mutex;
+
+    /// Records `(model, system_prompt)` per session; sessions echo nothing.
+    #[derive(Default)]
+    struct RecordingGateway {
+        calls: Mutex<Vec<(Model, Option<String>)>>,
+    }
+
+    struct NullSession(Model);
+
+    #[async_trait]
+    impl LlmSession for NullSession {
+        fn model(&self) -> &Model {
+            &self.0
+        }
+
+        async fn send(&self, _content: &str) -> Result<String, GatewayError> {
+            Ok(String::new())
+        }
+    }
+
+    impl RecordingGateway {
+        fn record(&self, model: &Model, prompt: Option<&str>) -> Box<dyn LlmSession> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((model.clone(), prompt.map(str::to_string)));
+            Box::new(NullSession(model.clone()))
+        }
+    }
+
+    #[async_trait]
+    impl LlmGateway for RecordingGateway {
+        async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
+            Ok(self.record(model, None))
+        }
+
+        async fn create_session_with_system_prompt(
+            &self,
+            model: &Model,
+            system_prompt: &str,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            Ok(self.record(model, Some(system_prompt)))
+        }
+
+        async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
+            Ok(vec![])
+        }
+    }
+
+    #[tokio::test]
+    async fn override_is_prepended_for_matching_model_only() {
+        let recorder = Arc::new(RecordingGateway::default());
+        let models =
+            ModelConfig::default().with_system_prompt_override(Model::Gpt53Codex, "Be concise.");
+        let gateway = with_system_prompt_overrides(recorder.clone(), &models);
+        let base = AgentPromptTemplate::agent_system();
+
+        gateway
+            .create_session_with_system_prompt(&Model::Gpt53Codex, &base)
+            .await
+            .unwrap();
+        gateway
+            .create_session_with_system_prompt(&Model::ClaudeSonnet45, &base)
+            .await
+            .unwrap();
+
+        let calls = recorder.calls.lock().unwrap();
+        let gpt = calls[0].1.as_deref().unwrap();
+        assert!(gpt.starts_with("Be concise.\n\n"));
+        assert!(gpt.ends_with(base.as_str()));
+        assert_eq!(calls[1].1.as_deref(), Some(base.as_str()));
+    }
+
+    #[tokio::test]
+    async fn plain_session_uses_override_as_system_prompt() {
+        let recorder = Arc::new(RecordingGateway::default());
+        let models =
+            ModelConfig::default().with_system_prompt_override(Model::Gpt53Codex, "Be concise.");
+        let gateway = with_system_prompt_overrides(recorder.clone(), &models);
+
+        gateway.create_session(&Model::Gpt53Codex).await.unwrap();
+        gateway
+            .create_session(&Model::ClaudeSonnet45)
+            .await
+            .unwrap();
+
+        let calls = recorder.calls.lock().unwrap();
+        assert_eq!(calls[0].1.as_deref(), Some("Be concise."));
+        assert_eq!(calls[1].1, None);
+    }
+}
@@ -0,0 +1,122 @@
+//! Per-model cancellation of ensemble plan generation.
+
+use quorum_domain::Model;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+
+/// Cancellation handles for the in-flight branches of ensemble planning,
+/// keyed by model.
+///
+/// Clones share the same registry: the caller keeps one clone and hands
+/// another to [`RunAgentUseCase::with_ensemble_branches`](super::RunAgentUseCase::with_ensemble_branches).
+/// While a model is generating its plan, [`Self::cancel`] stops just that
+/// branch; the others keep going and the cancelled model counts as failed.
+#[derive(Clone, Default)]
+pub struct EnsembleBranches {
+    tokens: Arc<Mutex<HashMap<Model, CancellationToken>>>,
+}
+
+impl EnsembleBranches {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the branch generating a plan with `model`.
+    ///
+    /// Returns `false` if no such branch is running.
+    pub fn cancel(&self, model: &Model) -> bool {
+        match self.tokens.lock().unwrap().remove(model) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Models whose branches are currently running.
+    pub fn active(&self) -> Vec<Model> {
+        let mut models: Vec<Model> = self.tokens.lock().unwrap().keys().cloned().collect();
+        models.sort_by_key(|m| m.to_string());
+        models
+    }
+
+    /// Close the branch for `model` once it has finished, without cancelling
+    /// it, so it no longer shows as [`active`](Self::active).
+    pub(super) fn close(&self, model: &Model) {
+        self.tokens.lock().unwrap().remove(model);
+    }
+
+    /// Open a branch for `model`, derived from the run's token (if any) so
+    /// cancelling the whole run also cancels the branch.
+    pub(super) fn register(
+        &self,
+        model: &Model,
+        parent: Option<&CancellationToken>,
+    ) -> CancellationToken {
+        let token = parent.map_or_else(CancellationToken::new, |p| p.child_token());
+        self.tokens
+            .lock()
+            .unwrap()
+            .insert(model.clone(), token.clone());
+        token
+    }
+
+    /// Start a round of plan generation; every branch registered during it
+    /// is closed when the returned guard drops, early returns included.
+    pub(super) fn scope(&self) -> BranchScope<'_> {
+        BranchScope(self)
+    }
+}
+
+/// Guard returned by [`EnsembleBranches::scope`].
+pub(super) struct BranchScope<'a>(&'a EnsembleBranches);
+
+impl Drop for BranchScope<'_> {
+    fn drop(&mut self) {
+        self.0.tokens.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_stops_only_that_branch() {
+        let branches = EnsembleBranches::new();
+        let run = CancellationToken::new();
+        let scope = branches.scope();
+        let haiku = branches.register(&Model::ClaudeHaiku45, Some(&run));
+        let sonnet = branches.register(&Model::ClaudeSonnet45, Some(&run));
+        assert_eq!(
+            branches.active(),
+            vec![Model::ClaudeHaiku45, Model::ClaudeSonnet45]
+        );
+
+        assert!(branches.cancel(&Model::ClaudeHaiku45));
+        assert!(haiku.is_cancelled());
+        assert!(!sonnet.is_cancelled());
+        assert!(!branches.cancel(&Model::ClaudeHaiku45));
+
+        // Cancelling the run still reaches the remaining branches
+        run.cancel();
+        assert!(sonnet.is_cancelled());
+
+        drop(scope);
+        assert!(branches.active().is_empty());
+    }
+
+    #[test]
+    fn test_close_removes_branch_without_cancelling() {
+        let branches = EnsembleBranches::new();
+        let _scope = branches.scope();
+        let haiku = branches.register(&Model::ClaudeHaiku45, None);
+
+        branches.close(&Model::ClaudeHaiku45);
+        assert!(branches.active().is_empty());
+        assert!(!haiku.is_cancelled());
+        assert!(!branches.cancel(&Model::ClaudeHaiku45));
+    }
+}
@@ -6,11 +6,34 @@ use super::RunAgentUseCase;
 use super::types::RunAgentError;
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::ports::human_intervention::HumanInterventionError;
-use quorum_domain::{AgentState, HilMode, HumanDecision};
+use quorum_domain::{AgentPolicy, AgentState, HilMode, HumanDecision};
 use tracing::{info, warn};
 
 use super::types::RunAgentInput;
 
+/// Turn a [`HumanInterventionPort`](crate::ports::human_intervention::HumanInterventionPort)
+/// result into the agent's decision.
+///
+/// A prompt that timed out takes `policy.hil_timeout_default`; cancellation
+/// and other failures become errors.
+pub(crate) fn resolve_intervention(
+    result: Result<HumanDecision, HumanInterventionError>,
+    policy: &AgentPolicy,
+) -> Result<HumanDecision, RunAgentError> {
+    match result {
+        Ok(decision) => Ok(decision),
+        Err(HumanInterventionError::TimedOut { .. }) => {
+            warn!(
+                "Human intervention timed out — taking the default decision ({})",
+                policy.hil_timeout_default
+            );
+            Ok(policy.hil_timeout_default.decision())
+        }
+        Err(HumanInterventionError::Cancelled) => Err(RunAgentError::Cancelled(None)),
+        Err(e) => Err(RunAgentError::HumanInterventionFailed(e.to_string())),
+    }
+}
+
 impl RunAgentUseCase {
     /// Handle human intervention when plan revision limit is exceeded
     pub(super) async fn handle_human_intervention(
@@ -44,19 +67,16 @@ impl RunAgentUseCase {
                 warn!("Auto-approving due to HilMode::AutoApprove - use with caution!");
                 Ok(HumanDecision::Approve)
             }
-            HilMode::Interactive => {
+            HilMode::Interactive | HilMode::BatchReview => {
                 // Use the human intervention port if available
                 if let Some(ref intervention) = self.human_intervention {
                     let _blocked = self
                         .status_tracker()
                         .enter_blocked("HiL: プラン承認待ち", self.event_publisher());
-                    intervention
+                    let result = intervention
                         .request_intervention(&input.request, plan, review_history)
-                        .await
-                        .map_err(|e| match e {
-                            HumanInterventionError::Cancelled => RunAgentError::Cancelled(None),
-                            _ => RunAgentError::HumanInterventionFailed(e.to_string()),
-                        })
+                        .await;
+                    resolve_intervention(result, &input.policy)
                 } else {
                     // No intervention handler, fall back to auto_reject
                     warn!("No human intervention handler configured, auto-rejecting");
@@ -70,7 +90,7 @@ impl RunAgentUseCase {
     ///
     /// This is the "are you sure?" gate between plan approval and task execution.
     /// The decision source depends on `HilMode`:
-    /// - `Interactive` → `HumanInterventionPort::request_execution_confirmation()`
+    /// - `Interactive` / `BatchReview` → `HumanInterventionPort::request_execution_confirmation()`
     /// - `AutoApprove` → automatically approve
     /// - `AutoReject` → automatically reject (plan created but not executed)
     pub(super) async fn handle_execution_confirmation(
@@ -84,7 +104,7 @@ impl RunAgentUseCase {
             .as_ref()
             .ok_or_else(|| RunAgentError::PlanningFailed("No plan available".to_string()))?;
 
-        progress.on_execution_confirmation_required(&input.request, plan);
+        progress.on_execution_confirmation_required(&input.request, plan, &plan.impact_summary());
 
         match input.policy.hil_mode {
             HilMode::AutoApprove => {
@@ -95,18 +115,15 @@ impl RunAgentUseCase {
                 info!("Execution confirmation auto-rejected (HilMode::AutoReject)");
                 Ok(HumanDecision::Reject)
             }
-            HilMode::Interactive => {
+            HilMode::Interactive | HilMode::BatchReview => {
                 if let Some(ref intervention) = self.human_intervention {
                     let _blocked = self
                         .status_tracker()
                         .enter_blocked("HiL: 実行確認待ち", self.event_publisher());
-                    intervention
+                    let result = intervention
                         .request_execution_confirmation(&input.request, plan)
-                        .await
-                        .map_err(|e| match e {
-                            HumanInterventionError::Cancelled => RunAgentError::Cancelled(None),
-                            _ => RunAgentError::HumanInterventionFailed(e.to_string()),
-                        })
+                        .await;
+                    resolve_intervention(result, &input.policy)
                 } else {
                     // No intervention handler → auto-approve (backwards compatible)
                     info!("No intervention handler for execution confirmation, auto-approving");
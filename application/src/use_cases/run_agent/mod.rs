@@ -13,20 +13,28 @@
 //! |    - Action Review       | yes  | skip  | N/A         |
 //! | 5. Final Review          | opt  | skip  | N/A         |
 
-mod hil;
+mod branches;
+pub(crate) mod hil;
 mod planning;
+mod replay;
 pub(crate) mod review;
 mod types;
+pub(crate) mod usage;
 
+pub use branches::EnsembleBranches;
+pub use replay::PhaseReplay;
 pub use types::{RunAgentError, RunAgentInput, RunAgentOutput};
 
 use types::{EnsemblePlanningOutcome, PlanningResult};
+use usage::UsageMeter;
 
+use crate::config::{ExecutionParams, RetryPolicy};
 use crate::ports::agent_progress::{AgentProgressNotifier, NoAgentProgress};
 use crate::ports::context_loader::ContextLoaderPort;
 use crate::ports::conversation_logger::{
     ConversationEvent, ConversationLogger, NoConversationLogger,
 };
+use crate::ports::decision_log::DecisionLogPort;
 use crate::ports::event_publisher::{ConversationLogEventPublisher, EventPublisher};
 use crate::ports::human_intervention::HumanInterventionPort;
 use crate::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession};
@@ -37,10 +45,12 @@ use crate::ports::tool_schema::ToolSchemaPort;
 use crate::status_tracker::StatusTracker;
 use crate::use_cases::execute_task::ExecuteTaskUseCase;
 use crate::use_cases::gather_context::GatherContextUseCase;
+use crate::use_cases::prompt_overrides::with_system_prompt_overrides;
 use crate::use_cases::shared::check_cancelled;
 use quorum_domain::core::string::truncate;
 use quorum_domain::{
-    AgentPhase, AgentPromptTemplate, AgentState, HumanDecision, ReviewRound, StreamEvent, Thought,
+    AgentPhase, AgentPromptTemplate, AgentState, DecisionOutcome, DecisionRecord, HumanDecision,
+    ReviewRound, StreamEvent, Thought,
 };
 use review::QuorumActionReviewer;
 use std::path::Path;
@@ -48,6 +58,9 @@ use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
+/// How many prior decisions the planning prompt is given.
+const PRIOR_DECISIONS: usize = 5;
+
 /// Use case for running an autonomous agent
 pub struct RunAgentUseCase {
     pub(super) gateway: Arc<dyn LlmGateway>,
@@ -61,6 +74,11 @@ pub struct RunAgentUseCase {
     pub(super) scripting_engine: Option<Arc<dyn ScriptingEnginePort>>,
     pub(super) event_publisher: Option<Arc<dyn EventPublisher>>,
     pub(super) status_tracker: Option<Arc<StatusTracker>>,
+    pub(super) retry_policy: RetryPolicy,
+    pub(super) ensemble_branches: EnsembleBranches,
+    pub(super) decision_log: Option<Arc<dyn DecisionLogPort>>,
+    /// Set only on the per-run copy made by [`Self::metered`]
+    usage_meter: Option<Arc<UsageMeter>>,
 }
 
 impl Clone for RunAgentUseCase {
@@ -77,6 +95,10 @@ impl Clone for RunAgentUseCase {
             scripting_engine: self.scripting_engine.clone(),
             event_publisher: self.event_publisher.clone(),
             status_tracker: self.status_tracker.clone(),
+            retry_policy: self.retry_policy,
+            ensemble_branches: self.ensemble_branches.clone(),
+            decision_log: self.decision_log.clone(),
+            usage_meter: self.usage_meter.clone(),
         }
     }
 }
@@ -116,6 +138,10 @@ impl RunAgentUseCase {
             scripting_engine: None,
             event_publisher: None,
             status_tracker: None,
+            retry_policy: RetryPolicy::default(),
+            ensemble_branches: EnsembleBranches::new(),
+            decision_log: None,
+            usage_meter: None,
         }
     }
 
@@ -137,6 +163,10 @@ impl RunAgentUseCase {
             scripting_engine: None,
             event_publisher: None,
             status_tracker: None,
+            retry_policy: RetryPolicy::default(),
+            ensemble_branches: EnsembleBranches::new(),
+            decision_log: None,
+            usage_meter: None,
         }
     }
 
@@ -152,6 +182,20 @@ impl RunAgentUseCase {
         self
     }
 
+    /// Share a registry through which individual ensemble planning branches
+    /// can be cancelled while the rest continue.
+    pub fn with_ensemble_branches(mut self, branches: EnsembleBranches) -> Self {
+        self.ensemble_branches = branches;
+        self
+    }
+
+    /// Set the log that approved plans are appended to and prior decisions
+    /// are read from for planning.
+    pub fn with_decision_log(mut self, log: Arc<dyn DecisionLogPort>) -> Self {
+        self.decision_log = Some(log);
+        self
+    }
+
     /// Set a reference resolver for automatic reference resolution during context gathering.
     pub fn with_reference_resolver(mut self, resolver: Arc<dyn ReferenceResolverPort>) -> Self {
         self.reference_resolver = Some(resolver);
@@ -170,6 +214,13 @@ impl RunAgentUseCase {
         self
     }
 
+    /// Set the backoff policy for transient LLM request failures
+    /// (planning, context gathering and task execution).
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Set the typed event publisher (the seam for `quorum_result` etc.).
     pub fn with_event_publisher(mut self, publisher: Arc<dyn EventPublisher>) -> Self {
         self.event_publisher = Some(publisher);
@@ -207,6 +258,39 @@ impl RunAgentUseCase {
             .unwrap_or_else(StatusTracker::new)
     }
 
+    /// Load the most recent approved plans into the planning context.
+    fn load_prior_decisions(&self, state: &mut AgentState) {
+        let Some(log) = &self.decision_log else {
+            return;
+        };
+        state.context.prior_decisions = log.recent(PRIOR_DECISIONS);
+        if !state.context.prior_decisions.is_empty() {
+            state.add_thought(Thought::observation(format!(
+                "Loaded {} prior decision(s)",
+                state.context.prior_decisions.len()
+            )));
+        }
+    }
+
+    /// Append the approved plan to the decision log.
+    ///
+    /// Only plans that went through consensus (review, ensemble vote or a
+    /// human) are recorded. A failed write is logged and otherwise ignored.
+    fn record_decision(&self, input: &RunAgentInput, state: &AgentState, outcome: DecisionOutcome) {
+        let (Some(log), Some(plan)) = (&self.decision_log, &state.plan) else {
+            return;
+        };
+        let record = DecisionRecord::new(
+            input.request.as_str(),
+            plan.objective.as_str(),
+            outcome,
+            chrono_lite_timestamp(),
+        );
+        if let Err(e) = log.append(&record) {
+            warn!("Failed to record decision: {}", e);
+        }
+    }
+
     /// Log `agent_complete` event to the conversation logger.
     ///
     /// Called from the normal completion path and all early-return paths
@@ -354,6 +438,21 @@ impl RunAgentUseCase {
         Ok(full_text)
     }
 
+    /// Build the [`GatherContextUseCase`] used for Phase 1.
+    fn gather_context_use_case(&self) -> GatherContextUseCase {
+        let mut gather_uc = GatherContextUseCase::new(
+            self.tool_executor.clone(),
+            self.tool_schema.clone(),
+            self.context_loader.clone(),
+            self.cancellation_token.clone(),
+        )
+        .with_retry_policy(self.retry_policy);
+        if let Some(ref resolver) = self.reference_resolver {
+            gather_uc = gather_uc.with_reference_resolver(resolver.clone());
+        }
+        gather_uc
+    }
+
     /// Execute the agent without progress reporting
     pub async fn execute(&self, input: RunAgentInput) -> Result<RunAgentOutput, RunAgentError> {
         self.execute_with_progress(input, &NoAgentProgress).await
@@ -371,9 +470,78 @@ impl RunAgentUseCase {
         progress: &dyn AgentProgressNotifier,
     ) -> Result<RunAgentOutput, RunAgentError> {
         let agent_id = format!("agent-{}", chrono_lite_timestamp());
-        let mut state = input.to_agent_state(agent_id);
+        let state = input.to_agent_state(agent_id);
+        self.run_to_output(&input, state, progress).await
+    }
 
-        match self.run_phases(&input, &mut state, progress).await {
+    /// Resume a run from a persisted [`AgentState`] snapshot.
+    ///
+    /// Continues from the recorded [`AgentPhase`] instead of starting over:
+    ///
+    /// - The gathered context is reused; context gathering only runs if the
+    ///   snapshot was taken before it finished.
+    /// - Snapshots taken during planning or plan review restart the planning
+    ///   loop against the saved context.
+    /// - Snapshots taken during execution skip planning and plan review and
+    ///   re-run only tasks that are not in a terminal
+    ///   [`TaskStatus`](quorum_domain::TaskStatus). Tasks left `InProgress`
+    ///   by the interruption are run again from scratch.
+    ///
+    /// `execution` supplies the loop parameters that are not part of the
+    /// snapshot (working directory, tool turn limits, context budget), see
+    /// [`RunAgentInput::from_agent_state`].
+    ///
+    /// Returns [`RunAgentError::InvalidConfig`] if the snapshot is already
+    /// `Completed` or `Failed`.
+    pub async fn resume(
+        &self,
+        snapshot: AgentState,
+        execution: ExecutionParams,
+        progress: &dyn AgentProgressNotifier,
+    ) -> Result<RunAgentOutput, RunAgentError> {
+        if snapshot.is_finished() {
+            return Err(RunAgentError::InvalidConfig(format!(
+                "cannot resume a run in the {} phase",
+                snapshot.phase
+            )));
+        }
+
+        let input = RunAgentInput::from_agent_state(&snapshot, execution);
+        let mut state = snapshot;
+        if let Some(plan) = &mut state.plan {
+            let requeued = plan.requeue_interrupted_tasks();
+            if requeued > 0 {
+                info!("Resume: re-running {} interrupted task(s)", requeued);
+            }
+        }
+        info!("Resuming agent {} from phase {}", state.id, state.phase);
+
+        self.run_to_output(&input, state, progress).await
+    }
+
+    /// Drive `state` through [`Self::run_phases`] and wrap the result,
+    /// attaching the state to [`RunAgentError::Cancelled`] on cancellation.
+    ///
+    /// [`RunAgentError::CostLimitExceeded`] is a graceful stop: it becomes an
+    /// unsuccessful [`RunAgentOutput`] whose summary explains the limit.
+    async fn run_to_output(
+        &self,
+        input: &RunAgentInput,
+        mut state: AgentState,
+        progress: &dyn AgentProgressNotifier,
+    ) -> Result<RunAgentOutput, RunAgentError> {
+        let meter = Arc::new(UsageMeter::new(
+            state.phase.clone(),
+            input.execution.max_cost_tokens,
+        ));
+        let mut use_case = self.metered(meter.clone());
+        use_case.gateway = with_system_prompt_overrides(use_case.gateway, &input.models);
+        let result = use_case.run_phases(input, &mut state, progress).await;
+        let (last_phase, elapsed) = meter.finish_phase();
+        use_case.log_phase_timing(&last_phase, elapsed);
+        meter.merge_into(&mut state);
+
+        match result {
             Ok((summary, success)) => Ok(RunAgentOutput {
                 summary,
                 success,
@@ -382,12 +550,25 @@ impl RunAgentUseCase {
             Err(RunAgentError::Cancelled(None)) => {
                 Err(RunAgentError::Cancelled(Some(Box::new(state))))
             }
+            Err(RunAgentError::CostLimitExceeded { used, limit }) => {
+                let summary = cost_limit_summary(&state, used, limit);
+                warn!("Agent stopped: {} of {} tokens used", used, limit);
+                state.fail(format!("Token limit exceeded ({} > {})", used, limit));
+                use_case.log_agent_complete(&state, &summary, false);
+                Ok(RunAgentOutput {
+                    summary,
+                    success: false,
+                    state,
+                })
+            }
             Err(e) => Err(e),
         }
     }
 
     /// Run the agent's phases (Context Gathering → Planning → Review → Execution → Final Review).
     ///
+    /// Starts from `state.phase`, so a fresh state (ContextGathering) runs every
+    /// phase while a resumed one skips the phases it already finished.
     /// Mutates `state` in place as the run progresses and returns `(summary, success)`
     /// on completion. On cancellation, returns `Err(RunAgentError::Cancelled(None))`;
     /// the caller ([`Self::execute_with_progress`]) attaches a snapshot of the
@@ -415,342 +596,446 @@ impl RunAgentUseCase {
         // Create system prompt (shared across phases)
         let system_prompt = AgentPromptTemplate::agent_system();
 
+        let start_phase = state.phase.clone();
+        let resuming_execution = matches!(
+            start_phase,
+            AgentPhase::Executing | AgentPhase::ActionReview | AgentPhase::FinalReview
+        );
+
         // ==================== Phase 1: Context Gathering ====================
         // Delegated to GatherContextUseCase
-        progress.on_phase_change(&AgentPhase::ContextGathering);
-        state.set_phase(AgentPhase::ContextGathering);
-
-        let context_session = self
-            .gateway
-            .create_session_with_system_prompt(&input.models.exploration, &system_prompt)
-            .await?;
+        if start_phase == AgentPhase::ContextGathering
+            && let Some(context) = &input.initial_context
+        {
+            progress.on_phase_change(&AgentPhase::ContextGathering);
+            self.enter_phase(state, AgentPhase::ContextGathering)?;
+            state.context = context.clone();
+            state.add_thought(Thought::observation(
+                "Context inherited from parent interaction",
+            ));
+        } else if start_phase == AgentPhase::ContextGathering && input.preloaded_plan.is_some() {
+            info!("Plan preloaded, skipping context gathering");
+            state.add_thought(Thought::observation(
+                "Context gathering skipped (plan preloaded)",
+            ));
+        } else if start_phase == AgentPhase::ContextGathering && !input.policy.gather_context {
+            info!("Context gathering disabled, planning with empty context");
+            state.add_thought(Thought::observation(
+                "Context gathering skipped (agent.gather_context = false)",
+            ));
+        } else if start_phase == AgentPhase::ContextGathering {
+            progress.on_phase_change(&AgentPhase::ContextGathering);
+            self.enter_phase(state, AgentPhase::ContextGathering)?;
+
+            let context_session = self
+                .gateway
+                .create_session_with_system_prompt(&input.models.exploration, &system_prompt)
+                .await?;
 
-        let mut gather_uc = GatherContextUseCase::new(
-            self.tool_executor.clone(),
-            self.tool_schema.clone(),
-            self.context_loader.clone(),
-            self.cancellation_token.clone(),
-        );
-        if let Some(ref resolver) = self.reference_resolver {
-            gather_uc = gather_uc.with_reference_resolver(resolver.clone());
+            match self
+                .gather_context_use_case()
+                .execute(
+                    context_session.as_ref(),
+                    &input.request,
+                    &input.execution,
+                    progress,
+                )
+                .await
+            {
+                Ok(context) => {
+                    state.context = context;
+                    state.add_thought(Thought::observation("Context gathered successfully"));
+                }
+                Err(e) => {
+                    warn!("Context gathering failed: {}", e);
+                    state.add_thought(Thought::observation(format!(
+                        "Context gathering failed: {}",
+                        e
+                    )));
+                    // Continue with empty context
+                }
+            }
         }
 
-        match gather_uc
-            .execute(
-                context_session.as_ref(),
-                &input.request,
-                &input.execution,
-                progress,
-            )
-            .await
-        {
-            Ok(context) => {
-                state.context = context;
-                state.add_thought(Thought::observation("Context gathered successfully"));
-            }
-            Err(e) => {
-                warn!("Context gathering failed: {}", e);
-                state.add_thought(Thought::observation(format!(
-                    "Context gathering failed: {}",
-                    e
-                )));
-                // Continue with empty context
+        // Inherited context already carries the parent's user-supplied text
+        if start_phase == AgentPhase::ContextGathering && input.initial_context.is_none() {
+            for text in input.user_context.iter().filter(|t| !t.trim().is_empty()) {
+                state.context.append_user_context(text);
             }
         }
+        if start_phase == AgentPhase::ContextGathering && input.preloaded_plan.is_none() {
+            self.load_prior_decisions(state);
+        }
 
         // ==================== Phase 2-3: Planning + Review Loop ====================
-        // Mode determines planning approach:
-        // - Single (Solo): decision_model creates plan, review_models vote
-        // - Ensemble: review_models each create plans in parallel, then vote on each other's plans
+        // Skipped when resuming a run whose plan was already approved.
+        if !resuming_execution {
+            // Mode determines planning approach:
+            // - Single (Solo): decision_model creates plan, review_models vote
+            // - Ensemble: review_models each create plans in parallel, then vote on each other's plans
 
-        let mut plan_feedback: Option<String> = None;
+            let mut plan_feedback: Option<String> = None;
 
-        loop {
-            // Check for cancellation at the start of each loop iteration
-            check_cancelled(&self.cancellation_token)?;
+            loop {
+                // Check for cancellation at the start of each loop iteration
+                check_cancelled(&self.cancellation_token)?;
 
-            // Phase 2: Planning
-            progress.on_phase_change(&AgentPhase::Planning);
-            state.set_phase(AgentPhase::Planning);
+                // A preloaded plan skips planning and goes straight to review
+                let mut ready_plan = input.preloaded_plan.clone();
+                if let Some(plan) = &ready_plan {
+                    state.add_thought(Thought::planning(format!(
+                        "Using preloaded plan with {} tasks: {}",
+                        plan.tasks.len(),
+                        plan.objective
+                    )));
+                } else {
+                    // Phase 2: Planning
+                    progress.on_phase_change(&AgentPhase::Planning);
+                    self.enter_phase(state, AgentPhase::Planning)?;
+                }
 
-            // Branch based on planning mode
-            if input.mode.planning_approach().is_ensemble() {
-                // ==================== Ensemble Planning ====================
-                // Multiple models create plans independently, then vote
-                info!(
-                    "Ensemble planning: {} models will generate plans",
-                    input.models.review.len()
-                );
+                // Branch based on planning mode
+                if ready_plan.is_none() && input.mode.planning_approach().is_ensemble() {
+                    // ==================== Ensemble Planning ====================
+                    // Multiple models create plans independently, then vote
+                    info!(
+                        "Ensemble planning: {} models will generate plans",
+                        input.models.review.len()
+                    );
+
+                    match self
+                        .create_ensemble_plans(
+                            input,
+                            &state.context,
+                            &system_prompt,
+                            plan_feedback.as_deref(),
+                            progress,
+                        )
+                        .await
+                    {
+                        Ok(EnsemblePlanningOutcome::Plans(result))
+                            if result.merged_plan.is_some() =>
+                        {
+                            // Nobody voted on a merged plan — send it
+                            // through plan review like a solo plan.
+                            info!("Ensemble planning result:\n{}", result.summary());
+                            ready_plan = result.merged_plan;
+                            if let Some(merged) = &ready_plan {
+                                state.add_thought(Thought::planning(format!(
+                                    "Moderator merged the top candidates into: {}",
+                                    merged.objective
+                                )));
+                            }
+                        }
+                        Ok(EnsemblePlanningOutcome::Plans(result)) => {
+                            // Get the selected plan
+                            let selected = result.selected().ok_or_else(|| {
+                                RunAgentError::EnsemblePlanningFailed(
+                                    "No plan was selected".to_string(),
+                                )
+                            })?;
+
+                            state.add_thought(Thought::planning(format!(
+                                "Ensemble selected plan from {} with score {:.1}/10: {}",
+                                selected.model,
+                                selected.average_score(),
+                                selected.plan.objective
+                            )));
+
+                            // Log the summary
+                            info!("Ensemble planning result:\n{}", result.summary());
+
+                            state.set_plan(selected.plan.clone());
+                            if let Some(plan) = &state.plan {
+                                progress.on_plan_created(plan);
+                            }
+
+                            // Ensemble mode: voting is already done during plan generation
+                            // Skip the separate review phase and mark as approved
+                            state.approve_plan();
+                            state.add_thought(Thought::observation(format!(
+                                "Plan selected by ensemble voting (avg score: {:.1}/10)",
+                                selected.average_score()
+                            )));
+                            self.record_decision(input, state, DecisionOutcome::EnsembleSelected);
+                            break; // Exit loop and proceed to Phase 4
+                        }
+                        Ok(EnsemblePlanningOutcome::TextResponse(text)) => {
+                            // All ensemble models returned text (no plans needed).
+                            // The moderator has already synthesized the responses.
+                            // This is the correct path for greetings, questions, etc.
+                            state.add_thought(Thought::observation(
+                                "No plan needed — ensemble text responses synthesized",
+                            ));
+                            state.complete();
+                            self.log_agent_complete(state, &text, true);
+                            return Ok((text, true));
+                        }
+                        Err(e) if e.is_cancelled() => return Err(e),
+                        Err(e) => {
+                            // Fallback to Solo planning
+                            warn!("Ensemble planning failed, falling back to solo: {}", e);
+                            progress.on_ensemble_fallback(&e.to_string());
+                            state.add_thought(Thought::observation(format!(
+                                "Ensemble planning failed ({}), falling back to solo",
+                                e
+                            )));
+                            // fall through to Solo Planning below
+                        }
+                    }
+                }
 
-                match self
-                    .create_ensemble_plans(
-                        input,
-                        &state.context,
-                        &system_prompt,
-                        plan_feedback.as_deref(),
-                        progress,
-                    )
-                    .await
-                {
-                    Ok(EnsemblePlanningOutcome::Plans(result)) => {
-                        // Get the selected plan
-                        let selected = result.selected().ok_or_else(|| {
-                            RunAgentError::EnsemblePlanningFailed(
-                                "No plan was selected".to_string(),
+                // ==================== Single (Solo) Planning ====================
+                // Also used as fallback when ensemble planning fails
+                // Uses decision_model (default: Sonnet - needs strong reasoning for planning)
+                let planned = match ready_plan {
+                    Some(plan) => Ok(PlanningResult::Plan(plan)),
+                    None => {
+                        let planning_session = self
+                            .gateway
+                            .create_session_with_system_prompt(
+                                &input.models.decision,
+                                &system_prompt,
                             )
-                        })?;
-
-                        state.add_thought(Thought::planning(format!(
-                            "Ensemble selected plan from {} with score {:.1}/10: {}",
-                            selected.model,
-                            selected.average_score(),
-                            selected.plan.objective
-                        )));
-
-                        // Log the summary
-                        info!("Ensemble planning result:\n{}", result.summary());
-
-                        state.set_plan(selected.plan.clone());
-                        if let Some(plan) = &state.plan {
-                            progress.on_plan_created(plan);
+                            .await?;
+                        let result = self
+                            .create_plan(
+                                planning_session.as_ref(),
+                                &input.request,
+                                &state.context,
+                                plan_feedback.as_deref(),
+                                input.policy.max_tasks_per_plan,
+                                progress,
+                            )
+                            .await;
+                        if let Ok(PlanningResult::Plan(plan)) = &result {
+                            state.add_thought(Thought::planning(format!(
+                                "Created plan with {} tasks: {}",
+                                plan.tasks.len(),
+                                plan.objective
+                            )));
                         }
-
-                        // Ensemble mode: voting is already done during plan generation
-                        // Skip the separate review phase and mark as approved
-                        state.approve_plan();
-                        state.add_thought(Thought::observation(format!(
-                            "Plan selected by ensemble voting (avg score: {:.1}/10)",
-                            selected.average_score()
-                        )));
-                        break; // Exit loop and proceed to Phase 4
+                        result
                     }
-                    Ok(EnsemblePlanningOutcome::TextResponse(text)) => {
-                        // All ensemble models returned text (no plans needed).
-                        // The moderator has already synthesized the responses.
-                        // This is the correct path for greetings, questions, etc.
-                        state.add_thought(Thought::observation(
-                            "No plan needed — ensemble text responses synthesized",
-                        ));
+                };
+
+                let plan = match planned {
+                    Ok(PlanningResult::Plan(plan)) => plan,
+                    Ok(PlanningResult::TextResponse(text)) => {
+                        // LLM determined no plan is needed — return text response directly
+                        state.add_thought(Thought::observation("No plan needed for this request"));
                         state.complete();
                         self.log_agent_complete(state, &text, true);
                         return Ok((text, true));
                     }
-                    Err(e) if e.is_cancelled() => return Err(e),
                     Err(e) => {
-                        // Fallback to Solo planning
-                        warn!("Ensemble planning failed, falling back to solo: {}", e);
-                        progress.on_ensemble_fallback(&e.to_string());
+                        let summary = format!("Agent failed during planning: {}", e);
+                        state.fail(format!("Planning failed: {}", e));
+                        self.log_agent_complete(state, &summary, false);
+                        return Ok((summary, false));
+                    }
+                };
+
+                state.set_plan(plan);
+                if let Some(plan) = &state.plan {
+                    progress.on_plan_created(plan);
+                }
+
+                // Phase 3: Plan Review (Quorum) - controlled by PhaseScope
+                if !input.mode.includes_plan_review() {
+                    // Skip plan review (Fast/PlanOnly) — auto-approve
+                    state.approve_plan();
+                    state.add_thought(Thought::observation(format!(
+                        "Plan review skipped (scope: {})",
+                        input.mode.phase_scope
+                    )));
+                    break;
+                }
+
+                progress.on_phase_change(&AgentPhase::PlanReview);
+                self.enter_phase(state, AgentPhase::PlanReview)?;
+
+                let plan_review = match self.review_plan(input, state, progress).await {
+                    Ok(review) => review,
+                    Err(RunAgentError::QuorumFailed(errors)) => {
+                        // No reviewer was reached — this is not a rejection, so
+                        // there is no feedback to revise against. Stop here.
+                        warn!("Plan review failed: could not reach any reviewer");
                         state.add_thought(Thought::observation(format!(
-                            "Ensemble planning failed ({}), falling back to solo",
-                            e
+                            "Could not reach reviewers: {}",
+                            errors.join("; ")
                         )));
-                        // fall through to Solo Planning below
+                        state.fail("Could not reach reviewers");
+                        let error = RunAgentError::QuorumFailed(errors);
+                        self.log_agent_complete(state, &error.to_string(), false);
+                        return Err(error);
                     }
+                    Err(e) => return Err(e),
+                };
+
+                // Create review round for history
+                let review_round = {
+                    let round_num = state
+                        .plan
+                        .as_ref()
+                        .map(|p| p.review_history.len() + 1)
+                        .unwrap_or(1);
+                    ReviewRound::new(round_num, plan_review.passed, plan_review.votes.clone())
+                };
+
+                // Add review round to plan history
+                if let Some(plan) = &mut state.plan {
+                    plan.add_review_round(review_round.clone());
+                }
+                state.record_plan_review(&plan_review);
+
+                // Notify with detailed vote information
+                progress.on_quorum_complete_with_votes(
+                    "plan_review",
+                    plan_review.passed,
+                    &plan_review.votes,
+                    plan_review.aggregated_feedback.as_deref(),
+                );
+                if let Some(plan) = &state.plan {
+                    // Revisions replace the plan, so count rounds on the state
+                    progress.on_plan_reviewed(
+                        plan,
+                        state.plan_revision_count + 1,
+                        plan_review.passed,
+                        &plan_review.votes,
+                    );
                 }
-            }
 
-            // ==================== Single (Solo) Planning ====================
-            // Also used as fallback when ensemble planning fails
-            // Uses decision_model (default: Sonnet - needs strong reasoning for planning)
-            let planning_session = self
-                .gateway
-                .create_session_with_system_prompt(&input.models.decision, &system_prompt)
-                .await?;
+                if plan_review.passed {
+                    state.approve_plan();
+                    state.add_thought(Thought::observation("Plan approved by quorum"));
+                    self.record_decision(input, state, DecisionOutcome::QuorumApproved);
+                    break; // Exit loop and proceed to Phase 4
+                }
 
-            let plan = match self
-                .create_plan(
-                    planning_session.as_ref(),
-                    &input.request,
-                    &state.context,
-                    plan_feedback.as_deref(),
-                    progress,
-                )
-                .await
-            {
-                Ok(PlanningResult::Plan(plan)) => {
-                    state.add_thought(Thought::planning(format!(
-                        "Created plan with {} tasks: {}",
-                        plan.tasks.len(),
-                        plan.objective
-                    )));
-                    plan
+                // Plan was rejected - check if we can retry
+                let feedback = plan_review
+                    .aggregated_feedback
+                    .unwrap_or_else(|| "No specific feedback".to_string());
+                state.reject_plan(&feedback);
+
+                // A preloaded plan has no planner to revise it against the feedback.
+                if input.preloaded_plan.is_some() {
+                    let summary = format!("Preloaded plan rejected by quorum: {}", feedback);
+                    state.fail("Preloaded plan rejected by quorum");
+                    self.log_agent_complete(state, &summary, false);
+                    return Ok((summary, false));
                 }
-                Ok(PlanningResult::TextResponse(text)) => {
-                    // LLM determined no plan is needed — return text response directly
-                    state.add_thought(Thought::observation("No plan needed for this request"));
-                    state.complete();
-                    self.log_agent_complete(state, &text, true);
-                    return Ok((text, true));
+
+                // Check plan revision limit for human intervention
+                // Note: We use state.plan_revision_count instead of plan.revision_count()
+                // because the Plan is recreated on each revision attempt, losing history.
+                let revision_count = state.plan_revision_count;
+
+                if revision_count >= input.policy.max_plan_revisions {
+                    // Human intervention required
+                    let decision = self
+                        .handle_human_intervention(input, state, progress)
+                        .await?;
+
+                    match decision {
+                        HumanDecision::Approve => {
+                            info!("Human approved plan despite quorum rejection");
+                            state.approve_plan();
+                            state.add_thought(Thought::observation(
+                                "Plan approved by human intervention",
+                            ));
+                            self.record_decision(input, state, DecisionOutcome::HumanApproved);
+                            break; // Exit loop and proceed to Phase 4
+                        }
+                        HumanDecision::Reject => {
+                            state.fail("Plan rejected by human");
+                            return Err(RunAgentError::HumanRejected);
+                        }
+                        HumanDecision::Edit(new_plan) => {
+                            info!("Human provided edited plan");
+                            state.plan = Some(new_plan);
+                            state.add_thought(Thought::observation("Plan edited by human"));
+                            // Continue to re-review the edited plan
+                            continue;
+                        }
+                    }
                 }
-                Err(e) => {
-                    let summary = format!("Agent failed during planning: {}", e);
-                    state.fail(format!("Planning failed: {}", e));
+
+                // Check iteration limit before retrying
+                if !state.increment_iteration() {
+                    let summary = format!(
+                        "Plan rejected after {} attempts. Last feedback: {}",
+                        state.iteration_count, feedback
+                    );
+                    state.fail("Max plan retries exceeded");
                     self.log_agent_complete(state, &summary, false);
                     return Ok((summary, false));
                 }
-            };
 
-            state.set_plan(plan);
-            if let Some(plan) = &state.plan {
-                progress.on_plan_created(plan);
-            }
+                // Notify about plan revision
+                progress.on_plan_revision(state.iteration_count, &feedback);
 
-            // Phase 3: Plan Review (Quorum) - controlled by PhaseScope
-            if !input.mode.includes_plan_review() {
-                // Skip plan review (Fast/PlanOnly) — auto-approve
-                state.approve_plan();
-                state.add_thought(Thought::observation(format!(
-                    "Plan review skipped (scope: {})",
-                    input.mode.phase_scope
+                // Store feedback for next iteration and retry
+                plan_feedback = Some(feedback.clone());
+                state.add_thought(Thought::reflection(format!(
+                    "Plan rejected, retrying with feedback: {}",
+                    truncate(&feedback, 100)
                 )));
-                break;
+                info!(
+                    "Plan rejected (attempt {}), retrying...",
+                    state.iteration_count
+                );
             }
 
-            progress.on_phase_change(&AgentPhase::PlanReview);
-            state.set_phase(AgentPhase::PlanReview);
-
-            let plan_review = self.review_plan(input, state, progress).await?;
-
-            // Create review round for history
-            let review_round = {
-                let round_num = state
+            // ==================== PlanOnly Early Return ====================
+            if !input.mode.includes_execution() {
+                let plan_summary = state
                     .plan
                     .as_ref()
-                    .map(|p| p.review_history.len() + 1)
-                    .unwrap_or(1);
-                ReviewRound::new(round_num, plan_review.passed, plan_review.votes.clone())
-            };
-
-            // Add review round to plan history
-            if let Some(plan) = &mut state.plan {
-                plan.add_review_round(review_round.clone());
-            }
-
-            // Notify with detailed vote information
-            progress.on_quorum_complete_with_votes(
-                "plan_review",
-                plan_review.passed,
-                &plan_review.votes,
-                plan_review.aggregated_feedback.as_deref(),
-            );
-
-            if plan_review.passed {
-                state.approve_plan();
-                state.add_thought(Thought::observation("Plan approved by quorum"));
-                break; // Exit loop and proceed to Phase 4
+                    .map(|p| p.objective.clone())
+                    .unwrap_or_default();
+                state.complete();
+                let summary = format!("Plan created (plan-only mode): {}", plan_summary);
+                info!("PlanOnly scope: skipping execution, returning plan");
+                self.log_agent_complete(state, &summary, true);
+                return Ok((summary, true));
             }
 
-            // Plan was rejected - check if we can retry
-            let feedback = plan_review
-                .aggregated_feedback
-                .unwrap_or_else(|| "No specific feedback".to_string());
-            state.reject_plan(&feedback);
-
-            // Check plan revision limit for human intervention
-            // Note: We use state.plan_revision_count instead of plan.revision_count()
-            // because the Plan is recreated on each revision attempt, losing history.
-            let revision_count = state.plan_revision_count;
-
-            if revision_count >= input.policy.max_plan_revisions {
-                // Human intervention required
+            // ==================== Execution Confirmation Gate ====================
+            if input.mode.requires_execution_confirmation() {
                 let decision = self
-                    .handle_human_intervention(input, state, progress)
+                    .handle_execution_confirmation(input, state, progress)
                     .await?;
-
                 match decision {
                     HumanDecision::Approve => {
-                        info!("Human approved plan despite quorum rejection");
-                        state.approve_plan();
-                        state.add_thought(Thought::observation(
-                            "Plan approved by human intervention",
-                        ));
-                        break; // Exit loop and proceed to Phase 4
-                    }
-                    HumanDecision::Reject => {
-                        state.fail("Plan rejected by human");
-                        return Err(RunAgentError::HumanRejected);
+                        info!("Execution confirmation: approved");
                     }
-                    HumanDecision::Edit(new_plan) => {
-                        info!("Human provided edited plan");
-                        state.plan = Some(new_plan);
-                        state.add_thought(Thought::observation("Plan edited by human"));
-                        // Continue to re-review the edited plan
-                        continue;
+                    _ => {
+                        // Reject or Edit — stop execution gracefully
+                        info!("Execution confirmation: rejected, stopping");
+                        state.complete();
+                        let summary = "Plan approved but not executed (user declined execution)";
+                        self.log_agent_complete(state, summary, true);
+                        return Ok((summary.to_string(), true));
                     }
                 }
             }
-
-            // Check iteration limit before retrying
-            if !state.increment_iteration() {
-                let summary = format!(
-                    "Plan rejected after {} attempts. Last feedback: {}",
-                    state.iteration_count, feedback
-                );
-                state.fail("Max plan retries exceeded");
-                self.log_agent_complete(state, &summary, false);
-                return Ok((summary, false));
-            }
-
-            // Notify about plan revision
-            progress.on_plan_revision(state.iteration_count, &feedback);
-
-            // Store feedback for next iteration and retry
-            plan_feedback = Some(feedback.clone());
-            state.add_thought(Thought::reflection(format!(
-                "Plan rejected, retrying with feedback: {}",
-                truncate(&feedback, 100)
-            )));
-            info!(
-                "Plan rejected (attempt {}), retrying...",
-                state.iteration_count
-            );
-        }
-
-        // ==================== PlanOnly Early Return ====================
-        if !input.mode.includes_execution() {
-            let plan_summary = state
-                .plan
-                .as_ref()
-                .map(|p| p.objective.clone())
-                .unwrap_or_default();
-            state.complete();
-            let summary = format!("Plan created (plan-only mode): {}", plan_summary);
-            info!("PlanOnly scope: skipping execution, returning plan");
-            self.log_agent_complete(state, &summary, true);
-            return Ok((summary, true));
-        }
-
-        // ==================== Execution Confirmation Gate ====================
-        if input.mode.requires_execution_confirmation() {
-            let decision = self
-                .handle_execution_confirmation(input, state, progress)
-                .await?;
-            match decision {
-                HumanDecision::Approve => {
-                    info!("Execution confirmation: approved");
-                }
-                _ => {
-                    // Reject or Edit — stop execution gracefully
-                    info!("Execution confirmation: rejected, stopping");
-                    state.complete();
-                    let summary = "Plan approved but not executed (user declined execution)";
-                    self.log_agent_complete(state, summary, true);
-                    return Ok((summary.to_string(), true));
-                }
-            }
         }
 
         // ==================== Phase 4: Task Execution ====================
         // Delegated to ExecuteTaskUseCase
         progress.on_phase_change(&AgentPhase::Executing);
-        state.set_phase(AgentPhase::Executing);
+        self.enter_phase(state, AgentPhase::Executing)?;
 
         let reviewer = QuorumActionReviewer::new(
             self.gateway.clone(),
             self.tool_executor.clone(),
             self.cancellation_token.clone(),
             self.event_publisher(),
-        );
+        )
+        .with_risk_overrides(input.policy.command_risk_overrides.clone())
+        .with_timeout(input.execution.review_timeout);
         let mut execute_uc = ExecuteTaskUseCase::new(
             self.gateway.clone(),
             self.tool_executor.clone(),
@@ -758,23 +1043,30 @@ impl RunAgentUseCase {
             self.cancellation_token.clone(),
             Arc::new(reviewer),
             self.conversation_logger.clone(),
-        );
+        )
+        .with_retry_policy(self.retry_policy);
         if let Some(engine) = &self.scripting_engine {
             execute_uc = execute_uc.with_scripting_engine(engine.clone());
         }
+        if let Some(intervention) = &self.human_intervention {
+            execute_uc = execute_uc.with_human_intervention(intervention.clone());
+        }
+        if let Some(meter) = &self.usage_meter {
+            execute_uc = execute_uc.with_usage_meter(meter.clone());
+        }
 
         let execution_result = execute_uc
             .execute(input, state, &system_prompt, progress)
             .await;
 
-        let summary = match execution_result {
+        let mut summary = match execution_result {
             Ok(mechanical_summary) => {
                 // Attempt LLM-based structured summary synthesis
                 self.synthesize_summary(input, state, &mechanical_summary)
                     .await
                     .unwrap_or(mechanical_summary)
             }
-            Err(e) if e.is_cancelled() => return Err(e),
+            Err(e) if e.is_cancelled() || e.is_cost_limit_exceeded() => return Err(e),
             Err(e) => {
                 let summary = format!("Agent failed during execution: {}", e);
                 state.fail(e.to_string());
@@ -783,22 +1075,30 @@ impl RunAgentUseCase {
             }
         };
 
-        // Phase 5: Final Review (optional, requires action review scope)
+        // Phase 5: Final Review (optional, requires action review scope).
+        // A rejecting reviewer may attach corrective tasks; those re-enter
+        // execution, bounded by `agent.max_final_review_fixes`.
         if input.policy.require_final_review && input.mode.includes_action_review() {
-            progress.on_phase_change(&AgentPhase::FinalReview);
-            state.set_phase(AgentPhase::FinalReview);
-
-            let final_review = self.final_review(input, state, &summary, progress).await?;
+            let mut fix_cycles = 0;
+            loop {
+                progress.on_phase_change(&AgentPhase::FinalReview);
+                self.enter_phase(state, AgentPhase::FinalReview)?;
+
+                let final_review = self.final_review(input, state, &summary, progress).await?;
+
+                // UI notification for final review result
+                progress.on_quorum_complete_with_votes(
+                    "final_review",
+                    final_review.passed,
+                    &final_review.votes,
+                    final_review.aggregated_feedback.as_deref(),
+                );
 
-            // UI notification for final review result
-            progress.on_quorum_complete_with_votes(
-                "final_review",
-                final_review.passed,
-                &final_review.votes,
-                final_review.aggregated_feedback.as_deref(),
-            );
+                if final_review.passed {
+                    state.add_thought(Thought::conclusion("Final review passed"));
+                    break;
+                }
 
-            if !final_review.passed {
                 state.add_thought(Thought::observation(format!(
                     "Final review raised concerns: {}",
                     final_review
@@ -806,8 +1106,52 @@ impl RunAgentUseCase {
                         .as_deref()
                         .unwrap_or("No details")
                 )));
-            } else {
-                state.add_thought(Thought::conclusion("Final review passed"));
+
+                if fix_cycles >= input.policy.max_final_review_fixes {
+                    break;
+                }
+                let Some(fixes) = review::corrective_plan(&final_review) else {
+                    break;
+                };
+                let Some(plan) = state.plan.as_mut() else {
+                    break;
+                };
+                fix_cycles += 1;
+                let fix_ids = plan.append_follow_up(fixes, &format!("fix{}", fix_cycles));
+                if fix_ids.is_empty() {
+                    break;
+                }
+                state.add_thought(Thought::reasoning(format!(
+                    "Final review proposed {} corrective task(s); re-entering execution (fix cycle {}/{})",
+                    fix_ids.len(),
+                    fix_cycles,
+                    input.policy.max_final_review_fixes
+                )));
+
+                progress.on_phase_change(&AgentPhase::Executing);
+                self.enter_phase(state, AgentPhase::Executing)?;
+
+                match execute_uc
+                    .execute(input, state, &system_prompt, progress)
+                    .await
+                {
+                    Ok(fix_summary) => {
+                        summary = format!(
+                            "{}\n\n## Corrective tasks (fix cycle {})\n\n{}",
+                            summary, fix_cycles, fix_summary
+                        );
+                    }
+                    Err(e) if e.is_cancelled() || e.is_cost_limit_exceeded() => return Err(e),
+                    Err(e) => {
+                        let summary = format!(
+                            "{}\n\nAgent failed during corrective execution: {}",
+                            summary, e
+                        );
+                        state.fail(e.to_string());
+                        self.log_agent_complete(state, &summary, false);
+                        return Ok((summary, false));
+                    }
+                }
             }
         }
 
@@ -828,6 +1172,23 @@ fn chrono_lite_timestamp() -> u64 {
         .as_millis() as u64
 }
 
+/// Summary for a run stopped by `agent.max_cost_tokens`: how far it got and
+/// how to let it go further.
+fn cost_limit_summary(state: &AgentState, used: u64, limit: u64) -> String {
+    let progress = match state.plan.as_ref().map(|plan| plan.progress()) {
+        Some((finished, total)) => format!(
+            "{}/{} task(s) finished before stopping in the {} phase.",
+            finished, total, state.phase
+        ),
+        None => format!("Stopped in the {} phase, before any plan ran.", state.phase),
+    };
+    format!(
+        "Agent stopped: token usage reached {} tokens, over the limit of {}.\n\n{}\n\n\
+         Raise `agent.max_cost_tokens` (or pass a larger `--max-cost-tokens`) to let the run go further.",
+        used, limit, progress
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -846,7 +1207,7 @@ mod tests {
             RunAgentError::InvalidConfig("test".to_string()),
             RunAgentError::PlanningFailed("test".to_string()),
             RunAgentError::MaxIterationsExceeded,
-            RunAgentError::QuorumFailed,
+            RunAgentError::QuorumFailed(vec![]),
         ];
 
         for error in errors {
@@ -864,15 +1225,22 @@ mod tests {
     // ==================== Flow Test Infrastructure ====================
 
     use crate::config::ExecutionParams;
+    use crate::ports::event_publisher::{AppEvent, RecordingEventPublisher};
     use crate::ports::human_intervention::{HumanInterventionError, HumanInterventionPort};
-    use crate::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession, ToolResultMessage};
+    use crate::ports::llm_gateway::{
+        GatewayError, LlmGateway, LlmSession, StreamObserver, ToolResultMessage,
+    };
     use crate::ports::tool_executor::ToolExecutorPort;
     use crate::ports::tool_schema::ToolSchemaPort;
     use async_trait::async_trait;
-    use quorum_domain::session::response::{ContentBlock, LlmResponse, StopReason};
+    use quorum_domain::quorum::{ConsensusOutcome, QuorumResultPayload, QuorumTopic};
+    use quorum_domain::session::response::{ContentBlock, LlmResponse, StopReason, TokenUsage};
     use quorum_domain::tool::entities::{ToolCall, ToolDefinition, ToolSpec};
     use quorum_domain::tool::value_objects::ToolResult;
-    use quorum_domain::{AgentPolicy, ConsensusLevel, Model, ModelConfig, PhaseScope, SessionMode};
+    use quorum_domain::{
+        AgentContext, AgentPolicy, ConsensusLevel, EnsembleSelection, Model, ModelConfig,
+        PhaseScope, SessionMode, ToolStat,
+    };
     use std::collections::{HashMap, VecDeque};
     use std::sync::{Arc, Mutex};
 
@@ -914,12 +1282,23 @@ mod tests {
         Response(LlmResponse),
         /// Return an error
         Error(String),
+        /// Return a non-retryable auth error
+        Unauthorized(String),
+        /// Return a rate-limit error asking to wait the given duration
+        RateLimited(std::time::Duration),
+        /// Never respond (simulates a hung model)
+        Hang,
+        /// Text whose chunks are also passed to the session's stream observer
+        Stream(Vec<String>),
+        /// Wait at the barrier, then answer with the inner response
+        Rendezvous(Arc<tokio::sync::Barrier>, Box<ScriptedResponse>),
     }
 
     /// Mock session that returns scripted responses in order
     struct ScriptedSession {
         model: Model,
         responses: Mutex<VecDeque<ScriptedResponse>>,
+        observer: Option<StreamObserver>,
     }
 
     impl ScriptedSession {
@@ -927,15 +1306,33 @@ mod tests {
             Self {
                 model,
                 responses: Mutex::new(responses.into()),
+                observer: None,
             }
         }
 
         fn next_response(&self) -> ScriptedResponse {
-            self.responses
+            let response = self
+                .responses
                 .lock()
                 .unwrap()
                 .pop_front()
-                .unwrap_or(ScriptedResponse::Text("(no more responses)".to_string()))
+                .unwrap_or(ScriptedResponse::Text("(no more responses)".to_string()));
+            if let (ScriptedResponse::Stream(chunks), Some(observer)) = (&response, &self.observer)
+            {
+                for chunk in chunks {
+                    observer(chunk);
+                }
+            }
+            response
+        }
+
+        async fn next_ready_response(&self) -> ScriptedResponse {
+            let mut response = self.next_response();
+            while let ScriptedResponse::Rendezvous(barrier, inner) = response {
+                barrier.wait().await;
+                response = *inner;
+            }
+            response
         }
     }
 
@@ -946,10 +1343,17 @@ mod tests {
         }
 
         async fn send(&self, _content: &str) -> Result<String, GatewayError> {
-            match self.next_response() {
+            match self.next_ready_response().await {
                 ScriptedResponse::Text(t) => Ok(t),
+                ScriptedResponse::Stream(chunks) => Ok(chunks.concat()),
                 ScriptedResponse::Response(r) => Ok(r.text_content()),
                 ScriptedResponse::Error(e) => Err(GatewayError::RequestFailed(e)),
+                ScriptedResponse::Unauthorized(e) => Err(GatewayError::Unauthorized(e)),
+                ScriptedResponse::RateLimited(wait) => Err(GatewayError::RateLimited {
+                    retry_after: Some(wait),
+                }),
+                ScriptedResponse::Hang => std::future::pending().await,
+                ScriptedResponse::Rendezvous(..) => unreachable!("resolved above"),
             }
         }
 
@@ -958,10 +1362,17 @@ mod tests {
             _content: &str,
             _tools: &[serde_json::Value],
         ) -> Result<LlmResponse, GatewayError> {
-            match self.next_response() {
+            match self.next_ready_response().await {
                 ScriptedResponse::Text(t) => Ok(LlmResponse::from_text(t)),
+                ScriptedResponse::Stream(chunks) => Ok(LlmResponse::from_text(chunks.concat())),
                 ScriptedResponse::Response(r) => Ok(r),
                 ScriptedResponse::Error(e) => Err(GatewayError::RequestFailed(e)),
+                ScriptedResponse::Unauthorized(e) => Err(GatewayError::Unauthorized(e)),
+                ScriptedResponse::RateLimited(wait) => Err(GatewayError::RateLimited {
+                    retry_after: Some(wait),
+                }),
+                ScriptedResponse::Hang => std::future::pending().await,
+                ScriptedResponse::Rendezvous(..) => unreachable!("resolved above"),
             }
         }
 
@@ -969,10 +1380,17 @@ mod tests {
             &self,
             _results: &[ToolResultMessage],
         ) -> Result<LlmResponse, GatewayError> {
-            match self.next_response() {
+            match self.next_ready_response().await {
                 ScriptedResponse::Text(t) => Ok(LlmResponse::from_text(t)),
+                ScriptedResponse::Stream(chunks) => Ok(LlmResponse::from_text(chunks.concat())),
                 ScriptedResponse::Response(r) => Ok(r),
                 ScriptedResponse::Error(e) => Err(GatewayError::RequestFailed(e)),
+                ScriptedResponse::Unauthorized(e) => Err(GatewayError::Unauthorized(e)),
+                ScriptedResponse::RateLimited(wait) => Err(GatewayError::RateLimited {
+                    retry_after: Some(wait),
+                }),
+                ScriptedResponse::Hang => std::future::pending().await,
+                ScriptedResponse::Rendezvous(..) => unreachable!("resolved above"),
             }
         }
     }
@@ -1042,15 +1460,35 @@ mod tests {
             self.create_session(model).await
         }
 
+        async fn create_streaming_session(
+            &self,
+            model: &Model,
+            _system_prompt: &str,
+            observer: StreamObserver,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            let model_str = model.to_string();
+            self.created_sessions
+                .lock()
+                .unwrap()
+                .push(model_str.clone());
+            let mut session =
+                ScriptedSession::new(model.clone(), self.get_session_responses(&model_str));
+            session.observer = Some(observer);
+            Ok(Box::new(session))
+        }
+
         async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
             Ok(vec![Model::ClaudeSonnet45])
         }
     }
 
     /// Mock tool executor that records calls and returns success
+    /// (except for `failing_tool`, which always fails)
     struct MockToolExecutor {
         spec: ToolSpec,
         calls: Mutex<Vec<String>>,
+        output: String,
+        failing_tool: Option<String>,
     }
 
     impl MockToolExecutor {
@@ -1069,7 +1507,30 @@ mod tests {
             Self {
                 spec,
                 calls: Mutex::new(Vec::new()),
+                output: "ok".to_string(),
+                failing_tool: None,
+            }
+        }
+
+        fn with_output(mut self, output: impl Into<String>) -> Self {
+            self.output = output.into();
+            self
+        }
+
+        fn with_failing_tool(mut self, tool_name: impl Into<String>) -> Self {
+            self.failing_tool = Some(tool_name.into());
+            self
+        }
+
+        fn result_for(&self, call: &ToolCall) -> ToolResult {
+            self.calls.lock().unwrap().push(call.tool_name.clone());
+            if self.failing_tool.as_deref() == Some(call.tool_name.as_str()) {
+                return ToolResult::failure(
+                    &call.tool_name,
+                    quorum_domain::ToolError::not_found("mock"),
+                );
             }
+            ToolResult::success(&call.tool_name, &self.output)
         }
     }
 
@@ -1080,13 +1541,11 @@ mod tests {
         }
 
         async fn execute(&self, call: &ToolCall) -> ToolResult {
-            self.calls.lock().unwrap().push(call.tool_name.clone());
-            ToolResult::success(&call.tool_name, "ok")
+            self.result_for(call)
         }
 
         fn execute_sync(&self, call: &ToolCall) -> ToolResult {
-            self.calls.lock().unwrap().push(call.tool_name.clone());
-            ToolResult::success(&call.tool_name, "ok")
+            self.result_for(call)
         }
     }
 
@@ -1097,6 +1556,7 @@ mod tests {
         /// Track how many times each method was called
         intervention_calls: Mutex<usize>,
         execution_confirmation_calls: Mutex<usize>,
+        action_approval_calls: Mutex<usize>,
     }
 
     impl MockHumanIntervention {
@@ -1106,6 +1566,7 @@ mod tests {
                 execution_confirmation_decision: Mutex::new(decision),
                 intervention_calls: Mutex::new(0),
                 execution_confirmation_calls: Mutex::new(0),
+                action_approval_calls: Mutex::new(0),
             }
         }
     }
@@ -1130,12 +1591,30 @@ mod tests {
             *self.execution_confirmation_calls.lock().unwrap() += 1;
             Ok(self.execution_confirmation_decision.lock().unwrap().clone())
         }
+
+        async fn request_action_approval(
+            &self,
+            _task: &quorum_domain::Task,
+            _tool_call_json: &str,
+            _reason: &str,
+        ) -> Result<HumanDecision, HumanInterventionError> {
+            *self.action_approval_calls.lock().unwrap() += 1;
+            Ok(HumanDecision::Reject)
+        }
     }
 
     /// Tracking progress notifier that records phase transitions
     struct TrackingProgress {
         phases: Mutex<Vec<AgentPhase>>,
         execution_confirmation_count: Mutex<usize>,
+        timed_out_models: Mutex<Vec<Model>>,
+        fallback_reasons: Mutex<Vec<String>>,
+        quorum_chunks: Mutex<Vec<(Model, String)>>,
+        ensemble_chunks: Mutex<Vec<(Model, String)>>,
+        context_files: Mutex<Vec<(String, String)>>,
+        /// `selected <model>` / `merged <moderator>` per ensemble result
+        ensemble_outcomes: Mutex<Vec<String>>,
+        plan_review_rounds: Mutex<Vec<usize>>,
     }
 
     impl TrackingProgress {
@@ -1143,9 +1622,38 @@ mod tests {
             Self {
                 phases: Mutex::new(Vec::new()),
                 execution_confirmation_count: Mutex::new(0),
+                timed_out_models: Mutex::new(Vec::new()),
+                fallback_reasons: Mutex::new(Vec::new()),
+                quorum_chunks: Mutex::new(Vec::new()),
+                ensemble_chunks: Mutex::new(Vec::new()),
+                context_files: Mutex::new(Vec::new()),
+                ensemble_outcomes: Mutex::new(Vec::new()),
+                plan_review_rounds: Mutex::new(Vec::new()),
             }
         }
 
+        /// Streamed ensemble planning chunks from `model`, in arrival order
+        fn ensemble_chunks_for(&self, model: &Model) -> Vec<String> {
+            self.ensemble_chunks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(m, _)| m == model)
+                .map(|(_, chunk)| chunk.clone())
+                .collect()
+        }
+
+        /// Streamed review chunks from `model`, in arrival order
+        fn quorum_chunks_for(&self, model: &Model) -> Vec<String> {
+            self.quorum_chunks
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(m, _)| m == model)
+                .map(|(_, chunk)| chunk.clone())
+                .collect()
+        }
+
         fn has_phase(&self, phase: &AgentPhase) -> bool {
             self.phases.lock().unwrap().contains(phase)
         }
@@ -1156,9 +1664,70 @@ mod tests {
             self.phases.lock().unwrap().push(phase.clone());
         }
 
-        fn on_execution_confirmation_required(&self, _request: &str, _plan: &Plan) {
-            *self.execution_confirmation_count.lock().unwrap() += 1;
-        }
+        fn on_execution_confirmation_required(
+            &self,
+            _request: &str,
+            _plan: &Plan,
+            _impact: &quorum_domain::PlanImpact,
+        ) {
+            *self.execution_confirmation_count.lock().unwrap() += 1;
+        }
+
+        fn on_ensemble_model_timeout(&self, model: &Model) {
+            self.timed_out_models.lock().unwrap().push(model.clone());
+        }
+
+        fn on_ensemble_fallback(&self, reason: &str) {
+            self.fallback_reasons
+                .lock()
+                .unwrap()
+                .push(reason.to_string());
+        }
+
+        fn on_plan_reviewed(
+            &self,
+            _plan: &Plan,
+            round: usize,
+            _approved: bool,
+            _votes: &[quorum_domain::Vote],
+        ) {
+            self.plan_review_rounds.lock().unwrap().push(round);
+        }
+
+        fn on_ensemble_complete(&self, selected_model: &Model, _score: f64) {
+            self.ensemble_outcomes
+                .lock()
+                .unwrap()
+                .push(format!("selected {}", selected_model));
+        }
+
+        fn on_ensemble_merged(&self, moderator: &Model, _source_count: usize) {
+            self.ensemble_outcomes
+                .lock()
+                .unwrap()
+                .push(format!("merged {}", moderator));
+        }
+
+        fn on_ensemble_model_chunk(&self, model: &Model, chunk: &str) {
+            self.ensemble_chunks
+                .lock()
+                .unwrap()
+                .push((model.clone(), chunk.to_string()));
+        }
+
+        fn on_quorum_model_chunk(&self, model: &Model, chunk: &str) {
+            self.quorum_chunks
+                .lock()
+                .unwrap()
+                .push((model.clone(), chunk.to_string()));
+        }
+
+        fn on_context_file_loaded(&self, path: &str, source: &str) {
+            self.context_files
+                .lock()
+                .unwrap()
+                .push((path.to_string(), source.to_string()));
+        }
     }
 
     /// Wraps [`TrackingProgress`] and cancels a [`CancellationToken`] as soon
@@ -1178,8 +1747,14 @@ mod tests {
             }
         }
 
-        fn on_execution_confirmation_required(&self, request: &str, plan: &Plan) {
-            self.inner.on_execution_confirmation_required(request, plan);
+        fn on_execution_confirmation_required(
+            &self,
+            request: &str,
+            plan: &Plan,
+            impact: &quorum_domain::PlanImpact,
+        ) {
+            self.inner
+                .on_execution_confirmation_required(request, plan, impact);
         }
     }
 
@@ -1209,6 +1784,7 @@ mod tests {
             }],
             stop_reason: Some(StopReason::ToolUse),
             model: None,
+            usage: None,
         })
     }
 
@@ -1224,9 +1800,18 @@ mod tests {
         policy: AgentPolicy,
         execution: ExecutionParams,
         gateway: ScriptedGateway,
-        tool_executor: MockToolExecutor,
+        tool_executor: Arc<MockToolExecutor>,
         human_intervention: Option<Arc<dyn HumanInterventionPort>>,
         event_publisher: Option<Arc<dyn EventPublisher>>,
+        /// Defaults to no retries so scripted errors surface immediately.
+        retry_policy: RetryPolicy,
+        context_loader: Option<Arc<dyn ContextLoaderPort>>,
+        initial_context: Option<AgentContext>,
+        preloaded_plan: Option<Plan>,
+        ensemble_branches: EnsembleBranches,
+        decision_log: Option<Arc<dyn DecisionLogPort>>,
+        user_context: Vec<String>,
+        conversation_logger: Option<Arc<dyn ConversationLogger>>,
     }
 
     impl FlowTestBuilder {
@@ -1253,10 +1838,15 @@ mod tests {
             let execution = ExecutionParams {
                 max_iterations: 50,
                 max_tool_turns: 3,
+                ask_max_tool_turns: None,
                 max_tool_retries: 2,
+                max_action_retries: 1,
                 working_dir: None,
                 ensemble_session_timeout: None,
+                review_timeout: None,
                 context_budget: quorum_domain::ContextBudget::default(),
+                max_parallel_tasks: 4,
+                max_cost_tokens: None,
             };
             let mut gateway = ScriptedGateway::new();
 
@@ -1294,9 +1884,17 @@ mod tests {
                 policy,
                 execution,
                 gateway,
-                tool_executor: MockToolExecutor::new(),
+                tool_executor: Arc::new(MockToolExecutor::new()),
                 human_intervention: None,
                 event_publisher: None,
+                conversation_logger: None,
+                retry_policy: RetryPolicy::none(),
+                context_loader: None,
+                initial_context: None,
+                preloaded_plan: None,
+                ensemble_branches: EnsembleBranches::new(),
+                decision_log: None,
+                user_context: Vec::new(),
             }
         }
 
@@ -1342,10 +1940,15 @@ mod tests {
             let execution = ExecutionParams {
                 max_iterations: 50,
                 max_tool_turns: 3,
+                ask_max_tool_turns: None,
                 max_tool_retries: 2,
+                max_action_retries: 1,
                 working_dir: None,
                 ensemble_session_timeout: None,
+                review_timeout: None,
                 context_budget: quorum_domain::ContextBudget::default(),
+                max_parallel_tasks: 4,
+                max_cost_tokens: None,
             };
             let mut gateway = ScriptedGateway::new();
 
@@ -1391,9 +1994,17 @@ mod tests {
                 policy,
                 execution,
                 gateway,
-                tool_executor: MockToolExecutor::new(),
+                tool_executor: Arc::new(MockToolExecutor::new()),
                 human_intervention: None,
                 event_publisher: None,
+                conversation_logger: None,
+                retry_policy: RetryPolicy::none(),
+                context_loader: None,
+                initial_context: None,
+                preloaded_plan: None,
+                ensemble_branches: EnsembleBranches::new(),
+                decision_log: None,
+                user_context: Vec::new(),
             }
         }
 
@@ -1443,6 +2054,11 @@ mod tests {
             self
         }
 
+        fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+            self.retry_policy = policy;
+            self
+        }
+
         fn with_hil_mode(mut self, hil_mode: HilMode) -> Self {
             self.policy.hil_mode = hil_mode;
             self
@@ -1471,9 +2087,12 @@ mod tests {
         async fn execute(self) -> (Result<RunAgentOutput, RunAgentError>, TrackingProgress) {
             let progress = TrackingProgress::new();
             let gateway = Arc::new(self.gateway);
-            let executor = Arc::new(self.tool_executor);
+            let executor = self.tool_executor;
 
-            let mut use_case = RunAgentUseCase::new(gateway, executor, mock_tool_schema());
+            let mut use_case = RunAgentUseCase::new(gateway, executor, mock_tool_schema())
+                .with_retry_policy(self.retry_policy)
+                .with_ensemble_branches(self.ensemble_branches);
+            use_case.context_loader = self.context_loader;
 
             if let Some(intervention) = self.human_intervention {
                 use_case = use_case.with_human_intervention(intervention);
@@ -1481,14 +2100,27 @@ mod tests {
             if let Some(publisher) = self.event_publisher {
                 use_case = use_case.with_event_publisher(publisher);
             }
+            if let Some(logger) = self.conversation_logger {
+                use_case = use_case.with_conversation_logger(logger);
+            }
+            if let Some(log) = self.decision_log {
+                use_case = use_case.with_decision_log(log);
+            }
 
-            let input = RunAgentInput::new(
+            let mut input = RunAgentInput::new(
                 "Test request",
                 self.mode,
                 self.models,
                 self.policy,
                 self.execution,
             );
+            if let Some(context) = self.initial_context {
+                input = input.with_initial_context(context);
+            }
+            input = input.with_user_context(self.user_context);
+            if let Some(plan) = self.preloaded_plan {
+                input = input.with_preloaded_plan(plan);
+            }
             let result = use_case.execute_with_progress(input, &progress).await;
 
             (result, progress)
@@ -1508,10 +2140,11 @@ mod tests {
                 trigger_phase,
             };
             let gateway = Arc::new(self.gateway);
-            let executor = Arc::new(self.tool_executor);
+            let executor = self.tool_executor;
 
             let mut use_case = RunAgentUseCase::new(gateway, executor, mock_tool_schema())
-                .with_cancellation(token);
+                .with_cancellation(token)
+                .with_retry_policy(self.retry_policy);
 
             if let Some(intervention) = self.human_intervention {
                 use_case = use_case.with_human_intervention(intervention);
@@ -1550,6 +2183,338 @@ mod tests {
         assert!(progress.has_phase(&AgentPhase::Executing));
     }
 
+    #[tokio::test]
+    async fn test_gather_context_disabled_skips_phase_but_still_plans() {
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.policy.gather_context = false;
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success, "summary: {}", output.summary);
+        assert!(!progress.has_phase(&AgentPhase::ContextGathering));
+        assert!(progress.has_phase(&AgentPhase::Planning));
+        assert!(progress.has_phase(&AgentPhase::PlanReview));
+        assert!(output.state.context.project_type.is_none());
+        assert!(
+            !output
+                .state
+                .token_usage
+                .contains_key(&AgentPhase::ContextGathering)
+        );
+    }
+
+    /// In-memory decision log.
+    #[derive(Default)]
+    struct MemoryDecisionLog {
+        records: Mutex<Vec<DecisionRecord>>,
+    }
+
+    impl DecisionLogPort for MemoryDecisionLog {
+        fn append(&self, record: &DecisionRecord) -> std::io::Result<()> {
+            self.records.lock().unwrap().push(record.clone());
+            Ok(())
+        }
+
+        fn recent(&self, n: usize) -> Vec<DecisionRecord> {
+            let records = self.records.lock().unwrap();
+            records[records.len().saturating_sub(n)..].to_vec()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approved_plan_recorded_and_prior_decisions_loaded() {
+        let prior = DecisionRecord::new(
+            "Earlier request",
+            "Earlier objective",
+            DecisionOutcome::EnsembleSelected,
+            1,
+        );
+        let log = Arc::new(MemoryDecisionLog::default());
+        log.append(&prior).unwrap();
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.decision_log = Some(log.clone());
+
+        let (result, _progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert_eq!(output.state.context.prior_decisions, vec![prior]);
+        let records = log.recent(5);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].request, "Test request");
+        assert_eq!(
+            Some(records[1].objective.as_str()),
+            output.state.plan.as_ref().map(|p| p.objective.as_str())
+        );
+        assert_eq!(records[1].outcome, DecisionOutcome::QuorumApproved);
+    }
+
+    #[tokio::test]
+    async fn test_unreviewed_plan_is_not_recorded() {
+        // Fast はレビューしないので、合意された決定として残さない
+        let log = Arc::new(MemoryDecisionLog::default());
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.decision_log = Some(log.clone());
+
+        let (result, _progress) = builder.execute().await;
+
+        assert!(result.expect("should succeed").success);
+        assert!(log.recent(5).is_empty());
+    }
+
+    /// Records `(event_type, payload)` for every logged conversation event.
+    #[derive(Default)]
+    struct RecordingLogger {
+        events: std::sync::Mutex<Vec<(String, serde_json::Value)>>,
+    }
+
+    impl ConversationLogger for RecordingLogger {
+        fn log(&self, event: ConversationEvent) {
+            self.events
+                .lock()
+                .unwrap()
+                .push((event.event_type.to_string(), event.payload));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_solo_full_flow_logs_phase_timings() {
+        let logger = Arc::new(RecordingLogger::default());
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.conversation_logger = Some(logger.clone());
+
+        let (result, _progress) = builder.execute().await;
+        assert!(result.expect("should succeed").success);
+
+        let timed: Vec<String> = logger
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(event_type, _)| event_type == "phase_timing")
+            .map(|(_, payload)| {
+                assert!(payload["duration_ms"].is_u64());
+                payload["phase"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(
+            timed,
+            vec!["context_gathering", "planning", "plan_review", "executing"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_final_review_corrective_tasks_are_executed() {
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.policy.require_final_review = true;
+
+        let haiku = Model::ClaudeHaiku45.to_string();
+        let sonnet = Model::ClaudeSonnet45.to_string();
+        let mut gateway = ScriptedGateway::new();
+        // Exploration model: context gathering, then the read_file task
+        gateway.add_session(
+            &haiku,
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(
+            &haiku,
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Read the file",
+            ))],
+        );
+        gateway.add_session(&sonnet, vec![make_plan_response("Test plan")]);
+        gateway.add_session(&sonnet, vec![ScriptedResponse::Text(approve_response())]);
+        // First final review rejects and proposes one corrective task
+        gateway.add_session(
+            &sonnet,
+            vec![ScriptedResponse::Text(
+                "FAILURE: the README was not updated.\n\n```plan\n{\"objective\": \"Fix\", \"tasks\": [{\"id\": \"1\", \"description\": \"Update the README\"}]}\n```".to_string(),
+            )],
+        );
+        // The corrective task has no planned tool, so the decision model runs it
+        gateway.add_session(
+            &sonnet,
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "README updated",
+            ))],
+        );
+        gateway.add_session(&sonnet, vec![ScriptedResponse::Text("SUCCESS".to_string())]);
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success, "summary: {}", output.summary);
+        assert!(
+            output.summary.contains("Corrective tasks (fix cycle 1)"),
+            "summary: {}",
+            output.summary
+        );
+        let plan = output.state.plan.unwrap();
+        assert_eq!(plan.tasks.len(), 2);
+        let fix = &plan.tasks[1];
+        assert_eq!(fix.id.as_str(), "fix1.1");
+        assert_eq!(fix.status, quorum_domain::TaskStatus::Completed);
+        assert_eq!(fix.result.as_ref().unwrap().output, "README updated");
+        assert_eq!(
+            progress
+                .phases
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|p| **p == AgentPhase::FinalReview)
+                .count(),
+            2
+        );
+    }
+
+    fn submit_review_response(approved: bool, score: u8, reasoning: &str) -> ScriptedResponse {
+        let mut input = HashMap::new();
+        input.insert("approved".to_string(), serde_json::json!(approved));
+        input.insert("score".to_string(), serde_json::json!(score));
+        input.insert("reasoning".to_string(), serde_json::json!(reasoning));
+        ScriptedResponse::Response(LlmResponse {
+            content: vec![ContentBlock::ToolUse {
+                id: "toolu_review_001".to_string(),
+                name: "submit_review".to_string(),
+                input,
+            }],
+            stop_reason: Some(StopReason::ToolUse),
+            model: None,
+            usage: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_request_review_reads_submit_review_tool_call() {
+        let mut gateway = ScriptedGateway::new();
+        // The verdict comes from the tool call, even though the reasoning
+        // contains words the text parser would read as approval
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![submit_review_response(
+                false,
+                3,
+                "Would APPROVE if step 2 did not delete the cache.",
+            )],
+        );
+
+        let submission =
+            review::request_review(&gateway, &Model::ClaudeSonnet45, "Review this", None)
+                .await
+                .unwrap();
+
+        assert!(!submission.approved);
+        assert_eq!(submission.score, Some(3));
+        let vote = review::review_vote(&Model::ClaudeSonnet45, submission);
+        assert!(vote.is_reject());
+        assert_eq!(vote.confidence, Some(0.3));
+    }
+
+    #[tokio::test]
+    async fn test_request_review_falls_back_to_text_verdict() {
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+
+        let submission =
+            review::request_review(&gateway, &Model::ClaudeSonnet45, "Review this", None)
+                .await
+                .unwrap();
+
+        assert!(submission.approved);
+        assert_eq!(submission.score, None);
+        assert_eq!(submission.reasoning, approve_response());
+        let vote = review::review_vote(&Model::ClaudeSonnet45, submission);
+        assert_eq!(vote.confidence, None);
+    }
+
+    #[tokio::test]
+    async fn test_plan_review_rejection_via_submit_review_triggers_revision() {
+        let mut builder = FlowTestBuilder::solo_full();
+        let sonnet = Model::ClaudeSonnet45.to_string();
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(&sonnet, vec![make_plan_response("First plan")]);
+        gateway.add_session(
+            &sonnet,
+            vec![submit_review_response(false, 2, "Missing a test step.")],
+        );
+        gateway.add_session(&sonnet, vec![make_plan_response("Revised plan")]);
+        gateway.add_session(
+            &sonnet,
+            vec![submit_review_response(true, 8, "Covers the tests now.")],
+        );
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success, "summary: {}", output.summary);
+        assert_eq!(output.state.plan_revision_count, 1);
+        assert_eq!(
+            output.state.plan.as_ref().unwrap().objective,
+            "Revised plan"
+        );
+        assert!(progress.has_phase(&AgentPhase::Executing));
+        // The revised plan starts a fresh review_history, but the round keeps counting
+        assert_eq!(*progress.plan_review_rounds.lock().unwrap(), vec![1, 2]);
+
+        let run = output.state.plan_review.as_ref().unwrap();
+        let rounds = run.rounds();
+        assert_eq!(rounds.len(), 2);
+        assert!(rounds[0].is_rejected());
+        assert_eq!(rounds[0].context.as_deref(), Some("First plan"));
+        assert!(rounds[1].is_approved());
+        assert_eq!(rounds[1].context.as_deref(), Some("Revised plan"));
+        assert_eq!(run.final_outcome(), Some(ConsensusOutcome::Approved));
+    }
+
+    #[tokio::test]
+    async fn test_plan_review_streams_reasoning_per_model() {
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.models.review = vec![Model::ClaudeHaiku45, Model::ClaudeSonnet45];
+
+        let haiku = Model::ClaudeHaiku45.to_string();
+        let sonnet = Model::ClaudeSonnet45.to_string();
+        let stream = |chunks: &[&str]| {
+            ScriptedResponse::Stream(chunks.iter().map(|c| c.to_string()).collect())
+        };
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &haiku,
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(&sonnet, vec![make_plan_response("Test plan")]);
+        gateway.add_session(&haiku, vec![stream(&["Reads only. ", "APPROVE"])]);
+        gateway.add_session(&sonnet, vec![stream(&["Safe", " plan. ", "APPROVE"])]);
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success, "summary: {}", output.summary);
+        assert_eq!(
+            progress.quorum_chunks_for(&Model::ClaudeHaiku45),
+            vec!["Reads only. ", "APPROVE"]
+        );
+        assert_eq!(
+            progress.quorum_chunks_for(&Model::ClaudeSonnet45),
+            vec!["Safe", " plan. ", "APPROVE"]
+        );
+    }
+
     #[tokio::test]
     async fn test_cancellation_mid_flight_returns_cancelled_with_partial_state() {
         // Cancel as soon as Context Gathering is reached — this fires before
@@ -1648,6 +2613,29 @@ mod tests {
         assert!(envelope.votes[0].is_approve());
     }
 
+    #[test]
+    fn test_action_reviewer_applies_command_risk_overrides() {
+        use crate::ports::action_reviewer::ActionReviewer;
+        use crate::ports::event_publisher::RecordingEventPublisher;
+        use quorum_domain::RiskLevel;
+
+        let reviewer = super::review::QuorumActionReviewer::new(
+            Arc::new(ScriptedGateway::new()),
+            Arc::new(MockToolExecutor::new()),
+            None,
+            Arc::new(RecordingEventPublisher::new()),
+        )
+        .with_risk_overrides(HashMap::from([
+            ("make".to_string(), RiskLevel::Low),
+            ("git log".to_string(), RiskLevel::High),
+        ]));
+        let command = |cmd: &str| HashMap::from([("command".to_string(), serde_json::json!(cmd))]);
+
+        assert!(!reviewer.is_high_risk_tool("run_command", &command("make build")));
+        assert!(reviewer.is_high_risk_tool("run_command", &command("git log -5")));
+        assert!(reviewer.is_high_risk_tool("run_command", &command("rm -rf target")));
+    }
+
     #[tokio::test]
     async fn test_action_review_publishes_quorum_result_event() {
         use crate::ports::event_publisher::{AppEvent, RecordingEventPublisher};
@@ -1727,98 +2715,1453 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_full_execution_confirmation_reject_stops() {
-        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
-            HumanDecision::Reject,
-        ));
-
-        let (result, progress) = FlowTestBuilder::solo_full()
-            .with_human_intervention(mock_hil.clone())
-            .execute()
-            .await;
+    async fn test_action_review_runs_reviewers_concurrently() {
+        use quorum_domain::Task;
+        use quorum_domain::quorum::VoteVerdict;
 
-        let output = result.expect("should succeed (graceful stop, not error)");
-        assert!(output.success);
-        assert!(output.summary.contains("not executed"));
+        // Every reviewer waits for the other two before answering, so a
+        // sequential review would never finish.
+        let barrier = Arc::new(tokio::sync::Barrier::new(3));
+        let after_barrier = |response: ScriptedResponse| {
+            ScriptedResponse::Rendezvous(barrier.clone(), Box::new(response))
+        };
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![after_barrier(ScriptedResponse::Text(approve_response()))],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![after_barrier(ScriptedResponse::Error(
+                "gateway down".to_string(),
+            ))],
+        );
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![after_barrier(ScriptedResponse::Text(approve_response()))],
+        );
 
-        // Plan review happened but execution did not
-        assert!(progress.has_phase(&AgentPhase::PlanReview));
-        assert!(!progress.has_phase(&AgentPhase::Executing));
-        assert_eq!(output.state.phase, AgentPhase::Completed);
+        let publisher = Arc::new(RecordingEventPublisher::new());
+        let reviewer = super::review::QuorumActionReviewer::new(
+            Arc::new(gateway),
+            Arc::new(MockToolExecutor::new()),
+            None,
+            publisher.clone(),
+        )
+        .with_timeout(Some(std::time::Duration::from_secs(5)));
 
-        // Execution confirmation was called
-        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 1);
-    }
+        let task = Task::new("task-1", "Run a risky command");
+        let models = ModelConfig {
+            review: vec![
+                Model::ClaudeOpus45,
+                Model::ClaudeSonnet45,
+                Model::ClaudeHaiku45,
+            ],
+            ..Default::default()
+        };
+        let state = quorum_domain::AgentState::new(
+            "agent-1",
+            "Test request",
+            SessionMode::default(),
+            models.clone(),
+            AgentPolicy::default(),
+            50,
+        );
 
-    #[tokio::test]
+        use crate::ports::action_reviewer::{ActionReviewer, ReviewDecision};
+        let decision = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            reviewer.review_action(
+                r#"{"name": "run_command", "arguments": {"command": "rm -rf target"}}"#,
+                &task,
+                &state,
+                &models,
+                &NoAgentProgress,
+            ),
+        )
+        .await
+        .expect("reviewers should run concurrently")
+        .expect("one erroring reviewer should not fail the review");
+        assert!(matches!(decision, ReviewDecision::Approved));
+
+        // Votes keep the configured reviewer order, not completion order
+        let events = publisher.events.lock().unwrap();
+        let AppEvent::QuorumResult(envelope) = &events[0] else {
+            panic!("expected QuorumResult, got {:?}", events[0]);
+        };
+        let verdicts: Vec<(String, VoteVerdict)> = envelope
+            .votes
+            .iter()
+            .map(|v| (v.model.clone(), v.verdict))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                (Model::ClaudeOpus45.to_string(), VoteVerdict::Approve),
+                (Model::ClaudeSonnet45.to_string(), VoteVerdict::ModelError),
+                (Model::ClaudeHaiku45.to_string(), VoteVerdict::Approve),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_action_review_timed_out_reviewer_is_model_error() {
+        use quorum_domain::Task;
+
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Hang],
+        );
+        let reviewer = super::review::QuorumActionReviewer::new(
+            Arc::new(gateway),
+            Arc::new(MockToolExecutor::new()),
+            None,
+            Arc::new(RecordingEventPublisher::new()),
+        )
+        .with_timeout(Some(std::time::Duration::from_millis(100)));
+
+        let models = ModelConfig {
+            review: vec![Model::ClaudeSonnet45, Model::ClaudeHaiku45],
+            ..Default::default()
+        };
+        let state = quorum_domain::AgentState::new(
+            "agent-1",
+            "Test request",
+            SessionMode::default(),
+            models.clone(),
+            AgentPolicy::default(),
+            50,
+        );
+
+        use crate::ports::action_reviewer::{ActionReviewer, ReviewDecision};
+        let decision = reviewer
+            .review_action(
+                r#"{"name": "run_command", "arguments": {"command": "rm -rf target"}}"#,
+                &Task::new("task-1", "Run a risky command"),
+                &state,
+                &models,
+                &NoAgentProgress,
+            )
+            .await
+            .expect("timed-out reviewer should not fail the review");
+        assert!(matches!(decision, ReviewDecision::Approved));
+    }
+
+    #[tokio::test]
+    async fn test_plan_review_all_reviewers_error_is_quorum_failed() {
+        // Every reviewer errors at the gateway: this must surface as
+        // QuorumFailed, not as a rejection that drives a revision loop.
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.models.review = vec![Model::ClaudeSonnet45, Model::ClaudeHaiku45];
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Test plan")],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Error("sonnet unreachable".to_string())],
+        );
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Error("haiku unreachable".to_string())],
+        );
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
+
+        let error = result.expect_err("all reviewers failing should be an error");
+        let RunAgentError::QuorumFailed(errors) = &error else {
+            panic!("expected QuorumFailed, got {:?}", error);
+        };
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("sonnet unreachable")));
+        assert!(errors.iter().any(|e| e.contains("haiku unreachable")));
+        assert!(error.to_string().starts_with("Could not reach reviewers"));
+
+        // Planning ran exactly once — no revision loop was entered.
+        let planning_rounds = progress
+            .phases
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| **p == AgentPhase::Planning)
+            .count();
+        assert_eq!(planning_rounds, 1);
+        assert!(!progress.has_phase(&AgentPhase::Executing));
+    }
+
+    /// Solo + Full run whose plan review is scripted per reviewer, with
+    /// Gemini 3 Pro configured as the tie-breaker.
+    fn tie_break_builder(
+        reviews: Vec<(Model, &str)>,
+        tie_break_response: &str,
+    ) -> (FlowTestBuilder, Arc<RecordingEventPublisher>) {
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.models.review = reviews.iter().map(|(m, _)| m.clone()).collect();
+        builder.models.tie_breaker = Some(Model::Gemini3Pro);
+
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Test plan")],
+        );
+        for (model, response) in reviews {
+            gateway.add_session(
+                &model.to_string(),
+                vec![ScriptedResponse::Text(response.to_string())],
+            );
+        }
+        gateway.add_session(
+            &Model::Gemini3Pro.to_string(),
+            vec![ScriptedResponse::Text(tie_break_response.to_string())],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed successfully",
+            ))],
+        );
+        builder.gateway = gateway;
+
+        let publisher = Arc::new(RecordingEventPublisher::new());
+        let builder = builder.with_event_publisher(publisher.clone());
+        (builder, publisher)
+    }
+
+    /// The first plan-review QuorumResult published during a run.
+    fn first_plan_review(publisher: &RecordingEventPublisher) -> QuorumResultPayload {
+        publisher
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|e| match e {
+                AppEvent::QuorumResult(p) if p.topic == QuorumTopic::PlanReview => {
+                    Some((**p).clone())
+                }
+                _ => None,
+            })
+            .expect("plan review result should be published")
+    }
+
+    fn split_reviews() -> Vec<(Model, &'static str)> {
+        vec![
+            (Model::ClaudeSonnet45, "I APPROVE this plan."),
+            (Model::ClaudeHaiku45, "I APPROVE this plan."),
+            (Model::ClaudeOpus45, "REVISE: missing rollback step"),
+            (Model::Gpt52Codex, "REVISE: no tests"),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_plan_review_tie_resolved_to_approve() {
+        let (builder, publisher) =
+            tie_break_builder(split_reviews(), "APPROVE: the concerns are minor");
+
+        let (result, progress) = builder.execute().await;
+
+        assert!(result.expect("run should succeed").success);
+        let review = first_plan_review(&publisher);
+        assert!(review.approved);
+        assert_eq!(review.votes.len(), 5);
+        assert!(
+            review
+                .votes
+                .iter()
+                .any(|v| v.model == Model::Gemini3Pro.to_string() && v.is_approve())
+        );
+        assert!(progress.has_phase(&AgentPhase::Executing));
+    }
+
+    #[tokio::test]
+    async fn test_plan_review_tie_resolved_to_reject() {
+        let (builder, publisher) =
+            tie_break_builder(split_reviews(), "REVISE: the missing rollback is a blocker");
+
+        let _ = builder.execute().await;
+
+        let review = first_plan_review(&publisher);
+        assert!(!review.approved);
+        assert_eq!(review.votes.len(), 5);
+        assert!(
+            review
+                .votes
+                .iter()
+                .any(|v| v.model == Model::Gemini3Pro.to_string() && v.is_reject())
+        );
+        assert!(
+            review
+                .feedback
+                .as_deref()
+                .is_some_and(|f| f.contains("rollback is a blocker"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_review_odd_quorum_skips_tie_breaker() {
+        // 2-1 has a clear majority: the tie-breaker (scripted to reject)
+        // must never be asked.
+        let (builder, publisher) = tie_break_builder(
+            vec![
+                (Model::ClaudeSonnet45, "I APPROVE this plan."),
+                (Model::ClaudeHaiku45, "I APPROVE this plan."),
+                (Model::ClaudeOpus45, "REVISE: no tests"),
+            ],
+            "REVISE: should not be consulted",
+        );
+
+        let (result, _) = builder.execute().await;
+
+        assert!(result.expect("run should succeed").success);
+        let review = first_plan_review(&publisher);
+        assert!(review.approved);
+        assert_eq!(review.votes.len(), 3);
+        assert!(
+            !review
+                .votes
+                .iter()
+                .any(|v| v.model == Model::Gemini3Pro.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_action_review_honors_unanimous_rule() {
+        use crate::ports::action_reviewer::{ActionReviewer, ReviewDecision};
+        use crate::ports::event_publisher::{AppEvent, RecordingEventPublisher};
+        use quorum_domain::Task;
+        use quorum_domain::quorum::QuorumRule;
+
+        // 2 approve + 1 reject passes under majority but must fail unanimously.
+        let mut gateway = ScriptedGateway::new();
+        for model in [Model::ClaudeSonnet45, Model::ClaudeHaiku45] {
+            gateway.add_session(
+                &model.to_string(),
+                vec![ScriptedResponse::Text(approve_response())],
+            );
+        }
+        gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![ScriptedResponse::Text(
+                "REJECT: this deletes the build cache".to_string(),
+            )],
+        );
+
+        let publisher = Arc::new(RecordingEventPublisher::new());
+        let reviewer = super::review::QuorumActionReviewer::new(
+            Arc::new(gateway),
+            Arc::new(MockToolExecutor::new()),
+            None,
+            publisher.clone(),
+        );
+
+        let models = ModelConfig {
+            review: vec![
+                Model::ClaudeSonnet45,
+                Model::ClaudeHaiku45,
+                Model::ClaudeOpus45,
+            ],
+            ..Default::default()
+        };
+        let state = quorum_domain::AgentState::new(
+            "agent-1",
+            "Test request",
+            SessionMode::default(),
+            models.clone(),
+            AgentPolicy::default().with_quorum_rule(QuorumRule::Unanimous),
+            50,
+        );
+
+        let decision = reviewer
+            .review_action(
+                r#"{"name": "run_command", "arguments": {"command": "rm -rf target"}}"#,
+                &Task::new("task-1", "Clean build"),
+                &state,
+                &models,
+                &NoAgentProgress,
+            )
+            .await
+            .expect("review should succeed");
+        let ReviewDecision::Rejected(feedback) = decision else {
+            panic!("expected rejection under unanimous rule");
+        };
+        assert!(feedback.contains("build cache"));
+
+        let events = publisher.events.lock().unwrap();
+        let AppEvent::QuorumResult(envelope) = &events[0] else {
+            panic!("expected QuorumResult, got {:?}", events[0]);
+        };
+        assert_eq!(envelope.rule, QuorumRule::Unanimous);
+        assert!(!envelope.approved);
+    }
+
+    /// Solo + Fast run whose single task calls the high-risk `write_file`.
+    fn high_risk_action_builder(policy: AgentPolicy) -> FlowTestBuilder {
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.policy = policy;
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        let mut plan = HashMap::new();
+        plan.insert(
+            "objective".to_string(),
+            serde_json::json!("Write the report"),
+        );
+        plan.insert("reasoning".to_string(), serde_json::json!("test reasoning"));
+        plan.insert(
+            "tasks".to_string(),
+            serde_json::json!([{
+                "id": "1",
+                "description": "Write report.txt",
+                "tool": "write_file",
+                "depends_on": []
+            }]),
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse {
+                content: vec![ContentBlock::ToolUse {
+                    id: "toolu_plan_001".to_string(),
+                    name: "create_plan".to_string(),
+                    input: plan,
+                }],
+                stop_reason: Some(StopReason::ToolUse),
+                model: None,
+                usage: None,
+            })],
+        );
+        let mut args = HashMap::new();
+        args.insert("path".to_string(), serde_json::json!("report.txt"));
+        args.insert("content".to_string(), serde_json::json!("done"));
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![
+                ScriptedResponse::Response(LlmResponse {
+                    content: vec![ContentBlock::ToolUse {
+                        id: "toolu_write_001".to_string(),
+                        name: "write_file".to_string(),
+                        input: args,
+                    }],
+                    stop_reason: Some(StopReason::ToolUse),
+                    model: None,
+                    usage: None,
+                }),
+                ScriptedResponse::Response(LlmResponse::from_text("Task finished")),
+            ],
+        );
+        builder.gateway = gateway;
+        builder
+    }
+
+    #[tokio::test]
+    async fn test_high_risk_action_below_reviewer_floor_escalates_to_human() {
+        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
+            HumanDecision::Approve,
+        ));
+        // One configured reviewer, floor of two
+        let policy = AgentPolicy::default()
+            .with_require_plan_review(false)
+            .with_min_reviewers(quorum_domain::RiskLevel::High, 2);
+
+        let builder = high_risk_action_builder(policy).with_human_intervention(mock_hil.clone());
+        let executor = builder.tool_executor.clone();
+        let (result, _) = builder.execute().await;
+
+        result.expect("run should finish");
+        assert_eq!(*mock_hil.action_approval_calls.lock().unwrap(), 1);
+        assert!(
+            executor.calls.lock().unwrap().is_empty(),
+            "the human rejected the action, so write_file must not have run"
+        );
+    }
+
+    /// Batch-review handler that records how many tool calls had already
+    /// run when the batch was presented, then answers with `decision`.
+    struct BatchHil {
+        executor: Arc<MockToolExecutor>,
+        decision: HumanDecision,
+        calls_when_presented: Mutex<Option<usize>>,
+        batch_size: Mutex<usize>,
+        escalations: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl HumanInterventionPort for BatchHil {
+        async fn request_intervention(
+            &self,
+            _request: &str,
+            _plan: &Plan,
+            _review_history: &[ReviewRound],
+        ) -> Result<HumanDecision, HumanInterventionError> {
+            Ok(HumanDecision::Approve)
+        }
+
+        async fn request_batch_approval(
+            &self,
+            _request: &str,
+            actions: &[quorum_domain::QueuedAction],
+        ) -> Result<Vec<HumanDecision>, HumanInterventionError> {
+            *self.calls_when_presented.lock().unwrap() =
+                Some(self.executor.calls.lock().unwrap().len());
+            *self.batch_size.lock().unwrap() = actions.len();
+            *self.escalations.lock().unwrap() =
+                actions.iter().map(|a| a.escalation.clone()).collect();
+            Ok(vec![self.decision.clone(); actions.len()])
+        }
+    }
+
+    async fn run_batch_review(decision: HumanDecision) -> (RunAgentOutput, Arc<BatchHil>) {
+        let policy = AgentPolicy::default()
+            .with_require_plan_review(false)
+            .with_hil_mode(HilMode::BatchReview);
+        run_batch_review_with(policy, decision).await
+    }
+
+    async fn run_batch_review_with(
+        policy: AgentPolicy,
+        decision: HumanDecision,
+    ) -> (RunAgentOutput, Arc<BatchHil>) {
+        let mut builder = high_risk_action_builder(policy);
+        // Action review of write_file
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+        let hil = Arc::new(BatchHil {
+            executor: builder.tool_executor.clone(),
+            decision,
+            calls_when_presented: Mutex::new(None),
+            batch_size: Mutex::new(0),
+            escalations: Mutex::new(Vec::new()),
+        });
+        let (result, _) = builder.with_human_intervention(hil.clone()).execute().await;
+        (result.expect("run should finish"), hil)
+    }
+
+    #[tokio::test]
+    async fn test_batch_review_executes_only_after_approval() {
+        let (output, hil) = run_batch_review(HumanDecision::Approve).await;
+
+        assert_eq!(*hil.batch_size.lock().unwrap(), 1);
+        assert_eq!(
+            *hil.calls_when_presented.lock().unwrap(),
+            Some(0),
+            "write_file must be queued, not executed, before batch approval"
+        );
+        assert_eq!(*hil.executor.calls.lock().unwrap(), vec!["write_file"]);
+        let task = &output.state.plan.as_ref().unwrap().tasks[0];
+        assert!(matches!(
+            task.tool_executions[0].state,
+            quorum_domain::ToolExecutionState::Completed { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batch_review_shows_why_an_action_was_escalated() {
+        // One configured reviewer, floor of two → escalated into the batch
+        let policy = AgentPolicy::default()
+            .with_require_plan_review(false)
+            .with_hil_mode(HilMode::BatchReview)
+            .with_min_reviewers(quorum_domain::RiskLevel::High, 2);
+        let (_, hil) = run_batch_review_with(policy, HumanDecision::Approve).await;
+
+        let escalations = hil.escalations.lock().unwrap().clone();
+        assert_eq!(escalations.len(), 1);
+        let reason = escalations[0].as_deref().expect("escalation reason");
+        assert!(reason.contains("require at least 2"), "reason: {}", reason);
+
+        // A call the reviewers approved outright carries none
+        let (_, hil) = run_batch_review(HumanDecision::Approve).await;
+        assert_eq!(*hil.escalations.lock().unwrap(), vec![None]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_review_rejected_action_never_runs() {
+        let (output, hil) = run_batch_review(HumanDecision::Reject).await;
+
+        assert_eq!(*hil.batch_size.lock().unwrap(), 1);
+        assert!(hil.executor.calls.lock().unwrap().is_empty());
+        let task = &output.state.plan.as_ref().unwrap().tasks[0];
+        match &task.tool_executions[0].state {
+            quorum_domain::ToolExecutionState::Error { error_message, .. } => {
+                assert!(error_message.contains("REJECTED in batch review"));
+            }
+            other => panic!("expected rejected execution, got {:?}", other),
+        }
+        // The task's only action never ran, so the task did not succeed
+        assert_eq!(task.status, quorum_domain::TaskStatus::Failed);
+    }
+
+    /// Batch-review handler whose user rejects the first action and lets
+    /// the deadline pass on the rest.
+    struct PartiallyAnsweredBatchHil;
+
+    #[async_trait]
+    impl HumanInterventionPort for PartiallyAnsweredBatchHil {
+        async fn request_intervention(
+            &self,
+            _request: &str,
+            _plan: &Plan,
+            _review_history: &[ReviewRound],
+        ) -> Result<HumanDecision, HumanInterventionError> {
+            Ok(HumanDecision::Approve)
+        }
+
+        async fn request_batch_approval(
+            &self,
+            _request: &str,
+            _actions: &[quorum_domain::QueuedAction],
+        ) -> Result<Vec<HumanDecision>, HumanInterventionError> {
+            Err(HumanInterventionError::TimedOut {
+                answered: vec![HumanDecision::Reject],
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_review_timeout_keeps_answered_decisions() {
+        let mut policy = AgentPolicy::default()
+            .with_require_plan_review(false)
+            .with_hil_mode(HilMode::BatchReview);
+        policy.hil_timeout_default = quorum_domain::HilTimeoutDefault::Approve;
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.policy = policy;
+
+        let write = |id: &str, path: &str| {
+            let mut args = HashMap::new();
+            args.insert("path".to_string(), serde_json::json!(path));
+            args.insert("content".to_string(), serde_json::json!("done"));
+            ScriptedResponse::Response(LlmResponse {
+                content: vec![ContentBlock::ToolUse {
+                    id: id.to_string(),
+                    name: "write_file".to_string(),
+                    input: args,
+                }],
+                stop_reason: Some(StopReason::ToolUse),
+                model: None,
+                usage: None,
+            })
+        };
+        let mut plan = HashMap::new();
+        plan.insert(
+            "objective".to_string(),
+            serde_json::json!("Write two files"),
+        );
+        plan.insert("reasoning".to_string(), serde_json::json!("test reasoning"));
+        plan.insert(
+            "tasks".to_string(),
+            serde_json::json!([
+                {"id": "1", "description": "Write a.txt", "tool": "write_file", "depends_on": []},
+                {"id": "2", "description": "Write b.txt", "tool": "write_file", "depends_on": []}
+            ]),
+        );
+        let sonnet = Model::ClaudeSonnet45.to_string();
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(
+            &sonnet,
+            vec![ScriptedResponse::Response(LlmResponse {
+                content: vec![ContentBlock::ToolUse {
+                    id: "toolu_plan_001".to_string(),
+                    name: "create_plan".to_string(),
+                    input: plan,
+                }],
+                stop_reason: Some(StopReason::ToolUse),
+                model: None,
+                usage: None,
+            })],
+        );
+        for (id, path) in [("toolu_a", "a.txt"), ("toolu_b", "b.txt")] {
+            gateway.add_session(
+                &sonnet,
+                vec![
+                    write(id, path),
+                    ScriptedResponse::Response(LlmResponse::from_text("Task finished")),
+                ],
+            );
+            // Action review of the write
+            gateway.add_session(&sonnet, vec![ScriptedResponse::Text(approve_response())]);
+        }
+        builder.gateway = gateway;
+        let executor = builder.tool_executor.clone();
+
+        let (result, _) = builder
+            .with_human_intervention(Arc::new(PartiallyAnsweredBatchHil))
+            .execute()
+            .await;
+
+        let output = result.expect("run should finish");
+        // Action 1 was rejected before the deadline; only action 2 takes
+        // the approve default
+        assert_eq!(*executor.calls.lock().unwrap(), vec!["write_file"]);
+        let tasks = &output.state.plan.as_ref().unwrap().tasks;
+        assert!(matches!(
+            tasks[0].tool_executions[0].state,
+            quorum_domain::ToolExecutionState::Error { .. }
+        ));
+        assert!(matches!(
+            tasks[1].tool_executions[0].state,
+            quorum_domain::ToolExecutionState::Completed { .. }
+        ));
+        assert_eq!(tasks[0].status, quorum_domain::TaskStatus::Failed);
+        assert_eq!(tasks[1].status, quorum_domain::TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_safe_mode_floor_not_bypassed_by_auto_approve() {
+        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
+            HumanDecision::Approve,
+        ));
+        let policy = AgentPolicy::default()
+            .with_require_plan_review(false)
+            .with_hil_mode(HilMode::AutoApprove)
+            .with_safe_mode();
+
+        let builder = high_risk_action_builder(policy).with_human_intervention(mock_hil.clone());
+        let executor = builder.tool_executor.clone();
+        let (result, _) = builder.execute().await;
+
+        result.expect("run should finish");
+        assert_eq!(*mock_hil.action_approval_calls.lock().unwrap(), 0);
+        assert!(executor.calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_full_execution_confirmation_reject_stops() {
+        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
+            HumanDecision::Reject,
+        ));
+
+        let (result, progress) = FlowTestBuilder::solo_full()
+            .with_human_intervention(mock_hil.clone())
+            .execute()
+            .await;
+
+        let output = result.expect("should succeed (graceful stop, not error)");
+        assert!(output.success);
+        assert!(output.summary.contains("not executed"));
+
+        // Plan review happened but execution did not
+        assert!(progress.has_phase(&AgentPhase::PlanReview));
+        assert!(!progress.has_phase(&AgentPhase::Executing));
+        assert_eq!(output.state.phase, AgentPhase::Completed);
+
+        // Execution confirmation was called
+        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
     async fn test_full_execution_confirmation_approve_continues() {
         let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
             HumanDecision::Approve,
         ));
 
-        let (result, progress) = FlowTestBuilder::solo_full()
-            .with_human_intervention(mock_hil.clone())
-            .execute()
-            .await;
+        let (result, progress) = FlowTestBuilder::solo_full()
+            .with_human_intervention(mock_hil.clone())
+            .execute()
+            .await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success);
+
+        // Both plan review and execution happened
+        assert!(progress.has_phase(&AgentPhase::PlanReview));
+        assert!(progress.has_phase(&AgentPhase::Executing));
+
+        // Execution confirmation was called
+        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 1);
+    }
+
+    /// Handler whose user never answers: it waits out its timeout, like
+    /// the interactive adapters do, and reports `TimedOut`.
+    struct UnansweredHil {
+        timeout: std::time::Duration,
+    }
+
+    #[async_trait]
+    impl HumanInterventionPort for UnansweredHil {
+        async fn request_intervention(
+            &self,
+            _request: &str,
+            _plan: &Plan,
+            _review_history: &[ReviewRound],
+        ) -> Result<HumanDecision, HumanInterventionError> {
+            Ok(HumanDecision::Approve)
+        }
+
+        async fn request_execution_confirmation(
+            &self,
+            _request: &str,
+            _plan: &Plan,
+        ) -> Result<HumanDecision, HumanInterventionError> {
+            tokio::time::timeout(self.timeout, std::future::pending())
+                .await
+                .map_err(|_| HumanInterventionError::TimedOut {
+                    answered: Vec::new(),
+                })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_confirmation_timeout_takes_default_decision() {
+        let unanswered = || {
+            Arc::new(UnansweredHil {
+                timeout: std::time::Duration::from_millis(10),
+            })
+        };
+
+        // Default: an unanswered confirmation rejects execution
+        let (result, progress) = FlowTestBuilder::solo_full()
+            .with_human_intervention(unanswered())
+            .execute()
+            .await;
+        let output = result.expect("timeout is a decision, not an error");
+        assert!(output.summary.contains("not executed"));
+        assert!(!progress.has_phase(&AgentPhase::Executing));
+
+        let mut builder = FlowTestBuilder::solo_full();
+        builder.policy.hil_timeout_default = quorum_domain::HilTimeoutDefault::Approve;
+        let (result, progress) = builder
+            .with_human_intervention(unanswered())
+            .execute()
+            .await;
+        assert!(result.expect("should succeed").success);
+        assert!(progress.has_phase(&AgentPhase::Executing));
+    }
+
+    #[tokio::test]
+    async fn test_fast_skips_execution_confirmation() {
+        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
+            HumanDecision::Reject, // Would reject if called
+        ));
+
+        let (result, _progress) = FlowTestBuilder::solo_fast()
+            .with_human_intervention(mock_hil.clone())
+            .execute()
+            .await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success);
+
+        // Fast mode never calls execution confirmation
+        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_plan_only_skips_execution_confirmation() {
+        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
+            HumanDecision::Reject,
+        ));
+
+        let (result, _progress) = FlowTestBuilder::solo_plan_only()
+            .with_human_intervention(mock_hil.clone())
+            .execute()
+            .await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success);
+
+        // PlanOnly never calls execution confirmation
+        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_hil_auto_approve_skips_execution_confirmation_prompt() {
+        // With auto_approve hil_mode, execution confirmation should auto-approve
+        let (result, _progress) = FlowTestBuilder::solo_full()
+            .with_hil_mode(HilMode::AutoApprove)
+            .execute()
+            .await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success);
+        assert!(output.state.phase == AgentPhase::Completed);
+    }
+
+    // ==================== Token Usage Flow Tests ====================
+
+    /// Attach `usage` to a structured scripted response.
+    fn with_usage(response: ScriptedResponse, prompt: u64, completion: u64) -> ScriptedResponse {
+        match response {
+            ScriptedResponse::Response(r) => {
+                ScriptedResponse::Response(r.with_usage(TokenUsage::new(prompt, completion)))
+            }
+            other => other,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_usage_aggregated_per_phase() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![with_usage(
+                ScriptedResponse::Response(LlmResponse::from_text("Context gathered")),
+                100,
+                10,
+            )],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![with_usage(make_plan_response("Test plan"), 200, 20)],
+        );
+        // read_file is low-risk, so the task runs on the exploration model
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![with_usage(
+                ScriptedResponse::Response(LlmResponse::from_text("Task completed successfully")),
+                300,
+                30,
+            )],
+        );
+        builder.gateway = gateway;
+
+        let (result, _) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        let usage = &output.state.token_usage;
+        assert_eq!(
+            usage[&AgentPhase::ContextGathering],
+            TokenUsage::new(100, 10)
+        );
+        assert_eq!(usage[&AgentPhase::Planning], TokenUsage::new(200, 20));
+        assert_eq!(usage[&AgentPhase::Executing], TokenUsage::new(300, 30));
+        assert_eq!(output.total_usage(), TokenUsage::new(600, 60));
+        // Summary synthesis is a plain-text call, which never carries usage
+        assert!(output.state.usage_unavailable);
+    }
+
+    #[tokio::test]
+    async fn test_tool_output_truncated_to_context_budget() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.tool_executor = Arc::new(MockToolExecutor::new().with_output("x".repeat(50)));
+        builder.execution.context_budget =
+            quorum_domain::ContextBudget::default().with_per_tool_output_chars(20);
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Text("Context gathered".to_string())],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Test plan")],
+        );
+        let mut args = HashMap::new();
+        args.insert("path".to_string(), serde_json::json!("test.txt"));
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![
+                ScriptedResponse::Response(LlmResponse {
+                    content: vec![ContentBlock::ToolUse {
+                        id: "toolu_read_001".to_string(),
+                        name: "read_file".to_string(),
+                        input: args,
+                    }],
+                    stop_reason: Some(StopReason::ToolUse),
+                    model: None,
+                    usage: None,
+                }),
+                ScriptedResponse::Text("Task finished".to_string()),
+            ],
+        );
+        builder.gateway = gateway;
+
+        let (result, _) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        let task = &output.state.plan.as_ref().unwrap().tasks[0];
+        let task_output = &task.result.as_ref().unwrap().output;
+        assert!(
+            task_output.contains(&format!("{}\n[truncated 30 of 50 chars]", "x".repeat(20))),
+            "{}",
+            task_output
+        );
+        assert!(!task_output.contains(&"x".repeat(21)));
+        let execution = &task.tool_executions[0];
+        match &execution.state {
+            quorum_domain::ToolExecutionState::Completed { metadata, .. } => {
+                assert_eq!(metadata.original_chars, Some(50));
+            }
+            other => panic!("expected completed execution, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_stats_count_scripted_tool_calls() {
+        let mut builder = FlowTestBuilder::solo_full();
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Text("Context gathered".to_string())],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Test plan")],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+        let tool_use = |id: &str, name: &str, args: serde_json::Value| ContentBlock::ToolUse {
+            id: id.to_string(),
+            name: name.to_string(),
+            input: serde_json::from_value(args).unwrap(),
+        };
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![
+                ScriptedResponse::Response(LlmResponse {
+                    content: vec![
+                        tool_use("toolu_1", "read_file", serde_json::json!({"path": "a.txt"})),
+                        tool_use("toolu_2", "read_file", serde_json::json!({"path": "b.txt"})),
+                    ],
+                    stop_reason: Some(StopReason::ToolUse),
+                    model: None,
+                    usage: None,
+                }),
+                ScriptedResponse::Response(LlmResponse {
+                    content: vec![tool_use(
+                        "toolu_3",
+                        "write_file",
+                        serde_json::json!({"path": "c.txt", "content": "done"}),
+                    )],
+                    stop_reason: Some(StopReason::ToolUse),
+                    model: None,
+                    usage: None,
+                }),
+                ScriptedResponse::Text("Task finished".to_string()),
+            ],
+        );
+        // Action review of the high-risk write_file
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+        builder.gateway = gateway;
+
+        let (result, _) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        let stats = output.tool_stats();
+        assert_eq!(
+            stats["read_file"],
+            ToolStat {
+                calls: 2,
+                successes: 2,
+                failures: 0
+            }
+        );
+        assert_eq!(
+            stats["write_file"],
+            ToolStat {
+                calls: 1,
+                successes: 1,
+                failures: 0
+            }
+        );
+        assert_eq!(output.to_json()["tools"]["read_file"]["calls"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_initial_context_replaces_context_gathering() {
+        let mut builder = FlowTestBuilder::solo_full();
+        let mut inherited = AgentContext::new();
+        inherited.set_structure_summary("parent structure");
+        builder.initial_context = Some(inherited);
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert_eq!(
+            output.state.context.structure_summary.as_deref(),
+            Some("parent structure")
+        );
+        assert!(progress.has_phase(&AgentPhase::ContextGathering));
+        assert!(
+            output
+                .state
+                .thoughts
+                .iter()
+                .any(|t| t.content == "Context inherited from parent interaction")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_context_reaches_planning_context() {
+        let mut builder = FlowTestBuilder::solo_plan_only();
+        builder.policy.gather_context = false;
+        builder.user_context = vec![
+            "Prefer small PRs".to_string(),
+            "Keep the public API stable".to_string(),
+        ];
+
+        let (result, _progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success, "summary: {}", output.summary);
+        let summary = output.state.context.structure_summary.unwrap_or_default();
+        assert_eq!(
+            summary,
+            "[User-supplied context]\nPrefer small PRs\n\n\
+             [User-supplied context]\nKeep the public API stable"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preloaded_plan_bypasses_planning() {
+        let mut builder = FlowTestBuilder::solo_full();
+        let mut plan = Plan::new("Preloaded plan", "From --plan-file");
+        plan.add_task(quorum_domain::Task::new("1", "Do the thing"));
+        builder.preloaded_plan = Some(plan);
+
+        // Only the review and execution sessions are consumed.
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed successfully",
+            ))],
+        );
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
 
         let output = result.expect("should succeed");
-        assert!(output.success);
-
-        // Both plan review and execution happened
+        assert!(output.success, "summary: {}", output.summary);
+        assert!(!progress.has_phase(&AgentPhase::ContextGathering));
+        assert!(!progress.has_phase(&AgentPhase::Planning));
         assert!(progress.has_phase(&AgentPhase::PlanReview));
-        assert!(progress.has_phase(&AgentPhase::Executing));
+        assert_eq!(
+            output.state.plan.as_ref().map(|p| p.objective.as_str()),
+            Some("Preloaded plan")
+        );
+    }
 
-        // Execution confirmation was called
-        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 1);
+    /// Context loader that only finds one pinned file (no CLAUDE.md).
+    struct PinnedOnlyLoader;
+
+    impl ContextLoaderPort for PinnedOnlyLoader {
+        fn load_known_files(&self, _project_root: &Path) -> Vec<quorum_domain::LoadedContextFile> {
+            vec![quorum_domain::LoadedContextFile::new(
+                quorum_domain::KnownContextFile::Pinned,
+                "/project/src/auth.rs",
+                "fn login() {}",
+            )]
+        }
+
+        fn context_file_exists(&self, _project_root: &Path) -> bool {
+            false
+        }
+
+        fn write_context_file(&self, _project_root: &Path, _content: &str) -> std::io::Result<()> {
+            Ok(())
+        }
     }
 
     #[tokio::test]
-    async fn test_fast_skips_execution_confirmation() {
-        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
-            HumanDecision::Reject, // Would reject if called
-        ));
+    async fn test_pinned_file_survives_exploration_without_claude_md() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.execution.working_dir = Some("/project".to_string());
+        builder.context_loader = Some(Arc::new(PinnedOnlyLoader));
 
-        let (result, _progress) = FlowTestBuilder::solo_fast()
-            .with_human_intervention(mock_hil.clone())
-            .execute()
-            .await;
+        let (result, _) = builder.execute().await;
 
         let output = result.expect("should succeed");
-        assert!(output.success);
+        let summary = output.state.context.structure_summary.unwrap();
+        assert!(summary.contains("## /project/src/auth.rs\nfn login() {}"));
+    }
 
-        // Fast mode never calls execution confirmation
-        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 0);
+    /// Context loader with several known files but no primary context, so
+    /// gathering continues into Stage 2.
+    struct SeveralFilesLoader;
+
+    impl ContextLoaderPort for SeveralFilesLoader {
+        fn load_known_files(&self, _project_root: &Path) -> Vec<quorum_domain::LoadedContextFile> {
+            use quorum_domain::{KnownContextFile, LoadedContextFile};
+            vec![
+                LoadedContextFile::new(KnownContextFile::ReadmeMd, "/project/README.md", "# Demo"),
+                LoadedContextFile::new(
+                    KnownContextFile::CargoToml,
+                    "/project/Cargo.toml",
+                    "[package]",
+                ),
+                LoadedContextFile::new(
+                    KnownContextFile::DocsMarkdown,
+                    "/project/docs/guide.md",
+                    "Guide",
+                ),
+            ]
+        }
+
+        fn context_file_exists(&self, _project_root: &Path) -> bool {
+            false
+        }
+
+        fn write_context_file(&self, _project_root: &Path, _content: &str) -> std::io::Result<()> {
+            Ok(())
+        }
     }
 
     #[tokio::test]
-    async fn test_plan_only_skips_execution_confirmation() {
-        let mock_hil = Arc::new(MockHumanIntervention::with_execution_confirmation(
-            HumanDecision::Reject,
-        ));
+    async fn test_context_file_loaded_fires_per_file() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.execution.working_dir = Some("/project".to_string());
+        builder.context_loader = Some(Arc::new(SeveralFilesLoader));
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![
+                ScriptedResponse::Response(LlmResponse {
+                    content: vec![ContentBlock::ToolUse {
+                        id: "toolu_1".to_string(),
+                        name: "read_file".to_string(),
+                        input: serde_json::from_value(serde_json::json!({"path": "src/main.rs"}))
+                            .unwrap(),
+                    }],
+                    stop_reason: Some(StopReason::ToolUse),
+                    model: None,
+                    usage: None,
+                }),
+                ScriptedResponse::Response(LlmResponse::from_text("Context gathered")),
+            ],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Test plan")],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed successfully",
+            ))],
+        );
+        builder.gateway = gateway;
 
-        let (result, _progress) = FlowTestBuilder::solo_plan_only()
-            .with_human_intervention(mock_hil.clone())
-            .execute()
-            .await;
+        let (result, progress) = builder.execute().await;
+
+        result.expect("should succeed");
+        let loaded = progress.context_files.lock().unwrap().clone();
+        let expected = [
+            ("/project/README.md", "known_file"),
+            ("/project/Cargo.toml", "known_file"),
+            ("/project/docs/guide.md", "known_file"),
+            ("src/main.rs", "exploration"),
+        ];
+        assert_eq!(
+            loaded,
+            expected
+                .iter()
+                .map(|(path, source)| (path.to_string(), source.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_read_is_not_reported_as_loaded() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.tool_executor = Arc::new(MockToolExecutor::new().with_failing_tool("read_file"));
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![
+                ScriptedResponse::Response(LlmResponse {
+                    content: vec![ContentBlock::ToolUse {
+                        id: "toolu_1".to_string(),
+                        name: "read_file".to_string(),
+                        input: serde_json::from_value(serde_json::json!({"path": "missing.rs"}))
+                            .unwrap(),
+                    }],
+                    stop_reason: Some(StopReason::ToolUse),
+                    model: None,
+                    usage: None,
+                }),
+                ScriptedResponse::Response(LlmResponse::from_text("Context gathered")),
+            ],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Test plan")],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed successfully",
+            ))],
+        );
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
+
+        result.expect("should succeed");
+        assert!(progress.context_files.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_token_usage_unavailable_without_backend_reports() {
+        let (result, _) = FlowTestBuilder::solo_fast().execute().await;
 
         let output = result.expect("should succeed");
-        assert!(output.success);
+        assert_eq!(output.total_usage(), TokenUsage::default());
+        assert!(output.state.usage_unavailable);
+        assert!(output.state.token_usage.contains_key(&AgentPhase::Planning));
+    }
 
-        // PlanOnly never calls execution confirmation
-        assert_eq!(*mock_hil.execution_confirmation_calls.lock().unwrap(), 0);
+    #[tokio::test]
+    async fn test_token_usage_kept_when_planning_fails() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![with_usage(
+                ScriptedResponse::Response(LlmResponse::from_text("Context gathered")),
+                100,
+                10,
+            )],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Error("backend down".to_string())],
+        );
+        builder.gateway = gateway;
+
+        let (result, _) = builder.execute().await;
+
+        let output = result.expect("planning failure returns output");
+        assert!(!output.success);
+        assert_eq!(output.total_usage(), TokenUsage::new(100, 10));
+        assert!(!output.state.usage_unavailable);
     }
 
     #[tokio::test]
-    async fn test_hil_auto_approve_skips_execution_confirmation_prompt() {
-        // With auto_approve hil_mode, execution confirmation should auto-approve
-        let (result, _progress) = FlowTestBuilder::solo_full()
-            .with_hil_mode(HilMode::AutoApprove)
+    async fn test_cost_limit_stops_run_at_next_phase_boundary() {
+        let mut builder = FlowTestBuilder::solo_fast();
+        builder.execution = builder.execution.clone().with_max_cost_tokens(200);
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![with_usage(
+                ScriptedResponse::Response(LlmResponse::from_text("Context gathered")),
+                100,
+                10,
+            )],
+        );
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![with_usage(make_plan_response("Test plan"), 200, 20)],
+        );
+        // Would run the task — the limit must stop the run before it starts
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![with_usage(
+                ScriptedResponse::Response(LlmResponse::from_text("Task completed successfully")),
+                300,
+                30,
+            )],
+        );
+        builder.gateway = gateway;
+
+        let (result, _) = builder.execute().await;
+
+        let output = result.expect("cost limit is a graceful stop");
+        assert!(!output.success);
+        assert_eq!(output.state.phase, AgentPhase::Failed);
+        assert!(output.summary.contains("330 tokens"), "{}", output.summary);
+        assert!(
+            output.summary.contains("limit of 200"),
+            "{}",
+            output.summary
+        );
+        assert_eq!(output.total_usage(), TokenUsage::new(300, 30));
+        assert!(
+            !output
+                .state
+                .token_usage
+                .contains_key(&AgentPhase::Executing)
+        );
+    }
+
+    // ==================== JSON Output Tests ====================
+
+    #[tokio::test]
+    async fn test_output_json_includes_task_statuses() {
+        let (result, _) = FlowTestBuilder::solo_full().execute().await;
+
+        let output = result.expect("should succeed");
+        let json = output.to_json();
+        let json: serde_json::Value = serde_json::from_str(&json.to_string()).unwrap();
+
+        assert_eq!(json["success"], true);
+        assert_eq!(json["phase"], "completed");
+        assert!(json["error"].is_null());
+        assert_eq!(json["plan"]["objective"], "Test plan");
+        let tasks = json["plan"]["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["id"], "1");
+        assert_eq!(tasks[0]["status"], "completed");
+        let phases: Vec<&str> = json["phases"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["phase"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            phases,
+            vec!["context_gathering", "planning", "plan_review", "executing"]
+        );
+        assert!(!json["thoughts"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_output_json_on_failure_has_error() {
+        let (result, _) = FlowTestBuilder::solo_fast()
+            .with_plan_response(ScriptedResponse::Error("backend down".to_string()))
             .execute()
             .await;
 
-        let output = result.expect("should succeed");
-        assert!(output.success);
-        assert!(output.state.phase == AgentPhase::Completed);
+        let json = result.expect("planning failure returns output").to_json();
+
+        assert_eq!(json["success"], false);
+        assert!(json["plan"].is_null());
+        assert!(json["error"].as_str().unwrap().contains("backend down"));
+    }
+
+    #[test]
+    fn test_error_json_has_error_field() {
+        let json = RunAgentError::MaxIterationsExceeded.to_json();
+
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"], "Max iterations exceeded");
     }
 
     // ==================== Plan Parse Failure Flow Tests ====================
@@ -1851,6 +4194,107 @@ mod tests {
         assert_eq!(output.state.phase, AgentPhase::Completed);
     }
 
+    /// Solo + Fast with the decision model's planning session scripted as `plan`.
+    fn retry_planning_builder(plan: Vec<ScriptedResponse>) -> FlowTestBuilder {
+        let mut builder = FlowTestBuilder::solo_fast().with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            jitter: std::time::Duration::ZERO,
+        });
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Context gathered",
+            ))],
+        );
+        gateway.add_session(&Model::ClaudeSonnet45.to_string(), plan);
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed",
+            ))],
+        );
+        builder.gateway = gateway;
+        builder
+    }
+
+    #[tokio::test]
+    async fn test_planning_retries_request_failures_then_produces_plan() {
+        let (result, progress) = retry_planning_builder(vec![
+            ScriptedResponse::Error("502 Bad Gateway".into()),
+            ScriptedResponse::Error("connection reset".into()),
+            make_plan_response("Plan after retries"),
+        ])
+        .execute()
+        .await;
+
+        let output = result.expect("should succeed after retries");
+        assert!(output.success, "summary: {}", output.summary);
+        assert_eq!(
+            output.state.plan.as_ref().map(|p| p.objective.as_str()),
+            Some("Plan after retries")
+        );
+        assert!(progress.has_phase(&AgentPhase::Executing));
+    }
+
+    #[tokio::test]
+    async fn test_planning_stops_retrying_after_max_attempts() {
+        let (result, progress) = retry_planning_builder(vec![
+            ScriptedResponse::Error("502 Bad Gateway".into()),
+            ScriptedResponse::Error("502 Bad Gateway".into()),
+            ScriptedResponse::Error("502 Bad Gateway".into()),
+            make_plan_response("Never reached"),
+        ])
+        .execute()
+        .await;
+
+        let output = result.expect("planning failure is reported in the output");
+        assert!(!output.success);
+        assert!(
+            output.summary.contains("502 Bad Gateway"),
+            "{}",
+            output.summary
+        );
+        assert!(output.state.plan.is_none());
+        assert!(!progress.has_phase(&AgentPhase::Executing));
+    }
+
+    #[tokio::test]
+    async fn test_planning_retries_rate_limited_after_requested_wait() {
+        let wait = std::time::Duration::from_millis(50);
+        let start = std::time::Instant::now();
+        let (result, _) = retry_planning_builder(vec![
+            ScriptedResponse::RateLimited(wait),
+            make_plan_response("Plan after rate limit"),
+        ])
+        .execute()
+        .await;
+
+        let output = result.expect("should succeed after the rate limit clears");
+        assert!(output.success, "summary: {}", output.summary);
+        assert!(start.elapsed() >= wait);
+    }
+
+    #[tokio::test]
+    async fn test_planning_does_not_retry_unauthorized() {
+        let (result, _) = retry_planning_builder(vec![
+            ScriptedResponse::Unauthorized("invalid token".into()),
+            make_plan_response("Never reached"),
+        ])
+        .execute()
+        .await;
+
+        let output = result.expect("planning failure is reported in the output");
+        assert!(!output.success);
+        assert!(
+            output.summary.contains("Unauthorized: invalid token"),
+            "{}",
+            output.summary
+        );
+        assert!(output.state.plan.is_none());
+    }
+
     #[tokio::test]
     async fn test_empty_tasks_native_tool_use_falls_back_to_text() {
         // Native Tool Use で create_plan を呼んだがタスク 0 個の場合、
@@ -1872,6 +4316,7 @@ mod tests {
             ],
             stop_reason: Some(StopReason::ToolUse),
             model: None,
+            usage: None,
         };
 
         // After extract fails, generate_plan_from_session retries by sending
@@ -1909,6 +4354,140 @@ mod tests {
         assert!(!progress.has_phase(&AgentPhase::Executing));
     }
 
+    /// Session for `generate_plan_from_session` tests: answers from a queue
+    /// and records every prompt and tool-result output it is sent.
+    struct PlanRetrySession {
+        responses: Mutex<VecDeque<LlmResponse>>,
+        sent: Mutex<Vec<String>>,
+    }
+
+    impl PlanRetrySession {
+        fn new(responses: Vec<LlmResponse>) -> Self {
+            Self {
+                responses: Mutex::new(responses.into()),
+                sent: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn next(&self) -> LlmResponse {
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| LlmResponse::from_text(""))
+        }
+    }
+
+    #[async_trait]
+    impl LlmSession for PlanRetrySession {
+        fn model(&self) -> &Model {
+            &Model::ClaudeSonnet45
+        }
+
+        async fn send(&self, content: &str) -> Result<String, GatewayError> {
+            self.sent.lock().unwrap().push(content.to_string());
+            Ok(self.next().text_content())
+        }
+
+        async fn send_with_tools(
+            &self,
+            content: &str,
+            _tools: &[serde_json::Value],
+        ) -> Result<LlmResponse, GatewayError> {
+            self.sent.lock().unwrap().push(content.to_string());
+            Ok(self.next())
+        }
+
+        async fn send_tool_results(
+            &self,
+            results: &[ToolResultMessage],
+        ) -> Result<LlmResponse, GatewayError> {
+            self.sent
+                .lock()
+                .unwrap()
+                .extend(results.iter().map(|r| r.output.clone()));
+            Ok(self.next())
+        }
+    }
+
+    fn dangling_plan_json() -> serde_json::Value {
+        serde_json::json!({
+            "objective": "Dangling",
+            "reasoning": "test",
+            "tasks": [
+                {"id": "1", "description": "Read", "tool": "read_file", "args": {"path": "a"}},
+                {"id": "2", "description": "Write", "depends_on": ["7"]}
+            ]
+        })
+    }
+
+    async fn plan_with_retry(session: &PlanRetrySession) -> Result<PlanningResult, RunAgentError> {
+        planning::generate_plan_from_session(
+            session,
+            "Do it",
+            &AgentContext::new(),
+            None,
+            None,
+            &None,
+            &RetryPolicy::default(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_dangling_dependency_is_fed_back_and_fails_on_repeat() {
+        let dangling_call = || {
+            let ScriptedResponse::Response(mut response) = make_plan_response("Dangling") else {
+                unreachable!()
+            };
+            let ContentBlock::ToolUse { input, .. } = &mut response.content[0] else {
+                unreachable!()
+            };
+            input.insert("tasks".to_string(), dangling_plan_json()["tasks"].clone());
+            response
+        };
+        let session = PlanRetrySession::new(vec![dangling_call(), dangling_call()]);
+
+        let error = match plan_with_retry(&session).await {
+            Err(RunAgentError::PlanningFailed(e)) => e,
+            Err(other) => panic!("expected PlanningFailed, got {other:?}"),
+            Ok(_) => panic!("a second dangling reference must fail planning"),
+        };
+        assert!(error.contains("depends on '7'"), "{}", error);
+
+        let sent = session.sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert!(sent[1].contains("task '2' depends on '7'"), "{}", sent[1]);
+        assert!(!sent[1].contains("requires 'objective'"), "{}", sent[1]);
+    }
+
+    #[tokio::test]
+    async fn test_dangling_dependency_in_text_plan_is_retried() {
+        let text_plan = format!("```plan\n{}\n```", dangling_plan_json());
+        let fixed = serde_json::json!({
+            "objective": "Fixed",
+            "tasks": [
+                {"id": "1", "description": "Read", "tool": "read_file", "args": {"path": "a"}},
+                {"id": "2", "description": "Write", "depends_on": ["1"]}
+            ]
+        });
+        let session = PlanRetrySession::new(vec![
+            LlmResponse::from_text(text_plan),
+            LlmResponse::from_text(format!("```plan\n{}\n```", fixed)),
+        ]);
+
+        let plan = match plan_with_retry(&session).await {
+            Ok(PlanningResult::Plan(plan)) => plan,
+            Ok(PlanningResult::TextResponse(text)) => panic!("expected a plan, got text: {text}"),
+            Err(e) => panic!("expected a plan, got {e:?}"),
+        };
+        assert_eq!(plan.objective, "Fixed");
+
+        let sent = session.sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert!(sent[1].contains("depends on '7'"), "{}", sent[1]);
+    }
+
     #[tokio::test]
     async fn test_hil_auto_reject_stops_at_execution_confirmation() {
         let (result, progress) = FlowTestBuilder::solo_full()
@@ -1940,7 +4519,7 @@ mod tests {
             ),
         ]);
 
-        // Moderator synthesis session (decision_model = ClaudeSonnet45)
+        // Moderator synthesis session (default moderator = ClaudeSonnet45)
         builder.gateway.add_session(
             &Model::ClaudeSonnet45.to_string(),
             vec![ScriptedResponse::Text(
@@ -1969,6 +4548,232 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_ensemble_merge_uses_moderator_plan() {
+        // merge モード: モデレーターが上位候補を統合した計画を実行する
+        let mut builder = FlowTestBuilder::ensemble_fast();
+        builder.policy.ensemble_selection = EnsembleSelection::Merge;
+        builder.models.moderator = Model::ClaudeOpus45;
+        builder.gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![make_plan_response("Merged plan")],
+        );
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed with merged plan");
+        assert!(output.success, "got: {}", output.summary);
+        let plan = output.state.plan.as_ref().expect("plan should be set");
+        assert_eq!(plan.objective, "Merged plan");
+        assert!(
+            output
+                .state
+                .thoughts
+                .iter()
+                .any(|t| t.content.contains("Moderator merged"))
+        );
+        // Nobody voted on the merged plan: no winner score, not "selected by voting"
+        assert_eq!(
+            *progress.ensemble_outcomes.lock().unwrap(),
+            vec![format!("merged {}", Model::ClaudeOpus45)]
+        );
+        assert!(
+            !output
+                .state
+                .thoughts
+                .iter()
+                .any(|t| t.content.contains("selected by ensemble voting"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_merge_is_bounded_by_session_timeout() {
+        // モデレーターが応答しなければ ensemble_session_timeout で打ち切り、勝者を使う
+        let mut builder = FlowTestBuilder::ensemble_fast();
+        builder.policy.ensemble_selection = EnsembleSelection::Merge;
+        builder.models.moderator = Model::ClaudeOpus45;
+        builder.execution.ensemble_session_timeout = Some(std::time::Duration::from_millis(200));
+        builder.gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![ScriptedResponse::Hang],
+        );
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed with the winning plan");
+        assert!(output.success, "got: {}", output.summary);
+        let plan = output.state.plan.as_ref().expect("plan should be set");
+        assert_eq!(plan.objective, "Plan from Haiku");
+        assert_eq!(
+            *progress.ensemble_outcomes.lock().unwrap(),
+            vec![format!("selected {}", Model::ClaudeHaiku45)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_merged_plan_goes_through_plan_review() {
+        // 統合計画は投票を経ていないので、通常の計画レビューに回す
+        let mut builder = FlowTestBuilder::ensemble_fast();
+        builder.mode.phase_scope = PhaseScope::Full;
+        builder.policy.ensemble_selection = EnsembleSelection::Merge;
+        builder.models.moderator = Model::ClaudeOpus45;
+        let mut gateway = ScriptedGateway::new();
+        for (model, responses) in [
+            (
+                Model::ClaudeHaiku45,
+                vec![ScriptedResponse::Text("Context gathered".into())],
+            ),
+            (
+                Model::ClaudeHaiku45,
+                vec![make_plan_response("Plan from Haiku")],
+            ),
+            (
+                Model::ClaudeSonnet45,
+                vec![make_plan_response("Plan from Sonnet")],
+            ),
+            (
+                Model::ClaudeHaiku45,
+                vec![ScriptedResponse::Text("Score: 7/10".into())],
+            ),
+            (
+                Model::ClaudeSonnet45,
+                vec![ScriptedResponse::Text("Score: 8/10".into())],
+            ),
+            (Model::ClaudeOpus45, vec![make_plan_response("Merged plan")]),
+            (
+                Model::ClaudeHaiku45,
+                vec![ScriptedResponse::Text(approve_response())],
+            ),
+            (
+                Model::ClaudeSonnet45,
+                vec![ScriptedResponse::Text(approve_response())],
+            ),
+            (
+                Model::ClaudeSonnet45,
+                vec![ScriptedResponse::Text("Task completed successfully".into())],
+            ),
+        ] {
+            gateway.add_session(&model.to_string(), responses);
+        }
+        builder.gateway = gateway;
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed with the reviewed merged plan");
+        assert!(output.success, "got: {}", output.summary);
+        assert!(progress.has_phase(&AgentPhase::PlanReview));
+        let plan = output.state.plan.as_ref().expect("plan should be set");
+        assert_eq!(plan.objective, "Merged plan");
+        assert_eq!(plan.review_history.len(), 1);
+        assert!(plan.review_history[0].approved);
+        assert!(
+            output
+                .state
+                .thoughts
+                .iter()
+                .any(|t| t.content == "Plan approved by quorum")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_merge_falls_back_to_winner() {
+        // モデレーターが計画を返さなければ最高スコアの計画を使う
+        let mut builder = FlowTestBuilder::ensemble_fast();
+        builder.policy.ensemble_selection = EnsembleSelection::Merge;
+        builder.models.moderator = Model::ClaudeOpus45;
+        builder.gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![ScriptedResponse::Text("These plans look fine.".into())],
+        );
+
+        let (result, _progress) = builder.execute().await;
+
+        let output = result.expect("should succeed with the winning plan");
+        assert!(output.success, "got: {}", output.summary);
+        // Haiku votes 7 for Sonnet's plan, Sonnet votes 8 for Haiku's plan
+        let plan = output.state.plan.as_ref().expect("plan should be set");
+        assert_eq!(plan.objective, "Plan from Haiku");
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_text_synthesis_uses_configured_moderator() {
+        let mut builder = FlowTestBuilder::ensemble_fast().with_ensemble_plan_responses(vec![
+            (Model::ClaudeHaiku45, ScriptedResponse::Text("Short".into())),
+            (
+                Model::ClaudeSonnet45,
+                ScriptedResponse::Text("Also short".into()),
+            ),
+        ]);
+        builder.models.moderator = Model::ClaudeOpus45;
+        builder.gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![ScriptedResponse::Text("Synthesized by Opus".into())],
+        );
+
+        let (result, _progress) = builder.execute().await;
+
+        let output = result.expect("should succeed via moderator synthesis");
+        assert!(output.success, "got: {}", output.summary);
+        assert!(output.summary.contains("Synthesized by Opus"));
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_text_synthesis_failure_returns_best_response() {
+        // モデレーター失敗時はエラーにせず、最も長い単独応答を返す
+        let mut builder = FlowTestBuilder::ensemble_fast().with_ensemble_plan_responses(vec![
+            (Model::ClaudeHaiku45, ScriptedResponse::Text("Short".into())),
+            (
+                Model::ClaudeSonnet45,
+                ScriptedResponse::Text("A longer and more complete answer".into()),
+            ),
+        ]);
+        builder.models.moderator = Model::ClaudeOpus45;
+        builder.gateway.add_session(
+            &Model::ClaudeOpus45.to_string(),
+            vec![ScriptedResponse::Error("moderator unavailable".into())],
+        );
+
+        let (result, _progress) = builder.execute().await;
+
+        let output = result.expect("moderator failure should degrade gracefully");
+        assert!(output.success, "got: {}", output.summary);
+        assert!(output.state.plan.is_none());
+        assert!(
+            output.summary.contains("A longer and more complete answer"),
+            "got: {}",
+            output.summary
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_planning_streams_chunks_per_model() {
+        let stream = |chunks: &[&str]| {
+            ScriptedResponse::Stream(chunks.iter().map(|c| c.to_string()).collect())
+        };
+        let mut builder = FlowTestBuilder::ensemble_fast().with_ensemble_plan_responses(vec![
+            (Model::ClaudeHaiku45, stream(&["Thinking ", "about it"])),
+            (Model::ClaudeSonnet45, stream(&["Looking", " at ", "files"])),
+        ]);
+        // Both answered in text, so the moderator synthesizes
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text("Synthesized".into())],
+        );
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed");
+        assert!(output.success, "got: {}", output.summary);
+        assert_eq!(
+            progress.ensemble_chunks_for(&Model::ClaudeHaiku45),
+            vec!["Thinking ", "about it"]
+        );
+        assert_eq!(
+            progress.ensemble_chunks_for(&Model::ClaudeSonnet45),
+            vec!["Looking", " at ", "files"]
+        );
+    }
+
     #[tokio::test]
     async fn test_ensemble_partial_plan_success() {
         // 1モデルがプラン、1モデルがテキスト → プランが使われる
@@ -2041,6 +4846,171 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_ensemble_timed_out_model_is_skipped() {
+        // 3 models, one never answers → proceed with the 2 plans that completed
+        let mut builder = FlowTestBuilder::ensemble_fast();
+        builder.models.review = vec![
+            Model::ClaudeHaiku45,
+            Model::ClaudeSonnet45,
+            Model::ClaudeOpus45,
+        ];
+        builder.execution.ensemble_session_timeout = Some(std::time::Duration::from_millis(200));
+        let mut builder = builder.with_ensemble_plan_responses(vec![
+            (Model::ClaudeHaiku45, make_plan_response("Plan from Haiku")),
+            (
+                Model::ClaudeSonnet45,
+                make_plan_response("Plan from Sonnet"),
+            ),
+            (Model::ClaudeOpus45, ScriptedResponse::Hang),
+        ]);
+        for (model, score) in [(Model::ClaudeHaiku45, 7), (Model::ClaudeSonnet45, 8)] {
+            builder.gateway.add_session(
+                &model.to_string(),
+                vec![ScriptedResponse::Text(format!("Score: {}/10", score))],
+            );
+        }
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed",
+            ))],
+        );
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed with partial quorum");
+        assert!(output.success);
+        assert_eq!(
+            *progress.timed_out_models.lock().unwrap(),
+            vec![Model::ClaudeOpus45]
+        );
+        assert!(progress.fallback_reasons.lock().unwrap().is_empty());
+        let objective = &output.state.plan.expect("plan should be set").objective;
+        assert!(objective.starts_with("Plan from"), "got: {}", objective);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_ensemble_cancelled_branch_is_skipped() {
+        // Haiku は応答しない → そのブランチだけキャンセルし、Sonnet の計画で続行
+        let mut builder = FlowTestBuilder::ensemble_fast().with_ensemble_plan_responses(vec![
+            (Model::ClaudeHaiku45, ScriptedResponse::Hang),
+            (
+                Model::ClaudeSonnet45,
+                make_plan_response("Plan from Sonnet"),
+            ),
+        ]);
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed",
+            ))],
+        );
+        let branches = builder.ensemble_branches.clone();
+        let canceller = tokio::spawn(async move {
+            // The paused clock only advances once Sonnet is done and
+            // Haiku's session is hanging
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            let active = branches.active();
+            (active, branches.cancel(&Model::ClaudeHaiku45))
+        });
+
+        let (result, progress) = builder.execute().await;
+        let (active, cancelled) = canceller.await.unwrap();
+        // Sonnet's finished branch was closed; only Haiku's is left
+        assert_eq!(active, vec![Model::ClaudeHaiku45]);
+        assert!(cancelled, "Haiku's branch should be running");
+
+        let output = result.expect("should succeed without the cancelled branch");
+        assert!(output.success, "got: {}", output.summary);
+        assert!(progress.timed_out_models.lock().unwrap().is_empty());
+        assert!(progress.fallback_reasons.lock().unwrap().is_empty());
+        assert_eq!(
+            output.state.plan.expect("plan should be set").objective,
+            "Plan from Sonnet"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_ensemble_cancelled_retry_is_skipped() {
+        // Haiku は初回エラー → リトライで応答しない → リトライ中のブランチだけキャンセル
+        let mut builder = FlowTestBuilder::ensemble_fast().with_ensemble_plan_responses(vec![(
+            Model::ClaudeSonnet45,
+            make_plan_response("Plan from Sonnet"),
+        )]);
+        builder.gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Error("transport closed".to_string())],
+        );
+        builder.gateway.add_session(
+            &Model::ClaudeHaiku45.to_string(),
+            vec![ScriptedResponse::Hang],
+        );
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed",
+            ))],
+        );
+        let branches = builder.ensemble_branches.clone();
+        let branches_after = builder.ensemble_branches.clone();
+        let canceller = tokio::spawn(async move {
+            // Past the 2s backoff (on the paused clock), while Haiku's retry
+            // session hangs
+            tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+            branches.cancel(&Model::ClaudeHaiku45)
+        });
+
+        let (result, progress) = builder.execute().await;
+        assert!(canceller.await.unwrap(), "Haiku's retry should be running");
+        assert!(branches_after.active().is_empty());
+
+        let output = result.expect("should succeed without the cancelled retry");
+        assert!(output.success, "got: {}", output.summary);
+        assert!(progress.fallback_reasons.lock().unwrap().is_empty());
+        assert_eq!(
+            output.state.plan.expect("plan should be set").objective,
+            "Plan from Sonnet"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensemble_timeout_below_two_models_falls_back_to_solo() {
+        // 2 models, one never answers → only 1 completed → Solo fallback
+        let mut builder = FlowTestBuilder::ensemble_fast();
+        builder.execution.ensemble_session_timeout = Some(std::time::Duration::from_millis(200));
+        let mut builder = builder.with_ensemble_plan_responses(vec![
+            (Model::ClaudeHaiku45, make_plan_response("Plan from Haiku")),
+            (Model::ClaudeSonnet45, ScriptedResponse::Hang),
+        ]);
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![make_plan_response("Solo fallback plan")],
+        );
+        builder.gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Task completed",
+            ))],
+        );
+
+        let (result, progress) = builder.execute().await;
+
+        let output = result.expect("should succeed via solo fallback");
+        assert!(output.success);
+        assert_eq!(
+            *progress.timed_out_models.lock().unwrap(),
+            vec![Model::ClaudeSonnet45]
+        );
+        let reasons = progress.fallback_reasons.lock().unwrap();
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons[0].contains("1 timed out"), "got: {}", reasons[0]);
+        assert_eq!(
+            output.state.plan.expect("plan should be set").objective,
+            "Solo fallback plan"
+        );
+    }
+
     #[tokio::test]
     async fn test_ensemble_and_solo_both_fail_returns_error() {
         // 全 ensemble モデルがエラー + Solo もエラー → 失敗
@@ -2069,4 +5039,157 @@ mod tests {
             "Should fail when both ensemble and solo fail"
         );
     }
+
+    // ==================== Resume Tests ====================
+
+    /// A snapshot interrupted mid-execution: task 1 done, task 2 in flight.
+    fn executing_snapshot() -> AgentState {
+        let builder = FlowTestBuilder::solo_full();
+        let input = RunAgentInput::new(
+            "Test request",
+            builder.mode,
+            builder.models,
+            builder.policy,
+            builder.execution,
+        );
+        let mut state = input.to_agent_state("agent-resume");
+        state.context.project_type = Some("rust".to_string());
+        let mut plan = Plan::new("Resume plan", "test reasoning")
+            .with_task(quorum_domain::Task::new("1", "Inspect the code"))
+            .with_task(quorum_domain::Task::new("2", "Summarize findings").with_dependency("1"));
+        plan.tasks[0].mark_completed(quorum_domain::TaskResult::success("inspected"));
+        plan.tasks[1].mark_in_progress();
+        state.set_plan(plan);
+        state.approve_plan();
+        state
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_executing_runs_only_incomplete_tasks() {
+        // Round-trip through JSON, as a persisted snapshot would be.
+        let snapshot = AgentState::from_snapshot(executing_snapshot().to_snapshot()).unwrap();
+
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Response(LlmResponse::from_text(
+                "Findings summarized",
+            ))],
+        );
+        let gateway = Arc::new(gateway);
+        let use_case = RunAgentUseCase::new(
+            gateway.clone(),
+            Arc::new(MockToolExecutor::new()),
+            mock_tool_schema(),
+        )
+        .with_retry_policy(RetryPolicy::none());
+        let progress = TrackingProgress::new();
+
+        let output = use_case
+            .resume(snapshot, ExecutionParams::default(), &progress)
+            .await
+            .unwrap();
+
+        assert!(output.success, "summary: {}", output.summary);
+        assert!(!progress.has_phase(&AgentPhase::ContextGathering));
+        assert!(!progress.has_phase(&AgentPhase::Planning));
+        assert!(!progress.has_phase(&AgentPhase::PlanReview));
+        assert!(progress.has_phase(&AgentPhase::Executing));
+
+        // Context is reused rather than re-gathered
+        assert_eq!(output.state.context.project_type.as_deref(), Some("rust"));
+
+        // Only task 2 ran: one decision-model session, no planning session
+        let sessions = gateway.created_sessions.lock().unwrap().clone();
+        let sonnet = Model::ClaudeSonnet45.to_string();
+        assert_eq!(sessions.iter().filter(|m| **m == sonnet).count(), 1);
+
+        let plan = output.state.plan.unwrap();
+        let task1 = &plan.tasks[0];
+        assert_eq!(task1.status, quorum_domain::TaskStatus::Completed);
+        assert_eq!(task1.result.as_ref().unwrap().output, "inspected");
+        assert_eq!(plan.tasks[1].status, quorum_domain::TaskStatus::Completed);
+        assert_eq!(output.state.phase, AgentPhase::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_resume_rejects_finished_snapshot() {
+        let mut snapshot = executing_snapshot();
+        snapshot.complete();
+
+        let use_case = RunAgentUseCase::new(
+            Arc::new(ScriptedGateway::new()),
+            Arc::new(MockToolExecutor::new()),
+            mock_tool_schema(),
+        );
+        let result = use_case
+            .resume(snapshot, ExecutionParams::default(), &NoAgentProgress)
+            .await;
+
+        assert!(matches!(result, Err(RunAgentError::InvalidConfig(_))));
+    }
+
+    // ==================== Phase Replay Tests ====================
+
+    #[tokio::test]
+    async fn test_replay_plan_review_returns_votes_without_execution() {
+        let mut state = AgentState::from_snapshot(executing_snapshot().to_snapshot()).unwrap();
+        state.set_phase(AgentPhase::PlanReview);
+
+        let mut gateway = ScriptedGateway::new();
+        gateway.add_session(
+            &Model::ClaudeSonnet45.to_string(),
+            vec![ScriptedResponse::Text(approve_response())],
+        );
+        let gateway = Arc::new(gateway);
+        let executor = Arc::new(MockToolExecutor::new());
+        let use_case = RunAgentUseCase::new(gateway.clone(), executor.clone(), mock_tool_schema());
+        let progress = TrackingProgress::new();
+
+        let replay = use_case
+            .replay_phase(
+                &state,
+                AgentPhase::PlanReview,
+                ExecutionParams::default(),
+                &progress,
+            )
+            .await
+            .unwrap();
+
+        match replay {
+            PhaseReplay::Review(review) => {
+                assert!(review.passed);
+                assert_eq!(review.votes.len(), 1);
+            }
+            other => panic!("expected a review result, got {:?}", other),
+        }
+        assert_eq!(
+            *gateway.created_sessions.lock().unwrap(),
+            vec![Model::ClaudeSonnet45.to_string()],
+            "only the reviewer session should be created"
+        );
+        assert!(executor.calls.lock().unwrap().is_empty());
+        assert!(!progress.has_phase(&AgentPhase::Executing));
+    }
+
+    #[tokio::test]
+    async fn test_replay_validates_state_for_phase() {
+        let use_case = RunAgentUseCase::new(
+            Arc::new(ScriptedGateway::new()),
+            Arc::new(MockToolExecutor::new()),
+            mock_tool_schema(),
+        );
+        let mut no_plan = executing_snapshot();
+        no_plan.plan = None;
+
+        for (state, phase) in [
+            (no_plan, AgentPhase::PlanReview),
+            (executing_snapshot(), AgentPhase::Executing),
+        ] {
+            let result = use_case
+                .replay_phase(&state, phase, ExecutionParams::default(), &NoAgentProgress)
+                .await;
+            assert!(matches!(result, Err(RunAgentError::InvalidConfig(_))));
+        }
+    }
 }
@@ -2,22 +2,39 @@
 
 use super::RunAgentUseCase;
 use super::types::{EnsemblePlanningOutcome, PlanningResult, RunAgentError, RunAgentInput};
+use crate::config::RetryPolicy;
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::ports::conversation_logger::ConversationEvent;
 use crate::ports::llm_gateway::{GatewayError, LlmSession, StreamObserver, ToolResultMessage};
-use crate::use_cases::shared::check_cancelled;
+use crate::use_cases::shared::{check_cancelled, send_with_tools_retrying};
 use quorum_domain::agent::plan_parser::extract_plan_from_response;
 use quorum_domain::quorum::parsing::parse_vote_score;
 use quorum_domain::session::response::LlmResponse;
 use quorum_domain::{
-    AgentContext, AgentPromptTemplate, EnsemblePlanResult, Model, Plan, PlanCandidate,
-    PromptTemplate, StreamContext,
+    AgentContext, AgentPromptTemplate, EnsemblePlanResult, EnsembleSelection, Model, Plan,
+    PlanCandidate, PromptTemplate, StreamContext,
 };
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
+/// How many top-scoring candidates the moderator merges under
+/// `agent.ensemble_selection = "merge"`.
+const MERGE_CANDIDATES: usize = 3;
+
+/// Why a single ensemble plan-generation task produced no result.
+enum PlanTaskFailure {
+    /// The model returned an error; retried once after backoff.
+    Error(String),
+    /// The model did not answer within `ensemble_session_timeout`; skipped.
+    TimedOut(Duration),
+    /// The branch was cancelled through [`EnsembleBranches`](super::EnsembleBranches); skipped.
+    Cancelled,
+}
+
 impl RunAgentUseCase {
     pub(super) async fn create_plan(
         &self,
@@ -25,17 +42,29 @@ impl RunAgentUseCase {
         request: &str,
         context: &AgentContext,
         previous_feedback: Option<&str>,
+        max_tasks: Option<usize>,
         _progress: &dyn AgentProgressNotifier,
     ) -> Result<PlanningResult, RunAgentError> {
         check_cancelled(&self.cancellation_token)?;
 
-        match generate_plan_from_session(session, request, context, previous_feedback).await {
+        match generate_plan_from_session(
+            session,
+            request,
+            context,
+            previous_feedback,
+            max_tasks,
+            &self.cancellation_token,
+            &self.retry_policy,
+        )
+        .await
+        {
             Ok(result) => Ok(result),
-            Err(e) => {
+            Err(RunAgentError::GatewayError(e)) => {
                 // Check if the real cause was cancellation
                 check_cancelled(&self.cancellation_token)?;
                 Err(RunAgentError::PlanningFailed(e.to_string()))
             }
+            Err(e) => Err(e),
         }
     }
 
@@ -70,14 +99,26 @@ impl RunAgentUseCase {
     /// - No review models are configured
     /// - Fewer than 2 models are configured
     /// - All models fail to generate plans
+    /// - Some models timed out and fewer than 2 models completed
+    ///
+    /// # Timeouts
+    ///
+    /// Each model's plan generation is bounded by `ensemble_session_timeout`.
+    /// A model that times out is skipped (not retried) and planning proceeds
+    /// with the models that completed, as long as at least 2 did.
+    /// The moderator's merge step (`agent.ensemble_selection = "merge"`) has
+    /// the same bound; on timeout the winning candidate is kept.
     ///
     /// # Progress Callbacks
     ///
     /// Calls the following progress notifier methods:
     /// - [`AgentProgressNotifier::on_ensemble_start`] - At the beginning
+    /// - [`AgentProgressNotifier::on_ensemble_model_chunk`] - For each streamed chunk, tagged with its model
     /// - [`AgentProgressNotifier::on_ensemble_plan_generated`] - For each plan
+    /// - [`AgentProgressNotifier::on_ensemble_model_timeout`] - For each timed-out model
     /// - [`AgentProgressNotifier::on_ensemble_voting_start`] - Before voting
     /// - [`AgentProgressNotifier::on_ensemble_complete`] - With the selected plan
+    /// - [`AgentProgressNotifier::on_ensemble_merged`] - Instead, when the moderator merged the top plans
     pub(super) async fn create_ensemble_plans(
         &self,
         input: &RunAgentInput,
@@ -109,9 +150,10 @@ impl RunAgentUseCase {
 
         let session_timeout = input.execution.ensemble_session_timeout;
         let mut join_set = JoinSet::new();
+        let branch_scope = self.ensemble_branches.scope();
 
         // Aggregation channel for real-time per-model streaming chunks
-        let (agg_tx, mut agg_rx) = mpsc::unbounded_channel::<(String, String)>();
+        let (agg_tx, mut agg_rx) = mpsc::unbounded_channel::<(Model, String)>();
 
         for model in models {
             let gateway = Arc::clone(&self.gateway);
@@ -120,14 +162,20 @@ impl RunAgentUseCase {
             let context = context.clone();
             let system_prompt = system_prompt.to_string();
             let feedback = previous_feedback.map(|s| s.to_string());
+            let branch_token = self
+                .ensemble_branches
+                .register(&model, self.cancellation_token.as_ref());
+            let cancellation_token = Some(branch_token.clone());
+            let retry_policy = self.retry_policy;
+            let max_tasks = input.policy.max_tasks_per_plan;
 
             progress.on_model_stream_start(&model.to_string(), &StreamContext::EnsemblePlanning);
 
             // Create observer that relays chunks to the aggregation channel
             let tx = agg_tx.clone();
-            let model_name = model.to_string();
+            let chunk_model = model.clone();
             let observer: StreamObserver = Arc::new(move |chunk: &str| {
-                let _ = tx.send((model_name.clone(), chunk.to_string()));
+                let _ = tx.send((chunk_model.clone(), chunk.to_string()));
             });
 
             join_set.spawn(async move {
@@ -142,19 +190,29 @@ impl RunAgentUseCase {
                         &request,
                         &context,
                         feedback.as_deref(),
+                        max_tasks,
+                        &cancellation_token,
+                        &retry_policy,
                     )
                     .await
                     .map_err(|e| e.to_string())
                 };
 
                 // Wrap with timeout if configured
-                let result = if let Some(timeout) = session_timeout {
-                    match tokio::time::timeout(timeout, plan_future).await {
-                        Ok(r) => r,
-                        Err(_) => Err(format!("session timed out after {}s", timeout.as_secs())),
+                let bounded = async {
+                    if let Some(timeout) = session_timeout {
+                        match tokio::time::timeout(timeout, plan_future).await {
+                            Ok(r) => r.map_err(PlanTaskFailure::Error),
+                            Err(_) => Err(PlanTaskFailure::TimedOut(timeout)),
+                        }
+                    } else {
+                        plan_future.await.map_err(PlanTaskFailure::Error)
                     }
-                } else {
-                    plan_future.await
+                };
+                let result = tokio::select! {
+                    biased;
+                    _ = branch_token.cancelled() => Err(PlanTaskFailure::Cancelled),
+                    result = bounded => result,
                 };
 
                 (model, result)
@@ -168,6 +226,7 @@ impl RunAgentUseCase {
         let mut text_responses: Vec<(String, String)> = Vec::new();
         let mut retryable_models: Vec<Model> = Vec::new();
         let mut failed_count = 0usize;
+        let mut timed_out_count = 0usize;
 
         loop {
             // Use select! to drain streaming chunks while waiting for task completion
@@ -179,7 +238,7 @@ impl RunAgentUseCase {
                         return Err(RunAgentError::Cancelled(None));
                     }
                     Some((model, chunk)) = agg_rx.recv() => {
-                        progress.on_model_stream_chunk(&model, &chunk);
+                        progress.on_ensemble_model_chunk(&model, &chunk);
                         continue;
                     }
                     result = join_set.join_next() => result,
@@ -188,7 +247,7 @@ impl RunAgentUseCase {
                 tokio::select! {
                     biased;
                     Some((model, chunk)) = agg_rx.recv() => {
-                        progress.on_model_stream_chunk(&model, &chunk);
+                        progress.on_ensemble_model_chunk(&model, &chunk);
                         continue;
                     }
                     result = join_set.join_next() => result,
@@ -198,6 +257,13 @@ impl RunAgentUseCase {
             let Some(result) = task_result else {
                 break;
             };
+            // A finished branch can no longer be cancelled, except a failed
+            // one, which stays open until its retry below
+            if let Ok((model, outcome)) = &result
+                && !matches!(outcome, Err(PlanTaskFailure::Error(_)))
+            {
+                self.ensemble_branches.close(model);
+            }
 
             match result {
                 Ok((model, Ok(PlanningResult::Plan(plan)))) => {
@@ -234,8 +300,26 @@ impl RunAgentUseCase {
                         text_responses.push((model_str, text));
                     }
                 }
-                Ok((model, Err(e))) => {
-                    // All errors are retryable (timeout, transport close, router stopped, etc.)
+                Ok((model, Err(PlanTaskFailure::TimedOut(timeout)))) => {
+                    // A hung model would hang again on retry — skip it.
+                    warn!(
+                        "Model {} timed out after {}s, skipping",
+                        model,
+                        timeout.as_secs_f64()
+                    );
+                    progress.on_model_stream_end(&model.to_string());
+                    progress.on_ensemble_model_timeout(&model);
+                    timed_out_count += 1;
+                    failed_count += 1;
+                }
+                Ok((model, Err(PlanTaskFailure::Cancelled))) => {
+                    warn!("Model {} was cancelled, skipping", model);
+                    progress.on_model_stream_end(&model.to_string());
+                    progress.on_ensemble_model_failed(&model, "cancelled");
+                    failed_count += 1;
+                }
+                Ok((model, Err(PlanTaskFailure::Error(e)))) => {
+                    // Errors are retryable (transport close, router stopped, etc.)
                     let model_str = model.to_string();
                     warn!("Model {} failed (will retry after backoff): {}", model, e);
                     progress.on_model_stream_end(&model_str);
@@ -263,31 +347,47 @@ impl RunAgentUseCase {
 
             for model in retryable_models {
                 check_cancelled(&self.cancellation_token)?;
+                if !self.ensemble_branches.active().contains(&model) {
+                    info!("Model {} was cancelled, not retrying", model);
+                    progress.on_ensemble_model_failed(&model, "cancelled");
+                    failed_count += 1;
+                    continue;
+                }
                 info!("Retrying timed-out model: {}", model);
 
-                let session = match self
-                    .gateway
-                    .create_session_with_system_prompt(&model, system_prompt)
+                // A fresh branch, so the retry can still be cancelled on its own
+                let branch_token = self
+                    .ensemble_branches
+                    .register(&model, self.cancellation_token.as_ref());
+                let cancellation_token = Some(branch_token.clone());
+                let attempt = async {
+                    let session = self
+                        .gateway
+                        .create_session_with_system_prompt(&model, system_prompt)
+                        .await
+                        .map_err(|e| format!("session creation failed: {}", e))?;
+
+                    generate_plan_from_session(
+                        session.as_ref(),
+                        &input.request,
+                        context,
+                        previous_feedback,
+                        input.policy.max_tasks_per_plan,
+                        &cancellation_token,
+                        &self.retry_policy,
+                    )
                     .await
-                {
-                    Ok(s) => s,
-                    Err(e) => {
-                        warn!("Model {} retry: session creation failed: {}", model, e);
-                        progress.on_ensemble_model_failed(&model, &e.to_string());
-                        failed_count += 1;
-                        continue;
-                    }
+                    .map_err(|e| e.to_string())
+                };
+                let result = tokio::select! {
+                    biased;
+                    _ = branch_token.cancelled() => None,
+                    result = attempt => Some(result),
                 };
+                self.ensemble_branches.close(&model);
 
-                match generate_plan_from_session(
-                    session.as_ref(),
-                    &input.request,
-                    context,
-                    previous_feedback,
-                )
-                .await
-                {
-                    Ok(PlanningResult::Plan(plan)) => {
+                match result {
+                    Some(Ok(PlanningResult::Plan(plan))) => {
                         let model_str = model.to_string();
                         info!(
                             "Model {} generated plan on retry: {}",
@@ -298,7 +398,7 @@ impl RunAgentUseCase {
                         progress.on_ensemble_plan_generated(&model);
                         candidates.push(PlanCandidate::new(model, plan));
                     }
-                    Ok(PlanningResult::TextResponse(text)) => {
+                    Some(Ok(PlanningResult::TextResponse(text))) => {
                         if text.trim().is_empty() {
                             warn!(
                                 "Model {} returned empty text response on retry, discarding",
@@ -315,15 +415,36 @@ impl RunAgentUseCase {
                             text_responses.push((model_str, text));
                         }
                     }
-                    Err(e) => {
+                    None => {
+                        check_cancelled(&self.cancellation_token)?;
+                        warn!("Model {} was cancelled during retry, skipping", model);
+                        progress.on_model_stream_end(&model.to_string());
+                        progress.on_ensemble_model_failed(&model, "cancelled");
+                        failed_count += 1;
+                    }
+                    Some(Err(e)) => {
                         warn!("Model {} retry failed: {}", model, e);
-                        progress.on_ensemble_model_failed(&model, &e.to_string());
+                        progress.on_ensemble_model_failed(&model, &e);
                         failed_count += 1;
                     }
                 }
             }
         }
 
+        drop(branch_scope);
+
+        // Partial quorum: when models timed out, continue only if at least
+        // two models still completed — otherwise let the caller fall back to solo.
+        let completed = candidates.len() + text_responses.len();
+        if timed_out_count > 0 && completed < 2 {
+            return Err(RunAgentError::EnsemblePlanningFailed(format!(
+                "Only {} of {} models completed ({} timed out)",
+                completed,
+                models.len(),
+                timed_out_count
+            )));
+        }
+
         if candidates.is_empty() {
             if !text_responses.is_empty() {
                 // All models returned text responses — synthesize via moderator
@@ -331,13 +452,25 @@ impl RunAgentUseCase {
                     "All models returned text responses ({} total), synthesizing via moderator",
                     text_responses.len()
                 );
-                let synthesized = self
-                    .synthesize_text_responses(
-                        &input.request,
-                        &text_responses,
-                        &input.models.decision,
-                    )
-                    .await?;
+                let moderator = &input.models.moderator;
+                let synthesized = match self
+                    .synthesize_text_responses(&input.request, &text_responses, moderator)
+                    .await
+                {
+                    Ok(text) => text,
+                    Err(e) => {
+                        check_cancelled(&self.cancellation_token)?;
+                        let Some((model, text)) = best_text_response(&text_responses, models)
+                        else {
+                            return Err(e);
+                        };
+                        warn!(
+                            "Text synthesis via {} failed ({}), using the response from {}",
+                            moderator, e, model
+                        );
+                        text.to_string()
+                    }
+                };
                 return Ok(EnsemblePlanningOutcome::TextResponse(synthesized));
             }
             return Err(RunAgentError::EnsemblePlanningFailed(format!(
@@ -386,7 +519,13 @@ impl RunAgentUseCase {
                             .create_session_with_system_prompt(&voter_model, &system_prompt)
                             .await?;
                         let response = session.send(&voting_prompt).await?;
-                        let score = parse_vote_score(&response);
+                        let (score, parsed) = parse_vote_score(&response);
+                        if !parsed {
+                            debug!(
+                                "No numeric score from {}, using fallback {}",
+                                voter_model, score
+                            );
+                        }
                         Ok::<(String, f64), GatewayError>((voter_model.to_string(), score))
                     };
 
@@ -460,9 +599,18 @@ impl RunAgentUseCase {
         }
 
         // Step 3: Select the best plan
-        let result = EnsemblePlanResult::select_best(candidates);
-
-        if let Some(selected) = result.selected() {
+        let mut result = EnsemblePlanResult::select_deterministic(candidates);
+
+        // Step 4 (optional): Merge the top candidates via the moderator.
+        // The merged plan was never voted on, so the winner's score is not
+        // reported for it.
+        if input.policy.ensemble_selection == EnsembleSelection::Merge
+            && let Some(merged) = self
+                .merge_top_plans(input, system_prompt, &result, progress)
+                .await?
+        {
+            result = result.with_merged_plan(merged);
+        } else if let Some(selected) = result.selected() {
             info!(
                 "Selected plan from {} with average score {:.1}/10",
                 selected.model,
@@ -482,6 +630,99 @@ impl RunAgentUseCase {
         Ok(EnsemblePlanningOutcome::Plans(result))
     }
 
+    /// Ask the moderator to merge the top-scoring candidates into one plan.
+    ///
+    /// Bounded by `ensemble_session_timeout`. Returns `Ok(None)` when the
+    /// moderator fails, times out or returns no valid plan; the caller then
+    /// keeps the winning candidate.
+    async fn merge_top_plans(
+        &self,
+        input: &RunAgentInput,
+        system_prompt: &str,
+        result: &EnsemblePlanResult,
+        progress: &dyn AgentProgressNotifier,
+    ) -> Result<Option<Plan>, RunAgentError> {
+        let top = result.top_candidates(MERGE_CANDIDATES);
+        if top.len() < 2 {
+            return Ok(None);
+        }
+
+        let moderator = &input.models.moderator;
+        info!("Merging top {} plans via {}", top.len(), moderator);
+        let prompt = AgentPromptTemplate::merge_plans(&input.request, &top);
+        let plan_tool = AgentPromptTemplate::plan_tool_schema();
+
+        let merge_future = async {
+            let session = self
+                .gateway
+                .create_session_with_system_prompt(moderator, system_prompt)
+                .await?;
+            send_with_tools_retrying(
+                session.as_ref(),
+                &prompt,
+                std::slice::from_ref(&plan_tool),
+                &self.cancellation_token,
+                &self.retry_policy,
+            )
+            .await
+        };
+        let response = match input.execution.ensemble_session_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, merge_future)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(GatewayError::Other(format!(
+                        "merge timed out after {}s",
+                        timeout.as_secs()
+                    )))
+                }),
+            None => merge_future.await,
+        };
+
+        let merged = match response {
+            Ok(response) => extract_plan_from_response(&response, input.policy.max_tasks_per_plan)
+                .map_err(|e| e.to_string()),
+            Err(e) => {
+                check_cancelled(&self.cancellation_token)?;
+                Err(e.to_string())
+            }
+        };
+
+        match merged {
+            Ok(Some(plan)) => {
+                self.conversation_logger.log(ConversationEvent::new(
+                    "plan_merged",
+                    serde_json::json!({
+                        "moderator": moderator.to_string(),
+                        "sources": top.iter().map(|c| c.model.to_string()).collect::<Vec<_>>(),
+                        "task_count": plan.tasks.len(),
+                    }),
+                ));
+                info!(
+                    "Moderator {} merged {} plans: {}",
+                    moderator,
+                    top.len(),
+                    plan.objective
+                );
+                progress.on_ensemble_merged(moderator, top.len());
+                Ok(Some(plan))
+            }
+            Ok(None) => {
+                warn!(
+                    "Moderator {} returned no merged plan, keeping the winner",
+                    moderator
+                );
+                Ok(None)
+            }
+            Err(e) => {
+                warn!(
+                    "Plan merge via {} failed, keeping the winner: {}",
+                    moderator, e
+                );
+                Ok(None)
+            }
+        }
+    }
+
     /// Synthesize text responses from multiple models using a moderator.
     ///
     /// Reuses the Quorum Discussion synthesis pattern
@@ -514,46 +755,89 @@ impl RunAgentUseCase {
 /// Generate a plan from an existing LLM session using Native Tool Use.
 ///
 /// Sends a planning prompt with a `create_plan` tool schema, then extracts
-/// the structured plan from the tool-use response.  If the LLM calls
-/// `create_plan` with empty/invalid arguments, one retry is attempted.
-/// If no plan is produced at all, the text content is returned instead.
+/// the structured plan from the tool-use response.  Transient request
+/// failures are retried per `retry_policy`.  If the LLM calls
+/// `create_plan` with empty/invalid arguments, or returns a plan that is
+/// rejected by [`extract_plan_from_response`] (more than `max_tasks` tasks, or
+/// a `depends_on` naming a task that does not exist), the error is sent back
+/// and one retry is attempted; a retry that is rejected again fails
+/// planning. If no plan is produced at all, the text content is returned
+/// instead.
 pub(super) async fn generate_plan_from_session(
     session: &dyn LlmSession,
     request: &str,
     context: &AgentContext,
     previous_feedback: Option<&str>,
-) -> Result<PlanningResult, GatewayError> {
+    max_tasks: Option<usize>,
+    cancellation_token: &Option<CancellationToken>,
+    retry_policy: &RetryPolicy,
+) -> Result<PlanningResult, RunAgentError> {
     let prompt = AgentPromptTemplate::planning_with_feedback(request, context, previous_feedback);
     let plan_tool = AgentPromptTemplate::plan_tool_schema();
 
-    let response = session.send_with_tools(&prompt, &[plan_tool]).await?;
-
-    if let Some(plan) = extract_plan_from_response(&response) {
-        return Ok(PlanningResult::Plan(plan));
-    }
+    let response = send_with_tools_retrying(
+        session,
+        &prompt,
+        std::slice::from_ref(&plan_tool),
+        cancellation_token,
+        retry_policy,
+    )
+    .await?;
+
+    let rejected = match extract_plan_from_response(&response, max_tasks) {
+        Ok(Some(plan)) => return Ok(PlanningResult::Plan(plan)),
+        Ok(None) => None,
+        Err(e) => {
+            debug!("Plan rejected, asking the model to fix it: {}", e);
+            Some(e)
+        }
+    };
 
-    // create_plan was called with empty/invalid arguments — send error and retry once
+    // create_plan was called with empty/invalid arguments or the plan was
+    // rejected — send the error and retry once
     let mut retry_response: Option<LlmResponse> = None;
     if response.has_tool_use("create_plan")
         && let Some(tool_use_id) = response.first_tool_use_id()
     {
-        debug!("create_plan called with empty arguments, sending error for retry");
+        let output = match &rejected {
+            Some(e) => format!("Error: {}. Please call create_plan again.", e),
+            None => {
+                debug!("create_plan called with empty arguments, sending error for retry");
+                "Error: create_plan requires 'objective', 'reasoning', and 'tasks' \
+                 fields. Please call create_plan again with all required arguments."
+                    .to_string()
+            }
+        };
         let results = vec![ToolResultMessage {
             tool_use_id: tool_use_id.to_string(),
             tool_name: "create_plan".to_string(),
-            output: "Error: create_plan requires 'objective', 'reasoning', and 'tasks' \
-                     fields. Please call create_plan again with all required arguments."
-                .to_string(),
+            output,
             is_error: true,
             is_rejected: false,
         }];
         let retry = session.send_tool_results(&results).await?;
-        if let Some(plan) = extract_plan_from_response(&retry) {
-            return Ok(PlanningResult::Plan(plan));
-        }
+        retry_response = Some(retry);
+    } else if let Some(e) = &rejected {
+        // Text-based plan (no Native Tool Use): ask again in a new turn
+        let retry = send_with_tools_retrying(
+            session,
+            &format!("{}. Please create the plan again.", e),
+            std::slice::from_ref(&plan_tool),
+            cancellation_token,
+            retry_policy,
+        )
+        .await?;
         retry_response = Some(retry);
     }
 
+    if let Some(retry) = &retry_response {
+        match extract_plan_from_response(retry, max_tasks) {
+            Ok(Some(plan)) => return Ok(PlanningResult::Plan(plan)),
+            Ok(None) => {}
+            Err(e) => return Err(RunAgentError::PlanningFailed(e.to_string())),
+        }
+    }
+
     // No plan found — LLM responded with text only
     // Prefer retry response text (if retry happened), fall back to original response
     let text = retry_response
@@ -568,6 +852,30 @@ pub(super) async fn generate_plan_from_session(
     Ok(PlanningResult::TextResponse(String::new()))
 }
 
+/// Pick the single text response to return when moderator synthesis fails:
+/// the longest one, ties going to the model listed first in `models`.
+fn best_text_response<'a>(
+    responses: &'a [(String, String)],
+    models: &[Model],
+) -> Option<(&'a str, &'a str)> {
+    let rank = |name: &str| {
+        models
+            .iter()
+            .position(|m| m.to_string() == name)
+            .unwrap_or(usize::MAX)
+    };
+    responses
+        .iter()
+        .max_by(|(a_model, a_text), (b_model, b_text)| {
+            a_text
+                .trim()
+                .len()
+                .cmp(&b_text.trim().len())
+                .then_with(|| rank(b_model).cmp(&rank(a_model)))
+        })
+        .map(|(model, text)| (model.as_str(), text.as_str()))
+}
+
 /// Format a plan's content for display in a per-model streaming pane.
 ///
 /// During Ensemble Planning, tool-use sessions produce no streaming text
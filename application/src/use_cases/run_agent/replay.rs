@@ -0,0 +1,184 @@
+//! Single-phase replay for debugging.
+//!
+//! [`RunAgentUseCase::replay_phase`] re-runs one phase against a persisted
+//! [`AgentState`] (e.g. from `--dump-state`) without running the phases
+//! around it, so a developer can inspect e.g. the plan review of a saved plan
+//! without re-gathering context or executing tasks. The state is never
+//! mutated; the phase's result is returned as a [`PhaseReplay`].
+
+use super::RunAgentUseCase;
+use super::types::{EnsemblePlanningOutcome, PlanningResult, RunAgentError, RunAgentInput};
+use crate::config::ExecutionParams;
+use crate::ports::agent_progress::AgentProgressNotifier;
+use quorum_domain::{AgentContext, AgentPhase, AgentPromptTemplate, AgentState, Plan, VoteResult};
+
+/// Result of replaying one phase with [`RunAgentUseCase::replay_phase`].
+#[derive(Debug, Clone)]
+pub enum PhaseReplay {
+    /// Context produced by Context Gathering
+    Context(AgentContext),
+    /// Plan produced by Planning
+    Plan(Plan),
+    /// Planning answered in text instead of creating a plan
+    TextResponse(String),
+    /// Votes from Plan Review or Final Review
+    Review(VoteResult),
+}
+
+impl RunAgentUseCase {
+    /// Re-run a single phase against `state` and return its result.
+    ///
+    /// | Phase | Needs | Result |
+    /// |-------|-------|--------|
+    /// | `ContextGathering` | request | [`PhaseReplay::Context`] |
+    /// | `Planning` | request (uses saved context) | [`PhaseReplay::Plan`] / [`PhaseReplay::TextResponse`] |
+    /// | `PlanReview` | plan, review models | [`PhaseReplay::Review`] |
+    /// | `FinalReview` | plan with finished tasks, review models | [`PhaseReplay::Review`] |
+    ///
+    /// Plan review always queries the reviewers, even if the saved policy
+    /// disables it. Executing and Action Review depend on live tool state and
+    /// cannot be replayed in isolation. Returns [`RunAgentError::InvalidConfig`]
+    /// if the phase is not replayable or the state lacks what it needs.
+    pub async fn replay_phase(
+        &self,
+        state: &AgentState,
+        phase: AgentPhase,
+        execution: ExecutionParams,
+        progress: &dyn AgentProgressNotifier,
+    ) -> Result<PhaseReplay, RunAgentError> {
+        let mut input = RunAgentInput::from_agent_state(state, execution);
+        let system_prompt = AgentPromptTemplate::agent_system();
+
+        match phase {
+            AgentPhase::ContextGathering => {
+                require_request(state)?;
+                let session = self
+                    .gateway
+                    .create_session_with_system_prompt(&input.models.exploration, &system_prompt)
+                    .await?;
+                let context = self
+                    .gather_context_use_case()
+                    .execute(session.as_ref(), &input.request, &input.execution, progress)
+                    .await?;
+                Ok(PhaseReplay::Context(context))
+            }
+            AgentPhase::Planning => {
+                require_request(state)?;
+                if input.mode.planning_approach().is_ensemble() {
+                    return match self
+                        .create_ensemble_plans(
+                            &input,
+                            &state.context,
+                            &system_prompt,
+                            None,
+                            progress,
+                        )
+                        .await?
+                    {
+                        EnsemblePlanningOutcome::Plans(result) => result
+                            .plan()
+                            .cloned()
+                            .map(PhaseReplay::Plan)
+                            .ok_or_else(|| {
+                                RunAgentError::EnsemblePlanningFailed(
+                                    "No plan was selected".to_string(),
+                                )
+                            }),
+                        EnsemblePlanningOutcome::TextResponse(text) => {
+                            Ok(PhaseReplay::TextResponse(text))
+                        }
+                    };
+                }
+                let session = self
+                    .gateway
+                    .create_session_with_system_prompt(&input.models.decision, &system_prompt)
+                    .await?;
+                match self
+                    .create_plan(
+                        session.as_ref(),
+                        &input.request,
+                        &state.context,
+                        None,
+                        input.policy.max_tasks_per_plan,
+                        progress,
+                    )
+                    .await?
+                {
+                    PlanningResult::Plan(plan) => Ok(PhaseReplay::Plan(plan)),
+                    PlanningResult::TextResponse(text) => Ok(PhaseReplay::TextResponse(text)),
+                }
+            }
+            AgentPhase::PlanReview => {
+                require_plan(state)?;
+                require_reviewers(&input)?;
+                input.policy.require_plan_review = true;
+                let review = self.review_plan(&input, state, progress).await?;
+                Ok(PhaseReplay::Review(review))
+            }
+            AgentPhase::FinalReview => {
+                let plan = require_plan(state)?;
+                require_reviewers(&input)?;
+                let (finished, total) = plan.progress();
+                if finished == 0 {
+                    return Err(RunAgentError::InvalidConfig(
+                        "final review needs a state with executed tasks".to_string(),
+                    ));
+                }
+                let summary = recorded_results_summary(plan, finished, total);
+                let review = self.final_review(&input, state, &summary, progress).await?;
+                Ok(PhaseReplay::Review(review))
+            }
+            other => Err(RunAgentError::InvalidConfig(format!(
+                "phase '{}' cannot be replayed in isolation",
+                other
+            ))),
+        }
+    }
+}
+
+fn require_request(state: &AgentState) -> Result<(), RunAgentError> {
+    if state.request.trim().is_empty() {
+        return Err(RunAgentError::InvalidConfig(
+            "saved state has no request".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn require_plan(state: &AgentState) -> Result<&Plan, RunAgentError> {
+    state
+        .plan
+        .as_ref()
+        .ok_or_else(|| RunAgentError::InvalidConfig("saved state has no plan".to_string()))
+}
+
+fn require_reviewers(input: &RunAgentInput) -> Result<(), RunAgentError> {
+    if input.models.review.is_empty() {
+        return Err(RunAgentError::InvalidConfig(
+            "no review models configured".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rebuild an execution summary from the task results recorded in `plan`.
+fn recorded_results_summary(plan: &Plan, finished: usize, total: usize) -> String {
+    let results: Vec<String> = plan
+        .tasks
+        .iter()
+        .filter_map(|task| {
+            let result = task.result.as_ref()?;
+            let status = if result.success { "OK" } else { "FAILED" };
+            Some(format!(
+                "Task {} ({}): {} — {}",
+                task.id, task.description, status, result.output
+            ))
+        })
+        .collect();
+    format!(
+        "Completed {}/{} tasks.\n\n{}",
+        finished,
+        total,
+        results.join("\n")
+    )
+}
@@ -8,14 +8,22 @@ use super::types::{RunAgentError, RunAgentInput};
 use crate::ports::action_reviewer::{ActionReviewer, ReviewDecision};
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::ports::event_publisher::{AppEvent, EventPublisher};
-use crate::ports::llm_gateway::{GatewayError, LlmGateway};
+use crate::ports::llm_gateway::{GatewayError, LlmGateway, StreamObserver};
 use crate::ports::tool_executor::ToolExecutorPort;
 use async_trait::async_trait;
 use quorum_domain::agent::model_config::ModelConfig;
-use quorum_domain::quorum::parsing::{parse_final_review_response, parse_review_response};
-use quorum_domain::quorum::{QuorumResultPayload, QuorumTarget, QuorumTopic, Vote, VoteResult};
-use quorum_domain::{AgentPromptTemplate, AgentState, Model, Task};
+use quorum_domain::quorum::parsing::{
+    ReviewSubmission, extract_review_submission, parse_final_review_response, parse_review_response,
+};
+use quorum_domain::quorum::{
+    ConsensusMode, QuorumResultPayload, QuorumRule, QuorumTarget, QuorumTopic, Vote, VoteResult,
+    VoteVerdict,
+};
+use quorum_domain::{AgentPromptTemplate, AgentState, Model, Plan, RiskLevel, Task, parse_plan};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
 use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
@@ -30,16 +38,200 @@ pub(crate) async fn query_model_for_review(
     model: &Model,
     prompt: &str,
 ) -> Result<String, GatewayError> {
-    let system_prompt = "You are a code reviewer evaluating plans and actions. \
-        Provide your assessment with a clear APPROVE or REJECT/REVISE recommendation.";
-
     let session = gateway
-        .create_text_only_session(model, system_prompt)
+        .create_text_only_session(model, REVIEWER_SYSTEM_PROMPT)
         .await?;
 
     session.send(prompt).await
 }
 
+/// Ask `model` for a plan or action review verdict via the `submit_review`
+/// tool, falling back to [`parse_review_response`] when it answers in text.
+///
+/// With an `observer`, the session streams the reviewer's text to it as it
+/// is generated (the reasoning inside a tool call arrives in one piece).
+pub(crate) async fn request_review(
+    gateway: &dyn LlmGateway,
+    model: &Model,
+    prompt: &str,
+    observer: Option<StreamObserver>,
+) -> Result<ReviewSubmission, GatewayError> {
+    let session = match observer {
+        Some(observer) => {
+            gateway
+                .create_streaming_session(model, REVIEWER_SYSTEM_PROMPT, observer)
+                .await?
+        }
+        None => {
+            gateway
+                .create_text_only_session(model, REVIEWER_SYSTEM_PROMPT)
+                .await?
+        }
+    };
+
+    let response = session
+        .send_with_tools(prompt, &[AgentPromptTemplate::review_tool_schema()])
+        .await?;
+
+    Ok(extract_review_submission(&response).unwrap_or_else(|| {
+        let (approved, reasoning) = parse_review_response(&response.text_content());
+        ReviewSubmission {
+            approved,
+            score: None,
+            reasoning,
+        }
+    }))
+}
+
+/// Ask every reviewer in `models` for a verdict concurrently.
+///
+/// Votes come back in `models` order regardless of which reviewer answers
+/// first, so aggregated feedback is reproducible. A reviewer that errors or
+/// does not answer within `timeout` gets a [`VoteVerdict::ModelError`] vote,
+/// which is recorded but not counted. With `stream`, each reviewer's text is
+/// forwarded to [`AgentProgressNotifier::on_quorum_model_chunk`] as it arrives.
+pub(crate) async fn collect_review_votes(
+    gateway: &Arc<dyn LlmGateway>,
+    models: &[Model],
+    prompt: &str,
+    stream: bool,
+    timeout: Option<Duration>,
+    cancellation_token: &Option<CancellationToken>,
+    progress: &dyn AgentProgressNotifier,
+) -> Result<Vec<Vote>, RunAgentError> {
+    let mut join_set = JoinSet::new();
+
+    // Aggregation channel for streaming each reviewer's reasoning live
+    let (agg_tx, mut agg_rx) = mpsc::unbounded_channel::<(Model, String)>();
+
+    for (index, model) in models.iter().enumerate() {
+        let gateway = Arc::clone(gateway);
+        let model = model.clone();
+        let prompt = prompt.to_string();
+        let observer: Option<StreamObserver> = stream.then(|| {
+            let tx = agg_tx.clone();
+            let chunk_model = model.clone();
+            Arc::new(move |chunk: &str| {
+                let _ = tx.send((chunk_model.clone(), chunk.to_string()));
+            }) as StreamObserver
+        });
+
+        join_set.spawn(async move {
+            let review = request_review(gateway.as_ref(), &model, &prompt, observer);
+            let result = match timeout {
+                Some(limit) => match tokio::time::timeout(limit, review).await {
+                    Ok(result) => result.map_err(|e| e.to_string()),
+                    Err(_) => Err(format!("Review timed out after {:?}", limit)),
+                },
+                None => review.await.map_err(|e| e.to_string()),
+            };
+            (index, model, result)
+        });
+    }
+    // Drop the original sender so agg_rx closes when all reviewers finish
+    drop(agg_tx);
+
+    // Collect votes with cancellation support, forwarding chunks as they arrive
+    let mut slots: Vec<Option<Vote>> = vec![None; models.len()];
+
+    loop {
+        let result = if let Some(token) = cancellation_token {
+            tokio::select! {
+                biased;
+                _ = token.cancelled() => {
+                    join_set.abort_all();
+                    return Err(RunAgentError::Cancelled(None));
+                }
+                Some((model, chunk)) = agg_rx.recv() => {
+                    progress.on_quorum_model_chunk(&model, &chunk);
+                    continue;
+                }
+                result = join_set.join_next() => result,
+            }
+        } else {
+            tokio::select! {
+                biased;
+                Some((model, chunk)) = agg_rx.recv() => {
+                    progress.on_quorum_model_chunk(&model, &chunk);
+                    continue;
+                }
+                result = join_set.join_next() => result,
+            }
+        };
+
+        let Some(result) = result else {
+            break; // All tasks complete
+        };
+
+        match result {
+            Ok((index, model, Ok(submission))) => {
+                let approved = submission.approved;
+                info!(
+                    "Model {} voted: {}",
+                    model,
+                    if approved { "APPROVE" } else { "REJECT" }
+                );
+                progress.on_quorum_model_complete(&model, approved);
+                slots[index] = Some(review_vote(&model, submission));
+            }
+            Ok((index, model, Err(e))) => {
+                warn!("Model {} failed to review: {}", model, e);
+                progress.on_quorum_model_complete(&model, false);
+                slots[index] = Some(Vote::model_error(model.to_string(), e));
+            }
+            Err(e) => {
+                warn!("Task join error: {}", e);
+            }
+        }
+    }
+
+    Ok(slots.into_iter().flatten().collect())
+}
+
+/// Turn a reviewer's submission into a [`Vote`]; the 1-10 score becomes
+/// the vote's confidence.
+pub(crate) fn review_vote(model: &Model, submission: ReviewSubmission) -> Vote {
+    let vote = if submission.approved {
+        Vote::approve(model.to_string(), submission.reasoning)
+    } else {
+        Vote::reject(model.to_string(), submission.reasoning)
+    };
+    match submission.score {
+        Some(score) => vote.with_confidence(f64::from(score) / 10.0),
+        None => vote,
+    }
+}
+
+const REVIEWER_SYSTEM_PROMPT: &str = "You are a code reviewer evaluating plans and actions. \
+    Provide your assessment with a clear APPROVE or REJECT/REVISE recommendation.";
+
+/// Corrective tasks a rejecting final reviewer attached as a ` ```plan ` block.
+///
+/// Takes the first parseable plan among the reject votes; `None` when no
+/// reviewer proposed anything actionable.
+pub(crate) fn corrective_plan(result: &VoteResult) -> Option<Plan> {
+    result
+        .votes
+        .iter()
+        .filter(|v| v.verdict == VoteVerdict::Reject)
+        .find_map(|v| parse_plan(&v.reasoning))
+}
+
+/// Build the [`RunAgentError::QuorumFailed`] error when no reviewer cast a vote.
+///
+/// Returns `None` if at least one vote was cast (the review has a real outcome).
+pub(crate) fn quorum_failure(votes: &[Vote]) -> Option<RunAgentError> {
+    if votes.iter().any(Vote::is_cast) {
+        return None;
+    }
+    Some(RunAgentError::QuorumFailed(
+        votes
+            .iter()
+            .map(|v| format!("{}: {}", v.model, v.reasoning))
+            .collect(),
+    ))
+}
+
 // ==================== QuorumActionReviewer ====================
 
 /// Action reviewer that uses quorum (multi-model voting) to review high-risk tool calls.
@@ -48,6 +240,10 @@ pub(crate) struct QuorumActionReviewer {
     tool_executor: Arc<dyn ToolExecutorPort>,
     cancellation_token: Option<CancellationToken>,
     event_publisher: Arc<dyn EventPublisher>,
+    /// `run_command` prefix overrides (`AgentPolicy::command_risk_overrides`).
+    risk_overrides: HashMap<String, RiskLevel>,
+    /// Per-reviewer time limit (`ExecutionParams::review_timeout`).
+    timeout: Option<Duration>,
 }
 
 impl QuorumActionReviewer {
@@ -62,8 +258,27 @@ impl QuorumActionReviewer {
             tool_executor,
             cancellation_token,
             event_publisher,
+            risk_overrides: HashMap::new(),
+            timeout: None,
         }
     }
+
+    /// Classify `run_command` calls with these prefix overrides.
+    pub(crate) fn with_risk_overrides(mut self, overrides: HashMap<String, RiskLevel>) -> Self {
+        self.risk_overrides = overrides;
+        self
+    }
+
+    /// Give each reviewer at most `timeout` to answer.
+    pub(crate) fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Count distinct reviewer models (a model listed twice is one reviewer).
+fn distinct_reviewers(models: impl Iterator<Item = String>) -> usize {
+    models.collect::<HashSet<_>>().len()
 }
 
 /// Extract the tool name from a serialized tool call for the event target.
@@ -86,6 +301,18 @@ impl ActionReviewer for QuorumActionReviewer {
         progress: &dyn AgentProgressNotifier,
     ) -> Result<ReviewDecision, RunAgentError> {
         let models = &models.review;
+        let floor = state.policy.min_reviewers(RiskLevel::High);
+        let configured = distinct_reviewers(models.iter().map(|m| m.to_string()));
+        if configured < floor {
+            warn!(
+                "Only {} reviewer(s) configured for a high-risk action (minimum {})",
+                configured, floor
+            );
+            return Ok(ReviewDecision::Escalate(format!(
+                "Only {} reviewer(s) configured; high-risk actions require at least {}",
+                configured, floor
+            )));
+        }
         if models.is_empty() {
             return Ok(ReviewDecision::SkipReview);
         }
@@ -95,82 +322,36 @@ impl ActionReviewer for QuorumActionReviewer {
 
         let prompt = AgentPromptTemplate::action_review(task, tool_call_json, &state.context);
 
-        // Query all quorum models in parallel
-        let mut join_set = JoinSet::new();
-
-        for model in models {
-            let gateway = Arc::clone(&self.gateway);
-            let model = model.clone();
-            let prompt = prompt.clone();
-
-            join_set.spawn(async move {
-                let result = query_model_for_review(gateway.as_ref(), &model, &prompt).await;
-                (model, result)
-            });
-        }
-
-        // Collect votes with cancellation support
-        let mut votes = Vec::new();
-
-        loop {
-            let result = if let Some(ref token) = self.cancellation_token {
-                tokio::select! {
-                    biased;
-                    _ = token.cancelled() => {
-                        join_set.abort_all();
-                        return Err(RunAgentError::Cancelled(None));
-                    }
-                    result = join_set.join_next() => result,
-                }
-            } else {
-                join_set.join_next().await
-            };
-
-            let Some(result) = result else {
-                break;
-            };
-
-            match result {
-                Ok((model, Ok(response))) => {
-                    let (approved, feedback) = parse_review_response(&response);
-                    info!(
-                        "Model {} voted: {}",
-                        model,
-                        if approved { "APPROVE" } else { "REJECT" }
-                    );
-                    progress.on_quorum_model_complete(&model, approved);
-                    votes.push(if approved {
-                        Vote::approve(model.to_string(), feedback)
-                    } else {
-                        Vote::reject(model.to_string(), feedback)
-                    });
-                }
-                Ok((model, Err(e))) => {
-                    warn!("Model {} failed to review: {}", model, e);
-                    progress.on_quorum_model_complete(&model, false);
-                    votes.push(Vote::model_error(model.to_string(), e.to_string()));
-                }
-                Err(e) => {
-                    warn!("Task join error: {}", e);
-                }
-            }
-        }
+        let votes = collect_review_votes(
+            &self.gateway,
+            models,
+            &prompt,
+            false,
+            self.timeout,
+            &self.cancellation_token,
+            progress,
+        )
+        .await?;
 
-        if !votes.iter().any(Vote::is_cast) {
-            return Err(RunAgentError::QuorumFailed);
+        if let Some(err) = quorum_failure(&votes) {
+            return Err(err);
         }
 
-        let review = VoteResult::from_votes(votes);
+        let rule = state.policy.quorum_rule;
+        let review = VoteResult::from_votes_with_rule(votes, &rule);
 
         self.event_publisher
-            .publish(AppEvent::QuorumResult(Box::new(QuorumResultPayload::new(
-                QuorumTopic::ActionReview,
-                Some(QuorumTarget::action(
-                    task.id.to_string(),
-                    tool_name_from_json(tool_call_json),
-                )),
-                &review,
-            ))));
+            .publish(AppEvent::QuorumResult(Box::new(
+                QuorumResultPayload::new(
+                    QuorumTopic::ActionReview,
+                    Some(QuorumTarget::action(
+                        task.id.to_string(),
+                        tool_name_from_json(tool_call_json),
+                    )),
+                    &review,
+                )
+                .with_rule(rule),
+            )));
 
         // Notify with detailed vote information
         progress.on_quorum_complete_with_votes(
@@ -180,7 +361,23 @@ impl ActionReviewer for QuorumActionReviewer {
             review.aggregated_feedback.as_deref(),
         );
 
-        if review.passed {
+        let voted = distinct_reviewers(
+            review
+                .votes
+                .iter()
+                .filter(|v| v.verdict != VoteVerdict::ModelError)
+                .map(|v| v.model.clone()),
+        );
+        if review.passed && !state.policy.meets_reviewer_floor(RiskLevel::High, voted) {
+            warn!(
+                "High-risk action approved by only {} reviewer(s) (minimum {})",
+                voted, floor
+            );
+            Ok(ReviewDecision::Escalate(format!(
+                "Only {} of {} reviewer(s) voted; high-risk actions require at least {}",
+                voted, configured, floor
+            )))
+        } else if review.passed {
             Ok(ReviewDecision::Approved)
         } else {
             Ok(ReviewDecision::Rejected(
@@ -199,7 +396,11 @@ impl ActionReviewer for QuorumActionReviewer {
         // For run_command, dynamically classify based on the actual command
         if tool_name == "run_command" {
             if let Some(cmd_str) = arguments.get("command").and_then(|v| v.as_str()) {
-                return quorum_domain::classify_command_risk(cmd_str).requires_quorum();
+                return quorum_domain::classify_command_risk_with_overrides(
+                    cmd_str,
+                    &self.risk_overrides,
+                )
+                .requires_quorum();
             }
             // No command argument → treat as high-risk (conservative)
             return true;
@@ -247,80 +448,49 @@ impl RunAgentUseCase {
 
         let prompt = AgentPromptTemplate::plan_review(&input.request, plan, &state.context);
 
-        // Query all quorum models in parallel
-        let mut join_set = JoinSet::new();
-
-        for model in models {
-            let gateway = Arc::clone(&self.gateway);
-            let model = model.clone();
-            let prompt = prompt.clone();
+        let votes = collect_review_votes(
+            &self.gateway,
+            models,
+            &prompt,
+            true,
+            input.execution.review_timeout,
+            &self.cancellation_token,
+            progress,
+        )
+        .await?;
 
-            join_set.spawn(async move {
-                let result = query_model_for_review(gateway.as_ref(), &model, &prompt).await;
-                (model, result)
-            });
+        if let Some(err) = quorum_failure(&votes) {
+            return Err(err);
         }
 
-        // Collect votes with cancellation support
-        let mut votes = Vec::new();
-
-        loop {
-            // Check for cancellation using select! if token exists
-            let result = if let Some(ref token) = self.cancellation_token {
-                tokio::select! {
-                    biased;
-                    _ = token.cancelled() => {
-                        join_set.abort_all();
-                        return Err(RunAgentError::Cancelled(None));
-                    }
-                    result = join_set.join_next() => result,
-                }
-            } else {
-                join_set.join_next().await
-            };
-
-            let Some(result) = result else {
-                break; // All tasks complete
-            };
-
-            match result {
-                Ok((model, Ok(response))) => {
-                    let (approved, feedback) = parse_review_response(&response);
-                    info!(
-                        "Model {} voted: {}",
-                        model,
-                        if approved { "APPROVE" } else { "REJECT" }
-                    );
-                    progress.on_quorum_model_complete(&model, approved);
-                    votes.push(if approved {
-                        Vote::approve(model.to_string(), feedback)
-                    } else {
-                        Vote::reject(model.to_string(), feedback)
-                    });
-                }
-                Ok((model, Err(e))) => {
-                    warn!("Model {} failed to review: {}", model, e);
-                    progress.on_quorum_model_complete(&model, false);
-                    votes.push(Vote::model_error(model.to_string(), e.to_string()));
-                }
-                Err(e) => {
-                    warn!("Task join error: {}", e);
-                }
-            }
+        let rule = input.policy.quorum_rule;
+        let mode = input.policy.consensus_mode;
+        let mut result = VoteResult::from_votes_with_rule(votes, &rule).with_consensus_mode(&mode);
+        if let Some(scores) = &result.scores {
+            info!(
+                "Plan review scores: {} -> {}",
+                scores,
+                if result.passed { "APPROVE" } else { "REJECT" }
+            );
         }
 
-        if !votes.iter().any(Vote::is_cast) {
-            return Err(RunAgentError::QuorumFailed);
+        // An exact split never reaches a majority; let the tie-breaker decide
+        // instead of spending a revision cycle. Odd quorums cannot tie, and
+        // score-threshold decisions don't count votes.
+        if rule == QuorumRule::Majority
+            && mode == ConsensusMode::Binary
+            && result.is_tie()
+            && let Some(tie_breaker) = &input.models.tie_breaker
+        {
+            result = self
+                .break_plan_tie(input, plan, tie_breaker, result, &rule)
+                .await;
         }
 
-        let result = VoteResult::from_votes(votes);
-
         self.event_publisher()
-            .publish(AppEvent::QuorumResult(Box::new(QuorumResultPayload::new(
-                QuorumTopic::PlanReview,
-                None,
-                &result,
-            ))));
+            .publish(AppEvent::QuorumResult(Box::new(
+                QuorumResultPayload::new(QuorumTopic::PlanReview, None, &result).with_rule(rule),
+            )));
 
         // Note: UI notification is handled by the caller (execute_with_progress)
         // to maintain separation between business logic and presentation
@@ -328,6 +498,46 @@ impl RunAgentUseCase {
         Ok(result)
     }
 
+    /// Ask the tie-breaker model for the deciding vote on a tied plan review.
+    ///
+    /// The tie-breaker's vote is added to the tally and the result is
+    /// recomputed. If the model cannot be reached the tied result is kept.
+    async fn break_plan_tie(
+        &self,
+        input: &RunAgentInput,
+        plan: &Plan,
+        tie_breaker: &Model,
+        tied: VoteResult,
+        rule: &QuorumRule,
+    ) -> VoteResult {
+        info!(
+            "Plan review tied {}-{}, consulting tie-breaker {}",
+            tied.approve_count, tied.reject_count, tie_breaker
+        );
+
+        let prompt = AgentPromptTemplate::plan_tie_break(&input.request, plan, &tied.votes);
+        match request_review(self.gateway.as_ref(), tie_breaker, &prompt, None).await {
+            Ok(submission) => {
+                info!(
+                    "Tie-breaker {} voted: {}",
+                    tie_breaker,
+                    if submission.approved {
+                        "APPROVE"
+                    } else {
+                        "REJECT"
+                    }
+                );
+                let mut votes = tied.votes;
+                votes.push(review_vote(tie_breaker, submission));
+                VoteResult::from_votes_with_rule(votes, rule)
+            }
+            Err(e) => {
+                warn!("Tie-breaker {} failed: {}", tie_breaker, e);
+                tied
+            }
+        }
+    }
+
     /// Final review of agent results using quorum (optional)
     pub(super) async fn final_review(
         &self,
@@ -413,8 +623,8 @@ impl RunAgentUseCase {
             }
         }
 
-        if !votes.iter().any(Vote::is_cast) {
-            return Err(RunAgentError::QuorumFailed);
+        if let Some(err) = quorum_failure(&votes) {
+            return Err(err);
         }
 
         let result = VoteResult::from_votes(votes);
@@ -5,7 +5,10 @@ use crate::ports::llm_gateway::GatewayError;
 use quorum_domain::agent::agent_policy::AgentPolicy;
 use quorum_domain::agent::model_config::ModelConfig;
 use quorum_domain::orchestration::session_mode::SessionMode;
-use quorum_domain::{AgentId, AgentState, EnsemblePlanResult, Plan};
+use quorum_domain::{
+    AgentContext, AgentId, AgentPhase, AgentState, EnsemblePlanResult, Plan, TokenUsage, ToolStat,
+};
+use std::collections::{BTreeMap, HashMap};
 use thiserror::Error;
 
 /// Errors that can occur during Agent execution
@@ -41,12 +44,22 @@ pub enum RunAgentError {
     #[error("Human intervention failed: {0}")]
     HumanInterventionFailed(String),
 
-    #[error("All quorum models failed")]
-    QuorumFailed,
+    /// Every reviewer's gateway call errored, so no vote was cast.
+    ///
+    /// Distinct from [`RunAgentError::PlanRejected`]: the reviewers were
+    /// never reached, so there is no feedback to revise against. Carries
+    /// the per-model error messages (`"<model>: <error>"`).
+    #[error("Could not reach reviewers: {}", .0.join("; "))]
+    QuorumFailed(Vec<String>),
 
     #[error("Gateway error: {0}")]
     GatewayError(#[from] GatewayError),
 
+    /// Cumulative token usage crossed `agent.max_cost_tokens`. The run stops
+    /// at the next phase or turn boundary and reports a failed output.
+    #[error("Token limit exceeded: used {used} tokens (limit {limit})")]
+    CostLimitExceeded { used: u64, limit: u64 },
+
     /// Operation cancelled. Carries the in-flight [`AgentState`] snapshot (if
     /// available at the point of cancellation) so callers can inspect/persist
     /// partial progress instead of losing it.
@@ -59,6 +72,24 @@ impl RunAgentError {
     pub fn is_cancelled(&self) -> bool {
         matches!(self, RunAgentError::Cancelled(_))
     }
+
+    /// Check if this error is the `agent.max_cost_tokens` stop
+    pub fn is_cost_limit_exceeded(&self) -> bool {
+        matches!(self, RunAgentError::CostLimitExceeded { .. })
+    }
+
+    /// `--output json` object for a run that ended in this error.
+    ///
+    /// Same shape as [`RunAgentOutput::to_json`]; a cancelled run includes the
+    /// state captured at cancellation, other errors only `success` and `error`.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut json = match self {
+            RunAgentError::Cancelled(Some(state)) => run_json(state, false, ""),
+            _ => serde_json::json!({ "success": false, "summary": "" }),
+        };
+        json["error"] = serde_json::json!(self.to_string());
+        json
+    }
 }
 
 /// Result of the planning phase.
@@ -109,6 +140,14 @@ pub struct RunAgentInput {
     pub policy: AgentPolicy,
     /// Execution loop control parameters
     pub execution: ExecutionParams,
+    /// Context inherited from a parent interaction. When set, the run adopts
+    /// it instead of gathering context itself.
+    pub initial_context: Option<AgentContext>,
+    /// Plan supplied up front (e.g. `--plan-file`). When set, the run skips
+    /// context gathering and planning and goes straight to review.
+    pub preloaded_plan: Option<Plan>,
+    /// Text appended to the gathered context before planning (`--context`).
+    pub user_context: Vec<String>,
 }
 
 impl RunAgentInput {
@@ -125,9 +164,30 @@ impl RunAgentInput {
             models,
             policy,
             execution,
+            initial_context: None,
+            preloaded_plan: None,
+            user_context: Vec::new(),
         }
     }
 
+    /// Start from `context` (e.g. a parent agent's) instead of gathering it.
+    pub fn with_initial_context(mut self, context: AgentContext) -> Self {
+        self.initial_context = Some(context);
+        self
+    }
+
+    /// Execute `plan` instead of asking the planner for one.
+    pub fn with_preloaded_plan(mut self, plan: Plan) -> Self {
+        self.preloaded_plan = Some(plan);
+        self
+    }
+
+    /// Append `texts` to the context the run plans with, without loading any file.
+    pub fn with_user_context(mut self, texts: Vec<String>) -> Self {
+        self.user_context = texts;
+        self
+    }
+
     /// Build an [`AgentState`] from this input, starting in the ContextGathering phase.
     pub fn to_agent_state(&self, id: impl Into<AgentId>) -> AgentState {
         AgentState::new(
@@ -139,6 +199,23 @@ impl RunAgentInput {
             self.execution.max_iterations,
         )
     }
+
+    /// Rebuild the input a persisted [`AgentState`] was created from.
+    ///
+    /// `execution` supplies the parameters that are not part of the state;
+    /// the state's own `max_iterations` takes precedence.
+    pub fn from_agent_state(state: &AgentState, execution: ExecutionParams) -> Self {
+        Self::new(
+            state.request.clone(),
+            state.mode.clone(),
+            state.models.clone(),
+            state.policy.clone(),
+            ExecutionParams {
+                max_iterations: state.max_iterations,
+                ..execution
+            },
+        )
+    }
 }
 
 /// Output from the RunAgent use case
@@ -151,3 +228,98 @@ pub struct RunAgentOutput {
     /// Whether the agent completed successfully
     pub success: bool,
 }
+
+impl RunAgentOutput {
+    /// Tokens used by the whole run, summed over all phases.
+    ///
+    /// Undercounts when [`AgentState::usage_unavailable`] is set, i.e. some
+    /// calls went through a backend that doesn't report usage.
+    pub fn total_usage(&self) -> TokenUsage {
+        self.state.total_usage()
+    }
+
+    /// Call/success/failure counts per tool over all successfully executed tasks.
+    pub fn tool_stats(&self) -> HashMap<String, ToolStat> {
+        self.state.tool_stats.clone()
+    }
+
+    /// Machine-readable form of the run for `--output json`.
+    ///
+    /// ```json
+    /// {
+    ///   "success": true, "summary": "...", "phase": "completed", "error": null,
+    ///   "phases": [{ "phase": "planning", "usage": { "prompt": 0, "completion": 0 } }],
+    ///   "usage": { "prompt": 0, "completion": 0, "available": false },
+    ///   "plan": { "objective": "...", "tasks": [{ "id": "1", "description": "...", "status": "completed" }] },
+    ///   "tools": { "read_file": { "calls": 2, "successes": 2, "failures": 0 } },
+    ///   "thoughts": [{ "type": "observation", "content": "..." }]
+    /// }
+    /// ```
+    ///
+    /// `plan` is `null` when no plan was created; `error` carries the
+    /// failure reason when the run did not succeed.
+    pub fn to_json(&self) -> serde_json::Value {
+        run_json(&self.state, self.success, &self.summary)
+    }
+}
+
+/// Phases in run order, for listing per-phase usage.
+const PHASE_ORDER: [AgentPhase; 6] = [
+    AgentPhase::ContextGathering,
+    AgentPhase::Planning,
+    AgentPhase::PlanReview,
+    AgentPhase::Executing,
+    AgentPhase::ActionReview,
+    AgentPhase::FinalReview,
+];
+
+fn run_json(state: &AgentState, success: bool, summary: &str) -> serde_json::Value {
+    let phases: Vec<serde_json::Value> = PHASE_ORDER
+        .iter()
+        .filter_map(|phase| {
+            let usage = state.token_usage.get(phase)?;
+            Some(serde_json::json!({ "phase": phase.as_str(), "usage": usage }))
+        })
+        .collect();
+    let total = state.total_usage();
+    let plan = state.plan.as_ref().map(|plan| {
+        let tasks: Vec<serde_json::Value> = plan
+            .tasks
+            .iter()
+            .map(|task| {
+                serde_json::json!({
+                    "id": task.id.as_str(),
+                    "description": task.description,
+                    "status": task.status.as_str(),
+                })
+            })
+            .collect();
+        serde_json::json!({ "objective": plan.objective, "tasks": tasks })
+    });
+    let thoughts: Vec<serde_json::Value> = state
+        .thoughts
+        .iter()
+        .map(|thought| {
+            serde_json::json!({
+                "type": thought.thought_type.as_str(),
+                "content": thought.content,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "success": success,
+        "summary": summary,
+        "phase": state.phase.as_str(),
+        "error": state.error,
+        "phases": phases,
+        "usage": {
+            "prompt": total.prompt,
+            "completion": total.completion,
+            "available": !state.usage_unavailable,
+        },
+        "plan": plan,
+        "tools": state.tool_stats.iter().collect::<BTreeMap<_, _>>(),
+        "thoughts": thoughts,
+    })
+}
@@ -0,0 +1,269 @@
+//! Token usage and phase timing metering for a single agent run.
+//!
+//! [`RunAgentUseCase::run_to_output`](super::RunAgentUseCase) runs the phases
+//! through a copy of the use case whose gateway is a [`MeteredGateway`], so
+//! every session created during the run — context gathering, planning,
+//! reviews, task execution — reports each LLM call to one [`UsageMeter`].
+//! The meter also times each phase span; every phase boundary logs a
+//! `phase_timing` [`ConversationEvent`].
+//!
+//! ```text
+//! run_to_output
+//!   ├─ metered(meter)        gateway → MeteredGateway(gateway, meter)
+//!   ├─ run_phases            enter_phase() → meter.set_phase() → phase_timing (previous phase)
+//!   │    └─ session.send_*   → meter.record(response.usage)
+//!   ├─ meter.finish_phase()  → phase_timing (last phase)
+//!   └─ meter.merge_into(state)  → AgentState::record_usage per call
+//! ```
+//!
+//! Calls that return plain text (`send`, `send_streaming`) never carry usage
+//! and are recorded as unreported.
+//!
+//! The meter also keeps a running token total for `agent.max_cost_tokens`:
+//! [`check_cost_limit`] is called at phase boundaries, at each execution
+//! round and before each tool-use turn, and returns
+//! [`RunAgentError::CostLimitExceeded`] once the total crosses the limit.
+
+use super::{RunAgentError, RunAgentUseCase};
+use crate::ports::conversation_logger::ConversationEvent;
+use crate::ports::llm_gateway::{
+    GatewayError, LlmGateway, LlmSession, StreamHandle, StreamObserver, ToolResultMessage,
+};
+use async_trait::async_trait;
+use quorum_domain::session::response::{LlmResponse, TokenUsage};
+use quorum_domain::{AgentPhase, AgentState, Model};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Collects per-call usage for one run, attributed to the current phase.
+pub(crate) struct UsageMeter {
+    inner: Mutex<MeterState>,
+    /// `agent.max_cost_tokens`; `None` means no limit.
+    limit: Option<u64>,
+}
+
+struct MeterState {
+    phase: AgentPhase,
+    phase_started: Instant,
+    /// Reported tokens (prompt + completion) across the whole run
+    total_tokens: u64,
+    /// `(phase, usage)` per LLM call since the last merge
+    calls: Vec<(AgentPhase, Option<TokenUsage>)>,
+}
+
+impl UsageMeter {
+    pub(super) fn new(phase: AgentPhase, limit: Option<u64>) -> Self {
+        Self {
+            inner: Mutex::new(MeterState {
+                phase,
+                phase_started: Instant::now(),
+                total_tokens: 0,
+                calls: Vec::new(),
+            }),
+            limit,
+        }
+    }
+
+    /// Attribute subsequent calls to `phase`.
+    ///
+    /// Returns the phase being left and how long it ran, or `None` if
+    /// `phase` is already the current one (its timing keeps running).
+    pub(super) fn set_phase(&self, phase: AgentPhase) -> Option<(AgentPhase, Duration)> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.phase == phase {
+            return None;
+        }
+        let now = Instant::now();
+        let elapsed = now - inner.phase_started;
+        inner.phase_started = now;
+        Some((std::mem::replace(&mut inner.phase, phase), elapsed))
+    }
+
+    /// The current phase and how long it has run (end of the run).
+    pub(super) fn finish_phase(&self) -> (AgentPhase, Duration) {
+        let inner = self.inner.lock().unwrap();
+        (inner.phase.clone(), inner.phase_started.elapsed())
+    }
+
+    fn record(&self, usage: Option<TokenUsage>) {
+        let mut inner = self.inner.lock().unwrap();
+        let phase = inner.phase.clone();
+        inner.total_tokens += usage.as_ref().map_or(0, TokenUsage::total);
+        inner.calls.push((phase, usage));
+    }
+
+    /// `(used, limit)` once the run's token total has crossed the limit.
+    fn exceeded(&self) -> Option<(u64, u64)> {
+        let limit = self.limit?;
+        let used = self.inner.lock().unwrap().total_tokens;
+        (used > limit).then_some((used, limit))
+    }
+
+    /// Move the recorded calls into `state` via [`AgentState::record_usage`].
+    pub(super) fn merge_into(&self, state: &mut AgentState) {
+        let calls = std::mem::take(&mut self.inner.lock().unwrap().calls);
+        for (phase, usage) in calls {
+            state.record_usage(phase, usage);
+        }
+    }
+}
+
+/// Return [`RunAgentError::CostLimitExceeded`] if `meter` is over its
+/// token limit. No meter (or no limit) never fails.
+pub(crate) fn check_cost_limit(meter: &Option<Arc<UsageMeter>>) -> Result<(), RunAgentError> {
+    match meter.as_ref().and_then(|meter| meter.exceeded()) {
+        Some((used, limit)) => Err(RunAgentError::CostLimitExceeded { used, limit }),
+        None => Ok(()),
+    }
+}
+
+impl RunAgentUseCase {
+    /// Copy of this use case whose LLM calls are recorded in `meter`.
+    pub(super) fn metered(&self, meter: Arc<UsageMeter>) -> Self {
+        let mut use_case = self.clone();
+        use_case.gateway = Arc::new(MeteredGateway {
+            inner: self.gateway.clone(),
+            meter: meter.clone(),
+        });
+        use_case.usage_meter = Some(meter);
+        use_case
+    }
+
+    /// Move `state` to `phase`, attributing subsequent LLM usage to it and
+    /// logging how long the previous phase took.
+    ///
+    /// Fails without entering `phase` if the run is over its token limit.
+    pub(super) fn enter_phase(
+        &self,
+        state: &mut AgentState,
+        phase: AgentPhase,
+    ) -> Result<(), RunAgentError> {
+        check_cost_limit(&self.usage_meter)?;
+        if let Some(meter) = &self.usage_meter
+            && let Some((left, elapsed)) = meter.set_phase(phase.clone())
+        {
+            self.log_phase_timing(&left, elapsed);
+        }
+        state.set_phase(phase);
+        Ok(())
+    }
+
+    pub(super) fn log_phase_timing(&self, phase: &AgentPhase, elapsed: Duration) {
+        self.conversation_logger
+            .log(ConversationEvent::phase_timing(
+                phase,
+                elapsed.as_millis() as u64,
+            ));
+    }
+}
+
+/// [`LlmGateway`] decorator whose sessions report usage to a [`UsageMeter`].
+struct MeteredGateway {
+    inner: Arc<dyn LlmGateway>,
+    meter: Arc<UsageMeter>,
+}
+
+impl MeteredGateway {
+    fn wrap(&self, session: Box<dyn LlmSession>) -> Box<dyn LlmSession> {
+        Box::new(MeteredSession {
+            inner: session,
+            meter: self.meter.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl LlmGateway for MeteredGateway {
+    async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
+        Ok(self.wrap(self.inner.create_session(model).await?))
+    }
+
+    async fn create_session_with_system_prompt(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        let session = self
+            .inner
+            .create_session_with_system_prompt(model, system_prompt)
+            .await?;
+        Ok(self.wrap(session))
+    }
+
+    async fn create_text_only_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        let session = self
+            .inner
+            .create_text_only_session(model, system_prompt)
+            .await?;
+        Ok(self.wrap(session))
+    }
+
+    async fn create_streaming_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+        observer: StreamObserver,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        let session = self
+            .inner
+            .create_streaming_session(model, system_prompt, observer)
+            .await?;
+        Ok(self.wrap(session))
+    }
+
+    async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
+        self.inner.available_models().await
+    }
+}
+
+struct MeteredSession {
+    inner: Box<dyn LlmSession>,
+    meter: Arc<UsageMeter>,
+}
+
+impl MeteredSession {
+    fn recorded(&self, response: LlmResponse) -> LlmResponse {
+        self.meter.record(response.usage);
+        response
+    }
+}
+
+#[async_trait]
+impl LlmSession for MeteredSession {
+    fn model(&self) -> &Model {
+        self.inner.model()
+    }
+
+    async fn send(&self, content: &str) -> Result<String, GatewayError> {
+        let text = self.inner.send(content).await?;
+        self.meter.record(None);
+        Ok(text)
+    }
+
+    async fn send_streaming(&self, content: &str) -> Result<StreamHandle, GatewayError> {
+        let handle = self.inner.send_streaming(content).await?;
+        self.meter.record(None);
+        Ok(handle)
+    }
+
+    async fn send_with_tools(
+        &self,
+        content: &str,
+        tools: &[serde_json::Value],
+    ) -> Result<LlmResponse, GatewayError> {
+        let response = self.inner.send_with_tools(content, tools).await?;
+        Ok(self.recorded(response))
+    }
+
+    async fn send_tool_results(
+        &self,
+        results: &[ToolResultMessage],
+    ) -> Result<LlmResponse, GatewayError> {
+        let response = self.inner.send_tool_results(results).await?;
+        Ok(self.recorded(response))
+    }
+}
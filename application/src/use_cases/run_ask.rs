@@ -5,6 +5,11 @@
 //! Unlike [`RunAgentUseCase`](super::run_agent::RunAgentUseCase), Ask has no
 //! planning phase, no HiL review, and only uses [`RiskLevel::Low`] tools.
 //! The `ask` model handles everything.
+//!
+//! When the question turns out to need real work, the model can call the
+//! [`ESCALATE_TOOL`] tool; the use case then returns
+//! [`RunAskOutput::EscalateToAgent`] instead of an answer, and the caller
+//! starts an Agent interaction for the request.
 
 use crate::config::ExecutionParams;
 use crate::ports::agent_progress::AgentProgressNotifier;
@@ -14,9 +19,11 @@ use crate::ports::conversation_logger::{
 use crate::ports::llm_gateway::{GatewayError, LlmGateway, ToolResultMessage};
 use crate::ports::tool_executor::ToolExecutorPort;
 use crate::ports::tool_schema::ToolSchemaPort;
+use crate::use_cases::prompt_overrides::with_system_prompt_overrides;
 use crate::use_cases::tool_helpers::tool_args_preview;
 use quorum_domain::agent::model_config::ModelConfig;
 use quorum_domain::interaction::InteractionResult;
+use quorum_domain::tool::entities::{RiskLevel, ToolDefinition, ToolParameter};
 use quorum_domain::util::truncate_str;
 use std::sync::Arc;
 use thiserror::Error;
@@ -32,6 +39,37 @@ pub enum RunAskError {
     EmptyResponse,
 }
 
+/// Tool the Ask model calls to hand a question over to an Agent.
+pub const ESCALATE_TOOL: &str = "escalate_to_agent";
+
+/// The [`ESCALATE_TOOL`] definition offered alongside the low-risk tools.
+fn escalate_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        ESCALATE_TOOL,
+        "Hand this request to an autonomous agent instead of answering. Call it only when \
+         the user needs changes made (editing files, running commands), not for questions \
+         you can answer by reading.",
+        RiskLevel::Low,
+    )
+    .with_parameter(ToolParameter::new(
+        "request",
+        "The task for the agent, stated as an instruction",
+        true,
+    ))
+}
+
+/// Output of the [`RunAskUseCase`].
+#[derive(Debug, Clone)]
+pub enum RunAskOutput {
+    /// The model answered the question.
+    Answer(InteractionResult),
+    /// The model asked for the question to be carried out by an Agent.
+    EscalateToAgent {
+        /// The task to hand to the Agent.
+        request: String,
+    },
+}
+
 /// Input for the [`RunAskUseCase`].
 ///
 /// Ask uses only the `ask` model from [`ModelConfig`] and limits
@@ -42,8 +80,11 @@ pub struct RunAskInput {
     pub query: String,
     /// Model configuration — only `ask` is used.
     pub models: ModelConfig,
-    /// Execution parameters — `max_tool_turns` limits the tool loop.
+    /// Execution parameters — [`ExecutionParams::ask_max_tool_turns`] limits the tool loop.
     pub execution: ExecutionParams,
+    /// Whether [`ESCALATE_TOOL`] is offered. Off for Asks that already run
+    /// under an escalated Agent, so escalation happens only once.
+    pub allow_escalation: bool,
 }
 
 impl RunAskInput {
@@ -52,8 +93,15 @@ impl RunAskInput {
             query: query.into(),
             models,
             execution,
+            allow_escalation: true,
         }
     }
+
+    /// Do not offer [`ESCALATE_TOOL`]; the model must answer.
+    pub fn without_escalation(mut self) -> Self {
+        self.allow_escalation = false;
+        self
+    }
 }
 
 /// Use case for running an Ask interaction.
@@ -62,7 +110,8 @@ impl RunAskInput {
 /// 1. Create session with the `exploration` model
 /// 2. Send query with low-risk tools via [`send_with_tools()`]
 /// 3. Multi-turn tool loop (low-risk only, parallel execution)
-/// 4. Return [`InteractionResult::AskResult`]
+/// 4. Return [`InteractionResult::AskResult`], or
+///    [`RunAskOutput::EscalateToAgent`] as soon as the model calls [`ESCALATE_TOOL`]
 pub struct RunAskUseCase {
     gateway: Arc<dyn LlmGateway>,
     tool_executor: Arc<dyn ToolExecutorPort>,
@@ -111,19 +160,23 @@ impl RunAskUseCase {
         &self,
         input: RunAskInput,
         progress: &dyn AgentProgressNotifier,
-    ) -> Result<InteractionResult, RunAskError> {
+    ) -> Result<RunAskOutput, RunAskError> {
         info!(
             "Starting Ask interaction: {}",
             truncate_str(&input.query, 100)
         );
 
         // Create session with the ask model
-        let session = self.gateway.create_session(&input.models.ask).await?;
+        let gateway = with_system_prompt_overrides(self.gateway.clone(), &input.models);
+        let session = gateway.create_session(&input.models.ask).await?;
 
         // Build low-risk tools only
-        let tools = self
+        let mut tools = self
             .tool_schema
             .low_risk_tools_schema(self.tool_executor.tool_spec());
+        if input.allow_escalation {
+            tools.push(self.tool_schema.tool_to_schema(&escalate_tool()));
+        }
 
         debug!(
             "Ask: using model {}, {} low-risk tools available",
@@ -145,7 +198,7 @@ impl RunAskUseCase {
         progress.on_llm_stream_end();
 
         // Multi-turn tool loop (low-risk only)
-        let max_turns = input.execution.max_tool_turns;
+        let max_turns = input.execution.ask_max_tool_turns();
         let mut turn_count = 0;
         let mut all_text = Vec::new();
 
@@ -160,9 +213,29 @@ impl RunAskUseCase {
                 break;
             }
 
+            if input.allow_escalation
+                && let Some(request) = tool_calls
+                    .iter()
+                    .find(|call| call.tool_name == ESCALATE_TOOL)
+                    .and_then(|call| call.get_string("request"))
+                    .filter(|request| !request.trim().is_empty())
+            {
+                info!("Ask escalated to Agent: {}", truncate_str(request, 100));
+                self.conversation_logger.log(ConversationEvent::new(
+                    "ask_escalated",
+                    serde_json::json!({
+                        "model": input.models.ask.to_string(),
+                        "request": request,
+                    }),
+                ));
+                return Ok(RunAskOutput::EscalateToAgent {
+                    request: request.trim().to_string(),
+                });
+            }
+
             turn_count += 1;
             if turn_count > max_turns {
-                warn!("Ask tool loop exceeded max_tool_turns ({})", max_turns);
+                warn!("Ask tool loop exceeded ask_max_tool_turns ({})", max_turns);
                 break;
             }
 
@@ -274,7 +347,9 @@ impl RunAskUseCase {
             }),
         ));
 
-        Ok(InteractionResult::AskResult { answer })
+        Ok(RunAskOutput::Answer(InteractionResult::AskResult {
+            answer,
+        }))
     }
 }
 
@@ -458,6 +533,7 @@ mod tests {
             content: vec![ContentBlock::Text(text.to_string())],
             stop_reason: Some(StopReason::EndTurn),
             model: Some("test-model".to_string()),
+            usage: None,
         }
     }
 
@@ -470,6 +546,7 @@ mod tests {
             }],
             stop_reason: Some(StopReason::ToolUse),
             model: Some("test-model".to_string()),
+            usage: None,
         }
     }
 
@@ -491,7 +568,7 @@ mod tests {
         let result = use_case.execute(input, &NoAgentProgress).await.unwrap();
 
         match result {
-            InteractionResult::AskResult { answer } => {
+            RunAskOutput::Answer(InteractionResult::AskResult { answer }) => {
                 assert_eq!(answer, "The answer is 42.");
             }
             _ => panic!("Expected AskResult"),
@@ -518,7 +595,7 @@ mod tests {
         let result = use_case.execute(input, &NoAgentProgress).await.unwrap();
 
         match result {
-            InteractionResult::AskResult { answer } => {
+            RunAskOutput::Answer(InteractionResult::AskResult { answer }) => {
                 assert_eq!(answer, "Based on the file, the answer is X.");
             }
             _ => panic!("Expected AskResult"),
@@ -537,6 +614,7 @@ mod tests {
             ],
             stop_reason: Some(StopReason::ToolUse),
             model: Some("test-model".to_string()),
+            usage: None,
         }
     }
 
@@ -570,7 +648,7 @@ mod tests {
         let result = use_case.execute(input, &NoAgentProgress).await.unwrap();
 
         match result {
-            InteractionResult::AskResult { answer } => {
+            RunAskOutput::Answer(InteractionResult::AskResult { answer }) => {
                 // Should contain only the last text (max_tool_turns=3, so turn 3 is the last)
                 assert!(answer.contains("Still working (3)..."));
                 // Intermediate texts should NOT be included
@@ -580,12 +658,118 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_ask_uses_ask_max_tool_turns_over_max_tool_turns() {
+        let mut responses = vec![text_and_tool_response(
+            "Thinking...",
+            "read_file",
+            "toolu_0",
+        )];
+        for i in 1..15 {
+            responses.push(text_and_tool_response(
+                &format!("Still working ({})...", i),
+                "read_file",
+                &format!("toolu_{}", i),
+            ));
+        }
+
+        let session = MockSession::new(responses);
+        let gateway = Arc::new(MockGateway::new(session));
+        let executor = Arc::new(MockToolExecutor::new());
+        let use_case = RunAskUseCase::new(gateway, executor, mock_tool_schema());
+
+        let execution = ExecutionParams::default()
+            .with_max_tool_turns(10)
+            .with_ask_max_tool_turns(2);
+        let input = RunAskInput::new("Quick question", ModelConfig::default(), execution);
+
+        let result = use_case.execute(input, &NoAgentProgress).await.unwrap();
+
+        match result {
+            RunAskOutput::Answer(InteractionResult::AskResult { answer }) => {
+                // Loop stops after 2 turns even though the model keeps asking
+                assert!(answer.contains("Still working (2)..."));
+                assert!(!answer.contains("Still working (3)..."));
+            }
+            _ => panic!("Expected AskResult"),
+        }
+    }
+
+    fn escalate_response(request: &str) -> LlmResponse {
+        LlmResponse {
+            content: vec![ContentBlock::ToolUse {
+                id: "toolu_esc".to_string(),
+                name: ESCALATE_TOOL.to_string(),
+                input: std::collections::HashMap::from([(
+                    "request".to_string(),
+                    serde_json::json!(request),
+                )]),
+            }],
+            stop_reason: Some(StopReason::ToolUse),
+            model: Some("test-model".to_string()),
+            usage: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ask_escalates_to_agent() {
+        // The model reads a file, then decides the question needs real work
+        let session = MockSession::new(vec![
+            tool_use_response("read_file", "toolu_1"),
+            escalate_response("Rename `foo` to `bar` across the crate"),
+        ]);
+        let gateway = Arc::new(MockGateway::new(session));
+        let executor = Arc::new(MockToolExecutor::new());
+        let use_case = RunAskUseCase::new(gateway, executor, mock_tool_schema());
+
+        let input = RunAskInput::new(
+            "Can you rename foo to bar?",
+            ModelConfig::default(),
+            ExecutionParams::default(),
+        );
+
+        match use_case.execute(input, &NoAgentProgress).await.unwrap() {
+            RunAskOutput::EscalateToAgent { request } => {
+                assert_eq!(request, "Rename `foo` to `bar` across the crate");
+            }
+            other => panic!("Expected EscalateToAgent, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ask_without_escalation_answers() {
+        // Escalation already happened upstream: the call is not honored and
+        // the model's next answer is returned
+        let session = MockSession::new(vec![
+            escalate_response("Rename foo"),
+            text_response("I can't make changes here; rename it with your editor."),
+        ]);
+        let gateway = Arc::new(MockGateway::new(session));
+        let executor = Arc::new(MockToolExecutor::new());
+        let use_case = RunAskUseCase::new(gateway, executor, mock_tool_schema());
+
+        let input = RunAskInput::new(
+            "Can you rename foo to bar?",
+            ModelConfig::default(),
+            ExecutionParams::default(),
+        )
+        .without_escalation();
+
+        match use_case.execute(input, &NoAgentProgress).await.unwrap() {
+            RunAskOutput::Answer(InteractionResult::AskResult { answer }) => {
+                assert!(answer.starts_with("I can't make changes here"));
+            }
+            other => panic!("Expected AskResult, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_ask_empty_response_is_error() {
         let session = MockSession::new(vec![LlmResponse {
             content: vec![],
             stop_reason: Some(StopReason::EndTurn),
             model: None,
+            usage: None,
         }]);
         let gateway = Arc::new(MockGateway::new(session));
         let executor = Arc::new(MockToolExecutor::new());
@@ -8,6 +8,7 @@
 //!   → Round 1: proponent opens, opponent attacks
 //!   → Round 2..max_rounds: proponent defends, opponent attacks, interjectors weigh in
 //!     (each round ends with a moderator checkpoint — may settle early)
+//!     (a round in which no participant's position moved is treated as the final one)
 //!   → Moderator's settling checkpoint IS the synthesis
 //! ```
 //!
@@ -34,6 +35,7 @@ use quorum_domain::{
     Phase, QuorumResult, QuorumResultPayload, QuorumTopic, SynthesisResult, Vote, VoteResult,
     VoteVerdict,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{info, warn};
 
@@ -239,7 +241,7 @@ impl DebateStrategyExecutor {
                 );
                 Ok(HumanDecision::Approve)
             }
-            HilMode::Interactive => {
+            HilMode::Interactive | HilMode::BatchReview => {
                 if let Some(intervention) = human_intervention {
                     intervention
                         .request_debate_escalation(
@@ -249,9 +251,14 @@ impl DebateStrategyExecutor {
                             can_continue,
                         )
                         .await
-                        .map_err(|e| match e {
-                            HumanInterventionError::Cancelled => RunQuorumError::Cancelled,
-                            _ => RunQuorumError::HumanInterventionFailed(e.to_string()),
+                        .or_else(|e| match e {
+                            // Debate has no timeout default of its own: fail-secure
+                            HumanInterventionError::TimedOut { .. } => {
+                                warn!("Debate escalation timed out, rejecting");
+                                Ok(HumanDecision::Reject)
+                            }
+                            HumanInterventionError::Cancelled => Err(RunQuorumError::Cancelled),
+                            _ => Err(RunQuorumError::HumanInterventionFailed(e.to_string())),
                         })
                 } else {
                     warn!(
@@ -372,8 +379,15 @@ impl StrategyExecutor for DebateStrategyExecutor {
         // escalation). The `Reject`/`Edit` escalation path returns an `Err`
         // before this point, so it never lingers `false` past the loop.
         let mut settled = false;
+        // Each participant's contribution in the previous round, keyed by
+        // role (interjectors by model), for convergence detection.
+        let mut previous_positions: HashMap<String, String> = HashMap::new();
 
         for round in 1..=max_rounds {
+            let mut positions: HashMap<String, String> = HashMap::new();
+            if round == 1 {
+                positions.insert("proponent".to_string(), transcript[0].1.clone());
+            }
             if round > 1 {
                 let defense_prompt = DebatePromptTemplate::proponent_defense_prompt(
                     question,
@@ -400,6 +414,7 @@ impl StrategyExecutor for DebateStrategyExecutor {
                     proponent.to_string(),
                     defense.clone(),
                 ));
+                positions.insert("proponent".to_string(), defense.clone());
                 transcript.push((format!("Proponent (round {} defense)", round), defense));
             }
 
@@ -429,6 +444,7 @@ impl StrategyExecutor for DebateStrategyExecutor {
             // pushes an intermediate "Proponent (round 1 decomposition)" entry,
             // shifting the opponent's actual attack to a later index.
             let first_round_attack_text = (round == 1).then(|| attack_text.clone());
+            positions.insert("opponent".to_string(), attack_text.clone());
             transcript.push((format!("Opponent (round {} attack)", round), attack_text));
 
             // Anti-mode-collapse divergence check (#316): only once, right after
@@ -541,6 +557,7 @@ impl StrategyExecutor for DebateStrategyExecutor {
                             format!("Round {} proposal", round),
                             text.clone(),
                         ));
+                        positions.insert(interjector.to_string(), text.clone());
                         transcript.push((
                             format!("Interjection ({}, round {})", interjector, round),
                             text,
@@ -558,7 +575,26 @@ impl StrategyExecutor for DebateStrategyExecutor {
                 }
             }
 
-            let is_final_round = round == max_rounds;
+            // Positions have stabilized when every participant who spoke this
+            // round restated its previous-round contribution — further rounds
+            // would only repeat themselves, so this checkpoint settles.
+            let converged = round > 1
+                && round < max_rounds
+                && positions.iter().all(|(who, text)| {
+                    previous_positions
+                        .get(who)
+                        .is_some_and(|previous| config.is_unchanged(previous, text))
+                });
+            if converged {
+                info!(
+                    "Debate positions converged at round {} of {} — settling early",
+                    round, max_rounds
+                );
+                progress.on_debate_converged(round);
+            }
+            previous_positions = positions;
+
+            let is_final_round = round == max_rounds || converged;
             let open_objections: Vec<(&str, &str)> = ledger
                 .open_objections()
                 .iter()
@@ -750,6 +786,7 @@ impl StrategyExecutor for DebateStrategyExecutor {
             total_votes,
             votes,
             aggregated_feedback: None,
+            scores: None,
         };
 
         let payload = QuorumResultPayload::new(QuorumTopic::Debate, None, &vote_result)
@@ -840,6 +877,7 @@ mod tests {
             intensity: DebateIntensity::Strong,
             allow_interjection: false,
             max_rounds,
+            convergence_threshold: 0.9,
         }
     }
 
@@ -998,6 +1036,90 @@ mod tests {
         );
     }
 
+    /// Records `on_debate_converged` rounds; every other callback is a no-op.
+    #[derive(Default)]
+    struct ConvergenceRecorder {
+        rounds: Mutex<Vec<usize>>,
+    }
+
+    impl ProgressNotifier for ConvergenceRecorder {
+        fn on_phase_start(&self, _phase: &Phase, _total_tasks: usize) {}
+        fn on_task_complete(&self, _phase: &Phase, _model: &Model, _success: bool) {}
+        fn on_phase_complete(&self, _phase: &Phase) {}
+        fn on_debate_converged(&self, round: usize) {
+            self.rounds.lock().unwrap().push(round);
+        }
+    }
+
+    /// Scripts a debate whose participants repeat themselves verbatim in
+    /// rounds 1 and 2, with the moderator asking to continue both times.
+    fn repeating_debate_gateway() -> ScriptedGateway {
+        let gateway = ScriptedGateway::new();
+        gateway.respond(Model::Gpt53Codex, "Write-through keeps consistency simple.");
+        gateway.respond(Model::Gemini31Pro, "Write-behind has better write latency.");
+        gateway.respond(
+            Model::ClaudeSonnet45,
+            divergence_response(true, "They disagree on consistency vs. latency tradeoffs."),
+        );
+        gateway.respond(
+            Model::ClaudeSonnet45,
+            verdict_response(false, &[], "Still contested."),
+        );
+        gateway.respond(Model::Gpt53Codex, "Write-through keeps consistency simple.");
+        gateway.respond(Model::Gemini31Pro, "Write-behind has better write latency.");
+        gateway.respond(
+            Model::ClaudeSonnet45,
+            verdict_response(false, &[], "Neither side is moving."),
+        );
+        gateway
+    }
+
+    #[tokio::test]
+    async fn debate_stops_early_when_positions_converge() {
+        let config = debate_config(vec![Model::Gpt53Codex, Model::Gemini31Pro], 5);
+        let input = base_input(OrchestrationStrategy::Debate(config));
+        let progress = ConvergenceRecorder::default();
+
+        // Only two rounds are scripted — a third would fail on an empty queue.
+        let result = DebateStrategyExecutor::new()
+            .execute(
+                &input,
+                Arc::new(repeating_debate_gateway()),
+                &progress,
+                Arc::new(NoEventPublisher),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(*progress.rounds.lock().unwrap(), vec![2]);
+        assert_eq!(result.responses.len(), 2); // opening + round-2 defense
+        assert_eq!(result.reviews.len(), 2); // round-1 + round-2 attacks
+        assert_eq!(result.synthesis.conclusion, "Neither side is moving.");
+    }
+
+    #[tokio::test]
+    async fn debate_convergence_detection_can_be_disabled() {
+        let config = debate_config(vec![Model::Gpt53Codex, Model::Gemini31Pro], 3)
+            .with_convergence_threshold(1.1);
+        let input = base_input(OrchestrationStrategy::Debate(config));
+        let progress = ConvergenceRecorder::default();
+
+        // With detection off, round 3 runs and exhausts the scripted gateway.
+        let result = DebateStrategyExecutor::new()
+            .execute(
+                &input,
+                Arc::new(repeating_debate_gateway()),
+                &progress,
+                Arc::new(NoEventPublisher),
+                None,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(progress.rounds.lock().unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn debate_contrarian_brief_fires_when_divergence_check_finds_no_divergence() {
         // No interjectors — the opponent doubles up as the contrarian.
@@ -1383,6 +1505,7 @@ mod tests {
             intensity: DebateIntensity::Strong,
             allow_interjection: false,
             max_rounds: 1,
+            convergence_threshold: 0.9,
         };
         let models = ModelConfig {
             participants: vec![Model::Gpt53Codex, Model::Gemini31Pro],
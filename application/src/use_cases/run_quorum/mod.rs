@@ -22,6 +22,7 @@ use crate::ports::event_publisher::{EventPublisher, NoEventPublisher};
 use crate::ports::human_intervention::HumanInterventionPort;
 use crate::ports::llm_gateway::LlmGateway;
 use crate::ports::progress::{NoProgress, ProgressNotifier};
+use crate::use_cases::prompt_overrides::with_system_prompt_overrides;
 use quorum_domain::{OrchestrationStrategy, QuorumResult};
 use std::sync::Arc;
 use tracing::info;
@@ -76,7 +77,7 @@ impl RunQuorumUseCase {
             input.models.participants.len()
         );
 
-        let gateway = Arc::clone(&self.gateway);
+        let gateway = with_system_prompt_overrides(Arc::clone(&self.gateway), &input.models);
         let event_publisher = Arc::clone(&self.event_publisher);
         let human_intervention = self.human_intervention.clone();
         match &input.strategy {
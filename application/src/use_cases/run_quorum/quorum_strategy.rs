@@ -13,7 +13,7 @@ use crate::ports::progress::ProgressNotifier;
 use async_trait::async_trait;
 use quorum_domain::{
     Model, ModelResponse, PeerReview, Phase, PromptTemplate, QuorumResult, StreamContext,
-    SynthesisResult,
+    StreamEvent, SynthesisResult,
 };
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -141,7 +141,10 @@ impl QuorumStrategyExecutor {
             }
 
             let gateway = Arc::clone(gateway);
-            let model: Model = response.model.parse().unwrap();
+            let model: Model = response
+                .model
+                .parse()
+                .unwrap_or_else(|_| Model::Custom(response.model.clone()));
             let question = input.question.content().to_string();
 
             progress.on_model_stream_start(&model, &StreamContext::QuorumReview);
@@ -236,12 +239,20 @@ impl QuorumStrategyExecutor {
 
         progress.on_model_stream_start(&moderator, &StreamContext::QuorumSynthesis);
 
-        // Use a channel to relay synthesis chunks to progress in real-time
-        let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+        // Relay synthesis chunks to progress in real-time as `StreamEvent::Delta`s:
+        // each one feeds the moderator's model pane and `on_synthesis_chunk`, so
+        // the conversation can show the answer growing before it completes.
+        let (tx, mut rx) = mpsc::unbounded_channel::<StreamEvent>();
         let moderator_name = moderator.to_string();
         let observer: StreamObserver = Arc::new(move |chunk: &str| {
-            let _ = tx.send(chunk.to_string());
+            let _ = tx.send(StreamEvent::Delta(chunk.to_string()));
         });
+        let relay = |event: StreamEvent| {
+            if let StreamEvent::Delta(chunk) = event {
+                progress.on_model_stream_chunk(&moderator_name, &chunk);
+                progress.on_synthesis_chunk(&chunk);
+            }
+        };
 
         let synthesis_future = Self::synthesize_streaming(
             gateway.as_ref(),
@@ -253,21 +264,21 @@ impl QuorumStrategyExecutor {
         );
         tokio::pin!(synthesis_future);
 
-        let synthesis_content = loop {
+        let synthesis_result = loop {
             tokio::select! {
                 biased;
-                Some(chunk) = rx.recv() => {
-                    progress.on_model_stream_chunk(&moderator_name, &chunk);
-                }
+                Some(event) = rx.recv() => relay(event),
                 result = &mut synthesis_future => {
                     // Drain remaining chunks
-                    while let Ok(chunk) = rx.try_recv() {
-                        progress.on_model_stream_chunk(&moderator_name, &chunk);
+                    while let Ok(event) = rx.try_recv() {
+                        relay(event);
                     }
-                    break result?;
+                    break result;
                 }
             }
         };
+        progress.on_synthesis_end();
+        let synthesis_content = synthesis_result?;
 
         progress.on_model_stream_end(&moderator.to_string());
         progress.on_task_complete(&Phase::Synthesis, &moderator, true);
@@ -482,4 +493,58 @@ mod tests {
 
         assert!(matches!(err, RunQuorumError::AllModelsFailed));
     }
+
+    /// Records synthesis stream callbacks; every other callback is a no-op.
+    #[derive(Default)]
+    struct SynthesisRecorder {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ProgressNotifier for SynthesisRecorder {
+        fn on_phase_start(&self, _phase: &Phase, _total_tasks: usize) {}
+        fn on_task_complete(&self, _phase: &Phase, _model: &Model, _success: bool) {}
+        fn on_phase_complete(&self, _phase: &Phase) {}
+        fn on_synthesis_chunk(&self, chunk: &str) {
+            self.events.lock().unwrap().push(chunk.to_string());
+        }
+        fn on_synthesis_end(&self) {
+            self.events.lock().unwrap().push("<end>".to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn quorum_flow_streams_synthesis_deltas_in_order() {
+        let models = ModelConfig::default()
+            .with_participants(vec![Model::ClaudeSonnet45, Model::Gpt53Codex])
+            .with_moderator(Model::ClaudeSonnet45);
+
+        let gateway = ScriptedGateway::new();
+        gateway.respond(Model::ClaudeSonnet45, "Use a write-through cache.");
+        gateway.respond(Model::Gpt53Codex, "Use a write-behind cache.");
+        gateway.respond_in_chunks(
+            Model::ClaudeSonnet45,
+            &["Final synthesis: ", "use ", "write-through."],
+        );
+        let progress = SynthesisRecorder::default();
+
+        let result = QuorumStrategyExecutor::new()
+            .execute(
+                &input(models).without_review(),
+                Arc::new(gateway),
+                &progress,
+                Arc::new(NoEventPublisher),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *progress.events.lock().unwrap(),
+            vec!["Final synthesis: ", "use ", "write-through.", "<end>"]
+        );
+        assert_eq!(
+            result.synthesis.conclusion,
+            "Final synthesis: use write-through."
+        );
+    }
 }
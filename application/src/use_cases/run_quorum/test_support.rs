@@ -1,6 +1,6 @@
 //! Shared test double for `run_quorum` strategy executor tests.
 
-use crate::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession};
+use crate::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession, StreamObserver};
 use async_trait::async_trait;
 use quorum_domain::Model;
 use std::collections::{HashMap, VecDeque};
@@ -9,12 +9,13 @@ use std::sync::{Arc, Mutex};
 /// A gateway that returns pre-scripted text responses per model, in FIFO order.
 ///
 /// Each `create_session[_with_system_prompt]` call yields a fresh session; each
-/// session's `send()` pops the next queued response for that model. Returns a
+/// session's `send()` pops the next queued response for that model (streaming
+/// sessions also hand each scripted chunk to their observer). Returns a
 /// `GatewayError` (not a panic) when a model's queue is exhausted, so tests can
 /// also exercise the failure path (e.g. "no response scripted" ~= "model failed").
 #[derive(Default)]
 pub(super) struct ScriptedGateway {
-    responses: Arc<Mutex<HashMap<Model, VecDeque<String>>>>,
+    responses: Arc<Mutex<HashMap<Model, VecDeque<Vec<String>>>>>,
     /// Every prompt passed to `send()`, in call order, regardless of model —
     /// lets tests assert on the exact content a caller constructed.
     sent: Arc<Mutex<Vec<(Model, String)>>>,
@@ -27,12 +28,19 @@ impl ScriptedGateway {
 
     /// Queue a response for `model`, returned on its next `send()` call.
     pub(super) fn respond(&self, model: Model, text: impl Into<String>) {
+        let text: String = text.into();
+        self.respond_in_chunks(model, &[&text]);
+    }
+
+    /// Queue a response for `model` that a streaming session delivers to its
+    /// observer one chunk at a time; `send()` returns the chunks joined.
+    pub(super) fn respond_in_chunks(&self, model: Model, chunks: &[&str]) {
         self.responses
             .lock()
             .unwrap()
             .entry(model)
             .or_default()
-            .push_back(text.into());
+            .push_back(chunks.iter().map(|c| c.to_string()).collect());
     }
 
     /// All prompts sent to `model` via `send()`, in call order.
@@ -49,8 +57,9 @@ impl ScriptedGateway {
 
 struct ScriptedSession {
     model: Model,
-    responses: Arc<Mutex<HashMap<Model, VecDeque<String>>>>,
+    responses: Arc<Mutex<HashMap<Model, VecDeque<Vec<String>>>>>,
     sent: Arc<Mutex<Vec<(Model, String)>>>,
+    observer: Option<StreamObserver>,
 }
 
 #[async_trait]
@@ -64,14 +73,21 @@ impl LlmSession for ScriptedSession {
             .lock()
             .unwrap()
             .push((self.model.clone(), content.to_string()));
-        self.responses
+        let chunks = self
+            .responses
             .lock()
             .unwrap()
             .get_mut(&self.model)
             .and_then(|q| q.pop_front())
             .ok_or_else(|| {
                 GatewayError::Other(format!("no scripted response left for {}", self.model))
-            })
+            })?;
+        if let Some(observer) = &self.observer {
+            for chunk in &chunks {
+                observer(chunk);
+            }
+        }
+        Ok(chunks.concat())
     }
 }
 
@@ -82,6 +98,7 @@ impl LlmGateway for ScriptedGateway {
             model: model.clone(),
             responses: Arc::clone(&self.responses),
             sent: Arc::clone(&self.sent),
+            observer: None,
         }))
     }
 
@@ -93,6 +110,20 @@ impl LlmGateway for ScriptedGateway {
         self.create_session(model).await
     }
 
+    async fn create_streaming_session(
+        &self,
+        model: &Model,
+        _system_prompt: &str,
+        observer: StreamObserver,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        Ok(Box::new(ScriptedSession {
+            model: model.clone(),
+            responses: Arc::clone(&self.responses),
+            sent: Arc::clone(&self.sent),
+            observer: Some(observer),
+        }))
+    }
+
     async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
         Ok(vec![])
     }
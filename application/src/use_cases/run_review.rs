@@ -11,6 +11,7 @@ use super::run_agent::review::query_model_for_review;
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::ports::event_publisher::{AppEvent, EventPublisher, NoEventPublisher};
 use crate::ports::llm_gateway::{GatewayError, LlmGateway};
+use crate::use_cases::prompt_overrides::with_system_prompt_overrides;
 use quorum_domain::quorum::{QuorumResultPayload, QuorumTopic, Vote, VoteResult};
 use quorum_domain::{ModelConfig, ReviewPromptTemplate, SynthesisResult};
 use std::sync::Arc;
@@ -103,9 +104,10 @@ impl RunReviewUseCase {
 
         let prompt = ReviewPromptTemplate::review_prompt(&input.material);
 
+        let gateway = with_system_prompt_overrides(Arc::clone(&self.gateway), &input.models);
         let mut join_set = JoinSet::new();
         for model in models {
-            let gateway = Arc::clone(&self.gateway);
+            let gateway = Arc::clone(&gateway);
             let model = model.clone();
             let prompt = prompt.clone();
 
@@ -174,8 +176,7 @@ impl RunReviewUseCase {
         let moderator = input.models.moderator.clone();
         let synthesis_prompt =
             ReviewPromptTemplate::synthesis_prompt(&input.material, &vote_result.votes);
-        let session = self
-            .gateway
+        let session = gateway
             .create_text_only_session(&moderator, ReviewPromptTemplate::synthesis_system())
             .await?;
         let synthesis_content = session.send(&synthesis_prompt).await?;
@@ -3,12 +3,15 @@
 //! Contains cancellation checking and cancellable LLM interaction helpers
 //! used across multiple use cases (GatherContext, ExecuteTask, RunAgent).
 
+use crate::config::RetryPolicy;
 use crate::ports::agent_progress::AgentProgressNotifier;
 use crate::use_cases::run_agent::RunAgentError;
 use quorum_domain::session::response::LlmResponse;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
+use tracing::warn;
 
-use crate::ports::llm_gateway::LlmSession;
+use crate::ports::llm_gateway::{GatewayError, LlmSession};
 
 /// Check if cancellation has been requested.
 ///
@@ -27,21 +30,29 @@ pub(crate) fn check_cancelled(token: &Option<CancellationToken>) -> Result<(), R
 /// Send a prompt with tools to the LLM with cancellation support (Native Tool Use path).
 ///
 /// Returns the full `LlmResponse` with structured content blocks.
-/// Checks for cancellation before sending and forwards text content to progress.
+/// Checks for cancellation before sending, retries transient failures per
+/// `retry_policy`, and forwards text content to progress.
 pub(crate) async fn send_with_tools_cancellable(
     session: &dyn LlmSession,
     prompt: &str,
     tools: &[serde_json::Value],
     progress: &dyn AgentProgressNotifier,
     cancellation_token: &Option<CancellationToken>,
+    retry_policy: &RetryPolicy,
 ) -> Result<LlmResponse, RunAgentError> {
     check_cancelled(cancellation_token)?;
     progress.on_llm_stream_start("native_tool_use");
 
-    let response = session
-        .send_with_tools(prompt, tools)
-        .await
-        .map_err(RunAgentError::GatewayError)?;
+    let response =
+        match send_with_tools_retrying(session, prompt, tools, cancellation_token, retry_policy)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                check_cancelled(cancellation_token)?;
+                return Err(RunAgentError::GatewayError(e));
+            }
+        };
 
     // Forward any text content to progress
     let text = response.text_content();
@@ -52,3 +63,61 @@ pub(crate) async fn send_with_tools_cancellable(
     progress.on_llm_stream_end();
     Ok(response)
 }
+
+/// Send a prompt with tools, retrying retryable errors with exponential backoff.
+///
+/// Non-retryable errors (see [`GatewayError::is_retryable`]) are returned
+/// immediately. A rate limit's `retry_after` is honoured when it is longer
+/// than the backoff. If the token is cancelled while waiting between attempts,
+/// the last error is returned; callers map it to `Cancelled` via
+/// [`check_cancelled`].
+pub(crate) async fn send_with_tools_retrying(
+    session: &dyn LlmSession,
+    prompt: &str,
+    tools: &[serde_json::Value],
+    cancellation_token: &Option<CancellationToken>,
+    retry_policy: &RetryPolicy,
+) -> Result<LlmResponse, GatewayError> {
+    let mut attempt = 1;
+    loop {
+        match session.send_with_tools(prompt, tools).await {
+            Ok(response) => return Ok(response),
+            Err(e) if e.is_retryable() && retry_policy.should_retry(attempt) => {
+                let backoff = retry_policy.backoff(attempt);
+                let delay = e.retry_after().map_or(backoff, |wait| wait.max(backoff));
+                warn!(
+                    "{}: attempt {}/{} failed ({}), retrying in {:?}",
+                    session.model(),
+                    attempt,
+                    retry_policy.max_attempts,
+                    e,
+                    delay
+                );
+                if !sleep_cancellable(delay, cancellation_token).await {
+                    return Err(e);
+                }
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Sleep for `delay`, waking early on cancellation.
+///
+/// Returns `false` if the token was cancelled before the delay elapsed.
+async fn sleep_cancellable(
+    delay: Duration,
+    cancellation_token: &Option<CancellationToken>,
+) -> bool {
+    match cancellation_token {
+        Some(token) => tokio::select! {
+            _ = token.cancelled() => false,
+            _ = tokio::time::sleep(delay) => true,
+        },
+        None => {
+            tokio::time::sleep(delay).await;
+            true
+        }
+    }
+}
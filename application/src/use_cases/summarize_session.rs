@@ -0,0 +1,175 @@
+//! Session transcript summarization (`:summarize`).
+//!
+//! Feeds the conversation shown in a tab to the decision model and returns a
+//! short TL;DR. The transcript is trimmed to the configured
+//! [`ContextBudget`] with [`trim_to_budget`] before the prompt is built, so a
+//! long session cannot overflow the model's context.
+
+use crate::ports::llm_gateway::{GatewayError, LlmGateway};
+use quorum_domain::session::entities::{Message, Role};
+use quorum_domain::session::trim::trim_to_budget;
+use quorum_domain::util::truncate_str;
+use quorum_domain::{ContextBudget, Model};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+
+/// Errors that can occur while summarizing a session.
+#[derive(Error, Debug)]
+pub enum SummarizeSessionError {
+    /// The transcript has no user or assistant messages.
+    #[error("Nothing to summarize yet")]
+    Empty,
+
+    #[error("Summary cancelled")]
+    Cancelled,
+
+    #[error("Gateway error: {0}")]
+    GatewayError(#[from] GatewayError),
+
+    #[error("No response from model")]
+    EmptyResponse,
+}
+
+/// Summarizes a session transcript with a single model call.
+#[derive(Clone)]
+pub struct SummarizeSessionUseCase {
+    gateway: Arc<dyn LlmGateway>,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl SummarizeSessionUseCase {
+    pub fn new(gateway: Arc<dyn LlmGateway>) -> Self {
+        Self {
+            gateway,
+            cancellation_token: None,
+        }
+    }
+
+    /// Stop waiting for the model as soon as `token` is cancelled.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Summarize `messages` with `model`, trimming them to `budget` first.
+    pub async fn execute(
+        &self,
+        messages: &[Message],
+        model: &Model,
+        budget: &ContextBudget,
+    ) -> Result<String, SummarizeSessionError> {
+        let prompt = build_summary_prompt(messages, budget).ok_or(SummarizeSessionError::Empty)?;
+
+        let request = async {
+            let session = self.gateway.create_session(model).await?;
+            session.send(&prompt).await
+        };
+        let response = match &self.cancellation_token {
+            Some(token) => tokio::select! {
+                biased;
+                _ = token.cancelled() => return Err(SummarizeSessionError::Cancelled),
+                response = request => response?,
+            },
+            None => request.await?,
+        };
+
+        let summary = response.trim();
+        if summary.is_empty() {
+            return Err(SummarizeSessionError::EmptyResponse);
+        }
+        Ok(summary.to_string())
+    }
+}
+
+/// Build the summarization prompt for `messages`.
+///
+/// System messages are left out (they are UI notices, not conversation).
+/// The rest is trimmed to `budget` — the dropped middle becomes a digest —
+/// and each message is capped at
+/// [`max_entry_bytes`](ContextBudget::max_entry_bytes). Returns `None` when
+/// there is nothing to summarize.
+pub fn build_summary_prompt(messages: &[Message], budget: &ContextBudget) -> Option<String> {
+    let conversation: Vec<Message> = messages
+        .iter()
+        .filter(|m| m.role != Role::System && !m.content.trim().is_empty())
+        .cloned()
+        .collect();
+    if conversation.is_empty() {
+        return None;
+    }
+
+    let mut prompt = String::from(
+        "Summarize this conversation as a short TL;DR: a few bullet points covering \
+         what was asked, what was decided or changed, and what is still open. \
+         Drop pleasantries.\n\n## Conversation\n",
+    );
+    for message in trim_to_budget(&conversation, budget) {
+        let role = match message.role {
+            Role::User => "User",
+            Role::Assistant => "Assistant",
+            Role::System => "Earlier",
+        };
+        prompt.push_str(&format!(
+            "**{}**: {}\n",
+            role,
+            truncate_str(&message.content, budget.max_entry_bytes())
+        ));
+    }
+    Some(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_includes_conversation_and_skips_system_notices() {
+        let messages = vec![
+            Message::system("Welcome! Model: claude-sonnet-4.5"),
+            Message::user("Add a --dry-run flag"),
+            Message::assistant("Added --dry-run to the CLI and wired it to the executor."),
+            Message::system("Mode changed: solo"),
+            Message::user("Does it cover high-risk tools?"),
+            Message::assistant("Yes, high-risk calls return a synthetic success."),
+        ];
+
+        let prompt = build_summary_prompt(&messages, &ContextBudget::default()).unwrap();
+
+        assert!(prompt.contains("**User**: Add a --dry-run flag"));
+        assert!(prompt.contains("**Assistant**: Yes, high-risk calls return a synthetic success."));
+        assert!(!prompt.contains("Welcome!"));
+        assert!(!prompt.contains("Mode changed"));
+        let first = prompt.find("Add a --dry-run flag").unwrap();
+        let last = prompt.find("Does it cover high-risk tools?").unwrap();
+        assert!(first < last);
+    }
+
+    #[test]
+    fn prompt_respects_context_budget() {
+        let mut messages = vec![Message::user("original request")];
+        for i in 0..10 {
+            messages.push(Message::user(format!("question {} {}", i, "q".repeat(100))));
+            messages.push(Message::assistant(format!(
+                "answer {} {}",
+                i,
+                "a".repeat(100)
+            )));
+        }
+        let budget = ContextBudget::new(50, 600, 2);
+
+        let prompt = build_summary_prompt(&messages, &budget).unwrap();
+
+        assert!(prompt.contains("original request"));
+        assert!(prompt.contains("question 9"));
+        assert!(!prompt.contains(&"a".repeat(100)));
+        assert!(prompt.contains("**Earlier**"));
+    }
+
+    #[test]
+    fn empty_conversation_has_nothing_to_summarize() {
+        let messages = vec![Message::system("Welcome!"), Message::user("   ")];
+        assert!(build_summary_prompt(&messages, &ContextBudget::default()).is_none());
+        assert!(build_summary_prompt(&[], &ContextBudget::default()).is_none());
+    }
+}
@@ -1,6 +1,9 @@
 //! Shared helpers for tool use cases.
 
-use quorum_domain::tool::entities::ToolCall;
+use crate::ports::agent_progress::AgentProgressNotifier;
+use quorum_domain::AgentPolicy;
+use quorum_domain::tool::entities::{ToolCall, ToolSpec};
+use quorum_domain::tool::value_objects::ToolError;
 
 /// Extract a short preview string from tool call arguments.
 ///
@@ -22,6 +25,70 @@ pub(crate) fn tool_args_preview(call: &ToolCall) -> String {
     String::new()
 }
 
+/// The error for a `run_command` call blocked by the policy's command
+/// allow/deny lists, or `None` if it may run (or isn't `run_command`).
+pub(crate) fn command_policy_error(policy: &AgentPolicy, call: &ToolCall) -> Option<ToolError> {
+    if call.tool_name != "run_command" {
+        return None;
+    }
+    let command = call
+        .arguments
+        .get("command")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    policy.check_command(command).err().map(|reason| {
+        ToolError::new("PERMISSION_DENIED", format!("Command blocked: {}", reason))
+            .with_details(command)
+    })
+}
+
+/// Tools whose calls have no side effects, so repeating one in a turn adds
+/// nothing.
+const READ_ONLY_TOOLS: &[&str] = &["read_file", "glob_search", "grep_search"];
+
+/// Drop repeated read-only tool calls — same tool, same arguments — from
+/// one turn.
+///
+/// Returns the calls to execute and `(duplicate_id, original_id)` pairs of
+/// native IDs, so each duplicate can be answered with its original's
+/// result. Calls to other tools always run: running a command or writing a
+/// file twice may be intended. Calls without a native ID are never merged
+/// either: their results could not be matched up.
+pub(crate) fn dedupe_tool_calls(calls: Vec<ToolCall>) -> (Vec<ToolCall>, Vec<(String, String)>) {
+    let mut unique = Vec::with_capacity(calls.len());
+    // (tool name, canonical arguments, native ID) of each executed call
+    let mut seen: Vec<(String, String, String)> = Vec::new();
+    let mut duplicates = Vec::new();
+    for call in calls {
+        let native_id = match &call.native_id {
+            Some(id) if READ_ONLY_TOOLS.contains(&call.tool_name.as_str()) => id.clone(),
+            _ => {
+                unique.push(call);
+                continue;
+            }
+        };
+        // serde_json objects keep keys sorted, so this is canonical
+        let args = serde_json::Value::Object(
+            call.arguments
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect(),
+        )
+        .to_string();
+        match seen
+            .iter()
+            .find(|(name, seen_args, _)| *name == call.tool_name && *seen_args == args)
+        {
+            Some((_, _, original)) => duplicates.push((native_id, original.clone())),
+            None => {
+                seen.push((call.tool_name.clone(), args, native_id));
+                unique.push(call);
+            }
+        }
+    }
+    (unique, duplicates)
+}
+
 fn truncate_preview(s: &str, max_len: usize) -> String {
     if s.chars().count() <= max_len {
         s.to_string()
@@ -31,6 +98,33 @@ fn truncate_preview(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Rewrite hallucinated tool names to their closest registered tool.
+///
+/// Uses [`ToolSpec::resolve_fuzzy`] and reports each correction via
+/// `on_tool_resolved`. Calls with no close match are left as-is — the
+/// executor answers them with `NOT_FOUND` — and reported via
+/// `on_tool_not_found`.
+pub(crate) fn resolve_tool_names(
+    calls: &mut [ToolCall],
+    spec: &ToolSpec,
+    progress: &dyn AgentProgressNotifier,
+) {
+    for call in calls {
+        match spec.resolve_fuzzy(&call.tool_name) {
+            Some((_, false)) => {}
+            Some((resolved, true)) => {
+                progress.on_tool_resolved(&call.tool_name, resolved);
+                call.tool_name = resolved.to_string();
+            }
+            None => {
+                let mut available: Vec<&str> = spec.names().collect();
+                available.sort_unstable();
+                progress.on_tool_not_found(&call.tool_name, &available);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,6 +139,60 @@ mod tests {
         }
     }
 
+    fn native_call(id: &str, tool: &str, args: serde_json::Value) -> ToolCall {
+        ToolCall {
+            tool_name: tool.to_string(),
+            arguments: serde_json::from_value(args).unwrap(),
+            reasoning: None,
+            native_id: Some(id.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_dedupe_tool_calls_ignores_argument_order() {
+        let calls = vec![
+            native_call(
+                "a",
+                "grep_search",
+                serde_json::json!({"pattern": "fn", "path": "src"}),
+            ),
+            native_call(
+                "b",
+                "grep_search",
+                serde_json::json!({"path": "src", "pattern": "fn"}),
+            ),
+            native_call("c", "read_file", serde_json::json!({"path": "src"})),
+        ];
+
+        let (unique, duplicates) = dedupe_tool_calls(calls);
+
+        let ids: Vec<_> = unique.iter().map(|c| c.native_id.as_deref()).collect();
+        assert_eq!(ids, vec![Some("a"), Some("c")]);
+        assert_eq!(duplicates, vec![("b".to_string(), "a".to_string())]);
+    }
+
+    #[test]
+    fn test_dedupe_tool_calls_keeps_repeated_side_effects() {
+        let call = |id| native_call(id, "run_command", serde_json::json!({"command": "make"}));
+
+        let (unique, duplicates) = dedupe_tool_calls(vec![call("a"), call("b")]);
+
+        assert_eq!(unique.len(), 2);
+        assert!(duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_dedupe_tool_calls_keeps_calls_without_native_id() {
+        let mut first = native_call("a", "read_file", serde_json::json!({"path": "x"}));
+        first.native_id = None;
+        let second = first.clone();
+
+        let (unique, duplicates) = dedupe_tool_calls(vec![first, second]);
+
+        assert_eq!(unique.len(), 2);
+        assert!(duplicates.is_empty());
+    }
+
     #[test]
     fn test_path_key_preferred() {
         let mut args = HashMap::new();
@@ -131,4 +279,51 @@ mod tests {
         assert!(result.chars().count() <= 50);
         assert!(result.ends_with('…'));
     }
+
+    /// Records `on_tool_resolved` / `on_tool_not_found` callbacks.
+    #[derive(Default)]
+    struct ResolveProgress {
+        resolved: std::sync::Mutex<Vec<(String, String)>>,
+        not_found: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl AgentProgressNotifier for ResolveProgress {
+        fn on_tool_resolved(&self, original_name: &str, resolved_name: &str) {
+            self.resolved
+                .lock()
+                .unwrap()
+                .push((original_name.to_string(), resolved_name.to_string()));
+        }
+
+        fn on_tool_not_found(&self, tool_name: &str, _available_tools: &[&str]) {
+            self.not_found.lock().unwrap().push(tool_name.to_string());
+        }
+    }
+
+    #[test]
+    fn resolve_tool_names_corrects_near_misses_and_reports_the_rest() {
+        use quorum_domain::tool::entities::{RiskLevel, ToolDefinition};
+        let spec = ToolSpec::new()
+            .register(ToolDefinition::new("read_file", "Read", RiskLevel::Low))
+            .register(ToolDefinition::new("grep_search", "Grep", RiskLevel::Low));
+        let mut calls = vec![
+            ToolCall::new("read_files"),
+            ToolCall::new("grep_search"),
+            ToolCall::new("frobnicate"),
+        ];
+        let progress = ResolveProgress::default();
+
+        resolve_tool_names(&mut calls, &spec, &progress);
+
+        let names: Vec<&str> = calls.iter().map(|c| c.tool_name.as_str()).collect();
+        assert_eq!(names, ["read_file", "grep_search", "frobnicate"]);
+        assert_eq!(
+            progress.resolved.lock().unwrap().as_slice(),
+            &[("read_files".to_string(), "read_file".to_string())]
+        );
+        assert_eq!(
+            progress.not_found.lock().unwrap().as_slice(),
+            &["frobnicate".to_string()]
+        );
+    }
 }
@@ -9,30 +9,38 @@
 
 mod review;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 use quorum_application::LlmGateway;
 #[cfg(feature = "scripting")]
 use quorum_application::ScriptingEnginePort;
 use quorum_application::ToolExecutorPort;
 use quorum_application::{ConfigAccessorPort, ConfigValue};
-use quorum_application::{QuorumConfig, RunAgentUseCase};
-use quorum_domain::ConsensusLevel;
+use quorum_application::{
+    ExecutionParams, NoAgentProgress, PhaseReplay, QuorumConfig, RunAgentError, RunAgentUseCase,
+};
 use quorum_domain::OutputFormat;
+use quorum_domain::interaction::InteractionForm;
+use quorum_domain::{
+    AgentPhase, AgentState, ConsensusLevel, ContextFileLimits, ModelConfig, Plan,
+    ReferenceProvider, parse_plan_json,
+};
 #[cfg(feature = "bedrock")]
 use quorum_infrastructure::BedrockProviderAdapter;
 use quorum_infrastructure::{
-    ArboardClipboard, CopilotLlmGateway, CopilotProviderAdapter, FallbackClipboard,
-    GitHubReferenceResolver, JsonSchemaToolConverter, JsonlConversationLogger, LocalContextLoader,
-    LocalToolExecutor, Osc52Clipboard,
+    ArboardClipboard, CachingReferenceResolver, CopilotLlmGateway, CopilotProviderAdapter,
+    DecisionLog, DryRunToolExecutor, FallbackClipboard, JsonSchemaToolConverter,
+    JsonlConversationLogger, LocalContextLoader, LocalFileReferenceResolver, LocalToolExecutor,
+    Osc52Clipboard, RedactingConversationLogger, Redactor, RoutingReferenceResolver,
 };
 use quorum_infrastructure::{ProviderAdapter, RoutingGateway};
 use quorum_presentation::{
     AgentProgressReporter, Cli, Command, InteractiveHumanIntervention, LayoutPreset, OutputConfig,
-    ReplConfig, TuiApp, TuiInputConfig, TuiLayoutConfig, run_rpc,
+    PlanTreePrinter, ReplConfig, TuiApp, TuiInputConfig, TuiLayoutConfig, run_rpc,
 };
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 use tracing_appender::non_blocking::WorkerGuard;
@@ -162,36 +170,44 @@ fn init_logging(
     }
 }
 
+/// Parse a `--model` value. On failure the error names the closest built-in
+/// model and lists all of them.
+fn parse_cli_model(models: &ModelConfig, name: &str) -> Result<quorum_domain::Model> {
+    models.resolve(name).map_err(|e| {
+        let known: Vec<&str> = quorum_domain::Model::all()
+            .iter()
+            .map(|m| m.as_str())
+            .collect();
+        let aliases: Vec<&str> = quorum_domain::Model::builtin_aliases()
+            .iter()
+            .map(|(alias, _)| *alias)
+            .chain(models.aliases.keys().map(String::as_str))
+            .collect();
+        anyhow::anyhow!(
+            "invalid --model value: {}\nKnown models: {}\nAliases: {}",
+            e,
+            known.join(", "),
+            aliases.join(", ")
+        )
+    })
+}
+
 /// Apply CLI argument overrides on top of Lua-configured QuorumConfig.
 ///
-/// CLI flags take precedence over init.lua settings.
-fn apply_cli_overrides(config: &mut QuorumConfig, cli: &Cli) {
+/// CLI flags take precedence over init.lua settings. Fails on a `--model`
+/// value that looks like a mistyped built-in model.
+fn apply_cli_overrides(config: &mut QuorumConfig, cli: &Cli) -> Result<()> {
     if cli.ensemble {
         config.mode_mut().consensus_level = ConsensusLevel::Ensemble;
     }
-    match cli.model.len() {
-        0 => {}
-        1 => {
-            let model = cli.model[0]
-                .parse::<quorum_domain::Model>()
-                .expect("Invalid model format");
-            config.models_mut().decision = model;
-            config.models_mut().review = vec![];
-        }
-        _ => {
-            let decision_model = cli.model[0]
-                .parse::<quorum_domain::Model>()
-                .expect("Invalid model format");
-            let review_models = cli.model[1..]
-                .iter()
-                .map(|m| {
-                    m.parse::<quorum_domain::Model>()
-                        .expect("Invalid model format")
-                })
-                .collect();
-            config.models_mut().decision = decision_model;
-            config.models_mut().review = review_models;
-        }
+    if let Some((decision, review)) = cli.model.split_first() {
+        let decision = parse_cli_model(config.models(), decision)?;
+        let review = review
+            .iter()
+            .map(|m| parse_cli_model(config.models(), m))
+            .collect::<Result<_>>()?;
+        config.models_mut().decision = decision;
+        config.models_mut().review = review;
     }
 
     if cli.no_quorum {
@@ -199,21 +215,48 @@ fn apply_cli_overrides(config: &mut QuorumConfig, cli: &Cli) {
         config.policy_mut().require_plan_review = false;
     }
 
+    if cli.no_context {
+        config.policy_mut().gather_context = false;
+    }
+
     if cli.final_review {
         config.policy_mut().require_final_review = true;
     }
 
+    if cli.safe {
+        let policy = config.policy().clone().with_safe_mode();
+        *config.policy_mut() = policy;
+    }
+
+    if let Some(max) = cli.max_cost_tokens {
+        config.execution_mut().max_cost_tokens = (max > 0).then_some(max);
+    }
+
     if cli.quiet {
         config
             .config_set("repl.show_progress", ConfigValue::Boolean(false))
             .ok();
     }
+    if cli.no_unicode {
+        config
+            .config_set("tui.ascii_mode", ConfigValue::Boolean(true))
+            .ok();
+    }
+    config.pinned_context_files_mut().extend(
+        cli.context_files
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned()),
+    );
+    config
+        .user_context_mut()
+        .extend(cli.context.iter().cloned());
     if let Some(output) = cli.output {
         let format: OutputFormat = output.into();
         config
             .config_set("output.format", ConfigValue::String(format.to_string()))
             .ok();
     }
+    Ok(())
 }
 
 /// Build presentation-layer output and repl configs from QuorumConfig.
@@ -248,12 +291,18 @@ fn build_tui_layout_config_from_quorum(
         .parse::<LayoutPreset>()
         .unwrap_or_default();
 
+    let mut form_presets = std::collections::HashMap::new();
+    if let Ok(discuss) = config.tui_layout_discuss_preset().parse::<LayoutPreset>() {
+        form_presets.insert(InteractionForm::Discuss, discuss);
+    }
+
     let layout = TuiLayoutConfig {
         preset,
         flex_threshold: config.tui_flex_threshold(),
         surface_config: Default::default(),
         route_overrides: Vec::new(),
         strategy_presets: std::collections::HashMap::new(),
+        form_presets,
         custom_presets: std::collections::HashMap::new(),
     };
 
@@ -266,6 +315,74 @@ fn build_tui_layout_config_from_quorum(
     layout
 }
 
+/// Print the `--about` report (features, default provider, external CLIs).
+fn print_about(provider_config: &quorum_domain::ProviderConfig) {
+    let features = quorum_application::BuildFeatures {
+        web_tools: cfg!(feature = "web-tools"),
+        scripting: cfg!(feature = "scripting"),
+        bedrock: cfg!(feature = "bedrock"),
+        // No Azure adapter is built yet; the provider name is only reserved
+        azure: false,
+        gitlab: cfg!(feature = "gitlab"),
+        jira: cfg!(feature = "jira"),
+    };
+    let report = quorum_application::about_report(
+        env!("CARGO_PKG_VERSION"),
+        &features,
+        provider_config,
+        quorum_infrastructure::detect_external_clis(),
+    );
+
+    println!("copilot-quorum {}", report.version);
+    println!();
+    println!("Features:");
+    for feature in &report.features {
+        if feature.enabled {
+            println!("  {:<10} enabled", feature.name);
+        } else {
+            println!(
+                "  {:<10} disabled ({})",
+                feature.name, feature.disabled_effect
+            );
+        }
+    }
+    println!();
+    println!("Default provider: {}", report.default_provider);
+    println!();
+    println!("External CLIs:");
+    let found = |present: bool| if present { "found" } else { "not found" };
+    println!("  {:<10} {}", "copilot", found(report.clis.copilot));
+    println!("  {:<10} {}", "gh", found(report.clis.gh));
+    println!("  {:<10} {}", "glab", found(report.clis.glab));
+    println!("  {:<10} {}", "jira", found(report.clis.jira));
+}
+
+/// Build the reference resolver: `@path` mentions are read from the working
+/// directory, tracker references (`gh`, `glab`, `jira`) go through the
+/// session cache.
+async fn build_reference_resolver(
+    working_dir: Option<String>,
+    cache_ttl: Duration,
+    context_limits: ContextFileLimits,
+) -> Arc<dyn quorum_application::ReferenceResolverPort> {
+    let root = working_dir
+        .as_ref()
+        .map(PathBuf::from)
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_else(|| PathBuf::from("."));
+    let mut routing = RoutingReferenceResolver::new().with_resolver(
+        ReferenceProvider::LocalFile,
+        Arc::new(LocalFileReferenceResolver::new(root).with_limits(context_limits)),
+    );
+    if let Some(trackers) = RoutingReferenceResolver::detect(working_dir).await {
+        routing = routing.with_fallback(Arc::new(CachingReferenceResolver::new(
+            Arc::new(trackers),
+            cache_ttl,
+        )));
+    }
+    Arc::new(routing)
+}
+
 /// Create the scripting engine (init.lua + plugins), returns engine + provider config.
 #[cfg(feature = "scripting")]
 fn create_scripting_engine(
@@ -358,13 +475,16 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Validate --plan-file up front so a bad plan fails before any LLM call
+    let preloaded_plan = cli.plan_file.as_deref().map(load_plan_file).transpose()?;
+
     // 1. Create QuorumConfig with Rust defaults
     let quorum_config = QuorumConfig::default();
     let shared_config = Arc::new(std::sync::Mutex::new(quorum_config));
 
     // Determine TUI mode before logging init. --headless always runs the TUI
     // core (event loop + state), just without a terminal attached.
-    let is_tui = cli.question.is_none() || cli.headless;
+    let is_tui = (cli.question.is_none() && cli.only_phase.is_none()) || cli.headless;
 
     // Initialize logging
     let logging = init_logging(cli.verbose, cli.log_dir.as_deref(), cli.no_log_file, is_tui);
@@ -406,10 +526,20 @@ async fn main() -> Result<()> {
     let scripting_engine: Arc<dyn quorum_application::ScriptingEnginePort> =
         Arc::new(quorum_application::NoScriptingEngine);
 
-    // 3. Apply CLI argument overrides (after Lua, so CLI wins)
+    // 3. Apply --profile, then CLI argument overrides (after Lua, so CLI wins)
     {
         let mut config = shared_config.lock().unwrap();
-        apply_cli_overrides(&mut config, &cli);
+        if let Some(name) = &cli.profile {
+            let issues = quorum_application::apply_profile(
+                &mut *config,
+                &scripting_engine.config_profiles(),
+                name,
+            )?;
+            for issue in &issues {
+                eprintln!("Warning: {}", issue.message);
+            }
+        }
+        apply_cli_overrides(&mut config, &cli)?;
     }
 
     // Supervisor status reporting (Issue #309): infra-agnostic at the
@@ -450,6 +580,26 @@ async fn main() -> Result<()> {
         .unwrap_or(provider_config);
     let custom_tools = scripting_engine.registered_custom_tools();
 
+    // Redact secrets from the conversation and decision logs before anything is written
+    let redactor = {
+        let redact_patterns = shared_config.lock().unwrap().redact_patterns().to_vec();
+        Redactor::new()
+            .with_extra_patterns(&redact_patterns)
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: invalid logging.redact_patterns entry: {}", e);
+                Redactor::new()
+            })
+    };
+    let conversation_logger: Arc<dyn quorum_application::ConversationLogger> = Arc::new(
+        RedactingConversationLogger::new(conversation_logger).with_redactor(redactor.clone()),
+    );
+
+    // --about: report build features, default provider and CLIs, then exit
+    if cli.about {
+        print_about(&provider_config);
+        return Ok(());
+    }
+
     // Resolve working directory before spawning providers — the Copilot CLI
     // process must start in the project directory so its built-in tools
     // resolve relative paths there instead of its session-state dir (#240)
@@ -463,39 +613,88 @@ async fn main() -> Result<()> {
                 .map(|p| p.to_string_lossy().to_string())
         });
 
-    // 5. Build providers
-    let copilot =
-        CopilotLlmGateway::new_with_logger(conversation_logger.clone(), working_dir.as_deref())
-            .await?;
-    #[allow(unused_mut)]
-    let mut providers: Vec<Arc<dyn ProviderAdapter>> =
-        vec![Arc::new(CopilotProviderAdapter::new(copilot))];
+    // 5. Build providers (none for --replay: a replayed log sends nothing)
+    let mut providers: Vec<Arc<dyn ProviderAdapter>> = Vec::new();
+    if cli.replay.is_none() {
+        let copilot =
+            CopilotLlmGateway::new_with_logger(conversation_logger.clone(), working_dir.as_deref())
+                .await?
+                .with_rate_limit(provider_config.copilot.max_rps);
+        providers.push(Arc::new(CopilotProviderAdapter::new(copilot)));
 
-    #[cfg(feature = "bedrock")]
-    {
-        if let Some(bedrock) = BedrockProviderAdapter::try_new(&provider_config.bedrock).await {
-            info!("Bedrock provider registered");
-            providers.push(Arc::new(bedrock));
+        #[cfg(feature = "bedrock")]
+        {
+            if let Some(bedrock) = BedrockProviderAdapter::try_new(&provider_config.bedrock).await {
+                info!("Bedrock provider registered");
+                providers.push(Arc::new(bedrock));
+            }
         }
     }
 
     let gateway: Arc<dyn LlmGateway> = Arc::new(RoutingGateway::new(providers, &provider_config));
 
+    // --check-models: report configured vs available models, then exit
+    if cli.check_models {
+        let models = shared_config.lock().unwrap().models().clone();
+        let report = quorum_application::check_models(gateway.as_ref(), &models).await?;
+        for entry in &report.entries {
+            if entry.available {
+                println!("  ok       {:<12} {}", entry.role, entry.model);
+            } else if let Some(ref suggestion) = entry.suggestion {
+                println!(
+                    "  missing  {:<12} {} (did you mean {}?)",
+                    entry.role, entry.model, suggestion
+                );
+            } else {
+                println!("  missing  {:<12} {}", entry.role, entry.model);
+            }
+        }
+        if let Some(reporter) = &herdr_reporter {
+            reporter.shutdown();
+        }
+        std::process::exit(if report.all_available() { 0 } else { 1 });
+    }
+
     // 6. Build tool executor (custom tools from Lua)
     let mut tool_executor = LocalToolExecutor::new();
     if let Some(ref dir) = working_dir {
         tool_executor = tool_executor.with_working_dir(dir);
     }
+    tool_executor =
+        tool_executor.with_timeouts(shared_config.lock().unwrap().tool_timeouts().clone());
     if !custom_tools.is_empty() {
-        tool_executor = tool_executor.with_custom_tool_defs(&custom_tools);
+        tool_executor = tool_executor
+            .with_custom_tool_defs(&custom_tools)
+            .with_script_engine(scripting_engine.clone());
         info!("Registered {} custom tool(s) from Lua", custom_tools.len());
     }
-    let tool_executor: Arc<dyn ToolExecutorPort> = Arc::new(tool_executor);
+    let mut tool_executor: Arc<dyn ToolExecutorPort> = Arc::new(tool_executor);
+    if cli.dry_run {
+        let risk_overrides = shared_config
+            .lock()
+            .unwrap()
+            .policy()
+            .command_risk_overrides
+            .clone();
+        tool_executor =
+            Arc::new(DryRunToolExecutor::new(tool_executor).with_risk_overrides(risk_overrides));
+        info!("Dry-run mode: high-risk tool calls will be previewed, not executed");
+    }
 
     let tool_schema: Arc<dyn quorum_application::ToolSchemaPort> =
         Arc::new(JsonSchemaToolConverter);
-    let context_loader: Arc<dyn quorum_application::ContextLoaderPort> =
-        Arc::new(LocalContextLoader::new());
+    let (context_limits, pinned_files) = {
+        let config = shared_config.lock().unwrap();
+        (
+            config.context_limits().clone(),
+            config.pinned_context_files().to_vec(),
+        )
+    };
+    let context_loader: Arc<dyn quorum_application::ContextLoaderPort> = Arc::new(
+        LocalContextLoader::new()
+            .with_limits(context_limits)
+            .with_pinned_files(pinned_files),
+    );
 
     // Apply working dir to config
     if let Some(ref dir) = working_dir {
@@ -503,6 +702,18 @@ async fn main() -> Result<()> {
         config.execution_mut().working_dir = Some(dir.clone());
     }
 
+    // Approved plans are remembered in .quorum/decisions.jsonl and fed back
+    // into later planning prompts (`context.decision_log`). A dry run reads
+    // prior decisions but records none.
+    let decision_log: Option<Arc<dyn quorum_application::DecisionLogPort>> =
+        if shared_config.lock().unwrap().decision_log_enabled() {
+            let log =
+                DecisionLog::new(working_dir.as_deref().unwrap_or(".")).with_redactor(redactor);
+            Some(Arc::new(if cli.dry_run { log.read_only() } else { log }))
+        } else {
+            None
+        };
+
     // 7. Branch: subcommand, TUI, or single-request mode.
     //
     // The subcommand check always exits the process, before the `is_tui`
@@ -538,6 +749,20 @@ async fn main() -> Result<()> {
         }
     }
 
+    // Debug: replay a single phase of a saved run (--only-phase), then exit.
+    if let (Some(phase), Some(path)) = (cli.only_phase, cli.load_state.as_deref()) {
+        let use_case = RunAgentUseCase::with_context_loader(
+            gateway,
+            tool_executor,
+            tool_schema,
+            context_loader,
+        )
+        .with_cancellation(cancellation_token.clone())
+        .with_conversation_logger(conversation_logger);
+        let execution = shared_config.lock().unwrap().execution().clone();
+        return run_only_phase(&use_case, phase.into(), path, execution).await;
+    }
+
     if is_tui {
         let (_output_config, _repl_config) = {
             let config = shared_config.lock().unwrap();
@@ -549,6 +774,11 @@ async fn main() -> Result<()> {
             build_tui_input_config(&config)
         };
 
+        let (tui_ascii_mode, tui_intent_routing) = {
+            let config = shared_config.lock().unwrap();
+            (config.tui_ascii_mode(), config.tui_intent_routing())
+        };
+
         let tui_layout_config = {
             let config = shared_config.lock().unwrap();
             build_tui_layout_config_from_quorum(
@@ -558,7 +788,16 @@ async fn main() -> Result<()> {
             )
         };
 
-        let reference_resolver = GitHubReferenceResolver::try_new(working_dir.clone()).await;
+        let (reference_cache_ttl, reference_limits) = {
+            let config = shared_config.lock().unwrap();
+            (
+                config.reference_cache_ttl(),
+                config.context_limits().clone(),
+            )
+        };
+        let reference_resolver =
+            build_reference_resolver(working_dir.clone(), reference_cache_ttl, reference_limits)
+                .await;
 
         // Clipboard adapter for TUI yank/copy.
         //
@@ -593,11 +832,15 @@ async fn main() -> Result<()> {
         )
         .with_tui_config(tui_input_config)
         .with_layout_config(tui_layout_config)
+        .with_ascii_mode(tui_ascii_mode)
+        .with_intent_routing(tui_intent_routing)
         .with_scripting_engine(scripting_engine)
         .with_tui_accessor(tui_accessor)
-        .with_clipboard(clipboard);
-        if let Some(resolver) = reference_resolver {
-            tui_app = tui_app.with_reference_resolver(Arc::new(resolver));
+        .with_clipboard(clipboard)
+        .with_export_dir(resolve_log_dir(cli.log_dir.as_deref()));
+        tui_app = tui_app.with_reference_resolver(reference_resolver);
+        if let Some(log) = decision_log {
+            tui_app = tui_app.with_decision_log(log);
         }
         if let Some(listen_path) = &cli.listen {
             tui_app = tui_app.with_listen(listen_path.clone());
@@ -609,6 +852,11 @@ async fn main() -> Result<()> {
         if let Some(question) = cli.question.take() {
             tui_app = tui_app.with_initial_request(question);
         }
+        if let Some(path) = &cli.replay {
+            let records = quorum_infrastructure::read_conversation_log(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            tui_app = tui_app.with_replay(&records);
+        }
         let run_result = if cli.headless {
             tui_app.run_headless().await
         } else {
@@ -628,10 +876,13 @@ async fn main() -> Result<()> {
     let request = cli.question.take().unwrap();
     let quorum_config = shared_config.lock().unwrap().clone();
 
-    let (_, repl_config) = build_presentation_configs(&quorum_config, &cli);
+    let (output_config, repl_config) = build_presentation_configs(&quorum_config, &cli);
+    // JSON goes to stdout on its own, so the banner and progress stay quiet
+    let json_output = output_config.format == OutputFormat::Json;
+    let show_progress = repl_config.show_progress && !json_output;
 
     // Print header
-    if repl_config.show_progress {
+    if show_progress {
         println!();
         println!("+============================================================+");
         println!("|           Copilot Quorum - Agent Mode                      |");
@@ -656,8 +907,14 @@ async fn main() -> Result<()> {
         println!();
     }
 
-    let human_intervention = Arc::new(InteractiveHumanIntervention::new());
-    let reference_resolver = GitHubReferenceResolver::try_new(working_dir.clone()).await;
+    let human_intervention =
+        Arc::new(InteractiveHumanIntervention::new().with_config(shared_config.clone()));
+    let reference_resolver = build_reference_resolver(
+        working_dir.clone(),
+        quorum_config.reference_cache_ttl(),
+        quorum_config.context_limits().clone(),
+    )
+    .await;
 
     let mut event_subscribers: Vec<Arc<dyn quorum_application::EventPublisher>> = vec![
         Arc::new(quorum_application::ConversationLogEventPublisher::new(
@@ -681,19 +938,28 @@ async fn main() -> Result<()> {
             .with_conversation_logger(conversation_logger)
             .with_event_publisher(event_publisher.clone())
             .with_status_tracker(status_tracker.clone());
-    if let Some(resolver) = reference_resolver {
-        use_case = use_case.with_reference_resolver(Arc::new(resolver));
+    use_case = use_case.with_reference_resolver(reference_resolver);
+    if let Some(log) = decision_log {
+        use_case = use_case.with_decision_log(log);
+    }
+    let mut input = quorum_config.to_agent_input(request);
+    if let Some(plan) = preloaded_plan {
+        input = input.with_preloaded_plan(plan);
     }
-    let input = quorum_config.to_agent_input(request);
 
     let result = {
         // Working for the duration of this single request — the guard drops
         // (and republishes Idle) as soon as this block ends, covering every
         // return path below including cancellation (Issue #309).
         let _working_guard = status_tracker.enter_working(event_publisher.clone());
-        if repl_config.show_progress {
-            let progress = AgentProgressReporter::with_options(cli.verbose > 0, cli.show_votes);
+        if show_progress {
+            let progress = AgentProgressReporter::with_options(cli.verbose > 0, cli.show_votes)
+                .with_explain_plan(cli.explain_plan);
             use_case.execute_with_progress(input, &progress).await
+        } else if cli.explain_plan && !json_output {
+            use_case
+                .execute_with_progress(input, &PlanTreePrinter)
+                .await
         } else {
             use_case.execute(input).await
         }
@@ -706,6 +972,29 @@ async fn main() -> Result<()> {
         reporter.shutdown();
     }
 
+    if let Some(path) = &cli.dump_state {
+        let state = match &result {
+            Ok(output) => Some(&output.state),
+            Err(RunAgentError::Cancelled(Some(state))) => Some(state.as_ref()),
+            Err(_) => None,
+        };
+        if let Some(state) = state {
+            dump_state(path, state)?;
+        }
+    }
+
+    if json_output {
+        let json = match &result {
+            Ok(output) => output.to_json(),
+            Err(e) => e.to_json(),
+        };
+        println!("{}", serde_json::to_string_pretty(&json)?);
+        return match result {
+            Err(e) if !e.is_cancelled() => Err(e.into()),
+            _ => Ok(()),
+        };
+    }
+
     match result {
         Ok(output) => {
             println!();
@@ -727,3 +1016,80 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Read and validate a plan JSON file for `--plan-file`.
+fn load_plan_file(path: &Path) -> Result<Plan> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read plan from {}", path.display()))?;
+    let value: serde_json::Value = serde_json::from_str(&json)
+        .with_context(|| format!("{} is not valid JSON", path.display()))?;
+    let plan = parse_plan_json(&value).with_context(|| {
+        format!(
+            "{} is not a valid plan: expected an \"objective\" string and a non-empty \"tasks\" array whose \"depends_on\" entries name tasks in the plan",
+            path.display()
+        )
+    })?;
+    if let Some(cycle) = plan.dependency_cycle() {
+        let ids: Vec<String> = cycle.iter().map(|id| id.to_string()).collect();
+        anyhow::bail!(
+            "{} has a dependency cycle: {}",
+            path.display(),
+            ids.join(" -> ")
+        );
+    }
+    Ok(plan)
+}
+
+/// Write `state` as pretty-printed JSON for `--dump-state`.
+fn dump_state(path: &Path, state: &AgentState) -> Result<()> {
+    let json = serde_json::to_string_pretty(&state.to_snapshot())?;
+    std::fs::write(path, json)
+        .with_context(|| format!("failed to write state to {}", path.display()))?;
+    eprintln!("Agent state written to {}", path.display());
+    Ok(())
+}
+
+/// Replay one phase of a saved run for `--only-phase` and print its result.
+async fn run_only_phase(
+    use_case: &RunAgentUseCase,
+    phase: AgentPhase,
+    path: &Path,
+    execution: ExecutionParams,
+) -> Result<()> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read state from {}", path.display()))?;
+    let state = AgentState::from_snapshot(serde_json::from_str(&json)?)
+        .with_context(|| format!("{} is not a saved agent state", path.display()))?;
+
+    println!("Replaying {} for: {}", phase.display_name(), state.request);
+    println!();
+    match use_case
+        .replay_phase(&state, phase, execution, &NoAgentProgress)
+        .await?
+    {
+        PhaseReplay::Context(context) => {
+            println!("{}", serde_json::to_string_pretty(&context)?);
+        }
+        PhaseReplay::Plan(plan) => {
+            println!("{}", serde_json::to_string_pretty(&plan)?);
+        }
+        PhaseReplay::TextResponse(text) => println!("{}", text),
+        PhaseReplay::Review(review) => {
+            println!(
+                "Review {} {} ({} approve / {} reject)",
+                if review.passed { "PASSED" } else { "FAILED" },
+                review.vote_summary(),
+                review.approve_count,
+                review.reject_count
+            );
+            for vote in &review.votes {
+                println!("- {} [{:?}]: {}", vote.model, vote.verdict, vote.reasoning);
+            }
+            if let Some(feedback) = &review.aggregated_feedback {
+                println!();
+                println!("Feedback:\n{}", feedback);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,243 @@
+//! High-risk actions held back for [`HilMode::BatchReview`](super::HilMode::BatchReview)
+//!
+//! Under batch review, high-risk tool calls that pass action review are not
+//! executed during the run. They are queued in an [`ActionBatch`] and shown to
+//! the user all at once after the last task; only the approved ones run.
+//!
+//! # Dependent actions
+//!
+//! Tasks keep going after queueing an action, as if it had succeeded, so a
+//! later action may rely on an earlier one. An action depends on every
+//! earlier queued action of the same task and of the tasks its task depends
+//! on, directly or transitively (`Task::depends_on`). When an action does not
+//! run — rejected by the user, skipped, or failed — every later action that
+//! depends on it is skipped too (see [`ActionBatch::blocker`]).
+
+use super::entities::Plan;
+use super::value_objects::TaskId;
+use crate::tool::entities::ToolCall;
+use serde::{Deserialize, Serialize};
+
+/// One high-risk tool call waiting for batch approval.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedAction {
+    /// Position in the batch (0-based, in queue order).
+    pub index: usize,
+    /// Task that issued the call.
+    pub task_id: TaskId,
+    /// The held-back call.
+    pub call: ToolCall,
+    /// Why action review escalated the call to a human (reviewer floor,
+    /// `--safe`); `None` when the reviewers approved it outright.
+    #[serde(default)]
+    pub escalation: Option<String>,
+}
+
+/// What happened to a queued action once the batch was decided.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatchActionOutcome {
+    /// Approved and executed successfully.
+    Executed,
+    /// Approved, but the tool call failed.
+    Failed(String),
+    /// Rejected by the user.
+    Rejected,
+    /// Approved, but not run because the action at this index did not run.
+    Skipped { blocked_by: usize },
+}
+
+impl BatchActionOutcome {
+    /// Whether dependents of this action may still run.
+    pub fn unblocks_dependents(&self) -> bool {
+        matches!(self, BatchActionOutcome::Executed)
+    }
+}
+
+/// High-risk actions queued during one execution phase, in queue order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActionBatch {
+    actions: Vec<QueuedAction>,
+}
+
+impl ActionBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `call` issued by `task_id`. Returns its index.
+    pub fn push(&mut self, task_id: TaskId, call: ToolCall) -> usize {
+        self.push_action(task_id, call, None)
+    }
+
+    /// Queue `call`, which action review escalated to a human for `reason`
+    /// instead of approving it. Returns its index.
+    pub fn push_escalated(
+        &mut self,
+        task_id: TaskId,
+        call: ToolCall,
+        reason: impl Into<String>,
+    ) -> usize {
+        self.push_action(task_id, call, Some(reason.into()))
+    }
+
+    fn push_action(
+        &mut self,
+        task_id: TaskId,
+        call: ToolCall,
+        escalation: Option<String>,
+    ) -> usize {
+        let index = self.actions.len();
+        self.actions.push(QueuedAction {
+            index,
+            task_id,
+            call,
+            escalation,
+        });
+        index
+    }
+
+    pub fn actions(&self) -> &[QueuedAction] {
+        &self.actions
+    }
+
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Whether the action at `later` depends on the earlier action at
+    /// `earlier`: same task, or `later`'s task depends on `earlier`'s task
+    /// through `plan`'s `depends_on` graph.
+    pub fn depends_on(&self, plan: &Plan, later: usize, earlier: usize) -> bool {
+        if earlier >= later {
+            return false;
+        }
+        let (Some(later), Some(earlier)) = (self.actions.get(later), self.actions.get(earlier))
+        else {
+            return false;
+        };
+        later.task_id == earlier.task_id || task_depends_on(plan, &later.task_id, &earlier.task_id)
+    }
+
+    /// The first earlier action that `index` depends on and that did not
+    /// run, given the outcomes decided so far (`outcomes[i]` for `i < index`).
+    pub fn blocker(
+        &self,
+        plan: &Plan,
+        index: usize,
+        outcomes: &[BatchActionOutcome],
+    ) -> Option<usize> {
+        outcomes
+            .iter()
+            .take(index)
+            .enumerate()
+            .find(|(earlier, outcome)| {
+                !outcome.unblocks_dependents() && self.depends_on(plan, index, *earlier)
+            })
+            .map(|(earlier, _)| earlier)
+    }
+}
+
+/// Whether `task` depends on `on`, directly or transitively.
+fn task_depends_on(plan: &Plan, task: &TaskId, on: &TaskId) -> bool {
+    let mut pending = vec![task.clone()];
+    let mut visited: Vec<TaskId> = Vec::new();
+    while let Some(current) = pending.pop() {
+        if visited.contains(&current) {
+            continue;
+        }
+        let Some(found) = plan.tasks.iter().find(|t| t.id == current) else {
+            visited.push(current);
+            continue;
+        };
+        if found.depends_on.contains(on) {
+            return true;
+        }
+        pending.extend(found.depends_on.iter().cloned());
+        visited.push(current);
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::entities::Task;
+
+    fn call(name: &str) -> ToolCall {
+        ToolCall::new(name)
+    }
+
+    /// 1 ← 2 ← 3, and 4 independent.
+    fn chain_plan() -> Plan {
+        Plan::new("objective", "reasoning")
+            .with_task(Task::new("1", "first"))
+            .with_task(Task::new("2", "second").with_dependency("1"))
+            .with_task(Task::new("3", "third").with_dependency("2"))
+            .with_task(Task::new("4", "independent"))
+    }
+
+    #[test]
+    fn test_push_assigns_indices_in_order() {
+        let mut batch = ActionBatch::new();
+        assert!(batch.is_empty());
+        assert_eq!(batch.push(TaskId::new("1"), call("write_file")), 0);
+        assert_eq!(batch.push(TaskId::new("2"), call("run_command")), 1);
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.actions()[1].task_id, TaskId::new("2"));
+        assert_eq!(
+            batch.push_escalated(TaskId::new("3"), call("write_file"), "1 of 2 reviewers"),
+            2
+        );
+        assert_eq!(batch.actions()[0].escalation, None);
+        assert_eq!(
+            batch.actions()[2].escalation.as_deref(),
+            Some("1 of 2 reviewers")
+        );
+    }
+
+    #[test]
+    fn test_depends_on_same_task_and_transitive_tasks() {
+        let plan = chain_plan();
+        let mut batch = ActionBatch::new();
+        batch.push(TaskId::new("1"), call("a"));
+        batch.push(TaskId::new("1"), call("b"));
+        batch.push(TaskId::new("3"), call("c"));
+        batch.push(TaskId::new("4"), call("d"));
+
+        assert!(batch.depends_on(&plan, 1, 0), "same task");
+        assert!(batch.depends_on(&plan, 2, 0), "3 → 2 → 1");
+        assert!(!batch.depends_on(&plan, 3, 0), "4 is independent");
+        assert!(!batch.depends_on(&plan, 0, 1), "never on a later action");
+    }
+
+    #[test]
+    fn test_blocker_cascades_through_skipped_actions() {
+        let plan = chain_plan();
+        let mut batch = ActionBatch::new();
+        batch.push(TaskId::new("1"), call("a"));
+        batch.push(TaskId::new("2"), call("b"));
+        batch.push(TaskId::new("4"), call("c"));
+
+        let mut outcomes = vec![BatchActionOutcome::Rejected];
+        assert_eq!(batch.blocker(&plan, 1, &outcomes), Some(0));
+        outcomes.push(BatchActionOutcome::Skipped { blocked_by: 0 });
+        assert_eq!(batch.blocker(&plan, 2, &outcomes), None);
+    }
+
+    #[test]
+    fn test_failed_action_blocks_dependents() {
+        let plan = chain_plan();
+        let mut batch = ActionBatch::new();
+        batch.push(TaskId::new("1"), call("a"));
+        batch.push(TaskId::new("3"), call("b"));
+
+        let outcomes = vec![BatchActionOutcome::Failed("boom".into())];
+        assert_eq!(batch.blocker(&plan, 1, &outcomes), Some(0));
+        let outcomes = vec![BatchActionOutcome::Executed];
+        assert_eq!(batch.blocker(&plan, 1, &outcomes), None);
+    }
+}
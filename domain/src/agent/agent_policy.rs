@@ -5,8 +5,14 @@
 //! These are domain policies that constrain [`AgentState`](super::entities::AgentState)
 //! transitions.
 
-use super::entities::HilMode;
+use super::entities::{HilMode, HumanDecision};
+use crate::quorum::{ConsensusMode, QuorumRule};
+use crate::tool::entities::{RiskLevel, classify_command_risk_with_overrides};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Reviewer floor for high-risk actions applied by [`AgentPolicy::with_safe_mode`].
+pub const SAFE_MODE_MIN_HIGH_RISK_REVIEWERS: usize = 2;
 
 /// Action determined by HiL policy evaluation.
 ///
@@ -23,6 +29,149 @@ pub enum HilAction {
     ForceApprove,
 }
 
+/// What task execution does after a task fails.
+///
+/// Configured via `agent.on_task_failure`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicy {
+    /// Stop the run; every task not yet started is skipped.
+    Abort,
+    /// Skip the tasks that depend (directly or transitively) on the failed
+    /// task; independent tasks still run.
+    ContinueIndependent,
+    /// Run every remaining task, ignoring failed dependencies (default).
+    #[default]
+    ContinueAll,
+}
+
+impl FailurePolicy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FailurePolicy::Abort => "abort",
+            FailurePolicy::ContinueIndependent => "continue_independent",
+            FailurePolicy::ContinueAll => "continue_all",
+        }
+    }
+}
+
+impl std::str::FromStr for FailurePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "abort" => Ok(FailurePolicy::Abort),
+            "continue_independent" => Ok(FailurePolicy::ContinueIndependent),
+            "continue_all" => Ok(FailurePolicy::ContinueAll),
+            _ => Err(format!(
+                "Invalid failure policy: {} (expected abort, continue_independent, or continue_all)",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for FailurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How ensemble planning turns the voted candidates into one plan.
+///
+/// Configured via `agent.ensemble_selection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnsembleSelection {
+    /// Execute the highest-scoring candidate (default).
+    #[default]
+    Winner,
+    /// Have the moderator merge the top-scoring candidates into one plan,
+    /// falling back to the winner if no merged plan comes back.
+    Merge,
+}
+
+impl EnsembleSelection {
+    pub fn as_str(&self) -> &str {
+        match self {
+            EnsembleSelection::Winner => "winner",
+            EnsembleSelection::Merge => "merge",
+        }
+    }
+}
+
+impl std::str::FromStr for EnsembleSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "winner" => Ok(EnsembleSelection::Winner),
+            "merge" => Ok(EnsembleSelection::Merge),
+            _ => Err(format!(
+                "Invalid ensemble selection: {} (expected winner or merge)",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for EnsembleSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Decision taken when a human intervention prompt times out.
+///
+/// Configured via `agent.hil_timeout_default`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HilTimeoutDefault {
+    /// Treat the unanswered prompt as a rejection (default, fail-secure).
+    #[default]
+    Reject,
+    /// Treat the unanswered prompt as an approval.
+    Approve,
+}
+
+impl HilTimeoutDefault {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HilTimeoutDefault::Reject => "reject",
+            HilTimeoutDefault::Approve => "approve",
+        }
+    }
+
+    /// The [`HumanDecision`] this default stands for.
+    pub fn decision(&self) -> HumanDecision {
+        match self {
+            HilTimeoutDefault::Reject => HumanDecision::Reject,
+            HilTimeoutDefault::Approve => HumanDecision::Approve,
+        }
+    }
+}
+
+impl std::str::FromStr for HilTimeoutDefault {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reject" => Ok(HilTimeoutDefault::Reject),
+            "approve" => Ok(HilTimeoutDefault::Approve),
+            _ => Err(format!(
+                "Invalid HiL timeout default: {} (expected reject or approve)",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for HilTimeoutDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Agent behavioral policy — static domain constraints.
 ///
 /// These settings constrain the agent's state machine transitions and
@@ -34,28 +183,133 @@ pub enum HilAction {
 pub struct AgentPolicy {
     /// Human-in-the-loop mode for handling plan revision limits.
     pub hil_mode: HilMode,
+    /// Seconds a human intervention prompt waits for an answer before
+    /// [`hil_timeout_default`](Self::hil_timeout_default) is taken.
+    /// Batch review applies it to each queued action, not to the whole batch.
+    ///
+    /// `None` waits forever. Default: None.
+    #[serde(default)]
+    pub hil_timeout_secs: Option<u64>,
+    /// Decision taken when a prompt times out.
+    ///
+    /// Default: [`HilTimeoutDefault::Reject`].
+    #[serde(default)]
+    pub hil_timeout_default: HilTimeoutDefault,
+    /// Whether to run Phase 1 (context gathering) before planning.
+    ///
+    /// `false` starts planning with an empty [`AgentContext`](super::value_objects::AgentContext).
+    /// Context inherited from a parent interaction is still used.
+    /// Default: true.
+    #[serde(default = "default_gather_context")]
+    pub gather_context: bool,
     /// Whether to require plan review (always true by design, but explicit).
     pub require_plan_review: bool,
     /// Whether to require final review after execution.
     pub require_final_review: bool,
+    /// How many times a rejected final review may send corrective tasks
+    /// back to execution. `0` only records the concerns.
+    ///
+    /// Default: 1.
+    #[serde(default = "default_max_final_review_fixes")]
+    pub max_final_review_fixes: usize,
     /// Maximum number of plan revisions before HiL triggers.
     pub max_plan_revisions: usize,
+    /// Maximum number of tasks a generated plan may have.
+    ///
+    /// Larger plans are rejected at parse time and the planner is asked to
+    /// consolidate. `None` = no limit. Default: `None`.
+    #[serde(default)]
+    pub max_tasks_per_plan: Option<usize>,
     /// Maximum consecutive action rejections before cascade escalation.
     ///
     /// When the Quorum rejects this many tool actions in a row (across tasks),
     /// the agent escalates based on `hil_mode` (same as plan revision HiL).
     /// Default: 3.
     pub max_action_rejections: usize,
+    /// How ensemble planning picks the plan to execute.
+    ///
+    /// Default: [`EnsembleSelection::Winner`].
+    #[serde(default)]
+    pub ensemble_selection: EnsembleSelection,
+    /// What happens to the remaining tasks when a task fails.
+    ///
+    /// Default: [`FailurePolicy::ContinueAll`].
+    #[serde(default)]
+    pub on_task_failure: FailurePolicy,
+    /// Rule used to aggregate plan and action review votes.
+    ///
+    /// Default: [`QuorumRule::Majority`].
+    #[serde(default)]
+    pub quorum_rule: QuorumRule,
+    /// How plan review votes become a decision: approve/reject counts
+    /// under `quorum_rule`, or the mean reviewer score against a threshold.
+    /// Action reviews always use `quorum_rule`.
+    ///
+    /// Default: [`ConsensusMode::Binary`].
+    #[serde(default)]
+    pub consensus_mode: ConsensusMode,
+    /// Minimum number of distinct reviewers that must vote on an action of
+    /// a given risk level.
+    ///
+    /// An action approved by fewer reviewers (too few configured, or some
+    /// failed to answer) escalates to human intervention instead of running.
+    /// Risk levels without an entry have no floor. Default: empty.
+    #[serde(default)]
+    pub min_reviewers_for_risk: HashMap<RiskLevel, usize>,
+    /// Enforce the reviewer floor even under [`HilMode::AutoApprove`].
+    ///
+    /// Set by `--safe`. Default: false.
+    #[serde(default)]
+    pub strict_reviewer_floor: bool,
+    /// Glob patterns a `run_command` command must match to run (`*` = any
+    /// sequence, `?` = one character). Empty = no restriction.
+    ///
+    /// Checked by [`check_command`](Self::check_command). Default: empty.
+    #[serde(default)]
+    pub command_allowlist: Vec<String>,
+    /// Glob patterns for `run_command` commands that never run, whatever
+    /// the quorum decides. Takes precedence over the allowlist.
+    /// Default: empty.
+    #[serde(default)]
+    pub command_denylist: Vec<String>,
+    /// Risk levels for `run_command` command prefixes, replacing the
+    /// built-in classification (`tools.risk_overrides`).
+    ///
+    /// See [`command_risk`](Self::command_risk). Default: empty.
+    #[serde(default)]
+    pub command_risk_overrides: HashMap<String, RiskLevel>,
+}
+
+fn default_gather_context() -> bool {
+    true
+}
+
+fn default_max_final_review_fixes() -> usize {
+    1
 }
 
 impl Default for AgentPolicy {
     fn default() -> Self {
         Self {
             hil_mode: HilMode::Interactive,
+            hil_timeout_secs: None,
+            hil_timeout_default: HilTimeoutDefault::Reject,
+            gather_context: default_gather_context(),
             require_plan_review: true,
             require_final_review: false,
+            max_final_review_fixes: default_max_final_review_fixes(),
             max_plan_revisions: 3,
+            max_tasks_per_plan: None,
             max_action_rejections: 3,
+            ensemble_selection: EnsembleSelection::Winner,
+            on_task_failure: FailurePolicy::ContinueAll,
+            quorum_rule: QuorumRule::Majority,
+            consensus_mode: ConsensusMode::Binary,
+            min_reviewers_for_risk: HashMap::new(),
+            strict_reviewer_floor: false,
+            command_allowlist: Vec::new(),
+            command_denylist: Vec::new(),
+            command_risk_overrides: HashMap::new(),
         }
     }
 }
@@ -68,6 +322,21 @@ impl AgentPolicy {
         self
     }
 
+    pub fn with_hil_timeout_secs(mut self, secs: Option<u64>) -> Self {
+        self.hil_timeout_secs = secs;
+        self
+    }
+
+    pub fn with_hil_timeout_default(mut self, default: HilTimeoutDefault) -> Self {
+        self.hil_timeout_default = default;
+        self
+    }
+
+    pub fn with_gather_context(mut self, gather: bool) -> Self {
+        self.gather_context = gather;
+        self
+    }
+
     pub fn with_require_plan_review(mut self, require: bool) -> Self {
         self.require_plan_review = require;
         self
@@ -78,16 +347,150 @@ impl AgentPolicy {
         self
     }
 
+    pub fn with_max_final_review_fixes(mut self, max: usize) -> Self {
+        self.max_final_review_fixes = max;
+        self
+    }
+
     pub fn with_max_plan_revisions(mut self, max: usize) -> Self {
         self.max_plan_revisions = max;
         self
     }
 
+    pub fn with_max_tasks_per_plan(mut self, max: Option<usize>) -> Self {
+        self.max_tasks_per_plan = max;
+        self
+    }
+
     pub fn with_max_action_rejections(mut self, max: usize) -> Self {
         self.max_action_rejections = max;
         self
     }
 
+    pub fn with_ensemble_selection(mut self, selection: EnsembleSelection) -> Self {
+        self.ensemble_selection = selection;
+        self
+    }
+
+    pub fn with_on_task_failure(mut self, policy: FailurePolicy) -> Self {
+        self.on_task_failure = policy;
+        self
+    }
+
+    pub fn with_quorum_rule(mut self, rule: QuorumRule) -> Self {
+        self.quorum_rule = rule;
+        self
+    }
+
+    pub fn with_consensus_mode(mut self, mode: ConsensusMode) -> Self {
+        self.consensus_mode = mode;
+        self
+    }
+
+    pub fn with_min_reviewers(mut self, risk: RiskLevel, count: usize) -> Self {
+        self.min_reviewers_for_risk.insert(risk, count);
+        self
+    }
+
+    pub fn with_command_allowlist(mut self, patterns: Vec<String>) -> Self {
+        self.command_allowlist = patterns;
+        self
+    }
+
+    pub fn with_command_denylist(mut self, patterns: Vec<String>) -> Self {
+        self.command_denylist = patterns;
+        self
+    }
+
+    pub fn with_command_risk_override(
+        mut self,
+        prefix: impl Into<String>,
+        risk: RiskLevel,
+    ) -> Self {
+        self.command_risk_overrides.insert(prefix.into(), risk);
+        self
+    }
+
+    /// Require at least [`SAFE_MODE_MIN_HIGH_RISK_REVIEWERS`] reviewers for
+    /// high-risk actions and enforce the floor strictly (`--safe`).
+    ///
+    /// A higher configured floor is kept.
+    pub fn with_safe_mode(mut self) -> Self {
+        let floor = self.min_reviewers(RiskLevel::High);
+        self.min_reviewers_for_risk.insert(
+            RiskLevel::High,
+            floor.max(SAFE_MODE_MIN_HIGH_RISK_REVIEWERS),
+        );
+        self.strict_reviewer_floor = true;
+        self
+    }
+
+    /// How long a human intervention prompt may wait for an answer.
+    pub fn hil_timeout(&self) -> Option<std::time::Duration> {
+        self.hil_timeout_secs.map(std::time::Duration::from_secs)
+    }
+
+    /// Minimum number of reviewers for an action of `risk` (0 = no floor).
+    pub fn min_reviewers(&self, risk: RiskLevel) -> usize {
+        self.min_reviewers_for_risk.get(&risk).copied().unwrap_or(0)
+    }
+
+    /// Whether `reviewers` distinct reviewers satisfy the floor for `risk`.
+    pub fn meets_reviewer_floor(&self, risk: RiskLevel, reviewers: usize) -> bool {
+        reviewers >= self.min_reviewers(risk)
+    }
+
+    /// Determine the action when an action was reviewed by fewer reviewers
+    /// than [`min_reviewers`](Self::min_reviewers) requires.
+    ///
+    /// Never [`HilAction::Continue`]: a thin quorum must not silently pass.
+    /// Under [`strict_reviewer_floor`](Self::strict_reviewer_floor),
+    /// `AutoApprove` aborts instead of force-approving.
+    pub fn reviewer_shortfall_action(&self) -> HilAction {
+        match self.hil_mode {
+            HilMode::Interactive | HilMode::BatchReview => HilAction::RequestIntervention,
+            HilMode::AutoReject => HilAction::Abort,
+            HilMode::AutoApprove if self.strict_reviewer_floor => HilAction::Abort,
+            HilMode::AutoApprove => HilAction::ForceApprove,
+        }
+    }
+
+    /// Risk level of a shell command, honouring
+    /// [`command_risk_overrides`](Self::command_risk_overrides).
+    pub fn command_risk(&self, command: &str) -> RiskLevel {
+        classify_command_risk_with_overrides(command, &self.command_risk_overrides)
+    }
+
+    /// Check a shell command against the command allow/deny lists.
+    ///
+    /// Returns the reason when the command must not run: it matches a
+    /// [`command_denylist`](Self::command_denylist) pattern, or the
+    /// [`command_allowlist`](Self::command_allowlist) is non-empty and no
+    /// pattern matches. Patterns are matched against the whole trimmed
+    /// command, so `rm -rf *` blocks `rm -rf /tmp/x` but not `echo rm -rf`.
+    pub fn check_command(&self, command: &str) -> Result<(), String> {
+        let command = command.trim();
+        if let Some(pattern) = self
+            .command_denylist
+            .iter()
+            .find(|p| glob_match(p, command))
+        {
+            return Err(format!(
+                "command matches agent.command_denylist pattern '{}'",
+                pattern
+            ));
+        }
+        if !self.command_allowlist.is_empty()
+            && !self
+                .command_allowlist
+                .iter()
+                .any(|p| glob_match(p, command))
+        {
+            return Err("command matches no agent.command_allowlist pattern".to_string());
+        }
+        Ok(())
+    }
+
     /// Determine the HiL action given the current plan revision count.
     ///
     /// This encodes the domain rule: "if revision count >= limit, act based on hil_mode".
@@ -96,7 +499,7 @@ impl AgentPolicy {
             return HilAction::Continue;
         }
         match self.hil_mode {
-            HilMode::Interactive => HilAction::RequestIntervention,
+            HilMode::Interactive | HilMode::BatchReview => HilAction::RequestIntervention,
             HilMode::AutoReject => HilAction::Abort,
             HilMode::AutoApprove => HilAction::ForceApprove,
         }
@@ -112,13 +515,44 @@ impl AgentPolicy {
             return HilAction::Continue;
         }
         match self.hil_mode {
-            HilMode::Interactive => HilAction::RequestIntervention,
+            HilMode::Interactive | HilMode::BatchReview => HilAction::RequestIntervention,
             HilMode::AutoReject => HilAction::Abort,
             HilMode::AutoApprove => HilAction::ForceApprove,
         }
     }
 }
 
+/// Whole-string glob match where `*` matches any sequence and `?` any
+/// single character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it was tried against
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +565,9 @@ mod tests {
         assert!(!policy.require_final_review);
         assert_eq!(policy.max_plan_revisions, 3);
         assert_eq!(policy.max_action_rejections, 3);
+        assert_eq!(policy.quorum_rule, QuorumRule::Majority);
+        assert!(policy.min_reviewers_for_risk.is_empty());
+        assert!(!policy.strict_reviewer_floor);
     }
 
     #[test]
@@ -138,9 +575,11 @@ mod tests {
         let policy = AgentPolicy::default()
             .with_hil_mode(HilMode::AutoReject)
             .with_max_plan_revisions(5)
-            .with_require_final_review(true);
+            .with_require_final_review(true)
+            .with_quorum_rule(QuorumRule::Unanimous);
 
         assert_eq!(policy.hil_mode, HilMode::AutoReject);
+        assert_eq!(policy.quorum_rule, QuorumRule::Unanimous);
         assert_eq!(policy.max_plan_revisions, 5);
         assert!(policy.require_final_review);
     }
@@ -172,6 +611,29 @@ mod tests {
         assert_eq!(policy.hil_action(3), HilAction::ForceApprove);
     }
 
+    #[test]
+    fn test_hil_timeout() {
+        let policy = AgentPolicy::default();
+        assert_eq!(policy.hil_timeout(), None);
+        assert!(matches!(
+            policy.hil_timeout_default.decision(),
+            HumanDecision::Reject
+        ));
+
+        let policy = policy
+            .with_hil_timeout_secs(Some(30))
+            .with_hil_timeout_default("approve".parse().unwrap());
+        assert_eq!(
+            policy.hil_timeout(),
+            Some(std::time::Duration::from_secs(30))
+        );
+        assert!(matches!(
+            policy.hil_timeout_default.decision(),
+            HumanDecision::Approve
+        ));
+        assert!("later".parse::<HilTimeoutDefault>().is_err());
+    }
+
     // ==================== action_rejection_action Tests ====================
 
     #[test]
@@ -214,4 +676,106 @@ mod tests {
             HilAction::RequestIntervention
         );
     }
+
+    // ==================== reviewer floor Tests ====================
+
+    #[test]
+    fn test_reviewer_floor_defaults_to_none() {
+        let policy = AgentPolicy::default();
+        assert_eq!(policy.min_reviewers(RiskLevel::High), 0);
+        assert!(policy.meets_reviewer_floor(RiskLevel::High, 1));
+    }
+
+    #[test]
+    fn test_reviewer_floor_per_risk_level() {
+        let policy = AgentPolicy::default().with_min_reviewers(RiskLevel::High, 3);
+        assert!(!policy.meets_reviewer_floor(RiskLevel::High, 2));
+        assert!(policy.meets_reviewer_floor(RiskLevel::High, 3));
+        assert!(policy.meets_reviewer_floor(RiskLevel::Low, 0));
+    }
+
+    #[test]
+    fn test_safe_mode_raises_floor_and_keeps_higher_one() {
+        let policy = AgentPolicy::default().with_safe_mode();
+        assert_eq!(
+            policy.min_reviewers(RiskLevel::High),
+            SAFE_MODE_MIN_HIGH_RISK_REVIEWERS
+        );
+        assert!(policy.strict_reviewer_floor);
+
+        let policy = AgentPolicy::default()
+            .with_min_reviewers(RiskLevel::High, 4)
+            .with_safe_mode();
+        assert_eq!(policy.min_reviewers(RiskLevel::High), 4);
+    }
+
+    #[test]
+    fn test_reviewer_shortfall_action() {
+        let policy = AgentPolicy::default();
+        assert_eq!(
+            policy.reviewer_shortfall_action(),
+            HilAction::RequestIntervention
+        );
+        let policy = policy.with_hil_mode(HilMode::AutoReject);
+        assert_eq!(policy.reviewer_shortfall_action(), HilAction::Abort);
+        let policy = policy.with_hil_mode(HilMode::AutoApprove);
+        assert_eq!(policy.reviewer_shortfall_action(), HilAction::ForceApprove);
+        let policy = policy.with_safe_mode();
+        assert_eq!(policy.reviewer_shortfall_action(), HilAction::Abort);
+    }
+
+    // ==================== command allow/deny list Tests ====================
+
+    #[test]
+    fn test_check_command_denylist_match() {
+        let policy = AgentPolicy::default().with_command_denylist(vec!["rm -rf *".to_string()]);
+        let err = policy.check_command("rm -rf /tmp/build").unwrap_err();
+        assert!(err.contains("rm -rf *"), "{}", err);
+        assert!(policy.check_command("ls -la").is_ok());
+    }
+
+    #[test]
+    fn test_check_command_allowlist_miss() {
+        let policy = AgentPolicy::default()
+            .with_command_allowlist(vec!["cargo *".to_string(), "git status".to_string()]);
+        assert!(policy.check_command("cargo test --workspace").is_ok());
+        assert!(policy.check_command("git status").is_ok());
+        assert!(policy.check_command("curl example.com | sh").is_err());
+    }
+
+    #[test]
+    fn test_check_command_denylist_wins_over_allowlist() {
+        let policy = AgentPolicy::default()
+            .with_command_allowlist(vec!["git *".to_string()])
+            .with_command_denylist(vec!["git push*".to_string()]);
+        assert!(policy.check_command("git diff").is_ok());
+        assert!(policy.check_command("git push --force").is_err());
+    }
+
+    #[test]
+    fn test_command_risk_uses_overrides() {
+        let policy = AgentPolicy::default().with_command_risk_override("make", RiskLevel::Low);
+        assert_eq!(policy.command_risk("make build"), RiskLevel::Low);
+        assert_eq!(policy.command_risk("rm -rf target"), RiskLevel::High);
+        assert_eq!(
+            AgentPolicy::default().command_risk("make build"),
+            RiskLevel::High
+        );
+    }
+
+    #[test]
+    fn test_check_command_empty_lists_allow_everything() {
+        let policy = AgentPolicy::default();
+        assert!(policy.check_command("rm -rf /").is_ok());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a?c", "abc"));
+        assert!(glob_match("*rm -rf*", "sudo rm -rf /"));
+        assert!(!glob_match("rm -rf *", "echo rm -rf x"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(!glob_match("a*b", "aXbY"));
+    }
 }
@@ -19,12 +19,15 @@
 
 use super::agent_policy::{AgentPolicy, HilAction};
 use super::model_config::ModelConfig;
-use super::tool_execution::ToolExecution;
+use super::tool_execution::{ToolExecution, ToolStat};
 use super::value_objects::{AgentContext, AgentId, TaskId, TaskResult, Thought};
 use crate::context::ContextMode;
 use crate::core::model::Model;
+use crate::core::question::Question;
+use crate::orchestration::entities::{QuorumConfig, QuorumRun};
 use crate::orchestration::session_mode::SessionMode;
-use crate::quorum::{Vote, VoteVerdict};
+use crate::quorum::{ConsensusRound, Vote, VoteResult, VoteVerdict};
+use crate::session::response::TokenUsage;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -72,6 +75,16 @@ pub enum HilMode {
     /// a plan that multiple models rejected. Only use in controlled
     /// environments or when you're confident the rejections are false positives.
     AutoApprove,
+
+    /// Like [`Interactive`](Self::Interactive), but high-risk actions are
+    /// queued instead of executed and approved all at once at the end.
+    ///
+    /// Actions that pass action review go into an
+    /// [`ActionBatch`](super::action_batch::ActionBatch); after the last task
+    /// the user approves or rejects each one via
+    /// `HumanInterventionPort::request_batch_approval()`, and only the
+    /// approved ones run. Plan-level decisions prompt as in `Interactive`.
+    BatchReview,
 }
 
 impl HilMode {
@@ -81,6 +94,7 @@ impl HilMode {
             HilMode::Interactive => "interactive",
             HilMode::AutoReject => "auto_reject",
             HilMode::AutoApprove => "auto_approve",
+            HilMode::BatchReview => "batch_review",
         }
     }
 }
@@ -94,11 +108,13 @@ impl std::str::FromStr for HilMode {
     /// - "interactive"
     /// - "auto_reject" or "autoreject"
     /// - "auto_approve" or "autoapprove"
+    /// - "batch_review", "batchreview" or "batch"
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "interactive" => Ok(HilMode::Interactive),
             "auto_reject" | "autoreject" => Ok(HilMode::AutoReject),
             "auto_approve" | "autoapprove" => Ok(HilMode::AutoApprove),
+            "batch_review" | "batchreview" | "batch" => Ok(HilMode::BatchReview),
             _ => Err(format!("Invalid HilMode: {}", s)),
         }
     }
@@ -525,11 +541,289 @@ impl Plan {
         self.tasks.iter().find(|t| t.is_ready(&resolved))
     }
 
+    /// All tasks that are ready to execute, in plan order.
+    ///
+    /// Same readiness rule as [`Self::next_task`]; the tasks returned do not
+    /// depend on each other and may run concurrently.
+    pub fn ready_tasks(&self) -> Vec<&Task> {
+        let resolved: Vec<TaskId> = self
+            .tasks
+            .iter()
+            .filter(|t| t.status.is_terminal())
+            .map(|t| t.id.clone())
+            .collect();
+
+        self.tasks
+            .iter()
+            .filter(|t| t.is_ready(&resolved))
+            .collect()
+    }
+
+    /// Append the tasks of `follow_up` (e.g. corrective tasks from a final
+    /// review), renaming each ID to `<prefix>.<id>`.
+    ///
+    /// Dependencies between follow-up tasks are renamed with them; other
+    /// dependencies keep pointing at this plan's tasks. Returns the new IDs.
+    pub fn append_follow_up(&mut self, follow_up: Plan, prefix: &str) -> Vec<TaskId> {
+        let local: Vec<TaskId> = follow_up.tasks.iter().map(|t| t.id.clone()).collect();
+        let rename = |id: &TaskId| TaskId::new(format!("{}.{}", prefix, id));
+        let mut added = Vec::new();
+        for mut task in follow_up.tasks {
+            task.id = rename(&task.id);
+            task.depends_on = task
+                .depends_on
+                .iter()
+                .map(|dep| {
+                    if local.contains(dep) {
+                        rename(dep)
+                    } else {
+                        dep.clone()
+                    }
+                })
+                .collect();
+            added.push(task.id.clone());
+            self.add_task(task);
+        }
+        added
+    }
+
+    /// Skip every pending task that depends on `failed`, directly or through
+    /// other skipped tasks. Returns the skipped task IDs in plan order.
+    pub fn skip_dependents(&mut self, failed: &TaskId) -> Vec<TaskId> {
+        let mut blocked = vec![failed.clone()];
+        let mut skipped = Vec::new();
+        loop {
+            let next = self.tasks.iter_mut().find(|t| {
+                t.status == TaskStatus::Pending && t.depends_on.iter().any(|d| blocked.contains(d))
+            });
+            let Some(task) = next else {
+                break;
+            };
+            task.mark_skipped();
+            blocked.push(task.id.clone());
+            skipped.push(task.id.clone());
+        }
+        self.tasks
+            .iter()
+            .filter(|t| skipped.contains(&t.id))
+            .map(|t| t.id.clone())
+            .collect()
+    }
+
+    /// Skip every task that has not started. Returns the skipped task IDs.
+    pub fn skip_pending(&mut self) -> Vec<TaskId> {
+        self.tasks
+            .iter_mut()
+            .filter(|t| t.status == TaskStatus::Pending)
+            .map(|t| {
+                t.mark_skipped();
+                t.id.clone()
+            })
+            .collect()
+    }
+
+    /// Find a cycle in the `depends_on` graph.
+    ///
+    /// Returns the task IDs along the cycle, starting and ending with the same
+    /// task (e.g. `1 → 2 → 1`). Dependencies on unknown task IDs are ignored.
+    pub fn dependency_cycle(&self) -> Option<Vec<TaskId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Unvisited,
+            InPath,
+            Done,
+        }
+
+        fn visit(
+            plan: &Plan,
+            index: usize,
+            marks: &mut [Mark],
+            path: &mut Vec<usize>,
+        ) -> Option<Vec<TaskId>> {
+            marks[index] = Mark::InPath;
+            path.push(index);
+            for dep in &plan.tasks[index].depends_on {
+                let Some(dep_index) = plan.tasks.iter().position(|t| &t.id == dep) else {
+                    continue;
+                };
+                match marks[dep_index] {
+                    Mark::InPath => {
+                        let start = path.iter().position(|&i| i == dep_index).unwrap_or(0);
+                        let mut cycle: Vec<TaskId> = path[start..]
+                            .iter()
+                            .map(|&i| plan.tasks[i].id.clone())
+                            .collect();
+                        cycle.push(dep.clone());
+                        return Some(cycle);
+                    }
+                    Mark::Unvisited => {
+                        if let Some(cycle) = visit(plan, dep_index, marks, path) {
+                            return Some(cycle);
+                        }
+                    }
+                    Mark::Done => {}
+                }
+            }
+            path.pop();
+            marks[index] = Mark::Done;
+            None
+        }
+
+        let mut marks = vec![Mark::Unvisited; self.tasks.len()];
+        for index in 0..self.tasks.len() {
+            if marks[index] == Mark::Unvisited
+                && let Some(cycle) = visit(self, index, &mut marks, &mut Vec::new())
+            {
+                return Some(cycle);
+            }
+        }
+        None
+    }
+
+    /// Render the plan as a dependency tree for `--explain-plan`.
+    ///
+    /// Tasks without (known) dependencies are roots. Every other task is
+    /// nested under its deepest dependency, so the indentation is the task's
+    /// dependency level; a task with several dependencies lists them all in
+    /// an `(after ...)` suffix. If the graph has a cycle, it is reported and
+    /// the tasks are listed flat in plan order.
+    pub fn to_tree_string(&self) -> String {
+        fn line(task: &Task, depth: usize) -> String {
+            let mut line = format!("{}- {}: {}", "  ".repeat(depth), task.id, task.description);
+            if let Some(tool) = &task.tool_name {
+                line.push_str(&format!(" [{}]", tool));
+            }
+            if task.depends_on.len() > 1 {
+                let deps: Vec<&str> = task.depends_on.iter().map(|d| d.as_str()).collect();
+                line.push_str(&format!(" (after {})", deps.join(", ")));
+            }
+            line
+        }
+
+        let mut lines = vec![format!("Plan: {}", self.objective)];
+
+        if let Some(cycle) = self.dependency_cycle() {
+            let ids: Vec<&str> = cycle.iter().map(|id| id.as_str()).collect();
+            lines.push(format!("Dependency cycle: {}", ids.join(" → ")));
+            lines.extend(self.tasks.iter().map(|t| line(t, 0)));
+            return lines.join("\n");
+        }
+
+        // Dependency level of each task (0 for roots); acyclic, so this terminates.
+        fn level(plan: &Plan, index: usize, levels: &mut [Option<usize>]) -> usize {
+            if let Some(level) = levels[index] {
+                return level;
+            }
+            let level = plan.tasks[index]
+                .depends_on
+                .iter()
+                .filter_map(|dep| plan.tasks.iter().position(|t| &t.id == dep))
+                .map(|dep_index| level(plan, dep_index, levels) + 1)
+                .max()
+                .unwrap_or(0);
+            levels[index] = Some(level);
+            level
+        }
+
+        let mut levels = vec![None; self.tasks.len()];
+        for index in 0..self.tasks.len() {
+            level(self, index, &mut levels);
+        }
+        let levels: Vec<usize> = levels.into_iter().map(|l| l.unwrap_or(0)).collect();
+
+        // Parent = the dependency with the highest level (first one on ties).
+        let parent: Vec<Option<usize>> = self
+            .tasks
+            .iter()
+            .map(|task| {
+                task.depends_on
+                    .iter()
+                    .filter_map(|dep| self.tasks.iter().position(|t| &t.id == dep))
+                    .fold(None, |best: Option<usize>, i| match best {
+                        Some(b) if levels[b] >= levels[i] => Some(b),
+                        _ => Some(i),
+                    })
+            })
+            .collect();
+
+        fn walk(
+            plan: &Plan,
+            index: usize,
+            depth: usize,
+            parent: &[Option<usize>],
+            lines: &mut Vec<String>,
+        ) {
+            lines.push(line(&plan.tasks[index], depth));
+            for child in (0..plan.tasks.len()).filter(|&c| parent[c] == Some(index)) {
+                walk(plan, child, depth + 1, parent, lines);
+            }
+        }
+
+        for root in (0..self.tasks.len()).filter(|&i| parent[i].is_none()) {
+            walk(self, root, 0, &parent, &mut lines);
+        }
+        lines.join("\n")
+    }
+
+    /// Render the task dependency graph as a Mermaid `graph TD` definition.
+    ///
+    /// Nodes are named `t<index>` (task IDs may contain characters Mermaid
+    /// does not allow in node names) and labelled `"<id>: <description>"`;
+    /// edges run from each dependency to its dependent. Dependencies on
+    /// unknown task IDs are left out.
+    pub fn to_mermaid(&self) -> String {
+        fn escape(label: &str) -> String {
+            let mut escaped = String::with_capacity(label.len());
+            for c in label.chars() {
+                match c {
+                    '#' => escaped.push_str("#35;"),
+                    '"' => escaped.push_str("#quot;"),
+                    '<' => escaped.push_str("#lt;"),
+                    '>' => escaped.push_str("#gt;"),
+                    '\n' | '\r' => escaped.push(' '),
+                    c => escaped.push(c),
+                }
+            }
+            escaped
+        }
+
+        let mut lines = vec!["graph TD".to_string()];
+        for (index, task) in self.tasks.iter().enumerate() {
+            lines.push(format!(
+                "    t{}[\"{}\"]",
+                index,
+                escape(&format!("{}: {}", task.id, task.description))
+            ));
+        }
+        for (index, task) in self.tasks.iter().enumerate() {
+            for dep in &task.depends_on {
+                if let Some(dep_index) = self.tasks.iter().position(|t| &t.id == dep) {
+                    lines.push(format!("    t{} --> t{}", dep_index, index));
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
     /// Get a mutable reference to a task by ID
     pub fn get_task_mut(&mut self, id: &TaskId) -> Option<&mut Task> {
         self.tasks.iter_mut().find(|t| &t.id == id)
     }
 
+    /// Reset tasks left `InProgress` by an interrupted run back to `Pending`
+    /// so they are picked up again. Returns the number of tasks reset.
+    pub fn requeue_interrupted_tasks(&mut self) -> usize {
+        let mut count = 0;
+        for task in &mut self.tasks {
+            if task.status == TaskStatus::InProgress {
+                task.status = TaskStatus::Pending;
+                task.started_at = None;
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Check if all tasks are complete
     pub fn is_complete(&self) -> bool {
         self.tasks.iter().all(|t| t.status.is_terminal())
@@ -540,6 +834,56 @@ impl Plan {
         let completed = self.tasks.iter().filter(|t| t.status.is_terminal()).count();
         (completed, self.tasks.len())
     }
+
+    /// Summarize what executing the plan will touch, from each task's
+    /// planned `tool_name`.
+    ///
+    /// Read-only built-ins (reads, searches) count toward nothing. Tasks
+    /// without a tool, or with a tool whose effects are not known here
+    /// (custom and provider tools), count as [`unknown`](PlanImpact::unknown).
+    pub fn impact_summary(&self) -> PlanImpact {
+        let mut impact = PlanImpact::default();
+        for task in &self.tasks {
+            match task.tool_name.as_deref() {
+                Some("write_file") => impact.files_written += 1,
+                Some("run_command") => impact.commands_run += 1,
+                Some("web_fetch" | "web_search") => impact.web_calls += 1,
+                Some("read_file" | "glob_search" | "grep_search") => {}
+                Some(_) | None => impact.unknown += 1,
+            }
+        }
+        impact
+    }
+}
+
+/// What executing a [`Plan`] will touch, shown before execution confirmation.
+///
+/// Built by [`Plan::impact_summary`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanImpact {
+    /// Tasks that write a file (`write_file`).
+    pub files_written: usize,
+    /// Tasks that run a shell command (`run_command`).
+    pub commands_run: usize,
+    /// Tasks that access the web (`web_fetch`, `web_search`).
+    pub web_calls: usize,
+    /// Tasks without a planned tool, or with a tool whose effects are not
+    /// known (custom and provider tools).
+    pub unknown: usize,
+}
+
+impl std::fmt::Display for PlanImpact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} file write(s), {} command(s), {} web call(s)",
+            self.files_written, self.commands_run, self.web_calls
+        )?;
+        if self.unknown > 0 {
+            write!(f, ", {} task(s) with unknown impact", self.unknown)?;
+        }
+        Ok(())
+    }
 }
 
 // ==================== Ensemble Planning Types ====================
@@ -627,8 +971,8 @@ impl PlanCandidate {
 /// Result of ensemble planning
 ///
 /// Contains all plan candidates with their votes and the selected winner.
-/// Use [`EnsemblePlanResult::select_best`] to automatically select the
-/// plan with the highest average score.
+/// Use [`EnsemblePlanResult::select_deterministic`] to automatically select
+/// the plan with the highest average score.
 ///
 /// # Ensemble Planning Flow
 ///
@@ -669,7 +1013,7 @@ impl PlanCandidate {
 /// candidate2.add_vote("Claude", 6.0);
 ///
 /// // Select the best plan
-/// let result = EnsemblePlanResult::select_best(vec![candidate1, candidate2]);
+/// let result = EnsemblePlanResult::select_deterministic(vec![candidate1, candidate2]);
 ///
 /// // Plan A has higher score, so it's selected
 /// assert_eq!(result.selected_index, 0);
@@ -682,6 +1026,9 @@ pub struct EnsemblePlanResult {
     pub candidates: Vec<PlanCandidate>,
     /// Index of the selected plan in candidates
     pub selected_index: usize,
+    /// Plan merged from the top candidates (`agent.ensemble_selection = "merge"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_plan: Option<Plan>,
 }
 
 impl EnsemblePlanResult {
@@ -690,14 +1037,44 @@ impl EnsemblePlanResult {
         Self {
             candidates,
             selected_index,
+            merged_plan: None,
         }
     }
 
+    /// Record a plan merged from the top candidates; it replaces the
+    /// selected candidate's plan in [`Self::plan`].
+    pub fn with_merged_plan(mut self, plan: Plan) -> Self {
+        self.merged_plan = Some(plan);
+        self
+    }
+
     /// Get the selected plan
     pub fn selected(&self) -> Option<&PlanCandidate> {
         self.candidates.get(self.selected_index)
     }
 
+    /// The plan to execute: the merged plan if there is one, otherwise the
+    /// selected candidate's.
+    pub fn plan(&self) -> Option<&Plan> {
+        self.merged_plan
+            .as_ref()
+            .or_else(|| self.selected().map(|c| &c.plan))
+    }
+
+    /// Candidates by descending average score (ties in selection order),
+    /// at most `limit` of them.
+    pub fn top_candidates(&self, limit: usize) -> Vec<&PlanCandidate> {
+        let mut ranked: Vec<(usize, &PlanCandidate)> = self.candidates.iter().enumerate().collect();
+        ranked.sort_by(|(i, a), (j, b)| {
+            b.average_score()
+                .partial_cmp(&a.average_score())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| (*j == self.selected_index).cmp(&(*i == self.selected_index)))
+                .then_with(|| i.cmp(j))
+        });
+        ranked.into_iter().take(limit).map(|(_, c)| c).collect()
+    }
+
     /// Get the selected plan (owned)
     pub fn into_selected(mut self) -> Option<PlanCandidate> {
         if self.selected_index < self.candidates.len() {
@@ -707,23 +1084,29 @@ impl EnsemblePlanResult {
         }
     }
 
-    /// Select the best plan based on average scores.
+    /// Select the plan with the highest average score, breaking ties by a
+    /// stable order so the same candidates always give the same winner,
+    /// whatever order the parallel generation finished in.
     ///
     /// Tiebreaker rules when average scores are equal:
     /// 1. More votes = more confidence (prefer the candidate with more votes)
-    /// 2. Lower index = generated first (stable ordering)
-    pub fn select_best(candidates: Vec<PlanCandidate>) -> Self {
+    /// 2. Model name, alphabetically first
+    /// 3. Plan content hash (objective, reasoning and every planned task
+    ///    field), lowest first
+    pub fn select_deterministic(candidates: Vec<PlanCandidate>) -> Self {
         let selected_index = candidates
             .iter()
             .enumerate()
-            .max_by(|(i, a), (j, b)| {
+            .max_by(|(_, a), (_, b)| {
                 a.average_score()
                     .partial_cmp(&b.average_score())
                     .unwrap_or(std::cmp::Ordering::Equal)
                     // Tiebreaker 1: more votes = more confidence
                     .then_with(|| a.vote_count().cmp(&b.vote_count()))
-                    // Tiebreaker 2: lower index = generated first (stable ordering)
-                    .then_with(|| j.cmp(i))
+                    // Tiebreaker 2: model name (reversed: max_by keeps the greatest)
+                    .then_with(|| b.model.to_string().cmp(&a.model.to_string()))
+                    // Tiebreaker 3: plan content hash
+                    .then_with(|| plan_hash(&b.plan).cmp(&plan_hash(&a.plan)))
             })
             .map(|(i, _)| i)
             .unwrap_or(0);
@@ -731,6 +1114,7 @@ impl EnsemblePlanResult {
         Self {
             candidates,
             selected_index,
+            merged_plan: None,
         }
     }
 
@@ -738,7 +1122,11 @@ impl EnsemblePlanResult {
     pub fn summary(&self) -> String {
         let mut summary = String::new();
         for (i, candidate) in self.candidates.iter().enumerate() {
-            let marker = if i == self.selected_index { "→" } else { " " };
+            let marker = if i == self.selected_index && self.merged_plan.is_none() {
+                "→"
+            } else {
+                " "
+            };
             summary.push_str(&format!(
                 "{} Plan {} ({}): avg {:.1}/10\n",
                 marker,
@@ -747,10 +1135,50 @@ impl EnsemblePlanResult {
                 candidate.average_score()
             ));
         }
+        if let Some(merged) = &self.merged_plan {
+            summary.push_str(&format!("→ Merged plan: {}\n", merged.objective));
+        }
         summary
     }
 }
 
+/// FNV-1a hash of a plan's objective, reasoning and tasks.
+///
+/// Covers everything the planner wrote for each task (tool arguments and
+/// dependencies included) but none of the execution state. Stable across
+/// runs and platforms (unlike `DefaultHasher`), so it can order plans
+/// reproducibly.
+fn plan_hash(plan: &Plan) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut feed = |text: &str| {
+        for byte in text.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    feed(&plan.objective);
+    feed(&plan.reasoning);
+    for task in &plan.tasks {
+        feed(task.id.as_str());
+        feed(&task.description);
+        feed(task.tool_name.as_deref().unwrap_or(""));
+        // HashMap order varies between runs, so feed the arguments sorted
+        let mut args: Vec<_> = task.tool_args.iter().collect();
+        args.sort_by_key(|(key, _)| *key);
+        for (key, value) in args {
+            feed(key);
+            feed(&value.to_string());
+        }
+        feed(if task.requires_review { "review" } else { "" });
+        for dep in &task.depends_on {
+            feed(dep.as_str());
+        }
+        feed(task.context_mode.as_ref().map_or("", |m| m.as_str()));
+        feed(task.context_brief.as_deref().unwrap_or(""));
+    }
+    hash
+}
+
 /// State of an agent execution (Entity).
 ///
 /// Tracks the complete state of an autonomous agent run, including:
@@ -771,7 +1199,13 @@ impl EnsemblePlanResult {
 /// | `models` | [`ModelConfig`] | Role-based model selection |
 /// | `policy` | [`AgentPolicy`] | Domain behavioral constraints |
 /// | `max_iterations` | scalar from `ExecutionParams` | Loop limit (app-layer value) |
-#[derive(Debug, Clone)]
+///
+/// # Snapshots
+///
+/// [`AgentState::to_snapshot`] / [`AgentState::from_snapshot`] round-trip the
+/// whole state through JSON so an interrupted run can be persisted and resumed
+/// later (see `RunAgentUseCase::resume` in the application layer).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentState {
     /// Unique identifier for this agent run
     pub id: AgentId,
@@ -805,8 +1239,27 @@ pub struct AgentState {
     /// or abort) when the agent gets stuck in a rejection loop.
     /// Reset to 0 when a task completes successfully.
     pub action_rejection_count: usize,
+    /// Action retries per task (re-executions after a rejected action).
+    /// Tasks that never needed a retry have no entry.
+    #[serde(default)]
+    pub task_retries: HashMap<TaskId, usize>,
     /// Error message if failed
     pub error: Option<String>,
+    /// Token usage per phase, summed over every LLM call made in that phase
+    #[serde(default)]
+    pub token_usage: HashMap<AgentPhase, TokenUsage>,
+    /// `true` if at least one LLM call did not report usage, so
+    /// `token_usage` undercounts the run
+    #[serde(default)]
+    pub usage_unavailable: bool,
+    /// Call/success/failure counts per tool name, over every executed task
+    #[serde(default)]
+    pub tool_stats: HashMap<String, ToolStat>,
+    /// Every plan review round across revisions (see
+    /// [`record_plan_review`](Self::record_plan_review)). `None` until the
+    /// first review.
+    #[serde(default)]
+    pub plan_review: Option<QuorumRun>,
 }
 
 impl AgentState {
@@ -833,7 +1286,12 @@ impl AgentState {
             iteration_count: 0,
             plan_revision_count: 0,
             action_rejection_count: 0,
+            task_retries: HashMap::new(),
             error: None,
+            token_usage: HashMap::new(),
+            usage_unavailable: false,
+            tool_stats: HashMap::new(),
+            plan_review: None,
         }
     }
 
@@ -870,6 +1328,26 @@ impl AgentState {
         self.plan_revision_count += 1;
     }
 
+    /// Records a plan review result as the next [`ConsensusRound`].
+    ///
+    /// Unlike [`Plan::review_history`], which is lost when a revision
+    /// replaces the plan, this history spans the whole run.
+    pub fn record_plan_review(&mut self, result: &VoteResult) {
+        let run = self.plan_review.get_or_insert_with(|| {
+            QuorumRun::new(
+                self.id.to_string(),
+                Question::new(self.request.clone()),
+                QuorumConfig::new(self.models.review.clone()).without_review(),
+            )
+        });
+        let mut round = ConsensusRound::from_result(run.next_round_number(), result.clone())
+            .with_rule(self.policy.quorum_rule);
+        if let Some(plan) = &self.plan {
+            round = round.with_context(plan.objective.clone());
+        }
+        run.record_round(round);
+    }
+
     /// Manually sets the execution phase.
     pub fn set_phase(&mut self, phase: AgentPhase) {
         self.phase = phase;
@@ -903,6 +1381,18 @@ impl AgentState {
         self.action_rejection_count = 0;
     }
 
+    /// Records how many action retries `task_id` needed (`0` records nothing).
+    pub fn record_task_retries(&mut self, task_id: &TaskId, retries: usize) {
+        if retries > 0 {
+            self.task_retries.insert(task_id.clone(), retries);
+        }
+    }
+
+    /// Action retries `task_id` needed so far.
+    pub fn task_retries(&self, task_id: &TaskId) -> usize {
+        self.task_retries.get(task_id).copied().unwrap_or(0)
+    }
+
     /// Increments iteration count and returns `true` if within limits.
     ///
     /// Used to prevent infinite loops during planning and execution.
@@ -923,6 +1413,42 @@ impl AgentState {
     pub fn hil_action(&self) -> HilAction {
         self.policy.hil_action(self.plan_revision_count)
     }
+
+    /// Adds the usage of one LLM call to `phase`'s total.
+    ///
+    /// `None` means the backend did not report usage: the call counts as zero
+    /// tokens and [`usage_unavailable`](Self::usage_unavailable) is set.
+    pub fn record_usage(&mut self, phase: AgentPhase, usage: Option<TokenUsage>) {
+        if usage.is_none() {
+            self.usage_unavailable = true;
+        }
+        *self.token_usage.entry(phase).or_default() += usage.unwrap_or_default();
+    }
+
+    /// Token usage summed over all phases.
+    pub fn total_usage(&self) -> TokenUsage {
+        self.token_usage.values().copied().sum()
+    }
+
+    /// Adds a task's tool executions to [`tool_stats`](Self::tool_stats).
+    pub fn record_tool_executions(&mut self, executions: &[ToolExecution]) {
+        for execution in executions {
+            self.tool_stats
+                .entry(execution.tool_name().to_string())
+                .or_default()
+                .record(execution);
+        }
+    }
+
+    /// Serialize the full state to a JSON value for persistence.
+    pub fn to_snapshot(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("AgentState is always representable as JSON")
+    }
+
+    /// Restore a state previously produced by [`AgentState::to_snapshot`].
+    pub fn from_snapshot(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
 }
 
 /// Get current timestamp in milliseconds
@@ -1023,6 +1549,136 @@ mod tests {
         assert!(state.is_finished());
     }
 
+    #[test]
+    fn test_agent_state_snapshot_round_trip() {
+        let mut state = AgentState::new(
+            "agent-1",
+            "Update the README",
+            SessionMode::default(),
+            ModelConfig::default(),
+            AgentPolicy::default().with_safe_mode(),
+            50,
+        );
+        state.context.project_type = Some("rust".to_string());
+        state.add_thought(Thought::planning("Edit the file"));
+        let mut plan = Plan::new("Update README", "Edit the file")
+            .with_task(Task::new("1", "Read README").with_tool("read_file"))
+            .with_task(Task::new("2", "Write README").with_dependency("1"));
+        plan.tasks[0].mark_completed(TaskResult::success("ok"));
+        plan.tasks[1].mark_in_progress();
+        state.set_plan(plan);
+        state.approve_plan();
+        state.iteration_count = 2;
+        state.record_usage(AgentPhase::Planning, Some(TokenUsage::new(100, 20)));
+
+        let restored = AgentState::from_snapshot(state.to_snapshot()).unwrap();
+
+        assert_eq!(restored.id, state.id);
+        assert_eq!(restored.request, state.request);
+        assert_eq!(restored.mode, state.mode);
+        assert_eq!(restored.models, state.models);
+        assert_eq!(
+            restored.policy.min_reviewers(crate::RiskLevel::High),
+            state.policy.min_reviewers(crate::RiskLevel::High)
+        );
+        assert_eq!(restored.phase, AgentPhase::Executing);
+        assert_eq!(restored.context.project_type.as_deref(), Some("rust"));
+        assert_eq!(restored.thoughts.len(), 1);
+        assert_eq!(restored.iteration_count, 2);
+        assert_eq!(restored.token_usage, state.token_usage);
+        let plan = restored.plan.unwrap();
+        assert!(plan.approved);
+        assert_eq!(plan.tasks[0].status, TaskStatus::Completed);
+        assert_eq!(plan.tasks[1].status, TaskStatus::InProgress);
+        assert_eq!(plan.tasks[1].depends_on, vec![TaskId::from("1")]);
+    }
+
+    #[test]
+    fn test_agent_state_record_usage_per_phase() {
+        let mut state = AgentState::new(
+            "agent-1",
+            "Test",
+            SessionMode::default(),
+            ModelConfig::default(),
+            AgentPolicy::default(),
+            10,
+        );
+        state.record_usage(AgentPhase::Planning, Some(TokenUsage::new(100, 20)));
+        state.record_usage(AgentPhase::Planning, Some(TokenUsage::new(50, 10)));
+        state.record_usage(AgentPhase::Executing, Some(TokenUsage::new(300, 40)));
+        assert!(!state.usage_unavailable);
+
+        state.record_usage(AgentPhase::Executing, None);
+
+        assert!(state.usage_unavailable);
+        assert_eq!(
+            state.token_usage[&AgentPhase::Planning],
+            TokenUsage::new(150, 30)
+        );
+        assert_eq!(
+            state.token_usage[&AgentPhase::Executing],
+            TokenUsage::new(300, 40)
+        );
+        assert_eq!(state.total_usage(), TokenUsage::new(450, 70));
+    }
+
+    #[test]
+    fn test_agent_state_record_tool_executions() {
+        use crate::tool::value_objects::ToolResult;
+        let mut state = AgentState::new(
+            "agent-1",
+            "Test",
+            SessionMode::default(),
+            ModelConfig::default(),
+            AgentPolicy::default(),
+            10,
+        );
+        let execution = |id: &str, tool: &str, ok: bool| {
+            let mut exec = ToolExecution::new(id, tool, HashMap::new(), None, 1);
+            exec.mark_running();
+            if ok {
+                exec.mark_completed(&ToolResult::success(tool, "ok"));
+            } else {
+                exec.mark_error("boom");
+            }
+            exec
+        };
+
+        state.record_tool_executions(&[
+            execution("1", "read_file", true),
+            execution("2", "read_file", false),
+        ]);
+        state.record_tool_executions(&[execution("3", "read_file", true)]);
+
+        assert_eq!(
+            state.tool_stats["read_file"],
+            ToolStat {
+                calls: 3,
+                successes: 2,
+                failures: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_agent_state_from_snapshot_rejects_garbage() {
+        assert!(AgentState::from_snapshot(serde_json::json!({"id": 1})).is_err());
+    }
+
+    #[test]
+    fn test_plan_requeue_interrupted_tasks() {
+        let mut plan = Plan::new("Objective", "Reasoning")
+            .with_task(Task::new("1", "Done"))
+            .with_task(Task::new("2", "Interrupted"));
+        plan.tasks[0].status = TaskStatus::Completed;
+        plan.tasks[1].mark_in_progress();
+
+        assert_eq!(plan.requeue_interrupted_tasks(), 1);
+        assert_eq!(plan.tasks[0].status, TaskStatus::Completed);
+        assert_eq!(plan.tasks[1].status, TaskStatus::Pending);
+        assert_eq!(plan.next_task().unwrap().id.as_str(), "2");
+    }
+
     #[test]
     fn test_agent_iteration_limit() {
         let mut state = AgentState::new(
@@ -1045,6 +1701,7 @@ mod tests {
         assert_eq!(HilMode::Interactive.as_str(), "interactive");
         assert_eq!(HilMode::AutoReject.as_str(), "auto_reject");
         assert_eq!(HilMode::AutoApprove.as_str(), "auto_approve");
+        assert_eq!(HilMode::BatchReview.as_str(), "batch_review");
 
         assert_eq!(
             "interactive".parse::<HilMode>().ok(),
@@ -1058,6 +1715,7 @@ mod tests {
             "autoapprove".parse::<HilMode>().ok(),
             Some(HilMode::AutoApprove)
         );
+        assert_eq!("batch".parse::<HilMode>().ok(), Some(HilMode::BatchReview));
         assert!("invalid".parse::<HilMode>().is_err());
     }
 
@@ -1078,6 +1736,38 @@ mod tests {
         assert_eq!(policy.hil_mode, HilMode::AutoReject);
     }
 
+    #[test]
+    fn test_plan_impact_summary() {
+        let plan = Plan::new("Ship it", "")
+            .with_task(Task::new("1", "Read config").with_tool("read_file"))
+            .with_task(Task::new("2", "Write config").with_tool("write_file"))
+            .with_task(Task::new("3", "Write docs").with_tool("write_file"))
+            .with_task(Task::new("4", "Run tests").with_tool("run_command"))
+            .with_task(Task::new("5", "Look up API").with_tool("web_search"))
+            .with_task(Task::new("6", "Fetch changelog").with_tool("web_fetch"))
+            .with_task(Task::new("7", "Figure it out"))
+            .with_task(Task::new("8", "Deploy").with_tool("deploy"));
+
+        let impact = plan.impact_summary();
+        assert_eq!(
+            impact,
+            PlanImpact {
+                files_written: 2,
+                commands_run: 1,
+                web_calls: 2,
+                unknown: 2,
+            }
+        );
+        assert_eq!(
+            impact.to_string(),
+            "2 file write(s), 1 command(s), 2 web call(s), 2 task(s) with unknown impact"
+        );
+        assert_eq!(
+            Plan::new("Empty", "").impact_summary(),
+            PlanImpact::default()
+        );
+    }
+
     #[test]
     fn test_plan_revision_count() {
         let mut plan = Plan::new("Test", "Reasoning");
@@ -1310,7 +2000,7 @@ mod tests {
         let mut candidate2 = PlanCandidate::new(Model::Gpt52Codex, plan2);
         candidate2.add_vote("Claude", 8.0);
 
-        let result = EnsemblePlanResult::select_best(vec![candidate1, candidate2]);
+        let result = EnsemblePlanResult::select_deterministic(vec![candidate1, candidate2]);
 
         // candidate2 has higher score (8.0 vs 6.0)
         assert_eq!(result.selected_index, 1);
@@ -1383,8 +2073,182 @@ mod tests {
         assert!(plan.next_task().is_none());
     }
 
+    fn ready_ids(plan: &Plan) -> Vec<&str> {
+        plan.ready_tasks().iter().map(|t| t.id.as_str()).collect()
+    }
+
     #[test]
-    fn test_select_best_tiebreaker_by_vote_count() {
+    fn test_ready_tasks_diamond() {
+        // 1 → {2, 3} → 4
+        let mut plan = Plan::new("Test", "Reasoning")
+            .with_task(Task::new("1", "Root"))
+            .with_task(Task::new("2", "Left").with_dependency("1"))
+            .with_task(Task::new("3", "Right").with_dependency("1"))
+            .with_task(
+                Task::new("4", "Join")
+                    .with_dependency("2")
+                    .with_dependency("3"),
+            );
+        assert!(plan.dependency_cycle().is_none());
+
+        assert_eq!(ready_ids(&plan), vec!["1"]);
+        plan.tasks[0].mark_completed(TaskResult::success("ok"));
+        // Both branches are ready at once
+        assert_eq!(ready_ids(&plan), vec!["2", "3"]);
+
+        plan.tasks[1].mark_completed(TaskResult::success("ok"));
+        plan.tasks[2].mark_in_progress();
+        // The join waits for both branches
+        assert!(ready_ids(&plan).is_empty());
+        plan.tasks[2].mark_completed(TaskResult::success("ok"));
+        assert_eq!(ready_ids(&plan), vec!["4"]);
+    }
+
+    #[test]
+    fn test_skip_dependents_is_transitive() {
+        // 1 → 2 → 3, and 4 independent
+        let mut plan = Plan::new("Test", "Reasoning")
+            .with_task(Task::new("1", "Fails"))
+            .with_task(Task::new("2", "Child").with_dependency("1"))
+            .with_task(Task::new("3", "Grandchild").with_dependency("2"))
+            .with_task(Task::new("4", "Independent"));
+        plan.tasks[0].mark_failed(TaskResult::failure("boom"));
+
+        assert_eq!(
+            plan.skip_dependents(&TaskId::from("1")),
+            vec![TaskId::from("2"), TaskId::from("3")]
+        );
+        assert_eq!(plan.tasks[1].status, TaskStatus::Skipped);
+        assert_eq!(plan.tasks[2].status, TaskStatus::Skipped);
+        assert_eq!(ready_ids(&plan), vec!["4"]);
+
+        assert_eq!(plan.skip_pending(), vec![TaskId::from("4")]);
+        assert!(plan.is_complete());
+    }
+
+    #[test]
+    fn test_append_follow_up_renames_local_dependencies() {
+        let mut plan = Plan::new("Test", "Reasoning").with_task(Task::new("1", "Build"));
+        plan.tasks[0].mark_completed(TaskResult::success("ok"));
+        let fixes = Plan::new("Fix", "")
+            .with_task(Task::new("a", "Patch").with_dependency("1"))
+            .with_task(Task::new("b", "Retest").with_dependency("a"));
+
+        let added = plan.append_follow_up(fixes, "fix1");
+
+        assert_eq!(added, vec![TaskId::from("fix1.a"), TaskId::from("fix1.b")]);
+        // External dependencies keep pointing at the original plan
+        assert_eq!(plan.tasks[1].depends_on, vec![TaskId::from("1")]);
+        assert_eq!(plan.tasks[2].depends_on, vec![TaskId::from("fix1.a")]);
+        assert_eq!(ready_ids(&plan), vec!["fix1.a"]);
+    }
+
+    #[test]
+    fn test_dependency_cycle() {
+        let plan = Plan::new("Test", "Reasoning")
+            .with_task(Task::new("1", "First").with_dependency("3"))
+            .with_task(Task::new("2", "Second").with_dependency("1"))
+            .with_task(Task::new("3", "Third").with_dependency("2"));
+        let cycle: Vec<String> = plan
+            .dependency_cycle()
+            .unwrap()
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        assert_eq!(cycle, vec!["1", "3", "2", "1"]);
+        assert!(plan.ready_tasks().is_empty());
+
+        // Self-dependency is a cycle too; unknown IDs are not
+        let plan = Plan::new("Test", "Reasoning")
+            .with_task(Task::new("1", "Loop").with_dependency("1"))
+            .with_task(Task::new("2", "Dangling").with_dependency("9"));
+        assert_eq!(plan.dependency_cycle().unwrap().len(), 2);
+        let plan = Plan::new("Test", "Reasoning")
+            .with_task(Task::new("2", "Dangling").with_dependency("9"));
+        assert!(plan.dependency_cycle().is_none());
+    }
+
+    #[test]
+    fn test_to_tree_string_linear() {
+        let plan = Plan::new("Fix bug", "Reasoning")
+            .with_task(Task::new("1", "Read code").with_tool("read_file"))
+            .with_task(
+                Task::new("2", "Patch")
+                    .with_tool("write_file")
+                    .with_dependency("1"),
+            )
+            .with_task(Task::new("3", "Summarize").with_dependency("2"));
+        assert_eq!(
+            plan.to_tree_string(),
+            "Plan: Fix bug\n\
+             - 1: Read code [read_file]\n\
+             \x20 - 2: Patch [write_file]\n\
+             \x20   - 3: Summarize"
+        );
+    }
+
+    #[test]
+    fn test_to_tree_string_branching() {
+        let plan = Plan::new("Release", "Reasoning")
+            .with_task(Task::new("1", "Build"))
+            .with_task(Task::new("2", "Test").with_dependency("1"))
+            .with_task(Task::new("3", "Lint"))
+            .with_task(Task::new("4", "Docs").with_dependency("1"))
+            .with_task(
+                Task::new("5", "Publish")
+                    .with_dependency("3")
+                    .with_dependency("2"),
+            );
+        assert_eq!(
+            plan.to_tree_string(),
+            "Plan: Release\n\
+             - 1: Build\n\
+             \x20 - 2: Test\n\
+             \x20   - 5: Publish (after 3, 2)\n\
+             \x20 - 4: Docs\n\
+             - 3: Lint"
+        );
+    }
+
+    #[test]
+    fn test_to_tree_string_reports_cycle() {
+        let plan = Plan::new("Loop", "Reasoning")
+            .with_task(Task::new("1", "First").with_dependency("2"))
+            .with_task(Task::new("2", "Second").with_dependency("1"));
+        assert_eq!(
+            plan.to_tree_string(),
+            "Plan: Loop\n\
+             Dependency cycle: 1 → 2 → 1\n\
+             - 1: First\n\
+             - 2: Second"
+        );
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let plan = Plan::new("Fix bug", "Reasoning")
+            .with_task(Task::new("1", "Read \"config\" <main>"))
+            .with_task(Task::new("2", "Patch #42").with_dependency("1"))
+            .with_task(
+                Task::new("3", "Test")
+                    .with_dependency("1")
+                    .with_dependency("2")
+                    .with_dependency("9"),
+            );
+        let mermaid = plan.to_mermaid();
+
+        assert!(mermaid.starts_with("graph TD\n"));
+        assert!(mermaid.contains("    t0[\"1: Read #quot;config#quot; #lt;main#gt;\"]"));
+        assert!(mermaid.contains("    t1[\"2: Patch #35;42\"]"));
+        assert!(mermaid.contains("    t2[\"3: Test\"]"));
+        assert!(mermaid.contains("    t0 --> t1"));
+        assert!(mermaid.contains("    t0 --> t2"));
+        assert!(mermaid.contains("    t1 --> t2"));
+        assert_eq!(mermaid.matches("-->").count(), 3);
+    }
+
+    #[test]
+    fn test_select_deterministic_tiebreaker_by_vote_count() {
         let mut c1 = PlanCandidate::new(Model::ClaudeSonnet45, Plan::new("A", "R"));
         c1.add_vote("GPT", 8.0);
         c1.add_vote("Gemini", 8.0);
@@ -1393,20 +2257,103 @@ mod tests {
         c2.add_vote("Claude", 8.0);
         // c2 has only 1 vote vs c1's 2 votes
 
-        let result = EnsemblePlanResult::select_best(vec![c1, c2]);
+        let result = EnsemblePlanResult::select_deterministic(vec![c1, c2]);
         assert_eq!(result.selected_index, 0); // c1 wins: more votes
     }
 
     #[test]
-    fn test_select_best_tiebreaker_by_index() {
-        let mut c1 = PlanCandidate::new(Model::ClaudeSonnet45, Plan::new("A", "R"));
-        c1.add_vote("GPT", 8.0);
+    fn test_select_deterministic_ignores_completion_order() {
+        let candidate = |model: Model, objective: &str| {
+            let mut c = PlanCandidate::new(model, Plan::new(objective, "R"));
+            c.add_vote("Other", 8.0);
+            c
+        };
 
-        let mut c2 = PlanCandidate::new(Model::Gpt52Codex, Plan::new("B", "R"));
-        c2.add_vote("Claude", 8.0);
+        // Same score, same votes: the model name decides, in either order
+        for order in [
+            vec![
+                candidate(Model::Gpt52Codex, "B"),
+                candidate(Model::ClaudeSonnet45, "A"),
+            ],
+            vec![
+                candidate(Model::ClaudeSonnet45, "A"),
+                candidate(Model::Gpt52Codex, "B"),
+            ],
+        ] {
+            let result = EnsemblePlanResult::select_deterministic(order);
+            assert_eq!(result.selected().unwrap().model, Model::ClaudeSonnet45);
+        }
 
-        let result = EnsemblePlanResult::select_best(vec![c1, c2]);
-        assert_eq!(result.selected_index, 0); // c1 wins: same score, same votes, lower index
+        // Same model too: the plan hash decides, in either order
+        let first = EnsemblePlanResult::select_deterministic(vec![
+            candidate(Model::ClaudeSonnet45, "A"),
+            candidate(Model::ClaudeSonnet45, "B"),
+        ]);
+        let second = EnsemblePlanResult::select_deterministic(vec![
+            candidate(Model::ClaudeSonnet45, "B"),
+            candidate(Model::ClaudeSonnet45, "A"),
+        ]);
+        assert_eq!(
+            first.selected().unwrap().plan.objective,
+            second.selected().unwrap().plan.objective
+        );
+    }
+
+    #[test]
+    fn test_plan_hash_covers_task_args_and_dependencies() {
+        let base = || {
+            Plan::new("Same", "R")
+                .with_task(Task::new("1", "Write").with_tool("write_file"))
+                .with_task(Task::new("2", "Test").with_tool("run_command"))
+        };
+        assert_eq!(plan_hash(&base()), plan_hash(&base()));
+
+        let mut with_args = base();
+        with_args.tasks[0]
+            .tool_args
+            .insert("path".to_string(), serde_json::json!("a.rs"));
+        assert_ne!(plan_hash(&with_args), plan_hash(&base()));
+
+        let mut with_dep = base();
+        with_dep.tasks[1].depends_on.push(TaskId::new("1"));
+        assert_ne!(plan_hash(&with_dep), plan_hash(&base()));
+
+        // Execution state is not part of the plan
+        let mut run = base();
+        run.tasks[0].status = TaskStatus::Completed;
+        assert_eq!(plan_hash(&run), plan_hash(&base()));
+    }
+
+    #[test]
+    fn test_ensemble_merged_plan_and_top_candidates() {
+        let candidate = |model: Model, objective: &str, score: f64| {
+            let mut c = PlanCandidate::new(model, Plan::new(objective, "R"));
+            c.add_vote("Other", score);
+            c
+        };
+        let result = EnsemblePlanResult::select_deterministic(vec![
+            candidate(Model::ClaudeHaiku45, "Low", 4.0),
+            candidate(Model::ClaudeSonnet45, "High", 9.0),
+            candidate(Model::Gpt52Codex, "Mid", 7.0),
+        ]);
+
+        let top: Vec<_> = result
+            .top_candidates(2)
+            .iter()
+            .map(|c| c.plan.objective.clone())
+            .collect();
+        assert_eq!(top, vec!["High", "Mid"]);
+        assert_eq!(result.plan().unwrap().objective, "High");
+
+        let merged = result.with_merged_plan(Plan::new("Merged", "R"));
+        assert_eq!(merged.plan().unwrap().objective, "Merged");
+        assert_eq!(merged.selected().unwrap().plan.objective, "High");
+        let summary = merged.summary();
+        assert!(
+            summary.ends_with("→ Merged plan: Merged\n"),
+            "got: {summary}"
+        );
+        assert_eq!(summary.matches('→').count(), 1);
     }
 
     #[test]
@@ -75,6 +75,7 @@
 //! assert_eq!(mode.consensus_level, ConsensusLevel::Ensemble);
 //! ```
 
+pub mod action_batch;
 pub mod agent_policy;
 pub mod entities;
 pub mod model_config;
@@ -84,14 +85,17 @@ pub mod tool_execution;
 pub mod validation;
 pub mod value_objects;
 
-pub use agent_policy::{AgentPolicy, HilAction};
+pub use action_batch::{ActionBatch, BatchActionOutcome, QueuedAction};
+pub use agent_policy::{
+    AgentPolicy, EnsembleSelection, FailurePolicy, HilAction, HilTimeoutDefault,
+};
 pub use entities::{
     AgentPhase, AgentState, EnsemblePlanResult, HilMode, HumanDecision, Plan, PlanCandidate,
-    ReviewRound, Task, TaskStatus,
+    PlanImpact, ReviewRound, Task, TaskStatus,
 };
 pub use model_config::ModelConfig;
-pub use plan_parser::{extract_plan_from_response, parse_plan, parse_plan_json};
+pub use plan_parser::{PlanParseError, extract_plan_from_response, parse_plan, parse_plan_json};
 pub use status::AgentStatus;
-pub use tool_execution::{ToolExecution, ToolExecutionId, ToolExecutionState};
+pub use tool_execution::{ToolExecution, ToolExecutionId, ToolExecutionState, ToolStat};
 pub use validation::{ConfigIssue, ConfigIssueCode, Severity};
 pub use value_objects::{AgentContext, AgentId, TaskId, TaskResult, Thought};
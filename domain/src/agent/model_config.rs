@@ -3,8 +3,10 @@
 //! [`ModelConfig`] groups the model selections for different agent phases.
 //! This is a static value object — once created, models don't change at runtime.
 
-use crate::core::model::Model;
+use crate::agent::validation::{ConfigIssue, ConfigIssueCode, Severity};
+use crate::core::model::{Model, ModelParseError};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 /// Role-based model configuration.
 ///
@@ -14,12 +16,24 @@ use serde::{Deserialize, Serialize};
 /// - **Exploration**: Cheap model for context gathering + low-risk tools
 /// - **Decision**: High-performance model for planning + high-risk decisions
 /// - **Review**: Multiple high-performance models for quality judgments
+/// - **Tie-breaker**: Optional model that casts the deciding vote when an
+///   even-sized review quorum splits exactly
 ///
 /// ## Interaction Roles
 /// - **Participants**: Models participating in Quorum Discussion
 /// - **Moderator**: Model for Quorum Synthesis
 /// - **Ask**: Model for lightweight Q&A
 ///
+/// ## System Prompt Overrides
+/// Per-model text prepended to every system prompt sent to that model (see
+/// [`system_prompt_for`](ModelConfig::system_prompt_for)), so e.g. a GPT
+/// reviewer and a Claude reviewer can get tailored framing.
+///
+/// ## Aliases
+/// User-defined short names (`models.aliases`) resolved by
+/// [`resolve`](ModelConfig::resolve) ahead of the built-in aliases, so
+/// `sonnet` can be pinned to an older model.
+///
 /// # Example
 ///
 /// ```
@@ -41,6 +55,12 @@ pub struct ModelConfig {
     pub decision: Model,
     /// Models for review phases: plan review, action review, final review.
     pub review: Vec<Model>,
+    /// Model consulted only when a plan review ends in an exact tie.
+    ///
+    /// `None` keeps the default behavior (a tie does not reach a majority
+    /// and the plan is sent back for revision).
+    #[serde(default)]
+    pub tie_breaker: Option<Model>,
 
     // ==================== Interaction Roles ====================
     /// Models participating in Quorum Discussion.
@@ -49,6 +69,16 @@ pub struct ModelConfig {
     pub moderator: Model,
     /// Model for Ask (lightweight Q&A) interaction.
     pub ask: Model,
+
+    // ==================== Prompt Tailoring ====================
+    /// Model-specific system prompt prefixes (`models.prompts`).
+    #[serde(default)]
+    pub system_prompt_overrides: HashMap<Model, String>,
+
+    // ==================== Aliases ====================
+    /// User-defined model aliases (`models.aliases`), keyed by lowercase name.
+    #[serde(default)]
+    pub aliases: BTreeMap<String, Model>,
 }
 
 impl Default for ModelConfig {
@@ -57,9 +87,12 @@ impl Default for ModelConfig {
             exploration: Model::ClaudeHaiku45,
             decision: Model::ClaudeSonnet45,
             review: vec![Model::ClaudeSonnet45, Model::Gpt53Codex],
+            tie_breaker: None,
             participants: vec![Model::ClaudeSonnet45, Model::Gpt53Codex],
             moderator: Model::ClaudeSonnet45,
             ask: Model::ClaudeSonnet45,
+            system_prompt_overrides: HashMap::new(),
+            aliases: BTreeMap::new(),
         }
     }
 }
@@ -82,6 +115,11 @@ impl ModelConfig {
         self
     }
 
+    pub fn with_tie_breaker(mut self, model: Option<Model>) -> Self {
+        self.tie_breaker = model;
+        self
+    }
+
     pub fn with_participants(mut self, models: Vec<Model>) -> Self {
         self.participants = models;
         self
@@ -96,6 +134,105 @@ impl ModelConfig {
         self.ask = model;
         self
     }
+
+    pub fn with_system_prompt_override(mut self, model: Model, prompt: impl Into<String>) -> Self {
+        self.system_prompt_overrides.insert(model, prompt.into());
+        self
+    }
+
+    pub fn with_alias(mut self, alias: impl AsRef<str>, model: Model) -> Self {
+        self.aliases
+            .insert(alias.as_ref().trim().to_ascii_lowercase(), model);
+        self
+    }
+
+    // ==================== Aliases ====================
+
+    /// Resolve a model name, consulting user aliases (case-insensitive)
+    /// before falling back to [`Model::from_str`](std::str::FromStr), which
+    /// handles built-in names and aliases.
+    pub fn resolve(&self, name: &str) -> Result<Model, ModelParseError> {
+        match self.aliases.get(&name.trim().to_ascii_lowercase()) {
+            Some(model) => Ok(model.clone()),
+            None => name.parse(),
+        }
+    }
+
+    // ==================== Prompt Tailoring ====================
+
+    /// The system prompt to send to `model`: its override (if any) followed
+    /// by `base`, separated by a blank line. `base` is returned unchanged
+    /// when `model` has no override.
+    pub fn system_prompt_for(&self, model: &Model, base: &str) -> String {
+        match self.system_prompt_overrides.get(model) {
+            Some(prefix) if base.is_empty() => prefix.clone(),
+            Some(prefix) => format!("{}\n\n{}", prefix, base),
+            None => base.to_string(),
+        }
+    }
+
+    // ==================== Validation ====================
+
+    /// Detect role assignments that are usually a mistake.
+    ///
+    /// All issues are [`Severity::Warning`]: the configuration still runs.
+    /// - `exploration` equal to `decision` or `moderator` (the cheap
+    ///   exploration model is meant to differ from the high-performance roles)
+    /// - a model listed more than once in `review` (its vote counts twice)
+    ///
+    /// A review list containing the decision model is not flagged: the
+    /// default configuration does exactly that.
+    pub fn validate_roles(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        for (field, model) in [
+            ("models.decision", &self.decision),
+            ("models.moderator", &self.moderator),
+        ] {
+            if *model == self.exploration {
+                issues.push(redundant_role(
+                    field,
+                    model,
+                    format!(
+                        "{} uses the exploration model '{}'; exploration is meant to be \
+                         a cheaper model than the other roles",
+                        field, model
+                    ),
+                ));
+            }
+        }
+
+        let mut seen: Vec<&Model> = Vec::new();
+        let mut reported: Vec<&Model> = Vec::new();
+        for model in &self.review {
+            if !seen.contains(&model) {
+                seen.push(model);
+            } else if !reported.contains(&model) {
+                reported.push(model);
+                issues.push(redundant_role(
+                    "models.review",
+                    model,
+                    format!(
+                        "models.review lists '{}' more than once; its vote would count twice",
+                        model
+                    ),
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+fn redundant_role(field: &str, model: &Model, message: String) -> ConfigIssue {
+    ConfigIssue {
+        severity: Severity::Warning,
+        code: ConfigIssueCode::RedundantModelRole {
+            field: field.to_string(),
+            model: model.to_string(),
+        },
+        message,
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +245,7 @@ mod tests {
         assert_eq!(config.exploration, Model::ClaudeHaiku45);
         assert_eq!(config.decision, Model::ClaudeSonnet45);
         assert_eq!(config.review.len(), 2);
+        assert_eq!(config.tie_breaker, None);
         // Interaction roles
         assert_eq!(config.participants.len(), 2);
         assert_eq!(config.moderator, Model::ClaudeSonnet45);
@@ -119,11 +257,13 @@ mod tests {
         let config = ModelConfig::default()
             .with_exploration(Model::ClaudeSonnet45)
             .with_decision(Model::ClaudeOpus45)
-            .with_review(vec![Model::ClaudeOpus45]);
+            .with_review(vec![Model::ClaudeOpus45])
+            .with_tie_breaker(Some(Model::Gemini3Pro));
 
         assert_eq!(config.exploration, Model::ClaudeSonnet45);
         assert_eq!(config.decision, Model::ClaudeOpus45);
         assert_eq!(config.review, vec![Model::ClaudeOpus45]);
+        assert_eq!(config.tie_breaker, Some(Model::Gemini3Pro));
     }
 
     #[test]
@@ -140,4 +280,75 @@ mod tests {
         assert_eq!(config.moderator, Model::ClaudeOpus45);
         assert_eq!(config.ask, Model::Gpt52Codex);
     }
+
+    #[test]
+    fn test_system_prompt_for() {
+        let config = ModelConfig::default()
+            .with_system_prompt_override(Model::Gpt53Codex, "Answer tersely.");
+
+        assert_eq!(
+            config.system_prompt_for(&Model::Gpt53Codex, "You are a reviewer."),
+            "Answer tersely.\n\nYou are a reviewer."
+        );
+        assert_eq!(
+            config.system_prompt_for(&Model::ClaudeSonnet45, "You are a reviewer."),
+            "You are a reviewer."
+        );
+        assert_eq!(
+            config.system_prompt_for(&Model::Gpt53Codex, ""),
+            "Answer tersely."
+        );
+    }
+
+    #[test]
+    fn test_resolve_user_alias_overrides_builtin() {
+        let config = ModelConfig::default().with_alias("Sonnet", Model::ClaudeSonnet45);
+
+        assert_eq!(config.resolve("sonnet").unwrap(), Model::ClaudeSonnet45);
+        assert_eq!(config.resolve("SONNET").unwrap(), Model::ClaudeSonnet45);
+        assert_eq!(config.resolve("opus").unwrap(), Model::ClaudeOpus46);
+        assert!(config.resolve("claude-sonet-4.5").is_err());
+    }
+
+    #[test]
+    fn test_validate_roles_default_is_clean() {
+        assert!(ModelConfig::default().validate_roles().is_empty());
+    }
+
+    #[test]
+    fn test_validate_roles_duplicate_review_entry() {
+        let config = ModelConfig::default().with_review(vec![
+            Model::ClaudeSonnet45,
+            Model::Gpt53Codex,
+            Model::ClaudeSonnet45,
+            Model::ClaudeSonnet45,
+        ]);
+        let issues = config.validate_roles();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert_eq!(
+            issues[0].code,
+            ConfigIssueCode::RedundantModelRole {
+                field: "models.review".to_string(),
+                model: Model::ClaudeSonnet45.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_validate_roles_exploration_equals_decision() {
+        let config = ModelConfig::default()
+            .with_exploration(Model::ClaudeSonnet45)
+            .with_moderator(Model::Gpt53Codex);
+        let issues = config.validate_roles();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert_eq!(
+            issues[0].code,
+            ConfigIssueCode::RedundantModelRole {
+                field: "models.decision".to_string(),
+                model: Model::ClaudeSonnet45.to_string(),
+            }
+        );
+    }
 }
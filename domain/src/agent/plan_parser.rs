@@ -11,11 +11,48 @@ use crate::agent::entities::{Plan, Task};
 use crate::context::ContextMode;
 use crate::session::response::{ContentBlock, LlmResponse};
 
+/// Error returned when a parsed plan is structurally invalid or violates a limit.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PlanParseError {
+    /// The plan has more tasks than `agent.max_tasks_per_plan` allows.
+    #[error(
+        "plan has {count} tasks but at most {limit} are allowed; consolidate related steps into fewer tasks"
+    )]
+    TooManyTasks { count: usize, limit: usize },
+    /// A task's `depends_on` names a task ID that is not in the plan.
+    #[error(
+        "task '{task}' depends on '{dependency}', which is not a task in the plan; depends_on may only reference task ids defined in 'tasks'"
+    )]
+    DanglingDependency { task: String, dependency: String },
+}
+
 /// Extract a plan from a structured [`LlmResponse`].
 ///
 /// 1. First looks for a `create_plan` ToolUse block (Native Tool Use path).
 /// 2. Falls back to text-based [`parse_plan()`] (for providers that don't support tool use).
-pub fn extract_plan_from_response(response: &LlmResponse) -> Option<Plan> {
+///
+/// A plan with more than `max_tasks` tasks is rejected with
+/// [`PlanParseError::TooManyTasks`]; `None` means no limit. A dependency on a
+/// task that does not exist is rejected with
+/// [`PlanParseError::DanglingDependency`]. Returns `Ok(None)` when the
+/// response contains no plan.
+pub fn extract_plan_from_response(
+    response: &LlmResponse,
+    max_tasks: Option<usize>,
+) -> Result<Option<Plan>, PlanParseError> {
+    let plan = extract_unchecked(response)?;
+    if let (Some(plan), Some(limit)) = (&plan, max_tasks)
+        && plan.tasks.len() > limit
+    {
+        return Err(PlanParseError::TooManyTasks {
+            count: plan.tasks.len(),
+            limit,
+        });
+    }
+    Ok(plan)
+}
+
+fn extract_unchecked(response: &LlmResponse) -> Result<Option<Plan>, PlanParseError> {
     // 1. Look for create_plan tool call
     for block in &response.content {
         if let ContentBlock::ToolUse { name, input, .. } = block
@@ -24,17 +61,17 @@ pub fn extract_plan_from_response(response: &LlmResponse) -> Option<Plan> {
             let json = serde_json::Value::Object(
                 input.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
             );
-            return parse_plan_json(&json);
+            return try_parse_plan_json(&json);
         }
     }
 
     // 2. Fallback: parse from text content (for providers without Native Tool Use)
     let text = response.text_content();
     if !text.is_empty() {
-        return parse_plan(&text);
+        return try_parse_plan(&text);
     }
 
-    None
+    Ok(None)
 }
 
 /// Parse a plan from model response text.
@@ -45,6 +82,10 @@ pub fn extract_plan_from_response(response: &LlmResponse) -> Option<Plan> {
 ///
 /// Returns `None` if no valid plan is found, or if the plan has no tasks.
 pub fn parse_plan(response: &str) -> Option<Plan> {
+    try_parse_plan(response).ok().flatten()
+}
+
+fn try_parse_plan(response: &str) -> Result<Option<Plan>, PlanParseError> {
     // Look for ```plan ... ``` blocks
     let mut in_plan_block = false;
     let mut current_block = String::new();
@@ -56,7 +97,7 @@ pub fn parse_plan(response: &str) -> Option<Plan> {
         } else if in_plan_block && line.trim() == "```" {
             in_plan_block = false;
             if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&current_block) {
-                return parse_plan_json(&parsed);
+                return try_parse_plan_json(&parsed);
             }
         } else if in_plan_block {
             current_block.push_str(line);
@@ -66,11 +107,11 @@ pub fn parse_plan(response: &str) -> Option<Plan> {
 
     // Try parsing the entire response as JSON
     if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(response) {
-        return parse_plan_json(&parsed);
+        return try_parse_plan_json(&parsed);
     }
 
     // No valid plan found — caller should handle the error
-    None
+    Ok(None)
 }
 
 /// JSON 値を文字列に変換（数値・bool も文字列化、null・空文字は None）
@@ -101,25 +142,60 @@ fn json_value_to_string(value: &serde_json::Value) -> Option<String> {
 /// }
 /// ```
 ///
-/// Returns `None` if required fields are missing or tasks array is empty.
+/// Task IDs are normalized to `"1"`, `"2"`, ... in task order, and
+/// `depends_on` references are rewritten to match. A reference to an ID
+/// used by several tasks resolves to the closest earlier one (or the first,
+/// if none precedes it); a task without an ID is referenced by its position.
+///
+/// Returns `None` if required fields are missing, the tasks array is empty,
+/// or a dependency references a task that does not exist.
 pub fn parse_plan_json(json: &serde_json::Value) -> Option<Plan> {
-    let objective = json.get("objective")?.as_str()?;
+    try_parse_plan_json(json).ok().flatten()
+}
+
+/// [`parse_plan_json`] that tells a dangling dependency apart from a missing
+/// plan, so the caller can report it back to the model.
+fn try_parse_plan_json(json: &serde_json::Value) -> Result<Option<Plan>, PlanParseError> {
+    let Some(objective) = json.get("objective").and_then(|v| v.as_str()) else {
+        return Ok(None);
+    };
     let reasoning = json.get("reasoning").and_then(|v| v.as_str()).unwrap_or("");
 
     let mut plan = Plan::new(objective, reasoning);
 
-    let tasks = json.get("tasks").and_then(|v| v.as_array())?;
+    let Some(tasks) = json.get("tasks").and_then(|v| v.as_array()) else {
+        return Ok(None);
+    };
 
     // Empty tasks array is not a valid plan
     if tasks.is_empty() {
-        return None;
+        return Ok(None);
     }
 
+    let original_ids: Vec<String> = tasks
+        .iter()
+        .enumerate()
+        .map(|(index, task_json)| {
+            task_json
+                .get("id")
+                .and_then(json_value_to_string)
+                .unwrap_or_else(|| format!("{}", index + 1))
+        })
+        .collect();
+    let resolve = |index: usize, reference: &str| -> Result<String, PlanParseError> {
+        let position = original_ids[..index]
+            .iter()
+            .rposition(|id| id == reference)
+            .or_else(|| original_ids.iter().position(|id| id == reference))
+            .ok_or_else(|| PlanParseError::DanglingDependency {
+                task: original_ids[index].clone(),
+                dependency: reference.to_string(),
+            })?;
+        Ok(format!("{}", position + 1))
+    };
+
     for (index, task_json) in tasks.iter().enumerate() {
-        let id = task_json
-            .get("id")
-            .and_then(json_value_to_string)
-            .unwrap_or_else(|| format!("{}", index + 1));
+        let id = format!("{}", index + 1);
         let description = task_json
             .get("description")
             .and_then(|v| v.as_str())
@@ -143,7 +219,7 @@ pub fn parse_plan_json(json: &serde_json::Value) -> Option<Plan> {
         if let Some(deps) = task_json.get("depends_on").and_then(|v| v.as_array()) {
             for dep in deps {
                 if let Some(dep_id) = json_value_to_string(dep) {
-                    task = task.with_dependency(dep_id);
+                    task = task.with_dependency(resolve(index, &dep_id)?);
                 }
             }
         }
@@ -163,7 +239,7 @@ pub fn parse_plan_json(json: &serde_json::Value) -> Option<Plan> {
         plan.add_task(task);
     }
 
-    Some(plan)
+    Ok(Some(plan))
 }
 
 #[cfg(test)]
@@ -302,10 +378,12 @@ Here's my plan:
             ],
             stop_reason: Some(StopReason::ToolUse),
             model: None,
+            usage: None,
         };
 
-        let plan =
-            extract_plan_from_response(&response).expect("should extract plan from tool call");
+        let plan = extract_plan_from_response(&response, None)
+            .unwrap()
+            .expect("should extract plan from tool call");
         assert_eq!(plan.objective, "Fix the bug");
         assert_eq!(plan.reasoning, "Bug is critical");
         assert_eq!(plan.tasks.len(), 2);
@@ -326,8 +404,9 @@ Here's my plan:
 ```"#;
         let response = LlmResponse::from_text(text_plan);
 
-        let plan =
-            extract_plan_from_response(&response).expect("should extract plan from text fallback");
+        let plan = extract_plan_from_response(&response, None)
+            .unwrap()
+            .expect("should extract plan from text fallback");
         assert_eq!(plan.objective, "Update README");
         assert_eq!(plan.tasks.len(), 1);
     }
@@ -335,7 +414,48 @@ Here's my plan:
     #[test]
     fn test_extract_plan_from_response_no_plan() {
         let response = LlmResponse::from_text("I'll think about this.");
-        assert!(extract_plan_from_response(&response).is_none());
+        assert!(
+            extract_plan_from_response(&response, None)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    fn three_task_plan_response() -> LlmResponse {
+        LlmResponse::from_text(
+            r#"{"objective": "Refactor", "tasks": [
+                {"id": "1", "description": "One"},
+                {"id": "2", "description": "Two"},
+                {"id": "3", "description": "Three"}
+            ]}"#,
+        )
+    }
+
+    #[test]
+    fn test_extract_plan_over_task_limit_fails() {
+        let err = extract_plan_from_response(&three_task_plan_response(), Some(2)).unwrap_err();
+        assert_eq!(err, PlanParseError::TooManyTasks { count: 3, limit: 2 });
+        assert!(err.to_string().contains("plan has 3 tasks but at most 2"));
+    }
+
+    #[test]
+    fn test_extract_plan_within_task_limit_passes() {
+        let plan = extract_plan_from_response(&three_task_plan_response(), Some(3))
+            .unwrap()
+            .expect("plan at the limit is accepted");
+        assert_eq!(plan.tasks.len(), 3);
+        // No limit, and no plan at all, are not errors
+        assert!(
+            extract_plan_from_response(&three_task_plan_response(), None)
+                .unwrap()
+                .is_some()
+        );
+        let text = LlmResponse::from_text("No plan here.");
+        assert!(
+            extract_plan_from_response(&text, Some(1))
+                .unwrap()
+                .is_none()
+        );
     }
 
     #[test]
@@ -398,11 +518,71 @@ Here's my plan:
             ]
         });
         let plan = parse_plan_json(&json).unwrap();
-        assert_eq!(plan.tasks[0].id, TaskId::new("alpha"));
-        assert_eq!(plan.tasks[1].id, TaskId::new("42"));
+        assert_eq!(plan.tasks[0].id, TaskId::new("1"));
+        assert_eq!(plan.tasks[1].id, TaskId::new("2"));
         assert_eq!(plan.tasks[2].id, TaskId::new("3"));
     }
 
+    #[test]
+    fn test_parse_plan_duplicate_ids_are_deduplicated() {
+        let json = serde_json::json!({
+            "objective": "Duplicates",
+            "tasks": [
+                {"id": "a", "description": "First a"},
+                {"id": "a", "description": "Second a", "depends_on": ["a"]},
+                {"id": "b", "description": "Uses a", "depends_on": ["a"]}
+            ]
+        });
+        let plan = parse_plan_json(&json).unwrap();
+        let ids: Vec<&str> = plan.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+        // References resolve to the closest earlier task with that ID
+        assert_eq!(plan.tasks[1].depends_on, vec![TaskId::new("1")]);
+        assert_eq!(plan.tasks[2].depends_on, vec![TaskId::new("2")]);
+    }
+
+    #[test]
+    fn test_parse_plan_dangling_dependency_rejected() {
+        let json = serde_json::json!({
+            "objective": "Dangling",
+            "tasks": [
+                {"id": "1", "description": "Read"},
+                {"id": "2", "description": "Write", "depends_on": ["7"]}
+            ]
+        });
+        assert!(parse_plan_json(&json).is_none());
+
+        let response = LlmResponse::from_text(format!("```plan\n{}\n```", json));
+        assert_eq!(
+            extract_plan_from_response(&response, None).unwrap_err(),
+            PlanParseError::DanglingDependency {
+                task: "2".to_string(),
+                dependency: "7".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_plan_depends_on_remapped_after_renumbering() {
+        let json = serde_json::json!({
+            "objective": "Renumber",
+            "tasks": [
+                {"id": "read", "description": "Read"},
+                {"id": 10, "description": "Test", "depends_on": ["write"]},
+                {"id": "write", "description": "Write", "depends_on": ["read"]},
+                {"description": "Report", "depends_on": [10, "write"]}
+            ]
+        });
+        let plan = parse_plan_json(&json).unwrap();
+        assert_eq!(plan.tasks[1].id, TaskId::new("2"));
+        assert_eq!(plan.tasks[1].depends_on, vec![TaskId::new("3")]);
+        assert_eq!(plan.tasks[2].depends_on, vec![TaskId::new("1")]);
+        assert_eq!(
+            plan.tasks[3].depends_on,
+            vec![TaskId::new("2"), TaskId::new("3")]
+        );
+    }
+
     #[test]
     fn test_parse_plan_context_mode() {
         use crate::context::ContextMode;
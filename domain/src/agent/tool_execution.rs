@@ -231,6 +231,30 @@ impl ToolExecution {
     }
 }
 
+/// Per-tool call counts accumulated over an agent run.
+///
+/// `calls` counts every tracked execution; `successes` and `failures` count
+/// those that reached [`Completed`](ToolExecutionState::Completed) or
+/// [`Error`](ToolExecutionState::Error) (blocked and rejected calls are failures).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolStat {
+    pub calls: usize,
+    pub successes: usize,
+    pub failures: usize,
+}
+
+impl ToolStat {
+    /// Count one execution.
+    pub fn record(&mut self, execution: &ToolExecution) {
+        self.calls += 1;
+        match execution.state {
+            ToolExecutionState::Completed { .. } => self.successes += 1,
+            ToolExecutionState::Error { .. } => self.failures += 1,
+            _ => {}
+        }
+    }
+}
+
 /// Truncate a string for preview display.
 fn truncate_preview(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
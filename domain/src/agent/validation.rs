@@ -48,6 +48,11 @@ pub enum ConfigIssueCode {
     /// A numeric constraint between config fields is violated
     /// (e.g. max_total_bytes < max_entry_bytes).
     InvalidConstraint { field: String },
+    /// A model is assigned to a role it likely should not share
+    /// (e.g. exploration == decision, or a duplicate review entry).
+    RedundantModelRole { field: String, model: String },
+    /// A renamed config key was used; its value was applied to `replacement`.
+    DeprecatedKey { key: String, replacement: String },
 }
 
 /// A detected issue in the configuration combination.
@@ -14,6 +14,7 @@
 //! - [`Thought`] - A recorded reasoning step from the agent
 //! - [`ThoughtType`] - Categories of agent thoughts (analysis, planning, etc.)
 
+use crate::context::DecisionRecord;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -142,6 +143,10 @@ pub struct AgentContext {
     pub structure_summary: Option<String>,
     /// Additional context gathered
     pub additional: HashMap<String, String>,
+    /// Recently approved plans from earlier sessions, oldest first.
+    /// Only the planning prompt shows them.
+    #[serde(default)]
+    pub prior_decisions: Vec<DecisionRecord>,
 }
 
 impl AgentContext {
@@ -172,11 +177,30 @@ impl AgentContext {
         self.structure_summary = Some(summary.into());
     }
 
+    /// Appends text the user supplied directly (e.g. `--context`) to the
+    /// structure summary, marked as user-supplied.
+    pub fn append_user_context(&mut self, text: &str) {
+        let note = format!("[User-supplied context]\n{}", text.trim());
+        self.structure_summary = Some(match self.structure_summary.take() {
+            Some(summary) if !summary.trim().is_empty() => format!("{}\n\n{}", summary, note),
+            _ => note,
+        });
+    }
+
     /// Adds arbitrary key-value context information.
     pub fn add_context(&mut self, key: impl Into<String>, value: impl Into<String>) {
         self.additional.insert(key.into(), value.into());
     }
 
+    /// Whether nothing has been gathered.
+    pub fn is_empty(&self) -> bool {
+        self.project_root.is_none()
+            && self.project_type.is_none()
+            && self.key_files.is_empty()
+            && self.structure_summary.is_none()
+            && self.additional.is_empty()
+    }
+
     /// Formats all gathered context as a string for use in LLM prompts.
     pub fn to_prompt_context(&self) -> String {
         let mut parts = Vec::new();
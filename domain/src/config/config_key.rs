@@ -36,7 +36,61 @@ pub fn lookup_key(key: &str) -> Option<&'static ConfigKeyInfo> {
     KNOWN_KEYS.iter().find(|k| k.key == key)
 }
 
-static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
+/// A config key that was renamed, mapped to its current name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecatedKey {
+    /// The old dotted key path (e.g. `"quorum.rule"`).
+    pub key: &'static str,
+    /// The known key that replaces it.
+    pub replacement: &'static str,
+}
+
+/// Renamed keys from older configurations (mostly the removed TOML
+/// sections), so old settings are migrated instead of silently ignored.
+pub fn deprecated_keys() -> &'static [DeprecatedKey] {
+    &DEPRECATED_KEYS
+}
+
+/// Look up a deprecated key by its old dotted path.
+pub fn lookup_deprecated(key: &str) -> Option<&'static DeprecatedKey> {
+    DEPRECATED_KEYS.iter().find(|k| k.key == key)
+}
+
+static DEPRECATED_KEYS: [DeprecatedKey; 7] = [
+    // [agent] model fields before the role split into models.*
+    DeprecatedKey {
+        key: "agent.exploration_model",
+        replacement: "models.exploration",
+    },
+    DeprecatedKey {
+        key: "agent.decision_model",
+        replacement: "models.decision",
+    },
+    DeprecatedKey {
+        key: "agent.review_models",
+        replacement: "models.review",
+    },
+    // [quorum] section
+    DeprecatedKey {
+        key: "quorum.rule",
+        replacement: "agent.quorum_rule",
+    },
+    DeprecatedKey {
+        key: "quorum.moderator",
+        replacement: "models.moderator",
+    },
+    // [council] section
+    DeprecatedKey {
+        key: "council.models",
+        replacement: "models.participants",
+    },
+    DeprecatedKey {
+        key: "council.moderator",
+        replacement: "models.moderator",
+    },
+];
+
+static KNOWN_KEYS: [ConfigKeyInfo; 71] = [
     // ==================== agent.* (SessionMode + AgentPolicy) ====================
     ConfigKeyInfo {
         key: "agent.consensus_level",
@@ -60,7 +114,25 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         key: "agent.hil_mode",
         description: "Human-in-the-loop mode",
         mutability: Mutability::Mutable,
-        valid_values: &["interactive", "auto_reject", "auto_approve"],
+        valid_values: &["interactive", "auto_reject", "auto_approve", "batch_review"],
+    },
+    ConfigKeyInfo {
+        key: "agent.hil_timeout_secs",
+        description: "Seconds a human intervention prompt waits before the default decision (0 = wait forever)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.hil_timeout_default",
+        description: "Decision taken when a human intervention prompt times out: reject or approve",
+        mutability: Mutability::Mutable,
+        valid_values: &["reject", "approve"],
+    },
+    ConfigKeyInfo {
+        key: "agent.gather_context",
+        description: "Run the context gathering phase before planning (false = start with empty context)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
     },
     ConfigKeyInfo {
         key: "agent.max_plan_revisions",
@@ -68,6 +140,72 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &[],
     },
+    ConfigKeyInfo {
+        key: "agent.max_tasks_per_plan",
+        description: "Reject generated plans with more tasks than this and ask for a consolidated plan (0 = no limit)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.ensemble_selection",
+        description: "How ensemble planning picks the plan: winner (top-scored) or merge (moderator merges the top candidates)",
+        mutability: Mutability::Mutable,
+        valid_values: &["winner", "merge"],
+    },
+    ConfigKeyInfo {
+        key: "agent.on_task_failure",
+        description: "After a failed task: abort, continue_independent, or continue_all",
+        mutability: Mutability::Mutable,
+        valid_values: &["abort", "continue_independent", "continue_all"],
+    },
+    ConfigKeyInfo {
+        key: "agent.max_action_retries",
+        description: "Retries per task after review rejects its actions (0 = fail on the first rejection)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.max_final_review_fixes",
+        description: "Corrective execution cycles a rejected final review may trigger",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.max_cost_tokens",
+        description: "Stop the agent once cumulative token usage exceeds this (0 = no limit)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.quorum_rule",
+        description: "Plan/action review vote rule: majority, unanimous, supermajority[:N/M], atleast:N, or N%",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.consensus_mode",
+        description: "Plan review decision: binary (vote count) or score[:N] (mean score above N, default 6.0)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.min_reviewers_high_risk",
+        description: "Minimum distinct reviewers for high-risk actions (0 = no floor)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.command_allowlist",
+        description: "Glob patterns run_command commands must match (empty = no restriction)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "agent.command_denylist",
+        description: "Glob patterns for run_command commands that are never executed",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
     // ==================== debate.* (DebateConfig) ====================
     ConfigKeyInfo {
         key: "debate.models",
@@ -112,6 +250,12 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &[],
     },
+    ConfigKeyInfo {
+        key: "models.tie_breaker",
+        description: "Model casting the deciding vote on a tied plan review (empty = none)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
     ConfigKeyInfo {
         key: "models.participants",
         description: "Models for Quorum Discussion",
@@ -130,6 +274,18 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &[],
     },
+    ConfigKeyInfo {
+        key: "models.prompts",
+        description: "Per-model system prompt prefixes (model = prompt)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "models.aliases",
+        description: "Model aliases (alias = model), checked before built-in aliases",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
     // ==================== execution.* (ExecutionParams) ====================
     ConfigKeyInfo {
         key: "execution.max_iterations",
@@ -143,6 +299,18 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &[],
     },
+    ConfigKeyInfo {
+        key: "execution.ask_max_tool_turns",
+        description: "Maximum tool call turns for Ask (defaults to execution.max_tool_turns)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "execution.max_parallel_tasks",
+        description: "Maximum independent plan tasks executed concurrently",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
     // ==================== output.* ====================
     ConfigKeyInfo {
         key: "output.format",
@@ -188,6 +356,43 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &[],
     },
+    ConfigKeyInfo {
+        key: "context_budget.per_tool_output_chars",
+        description: "Maximum characters of a single tool output sent to the LLM",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    // ==================== context.* (ContextFileLimits) ====================
+    ConfigKeyInfo {
+        key: "context.max_file_bytes",
+        description: "Maximum bytes loaded from a single context file (larger files are truncated)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "context.max_total_bytes",
+        description: "Maximum bytes loaded across all context files (lower-priority files are skipped)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "context.pinned_files",
+        description: "Files always loaded into the project context (relative to the working directory)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "context.reference_cache_ttl_secs",
+        description: "Seconds a resolved issue/PR reference is reused within a session (0 = no caching)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "context.decision_log",
+        description: "Record approved plans in .quorum/decisions.jsonl and feed recent ones into planning",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
     // ==================== tui.input.* ====================
     ConfigKeyInfo {
         key: "tui.input.submit_key",
@@ -244,6 +449,75 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &[],
     },
+    ConfigKeyInfo {
+        key: "tui.layout.discuss_preset",
+        description: "Layout preset while a Discuss interaction runs (empty: keep current)",
+        mutability: Mutability::Mutable,
+        valid_values: &["", "default", "minimal", "wide", "stacked"],
+    },
+    // ==================== tui.* (accessibility / routing) ====================
+    ConfigKeyInfo {
+        key: "tui.ascii_mode",
+        description: "ASCII borders and no color styling in the TUI",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "tui.intent_routing",
+        description: "Form hint for untyped TUI input: off, suggest (hint only) or auto (start the suggested form)",
+        mutability: Mutability::Mutable,
+        valid_values: &["off", "suggest", "auto"],
+    },
+    // ==================== tools.* ====================
+    ConfigKeyInfo {
+        key: "tools.risk_overrides",
+        description: "Risk level per run_command prefix, overriding the built-in classification (prefix = low|high)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "tools.default_timeout_secs",
+        description: "Time limit in seconds for any tool call without its own entry in tools.timeouts",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "tools.timeouts",
+        description: "Per-tool time limits in seconds (tool name = seconds)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    // ==================== interaction.* ====================
+    ConfigKeyInfo {
+        key: "interaction.max_nesting_depth",
+        description: "How many levels of child interactions may nest below the root (0 disables nesting)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
+    ConfigKeyInfo {
+        key: "interaction.agent.context_mode",
+        description: "Context mode for new Agent interactions (default: full)",
+        mutability: Mutability::Mutable,
+        valid_values: &["full", "projected", "fresh"],
+    },
+    ConfigKeyInfo {
+        key: "interaction.ask.context_mode",
+        description: "Context mode for new Ask interactions (default: projected)",
+        mutability: Mutability::Mutable,
+        valid_values: &["full", "projected", "fresh"],
+    },
+    ConfigKeyInfo {
+        key: "interaction.discuss.context_mode",
+        description: "Context mode for new Discuss interactions (default: full)",
+        mutability: Mutability::Mutable,
+        valid_values: &["full", "projected", "fresh"],
+    },
+    ConfigKeyInfo {
+        key: "interaction.review.context_mode",
+        description: "Context mode for new Review interactions (default: fresh)",
+        mutability: Mutability::Mutable,
+        valid_values: &["full", "projected", "fresh"],
+    },
     // ==================== supervisor.* ====================
     ConfigKeyInfo {
         key: "supervisor.reporter",
@@ -251,12 +525,37 @@ static KNOWN_KEYS: [ConfigKeyInfo; 34] = [
         mutability: Mutability::Mutable,
         valid_values: &["auto", "none"],
     },
+    // ==================== logging.* ====================
+    ConfigKeyInfo {
+        key: "logging.redact_patterns",
+        description: "Extra regexes redacted from conversation logs (in addition to built-in secret shapes)",
+        mutability: Mutability::Mutable,
+        valid_values: &[],
+    },
 ];
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_deprecated_keys_map_to_known_keys() {
+        for dep in deprecated_keys() {
+            assert!(
+                lookup_key(dep.replacement).is_some(),
+                "{} -> {} is not a known key",
+                dep.key,
+                dep.replacement
+            );
+            assert!(lookup_key(dep.key).is_none(), "{} is still known", dep.key);
+        }
+        assert_eq!(
+            lookup_deprecated("quorum.rule").map(|d| d.replacement),
+            Some("agent.quorum_rule")
+        );
+        assert!(lookup_deprecated("agent.quorum_rule").is_none());
+    }
+
     #[test]
     fn test_known_keys_not_empty() {
         assert!(!known_keys().is_empty());
@@ -279,12 +578,12 @@ mod tests {
 
     #[test]
     fn test_all_keys_mutable() {
-        // All 34 keys are mutable
+        // All 71 keys are mutable
         let mutable: Vec<_> = known_keys()
             .iter()
             .filter(|k| k.mutability == Mutability::Mutable)
             .collect();
-        assert_eq!(mutable.len(), 34);
+        assert_eq!(mutable.len(), 71);
     }
 
     #[test]
@@ -309,8 +608,10 @@ mod tests {
     #[test]
     fn test_new_model_keys() {
         assert!(lookup_key("models.participants").is_some());
+        assert!(lookup_key("models.tie_breaker").is_some());
         assert!(lookup_key("models.moderator").is_some());
         assert!(lookup_key("models.ask").is_some());
+        assert!(lookup_key("models.prompts").is_some());
     }
 
     #[test]
@@ -325,6 +626,14 @@ mod tests {
         assert!(lookup_key("context_budget.max_entry_bytes").is_some());
         assert!(lookup_key("context_budget.max_total_bytes").is_some());
         assert!(lookup_key("context_budget.recent_full_count").is_some());
+        assert!(lookup_key("context_budget.per_tool_output_chars").is_some());
+    }
+
+    #[test]
+    fn test_context_keys() {
+        assert!(lookup_key("context.max_file_bytes").is_some());
+        assert!(lookup_key("context.max_total_bytes").is_some());
+        assert!(lookup_key("context.pinned_files").is_some());
     }
 
     #[test]
@@ -348,6 +657,10 @@ mod tests {
         assert!(preset.valid_values.contains(&"wide"));
         assert!(preset.valid_values.contains(&"stacked"));
         assert!(lookup_key("tui.layout.flex_threshold").is_some());
+        assert!(lookup_key("tui.layout.discuss_preset").is_some());
+        assert!(lookup_key("tui.ascii_mode").is_some());
+        let routing = lookup_key("tui.intent_routing").unwrap();
+        assert!(routing.valid_values.contains(&"suggest"));
     }
 
     #[test]
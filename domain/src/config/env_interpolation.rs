@@ -0,0 +1,95 @@
+//! `${VAR}` environment variable interpolation for config strings.
+//!
+//! Supports `${VAR}` and `${VAR:-default}`. A `$` not followed by `{`, or a
+//! `${` without a closing `}`, is kept literally. The variable lookup is
+//! injected so the domain stays free of process state.
+
+/// Expand `${VAR}` / `${VAR:-default}` in `input` using `lookup`.
+///
+/// Returns the name of the first variable that is unset and has no default.
+/// An empty default (`${VAR:-}`) is allowed and expands to nothing.
+pub fn interpolate_env(
+    input: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            // Unterminated: keep the remainder as-is
+            out.push_str(&rest[start..]);
+            return Ok(out);
+        };
+
+        let expr = &after[..end];
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        match lookup(name).or_else(|| default.map(str::to_string)) {
+            Some(value) => out.push_str(&value),
+            None => return Err(name.to_string()),
+        }
+        rest = &after[end + 1..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/quorum".to_string()),
+            "API_KEY" => Some("sk-123".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_set_var() {
+        assert_eq!(
+            interpolate_env("${HOME}/.quorum_history", env).unwrap(),
+            "/home/quorum/.quorum_history"
+        );
+        assert_eq!(
+            interpolate_env("key=${API_KEY};home=${HOME}", env).unwrap(),
+            "key=sk-123;home=/home/quorum"
+        );
+        // A set variable wins over its default
+        assert_eq!(
+            interpolate_env("${HOME:-/tmp}", env).unwrap(),
+            "/home/quorum"
+        );
+    }
+
+    #[test]
+    fn test_unset_var_with_default() {
+        assert_eq!(
+            interpolate_env("${QUORUM_URL:-https://api.example.com}/v1", env).unwrap(),
+            "https://api.example.com/v1"
+        );
+        assert_eq!(interpolate_env("x${EMPTY:-}y", env).unwrap(), "xy");
+    }
+
+    #[test]
+    fn test_unset_var_without_default_is_an_error() {
+        assert_eq!(
+            interpolate_env("${HOME}/${MISSING}", env).unwrap_err(),
+            "MISSING"
+        );
+    }
+
+    #[test]
+    fn test_literal_text_is_untouched() {
+        assert_eq!(interpolate_env("plain", env).unwrap(), "plain");
+        assert_eq!(interpolate_env("cost: $5", env).unwrap(), "cost: $5");
+        assert_eq!(interpolate_env("open ${HOME", env).unwrap(), "open ${HOME");
+    }
+}
@@ -0,0 +1,65 @@
+//! Intent routing mode value object
+
+/// How the TUI uses [`classify_intent`](crate::interaction::classify_intent)
+/// when a message is typed without choosing an interaction form.
+///
+/// Explicit form commands (`:ask`, `:agent`, `:discuss`) always win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntentRouting {
+    /// Never classify; plain input always starts an Agent.
+    Off,
+    /// Classify and show a hint when another form looks like a better fit,
+    /// but still start an Agent.
+    #[default]
+    Suggest,
+    /// Classify and start the suggested form directly.
+    Auto,
+}
+
+impl std::str::FromStr for IntentRouting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(IntentRouting::Off),
+            "suggest" => Ok(IntentRouting::Suggest),
+            "auto" => Ok(IntentRouting::Auto),
+            _ => Err(format!(
+                "invalid intent routing '{}', valid: off, suggest, auto",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for IntentRouting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntentRouting::Off => write!(f, "off"),
+            IntentRouting::Suggest => write!(f, "suggest"),
+            IntentRouting::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_suggest() {
+        assert_eq!(IntentRouting::default(), IntentRouting::Suggest);
+    }
+
+    #[test]
+    fn test_display_matches_from_str() {
+        for mode in [
+            IntentRouting::Off,
+            IntentRouting::Suggest,
+            IntentRouting::Auto,
+        ] {
+            assert_eq!(mode.to_string().parse::<IntentRouting>().unwrap(), mode);
+        }
+        assert!("always".parse::<IntentRouting>().is_err());
+    }
+}
@@ -4,9 +4,16 @@
 //! used across multiple layers.
 
 pub mod config_key;
+mod env_interpolation;
+mod intent_routing;
 mod output_format;
 mod supervisor_reporter_mode;
 
-pub use config_key::{ConfigKeyInfo, Mutability, known_keys, lookup_key};
+pub use config_key::{
+    ConfigKeyInfo, DeprecatedKey, Mutability, deprecated_keys, known_keys, lookup_deprecated,
+    lookup_key,
+};
+pub use env_interpolation::interpolate_env;
+pub use intent_routing::IntentRouting;
 pub use output_format::OutputFormat;
 pub use supervisor_reporter_mode::SupervisorReporterMode;
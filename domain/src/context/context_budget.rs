@@ -37,16 +37,29 @@ use serde::{Deserialize, Serialize};
 
 /// Budget controlling how much task result context is retained.
 ///
-/// Three knobs:
+/// Four knobs:
 /// - `max_entry_bytes`: Maximum bytes for a single task result (head+tail truncated)
 /// - `max_total_bytes`: Maximum bytes for the entire `previous_results` buffer
 /// - `recent_full_count`: How many recent task results to keep in full
 ///   (older entries are replaced with a one-line summary)
+/// - `per_tool_output_chars`: Maximum characters of a single tool output
+///   passed back to the LLM (see [`ToolResult::truncate_output`])
+///
+/// [`ToolResult::truncate_output`]: crate::tool::value_objects::ToolResult::truncate_output
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ContextBudget {
     max_entry_bytes: usize,
     max_total_bytes: usize,
     recent_full_count: usize,
+    #[serde(default = "default_per_tool_output_chars")]
+    per_tool_output_chars: usize,
+}
+
+/// Default cap on a single tool output (10K chars).
+const DEFAULT_PER_TOOL_OUTPUT_CHARS: usize = 10_000;
+
+fn default_per_tool_output_chars() -> usize {
+    DEFAULT_PER_TOOL_OUTPUT_CHARS
 }
 
 impl ContextBudget {
@@ -73,6 +86,7 @@ impl ContextBudget {
             max_entry_bytes,
             max_total_bytes,
             recent_full_count,
+            per_tool_output_chars: DEFAULT_PER_TOOL_OUTPUT_CHARS,
         }
     }
 
@@ -88,6 +102,7 @@ impl ContextBudget {
             max_entry_bytes,
             max_total_bytes,
             recent_full_count,
+            per_tool_output_chars: DEFAULT_PER_TOOL_OUTPUT_CHARS,
         };
         let issues = candidate.validate();
         if issues.is_empty() {
@@ -108,16 +123,19 @@ impl ContextBudget {
                 max_entry_bytes: 20_000,
                 max_total_bytes: 60_000,
                 recent_full_count: 3,
+                per_tool_output_chars: DEFAULT_PER_TOOL_OUTPUT_CHARS,
             },
             ContextMode::Projected => Self {
                 max_entry_bytes: 15_000,
                 max_total_bytes: 50_000,
                 recent_full_count: 3,
+                per_tool_output_chars: DEFAULT_PER_TOOL_OUTPUT_CHARS,
             },
             ContextMode::Fresh => Self {
                 max_entry_bytes: 25_000,
                 max_total_bytes: 80_000,
                 recent_full_count: 4,
+                per_tool_output_chars: DEFAULT_PER_TOOL_OUTPUT_CHARS,
             },
         }
     }
@@ -128,6 +146,7 @@ impl ContextBudget {
             max_entry_bytes: 40_000,
             max_total_bytes: 120_000,
             recent_full_count: 5,
+            per_tool_output_chars: 20_000,
         }
     }
 
@@ -137,6 +156,7 @@ impl ContextBudget {
             max_entry_bytes: 10_000,
             max_total_bytes: 30_000,
             recent_full_count: 2,
+            per_tool_output_chars: 4_000,
         }
     }
 
@@ -146,6 +166,7 @@ impl ContextBudget {
             max_entry_bytes: usize::MAX,
             max_total_bytes: usize::MAX,
             recent_full_count: usize::MAX,
+            per_tool_output_chars: usize::MAX,
         }
     }
 
@@ -163,6 +184,10 @@ impl ContextBudget {
         self.recent_full_count
     }
 
+    pub fn per_tool_output_chars(&self) -> usize {
+        self.per_tool_output_chars
+    }
+
     // ==================== Builder Methods ====================
 
     pub fn with_max_entry_bytes(mut self, bytes: usize) -> Self {
@@ -180,6 +205,11 @@ impl ContextBudget {
         self
     }
 
+    pub fn with_per_tool_output_chars(mut self, chars: usize) -> Self {
+        self.per_tool_output_chars = chars;
+        self
+    }
+
     // ==================== Validation ====================
 
     /// Validate this budget, returning a list of issues.
@@ -187,6 +217,7 @@ impl ContextBudget {
     /// Rules:
     /// - `max_total_bytes >= max_entry_bytes`
     /// - `recent_full_count >= 1`
+    /// - `per_tool_output_chars >= 1`
     pub fn validate(&self) -> Vec<String> {
         let mut issues = Vec::new();
         if self.max_total_bytes < self.max_entry_bytes {
@@ -198,12 +229,16 @@ impl ContextBudget {
         if self.recent_full_count < 1 {
             issues.push("context_budget: recent_full_count must be >= 1".to_string());
         }
+        if self.per_tool_output_chars < 1 {
+            issues.push("context_budget: per_tool_output_chars must be >= 1".to_string());
+        }
         issues
     }
 }
 
 impl Default for ContextBudget {
-    /// Default: 20KB/entry, 60KB total, 3 recent entries kept in full.
+    /// Default: 20KB/entry, 60KB total, 3 recent entries kept in full,
+    /// 10K chars per tool output.
     fn default() -> Self {
         Self::for_context_mode(ContextMode::Full)
     }
@@ -219,6 +254,7 @@ mod tests {
         assert_eq!(budget.max_entry_bytes(), 20_000);
         assert_eq!(budget.max_total_bytes(), 60_000);
         assert_eq!(budget.recent_full_count(), 3);
+        assert_eq!(budget.per_tool_output_chars(), 10_000);
     }
 
     #[test]
@@ -261,10 +297,28 @@ mod tests {
         let budget = ContextBudget::default()
             .with_max_entry_bytes(5_000)
             .with_max_total_bytes(15_000)
-            .with_recent_full_count(2);
+            .with_recent_full_count(2)
+            .with_per_tool_output_chars(800);
         assert_eq!(budget.max_entry_bytes(), 5_000);
         assert_eq!(budget.max_total_bytes(), 15_000);
         assert_eq!(budget.recent_full_count(), 2);
+        assert_eq!(budget.per_tool_output_chars(), 800);
+    }
+
+    #[test]
+    fn test_validate_zero_per_tool_output_chars() {
+        let issues = ContextBudget::default()
+            .with_per_tool_output_chars(0)
+            .validate();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("per_tool_output_chars"));
+    }
+
+    #[test]
+    fn test_deserialize_without_per_tool_output_chars() {
+        let json = r#"{"max_entry_bytes":1,"max_total_bytes":2,"recent_full_count":1}"#;
+        let budget: ContextBudget = serde_json::from_str(json).unwrap();
+        assert_eq!(budget.per_tool_output_chars(), 10_000);
     }
 
     #[test]
@@ -0,0 +1,114 @@
+//! Decisions carried across sessions
+//!
+//! Every approved plan leaves a [`DecisionRecord`] in the project's decision
+//! log (`.quorum/decisions.jsonl`). Later runs load the most recent ones into
+//! [`AgentContext::prior_decisions`](crate::AgentContext) so planning can stay
+//! consistent with what was already agreed.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// How an approved plan reached consensus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionOutcome {
+    /// The plan review quorum voted to approve
+    QuorumApproved,
+    /// Ensemble voting selected the plan
+    EnsembleSelected,
+    /// A human approved the plan after the quorum rejected it
+    HumanApproved,
+}
+
+impl DecisionOutcome {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DecisionOutcome::QuorumApproved => "quorum_approved",
+            DecisionOutcome::EnsembleSelected => "ensemble_selected",
+            DecisionOutcome::HumanApproved => "human_approved",
+        }
+    }
+}
+
+impl FromStr for DecisionOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quorum_approved" => Ok(DecisionOutcome::QuorumApproved),
+            "ensemble_selected" => Ok(DecisionOutcome::EnsembleSelected),
+            "human_approved" => Ok(DecisionOutcome::HumanApproved),
+            _ => Err(format!("Invalid decision outcome: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for DecisionOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One approved plan, as remembered by later sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    /// The request the plan answered
+    pub request: String,
+    /// The approved plan's objective
+    pub objective: String,
+    pub outcome: DecisionOutcome,
+    /// Milliseconds since the Unix epoch
+    pub recorded_at: u64,
+}
+
+impl DecisionRecord {
+    pub fn new(
+        request: impl Into<String>,
+        objective: impl Into<String>,
+        outcome: DecisionOutcome,
+        recorded_at: u64,
+    ) -> Self {
+        Self {
+            request: request.into(),
+            objective: objective.into(),
+            outcome,
+            recorded_at,
+        }
+    }
+
+    /// One-line form used in planning prompts.
+    pub fn to_prompt_line(&self) -> String {
+        let request = crate::util::truncate_str(&self.request, 120);
+        let ellipsis = if request.len() < self.request.len() {
+            "..."
+        } else {
+            ""
+        };
+        format!(
+            "{} ({}) — request: {}{}",
+            self.objective, self.outcome, request, ellipsis
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decision_outcome_round_trip() {
+        for outcome in [
+            DecisionOutcome::QuorumApproved,
+            DecisionOutcome::EnsembleSelected,
+            DecisionOutcome::HumanApproved,
+        ] {
+            assert_eq!(outcome.as_str().parse::<DecisionOutcome>(), Ok(outcome));
+            assert_eq!(
+                serde_json::to_value(outcome).unwrap(),
+                serde_json::json!(outcome.as_str())
+            );
+        }
+        assert!("approved".parse::<DecisionOutcome>().is_err());
+    }
+}
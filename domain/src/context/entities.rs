@@ -38,6 +38,7 @@
 //! assert_eq!(ctx.project_type, Some("rust".to_string()));
 //! ```
 
+use super::project_type::detect_project_type;
 use super::value_objects::{KnownContextFile, LoadedContextFile};
 use crate::core::string::truncate;
 
@@ -87,6 +88,13 @@ pub struct ProjectContext {
     /// Combined content from all markdown files in the docs/ directory.
     pub documentation: Option<String>,
 
+    /// Files pinned via `context.pinned_files` / `--context-file`, as
+    /// `(path, content)` pairs in load order.
+    pub pinned: Vec<(String, String)>,
+
+    /// Notes about files the loader truncated or skipped due to size limits.
+    pub notes: Vec<String>,
+
     /// All loaded files.
     ///
     /// Keeps track of which files were loaded to build this context.
@@ -152,24 +160,28 @@ impl ProjectContext {
         sorted_files.sort_by_key(|f| f.file_type.priority());
 
         for file in &sorted_files {
+            if let Some(note) = file.size_note() {
+                ctx.notes.push(note);
+            }
+            if file.is_skipped() {
+                continue;
+            }
+
             // Set primary context from highest priority file
             if file.is_primary() && ctx.primary_context.is_none() {
                 ctx.primary_context = Some(file.content.clone());
                 ctx.context_source = Some(file.file_type);
             }
 
+            if file.file_type == KnownContextFile::Pinned {
+                ctx.pinned.push((file.path.clone(), file.content.clone()));
+            }
+
             // Set README
             if file.file_type == KnownContextFile::ReadmeMd && ctx.readme.is_none() {
                 ctx.readme = Some(file.content.clone());
             }
 
-            // Set project type
-            if let Some(pt) = file.project_type()
-                && ctx.project_type.is_none()
-            {
-                ctx.project_type = Some(pt.to_string());
-            }
-
             // Aggregate documentation
             if file.file_type == KnownContextFile::DocsMarkdown {
                 let docs = ctx.documentation.get_or_insert_with(String::new);
@@ -180,6 +192,14 @@ impl ProjectContext {
             }
         }
 
+        // Set project type from whichever build manifests were loaded
+        let markers: Vec<&str> = sorted_files
+            .iter()
+            .filter(|f| f.file_type.provides_project_type() && !f.is_skipped())
+            .map(|f| f.file_type.relative_path())
+            .collect();
+        ctx.project_type = detect_project_type(&markers);
+
         ctx
     }
 
@@ -220,6 +240,7 @@ impl ProjectContext {
             && self.readme.is_none()
             && self.project_type.is_none()
             && self.documentation.is_none()
+            && self.pinned.is_empty()
     }
 
     /// Gets a description of the context source for logging.
@@ -227,7 +248,8 @@ impl ProjectContext {
     /// # Returns
     ///
     /// The relative path of the primary context source, or "none" if
-    /// no primary context is available.
+    /// no primary context is available. Notes truncation if the loader
+    /// cut the source short.
     ///
     /// # Examples
     ///
@@ -243,9 +265,17 @@ impl ProjectContext {
     /// assert_eq!(ctx.source_description(), "CLAUDE.md");
     /// ```
     pub fn source_description(&self) -> String {
-        match &self.context_source {
-            Some(src) => src.to_string(),
-            None => "none".to_string(),
+        let Some(src) = &self.context_source else {
+            return "none".to_string();
+        };
+        let truncated = self
+            .loaded_files
+            .iter()
+            .find(|f| f.file_type == *src)
+            .and_then(|f| f.original_bytes.filter(|_| f.is_truncated()));
+        match truncated {
+            Some(original) => format!("{} (truncated from {} bytes)", src, original),
+            None => src.to_string(),
         }
     }
 
@@ -268,6 +298,7 @@ impl ProjectContext {
     /// 2. Primary context (truncated to 2000 chars)
     /// 3. README (truncated to 1000 chars)
     /// 4. Documentation (truncated to 1000 chars)
+    /// 5. Pinned files (see [`pinned_summary`](Self::pinned_summary))
     ///
     /// # Returns
     ///
@@ -292,12 +323,34 @@ impl ProjectContext {
             parts.push(format!("Documentation:\n{}", truncate(docs, 1000)));
         }
 
+        if let Some(pinned) = self.pinned_summary() {
+            parts.push(pinned);
+        }
+
         if parts.is_empty() {
             "No context available.".to_string()
         } else {
             parts.join("\n\n")
         }
     }
+
+    /// The pinned files formatted for prompts, or `None` if none were pinned.
+    ///
+    /// Pinned content is kept in full (the loader's [`ContextFileLimits`]
+    /// already capped it) since the user asked for it explicitly.
+    ///
+    /// [`ContextFileLimits`]: super::value_objects::ContextFileLimits
+    pub fn pinned_summary(&self) -> Option<String> {
+        if self.pinned.is_empty() {
+            return None;
+        }
+        let files: Vec<String> = self
+            .pinned
+            .iter()
+            .map(|(path, content)| format!("## {}\n{}", path, content))
+            .collect();
+        Some(format!("Pinned Files:\n{}", files.join("\n\n")))
+    }
 }
 
 #[cfg(test)]
@@ -361,4 +414,46 @@ mod tests {
         assert_eq!(ctx.primary_context, Some("Quorum context".to_string()));
         assert_eq!(ctx.context_source, Some(KnownContextFile::QuorumContext));
     }
+
+    #[test]
+    fn test_project_context_records_size_notes() {
+        let files = vec![
+            LoadedContextFile::new(
+                KnownContextFile::ClaudeMdLocal,
+                "/project/CLAUDE.md",
+                "x".repeat(50),
+            )
+            .truncated_to(20),
+            LoadedContextFile::skipped(KnownContextFile::ReadmeMd, "/project/README.md", 999),
+        ];
+
+        let ctx = ProjectContext::from_files(files);
+
+        assert_eq!(
+            ctx.source_description(),
+            "CLAUDE.md (truncated from 50 bytes)"
+        );
+        // Skipped files contribute a note but no content
+        assert!(ctx.readme.is_none());
+        assert_eq!(ctx.notes.len(), 2);
+        assert!(ctx.notes[1].contains("README.md skipped"));
+    }
+
+    #[test]
+    fn test_pinned_files_are_summarized_but_not_primary() {
+        let files = vec![LoadedContextFile::new(
+            KnownContextFile::Pinned,
+            "/project/src/auth.rs",
+            "fn login() {}",
+        )];
+
+        let ctx = ProjectContext::from_files(files);
+
+        assert!(!ctx.has_sufficient_context());
+        assert!(!ctx.is_empty());
+        assert_eq!(
+            ctx.to_summary(),
+            "Pinned Files:\n## /project/src/auth.rs\nfn login() {}"
+        );
+    }
 }
@@ -11,13 +11,16 @@
 //!
 //! - **Primary context**: CLAUDE.md, .quorum/context.md
 //! - **Documentation**: README.md, docs/**/*.md
-//! - **Project metadata**: Cargo.toml, package.json, pyproject.toml
+//! - **Project metadata**: build manifests (Cargo.toml, package.json, go.mod, ...)
 //!
 //! # Key Types
 //!
 //! - [`KnownContextFile`] - Enum of recognized context file types
 //! - [`LoadedContextFile`] - A file that has been loaded with its content
+//! - [`ContextFileLimits`] - Per-file and total size caps applied while loading
 //! - [`ProjectContext`] - Aggregated context from multiple sources
+//! - [`detect_project_type`] - Project type from marker file names
+//! - [`DecisionRecord`] - An approved plan remembered across sessions
 //!
 //! # Context Priority
 //!
@@ -58,14 +61,18 @@
 
 pub mod context_budget;
 pub mod context_mode;
+pub mod decision;
 pub mod entities;
+pub mod project_type;
 pub mod reference;
 pub mod task_result_buffer;
 pub mod value_objects;
 
 pub use context_budget::ContextBudget;
 pub use context_mode::ContextMode;
+pub use decision::{DecisionOutcome, DecisionRecord};
 pub use entities::ProjectContext;
-pub use reference::{ResourceReference, extract_references};
+pub use project_type::detect_project_type;
+pub use reference::{ReferenceProvider, ResourceReference, extract_references};
 pub use task_result_buffer::TaskResultBuffer;
-pub use value_objects::{KnownContextFile, LoadedContextFile};
+pub use value_objects::{ContextFileLimits, KnownContextFile, LoadedContextFile};
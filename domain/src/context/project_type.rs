@@ -0,0 +1,105 @@
+//! Project type detection from marker files
+//!
+//! Both the context loader (Stage 1) and the exploration agent (Stage 2)
+//! infer the project's ecosystem from the build manifests they come across.
+//! [`detect_project_type`] is the single rule set for both.
+
+use std::path::Path;
+
+/// Marker file names and the project type each indicates, in precedence
+/// order: when several markers are present, the earliest entry wins.
+///
+/// `package.json` ranks last because it frequently sits next to another
+/// ecosystem's manifest just for frontend tooling.
+const PROJECT_MARKERS: &[(&str, &str)] = &[
+    ("Cargo.toml", "rust"),
+    ("go.mod", "go"),
+    ("pom.xml", "java"),
+    ("build.gradle", "java"),
+    ("pyproject.toml", "python"),
+    ("setup.py", "python"),
+    ("Gemfile", "ruby"),
+    ("composer.json", "php"),
+    ("package.json", "nodejs"),
+];
+
+/// The project type indicated by a single marker file name, if any.
+pub(crate) fn project_type_of(file_name: &str) -> Option<&'static str> {
+    PROJECT_MARKERS
+        .iter()
+        .find(|(marker, _)| *marker == file_name)
+        .map(|(_, project_type)| *project_type)
+}
+
+/// Detects the project type from a list of file paths or names.
+///
+/// Only the final path component is compared against the known markers.
+/// The result depends on which markers are present, not on their order in
+/// `files`.
+///
+/// # Examples
+///
+/// ```
+/// use quorum_domain::context::detect_project_type;
+///
+/// assert_eq!(detect_project_type(&["src/main.go", "go.mod"]), Some("go".to_string()));
+/// assert_eq!(detect_project_type(&["package.json", "Gemfile"]), Some("ruby".to_string()));
+/// assert_eq!(detect_project_type(&["README.md"]), None);
+/// ```
+pub fn detect_project_type(files: &[&str]) -> Option<String> {
+    let names: Vec<&str> = files
+        .iter()
+        .filter_map(|file| Path::new(file).file_name().and_then(|name| name.to_str()))
+        .collect();
+    PROJECT_MARKERS
+        .iter()
+        .find(|(marker, _)| names.contains(marker))
+        .map(|(_, project_type)| project_type.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_project_type() {
+        let cases: &[(&[&str], Option<&str>)] = &[
+            (&["Cargo.toml"], Some("rust")),
+            (&["go.mod"], Some("go")),
+            (&["pom.xml"], Some("java")),
+            (&["build.gradle"], Some("java")),
+            (&["pyproject.toml"], Some("python")),
+            (&["setup.py"], Some("python")),
+            (&["Gemfile"], Some("ruby")),
+            (&["composer.json"], Some("php")),
+            (&["package.json"], Some("nodejs")),
+            (&["/repo/backend/go.mod"], Some("go")),
+            (&["README.md", "src/lib.rs"], None),
+            (&[], None),
+        ];
+        for (files, expected) in cases {
+            assert_eq!(
+                detect_project_type(files).as_deref(),
+                *expected,
+                "files: {:?}",
+                files
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixed_markers_use_fixed_precedence() {
+        assert_eq!(
+            detect_project_type(&["package.json", "composer.json", "Cargo.toml"]),
+            Some("rust".to_string())
+        );
+        assert_eq!(
+            detect_project_type(&["Cargo.toml", "composer.json", "package.json"]),
+            Some("rust".to_string())
+        );
+        assert_eq!(
+            detect_project_type(&["package.json", "pom.xml"]),
+            Some("java".to_string())
+        );
+    }
+}
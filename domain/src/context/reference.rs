@@ -1,12 +1,41 @@
 //! Resource reference extraction and types.
 //!
-//! Extracts references to GitHub Issues, Pull Requests, and other resources
-//! from text. Used by the context gathering phase to automatically resolve
-//! referenced resources.
+//! Extracts references to GitHub Issues / Pull Requests, GitLab Issues /
+//! Merge Requests, Jira issues and `@path` file mentions from text. Used by
+//! the context gathering phase to automatically resolve referenced resources.
 
 use std::collections::HashSet;
 use std::fmt;
 
+/// The tracker a [`ResourceReference`] points at.
+///
+/// Infrastructure uses this to pick the resolver for a reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceProvider {
+    GitHub,
+    GitLab,
+    Jira,
+    /// Files in the local working directory
+    LocalFile,
+}
+
+impl ReferenceProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReferenceProvider::GitHub => "github",
+            ReferenceProvider::GitLab => "gitlab",
+            ReferenceProvider::Jira => "jira",
+            ReferenceProvider::LocalFile => "file",
+        }
+    }
+}
+
+impl fmt::Display for ReferenceProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// A reference to an external resource found in text.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ResourceReference {
@@ -14,6 +43,20 @@ pub enum ResourceReference {
     GitHubIssue { repo: Option<String>, number: u64 },
     /// A GitHub Pull Request reference (e.g., `PR #123`, GitHub PR URL)
     GitHubPullRequest { repo: Option<String>, number: u64 },
+    /// A GitLab Issue reference (GitLab issue URL)
+    GitLabIssue {
+        project: Option<String>,
+        number: u64,
+    },
+    /// A GitLab Merge Request reference (e.g., `!42`, `group/project!42`, MR URL)
+    GitLabMergeRequest {
+        project: Option<String>,
+        number: u64,
+    },
+    /// A Jira issue reference (e.g., `PROJ-123`)
+    JiraIssue { key: String },
+    /// A file in the working directory (e.g., `@src/main.rs`)
+    LocalFile { path: String },
 }
 
 impl ResourceReference {
@@ -38,22 +81,75 @@ impl ResourceReference {
             ResourceReference::GitHubPullRequest { repo: None, number } => {
                 format!("PR #{}", number)
             }
+            ResourceReference::GitLabIssue {
+                project: Some(p),
+                number,
+            } => {
+                format!("GitLab Issue {}#{}", p, number)
+            }
+            ResourceReference::GitLabIssue {
+                project: None,
+                number,
+            } => {
+                format!("GitLab Issue #{}", number)
+            }
+            ResourceReference::GitLabMergeRequest {
+                project: Some(p),
+                number,
+            } => {
+                format!("MR {}!{}", p, number)
+            }
+            ResourceReference::GitLabMergeRequest {
+                project: None,
+                number,
+            } => {
+                format!("MR !{}", number)
+            }
+            ResourceReference::JiraIssue { key } => format!("Jira {}", key),
+            ResourceReference::LocalFile { path } => format!("File {}", path),
+        }
+    }
+
+    /// The tracker this reference belongs to.
+    pub fn provider(&self) -> ReferenceProvider {
+        match self {
+            ResourceReference::GitHubIssue { .. } | ResourceReference::GitHubPullRequest { .. } => {
+                ReferenceProvider::GitHub
+            }
+            ResourceReference::GitLabIssue { .. }
+            | ResourceReference::GitLabMergeRequest { .. } => ReferenceProvider::GitLab,
+            ResourceReference::JiraIssue { .. } => ReferenceProvider::Jira,
+            ResourceReference::LocalFile { .. } => ReferenceProvider::LocalFile,
         }
     }
 
-    /// The issue/PR number.
+    /// The issue/PR/MR number (for Jira, the numeric part of the key;
+    /// `0` for local files).
     pub fn number(&self) -> u64 {
         match self {
             ResourceReference::GitHubIssue { number, .. }
-            | ResourceReference::GitHubPullRequest { number, .. } => *number,
+            | ResourceReference::GitHubPullRequest { number, .. }
+            | ResourceReference::GitLabIssue { number, .. }
+            | ResourceReference::GitLabMergeRequest { number, .. } => *number,
+            ResourceReference::JiraIssue { key } => key
+                .rsplit_once('-')
+                .and_then(|(_, n)| n.parse().ok())
+                .unwrap_or(0),
+            ResourceReference::LocalFile { .. } => 0,
         }
     }
 
-    /// The optional repository (owner/repo).
+    /// The optional repository (GitHub `owner/repo`, GitLab project path).
+    ///
+    /// Always `None` for Jira issues (the project is part of the key) and
+    /// local files.
     pub fn repo(&self) -> Option<&str> {
         match self {
             ResourceReference::GitHubIssue { repo, .. }
             | ResourceReference::GitHubPullRequest { repo, .. } => repo.as_deref(),
+            ResourceReference::GitLabIssue { project, .. }
+            | ResourceReference::GitLabMergeRequest { project, .. } => project.as_deref(),
+            ResourceReference::JiraIssue { .. } | ResourceReference::LocalFile { .. } => None,
         }
     }
 }
@@ -68,12 +164,19 @@ impl fmt::Display for ResourceReference {
 ///
 /// Recognizes (in specificity order):
 /// 1. GitHub URLs: `github.com/{owner}/{repo}/(issues|pull)/{N}`
-/// 2. Cross-repo refs: `{owner}/{repo}#{N}`
-/// 3. Typed explicit: `Issue #N`, `PR #N`, `Pull Request #N`
-/// 4. Range refs: `#N-M` (M-N <= 10)
-/// 5. Bare refs: `#N`
+/// 2. GitLab URLs: `{host}/{group}/{project}/-/(issues|merge_requests)/{N}`
+/// 3. Local file mentions: `@{path}` (e.g. `@src/foo.rs`)
+/// 4. Cross-repo refs: `{owner}/{repo}#{N}` (GitHub), `{group}/{project}!{N}` (GitLab MR)
+/// 5. Typed explicit: `Issue #N`, `PR #N`, `Pull Request #N`
+/// 6. Jira keys: `{PROJECT}-{N}` (e.g. `PROJ-123`)
+/// 7. Bare MR refs: `!N`
+/// 8. Range refs: `#N-M` (M-N <= 10)
+/// 9. Bare refs: `#N`
 ///
-/// `Discussion #N` is skipped (Phase 1 scope).
+/// Bare `#N` is ambiguous between GitHub and GitLab issues and is always
+/// treated as a GitHub Issue. `@name` without a `/` or `.` is a user mention,
+/// not a file. `Discussion #N` is skipped (Phase 1 scope),
+/// and anything that matches none of the formats is ignored.
 /// Results are deduplicated.
 pub fn extract_references(text: &str) -> Vec<ResourceReference> {
     let mut seen = HashSet::new();
@@ -103,7 +206,34 @@ pub fn extract_references(text: &str) -> Vec<ResourceReference> {
         }
     }
 
-    // === Patterns 2-5: scan character by character ===
+    // === Pattern 2: GitLab URLs ===
+    // {host}/{group}/{project}/-/(issues|merge_requests)/{N}
+    // Keyed on the `/-/` path marker so self-hosted instances are found too.
+    for marker in ["/-/issues/", "/-/merge_requests/"] {
+        let mut search_from = 0;
+        while let Some(idx) = text[search_from..].find(marker) {
+            let abs_idx = search_from + idx;
+            let after_marker = abs_idx + marker.len();
+            search_from = after_marker;
+
+            let url_start = text[..abs_idx]
+                .char_indices()
+                .rev()
+                .find(|(_, c)| c.is_whitespace() || matches!(c, '(' | '<' | '[' | '"' | '\''))
+                .map(|(i, c)| i + c.len_utf8())
+                .unwrap_or(0);
+            if let Some((reference, num_len)) =
+                parse_gitlab_url(&text[url_start..abs_idx], marker, &text[after_marker..])
+            {
+                let end = after_marker + num_len;
+                matched_positions.push((url_start, end));
+                seen.insert(reference);
+                search_from = end;
+            }
+        }
+    }
+
+    // === Patterns 3-9: scan character by character ===
     let chars: Vec<char> = text.chars().collect();
     let mut char_idx = 0;
     let mut byte_idx = 0;
@@ -119,7 +249,18 @@ pub fn extract_references(text: &str) -> Vec<ResourceReference> {
             continue;
         }
 
-        // === Pattern 2: Cross-repo `owner/repo#N` ===
+        // === Pattern 3: Local file `@path` ===
+        if ch == '@'
+            && let Some(parsed) = try_parse_file_mention(&chars, char_idx, byte_idx)
+        {
+            matched_positions.push((parsed.start_byte, parsed.end_byte));
+            seen.insert(parsed.reference);
+            byte_idx = parsed.end_byte;
+            char_idx = parsed.end_char;
+            continue;
+        }
+
+        // === Pattern 4: Cross-repo `owner/repo#N` / `group/project!N` ===
         // Look for `{owner}/{repo}#{N}` — owner/repo must be alphanumeric + hyphens
         if (ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
             && let Some(parsed) = try_parse_cross_repo(&chars, char_idx, byte_idx, text)
@@ -132,7 +273,7 @@ pub fn extract_references(text: &str) -> Vec<ResourceReference> {
             continue;
         }
 
-        // === Pattern 3: Typed explicit (Issue #N, PR #N, Pull Request #N) ===
+        // === Pattern 5: Typed explicit (Issue #N, PR #N, Pull Request #N) ===
         if (ch == 'I' || ch == 'i')
             && char_idx + 6 < chars.len()
             && let Some(parsed) = try_parse_typed_issue(&chars, char_idx, byte_idx, text)
@@ -154,6 +295,28 @@ pub fn extract_references(text: &str) -> Vec<ResourceReference> {
             continue;
         }
 
+        // === Pattern 6: Jira keys `PROJ-123` ===
+        if ch.is_ascii_uppercase()
+            && let Some(parsed) = try_parse_jira_key(&chars, char_idx, byte_idx)
+        {
+            matched_positions.push((parsed.start_byte, parsed.end_byte));
+            seen.insert(parsed.reference);
+            byte_idx = parsed.end_byte;
+            char_idx = parsed.end_char;
+            continue;
+        }
+
+        // === Pattern 7: Bare GitLab MR `!N` ===
+        if ch == '!'
+            && let Some(parsed) = try_parse_bare_mr(&chars, char_idx, byte_idx)
+        {
+            matched_positions.push((parsed.start_byte, parsed.end_byte));
+            seen.insert(parsed.reference);
+            byte_idx = parsed.end_byte;
+            char_idx = parsed.end_char;
+            continue;
+        }
+
         // === Skip Discussion #N ===
         if (ch == 'D' || ch == 'd')
             && char_idx + 11 < chars.len()
@@ -186,7 +349,7 @@ pub fn extract_references(text: &str) -> Vec<ResourceReference> {
             }
         }
 
-        // === Pattern 4 & 5: Bare #N or range #N-M ===
+        // === Pattern 8 & 9: Bare #N or range #N-M ===
         if ch == '#'
             && !is_matched(byte_idx, &matched_positions)
             && let Some(parsed) = try_parse_bare_ref(&chars, char_idx, byte_idx)
@@ -264,6 +427,41 @@ fn parse_github_url(after_prefix: &str) -> Option<ParsedUrl> {
     })
 }
 
+/// Parse a GitLab URL split around its `/-/(issues|merge_requests)/` marker.
+///
+/// `before` is the URL up to the marker (`https://{host}/{project}`), `after`
+/// the text following it. Returns the reference and the length of the number.
+fn parse_gitlab_url(before: &str, marker: &str, after: &str) -> Option<(ResourceReference, usize)> {
+    let without_scheme = before
+        .strip_prefix("https://")
+        .or_else(|| before.strip_prefix("http://"))
+        .unwrap_or(before);
+    let (host, project) = without_scheme.split_once('/')?;
+    // Projects always live under a namespace: `{group}/{project}`
+    if !host.contains('.') || !project.contains('/') || project.ends_with('/') {
+        return None;
+    }
+
+    let num_end = after
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(after.len());
+    if num_end == 0 {
+        return None;
+    }
+    let number: u64 = after[..num_end].parse().ok()?;
+    if number == 0 {
+        return None;
+    }
+
+    let project = Some(project.to_string());
+    let reference = if marker.contains("merge_requests") {
+        ResourceReference::GitLabMergeRequest { project, number }
+    } else {
+        ResourceReference::GitLabIssue { project, number }
+    };
+    Some((reference, num_end))
+}
+
 struct ParsedRef {
     reference: ResourceReference,
     start_byte: usize,
@@ -278,7 +476,8 @@ struct ParsedBareRef {
     end_char: usize,
 }
 
-/// Try to parse `owner/repo#N` starting at char_idx
+/// Try to parse `owner/repo#N` (GitHub) or `group/project!N` (GitLab MR)
+/// starting at char_idx
 fn try_parse_cross_repo(
     chars: &[char],
     start_char: usize,
@@ -324,15 +523,16 @@ fn try_parse_cross_repo(
         bi += chars[ci].len_utf8();
         ci += 1;
     }
-    if ci >= chars.len() || chars[ci] != '#' {
+    if ci >= chars.len() || (chars[ci] != '#' && chars[ci] != '!') {
         return None;
     }
     let repo_end = ci;
     if repo_end == repo_start {
         return None;
     }
+    let is_merge_request = chars[ci] == '!';
 
-    // Skip '#'
+    // Skip '#' / '!'
     bi += 1;
     ci += 1;
 
@@ -376,12 +576,20 @@ fn try_parse_cross_repo(
     // Use text slice to get the owner/repo string with correct encoding
     let _ = text; // we already computed it from chars
     let full_repo = format!("{}/{}", owner, repo);
-
-    Some(ParsedRef {
-        reference: ResourceReference::GitHubIssue {
+    let reference = if is_merge_request {
+        ResourceReference::GitLabMergeRequest {
+            project: Some(full_repo),
+            number,
+        }
+    } else {
+        ResourceReference::GitHubIssue {
             repo: Some(full_repo),
             number,
-        },
+        }
+    };
+
+    Some(ParsedRef {
+        reference,
         start_byte,
         end_byte: bi,
         end_char: ci,
@@ -551,6 +759,157 @@ fn try_parse_typed_pr(
     None
 }
 
+/// Try to parse `@path` starting at char_idx (where chars[char_idx] == '@')
+///
+/// Path characters are ASCII alphanumerics and `/ . _ -`; trailing sentence
+/// punctuation is not part of the path. The token must contain `/` or `.`
+/// so that `@user` mentions are left alone.
+fn try_parse_file_mention(
+    chars: &[char],
+    start_char: usize,
+    start_byte: usize,
+) -> Option<ParsedRef> {
+    debug_assert_eq!(chars[start_char], '@');
+
+    // `user@example.com` is an address, not a mention
+    if start_char > 0 {
+        let prev = chars[start_char - 1];
+        if prev.is_ascii_alphanumeric() || prev == '_' || prev == '.' {
+            return None;
+        }
+    }
+
+    let path_start = start_char + 1;
+    let mut ci = path_start;
+    while ci < chars.len()
+        && (chars[ci].is_ascii_alphanumeric() || matches!(chars[ci], '/' | '.' | '_' | '-'))
+    {
+        ci += 1;
+    }
+    while ci > path_start && chars[ci - 1] == '.' {
+        ci -= 1;
+    }
+    if ci == path_start {
+        return None;
+    }
+
+    let path: String = chars[path_start..ci].iter().collect();
+    if !path.contains('/') && !path.contains('.') {
+        return None;
+    }
+
+    Some(ParsedRef {
+        reference: ResourceReference::LocalFile { path },
+        start_byte,
+        // Paths are pure ASCII: one byte per char
+        end_byte: start_byte + (ci - start_char),
+        end_char: ci,
+    })
+}
+
+/// All-caps words that look like Jira keys but are almost always something
+/// else (`UTF-8`, `SHA-256`, `CVE-2024-1234`, `GPT-4`, ...).
+const NON_JIRA_PREFIXES: &[&str] = &[
+    "UTF", "SHA", "ISO", "RFC", "CVE", "HTTP", "TLS", "SSL", "MD", "PR", "GPT", "COVID",
+];
+
+/// Try to parse a Jira key `PROJ-123` starting at char_idx
+///
+/// The project part starts with at least two uppercase letters, optionally
+/// followed by uppercase letters, digits or `_` (so `X86-64` is not a key).
+/// The key must stand alone as a word.
+fn try_parse_jira_key(chars: &[char], start_char: usize, start_byte: usize) -> Option<ParsedRef> {
+    if start_char > 0 {
+        let prev = chars[start_char - 1];
+        if prev.is_ascii_alphanumeric() || prev == '_' || prev == '-' {
+            return None;
+        }
+    }
+
+    let mut ci = start_char;
+    while ci < chars.len() && chars[ci].is_ascii_uppercase() {
+        ci += 1;
+    }
+    if ci - start_char < 2 {
+        return None;
+    }
+    while ci < chars.len()
+        && (chars[ci].is_ascii_uppercase() || chars[ci].is_ascii_digit() || chars[ci] == '_')
+    {
+        ci += 1;
+    }
+    let project_end = ci;
+    if ci >= chars.len() || chars[ci] != '-' {
+        return None;
+    }
+    ci += 1;
+
+    let num_start = ci;
+    while ci < chars.len() && chars[ci].is_ascii_digit() {
+        ci += 1;
+    }
+    if ci == num_start || chars[num_start] == '0' {
+        return None;
+    }
+    if ci < chars.len() && (chars[ci].is_ascii_alphanumeric() || chars[ci] == '_') {
+        return None;
+    }
+
+    let project: String = chars[start_char..project_end].iter().collect();
+    if NON_JIRA_PREFIXES.contains(&project.as_str()) {
+        return None;
+    }
+    let key: String = chars[start_char..ci].iter().collect();
+
+    Some(ParsedRef {
+        reference: ResourceReference::JiraIssue { key },
+        start_byte,
+        // Keys are pure ASCII: one byte per char
+        end_byte: start_byte + (ci - start_char),
+        end_char: ci,
+    })
+}
+
+/// Try to parse bare `!N` (GitLab MR) starting at char_idx (where chars[char_idx] == '!')
+fn try_parse_bare_mr(chars: &[char], start_char: usize, start_byte: usize) -> Option<ParsedRef> {
+    debug_assert_eq!(chars[start_char], '!');
+
+    // `foo!1` / `!!1` are not references
+    if start_char > 0 {
+        let prev = chars[start_char - 1];
+        if prev.is_ascii_alphanumeric() || prev == '!' || prev == '_' {
+            return None;
+        }
+    }
+
+    let mut ci = start_char + 1;
+    while ci < chars.len() && chars[ci].is_ascii_digit() {
+        ci += 1;
+    }
+    if ci == start_char + 1 {
+        return None;
+    }
+    if ci < chars.len() && (chars[ci].is_ascii_alphabetic() || chars[ci] == '_') {
+        return None;
+    }
+
+    let num_str: String = chars[start_char + 1..ci].iter().collect();
+    let number: u64 = num_str.parse().ok()?;
+    if number == 0 {
+        return None;
+    }
+
+    Some(ParsedRef {
+        reference: ResourceReference::GitLabMergeRequest {
+            project: None,
+            number,
+        },
+        start_byte,
+        end_byte: start_byte + (ci - start_char),
+        end_char: ci,
+    })
+}
+
 /// Try to parse bare `#N` or range `#N-M` starting at char_idx (where chars[char_idx] == '#')
 fn try_parse_bare_ref(
     chars: &[char],
@@ -816,6 +1175,111 @@ mod tests {
         assert_eq!(refs.len(), 4);
     }
 
+    #[test]
+    fn test_mixed_providers() {
+        let text = "Fixes #12 and PROJ-345; see group/app!7, !8 and \
+                    https://gitlab.example.com/group/sub/app/-/issues/9 \
+                    plus https://github.com/org/lib/pull/3. UTF-8 and SHA-256 are not tickets.";
+        let refs = extract_references(text);
+        assert_eq!(refs.len(), 6, "{:?}", refs);
+        assert!(refs.contains(&ResourceReference::GitHubIssue {
+            repo: None,
+            number: 12,
+        }));
+        assert!(refs.contains(&ResourceReference::GitHubPullRequest {
+            repo: Some("org/lib".to_string()),
+            number: 3,
+        }));
+        assert!(refs.contains(&ResourceReference::JiraIssue {
+            key: "PROJ-345".to_string(),
+        }));
+        assert!(refs.contains(&ResourceReference::GitLabMergeRequest {
+            project: Some("group/app".to_string()),
+            number: 7,
+        }));
+        assert!(refs.contains(&ResourceReference::GitLabMergeRequest {
+            project: None,
+            number: 8,
+        }));
+        assert!(refs.contains(&ResourceReference::GitLabIssue {
+            project: Some("group/sub/app".to_string()),
+            number: 9,
+        }));
+    }
+
+    #[test]
+    fn test_gitlab_merge_request_url() {
+        let refs =
+            extract_references("Review https://gitlab.com/team/service/-/merge_requests/21/diffs");
+        assert_eq!(
+            refs,
+            vec![ResourceReference::GitLabMergeRequest {
+                project: Some("team/service".to_string()),
+                number: 21,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_jira_key_boundaries() {
+        let refs = extract_references("ABC-12x, xABC-12, A-1, PROJ-0 and OPS_2-7");
+        assert_eq!(
+            refs,
+            vec![ResourceReference::JiraIssue {
+                key: "OPS_2-7".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_jira_key_needs_two_leading_letters() {
+        let refs = extract_references("Build for X86-64 and A1B-2, see AB1-3 and Q3-2024");
+        assert_eq!(
+            refs,
+            vec![ResourceReference::JiraIssue {
+                key: "AB1-3".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_bang_without_number_ignored() {
+        let refs = extract_references("if !ready && x != 1 { wow!1 }");
+        assert!(refs.is_empty(), "{:?}", refs);
+    }
+
+    #[test]
+    fn test_file_mentions() {
+        let refs = extract_references(
+            "Compare @src/foo.rs with @Cargo.toml. Ping @alice, mail bob@example.com",
+        );
+        assert_eq!(refs.len(), 2, "{:?}", refs);
+        assert!(refs.contains(&ResourceReference::LocalFile {
+            path: "src/foo.rs".to_string(),
+        }));
+        assert!(refs.contains(&ResourceReference::LocalFile {
+            path: "Cargo.toml".to_string(),
+        }));
+    }
+
+    #[test]
+    fn test_provider() {
+        let jira = ResourceReference::JiraIssue {
+            key: "PROJ-42".to_string(),
+        };
+        assert_eq!(jira.provider(), ReferenceProvider::Jira);
+        assert_eq!(jira.number(), 42);
+        assert_eq!(jira.label(), "Jira PROJ-42");
+
+        let mr = ResourceReference::GitLabMergeRequest {
+            project: Some("group/app".to_string()),
+            number: 7,
+        };
+        assert_eq!(mr.provider(), ReferenceProvider::GitLab);
+        assert_eq!(mr.label(), "MR group/app!7");
+        assert_eq!(mr.repo(), Some("group/app"));
+    }
+
     #[test]
     fn test_zero_number_ignored() {
         let refs = extract_references("#0");
@@ -11,7 +11,10 @@
 //!
 //! - **Primary context files**: CLAUDE.md, .quorum/context.md
 //! - **Documentation files**: README.md, docs/*.md
-//! - **Project configuration**: Cargo.toml, package.json, pyproject.toml
+//! - **Project configuration**: Cargo.toml, package.json, pyproject.toml,
+//!   go.mod, pom.xml, build.gradle, Gemfile, composer.json
+//! - **Pinned files**: arbitrary files named via `context.pinned_files` or
+//!   `--context-file`
 //!
 //! # Example
 //!
@@ -33,6 +36,9 @@
 //! assert!(loaded.is_primary());
 //! ```
 
+use super::project_type::project_type_of;
+use crate::core::string::truncate;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 /// Known context files that should be checked in a project.
@@ -48,14 +54,18 @@ use std::path::Path;
 /// 3. `.claude/CLAUDE.md` - Global Claude configuration
 /// 4. `README.md` - Project readme
 /// 5. `docs/**/*.md` - Documentation directory
-/// 6. Build files (Cargo.toml, package.json, pyproject.toml)
+/// 6. Build files (Cargo.toml, package.json, go.mod, ...)
+///
+/// [`Pinned`](KnownContextFile::Pinned) files are not discovered but named
+/// explicitly by the user; they share the top priority so they are never
+/// squeezed out of the size budget by discovered files.
 ///
 /// # Primary vs Secondary Context
 ///
 /// Primary context files (QuorumContext, ClaudeMdLocal, ClaudeMdGlobal)
 /// are considered sufficient to skip the exploration phase. Secondary
 /// files provide supplementary information.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum KnownContextFile {
     /// `.quorum/context.md` - Generated context file with highest priority.
     ///
@@ -101,6 +111,27 @@ pub enum KnownContextFile {
     ///
     /// Indicates a Python project using modern packaging standards.
     PyprojectToml,
+
+    /// `go.mod` - Go module definition.
+    GoMod,
+
+    /// `pom.xml` - Maven project model (Java).
+    PomXml,
+
+    /// `build.gradle` - Gradle build script (Java).
+    BuildGradle,
+
+    /// `Gemfile` - Bundler dependency list (Ruby).
+    Gemfile,
+
+    /// `composer.json` - Composer manifest (PHP).
+    ComposerJson,
+
+    /// A file pinned via `context.pinned_files` or `--context-file`.
+    ///
+    /// Always loaded when present, but not a primary context source:
+    /// pinned files supplement discovery rather than replace it.
+    Pinned,
 }
 
 impl KnownContextFile {
@@ -127,6 +158,12 @@ impl KnownContextFile {
             KnownContextFile::CargoToml => "Cargo.toml",
             KnownContextFile::PackageJson => "package.json",
             KnownContextFile::PyprojectToml => "pyproject.toml",
+            KnownContextFile::GoMod => "go.mod",
+            KnownContextFile::PomXml => "pom.xml",
+            KnownContextFile::BuildGradle => "build.gradle",
+            KnownContextFile::Gemfile => "Gemfile",
+            KnownContextFile::ComposerJson => "composer.json",
+            KnownContextFile::Pinned => "(pinned)",
         }
     }
 
@@ -138,22 +175,27 @@ impl KnownContextFile {
     ///
     /// # Priority Values
     ///
-    /// - 0: QuorumContext (highest)
+    /// - 0: QuorumContext, Pinned (highest)
     /// - 1: ClaudeMdLocal
     /// - 2: ClaudeMdGlobal
     /// - 3: ReadmeMd
     /// - 4: DocsMarkdown
-    /// - 5: Build files (CargoToml, PackageJson, PyprojectToml)
+    /// - 5: Build files (CargoToml, PackageJson, GoMod, ...)
     pub fn priority(&self) -> u8 {
         match self {
-            KnownContextFile::QuorumContext => 0,
+            KnownContextFile::QuorumContext | KnownContextFile::Pinned => 0,
             KnownContextFile::ClaudeMdLocal => 1,
             KnownContextFile::ClaudeMdGlobal => 2,
             KnownContextFile::ReadmeMd => 3,
             KnownContextFile::DocsMarkdown => 4,
-            KnownContextFile::CargoToml => 5,
-            KnownContextFile::PackageJson => 5,
-            KnownContextFile::PyprojectToml => 5,
+            KnownContextFile::CargoToml
+            | KnownContextFile::PackageJson
+            | KnownContextFile::PyprojectToml
+            | KnownContextFile::GoMod
+            | KnownContextFile::PomXml
+            | KnownContextFile::BuildGradle
+            | KnownContextFile::Gemfile
+            | KnownContextFile::ComposerJson => 5,
         }
     }
 
@@ -180,35 +222,27 @@ impl KnownContextFile {
     /// Build configuration files can be used to automatically detect
     /// the project's programming language and ecosystem.
     pub fn provides_project_type(&self) -> bool {
-        matches!(
-            self,
-            KnownContextFile::CargoToml
-                | KnownContextFile::PackageJson
-                | KnownContextFile::PyprojectToml
-        )
+        self.project_type().is_some()
     }
 
     /// Returns the project type indicated by this file, if any.
     ///
-    /// # Returns
-    ///
-    /// - `Some("rust")` for CargoToml
-    /// - `Some("nodejs")` for PackageJson
-    /// - `Some("python")` for PyprojectToml
-    /// - `None` for other file types
+    /// Build files map through the same table as
+    /// [`detect_project_type`](super::detect_project_type) (e.g.
+    /// `Some("rust")` for CargoToml, `Some("java")` for PomXml); other file
+    /// types return `None`.
     pub fn project_type(&self) -> Option<&'static str> {
         match self {
-            KnownContextFile::CargoToml => Some("rust"),
-            KnownContextFile::PackageJson => Some("nodejs"),
-            KnownContextFile::PyprojectToml => Some("python"),
-            _ => None,
+            KnownContextFile::Pinned => None,
+            file_type => project_type_of(file_type.relative_path()),
         }
     }
 
-    /// Returns all known file types in priority order.
+    /// Returns all discoverable file types in priority order.
     ///
     /// This is useful for iterating over all file types when loading
-    /// context from a project.
+    /// context from a project. [`Pinned`](KnownContextFile::Pinned) is
+    /// excluded since pinned paths come from configuration.
     ///
     /// # Examples
     ///
@@ -217,7 +251,7 @@ impl KnownContextFile {
     ///
     /// let all_types = KnownContextFile::all();
     /// assert_eq!(all_types[0], KnownContextFile::QuorumContext);
-    /// assert_eq!(all_types.len(), 8);
+    /// assert_eq!(all_types.len(), 13);
     /// ```
     pub fn all() -> &'static [KnownContextFile] {
         &[
@@ -229,6 +263,11 @@ impl KnownContextFile {
             KnownContextFile::CargoToml,
             KnownContextFile::PackageJson,
             KnownContextFile::PyprojectToml,
+            KnownContextFile::GoMod,
+            KnownContextFile::PomXml,
+            KnownContextFile::BuildGradle,
+            KnownContextFile::Gemfile,
+            KnownContextFile::ComposerJson,
         ]
     }
 }
@@ -259,7 +298,7 @@ impl std::fmt::Display for KnownContextFile {
 /// assert_eq!(loaded.filename(), "README.md");
 /// assert!(!loaded.is_primary());
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoadedContextFile {
     /// The type of file that was loaded.
     pub file_type: KnownContextFile,
@@ -269,6 +308,10 @@ pub struct LoadedContextFile {
 
     /// The content of the file.
     pub content: String,
+
+    /// Original size in bytes when the loader truncated or skipped this file
+    /// because of [`ContextFileLimits`]; `None` if loaded in full.
+    pub original_bytes: Option<usize>,
 }
 
 impl LoadedContextFile {
@@ -300,9 +343,68 @@ impl LoadedContextFile {
             file_type,
             path: path.into(),
             content: content.into(),
+            original_bytes: None,
+        }
+    }
+
+    /// Creates a placeholder for a file that was skipped because the total
+    /// size budget was exhausted. It carries no content, only the note.
+    pub fn skipped(
+        file_type: KnownContextFile,
+        path: impl Into<String>,
+        original_bytes: usize,
+    ) -> Self {
+        Self {
+            file_type,
+            path: path.into(),
+            content: String::new(),
+            original_bytes: Some(original_bytes),
         }
     }
 
+    /// Truncates the content to at most `max_bytes` (UTF-8 safe), recording
+    /// the original size. No-op if the content already fits.
+    pub fn truncated_to(mut self, max_bytes: usize) -> Self {
+        if self.content.len() > max_bytes {
+            let original = self.original_bytes.unwrap_or(self.content.len());
+            self.content = truncate(&self.content, max_bytes);
+            self.original_bytes = Some(original);
+        }
+        self
+    }
+
+    /// Whether the loader cut this file's content short.
+    pub fn is_truncated(&self) -> bool {
+        self.original_bytes.is_some() && !self.content.is_empty()
+    }
+
+    /// Whether the loader dropped this file's content entirely.
+    pub fn is_skipped(&self) -> bool {
+        self.original_bytes.is_some() && self.content.is_empty()
+    }
+
+    /// Human-readable note describing truncation or skipping, if any.
+    pub fn size_note(&self) -> Option<String> {
+        let original = self.original_bytes?;
+        let name = match self.file_type {
+            KnownContextFile::Pinned => self.path.clone(),
+            file_type => file_type.to_string(),
+        };
+        Some(if self.is_skipped() {
+            format!(
+                "{} skipped ({} bytes; context size limit reached)",
+                name, original
+            )
+        } else {
+            format!(
+                "{} truncated to {} of {} bytes",
+                name,
+                self.content.len(),
+                original
+            )
+        })
+    }
+
     /// Checks if this file is a primary context source.
     ///
     /// Primary context sources are sufficient to skip the exploration
@@ -352,6 +454,77 @@ impl LoadedContextFile {
     }
 }
 
+/// Size caps applied by the context loader.
+///
+/// - `max_file_bytes`: Maximum bytes kept from any single context file
+/// - `max_total_bytes`: Maximum bytes across all loaded files; once exhausted,
+///   lower-priority files are truncated to what is left, then skipped
+///
+/// Keeps one giant README or generated doc from crowding out everything else.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextFileLimits {
+    max_file_bytes: usize,
+    max_total_bytes: usize,
+}
+
+impl ContextFileLimits {
+    /// Try to create limits, returning validation errors on bad values.
+    pub fn try_new(max_file_bytes: usize, max_total_bytes: usize) -> Result<Self, Vec<String>> {
+        let mut issues = Vec::new();
+        if max_file_bytes == 0 {
+            issues.push("context: max_file_bytes must be > 0".to_string());
+        }
+        if max_total_bytes < max_file_bytes {
+            issues.push(format!(
+                "context: max_total_bytes ({}) must be >= max_file_bytes ({})",
+                max_total_bytes, max_file_bytes
+            ));
+        }
+        if issues.is_empty() {
+            Ok(Self {
+                max_file_bytes,
+                max_total_bytes,
+            })
+        } else {
+            Err(issues)
+        }
+    }
+
+    pub fn max_file_bytes(&self) -> usize {
+        self.max_file_bytes
+    }
+
+    pub fn max_total_bytes(&self) -> usize {
+        self.max_total_bytes
+    }
+
+    /// Apply the caps to files already sorted by priority (highest first).
+    pub fn apply(&self, files: Vec<LoadedContextFile>) -> Vec<LoadedContextFile> {
+        let mut remaining = self.max_total_bytes;
+        files
+            .into_iter()
+            .map(|file| {
+                if remaining == 0 {
+                    let original = file.original_bytes.unwrap_or(file.content.len());
+                    return LoadedContextFile::skipped(file.file_type, file.path, original);
+                }
+                let file = file.truncated_to(self.max_file_bytes.min(remaining));
+                remaining = remaining.saturating_sub(file.content.len());
+                file
+            })
+            .collect()
+    }
+}
+
+impl Default for ContextFileLimits {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 100_000,
+            max_total_bytes: 400_000,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,7 +553,10 @@ mod tests {
             KnownContextFile::PyprojectToml.project_type(),
             Some("python")
         );
+        assert_eq!(KnownContextFile::GoMod.project_type(), Some("go"));
+        assert_eq!(KnownContextFile::BuildGradle.project_type(), Some("java"));
         assert_eq!(KnownContextFile::ReadmeMd.project_type(), None);
+        assert_eq!(KnownContextFile::Pinned.project_type(), None);
     }
 
     #[test]
@@ -394,5 +570,46 @@ mod tests {
         assert!(loaded.is_primary());
         assert_eq!(loaded.filename(), "CLAUDE.md");
         assert!(loaded.project_type().is_none());
+        assert!(loaded.size_note().is_none());
+    }
+
+    #[test]
+    fn test_context_file_limits_truncate_and_skip() {
+        let limits = ContextFileLimits::try_new(10, 15).unwrap();
+        let files = vec![
+            LoadedContextFile::new(
+                KnownContextFile::ClaudeMdLocal,
+                "/p/CLAUDE.md",
+                "a".repeat(40),
+            ),
+            LoadedContextFile::new(KnownContextFile::ReadmeMd, "/p/README.md", "b".repeat(8)),
+            LoadedContextFile::new(KnownContextFile::CargoToml, "/p/Cargo.toml", "[package]"),
+        ];
+
+        let files = limits.apply(files);
+
+        // Per-file cap
+        assert!(files[0].is_truncated());
+        assert_eq!(files[0].content.len(), 10);
+        assert_eq!(
+            files[0].size_note().as_deref(),
+            Some("CLAUDE.md truncated to 10 of 40 bytes")
+        );
+        // Truncated to what remains of the total budget
+        assert!(files[1].is_truncated());
+        assert_eq!(files[1].content.len(), 5);
+        // Budget exhausted: skipped
+        assert!(files[2].is_skipped());
+        assert_eq!(
+            files[2].size_note().as_deref(),
+            Some("Cargo.toml skipped (9 bytes; context size limit reached)")
+        );
+    }
+
+    #[test]
+    fn test_context_file_limits_validation() {
+        assert!(ContextFileLimits::try_new(0, 10).is_err());
+        assert!(ContextFileLimits::try_new(20, 10).is_err());
+        assert!(ContextFileLimits::try_new(10, 10).is_ok());
     }
 }
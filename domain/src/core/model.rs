@@ -1,5 +1,6 @@
 //! Model value object representing an LLM model
 
+use crate::util::edit_distance;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Available LLM models (Value Object)
@@ -119,32 +120,141 @@ impl std::fmt::Display for Model {
     }
 }
 
+/// Built-in models, in the order [`Model::all`] lists them.
+static ALL_MODELS: [Model; 19] = [
+    Model::ClaudeSonnet46,
+    Model::ClaudeOpus46,
+    Model::ClaudeSonnet45,
+    Model::ClaudeHaiku45,
+    Model::ClaudeOpus45,
+    Model::ClaudeSonnet4,
+    Model::Gpt54,
+    Model::Gpt53Codex,
+    Model::Gpt52Codex,
+    Model::Gpt51CodexMax,
+    Model::Gpt51Codex,
+    Model::Gpt52,
+    Model::Gpt51,
+    Model::Gpt5,
+    Model::Gpt51CodexMini,
+    Model::Gpt5Mini,
+    Model::Gpt41,
+    Model::Gemini3Pro,
+    Model::Gemini31Pro,
+];
+
+/// Built-in short names and the model each currently stands for. Configs
+/// that use them follow version bumps made here; `models.aliases` can
+/// override or add to them.
+static BUILTIN_ALIASES: [(&str, Model); 5] = [
+    ("sonnet", Model::ClaudeSonnet46),
+    ("opus", Model::ClaudeOpus46),
+    ("haiku", Model::ClaudeHaiku45),
+    ("gpt", Model::Gpt54),
+    ("gemini", Model::Gemini31Pro),
+];
+
+/// Error returned by [`Model::from_str`](std::str::FromStr::from_str).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ModelParseError {
+    #[error("model name is empty")]
+    Empty,
+    /// The name is a likely typo of a built-in model (e.g. `claude-sonet-4.5`).
+    #[error("unknown model '{input}' (did you mean '{suggestion}'?)")]
+    Unknown { input: String, suggestion: Model },
+}
+
+/// Case- and punctuation-insensitive form of a model name, so that
+/// provider-style IDs such as `claude-sonnet-4-5` or `GPT5.4` resolve to
+/// the built-in model.
+fn normalized_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Largest edit distance (between normalized keys) still treated as a typo.
+const MAX_TYPO_DISTANCE: usize = 2;
+
+/// The built-in model `key` is most likely a typo of, if any.
+///
+/// Only names with the same version digits are considered, so a different
+/// version (`gpt-4o`, `claude-opus-4.1`) stays a valid custom model.
+fn typo_of(key: &str) -> Option<&'static Model> {
+    let digits = |k: &str| k.chars().filter(char::is_ascii_digit).collect::<String>();
+    let version = digits(key);
+    Model::all()
+        .iter()
+        .map(|m| (m, normalized_key(m.as_str())))
+        .filter(|(_, builtin)| digits(builtin) == version)
+        .map(|(m, builtin)| (m, edit_distance(key, &builtin)))
+        .filter(|(_, distance)| *distance <= MAX_TYPO_DISTANCE)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(m, _)| m)
+}
+
+impl Model {
+    /// Every built-in model (no [`Model::Custom`]).
+    pub fn all() -> &'static [Model] {
+        &ALL_MODELS
+    }
+
+    /// Built-in aliases (`sonnet`, `opus`, ...) and their current targets.
+    pub fn builtin_aliases() -> &'static [(&'static str, Model)] {
+        &BUILTIN_ALIASES
+    }
+
+    /// Resolve a built-in alias (case-insensitive).
+    pub fn from_alias(name: &str) -> Option<Model> {
+        let name = name.trim();
+        Model::builtin_aliases()
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+            .map(|(_, model)| model.clone())
+    }
+}
+
 impl std::str::FromStr for Model {
-    type Err = std::convert::Infallible;
+    type Err = ModelParseError;
 
+    /// Parse a model name.
+    ///
+    /// Built-in names and aliases ([`Model::builtin_aliases`]) match
+    /// case-insensitively, and a name that differs from a built-in one only
+    /// in punctuation (`claude-sonnet-4-5`) resolves to that model. A name
+    /// within a small edit distance of a built-in one with the same version
+    /// digits (`claude-sonet-4.5`) is rejected with that model as the
+    /// suggestion. Any other name is taken as-is as a [`Model::Custom`]
+    /// identifier (provider-specific IDs, models newer than this list).
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Ok(match s {
-            "claude-sonnet-4.6" => Model::ClaudeSonnet46,
-            "claude-opus-4.6" => Model::ClaudeOpus46,
-            "claude-sonnet-4.5" => Model::ClaudeSonnet45,
-            "claude-haiku-4.5" => Model::ClaudeHaiku45,
-            "claude-opus-4.5" => Model::ClaudeOpus45,
-            "claude-sonnet-4" => Model::ClaudeSonnet4,
-            "gpt-5.4" => Model::Gpt54,
-            "gpt-5.3-codex" => Model::Gpt53Codex,
-            "gpt-5.2-codex" => Model::Gpt52Codex,
-            "gpt-5.1-codex-max" => Model::Gpt51CodexMax,
-            "gpt-5.1-codex" => Model::Gpt51Codex,
-            "gpt-5.2" => Model::Gpt52,
-            "gpt-5.1" => Model::Gpt51,
-            "gpt-5" => Model::Gpt5,
-            "gpt-5.1-codex-mini" => Model::Gpt51CodexMini,
-            "gpt-5-mini" => Model::Gpt5Mini,
-            "gpt-4.1" => Model::Gpt41,
-            "gemini-3-pro-preview" => Model::Gemini3Pro,
-            "gemini-3.1-pro-preview" => Model::Gemini31Pro,
-            other => Model::Custom(other.to_string()),
-        })
+        let name = s.trim();
+        if name.is_empty() {
+            return Err(ModelParseError::Empty);
+        }
+        if let Some(model) = Model::all()
+            .iter()
+            .find(|m| m.as_str().eq_ignore_ascii_case(name))
+        {
+            return Ok(model.clone());
+        }
+        if let Some(model) = Model::from_alias(name) {
+            return Ok(model);
+        }
+        let key = normalized_key(name);
+        if let Some(model) = Model::all()
+            .iter()
+            .find(|m| normalized_key(m.as_str()) == key)
+        {
+            return Ok(model.clone());
+        }
+        if let Some(model) = typo_of(&key) {
+            return Err(ModelParseError::Unknown {
+                input: name.to_string(),
+                suggestion: model.clone(),
+            });
+        }
+        Ok(Model::Custom(name.to_string()))
     }
 }
 
@@ -163,7 +273,7 @@ impl<'de> Deserialize<'de> for Model {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        Ok(s.parse().unwrap())
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -188,6 +298,74 @@ mod tests {
         assert_eq!(model.to_string(), "custom-model-v1");
     }
 
+    #[test]
+    fn test_parse_is_case_insensitive() {
+        assert_eq!(
+            "Claude-Sonnet-4.5".parse::<Model>().unwrap(),
+            Model::ClaudeSonnet45
+        );
+        assert_eq!("GPT-5.4".parse::<Model>().unwrap(), Model::Gpt54);
+        assert_eq!(" gpt-5-mini ".parse::<Model>().unwrap(), Model::Gpt5Mini);
+    }
+
+    #[test]
+    fn test_dash_form_ids_resolve_to_builtin_model() {
+        assert_eq!(
+            "claude-sonnet-4-5".parse::<Model>().unwrap(),
+            Model::ClaudeSonnet45
+        );
+        assert_eq!("GPT5.4".parse::<Model>().unwrap(), Model::Gpt54);
+        let model: Model = serde_json::from_str("\"claude-opus-4-6\"").unwrap();
+        assert_eq!(model, Model::ClaudeOpus46);
+    }
+
+    #[test]
+    fn test_typo_suggests_builtin_model() {
+        let err = "claude-sonet-4.5".parse::<Model>().unwrap_err();
+        assert_eq!(
+            err,
+            ModelParseError::Unknown {
+                input: "claude-sonet-4.5".to_string(),
+                suggestion: Model::ClaudeSonnet45,
+            }
+        );
+        assert!(err.to_string().contains("did you mean 'claude-sonnet-4.5'"));
+        assert!(serde_json::from_str::<Model>("\"gpt-5-minii\"").is_err());
+    }
+
+    #[test]
+    fn test_other_versions_stay_custom_models() {
+        for name in ["gpt-4o", "claude-opus-4.1", "gpt-5-nano", "gemini-3-flash"] {
+            assert_eq!(
+                name.parse::<Model>().unwrap(),
+                Model::Custom(name.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_builtin_aliases_resolve_to_current_models() {
+        assert_eq!("sonnet".parse::<Model>().unwrap(), Model::ClaudeSonnet46);
+        assert_eq!("Opus".parse::<Model>().unwrap(), Model::ClaudeOpus46);
+        assert_eq!("haiku".parse::<Model>().unwrap(), Model::ClaudeHaiku45);
+        assert_eq!("gpt".parse::<Model>().unwrap(), Model::Gpt54);
+        assert_eq!("gemini".parse::<Model>().unwrap(), Model::Gemini31Pro);
+    }
+
+    #[test]
+    fn test_empty_model_name_is_an_error() {
+        assert_eq!("  ".parse::<Model>(), Err(ModelParseError::Empty));
+    }
+
+    #[test]
+    fn test_all_lists_builtin_models_only() {
+        assert_eq!(Model::all().len(), 19);
+        assert!(!Model::all().iter().any(|m| matches!(m, Model::Custom(_))));
+        for model in Model::all() {
+            assert_eq!(&model.as_str().parse::<Model>().unwrap(), model);
+        }
+    }
+
     #[test]
     fn test_model_family_detection() {
         assert!(Model::ClaudeSonnet45.is_claude());
@@ -0,0 +1,173 @@
+//! Heuristic intent classification for untyped user input.
+//!
+//! [`classify_intent`] guesses which [`InteractionForm`] fits a message the
+//! user typed without picking a form. It is deliberately conservative: only
+//! clear signals move a message away from [`InteractionForm::Agent`], the
+//! form plain input has always started.
+
+use super::InteractionForm;
+use crate::core::question::Question;
+
+/// Phrases that ask for a comparison or a judgment between options.
+const DISCUSS_PHRASES: &[&str] = &[
+    "compare",
+    "comparison",
+    "which is better",
+    "which one is better",
+    "which should i",
+    "pros and cons",
+    "trade-off",
+    "tradeoff",
+    " vs ",
+    " vs. ",
+    " versus ",
+    "比較",
+];
+
+/// Leading verbs that ask for a change to the project.
+const CHANGE_VERBS: &[&str] = &[
+    "add",
+    "build",
+    "bump",
+    "change",
+    "convert",
+    "create",
+    "delete",
+    "fix",
+    "generate",
+    "implement",
+    "install",
+    "migrate",
+    "modify",
+    "move",
+    "refactor",
+    "remove",
+    "rename",
+    "replace",
+    "rewrite",
+    "run",
+    "update",
+    "upgrade",
+    "write",
+];
+
+/// Leading words that open a question.
+const QUESTION_WORDS: &[&str] = &[
+    "what", "why", "how", "where", "when", "who", "which", "is", "are", "does", "do", "can",
+    "could", "should", "explain", "describe",
+];
+
+/// Polite prefixes skipped before looking at the leading verb
+/// ("please add …", "can you fix …").
+const POLITE_PREFIXES: &[&[&str]] = &[
+    &["please"],
+    &["can", "you"],
+    &["could", "you"],
+    &["would", "you"],
+];
+
+/// Guess the interaction form that fits `question`.
+///
+/// Rules, in order:
+/// 1. Comparison phrases ("compare", "which is better", " vs ") → `Discuss`
+/// 2. A leading change verb, optionally after "please" / "can you" → `Agent`
+/// 3. A leading question word or a trailing `?` → `Ask`
+/// 4. Anything else → `Agent`
+///
+/// # Examples
+///
+/// ```
+/// use quorum_domain::interaction::{InteractionForm, classify_intent};
+/// use quorum_domain::Question;
+///
+/// assert_eq!(classify_intent(&Question::new("what does main do?")), InteractionForm::Ask);
+/// assert_eq!(classify_intent(&Question::new("add logging to main")), InteractionForm::Agent);
+/// ```
+pub fn classify_intent(question: &Question) -> InteractionForm {
+    let text = question.content().trim().to_lowercase();
+
+    if DISCUSS_PHRASES.iter().any(|p| text.contains(p)) {
+        return InteractionForm::Discuss;
+    }
+
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let rest = POLITE_PREFIXES
+        .iter()
+        .find(|prefix| words.starts_with(prefix))
+        .map_or(&words[..], |prefix| &words[prefix.len()..]);
+    if rest.first().is_some_and(|w| CHANGE_VERBS.contains(w)) {
+        return InteractionForm::Agent;
+    }
+
+    let asks = words.first().is_some_and(|w| QUESTION_WORDS.contains(w))
+        || text.ends_with('?')
+        || text.ends_with('？');
+    if asks {
+        InteractionForm::Ask
+    } else {
+        InteractionForm::Agent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(s: &str) -> InteractionForm {
+        classify_intent(&Question::new(s))
+    }
+
+    #[test]
+    fn test_questions_are_ask() {
+        for prompt in [
+            "what does this function do?",
+            "Why is the build slow",
+            "How do I run the tests?",
+            "where is the config loaded",
+            "Explain the review flow",
+            "is this thread-safe?",
+            "main.rs の役割は？",
+        ] {
+            assert_eq!(classify(prompt), InteractionForm::Ask, "{}", prompt);
+        }
+    }
+
+    #[test]
+    fn test_change_requests_are_agent() {
+        for prompt in [
+            "add logging to main",
+            "Fix the failing test in vote.rs",
+            "please refactor the parser",
+            "can you rename Foo to Bar?",
+            "Implement retry with backoff.",
+            "update the README",
+        ] {
+            assert_eq!(classify(prompt), InteractionForm::Agent, "{}", prompt);
+        }
+    }
+
+    #[test]
+    fn test_comparisons_are_discuss() {
+        for prompt in [
+            "compare tokio and async-std for this project",
+            "Which is better here: an enum or a trait object?",
+            "pros and cons of moving config to TOML",
+            "thiserror vs anyhow for the domain layer",
+            "JoinSet と FuturesUnordered を比較して",
+        ] {
+            assert_eq!(classify(prompt), InteractionForm::Discuss, "{}", prompt);
+        }
+    }
+
+    #[test]
+    fn test_unclear_input_stays_agent() {
+        for prompt in ["logging in main", "the parser", "トークン数の上限"] {
+            assert_eq!(classify(prompt), InteractionForm::Agent, "{}", prompt);
+        }
+    }
+}
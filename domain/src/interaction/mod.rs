@@ -13,7 +13,9 @@
 //!
 //! # Nesting
 //!
-//! Interactions can spawn child interactions up to [`DEFAULT_MAX_NESTING_DEPTH`].
+//! Interactions can spawn child interactions up to [`DEFAULT_MAX_NESTING_DEPTH`]
+//! levels deep, or the depth set with [`InteractionTree::with_max_depth`]
+//! (`interaction.max_nesting_depth`).
 //! For example, an Agent task might spawn an Ask sub-interaction to clarify
 //! requirements, or a Discuss to get multi-model input on a design decision.
 //!
@@ -39,15 +41,24 @@ use crate::quorum::Vote;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+mod intent;
+
+pub use intent::classify_intent;
+
 /// Maximum length of the query summary embedded in parent notifications.
 const PARENT_NOTIFICATION_QUERY_MAX_LEN: usize = 60;
 
-/// Maximum nesting depth for interactions.
+/// Default maximum nesting depth for interactions.
 ///
 /// Prevents unbounded recursion when interactions spawn children.
 /// Depth 0 is the root interaction, so max depth of 3 allows 4 levels total.
+/// Overridden per tree with [`InteractionTree::with_max_depth`].
 pub const DEFAULT_MAX_NESTING_DEPTH: usize = 3;
 
+/// Per-form [`ContextMode`] overrides from config, consulted by
+/// [`InteractionForm::context_mode_with`].
+pub type ContextModeOverrides = HashMap<InteractionForm, ContextMode>;
+
 /// The form of an interaction — determines behavior, context defaults, and
 /// which config types are relevant.
 ///
@@ -93,6 +104,16 @@ impl InteractionForm {
         }
     }
 
+    /// The [`ContextMode`] for this form given config-provided overrides
+    /// (`interaction.<form>.context_mode`), falling back to
+    /// [`Self::default_context_mode`] for forms without an override.
+    pub fn context_mode_with(&self, overrides: &ContextModeOverrides) -> ContextMode {
+        overrides
+            .get(self)
+            .copied()
+            .unwrap_or_else(|| self.default_context_mode())
+    }
+
     /// Whether this form uses `SessionMode` for orchestration decisions.
     ///
     /// All forms use `SessionMode` (at minimum for model selection), but
@@ -251,9 +272,16 @@ impl Interaction {
         self
     }
 
-    /// Whether this interaction can spawn children (depth check).
+    /// Whether this interaction can spawn children under the default
+    /// [`DEFAULT_MAX_NESTING_DEPTH`].
     pub fn can_spawn(&self) -> bool {
-        self.depth < DEFAULT_MAX_NESTING_DEPTH
+        self.can_spawn_within(DEFAULT_MAX_NESTING_DEPTH)
+    }
+
+    /// Whether this interaction can spawn children when nesting is limited
+    /// to `max_depth`.
+    pub fn can_spawn_within(&self, max_depth: usize) -> bool {
+        self.depth < max_depth
     }
 }
 
@@ -262,14 +290,55 @@ impl Interaction {
 // =============================================================================
 
 /// Tree structure for nesting interactions with automatic id allocation.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct InteractionTree {
     nodes: HashMap<InteractionId, Interaction>,
     children: HashMap<InteractionId, Vec<InteractionId>>,
     next_id: usize,
+    max_depth: usize,
+    /// Completed children's context injections, queued per parent until the
+    /// parent's next request.
+    pending_results: HashMap<InteractionId, Vec<String>>,
+}
+
+impl Default for InteractionTree {
+    fn default() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            children: HashMap::new(),
+            next_id: 0,
+            max_depth: DEFAULT_MAX_NESTING_DEPTH,
+            pending_results: HashMap::new(),
+        }
+    }
 }
 
 impl InteractionTree {
+    /// Limit nesting to `max_depth` levels below the root instead of
+    /// [`DEFAULT_MAX_NESTING_DEPTH`]. `0` disables child interactions.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Change the nesting limit; existing deeper nodes are kept, but can no
+    /// longer spawn.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
+    /// The nesting limit this tree enforces.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Whether interaction `id` can spawn a child under this tree's limit.
+    pub fn can_spawn(&self, id: InteractionId) -> bool {
+        self.nodes
+            .get(&id)
+            .is_some_and(|node| node.can_spawn_within(self.max_depth))
+    }
+
     /// Create and register a root interaction.
     pub fn create_root(&mut self, form: InteractionForm) -> InteractionId {
         let id = self.allocate_id();
@@ -279,6 +348,19 @@ impl InteractionTree {
         id
     }
 
+    /// Create and register a root interaction with an explicit context mode.
+    pub fn create_root_with_context(
+        &mut self,
+        form: InteractionForm,
+        context_mode: ContextMode,
+    ) -> InteractionId {
+        let id = self.create_root(form);
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.context_mode = context_mode;
+        }
+        id
+    }
+
     /// Spawn a child interaction with the form's default context mode.
     pub fn spawn_child(
         &mut self,
@@ -313,6 +395,27 @@ impl InteractionTree {
         self.children.get(&id).map(|children| children.as_slice())
     }
 
+    /// Queue a completed child's [`InteractionResult::to_context_injection`]
+    /// for its parent. Returns the parent, or `None` for a root interaction
+    /// (nothing to inject into).
+    pub fn record_result(
+        &mut self,
+        child_id: InteractionId,
+        result: &InteractionResult,
+    ) -> Option<InteractionId> {
+        let parent_id = self.parent_of(child_id)?;
+        self.pending_results
+            .entry(parent_id)
+            .or_default()
+            .push(result.to_context_injection());
+        Some(parent_id)
+    }
+
+    /// Take the queued child results for `id`, in completion order.
+    pub fn take_child_results(&mut self, id: InteractionId) -> Vec<String> {
+        self.pending_results.remove(&id).unwrap_or_default()
+    }
+
     fn allocate_id(&mut self) -> InteractionId {
         let id = InteractionId(self.next_id);
         self.next_id += 1;
@@ -330,11 +433,10 @@ impl InteractionTree {
             .get(&parent_id)
             .cloned()
             .ok_or(SpawnError::ParentNotFound(parent_id))?;
-        let child_depth = parent.depth + 1;
-        if child_depth > DEFAULT_MAX_NESTING_DEPTH {
+        if !parent.can_spawn_within(self.max_depth) {
             return Err(SpawnError::MaxDepthExceeded {
-                depth: child_depth,
-                max: DEFAULT_MAX_NESTING_DEPTH,
+                depth: parent.depth + 1,
+                max: self.max_depth,
             });
         }
         let id = self.allocate_id();
@@ -537,6 +639,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_interaction_form_context_mode_with_overrides() {
+        let overrides = ContextModeOverrides::from([(InteractionForm::Ask, ContextMode::Full)]);
+        assert_eq!(
+            InteractionForm::Ask.context_mode_with(&overrides),
+            ContextMode::Full
+        );
+        // Forms without an override keep their compiled default
+        assert_eq!(
+            InteractionForm::Agent.context_mode_with(&overrides),
+            InteractionForm::Agent.default_context_mode()
+        );
+        assert_eq!(
+            InteractionForm::Review.context_mode_with(&overrides),
+            ContextMode::Fresh
+        );
+
+        let mut tree = InteractionTree::default();
+        let ask = tree.create_root_with_context(
+            InteractionForm::Ask,
+            InteractionForm::Ask.context_mode_with(&overrides),
+        );
+        assert_eq!(tree.get(ask).unwrap().context_mode, ContextMode::Full);
+        assert_eq!(tree.get(ask).unwrap().depth, 0);
+    }
+
     #[test]
     fn test_interaction_form_uses_session_mode() {
         // All forms use SessionMode
@@ -715,6 +843,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_interaction_tree_custom_max_depth() {
+        let mut tree = InteractionTree::default().with_max_depth(5);
+        let mut current_id = tree.create_root(InteractionForm::Agent);
+
+        // Deeper than the default, within the configured limit
+        for _ in 0..5 {
+            assert!(tree.can_spawn(current_id));
+            current_id = tree
+                .spawn_child(current_id, InteractionForm::Ask)
+                .expect("spawn within configured depth");
+        }
+        assert_eq!(tree.get(current_id).unwrap().depth, 5);
+        assert!(!tree.can_spawn(current_id));
+
+        let err = tree
+            .spawn_child(current_id, InteractionForm::Ask)
+            .unwrap_err();
+        assert_eq!(err, SpawnError::MaxDepthExceeded { depth: 6, max: 5 });
+    }
+
+    #[test]
+    fn test_interaction_tree_smaller_max_depth() {
+        let mut tree = InteractionTree::default().with_max_depth(1);
+        let root_id = tree.create_root(InteractionForm::Agent);
+        let child_id = tree.spawn_child(root_id, InteractionForm::Ask).unwrap();
+
+        let err = tree
+            .spawn_child(child_id, InteractionForm::Discuss)
+            .unwrap_err();
+        assert_eq!(err, SpawnError::MaxDepthExceeded { depth: 2, max: 1 });
+        assert_eq!(
+            err.to_string(),
+            "Max nesting depth exceeded (depth 2, max 1)"
+        );
+
+        // No nesting at all
+        let mut flat = InteractionTree::default().with_max_depth(0);
+        let root_id = flat.create_root(InteractionForm::Agent);
+        assert!(!flat.can_spawn(root_id));
+        assert!(flat.spawn_child(root_id, InteractionForm::Ask).is_err());
+    }
+
+    #[test]
+    fn test_interaction_tree_child_results_in_completion_order() {
+        let mut tree = InteractionTree::default();
+        let root_id = tree.create_root(InteractionForm::Agent);
+        let ask_id = tree.spawn_child(root_id, InteractionForm::Ask).unwrap();
+        let discuss_id = tree.spawn_child(root_id, InteractionForm::Discuss).unwrap();
+
+        // Discuss finishes first
+        let discussed = InteractionResult::DiscussResult {
+            synthesis: "Use JWT".to_string(),
+            participant_count: 2,
+        };
+        let asked = InteractionResult::AskResult {
+            answer: "42".to_string(),
+        };
+        assert_eq!(tree.record_result(discuss_id, &discussed), Some(root_id));
+        assert_eq!(tree.record_result(ask_id, &asked), Some(root_id));
+        // A root has no parent to inject into
+        assert_eq!(tree.record_result(root_id, &asked), None);
+
+        assert_eq!(
+            tree.take_child_results(root_id),
+            vec![
+                "[Discuss Result (2 models)]: Use JWT".to_string(),
+                "[Ask Result]: 42".to_string(),
+            ]
+        );
+        // Taken once
+        assert!(tree.take_child_results(root_id).is_empty());
+    }
+
     #[test]
     fn test_interaction_tree_parent_not_found() {
         let mut tree = InteractionTree::default();
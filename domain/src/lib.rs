@@ -33,28 +33,35 @@ pub mod util;
 
 // Re-export commonly used types
 pub use agent::{
-    agent_policy::{AgentPolicy, HilAction},
+    action_batch::{ActionBatch, BatchActionOutcome, QueuedAction},
+    agent_policy::{AgentPolicy, EnsembleSelection, FailurePolicy, HilAction, HilTimeoutDefault},
     entities::{
         AgentPhase, AgentState, EnsemblePlanResult, HilMode, HumanDecision, Plan, PlanCandidate,
-        ReviewRound, Task, TaskStatus,
+        PlanImpact, ReviewRound, Task, TaskStatus,
     },
     model_config::ModelConfig,
     status::AgentStatus,
-    tool_execution::{ToolExecution, ToolExecutionId, ToolExecutionState},
+    tool_execution::{ToolExecution, ToolExecutionId, ToolExecutionState, ToolStat},
     validation::{ConfigIssue, ConfigIssueCode, Severity},
     value_objects::{AgentContext, AgentId, TaskId, TaskResult, Thought, ThoughtType},
 };
 pub use config::{
-    ConfigKeyInfo, Mutability, OutputFormat, SupervisorReporterMode, known_keys, lookup_key,
+    ConfigKeyInfo, IntentRouting, Mutability, OutputFormat, SupervisorReporterMode, known_keys,
+    lookup_key,
 };
 pub use context::{
-    ContextBudget, ContextMode, KnownContextFile, LoadedContextFile, ProjectContext,
-    ResourceReference, TaskResultBuffer, extract_references,
+    ContextBudget, ContextFileLimits, ContextMode, DecisionOutcome, DecisionRecord,
+    KnownContextFile, LoadedContextFile, ProjectContext, ReferenceProvider, ResourceReference,
+    TaskResultBuffer, detect_project_type, extract_references,
+};
+pub use core::{
+    error::DomainError,
+    model::{Model, ModelParseError},
+    question::Question,
 };
-pub use core::{error::DomainError, model::Model, question::Question};
 pub use interaction::{
-    DEFAULT_MAX_NESTING_DEPTH, Interaction, InteractionForm, InteractionId, InteractionResult,
-    InteractionTree, SpawnError,
+    ContextModeOverrides, DEFAULT_MAX_NESTING_DEPTH, Interaction, InteractionForm, InteractionId,
+    InteractionResult, InteractionTree, SpawnError, classify_intent,
 };
 pub use orchestration::{
     entities::{Phase, QuorumRun},
@@ -67,31 +74,37 @@ pub use orchestration::{
 };
 pub use prompt::{AgentPromptTemplate, DebatePromptTemplate, PromptTemplate, ReviewPromptTemplate};
 pub use providers::{
-    AnthropicProviderConfig, BedrockProviderConfig, OpenAiProviderConfig, ProviderConfig,
+    AnthropicProviderConfig, BedrockProviderConfig, CopilotProviderConfig, OpenAiProviderConfig,
+    ProviderConfig,
 };
 pub use scripting::{ScriptEventData, ScriptEventType, ScriptValue};
 pub use session::{
     entities::Message,
     repository::LlmSessionRepository,
-    response::{ContentBlock, LlmResponse, StopReason},
+    response::{ContentBlock, LlmResponse, StopReason, TokenUsage},
     stream::StreamEvent,
 };
 pub use tool::{
     detection::looks_like_tool_call_json,
     entities::{
         RiskLevel, ToolCall, ToolDefinition, ToolParameter, ToolSpec, classify_command_risk,
+        classify_command_risk_with_overrides,
     },
-    traits::{DefaultToolValidator, ToolValidator},
+    timeouts::ToolTimeouts,
+    traits::{DefaultToolValidator, ToolValidator, unknown_arguments},
     value_objects::{ErrorCategory, ToolError, ToolResult, ToolResultMetadata},
 };
 
 // Re-export quorum types
 pub use quorum::{
-    ConsensusOutcome, ConsensusRound, Objection, ObjectionLedger, ObjectionSeverity,
+    ConsensusMode, ConsensusOutcome, ConsensusRound, Objection, ObjectionLedger, ObjectionSeverity,
     ObjectionStatus, QUORUM_RESULT_API_VERSION, QUORUM_RESULT_EVENT_TYPE, QuorumResultPayload,
-    QuorumRule, QuorumTarget, QuorumTopic, Vote, VoteResult, VoteVerdict,
-    parse_final_review_response, parse_review_response, parse_vote_score,
+    QuorumRule, QuorumTarget, QuorumTopic, ReviewSubmission, ScoreTally, Vote, VoteResult,
+    VoteVerdict, extract_review_submission, parse_final_review_response, parse_review_response,
+    parse_vote_score,
 };
 
 // Re-export plan parser
-pub use agent::plan_parser::{extract_plan_from_response, parse_plan, parse_plan_json};
+pub use agent::plan_parser::{
+    PlanParseError, extract_plan_from_response, parse_plan, parse_plan_json,
+};
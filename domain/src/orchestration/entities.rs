@@ -10,10 +10,12 @@
 //!
 //! - [`Phase`] - Represents the current phase of a Quorum run
 //! - [`QuorumConfig`] - Configuration for which models participate and how
-//! - [`QuorumRun`] - Tracks the state of a single Quorum session
+//! - [`QuorumRun`] - Tracks the state of a single Quorum session, including
+//!   every [`ConsensusRound`] voted on during it
 
 use crate::core::model::Model;
 use crate::core::question::Question;
+use crate::quorum::{ConsensusOutcome, ConsensusRound};
 use serde::{Deserialize, Serialize};
 
 /// Phase of a Quorum run
@@ -120,13 +122,17 @@ impl QuorumConfig {
 
 /// Represents a single Quorum run (Entity)
 ///
-/// Tracks the state and progress of a Quorum discussion.
-#[derive(Debug, Clone)]
+/// Tracks the state and progress of a Quorum discussion, and the history of
+/// consensus rounds (votes, scores, feedback) so the deliberation can be
+/// inspected after the fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuorumRun {
     id: String,
     question: Question,
     config: QuorumConfig,
     current_phase: Option<Phase>,
+    #[serde(default)]
+    rounds: Vec<ConsensusRound>,
 }
 
 impl QuorumRun {
@@ -139,6 +145,7 @@ impl QuorumRun {
             question,
             config,
             current_phase: None,
+            rounds: Vec::new(),
         }
     }
 
@@ -167,6 +174,26 @@ impl QuorumRun {
         self.current_phase = Some(phase);
     }
 
+    /// Appends a consensus round to the history.
+    pub fn record_round(&mut self, round: ConsensusRound) {
+        self.rounds.push(round);
+    }
+
+    /// Returns every consensus round recorded so far, oldest first.
+    pub fn rounds(&self) -> &[ConsensusRound] {
+        &self.rounds
+    }
+
+    /// Number the next recorded round should carry (1-indexed).
+    pub fn next_round_number(&self) -> usize {
+        self.rounds.len() + 1
+    }
+
+    /// Returns the outcome of the latest round, or `None` before any vote.
+    pub fn final_outcome(&self) -> Option<ConsensusOutcome> {
+        self.rounds.last().map(|r| r.outcome)
+    }
+
     /// Returns the sequence of phases that will be executed.
     ///
     /// If review is disabled in config, returns `[Initial, Synthesis]`.
@@ -179,3 +206,53 @@ impl QuorumRun {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quorum::{QuorumRule, Vote, VoteResult};
+
+    #[test]
+    fn test_quorum_run_records_round_history() {
+        let mut run = QuorumRun::new(
+            "run-1",
+            Question::new("Add authentication"),
+            QuorumConfig::new(vec![Model::ClaudeSonnet45, Model::Gpt53Codex]),
+        );
+        assert!(run.rounds().is_empty());
+        assert_eq!(run.final_outcome(), None);
+
+        let rejected = VoteResult::from_votes(vec![
+            Vote::approve("claude-sonnet-4.5", "OK"),
+            Vote::reject("gpt-5.3-codex", "No password hashing"),
+        ]);
+        run.record_round(ConsensusRound::from_result(
+            run.next_round_number(),
+            rejected,
+        ));
+        assert_eq!(run.final_outcome(), Some(ConsensusOutcome::Rejected));
+
+        let approved = VoteResult::from_votes(vec![
+            Vote::approve("claude-sonnet-4.5", "OK"),
+            Vote::approve("gpt-5.3-codex", "Hashing added"),
+        ]);
+        run.record_round(
+            ConsensusRound::from_result(run.next_round_number(), approved)
+                .with_rule(QuorumRule::Unanimous),
+        );
+
+        let rounds = run.rounds();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].round, 1);
+        assert!(rounds[0].is_rejected());
+        assert!(
+            rounds[0]
+                .rejection_feedback()
+                .contains("No password hashing")
+        );
+        assert_eq!(rounds[1].round, 2);
+        assert!(rounds[1].is_approved());
+        assert_eq!(rounds[1].rule, QuorumRule::Unanimous);
+        assert_eq!(run.final_outcome(), Some(ConsensusOutcome::Approved));
+    }
+}
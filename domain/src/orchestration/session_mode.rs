@@ -22,7 +22,7 @@ use serde::{Deserialize, Serialize};
 /// invalid state "Solo + Debate", but the flat structure is intentional:
 /// TUI users switching `/solo` → `/ens` expect their previous strategy setting
 /// to be preserved. The flat structure makes this natural.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SessionMode {
     pub consensus_level: ConsensusLevel,
     pub phase_scope: PhaseScope,
@@ -13,6 +13,7 @@
 use crate::core::model::Model;
 use crate::orchestration::entities::{Phase, QuorumConfig};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Orchestration strategy — determines how multi-model discussion is conducted.
 ///
@@ -20,7 +21,7 @@ use serde::{Deserialize, Serialize};
 /// [`ConsensusLevel`](super::mode::ConsensusLevel): strategies define *how*
 /// models discuss, while consensus level defines *whether* multiple models
 /// participate at all.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum OrchestrationStrategy {
     /// Quorum strategy: equal discussion → review → synthesis.
     /// Uses the existing [`QuorumConfig`] for configuration.
@@ -87,7 +88,7 @@ impl std::fmt::Display for OrchestrationStrategy {
 ///
 /// Debate is an adversarial discussion format where models argue
 /// opposing positions, moderated by an optional moderator model.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DebateConfig {
     /// Models participating in the debate. Empty by default — falls back to
     /// `ModelConfig.participants` at runtime (see `DebateStrategyExecutor::roster()`
@@ -102,6 +103,16 @@ pub struct DebateConfig {
     pub allow_interjection: bool,
     /// Maximum number of debate rounds
     pub max_rounds: usize,
+    /// Token-overlap similarity (0.0–1.0) at which a participant's response
+    /// counts as unchanged from the previous round. Once every participant
+    /// is at or above it, the debate stops early. Values above 1.0 disable
+    /// convergence detection.
+    #[serde(default = "default_convergence_threshold")]
+    pub convergence_threshold: f64,
+}
+
+fn default_convergence_threshold() -> f64 {
+    0.9
 }
 
 impl Default for DebateConfig {
@@ -112,10 +123,42 @@ impl Default for DebateConfig {
             intensity: DebateIntensity::default(),
             allow_interjection: false,
             max_rounds: 3,
+            convergence_threshold: default_convergence_threshold(),
         }
     }
 }
 
+impl DebateConfig {
+    pub fn with_convergence_threshold(mut self, threshold: f64) -> Self {
+        self.convergence_threshold = threshold;
+        self
+    }
+
+    /// Whether `current` restates `previous` closely enough to count as a
+    /// stable position (see [`token_overlap`]).
+    pub fn is_unchanged(&self, previous: &str, current: &str) -> bool {
+        token_overlap(previous, current) >= self.convergence_threshold
+    }
+}
+
+/// Jaccard similarity of the lowercase word sets of `a` and `b`.
+///
+/// Returns 1.0 for two texts without any words, 0.0 when only one has words.
+pub fn token_overlap(a: &str, b: &str) -> f64 {
+    fn words(text: &str) -> HashSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    }
+    let (a, b) = (words(a), words(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
 /// Intensity level for debate strategy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum DebateIntensity {
@@ -183,6 +226,31 @@ mod tests {
         assert!(DebateConfig::default().models.is_empty());
     }
 
+    #[test]
+    fn test_token_overlap() {
+        assert_eq!(token_overlap("Use Rust.", "use rust"), 1.0);
+        assert_eq!(token_overlap("", ""), 1.0);
+        assert_eq!(token_overlap("alpha", ""), 0.0);
+        assert_eq!(token_overlap("a b c d", "a b x y"), 2.0 / 6.0);
+    }
+
+    #[test]
+    fn test_debate_config_is_unchanged() {
+        let config = DebateConfig::default();
+        assert!(config.is_unchanged("Keep the cache.", "keep the cache"));
+        assert!(!config.is_unchanged("Keep the cache.", "Drop the cache entirely."));
+
+        let disabled = DebateConfig::default().with_convergence_threshold(1.1);
+        assert!(!disabled.is_unchanged("same", "same"));
+    }
+
+    #[test]
+    fn test_debate_config_threshold_defaults_when_missing() {
+        let json = r#"{"models":[],"moderator":null,"intensity":"Mild","allow_interjection":false,"max_rounds":2}"#;
+        let config: DebateConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.convergence_threshold, 0.9);
+    }
+
     #[test]
     fn test_debate_intensity() {
         assert_eq!(DebateIntensity::default(), DebateIntensity::Mild);
@@ -18,6 +18,8 @@ pub enum StreamContext {
     QuorumReview,
     /// Quorum Discussion Phase 3: moderator synthesis.
     QuorumSynthesis,
+    /// Agent plan review: a reviewer's reasoning before its vote.
+    PlanReview,
 }
 
 impl StreamContext {
@@ -28,6 +30,7 @@ impl StreamContext {
             Self::QuorumInitial => "Initial Query",
             Self::QuorumReview => "Peer Review",
             Self::QuorumSynthesis => "Synthesis",
+            Self::PlanReview => "Plan Review",
         }
     }
 }
@@ -51,6 +54,7 @@ mod tests {
         assert_eq!(StreamContext::QuorumInitial.to_string(), "Initial Query");
         assert_eq!(StreamContext::QuorumReview.to_string(), "Peer Review");
         assert_eq!(StreamContext::QuorumSynthesis.to_string(), "Synthesis");
+        assert_eq!(StreamContext::PlanReview.to_string(), "Plan Review");
     }
 
     #[test]
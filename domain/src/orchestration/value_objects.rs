@@ -4,7 +4,8 @@
 //! - [`ModelResponse`] - Individual model's answer from the Initial Query phase
 //! - [`PeerReview`] - Review of one model's response by another
 //! - [`SynthesisResult`] - Final combined answer from the moderator
-//! - [`QuorumResult`] - Complete result containing all phases
+//! - [`QuorumResult`] - Complete result containing all phases (exportable via
+//!   [`QuorumResult::to_markdown`])
 
 use serde::{Deserialize, Serialize};
 
@@ -196,4 +197,132 @@ impl QuorumResult {
     pub fn failed_responses(&self) -> impl Iterator<Item = &ModelResponse> {
         self.responses.iter().filter(|r| !r.success)
     }
+
+    /// Renders the full discussion transcript as a Markdown document.
+    ///
+    /// Each response and review gets its own `###` header naming the model;
+    /// peer review scores are included when present. Model names are
+    /// escaped so characters like `*` or `#` don't alter the heading.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Quorum Discussion\n\n");
+        out.push_str(&format!("**Question:** {}\n\n", self.question));
+        if !self.models.is_empty() {
+            let models: Vec<String> = self.models.iter().map(|m| escape_markdown(m)).collect();
+            out.push_str(&format!("**Participants:** {}\n\n", models.join(", ")));
+        }
+
+        out.push_str("## Responses\n\n");
+        for response in &self.responses {
+            let model = escape_markdown(&response.model);
+            if response.success {
+                out.push_str(&format!("### {}\n\n{}\n\n", model, response.content.trim()));
+            } else {
+                out.push_str(&format!(
+                    "### {} (failed)\n\n_Error: {}_\n\n",
+                    model,
+                    response.error.as_deref().unwrap_or("unknown error")
+                ));
+            }
+        }
+
+        if !self.reviews.is_empty() {
+            out.push_str("## Peer Reviews\n\n");
+            for review in &self.reviews {
+                out.push_str(&format!(
+                    "### {} on {}\n\n",
+                    escape_markdown(&review.reviewer),
+                    escape_markdown(&review.reviewed_id)
+                ));
+                if let Some(score) = review.score {
+                    out.push_str(&format!("**Score:** {}/10\n\n", score));
+                }
+                out.push_str(&format!("{}\n\n", review.content.trim()));
+            }
+        }
+
+        let synthesis = &self.synthesis;
+        out.push_str("## Synthesis\n\n");
+        out.push_str(&format!(
+            "_Moderator: {}_\n\n{}\n",
+            escape_markdown(&synthesis.moderator),
+            synthesis.conclusion.trim()
+        ));
+        for (title, items) in [
+            ("Key Points", &synthesis.key_points),
+            ("Consensus", &synthesis.consensus),
+            ("Disagreements", &synthesis.disagreements),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n### {}\n\n", title));
+            for item in items {
+                out.push_str(&format!("- {}\n", item));
+            }
+        }
+        out
+    }
+}
+
+/// Backslash-escapes inline Markdown syntax and flattens newlines, so a
+/// value can be embedded in a heading or emphasis span verbatim.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '<' | '>' | '|' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' | '\r' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_markdown_renders_all_phases() {
+        let result = QuorumResult::new(
+            "Cache strategy?",
+            vec!["gpt-5".to_string(), "claude_sonnet".to_string()],
+            vec![
+                ModelResponse::success("gpt-5", "Write-through."),
+                ModelResponse::success("claude_sonnet", "Write-behind."),
+            ],
+            vec![PeerReview::new("gpt-5", "Response B", "Latency is overstated.").with_score(6)],
+            SynthesisResult::new("claude_sonnet", "Write-through wins.")
+                .with_disagreements(vec!["Latency impact".to_string()]),
+        );
+
+        let markdown = result.to_markdown();
+        assert!(markdown.starts_with("# Quorum Discussion\n\n**Question:** Cache strategy?"));
+        assert!(markdown.contains("### gpt-5\n\nWrite-through.\n"));
+        assert!(markdown.contains("### claude\\_sonnet\n\nWrite-behind.\n"));
+        assert!(markdown.contains("### gpt-5 on Response B\n\n**Score:** 6/10\n"));
+        assert!(
+            markdown
+                .contains("## Synthesis\n\n_Moderator: claude\\_sonnet_\n\nWrite-through wins.\n")
+        );
+        assert!(markdown.ends_with("### Disagreements\n\n- Latency impact\n"));
+    }
+
+    #[test]
+    fn test_to_markdown_escapes_model_headers() {
+        let result = QuorumResult::new(
+            "Q",
+            vec![],
+            vec![ModelResponse::failure("model*[beta]#1", "timeout")],
+            vec![],
+            SynthesisResult::new("mod", "Done."),
+        );
+
+        let markdown = result.to_markdown();
+        assert!(markdown.contains("### model\\*\\[beta\\]\\#1 (failed)\n\n_Error: timeout_\n"));
+        assert!(!markdown.contains("## Peer Reviews"));
+    }
 }
@@ -1,7 +1,8 @@
 //! Prompt templates for the Agent system
 
-use crate::agent::{AgentContext, Plan, Task};
+use crate::agent::{AgentContext, Plan, PlanCandidate, Task};
 use crate::context::ContextMode;
+use crate::quorum::Vote;
 use serde_json::json;
 
 /// Templates for generating agent prompts
@@ -120,6 +121,40 @@ Call tools directly without preamble. Do not wrap tool calls in code blocks."#
         })
     }
 
+    /// JSON Schema for the `submit_review` virtual tool.
+    ///
+    /// Passed to `send_with_tools()` during plan and action review so the
+    /// reviewer returns its verdict as structured data. Like `create_plan`,
+    /// it is never registered in `ToolSpec` or executed.
+    pub fn review_tool_schema() -> serde_json::Value {
+        json!({
+            "name": "submit_review",
+            "description": "Submit your review verdict. Call this exactly once with all fields.",
+            "input_schema": {
+                "type": "object",
+                "properties": {
+                    "approved": {
+                        "type": "boolean",
+                        "description": "true to approve, false to reject or request revision"
+                    },
+                    "score": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 10,
+                        "description": "Overall quality from 1 (unacceptable) to 10 (excellent)"
+                    },
+                    "reasoning": {
+                        "type": "string",
+                        "description": "Specific feedback, concerns and suggested changes",
+                        "minLength": 1
+                    }
+                },
+                "required": ["approved", "score", "reasoning"],
+                "additionalProperties": false
+            }
+        })
+    }
+
     /// Prompt for context gathering phase
     pub fn context_gathering(request: &str, project_root: Option<&str>) -> String {
         let root_info = project_root
@@ -165,6 +200,22 @@ Output your findings in a structured format."#,
     ) -> String {
         let context_info = context.to_prompt_context();
 
+        let decisions_section = if context.prior_decisions.is_empty() {
+            String::new()
+        } else {
+            let lines: Vec<String> = context
+                .prior_decisions
+                .iter()
+                .map(|d| format!("- {}", d.to_prompt_line()))
+                .collect();
+            format!(
+                "\n\n## Prior Decisions\n\n\
+                 Plans approved in earlier sessions, oldest first. Stay consistent with them \
+                 unless the request says otherwise:\n\n{}",
+                lines.join("\n")
+            )
+        };
+
         let feedback_section = previous_feedback
             .map(|fb| {
                 format!(
@@ -190,7 +241,7 @@ Create a detailed plan to accomplish the user's request.
 
 ## Project Context
 
-{context_info}
+{context_info}{decisions_section}
 
 ## User Request
 
@@ -235,6 +286,7 @@ specific conventions/patterns matters more than knowing the whole project.
 
 Use the `create_plan` tool to submit your plan. Be thorough but focused. Don't include unnecessary steps."#,
             context_info = context_info,
+            decisions_section = decisions_section,
             request = request,
             feedback_section = feedback_section
         )
@@ -297,7 +349,9 @@ Evaluate the plan for:
 Provide your assessment with:
 - Overall recommendation: APPROVE or REVISE
 - Specific feedback and suggestions
-- Any concerns about safety or correctness"#,
+- Any concerns about safety or correctness
+
+Submit your verdict with the `submit_review` tool."#,
             request = request,
             context_info = context_info,
             objective = plan.objective,
@@ -306,6 +360,69 @@ Provide your assessment with:
         )
     }
 
+    /// Prompt for breaking an exact tie in plan review
+    ///
+    /// Sent to the configured tie-breaker model with the plan and the
+    /// split reviewer votes. The model must answer APPROVE or REVISE so the
+    /// response can be parsed like a regular plan review.
+    pub fn plan_tie_break(request: &str, plan: &Plan, votes: &[Vote]) -> String {
+        let tasks_description = plan
+            .tasks
+            .iter()
+            .enumerate()
+            .map(|(i, t)| format!("{}. {}", i + 1, t.description))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let votes_description = votes
+            .iter()
+            .filter(|v| v.is_cast())
+            .map(|v| {
+                let verdict = if v.is_approve() { "APPROVE" } else { "REVISE" };
+                format!("### {} — {}\n\n{}", v.model, verdict, v.reasoning)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        format!(
+            r#"## Task
+
+The review committee is evenly split on the following plan. You hold the
+deciding vote.
+
+## User Request
+
+{request}
+
+## Proposed Plan
+
+**Objective**: {objective}
+
+**Reasoning**: {reasoning}
+
+**Tasks**:
+{tasks}
+
+## Reviewer Votes
+
+{votes}
+
+## Instructions
+
+Weigh the arguments on both sides and make a final decision.
+
+Provide:
+- Final recommendation: APPROVE or REVISE
+- The deciding reason in one or two sentences
+
+Submit your verdict with the `submit_review` tool."#,
+            request = request,
+            objective = plan.objective,
+            reasoning = plan.reasoning,
+            tasks = tasks_description,
+            votes = votes_description
+        )
+    }
+
     /// Prompt for ensemble plan voting
     ///
     /// Used during ensemble planning when one model evaluates another model's plan.
@@ -386,6 +503,78 @@ Respond with JSON only:
         )
     }
 
+    /// Prompt for merging ensemble plan candidates
+    ///
+    /// Used when `agent.ensemble_selection = "merge"`: after voting, the
+    /// moderator receives the top-scoring candidates (best first) and submits
+    /// a single plan via the `create_plan` tool that keeps the strongest steps
+    /// of each. If no plan comes back, the winning candidate is used as-is.
+    pub fn merge_plans(request: &str, candidates: &[&PlanCandidate]) -> String {
+        let candidates_description = candidates
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let tasks = c
+                    .plan
+                    .tasks
+                    .iter()
+                    .map(|t| {
+                        let tool_info = t
+                            .tool_name
+                            .as_ref()
+                            .map(|n| format!(" (using {})", n))
+                            .unwrap_or_default();
+                        let deps = if t.depends_on.is_empty() {
+                            String::new()
+                        } else {
+                            let deps: Vec<&str> = t.depends_on.iter().map(|d| d.as_str()).collect();
+                            format!(" [after {}]", deps.join(", "))
+                        };
+                        format!("{}. {}{}{}", t.id, t.description, tool_info, deps)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!(
+                    "### Candidate {n} — {model} (score {score:.1}/10)\n\n**Objective**: {objective}\n\n**Reasoning**: {reasoning}\n\n**Tasks**:\n{tasks}",
+                    n = i + 1,
+                    model = c.model,
+                    score = c.average_score(),
+                    objective = c.plan.objective,
+                    reasoning = c.plan.reasoning,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        format!(
+            r#"## Task
+
+Several models independently planned the same request, and the plans below
+scored highest in peer voting (best first). Merge them into ONE plan.
+
+## Original Request
+
+{request}
+
+## Candidate Plans
+
+{candidates}
+
+## Merge Guidelines
+
+- Start from the highest-scoring candidate and keep its overall structure
+- Add steps from other candidates only when they cover something it misses
+- Drop duplicate or redundant steps; do not simply concatenate the plans
+- Keep dependencies consistent with the final task numbering
+
+## Submitting Your Plan
+
+Use the `create_plan` tool to submit the merged plan."#,
+            request = request,
+            candidates = candidates_description
+        )
+    }
+
     /// Prompt for task execution.
     ///
     /// When a tool is specified, the instruction is placed at the TOP of the
@@ -507,7 +696,9 @@ This is a high-risk operation. Evaluate:
 Provide your assessment with:
 - Recommendation: APPROVE or REJECT
 - Specific concerns (if any)
-- Suggested modifications (if applicable)"#,
+- Suggested modifications (if applicable)
+
+Submit your verdict with the `submit_review` tool."#,
             context_info = context_info,
             description = task.description,
             tool_call = tool_call
@@ -747,7 +938,16 @@ Evaluate the overall execution:
 Provide:
 - Overall assessment: SUCCESS, PARTIAL, or FAILURE
 - Summary of what was accomplished
-- Any recommendations or follow-up actions"#,
+- Any recommendations or follow-up actions
+
+If the assessment is PARTIAL or FAILURE and concrete fixes would resolve it,
+add them as a follow-up plan (they will be executed):
+
+```plan
+{{"objective": "Fix ...", "tasks": [{{"id": "1", "description": "...", "tool": "...", "args": {{}}}}]}}
+```
+
+Omit the plan block when there is nothing concrete to fix."#,
             request = request,
             objective = plan.objective,
             tasks_summary = tasks_summary,
@@ -800,6 +1000,69 @@ mod tests {
         assert!(!prompt.contains("```plan"));
     }
 
+    #[test]
+    fn test_planning_prompt_lists_prior_decisions() {
+        use crate::context::{DecisionOutcome, DecisionRecord};
+
+        let mut context = AgentContext::new().with_project_type("rust");
+        assert!(
+            !AgentPromptTemplate::planning("Add caching", &context).contains("Prior Decisions")
+        );
+
+        context.prior_decisions = vec![DecisionRecord::new(
+            "Switch the config format",
+            "Migrate config from TOML to Lua",
+            DecisionOutcome::QuorumApproved,
+            0,
+        )];
+        let prompt = AgentPromptTemplate::planning("Add caching", &context);
+
+        assert!(prompt.contains("## Prior Decisions"));
+        assert!(prompt.contains(
+            "- Migrate config from TOML to Lua (quorum_approved) — request: Switch the config format"
+        ));
+    }
+
+    #[test]
+    fn test_merge_plans_prompt() {
+        use crate::core::model::Model;
+
+        let mut first = Plan::new("Fix the bug", "Reproduce first");
+        first.add_task(Task::new("1", "Read the failing test").with_tool("read_file"));
+        let mut second = Plan::new("Fix the bug", "Patch directly");
+        second.add_task(Task::new("1", "Edit the parser"));
+        second.add_task(Task::new("2", "Run tests").with_dependency("1"));
+        let mut a = PlanCandidate::new(Model::ClaudeSonnet45, first);
+        a.add_vote("Other", 9.0);
+        let b = PlanCandidate::new(Model::Gpt52Codex, second);
+
+        let prompt = AgentPromptTemplate::merge_plans("Fix the parser bug", &[&a, &b]);
+
+        assert!(prompt.contains("Fix the parser bug"));
+        assert!(prompt.contains("Candidate 1"));
+        assert!(prompt.contains("score 9.0/10"));
+        assert!(prompt.contains("1. Read the failing test (using read_file)"));
+        assert!(prompt.contains("2. Run tests [after 1]"));
+        assert!(prompt.contains("create_plan"));
+    }
+
+    #[test]
+    fn test_review_tool_schema() {
+        let schema = AgentPromptTemplate::review_tool_schema();
+        assert_eq!(schema["name"], "submit_review");
+        let required = schema["input_schema"]["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(required, vec!["approved", "score", "reasoning"]);
+        assert_eq!(
+            schema["input_schema"]["properties"]["approved"]["type"],
+            "boolean"
+        );
+    }
+
     #[test]
     fn test_plan_tool_schema() {
         let schema = AgentPromptTemplate::plan_tool_schema();
@@ -850,6 +1113,26 @@ mod tests {
         assert!(prompt.contains("APPROVE or REVISE"));
     }
 
+    #[test]
+    fn test_plan_tie_break_prompt() {
+        let plan =
+            Plan::new("Test objective", "Test reasoning").with_task(Task::new("1", "First task"));
+        let votes = vec![
+            Vote::approve("model-a", "Looks good"),
+            Vote::reject("model-b", "Missing tests"),
+            Vote::model_error("model-c", "timeout"),
+        ];
+
+        let prompt = AgentPromptTemplate::plan_tie_break("Original request", &plan, &votes);
+
+        assert!(prompt.contains("deciding vote"));
+        assert!(prompt.contains("First task"));
+        assert!(prompt.contains("model-a — APPROVE"));
+        assert!(prompt.contains("model-b — REVISE"));
+        assert!(prompt.contains("Missing tests"));
+        assert!(!prompt.contains("model-c"));
+    }
+
     #[test]
     fn test_task_execution_prompt() {
         let context = AgentContext::new();
@@ -994,6 +1277,8 @@ mod tests {
         assert!(prompt.contains("Original request"));
         assert!(prompt.contains("Do something"));
         assert!(prompt.contains("SUCCESS, PARTIAL, or FAILURE"));
+        // Reviewers may hand back corrective tasks
+        assert!(prompt.contains("```plan"));
     }
 
     #[test]
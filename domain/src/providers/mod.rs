@@ -12,6 +12,11 @@ pub struct ProviderConfig {
     pub default: Option<String>,
     /// Explicit model → provider routing overrides.
     pub routing: HashMap<String, String>,
+    /// Ordered providers to try, per model, when the routed provider fails
+    /// to create a session.
+    pub routing_fallback: HashMap<String, Vec<String>>,
+    /// GitHub Copilot CLI settings.
+    pub copilot: CopilotProviderConfig,
     /// AWS Bedrock settings.
     pub bedrock: BedrockProviderConfig,
     /// Anthropic API settings.
@@ -20,6 +25,14 @@ pub struct ProviderConfig {
     pub openai: OpenAiProviderConfig,
 }
 
+/// GitHub Copilot CLI provider configuration.
+#[derive(Debug, Clone, Default)]
+pub struct CopilotProviderConfig {
+    /// Maximum requests per second across all Copilot sessions
+    /// (default: None = unlimited).
+    pub max_rps: Option<f64>,
+}
+
 /// AWS Bedrock provider configuration.
 #[derive(Debug, Clone)]
 pub struct BedrockProviderConfig {
@@ -1,6 +1,7 @@
 //! Quorum Consensus types
 //!
-//! This module defines the consensus round for tracking voting history.
+//! This module defines the consensus round for tracking voting history,
+//! and the score-threshold alternative to binary approve/reject counting.
 
 use super::rule::QuorumRule;
 use super::vote::{Vote, VoteResult};
@@ -34,6 +35,30 @@ impl ConsensusOutcome {
     }
 }
 
+impl ConsensusOutcome {
+    /// Decide on reviewer scores (1-10) instead of approve/reject counts.
+    ///
+    /// Approved when the mean score is strictly above `threshold`;
+    /// `Pending` when there are no scores.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quorum_domain::quorum::ConsensusOutcome;
+    ///
+    /// // One strong approval outweighs two weak rejections.
+    /// assert!(ConsensusOutcome::from_scores(&[10.0, 5.0, 5.0], 6.0).is_approved());
+    /// assert!(ConsensusOutcome::from_scores(&[7.0, 3.0, 3.0], 6.0).is_rejected());
+    /// ```
+    pub fn from_scores(scores: &[f64], threshold: f64) -> Self {
+        match ScoreTally::from_scores(scores) {
+            Some(tally) if tally.mean > threshold => ConsensusOutcome::Approved,
+            Some(_) => ConsensusOutcome::Rejected,
+            None => ConsensusOutcome::Pending,
+        }
+    }
+}
+
 impl std::fmt::Display for ConsensusOutcome {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -44,6 +69,112 @@ impl std::fmt::Display for ConsensusOutcome {
     }
 }
 
+/// Summary of the reviewer scores behind a score-threshold decision.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoreTally {
+    /// Mean score (1-10)
+    pub mean: f64,
+    /// Lowest score
+    pub min: f64,
+    /// Highest score
+    pub max: f64,
+    /// Distance between the highest and lowest score. A wide spread means
+    /// the reviewers disagree even if the mean clears the threshold.
+    #[serde(default)]
+    pub spread: f64,
+    /// Number of scores
+    pub count: usize,
+}
+
+impl ScoreTally {
+    /// Tally `scores`; `None` when there are none.
+    pub fn from_scores(scores: &[f64]) -> Option<Self> {
+        if scores.is_empty() {
+            return None;
+        }
+        let min = scores.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Some(Self {
+            mean: scores.iter().sum::<f64>() / scores.len() as f64,
+            min,
+            max,
+            spread: max - min,
+            count: scores.len(),
+        })
+    }
+}
+
+impl std::fmt::Display for ScoreTally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {:.1}/10 (spread {:.1}, n={})",
+            self.mean, self.spread, self.count
+        )
+    }
+}
+
+/// How a plan review turns votes into a decision
+///
+/// - `Binary`: count approve/reject votes against the [`QuorumRule`] (default)
+/// - `ScoreThreshold(t)`: approve when the mean 1-10 score is above `t`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsensusMode {
+    #[default]
+    Binary,
+    ScoreThreshold(f64),
+}
+
+impl ConsensusMode {
+    /// Threshold used by `"score"` without an explicit value.
+    pub const DEFAULT_SCORE_THRESHOLD: f64 = 6.0;
+
+    /// Canonical config string for this mode (round-trips through [`FromStr`](std::str::FromStr))
+    pub fn to_config_string(&self) -> String {
+        match self {
+            ConsensusMode::Binary => "binary".to_string(),
+            ConsensusMode::ScoreThreshold(t) => format!("score:{}", t),
+        }
+    }
+}
+
+impl std::fmt::Display for ConsensusMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsensusMode::Binary => write!(f, "binary (approve/reject count)"),
+            ConsensusMode::ScoreThreshold(t) => write!(f, "mean score above {}", t),
+        }
+    }
+}
+
+impl std::str::FromStr for ConsensusMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "binary" => Ok(ConsensusMode::Binary),
+            "score" => Ok(ConsensusMode::ScoreThreshold(
+                ConsensusMode::DEFAULT_SCORE_THRESHOLD,
+            )),
+            s if s.starts_with("score:") => {
+                let t: f64 = s
+                    .trim_start_matches("score:")
+                    .parse()
+                    .map_err(|_| "Invalid score threshold")?;
+                if !(0.0..=10.0).contains(&t) {
+                    return Err(format!("Score threshold {} must be between 0 and 10", t));
+                }
+                Ok(ConsensusMode::ScoreThreshold(t))
+            }
+            _ => Err(format!(
+                "Unknown consensus mode: {}. Valid: binary, score, score:N",
+                s
+            )),
+        }
+    }
+}
+
 /// A single round of Quorum consensus voting
 ///
 /// This tracks the complete state of a voting round, including:
@@ -125,6 +256,12 @@ impl ConsensusRound {
         }
     }
 
+    /// Record the rule the result was decided under
+    pub fn with_rule(mut self, rule: QuorumRule) -> Self {
+        self.rule = rule;
+        self
+    }
+
     /// Add context about what was being voted on
     pub fn with_context(mut self, context: impl Into<String>) -> Self {
         self.context = Some(context.into());
@@ -165,6 +302,11 @@ impl ConsensusRound {
     pub fn rejection_feedback(&self) -> String {
         self.result.aggregate_rejection_feedback()
     }
+
+    /// Reviewer scores, when decided by [`ConsensusMode::ScoreThreshold`]
+    pub fn scores(&self) -> Option<&ScoreTally> {
+        self.result.scores.as_ref()
+    }
 }
 
 /// Get current timestamp in milliseconds
@@ -254,6 +396,60 @@ mod tests {
         assert_eq!(ConsensusOutcome::Pending.to_string(), "Pending");
     }
 
+    #[test]
+    fn test_from_scores_strong_approve_outweighs_weak_rejects() {
+        // Binary majority rejects: 1 approve vs 2 rejects.
+        let votes = vec![
+            Vote::approve("a", "Great").with_confidence(1.0),
+            Vote::reject("b", "Minor nits").with_confidence(0.5),
+            Vote::reject("c", "Minor nits").with_confidence(0.5),
+        ];
+        let round = ConsensusRound::new(1, votes.clone(), QuorumRule::Majority);
+        assert!(round.is_rejected());
+
+        // Mean 6.67 clears the default 6.0 threshold.
+        let scores: Vec<f64> = votes.iter().filter_map(Vote::score).collect();
+        assert!(ConsensusOutcome::from_scores(&scores, 6.0).is_approved());
+        let tally = ScoreTally::from_scores(&scores).unwrap();
+        assert_eq!(tally.spread, 5.0);
+        assert_eq!(tally.count, 3);
+    }
+
+    #[test]
+    fn test_from_scores_weak_approvals_lose_to_threshold() {
+        // Binary majority approves: 2 lukewarm approvals vs 1 reject.
+        let votes = vec![
+            Vote::approve("a", "OK I guess").with_confidence(0.6),
+            Vote::approve("b", "Fine").with_confidence(0.6),
+            Vote::reject("c", "Unsafe").with_confidence(0.2),
+        ];
+        let round = ConsensusRound::new(1, votes.clone(), QuorumRule::Majority);
+        assert!(round.is_approved());
+
+        let scores: Vec<f64> = votes.iter().filter_map(Vote::score).collect();
+        assert!(ConsensusOutcome::from_scores(&scores, 6.0).is_rejected());
+    }
+
+    #[test]
+    fn test_from_scores_threshold_is_exclusive_and_empty_is_pending() {
+        assert!(ConsensusOutcome::from_scores(&[6.0, 6.0], 6.0).is_rejected());
+        assert!(ConsensusOutcome::from_scores(&[], 6.0).is_pending());
+    }
+
+    #[test]
+    fn test_consensus_mode_parse_round_trip() {
+        assert_eq!("binary".parse::<ConsensusMode>(), Ok(ConsensusMode::Binary));
+        assert_eq!(
+            "score".parse::<ConsensusMode>(),
+            Ok(ConsensusMode::ScoreThreshold(6.0))
+        );
+        let mode: ConsensusMode = "score:7.5".parse().unwrap();
+        assert_eq!(mode, ConsensusMode::ScoreThreshold(7.5));
+        assert_eq!(mode.to_config_string().parse::<ConsensusMode>(), Ok(mode));
+        assert!("score:11".parse::<ConsensusMode>().is_err());
+        assert!("weighted".parse::<ConsensusMode>().is_err());
+    }
+
     #[test]
     fn test_from_result() {
         let votes = vec![
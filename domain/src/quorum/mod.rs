@@ -60,15 +60,20 @@ pub mod objection;
 pub mod parsing;
 pub mod result_event;
 pub mod rule;
+pub mod synthesis_diff;
 pub mod vote;
 
 // Re-export main types
-pub use consensus::{ConsensusOutcome, ConsensusRound};
+pub use consensus::{ConsensusMode, ConsensusOutcome, ConsensusRound, ScoreTally};
 pub use objection::{Objection, ObjectionLedger, ObjectionSeverity, ObjectionStatus};
-pub use parsing::{parse_final_review_response, parse_review_response, parse_vote_score};
+pub use parsing::{
+    ReviewSubmission, extract_review_submission, parse_final_review_response,
+    parse_review_response, parse_vote_score,
+};
 pub use result_event::{
     QUORUM_RESULT_API_VERSION, QUORUM_RESULT_EVENT_TYPE, QuorumResultPayload, QuorumTarget,
     QuorumTopic,
 };
 pub use rule::QuorumRule;
+pub use synthesis_diff::{AttributedSentence, DroppedSentence, SynthesisDiff, diff_synthesis};
 pub use vote::{Vote, VoteResult, VoteVerdict};
@@ -8,7 +8,8 @@
 //!
 //! | Function | Use Case | Keywords |
 //! |----------|----------|----------|
-//! | [`parse_review_response`] | Plan/action review | APPROVE / REJECT |
+//! | [`extract_review_submission`] | Plan/action review (Native Tool Use) | `submit_review` tool call |
+//! | [`parse_review_response`] | Plan/action review (text fallback) | APPROVE / REJECT |
 //! | [`parse_final_review_response`] | Final outcome review | SUCCESS / FAILURE |
 //! | [`parse_vote_score`] | Ensemble plan voting | Numeric score 1-10 |
 //! | [`parse_debate_verdict`] | Debate moderator checkpoint | VERDICT: SETTLED / CONTINUE |
@@ -18,6 +19,7 @@
 //! | [`parse_decomposition_request`] | Opponent decomposition request | DECOMPOSE_REQUEST: |
 
 use super::objection::ObjectionSeverity;
+use crate::session::response::{ContentBlock, LlmResponse};
 
 /// Check whether `line` (after trimming leading whitespace) starts with
 /// `label`, case-insensitively, and return the trimmed rest if so.
@@ -40,6 +42,43 @@ fn label_rest<'a>(line: &'a str, label: &str) -> Option<&'a str> {
     }
 }
 
+/// A verdict a reviewer submitted through the `submit_review` tool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewSubmission {
+    pub approved: bool,
+    /// Overall score, clamped to 1-10. `None` when the reviewer left it out.
+    pub score: Option<u8>,
+    pub reasoning: String,
+}
+
+/// Extract a [`ReviewSubmission`] from a `submit_review` tool call.
+///
+/// Returns `None` when the response has no such call, or the call lacks the
+/// `approved` flag — the caller should then fall back to
+/// [`parse_review_response`] on the text content.
+pub fn extract_review_submission(response: &LlmResponse) -> Option<ReviewSubmission> {
+    response.content.iter().find_map(|block| match block {
+        ContentBlock::ToolUse { name, input, .. } if name == "submit_review" => {
+            let approved = input.get("approved")?.as_bool()?;
+            let score = input
+                .get("score")
+                .and_then(|v| v.as_f64())
+                .map(|s| s.round().clamp(1.0, 10.0) as u8);
+            let reasoning = input
+                .get("reasoning")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            Some(ReviewSubmission {
+                approved,
+                score,
+                reasoning,
+            })
+        }
+        _ => None,
+    })
+}
+
 /// Parse a review response to extract approval status and feedback.
 ///
 /// Checks for explicit APPROVE/REJECT keywords in the response text.
@@ -127,25 +166,28 @@ pub fn parse_final_review_response(response: &str) -> (bool, String) {
 /// # Supported Formats
 ///
 /// 1. **JSON** (preferred): `{"score": 8, "reasoning": "..."}`
-/// 2. **Fraction**: `8/10` or `Score: 7/10`
-/// 3. **Standalone number**: `9` (if in valid range 1-10)
+/// 2. **Fraction**: `8/10`, `Score: 8.5/10` or `an 8 out of 10`
+/// 3. **Standalone number**: `9` or `7.5 — solid plan` (if in valid range 1-10)
+/// 4. **Verdict keyword** (no number found): `APPROVE` → 8, `REJECT` → 3
 ///
 /// # Return Value
 ///
-/// - Returns the parsed score clamped to 1.0-10.0
-/// - Returns 5.0 (neutral) if parsing fails
+/// `(score, parsed)` — the score clamped to 1.0-10.0, and whether it was
+/// read from a number. Keyword fallbacks and the neutral 5.0 for responses
+/// with neither a number nor a verdict have `parsed == false`.
 ///
 /// # Examples
 ///
 /// ```
 /// use quorum_domain::quorum::parsing::parse_vote_score;
 ///
-/// assert_eq!(parse_vote_score(r#"{"score": 8, "reasoning": "Good"}"#), 8.0);
-/// assert_eq!(parse_vote_score("I rate this 7/10"), 7.0);
-/// assert_eq!(parse_vote_score("Score: 9"), 9.0);
-/// assert_eq!(parse_vote_score("No numbers here"), 5.0); // fallback
+/// assert_eq!(parse_vote_score(r#"{"score": 8, "reasoning": "Good"}"#), (8.0, true));
+/// assert_eq!(parse_vote_score("I'd give this an 8 out of 10"), (8.0, true));
+/// assert_eq!(parse_vote_score("Score: 8.5/10."), (8.5, true));
+/// assert_eq!(parse_vote_score("APPROVE, but add tests"), (8.0, false));
+/// assert_eq!(parse_vote_score("No numbers here"), (5.0, false)); // fallback
 /// ```
-pub fn parse_vote_score(response: &str) -> f64 {
+pub fn parse_vote_score(response: &str) -> (f64, bool) {
     // Try to find JSON in the response
     if let Some(start) = response.find('{')
         && let Some(end) = response[start..].rfind('}')
@@ -155,31 +197,70 @@ pub fn parse_vote_score(response: &str) -> f64 {
             && let Some(score) = parsed.get("score").and_then(|v| v.as_f64())
         {
             // Clamp to valid range
-            return score.clamp(1.0, 10.0);
+            return (score.clamp(1.0, 10.0), true);
         }
     }
 
-    // Fallback: try to find a number that looks like a score
-    // Look for patterns like "8/10" or "score: 8" or just a standalone number
-    for word in response.split_whitespace() {
-        // Check for "N/10" pattern
-        if let Some(num_str) = word.strip_suffix("/10")
-            && let Ok(num) = num_str.parse::<f64>()
-        {
-            return num.clamp(1.0, 10.0);
-        }
-        // Check for standalone number (1-10)
-        if let Ok(num) = word
-            .trim_matches(|c: char| !c.is_ascii_digit())
-            .parse::<f64>()
-            && (1.0..=10.0).contains(&num)
+    let numbers = score_numbers(response);
+
+    // "N/10" or "N out of 10"
+    for &(num, end) in &numbers {
+        let rest = response[end..].trim_start().to_ascii_lowercase();
+        let denominator = rest
+            .strip_prefix('/')
+            .or_else(|| rest.strip_prefix("out of"))
+            .map(str::trim_start);
+        if let Some(denominator) = denominator
+            && denominator.starts_with("10")
+            && !denominator[2..].starts_with(|c: char| c.is_ascii_digit())
         {
-            return num;
+            return (num.clamp(1.0, 10.0), true);
         }
     }
 
-    // Default to middle score if parsing fails
-    5.0
+    // Standalone number (1-10), e.g. a bare leading "8"
+    if let Some(&(num, _)) = numbers.iter().find(|(n, _)| (1.0..=10.0).contains(n)) {
+        return (num, true);
+    }
+
+    // No number: fall back to the verdict, or a neutral middle score
+    let upper = response.to_uppercase();
+    if upper.contains("APPROVE") || upper.contains("REJECT") {
+        let (approved, _) = parse_review_response(response);
+        return (if approved { 8.0 } else { 3.0 }, false);
+    }
+    (5.0, false)
+}
+
+/// Numbers (integers or decimals) in `text`, with the byte offset just past
+/// each. Digits glued to a word or version (`gpt-5.2`, `v2`) are skipped.
+fn score_numbers(text: &str) -> Vec<(f64, usize)> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i + 1 < bytes.len() && bytes[i] == b'.' && bytes[i + 1].is_ascii_digit() {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+        let glued = start > 0
+            && (matches!(bytes[start - 1], b'-' | b'.' | b'_')
+                || bytes[start - 1].is_ascii_alphabetic());
+        if !glued && let Ok(num) = text[start..i].parse::<f64>() {
+            numbers.push((num, i));
+        }
+    }
+    numbers
 }
 
 /// Parse a Debate moderator's round checkpoint response.
@@ -590,7 +671,7 @@ mod tests {
     fn test_parse_vote_score_json() {
         // Standard JSON response
         let response = r#"{"score": 8, "reasoning": "Good plan"}"#;
-        assert_eq!(parse_vote_score(response), 8.0);
+        assert_eq!(parse_vote_score(response), (8.0, true));
 
         // With markdown code block
         let response = r#"
@@ -599,34 +680,150 @@ Here is my evaluation:
 {"score": 7, "reasoning": "Solid but could improve"}
 ```
 "#;
-        assert_eq!(parse_vote_score(response), 7.0);
+        assert_eq!(parse_vote_score(response), (7.0, true));
     }
 
     #[test]
     fn test_parse_vote_score_pattern() {
         // "N/10" pattern
-        assert_eq!(parse_vote_score("I rate this 8/10"), 8.0);
-        assert_eq!(parse_vote_score("Score: 6/10"), 6.0);
+        assert_eq!(parse_vote_score("I rate this 8/10"), (8.0, true));
+        assert_eq!(parse_vote_score("Score: 6/10"), (6.0, true));
+        assert_eq!(parse_vote_score("Score:7 / 10"), (7.0, true));
 
         // Standalone number
-        assert_eq!(parse_vote_score("My score is 9"), 9.0);
+        assert_eq!(parse_vote_score("My score is 9"), (9.0, true));
+    }
+
+    #[test]
+    fn test_parse_vote_score_out_of_ten() {
+        assert_eq!(
+            parse_vote_score("I'd give this an 8 out of 10."),
+            (8.0, true)
+        );
+        assert_eq!(parse_vote_score("Overall: 6 Out Of 10"), (6.0, true));
+    }
+
+    #[test]
+    fn test_parse_vote_score_decimal() {
+        assert_eq!(parse_vote_score("8.5/10"), (8.5, true));
+        assert_eq!(parse_vote_score("Score: 7.5/10."), (7.5, true));
+        assert_eq!(parse_vote_score("About 6.5 out of 10"), (6.5, true));
+    }
+
+    #[test]
+    fn test_parse_vote_score_bare_leading_number() {
+        assert_eq!(
+            parse_vote_score("7 — solid, but the rollback step is vague."),
+            (7.0, true)
+        );
+        assert_eq!(parse_vote_score("9."), (9.0, true));
+    }
+
+    #[test]
+    fn test_parse_vote_score_prefers_fraction_over_scale() {
+        // The scale bounds come first, the fraction is the score
+        assert_eq!(
+            parse_vote_score("On a scale of 1 to 10 this plan is a 4/10"),
+            (4.0, true)
+        );
+    }
+
+    #[test]
+    fn test_parse_vote_score_ignores_version_numbers() {
+        assert_eq!(
+            parse_vote_score("Compared with gpt-5.2, I'd say 6/10"),
+            (6.0, true)
+        );
     }
 
     #[test]
     fn test_parse_vote_score_clamp() {
         // Clamps to valid range
         let response = r#"{"score": 15, "reasoning": "Too high"}"#;
-        assert_eq!(parse_vote_score(response), 10.0);
+        assert_eq!(parse_vote_score(response), (10.0, true));
 
         let response = r#"{"score": -5, "reasoning": "Too low"}"#;
-        assert_eq!(parse_vote_score(response), 1.0);
+        assert_eq!(parse_vote_score(response), (1.0, true));
+
+        assert_eq!(parse_vote_score("12/10, flawless"), (10.0, true));
+    }
+
+    #[test]
+    fn test_parse_vote_score_verdict_fallback() {
+        assert_eq!(parse_vote_score("APPROVE. Clear plan."), (8.0, false));
+        assert_eq!(parse_vote_score("REJECT: no tests."), (3.0, false));
+        assert_eq!(parse_vote_score("I cannot approve this."), (3.0, false));
     }
 
     #[test]
     fn test_parse_vote_score_fallback() {
-        // Fallback to 5.0 when parsing fails
-        assert_eq!(parse_vote_score("No numbers here"), 5.0);
-        assert_eq!(parse_vote_score(""), 5.0);
+        // Neutral 5.0 when there is neither a number nor a verdict
+        assert_eq!(parse_vote_score("No numbers here"), (5.0, false));
+        assert_eq!(parse_vote_score(""), (5.0, false));
+        assert_eq!(parse_vote_score("¯\\_(ツ)_/¯ ???"), (5.0, false));
+    }
+
+    // ==================== extract_review_submission Tests ====================
+
+    fn submit_review_call(input: serde_json::Value) -> LlmResponse {
+        LlmResponse {
+            content: vec![
+                ContentBlock::Text("Looking at the plan...".to_string()),
+                ContentBlock::ToolUse {
+                    id: "toolu_review".to_string(),
+                    name: "submit_review".to_string(),
+                    input: serde_json::from_value(input).unwrap(),
+                },
+            ],
+            stop_reason: None,
+            model: None,
+            usage: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_review_submission() {
+        let response = submit_review_call(serde_json::json!({
+            "approved": false,
+            "score": 4,
+            "reasoning": "Step 2 deletes the build cache without need."
+        }));
+        assert_eq!(
+            extract_review_submission(&response),
+            Some(ReviewSubmission {
+                approved: false,
+                score: Some(4),
+                reasoning: "Step 2 deletes the build cache without need.".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_review_submission_clamps_and_tolerates_missing_score() {
+        let response = submit_review_call(serde_json::json!({
+            "approved": true,
+            "score": 42,
+            "reasoning": "Fine"
+        }));
+        assert_eq!(
+            extract_review_submission(&response).unwrap().score,
+            Some(10)
+        );
+
+        let response = submit_review_call(serde_json::json!({"approved": true}));
+        let submission = extract_review_submission(&response).unwrap();
+        assert!(submission.approved);
+        assert_eq!(submission.score, None);
+    }
+
+    #[test]
+    fn test_extract_review_submission_requires_tool_call() {
+        assert_eq!(
+            extract_review_submission(&LlmResponse::from_text("I APPROVE this plan.")),
+            None
+        );
+        let response = submit_review_call(serde_json::json!({"reasoning": "No verdict"}));
+        assert_eq!(extract_review_submission(&response), None);
     }
 
     // ==================== parse_review_response Tests ====================
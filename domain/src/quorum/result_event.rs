@@ -14,6 +14,7 @@
 //!   every other sink (stdout, RPC) must produce the identical shape via
 //!   [`QuorumResultPayload::to_record`] so the three surfaces never drift.
 
+use super::consensus::ScoreTally;
 use super::rule::QuorumRule;
 use super::vote::{Vote, VoteResult};
 use crate::orchestration::value_objects::SynthesisResult;
@@ -123,6 +124,9 @@ pub struct QuorumResultPayload {
     /// Moderator's synthesized review (pr_review; additive to v1, #300)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub synthesis: Option<SynthesisResult>,
+    /// Reviewer score tally incl. spread (score consensus mode; additive to v1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scores: Option<ScoreTally>,
 }
 
 impl QuorumResultPayload {
@@ -137,9 +141,16 @@ impl QuorumResultPayload {
             votes: result.votes.clone(),
             feedback: result.aggregated_feedback.clone(),
             synthesis: None,
+            scores: result.scores,
         }
     }
 
+    /// Record the rule the votes were aggregated with (defaults to majority).
+    pub fn with_rule(mut self, rule: QuorumRule) -> Self {
+        self.rule = rule;
+        self
+    }
+
     /// Attach the moderator's synthesized review (pr_review, #300).
     pub fn with_synthesis(mut self, synthesis: SynthesisResult) -> Self {
         self.synthesis = Some(synthesis);
@@ -169,6 +180,7 @@ impl QuorumResultPayload {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quorum::ConsensusMode;
 
     // Golden test: pins the v1 JSON contract. If this test needs changing,
     // the api_version must be bumped and consumers notified.
@@ -218,6 +230,23 @@ mod tests {
         assert!(json.get("target").is_none());
         assert!(json.get("feedback").is_none());
         assert!(json.get("synthesis").is_none());
+        assert!(json.get("scores").is_none());
+    }
+
+    #[test]
+    fn test_payload_carries_score_tally() {
+        let votes = vec![
+            Vote::approve("a", "Great").with_confidence(0.9),
+            Vote::approve("b", "Barely").with_confidence(0.4),
+        ];
+        let result =
+            VoteResult::from_votes(votes).with_consensus_mode(&ConsensusMode::ScoreThreshold(6.0));
+        let payload = QuorumResultPayload::new(QuorumTopic::PlanReview, None, &result);
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json["scores"]["spread"], 5.0);
+        assert_eq!(json["scores"]["min"], 4.0);
+        assert_eq!(json["scores"]["count"], 2);
     }
 
     #[test]
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 /// Different rules can be used depending on the criticality of the decision:
 /// - `Majority`: More than half must approve (default)
 /// - `Unanimous`: All must approve (strictest)
+/// - `SuperMajority(num, den)`: At least num/den must approve (e.g. 2/3)
 /// - `AtLeast(n)`: At least n votes must approve
 /// - `Percentage(p)`: At least p% must approve
 ///
@@ -35,6 +36,10 @@ pub enum QuorumRule {
     /// All participants must approve
     Unanimous,
 
+    /// At least `numerator / denominator` of participants must approve
+    /// (e.g. `SuperMajority(2, 3)` for a two-thirds majority)
+    SuperMajority(u8, u8),
+
     /// At least n votes must approve
     AtLeast(usize),
 
@@ -52,6 +57,7 @@ impl QuorumRule {
         match self {
             QuorumRule::Majority => approvals > total / 2,
             QuorumRule::Unanimous => approvals == total,
+            QuorumRule::SuperMajority(..) => approvals >= self.min_approvals_needed(total),
             QuorumRule::AtLeast(n) => approvals >= *n,
             QuorumRule::Percentage(p) => {
                 let required = (total as f64 * (*p as f64 / 100.0)).ceil() as usize;
@@ -65,6 +71,9 @@ impl QuorumRule {
         match self {
             QuorumRule::Majority => "majority (more than half)".to_string(),
             QuorumRule::Unanimous => "unanimous (all must approve)".to_string(),
+            QuorumRule::SuperMajority(num, den) => {
+                format!("supermajority (at least {}/{})", num, den)
+            }
             QuorumRule::AtLeast(n) => format!("at least {} approvals", n),
             QuorumRule::Percentage(p) => format!("at least {}% approval", p),
         }
@@ -75,12 +84,31 @@ impl QuorumRule {
         match self {
             QuorumRule::Majority => total / 2 + 1,
             QuorumRule::Unanimous => total,
+            QuorumRule::SuperMajority(num, den) => {
+                let (num, den) = (*num as usize, (*den).max(1) as usize);
+                (total * num).div_ceil(den)
+            }
             QuorumRule::AtLeast(n) => *n,
             QuorumRule::Percentage(p) => (total as f64 * (*p as f64 / 100.0)).ceil() as usize,
         }
     }
 }
 
+impl QuorumRule {
+    /// Canonical config string for this rule (round-trips through [`FromStr`](std::str::FromStr))
+    ///
+    /// Used by `agent.quorum_rule` so `config.get` returns a value `config.set` accepts.
+    pub fn to_config_string(&self) -> String {
+        match self {
+            QuorumRule::Majority => "majority".to_string(),
+            QuorumRule::Unanimous => "unanimous".to_string(),
+            QuorumRule::SuperMajority(num, den) => format!("supermajority:{}/{}", num, den),
+            QuorumRule::AtLeast(n) => format!("atleast:{}", n),
+            QuorumRule::Percentage(p) => format!("percentage:{}", p),
+        }
+    }
+}
+
 impl std::fmt::Display for QuorumRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.description())
@@ -94,6 +122,24 @@ impl std::str::FromStr for QuorumRule {
         match s.to_lowercase().as_str() {
             "majority" => Ok(QuorumRule::Majority),
             "unanimous" => Ok(QuorumRule::Unanimous),
+            "supermajority" | "super_majority" => Ok(QuorumRule::SuperMajority(2, 3)),
+            s if s.starts_with("supermajority:") || s.starts_with("super_majority:") => {
+                let ratio = s.split(':').nth(1).unwrap_or_default();
+                let (num, den) = ratio
+                    .split_once('/')
+                    .ok_or("Supermajority ratio must be N/M (e.g. 2/3)")?;
+                let num: u8 = num.parse().map_err(|_| "Invalid supermajority numerator")?;
+                let den: u8 = den
+                    .parse()
+                    .map_err(|_| "Invalid supermajority denominator")?;
+                if den == 0 || num == 0 || num > den {
+                    return Err(format!(
+                        "Invalid supermajority ratio {}/{}: need 0 < N <= M",
+                        num, den
+                    ));
+                }
+                Ok(QuorumRule::SuperMajority(num, den))
+            }
             s if s.starts_with("atleast:") || s.starts_with("at_least:") => {
                 let n: usize = s
                     .split(':')
@@ -109,7 +155,7 @@ impl std::str::FromStr for QuorumRule {
                 Ok(QuorumRule::Percentage(p))
             }
             _ => Err(format!(
-                "Unknown quorum rule: {}. Valid: majority, unanimous, atleast:N, percentage:N or N%",
+                "Unknown quorum rule: {}. Valid: majority, unanimous, supermajority[:N/M], atleast:N, percentage:N or N%",
                 s
             )),
         }
@@ -143,6 +189,23 @@ mod tests {
         assert!(rule.is_satisfied(1, 1));
     }
 
+    #[test]
+    fn test_super_majority_rule() {
+        let rule = QuorumRule::SuperMajority(2, 3);
+
+        // 3 total: need ceil(2) = 2
+        assert!(!rule.is_satisfied(1, 3));
+        assert!(rule.is_satisfied(2, 3));
+
+        // 5 total: need ceil(3.33) = 4
+        assert!(!rule.is_satisfied(3, 5));
+        assert!(rule.is_satisfied(4, 5));
+
+        // 3/4 over 4 total: need 3
+        assert!(!QuorumRule::SuperMajority(3, 4).is_satisfied(2, 4));
+        assert!(QuorumRule::SuperMajority(3, 4).is_satisfied(3, 4));
+    }
+
     #[test]
     fn test_at_least_rule() {
         let rule = QuorumRule::AtLeast(2);
@@ -170,6 +233,7 @@ mod tests {
         // All rules should return false for zero total
         assert!(!QuorumRule::Majority.is_satisfied(0, 0));
         assert!(!QuorumRule::Unanimous.is_satisfied(0, 0));
+        assert!(!QuorumRule::SuperMajority(2, 3).is_satisfied(0, 0));
         assert!(!QuorumRule::AtLeast(1).is_satisfied(0, 0));
         assert!(!QuorumRule::Percentage(50).is_satisfied(0, 0));
     }
@@ -179,6 +243,8 @@ mod tests {
         assert_eq!(QuorumRule::Majority.min_approvals_needed(3), 2);
         assert_eq!(QuorumRule::Majority.min_approvals_needed(4), 3);
         assert_eq!(QuorumRule::Unanimous.min_approvals_needed(3), 3);
+        assert_eq!(QuorumRule::SuperMajority(2, 3).min_approvals_needed(3), 2);
+        assert_eq!(QuorumRule::SuperMajority(2, 3).min_approvals_needed(5), 4);
         assert_eq!(QuorumRule::AtLeast(2).min_approvals_needed(5), 2);
         assert_eq!(QuorumRule::Percentage(75).min_approvals_needed(4), 3);
     }
@@ -209,6 +275,29 @@ mod tests {
             "80%".parse::<QuorumRule>().ok(),
             Some(QuorumRule::Percentage(80))
         );
+        assert_eq!(
+            "supermajority".parse::<QuorumRule>().ok(),
+            Some(QuorumRule::SuperMajority(2, 3))
+        );
+        assert_eq!(
+            "supermajority:3/4".parse::<QuorumRule>().ok(),
+            Some(QuorumRule::SuperMajority(3, 4))
+        );
+        assert!("supermajority:4/3".parse::<QuorumRule>().is_err());
+        assert!("supermajority:1/0".parse::<QuorumRule>().is_err());
+    }
+
+    #[test]
+    fn test_config_string_round_trip() {
+        for rule in [
+            QuorumRule::Majority,
+            QuorumRule::Unanimous,
+            QuorumRule::SuperMajority(2, 3),
+            QuorumRule::AtLeast(2),
+            QuorumRule::Percentage(75),
+        ] {
+            assert_eq!(rule.to_config_string().parse::<QuorumRule>(), Ok(rule));
+        }
     }
 
     #[test]
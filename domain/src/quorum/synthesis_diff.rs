@@ -0,0 +1,271 @@
+//! Synthesis attribution
+//!
+//! Compares a Quorum Discussion synthesis with the Phase 1 responses it was
+//! built from: which synthesis sentences came from which models, which are
+//! the moderator's own, and which response points were left out. Matching is
+//! plain word overlap between sentences, so paraphrases may go unattributed.
+
+use crate::orchestration::value_objects::{ModelResponse, SynthesisResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Minimum overlap coefficient (shared words / words in the shorter
+/// sentence) for two sentences to count as the same point.
+const OVERLAP_THRESHOLD: f64 = 0.5;
+
+/// A synthesis sentence and the models whose responses contain it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributedSentence {
+    pub text: String,
+    /// Models in response order; empty when the sentence is novel
+    pub sources: Vec<String>,
+}
+
+impl AttributedSentence {
+    /// Whether no response contains this point (the moderator added it).
+    pub fn is_novel(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// A response sentence that no synthesis sentence reflects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DroppedSentence {
+    pub model: String,
+    pub text: String,
+}
+
+/// Result of [`diff_synthesis`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SynthesisDiff {
+    /// Synthesis sentences in order
+    pub sentences: Vec<AttributedSentence>,
+    /// Response points the moderator left out, in response order
+    pub dropped: Vec<DroppedSentence>,
+}
+
+impl SynthesisDiff {
+    /// Synthesis sentences found in no response.
+    pub fn novel(&self) -> impl Iterator<Item = &AttributedSentence> {
+        self.sentences.iter().filter(|s| s.is_novel())
+    }
+
+    /// Number of synthesis sentences drawn (at least partly) from `model`.
+    pub fn attributed_to(&self, model: &str) -> usize {
+        self.sentences
+            .iter()
+            .filter(|s| s.sources.iter().any(|m| m == model))
+            .count()
+    }
+}
+
+/// Attribute each sentence of the synthesis conclusion to the successful
+/// responses that contain it, and collect the response sentences the
+/// synthesis left out.
+///
+/// # Example
+///
+/// ```
+/// use quorum_domain::quorum::diff_synthesis;
+/// use quorum_domain::{ModelResponse, SynthesisResult};
+///
+/// let responses = vec![
+///     ModelResponse::success("gpt", "Cache the parsed config."),
+///     ModelResponse::success("claude", "Validate input before parsing."),
+/// ];
+/// let synthesis = SynthesisResult::new("judge", "Cache the parsed config. Add metrics too.");
+///
+/// let diff = diff_synthesis(&responses, &synthesis);
+/// assert_eq!(diff.sentences[0].sources, vec!["gpt"]);
+/// assert!(diff.sentences[1].is_novel());
+/// assert_eq!(diff.dropped[0].model, "claude");
+/// ```
+pub fn diff_synthesis(responses: &[ModelResponse], synthesis: &SynthesisResult) -> SynthesisDiff {
+    let response_sentences: Vec<(&str, Vec<Sentence>)> = responses
+        .iter()
+        .filter(|r| r.success)
+        .map(|r| (r.model.as_str(), tokenized_sentences(&r.content)))
+        .collect();
+    let synthesis_sentences = tokenized_sentences(&synthesis.conclusion);
+
+    let sentences = synthesis_sentences
+        .iter()
+        .map(|(text, words)| AttributedSentence {
+            text: text.clone(),
+            sources: response_sentences
+                .iter()
+                .filter(|(_, sentences)| sentences.iter().any(|(_, w)| matches(words, w)))
+                .map(|(model, _)| model.to_string())
+                .collect(),
+        })
+        .collect();
+
+    let dropped = response_sentences
+        .iter()
+        .flat_map(|(model, sentences)| {
+            sentences
+                .iter()
+                .filter(|(_, words)| !synthesis_sentences.iter().any(|(_, w)| matches(words, w)))
+                .map(|(text, _)| DroppedSentence {
+                    model: model.to_string(),
+                    text: text.clone(),
+                })
+        })
+        .collect();
+
+    SynthesisDiff { sentences, dropped }
+}
+
+/// A sentence and its content words.
+type Sentence = (String, BTreeSet<String>);
+
+fn matches(a: &BTreeSet<String>, b: &BTreeSet<String>) -> bool {
+    let shorter = a.len().min(b.len());
+    shorter > 0 && a.intersection(b).count() as f64 / shorter as f64 >= OVERLAP_THRESHOLD
+}
+
+/// Split into sentences, keeping only those with at least one content word.
+fn tokenized_sentences(text: &str) -> Vec<Sentence> {
+    split_sentences(text)
+        .into_iter()
+        .filter_map(|s| {
+            let words = content_words(&s);
+            (!words.is_empty()).then_some((s, words))
+        })
+        .collect()
+}
+
+/// Sentences end at a line break, at `.`/`!`/`?` followed by whitespace,
+/// and at `。`/`！`/`？`.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            push_trimmed(&mut sentences, &mut current);
+            continue;
+        }
+        current.push(c);
+        let ends = match c {
+            '。' | '！' | '？' => true,
+            '.' | '!' | '?' => chars.peek().is_none_or(|n| n.is_whitespace()),
+            _ => false,
+        };
+        if ends {
+            push_trimmed(&mut sentences, &mut current);
+        }
+    }
+    push_trimmed(&mut sentences, &mut current);
+    sentences
+}
+
+fn push_trimmed(sentences: &mut Vec<String>, current: &mut String) {
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        sentences.push(trimmed.to_string());
+    }
+    current.clear();
+}
+
+/// Lowercased ASCII words of 3+ characters; every other letter (CJK
+/// included) counts as a word of its own.
+fn content_words(sentence: &str) -> BTreeSet<String> {
+    let mut words = BTreeSet::new();
+    let mut word = String::new();
+    for c in sentence.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_lowercase());
+            continue;
+        }
+        if word.len() >= 3 {
+            words.insert(std::mem::take(&mut word));
+        }
+        word.clear();
+        if c.is_alphanumeric() {
+            words.insert(c.to_string());
+        }
+    }
+    if word.len() >= 3 {
+        words.insert(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_synthesis_attributes_points_to_models() {
+        let responses = vec![
+            ModelResponse::success(
+                "gpt",
+                "Use a connection pool for the database. Retry failed requests with backoff.",
+            ),
+            ModelResponse::success(
+                "claude",
+                "Add request timeouts everywhere. Log every retry for debugging.",
+            ),
+            ModelResponse::failure("gemini", "timeout"),
+        ];
+        let synthesis = SynthesisResult::new(
+            "judge",
+            "Use a connection pool for the database.\n\
+             Add request timeouts everywhere.\n\
+             Retry failed requests with backoff and log every retry.\n\
+             Benchmark before and after the change.",
+        );
+
+        let diff = diff_synthesis(&responses, &synthesis);
+
+        let sources: Vec<Vec<&str>> = diff
+            .sentences
+            .iter()
+            .map(|s| s.sources.iter().map(String::as_str).collect())
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                vec!["gpt"],
+                vec!["claude"],
+                vec!["gpt", "claude"],
+                Vec::<&str>::new(),
+            ]
+        );
+        assert_eq!(
+            diff.novel().map(|s| s.text.as_str()).collect::<Vec<_>>(),
+            vec!["Benchmark before and after the change."]
+        );
+        assert_eq!(diff.attributed_to("gpt"), 2);
+        assert_eq!(diff.attributed_to("gemini"), 0);
+        assert!(diff.dropped.is_empty(), "{:?}", diff.dropped);
+    }
+
+    #[test]
+    fn test_diff_synthesis_reports_dropped_points() {
+        let responses = vec![ModelResponse::success(
+            "gpt",
+            "Cache the parsed config. Rewrite the parser in a weekend.",
+        )];
+        let synthesis = SynthesisResult::new("judge", "Cache the parsed config.");
+
+        let diff = diff_synthesis(&responses, &synthesis);
+
+        assert_eq!(
+            diff.dropped,
+            vec![DroppedSentence {
+                model: "gpt".to_string(),
+                text: "Rewrite the parser in a weekend.".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("v1.2 is out! Upgrade now.\n- Check logs。次に進む"),
+            vec!["v1.2 is out!", "Upgrade now.", "- Check logs。", "次に進む"]
+        );
+    }
+}
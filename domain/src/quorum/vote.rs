@@ -2,6 +2,7 @@
 //!
 //! This module defines the core voting primitives used in Quorum decision making.
 
+use super::consensus::{ConsensusMode, ConsensusOutcome, ScoreTally};
 use serde::{Deserialize, Serialize};
 
 /// The verdict a model returned for a Quorum decision
@@ -103,6 +104,11 @@ impl Vote {
         self
     }
 
+    /// The reviewer's 1-10 score, recovered from the confidence
+    pub fn score(&self) -> Option<f64> {
+        self.confidence.map(|c| c * 10.0)
+    }
+
     /// Get a short display name for the model
     ///
     /// E.g., "claude-sonnet-4.5" -> "claude"
@@ -131,6 +137,10 @@ pub struct VoteResult {
     pub votes: Vec<Vote>,
     /// Aggregated feedback from all votes
     pub aggregated_feedback: Option<String>,
+    /// Reviewer scores, when the result was decided by
+    /// [`ConsensusMode::ScoreThreshold`](super::consensus::ConsensusMode)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scores: Option<ScoreTally>,
 }
 
 impl VoteResult {
@@ -140,31 +150,14 @@ impl VoteResult {
     /// When the vote does not pass, rejection feedback is aggregated
     /// automatically.
     pub fn from_votes(votes: Vec<Vote>) -> Self {
-        let approve_count = votes.iter().filter(|v| v.is_approve()).count();
-        let reject_count = votes.iter().filter(|v| v.is_reject()).count();
-        let cast_votes = approve_count + reject_count;
-        let total_votes = votes.len();
-
-        // Majority rule: more than half of cast votes must approve
-        let passed = approve_count > cast_votes / 2;
-
-        let mut result = Self {
-            passed,
-            approve_count,
-            reject_count,
-            total_votes,
-            votes,
-            aggregated_feedback: None,
-        };
-        if !result.passed && result.reject_count > 0 {
-            result.aggregated_feedback = Some(result.aggregate_rejection_feedback());
-        }
-        result
+        Self::from_votes_with_rule(votes, &super::rule::QuorumRule::Majority)
     }
 
     /// Create a VoteResult with a specific rule
     ///
     /// The denominator is the number of *cast* votes (approve + reject).
+    /// When the vote does not pass, every rejection's feedback is aggregated
+    /// regardless of the rule applied.
     pub fn from_votes_with_rule(votes: Vec<Vote>, rule: &super::rule::QuorumRule) -> Self {
         let approve_count = votes.iter().filter(|v| v.is_approve()).count();
         let reject_count = votes.iter().filter(|v| v.is_reject()).count();
@@ -180,6 +173,7 @@ impl VoteResult {
             total_votes,
             votes,
             aggregated_feedback: None,
+            scores: None,
         };
         if !result.passed && result.reject_count > 0 {
             result.aggregated_feedback = Some(result.aggregate_rejection_feedback());
@@ -187,6 +181,39 @@ impl VoteResult {
         result
     }
 
+    /// Re-decide this result under `mode`.
+    ///
+    /// `Binary` leaves it unchanged. `ScoreThreshold` replaces `passed` with
+    /// [`ConsensusOutcome::from_scores`] over the cast votes that carry a
+    /// score and records the [`ScoreTally`]; with no scored votes the binary
+    /// outcome stands.
+    pub fn with_consensus_mode(mut self, mode: &ConsensusMode) -> Self {
+        let ConsensusMode::ScoreThreshold(threshold) = *mode else {
+            return self;
+        };
+        let scores: Vec<f64> = self
+            .votes
+            .iter()
+            .filter(|v| v.is_cast())
+            .filter_map(Vote::score)
+            .collect();
+        let Some(tally) = ScoreTally::from_scores(&scores) else {
+            return self;
+        };
+        self.passed = ConsensusOutcome::from_scores(&scores, threshold).is_approved();
+        self.scores = Some(tally);
+        self.aggregated_feedback = if self.passed {
+            None
+        } else if self.reject_count > 0 {
+            Some(self.aggregate_rejection_feedback())
+        } else {
+            // Every vote approved, but the mean fell short: the lowest
+            // scores explain why
+            Some(self.aggregate_lowest_score_feedback(tally.min))
+        };
+        self
+    }
+
     /// Create a VoteResult for a skipped / auto-approved review (no votes)
     pub fn skipped() -> Self {
         Self {
@@ -196,6 +223,7 @@ impl VoteResult {
             total_votes: 0,
             votes: Vec::new(),
             aggregated_feedback: None,
+            scores: None,
         }
     }
 
@@ -221,6 +249,14 @@ impl VoteResult {
         cast > 0 && (self.approve_count == cast || self.reject_count == cast)
     }
 
+    /// Check if the cast votes split exactly in half
+    ///
+    /// Only possible with an even number of cast votes; used to decide
+    /// whether a tie-breaker model should be consulted.
+    pub fn is_tie(&self) -> bool {
+        self.has_cast_votes() && self.approve_count == self.reject_count
+    }
+
     /// Get the approval ratio over cast votes (0.0 to 1.0)
     pub fn approval_ratio(&self) -> f64 {
         let cast = self.cast_votes();
@@ -262,11 +298,22 @@ impl VoteResult {
             .collect::<Vec<_>>()
             .join("\n\n")
     }
+
+    /// Reasoning of the cast votes that scored `min`
+    fn aggregate_lowest_score_feedback(&self, min: f64) -> String {
+        self.votes
+            .iter()
+            .filter(|v| v.is_cast() && v.score() == Some(min))
+            .map(|v| format!("{} (score {:.1}/10): {}", v.model, min, v.reasoning))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quorum::QuorumRule;
 
     #[test]
     fn test_vote_creation() {
@@ -355,6 +402,29 @@ mod tests {
         assert_eq!(result.approval_ratio(), 1.0);
     }
 
+    #[test]
+    fn test_vote_result_is_tie() {
+        let tied = VoteResult::from_votes(vec![
+            Vote::approve("model-a", "Yes"),
+            Vote::approve("model-b", "Yes"),
+            Vote::reject("model-c", "No"),
+            Vote::reject("model-d", "No"),
+        ]);
+        assert!(tied.is_tie());
+        assert!(!tied.passed);
+
+        // A model error does not count toward the split
+        let not_tied = VoteResult::from_votes(vec![
+            Vote::approve("model-a", "Yes"),
+            Vote::reject("model-b", "No"),
+            Vote::model_error("model-c", "timeout"),
+            Vote::approve("model-d", "Yes"),
+        ]);
+        assert!(!not_tied.is_tie());
+
+        assert!(!VoteResult::skipped().is_tie());
+    }
+
     #[test]
     fn test_model_error_excluded_from_denominator() {
         // 1 approve, 1 reject, 1 model_error: cast = 2, majority needs > 1
@@ -414,6 +484,70 @@ mod tests {
         assert_eq!(result.vote_summary(), "[●●○!]");
     }
 
+    fn votes(approvals: usize, total: usize) -> Vec<Vote> {
+        (0..total)
+            .map(|i| {
+                let model = format!("model-{}", i);
+                if i < approvals {
+                    Vote::approve(model, "Yes")
+                } else {
+                    Vote::reject(model, format!("Concern {}", i))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rule_majority_with_3_and_5_models() {
+        let rule = QuorumRule::Majority;
+        assert!(VoteResult::from_votes_with_rule(votes(2, 3), &rule).passed);
+        assert!(!VoteResult::from_votes_with_rule(votes(1, 3), &rule).passed);
+        assert!(VoteResult::from_votes_with_rule(votes(3, 5), &rule).passed);
+        assert!(!VoteResult::from_votes_with_rule(votes(2, 5), &rule).passed);
+    }
+
+    #[test]
+    fn test_rule_unanimous_with_3_and_5_models() {
+        let rule = QuorumRule::Unanimous;
+        assert!(VoteResult::from_votes_with_rule(votes(3, 3), &rule).passed);
+        assert!(VoteResult::from_votes_with_rule(votes(5, 5), &rule).passed);
+
+        // A single reject fails the vote
+        let result = VoteResult::from_votes_with_rule(votes(2, 3), &rule);
+        assert!(!result.passed);
+        assert_eq!(
+            result.aggregated_feedback.as_deref(),
+            Some("model-2: Concern 2")
+        );
+        assert!(!VoteResult::from_votes_with_rule(votes(4, 5), &rule).passed);
+    }
+
+    #[test]
+    fn test_rule_super_majority_with_3_and_5_models() {
+        let rule = QuorumRule::SuperMajority(2, 3);
+        assert!(VoteResult::from_votes_with_rule(votes(2, 3), &rule).passed);
+        assert!(!VoteResult::from_votes_with_rule(votes(1, 3), &rule).passed);
+        // 3/5 is a simple majority but short of two-thirds
+        assert!(!VoteResult::from_votes_with_rule(votes(3, 5), &rule).passed);
+        assert!(VoteResult::from_votes_with_rule(votes(4, 5), &rule).passed);
+    }
+
+    #[test]
+    fn test_rule_feedback_collects_all_rejections() {
+        for rule in [
+            QuorumRule::Majority,
+            QuorumRule::Unanimous,
+            QuorumRule::SuperMajority(2, 3),
+        ] {
+            let result = VoteResult::from_votes_with_rule(votes(1, 5), &rule);
+            assert!(!result.passed, "{rule} should fail with 1/5");
+            let feedback = result.aggregated_feedback.unwrap();
+            for i in 1..5 {
+                assert!(feedback.contains(&format!("model-{i}: Concern {i}")));
+            }
+        }
+    }
+
     #[test]
     fn test_aggregate_rejection_feedback() {
         let votes = vec![
@@ -427,4 +561,54 @@ mod tests {
         assert!(feedback.contains("model-b: Security issue found"));
         assert!(feedback.contains("model-c: Missing error handling"));
     }
+
+    #[test]
+    fn test_with_consensus_mode_score_threshold_overrides_vote_count() {
+        let votes = vec![
+            Vote::approve("a", "Great").with_confidence(1.0),
+            Vote::reject("b", "Nits").with_confidence(0.5),
+            Vote::reject("c", "Nits").with_confidence(0.5),
+            Vote::model_error("d", "timeout"),
+        ];
+        let binary = VoteResult::from_votes(votes.clone());
+        assert!(!binary.passed);
+        assert!(binary.scores.is_none());
+
+        let scored =
+            VoteResult::from_votes(votes).with_consensus_mode(&ConsensusMode::ScoreThreshold(6.0));
+        assert!(scored.passed);
+        assert!(scored.aggregated_feedback.is_none());
+        let tally = scored.scores.unwrap();
+        assert_eq!(tally.count, 3);
+        assert_eq!(tally.spread, 5.0);
+    }
+
+    #[test]
+    fn test_score_rejection_without_reject_votes_explains_lowest_scores() {
+        let votes = vec![
+            Vote::approve("a", "Solid").with_confidence(0.8),
+            Vote::approve("b", "Works, but no rollback step").with_confidence(0.3),
+            Vote::approve("c", "Thin on tests").with_confidence(0.3),
+        ];
+        let result =
+            VoteResult::from_votes(votes).with_consensus_mode(&ConsensusMode::ScoreThreshold(6.0));
+
+        assert!(!result.passed);
+        assert_eq!(
+            result.aggregated_feedback.as_deref(),
+            Some(
+                "b (score 3.0/10): Works, but no rollback step\n\n\
+                 c (score 3.0/10): Thin on tests"
+            )
+        );
+    }
+
+    #[test]
+    fn test_with_consensus_mode_without_scores_keeps_binary_outcome() {
+        let votes = vec![Vote::approve("a", "OK"), Vote::reject("b", "No")];
+        let result =
+            VoteResult::from_votes(votes).with_consensus_mode(&ConsensusMode::ScoreThreshold(6.0));
+        assert!(!result.passed);
+        assert!(result.scores.is_none());
+    }
 }
@@ -40,6 +40,10 @@ pub enum ScriptEventType {
     /// Fired when the agent creates a plan.
     /// Data: objective (string), task_count (int)
     PlanCreated,
+    /// Fired when the quorum plan review concludes.
+    /// Data: objective (string), task_count (int), approved (bool),
+    /// approve_count (int), reject_count (int), round (int)
+    PlanReviewed,
     /// Fired when a quorum review concludes (plan / action / final review).
     /// Data: topic (string), approved (bool), approve_count (int),
     /// reject_count (int), votes_json (JSON string, `quorum_result` v1 votes)
@@ -61,6 +65,7 @@ impl ScriptEventType {
             Self::ToolCallAfter => "ToolCallAfter",
             Self::PhaseChanged => "PhaseChanged",
             Self::PlanCreated => "PlanCreated",
+            Self::PlanReviewed => "PlanReviewed",
             Self::QuorumResult => "QuorumResult",
         }
     }
@@ -87,6 +92,7 @@ impl std::str::FromStr for ScriptEventType {
             "ToolCallAfter" => Ok(Self::ToolCallAfter),
             "PhaseChanged" => Ok(Self::PhaseChanged),
             "PlanCreated" => Ok(Self::PlanCreated),
+            "PlanReviewed" => Ok(Self::PlanReviewed),
             "QuorumResult" => Ok(Self::QuorumResult),
             other => Err(format!("unknown event: '{}'", other)),
         }
@@ -111,20 +117,57 @@ pub struct ScriptEventData {
 }
 
 /// A simple value type that can be passed to/from scripts.
+///
+/// `Table` and `Array` carry structured payloads (e.g. per-model votes) and
+/// become nested Lua tables; `Array` is 1-indexed on the Lua side.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ScriptValue {
     String(String),
     Integer(i64),
     Boolean(bool),
+    Table(HashMap<String, ScriptValue>),
+    Array(Vec<ScriptValue>),
     Nil,
 }
 
+impl ScriptValue {
+    /// Build a `Table` from key-value pairs.
+    pub fn table<K: Into<String>>(entries: impl IntoIterator<Item = (K, ScriptValue)>) -> Self {
+        Self::Table(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+}
+
 impl std::fmt::Display for ScriptValue {
+    /// Lua-like literal; table keys are sorted so the output is stable.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::String(s) => write!(f, "{}", s),
             Self::Integer(n) => write!(f, "{}", n),
             Self::Boolean(b) => write!(f, "{}", b),
+            Self::Table(fields) => {
+                let mut keys: Vec<&String> = fields.keys().collect();
+                keys.sort();
+                write!(f, "{{")?;
+                for (i, key) in keys.into_iter().enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{} = {}", sep, key, fields[key])?;
+                }
+                if fields.is_empty() {
+                    write!(f, "}}")
+                } else {
+                    write!(f, " }}")
+                }
+            }
+            Self::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
             Self::Nil => write!(f, "nil"),
         }
     }
@@ -143,6 +186,13 @@ impl ScriptEventData {
     pub fn fields(&self) -> &HashMap<String, ScriptValue> {
         &self.fields
     }
+
+    /// Payload for [`ScriptEventType::ModeChanged`]: `{ old_mode, new_mode }`.
+    pub fn mode_changed(old_mode: impl Into<String>, new_mode: impl Into<String>) -> Self {
+        Self::new()
+            .with_field("old_mode", ScriptValue::String(old_mode.into()))
+            .with_field("new_mode", ScriptValue::String(new_mode.into()))
+    }
 }
 
 #[cfg(test)]
@@ -163,6 +213,7 @@ mod tests {
             ScriptEventType::ToolCallAfter,
             ScriptEventType::PhaseChanged,
             ScriptEventType::PlanCreated,
+            ScriptEventType::PlanReviewed,
             ScriptEventType::QuorumResult,
         ];
         for event in &events {
@@ -190,6 +241,7 @@ mod tests {
         assert!(!ScriptEventType::ToolCallAfter.is_cancellable());
         assert!(!ScriptEventType::PhaseChanged.is_cancellable());
         assert!(!ScriptEventType::PlanCreated.is_cancellable());
+        assert!(!ScriptEventType::PlanReviewed.is_cancellable());
         assert!(!ScriptEventType::QuorumResult.is_cancellable());
     }
 
@@ -206,4 +258,66 @@ mod tests {
             Some(&ScriptValue::String("agent.strategy".into()))
         );
     }
+
+    #[test]
+    fn test_nested_event_data() {
+        let votes = ScriptValue::Array(vec![
+            ScriptValue::table([
+                ("model", ScriptValue::String("gpt".into())),
+                ("approved", ScriptValue::Boolean(true)),
+            ]),
+            ScriptValue::table([
+                ("model", ScriptValue::String("claude".into())),
+                ("approved", ScriptValue::Boolean(false)),
+            ]),
+        ]);
+        let data = ScriptEventData::new()
+            .with_field("approved", ScriptValue::Boolean(false))
+            .with_field("votes", votes);
+
+        let Some(ScriptValue::Array(items)) = data.fields().get("votes") else {
+            panic!("votes should be an array");
+        };
+        assert_eq!(items.len(), 2);
+        let ScriptValue::Table(second) = &items[1] else {
+            panic!("vote should be a table");
+        };
+        assert_eq!(
+            second.get("model"),
+            Some(&ScriptValue::String("claude".into()))
+        );
+        assert_eq!(second.get("approved"), Some(&ScriptValue::Boolean(false)));
+    }
+
+    #[test]
+    fn test_display_nested_values() {
+        let value = ScriptValue::Array(vec![
+            ScriptValue::table([
+                ("model", ScriptValue::String("gpt".into())),
+                ("approved", ScriptValue::Boolean(true)),
+            ]),
+            ScriptValue::Integer(3),
+            ScriptValue::Nil,
+        ]);
+        assert_eq!(
+            value.to_string(),
+            "[{ approved = true, model = gpt }, 3, nil]"
+        );
+        assert_eq!(ScriptValue::Table(HashMap::new()).to_string(), "{}");
+        assert_eq!(ScriptValue::Array(Vec::new()).to_string(), "[]");
+    }
+
+    #[test]
+    fn test_mode_changed_event_data() {
+        let data = ScriptEventData::mode_changed("Normal", "Insert");
+        assert_eq!(data.fields().len(), 2);
+        assert_eq!(
+            data.fields().get("old_mode"),
+            Some(&ScriptValue::String("Normal".into()))
+        );
+        assert_eq!(
+            data.fields().get("new_mode"),
+            Some(&ScriptValue::String("Insert".into()))
+        );
+    }
 }
@@ -3,8 +3,12 @@
 //! - [`entities::Session`] — a conversation session with an LLM
 //! - [`entities::Message`] — a single message within a session
 //! - [`repository::LlmSessionRepository`] — trait for session persistence
+//! - [`trim::trim_to_budget`] — fit a message history into a [`ContextBudget`](crate::context::ContextBudget)
 
 pub mod entities;
 pub mod repository;
 pub mod response;
 pub mod stream;
+pub mod trim;
+
+pub use trim::trim_to_budget;
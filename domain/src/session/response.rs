@@ -93,6 +93,64 @@ pub enum StopReason {
     Other(String),
 }
 
+/// Token counts reported by the backend for one or more LLM calls.
+///
+/// Usage values add up, so per-call usage can be accumulated into per-phase
+/// or per-run totals with `+=`.
+///
+/// # Examples
+///
+/// ```
+/// use quorum_domain::session::response::TokenUsage;
+///
+/// let mut total = TokenUsage::default();
+/// total += TokenUsage::new(1200, 300);
+/// total += TokenUsage::new(800, 150);
+/// assert_eq!(total, TokenUsage::new(2000, 450));
+/// assert_eq!(total.total(), 2450);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Input (prompt) tokens.
+    pub prompt: u64,
+    /// Output (completion) tokens.
+    pub completion: u64,
+}
+
+impl TokenUsage {
+    pub fn new(prompt: u64, completion: u64) -> Self {
+        Self { prompt, completion }
+    }
+
+    /// Prompt and completion tokens combined.
+    pub fn total(&self) -> u64 {
+        self.prompt + self.completion
+    }
+}
+
+impl std::ops::Add for TokenUsage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            prompt: self.prompt + other.prompt,
+            completion: self.completion + other.completion,
+        }
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::iter::Sum for TokenUsage {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, usage| acc + usage)
+    }
+}
+
 /// A structured response from an LLM, supporting both text and tool use.
 ///
 /// `LlmResponse` is the primary return type for Native Tool Use API calls.
@@ -104,6 +162,7 @@ pub enum StopReason {
 /// - [`tool_calls()`](Self::tool_calls) — extract tool use blocks as `Vec<ToolCall>`
 /// - [`has_tool_calls()`](Self::has_tool_calls) — quick check for tool use
 /// - [`from_text()`](Self::from_text) — wrap a plain string (fallback compatibility)
+/// - [`with_usage()`](Self::with_usage) — attach backend-reported token usage
 ///
 /// # Examples
 ///
@@ -128,6 +187,7 @@ pub enum StopReason {
 ///     ],
 ///     stop_reason: Some(StopReason::ToolUse),
 ///     model: Some("claude-sonnet-4-5-20250929".to_string()),
+///     usage: None,
 /// };
 /// assert!(response.has_tool_calls());
 /// assert_eq!(response.tool_calls().len(), 1);
@@ -141,6 +201,8 @@ pub struct LlmResponse {
     pub stop_reason: Option<StopReason>,
     /// Model identifier (if returned by the API).
     pub model: Option<String>,
+    /// Token usage for this call, if the backend reported it.
+    pub usage: Option<TokenUsage>,
 }
 
 impl LlmResponse {
@@ -150,9 +212,16 @@ impl LlmResponse {
             content: vec![ContentBlock::Text(text.into())],
             stop_reason: Some(StopReason::EndTurn),
             model: None,
+            usage: None,
         }
     }
 
+    /// Attach token usage reported by the backend.
+    pub fn with_usage(mut self, usage: TokenUsage) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
     /// Concatenate all `Text` content blocks into a single string.
     pub fn text_content(&self) -> String {
         self.content
@@ -247,6 +316,7 @@ mod tests {
             ],
             stop_reason: Some(StopReason::ToolUse),
             model: Some("claude-sonnet-4-5-20250929".to_string()),
+            usage: None,
         };
 
         assert!(response.has_tool_calls());
@@ -273,6 +343,7 @@ mod tests {
             content: vec![],
             stop_reason: None,
             model: None,
+            usage: None,
         };
 
         assert_eq!(response.text_content(), "");
@@ -323,6 +394,7 @@ mod tests {
             ],
             stop_reason: Some(StopReason::ToolUse),
             model: None,
+            usage: None,
         };
 
         assert!(response.has_tool_use("create_plan"));
@@ -347,6 +419,7 @@ mod tests {
             ],
             stop_reason: Some(StopReason::ToolUse),
             model: None,
+            usage: None,
         };
 
         assert_eq!(response.first_tool_use_id(), Some("toolu_first"));
@@ -0,0 +1,215 @@
+//! Budget-aware trimming of conversation history.
+//!
+//! Long sessions accumulate messages until the prompt no longer fits.
+//! [`trim_to_budget`] keeps what the model cannot do without — system
+//! prompts, the original user request and the most recent exchanges — and
+//! folds everything in between into a single synthetic summary message.
+//!
+//! The rule-based [`digest`] is only a fallback: callers that can reach an
+//! LLM use [`split_for_budget`] and summarize [`HistorySplit::dropped`]
+//! themselves (see `AgentController::compact_history`).
+
+use crate::context::ContextBudget;
+use crate::session::entities::{Message, Role};
+use crate::util::truncate_str;
+
+/// Header of the synthetic message that replaces trimmed history.
+pub const SUMMARY_HEADER: &str = "[Summary of earlier conversation]";
+
+/// Bytes of each dropped message kept by [`digest`].
+const DIGEST_LINE_BYTES: usize = 200;
+
+/// History split into kept and dropped parts by [`split_for_budget`].
+#[derive(Debug, Clone)]
+pub struct HistorySplit {
+    /// Leading system messages and the original user request.
+    pub head: Vec<Message>,
+    /// Messages to fold into a summary.
+    pub dropped: Vec<Message>,
+    /// The most recent exchanges, kept verbatim.
+    pub tail: Vec<Message>,
+}
+
+impl HistorySplit {
+    /// Reassemble the history with `summary` in place of the dropped messages.
+    pub fn into_messages(self, summary: &str) -> Vec<Message> {
+        let mut messages = self.head;
+        messages.push(Message::system(format!("{}\n{}", SUMMARY_HEADER, summary)));
+        messages.extend(self.tail);
+        messages
+    }
+}
+
+/// Trim `messages` to fit `budget`, summarizing the dropped middle with
+/// [`digest`].
+///
+/// Returns the messages unchanged when they already fit
+/// [`max_total_bytes`](ContextBudget::max_total_bytes) or nothing can be
+/// dropped.
+pub fn trim_to_budget(messages: &[Message], budget: &ContextBudget) -> Vec<Message> {
+    match split_for_budget(messages, budget) {
+        Some(split) => {
+            let summary = digest(&split.dropped);
+            split.into_messages(&summary)
+        }
+        None => messages.to_vec(),
+    }
+}
+
+/// Decide which messages to drop so the history fits `budget`.
+///
+/// Always kept: leading system messages, the first user message (the
+/// original request) and the last exchange. Up to
+/// [`recent_full_count`](ContextBudget::recent_full_count) recent exchanges
+/// are kept, fewer if they alone exceed the budget. An exchange starts at a
+/// user message. Returns `None` when no trimming is needed or possible.
+pub fn split_for_budget(messages: &[Message], budget: &ContextBudget) -> Option<HistorySplit> {
+    if total_bytes(messages) <= budget.max_total_bytes() {
+        return None;
+    }
+
+    let leading_system = messages
+        .iter()
+        .take_while(|m| m.role == Role::System)
+        .count();
+    let head_end = match messages[leading_system..]
+        .iter()
+        .position(|m| m.role == Role::User)
+    {
+        Some(i) => leading_system + i + 1,
+        None => leading_system,
+    };
+
+    let exchange_starts: Vec<usize> = (head_end..messages.len())
+        .filter(|&i| messages[i].role == Role::User)
+        .collect();
+    if exchange_starts.is_empty() {
+        return None;
+    }
+
+    let head_bytes = total_bytes(&messages[..head_end]);
+    let mut keep = budget.recent_full_count().clamp(1, exchange_starts.len());
+    let mut tail_start = exchange_starts[exchange_starts.len() - keep];
+    while keep > 1 && head_bytes + total_bytes(&messages[tail_start..]) > budget.max_total_bytes() {
+        keep -= 1;
+        tail_start = exchange_starts[exchange_starts.len() - keep];
+    }
+
+    if tail_start == head_end {
+        return None;
+    }
+
+    Some(HistorySplit {
+        head: messages[..head_end].to_vec(),
+        dropped: messages[head_end..tail_start].to_vec(),
+        tail: messages[tail_start..].to_vec(),
+    })
+}
+
+/// Rule-based summary: one truncated line per message.
+pub fn digest(messages: &[Message]) -> String {
+    messages
+        .iter()
+        .map(|m| {
+            let role = match m.role {
+                Role::System => "System",
+                Role::User => "User",
+                Role::Assistant => "Assistant",
+            };
+            let first_line = m.content.lines().next().unwrap_or_default();
+            format!(
+                "- {}: {}",
+                role,
+                truncate_str(first_line, DIGEST_LINE_BYTES)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn total_bytes(messages: &[Message]) -> usize {
+    messages.iter().map(|m| m.content.len()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exchange(i: usize, size: usize) -> [Message; 2] {
+        [
+            Message::user(format!("question {} {}", i, "q".repeat(size))),
+            Message::assistant(format!("answer {} {}", i, "a".repeat(size))),
+        ]
+    }
+
+    fn long_history() -> Vec<Message> {
+        let mut messages = vec![
+            Message::system("You are helpful."),
+            Message::user("Original request: fix the login bug"),
+            Message::assistant("Looking into it"),
+        ];
+        for i in 0..10 {
+            messages.extend(exchange(i, 100));
+        }
+        messages
+    }
+
+    fn budget(max_total_bytes: usize, recent: usize) -> ContextBudget {
+        ContextBudget::new(10, max_total_bytes, recent)
+    }
+
+    #[test]
+    fn test_within_budget_is_unchanged() {
+        let messages = long_history();
+        let trimmed = trim_to_budget(&messages, &ContextBudget::unlimited());
+        assert_eq!(trimmed.len(), messages.len());
+    }
+
+    #[test]
+    fn test_trim_preserves_first_user_message_and_last_exchange() {
+        let messages = long_history();
+        let trimmed = trim_to_budget(&messages, &budget(1_000, 2));
+
+        assert_eq!(trimmed[0].role, Role::System);
+        assert_eq!(trimmed[1].content, "Original request: fix the login bug");
+        assert!(trimmed[2].content.starts_with(SUMMARY_HEADER));
+        assert!(trimmed[2].content.contains("Looking into it"));
+
+        let last_two = &trimmed[trimmed.len() - 2..];
+        assert!(last_two[0].content.starts_with("question 9"));
+        assert!(last_two[1].content.starts_with("answer 9"));
+        // recent_full_count = 2 exchanges kept verbatim after the summary
+        assert_eq!(trimmed.len(), 2 + 1 + 4);
+    }
+
+    #[test]
+    fn test_recent_exchanges_shrink_to_fit_but_keep_last() {
+        let messages = long_history();
+        // Only room for the head plus one exchange
+        let trimmed = trim_to_budget(&messages, &budget(300, 5));
+
+        assert_eq!(trimmed.len(), 2 + 1 + 2);
+        assert!(trimmed[3].content.starts_with("question 9"));
+    }
+
+    #[test]
+    fn test_nothing_to_drop_returns_none() {
+        let messages = vec![
+            Message::user("u".repeat(500)),
+            Message::assistant("a".repeat(500)),
+        ];
+        assert!(split_for_budget(&messages, &budget(100, 1)).is_none());
+        assert_eq!(trim_to_budget(&messages, &budget(100, 1)).len(), 2);
+    }
+
+    #[test]
+    fn test_split_reassembles_with_custom_summary() {
+        let split = split_for_budget(&long_history(), &budget(1_000, 1)).unwrap();
+        assert!(!split.dropped.is_empty());
+        let messages = split.into_messages("They discussed the login bug.");
+        assert_eq!(
+            messages[2].content,
+            format!("{}\nThey discussed the login bug.", SUMMARY_HEADER)
+        );
+    }
+}
@@ -8,6 +8,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::util::edit_distance;
+
 /// Risk level of a tool operation, used by the **Quorum review system** to
 /// determine whether multi-model consensus is required before execution.
 ///
@@ -246,6 +248,18 @@ fn split_unquoted_operators(cmd: &str) -> Vec<String> {
     segments
 }
 
+/// Whether the command writes files or runs unpredictable code through shell
+/// syntax alone: output redirection (`>`, `>>`, simple heuristic that ignores
+/// quoting) or command substitution (`$()`, backticks).
+fn has_unsafe_shell_syntax(trimmed: &str) -> bool {
+    trimmed.contains(" > ")
+        || trimmed.contains(" >> ")
+        || trimmed.ends_with('>')
+        || trimmed.contains(">>")
+        || trimmed.contains("$(")
+        || trimmed.contains('`')
+}
+
 /// Classify the risk level of a shell command dynamically.
 ///
 /// Analyzes the command string to determine if it's safe (read-only) or
@@ -283,18 +297,7 @@ pub fn classify_command_risk(command: &str) -> RiskLevel {
         return RiskLevel::Low;
     }
 
-    // Output redirection → file write → High
-    // Check for > or >> but not inside quotes (simple heuristic)
-    if trimmed.contains(" > ")
-        || trimmed.contains(" >> ")
-        || trimmed.ends_with('>')
-        || trimmed.contains(">>")
-    {
-        return RiskLevel::High;
-    }
-
-    // Command substitution → unpredictable → High
-    if trimmed.contains("$(") || trimmed.contains('`') {
+    if has_unsafe_shell_syntax(trimmed) {
         return RiskLevel::High;
     }
 
@@ -313,6 +316,74 @@ pub fn classify_command_risk(command: &str) -> RiskLevel {
     RiskLevel::Low
 }
 
+/// Classify a shell command, consulting configured prefix overrides first.
+///
+/// `overrides` maps command prefixes (e.g. `"cargo test"`) to the risk level
+/// a team wants for them (`tools.risk_overrides`). A prefix matches when the
+/// trimmed command equals it or continues with whitespace after it, so
+/// `"cargo test"` covers `cargo test --workspace` but not `cargo testify`.
+/// When several prefixes match, the longest one wins. Without a match the
+/// baseline [`classify_command_risk`] applies.
+///
+/// A `low` override only applies to a single plain command: operator chains,
+/// redirects and command substitution keep their baseline classification, so
+/// downgrading `cargo test` never lets `cargo test && rm -rf /` through.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use quorum_domain::tool::entities::{classify_command_risk_with_overrides, RiskLevel};
+///
+/// let overrides = HashMap::from([
+///     ("make".to_string(), RiskLevel::Low),
+///     ("git diff".to_string(), RiskLevel::High),
+/// ]);
+///
+/// assert_eq!(classify_command_risk_with_overrides("make lint", &overrides), RiskLevel::Low);
+/// assert_eq!(classify_command_risk_with_overrides("git diff HEAD", &overrides), RiskLevel::High);
+/// assert_eq!(classify_command_risk_with_overrides("rm -rf /", &overrides), RiskLevel::High);
+/// ```
+pub fn classify_command_risk_with_overrides(
+    command: &str,
+    overrides: &HashMap<String, RiskLevel>,
+) -> RiskLevel {
+    let trimmed = command.trim();
+    let matched = overrides
+        .iter()
+        .map(|(prefix, level)| (prefix.trim(), *level))
+        .filter(|(prefix, _)| {
+            !prefix.is_empty()
+                && trimmed
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })
+        .max_by_key(|(prefix, _)| prefix.len());
+
+    match matched {
+        Some((_, RiskLevel::High)) => RiskLevel::High,
+        Some((_, RiskLevel::Low))
+            if !has_unsafe_shell_syntax(trimmed)
+                && split_unquoted_operators(trimmed).len() == 1 =>
+        {
+            RiskLevel::Low
+        }
+        _ => classify_command_risk(command),
+    }
+}
+
+impl std::str::FromStr for RiskLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(RiskLevel::Low),
+            "high" => Ok(RiskLevel::High),
+            _ => Err(format!("invalid risk level '{}', valid: low, high", s)),
+        }
+    }
+}
+
 impl std::fmt::Display for RiskLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -407,8 +478,9 @@ impl ToolParameter {
 /// Registry of available tools.
 ///
 /// `ToolSpec` stores [`ToolDefinition`]s keyed by canonical name. Tools are
-/// passed to the LLM via the Native Tool Use API, which enforces valid tool
-/// names — no alias resolution needed.
+/// passed to the LLM via the Native Tool Use API, which normally enforces
+/// valid tool names; [`resolve_fuzzy`](Self::resolve_fuzzy) recovers the
+/// occasional hallucinated near-miss (`read_files`, `grp`).
 ///
 /// # Examples
 ///
@@ -471,6 +543,71 @@ impl ToolSpec {
     pub fn tool_count(&self) -> usize {
         self.tools.len()
     }
+
+    /// Resolve a possibly misspelled tool name to a registered canonical name.
+    ///
+    /// Returns the canonical name and whether the match was fuzzy (`false`
+    /// for an exact hit). Candidates are each canonical name plus its leading
+    /// word (`grep` for `grep_search`) when no other tool shares that word.
+    /// Returns `None` when no candidate reaches [`FUZZY_MATCH_THRESHOLD`] or
+    /// the best score is tied between different tools.
+    pub fn resolve_fuzzy(&self, name: &str) -> Option<(&str, bool)> {
+        if let Some((canonical, _)) = self.tools.get_key_value(name) {
+            return Some((canonical.as_str(), false));
+        }
+
+        let name = name.to_lowercase();
+        let mut best: Option<(f64, &str)> = None;
+        let mut tied = false;
+        for canonical in self.tools.keys() {
+            let canonical = canonical.as_str();
+            let score = self
+                .fuzzy_candidates(canonical)
+                .map(|candidate| similarity(&name, candidate))
+                .fold(0.0, f64::max);
+            match best {
+                Some((top, top_name)) if score == top && top_name != canonical => tied = true,
+                Some((top, _)) if score <= top => {}
+                _ => {
+                    best = Some((score, canonical));
+                    tied = false;
+                }
+            }
+        }
+
+        match best {
+            Some((score, canonical)) if score >= FUZZY_MATCH_THRESHOLD && !tied => {
+                Some((canonical, true))
+            }
+            _ => None,
+        }
+    }
+
+    /// Names `canonical` may be matched by: itself and, if unique, its leading word.
+    fn fuzzy_candidates<'a>(&self, canonical: &'a str) -> impl Iterator<Item = &'a str> {
+        let word = canonical
+            .split_once('_')
+            .map(|(word, _)| word)
+            .filter(|word| {
+                self.tools
+                    .keys()
+                    .filter(|other| other.split('_').next() == Some(word))
+                    .count()
+                    == 1
+            });
+        std::iter::once(canonical).chain(word)
+    }
+}
+
+/// Minimum similarity (`1 - distance / longer length`) for a fuzzy tool-name match.
+pub const FUZZY_MATCH_THRESHOLD: f64 = 0.7;
+
+fn similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - edit_distance(a, b) as f64 / longest as f64
 }
 
 /// A request to invoke a tool, extracted from an LLM response.
@@ -556,6 +693,18 @@ impl ToolCall {
     pub fn get_bool(&self, key: &str) -> Option<bool> {
         self.arguments.get(key).and_then(|v| v.as_bool())
     }
+
+    /// Get an optional list of strings (a single string counts as one item)
+    pub fn get_string_list(&self, key: &str) -> Vec<String> {
+        match self.arguments.get(key) {
+            Some(serde_json::Value::Array(items)) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            Some(serde_json::Value::String(s)) => vec![s.clone()],
+            _ => Vec::new(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -568,6 +717,13 @@ mod tests {
         assert!(RiskLevel::High.requires_quorum());
     }
 
+    #[test]
+    fn test_risk_level_from_str() {
+        assert_eq!("low".parse::<RiskLevel>(), Ok(RiskLevel::Low));
+        assert_eq!("HIGH".parse::<RiskLevel>(), Ok(RiskLevel::High));
+        assert!("medium".parse::<RiskLevel>().is_err());
+    }
+
     #[test]
     fn test_tool_definition() {
         let tool = ToolDefinition::new("read_file", "Read file contents", RiskLevel::Low)
@@ -581,6 +737,56 @@ mod tests {
         assert_eq!(tool.parameters[0].name, "path");
     }
 
+    fn builtin_like_spec() -> ToolSpec {
+        [
+            "read_file",
+            "write_file",
+            "run_command",
+            "glob_search",
+            "grep_search",
+            "web_fetch",
+            "web_search",
+        ]
+        .into_iter()
+        .fold(ToolSpec::new(), |spec, name| {
+            spec.register(ToolDefinition::new(name, name, RiskLevel::Low))
+        })
+    }
+
+    #[test]
+    fn test_resolve_fuzzy_exact_name_is_not_fuzzy() {
+        assert_eq!(
+            builtin_like_spec().resolve_fuzzy("read_file"),
+            Some(("read_file", false))
+        );
+    }
+
+    #[test]
+    fn test_resolve_fuzzy_plural_typo() {
+        assert_eq!(
+            builtin_like_spec().resolve_fuzzy("read_files"),
+            Some(("read_file", true))
+        );
+    }
+
+    #[test]
+    fn test_resolve_fuzzy_abbreviated_leading_word() {
+        assert_eq!(
+            builtin_like_spec().resolve_fuzzy("grp"),
+            Some(("grep_search", true))
+        );
+    }
+
+    #[test]
+    fn test_resolve_fuzzy_nonsense_has_no_match() {
+        assert_eq!(builtin_like_spec().resolve_fuzzy("frobnicate"), None);
+    }
+
+    #[test]
+    fn test_resolve_fuzzy_shared_leading_word_is_not_a_candidate() {
+        assert_eq!(builtin_like_spec().resolve_fuzzy("web"), None);
+    }
+
     #[test]
     fn test_tool_spec() {
         let spec = ToolSpec::new()
@@ -616,6 +822,17 @@ mod tests {
         assert_eq!(call.native_id, None);
     }
 
+    #[test]
+    fn test_tool_call_string_list() {
+        let call = ToolCall::new("glob_search")
+            .with_arg("exclude", serde_json::json!(["target/", 3, "*.lock"]))
+            .with_arg("single", "build/");
+
+        assert_eq!(call.get_string_list("exclude"), vec!["target/", "*.lock"]);
+        assert_eq!(call.get_string_list("single"), vec!["build/"]);
+        assert!(call.get_string_list("missing").is_empty());
+    }
+
     #[test]
     fn test_tool_call_from_native() {
         let input: HashMap<String, serde_json::Value> =
@@ -927,4 +1144,101 @@ mod tests {
         assert_eq!(classify_command_risk("make"), RiskLevel::High);
         assert_eq!(classify_command_risk("yarn build"), RiskLevel::High);
     }
+
+    // ==================== Risk Override Tests ====================
+
+    fn overrides(entries: &[(&str, RiskLevel)]) -> HashMap<String, RiskLevel> {
+        entries
+            .iter()
+            .map(|(prefix, level)| (prefix.to_string(), *level))
+            .collect()
+    }
+
+    #[test]
+    fn test_override_downgrades_high_command() {
+        let table = overrides(&[("make", RiskLevel::Low)]);
+        assert_eq!(classify_command_risk("make test"), RiskLevel::High);
+        assert_eq!(
+            classify_command_risk_with_overrides("make test", &table),
+            RiskLevel::Low
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("make", &table),
+            RiskLevel::Low
+        );
+    }
+
+    #[test]
+    fn test_override_upgrades_low_command() {
+        let table = overrides(&[("git log", RiskLevel::High)]);
+        assert_eq!(classify_command_risk("git log --oneline"), RiskLevel::Low);
+        assert_eq!(
+            classify_command_risk_with_overrides("git log --oneline", &table),
+            RiskLevel::High
+        );
+    }
+
+    #[test]
+    fn test_override_longest_prefix_wins() {
+        let table = overrides(&[
+            ("cargo", RiskLevel::High),
+            ("cargo publish", RiskLevel::High),
+            ("cargo publish --dry-run", RiskLevel::Low),
+        ]);
+        assert_eq!(
+            classify_command_risk_with_overrides("cargo publish --dry-run", &table),
+            RiskLevel::Low
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("cargo publish", &table),
+            RiskLevel::High
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("cargo test", &table),
+            RiskLevel::High
+        );
+    }
+
+    #[test]
+    fn test_override_matches_whole_words_only() {
+        let table = overrides(&[("make", RiskLevel::Low)]);
+        assert_eq!(
+            classify_command_risk_with_overrides("makefile-gen", &table),
+            RiskLevel::High
+        );
+    }
+
+    #[test]
+    fn test_override_falls_back_to_baseline() {
+        let table = overrides(&[("make", RiskLevel::Low)]);
+        assert_eq!(
+            classify_command_risk_with_overrides("ls -la", &table),
+            RiskLevel::Low
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("rm -rf /", &table),
+            RiskLevel::High
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("rm -rf /", &HashMap::new()),
+            RiskLevel::High
+        );
+    }
+
+    #[test]
+    fn test_low_override_does_not_cover_chains_or_redirects() {
+        let table = overrides(&[("make", RiskLevel::Low)]);
+        assert_eq!(
+            classify_command_risk_with_overrides("make && rm -rf /", &table),
+            RiskLevel::High
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("make > out.txt", &table),
+            RiskLevel::High
+        );
+        assert_eq!(
+            classify_command_risk_with_overrides("make $(cat targets)", &table),
+            RiskLevel::High
+        );
+    }
 }
@@ -16,22 +16,17 @@
 //! │ (registry)   │    │ (invocation) │    │ (output)     │
 //! └──────┬───────┘    └──────────────┘    └──────────────┘
 //!        │
-//!        ├─ aliases: "bash" → "run_command"
-//!        └─ tools:   "run_command" → ToolDefinition
+//!        ├─ tools:   "run_command" → ToolDefinition
+//!        └─ resolve_fuzzy: "read_files" → "read_file"
 //! ```
 //!
-//! # Tool Name Alias System
+//! # Fuzzy Tool Name Resolution
 //!
-//! LLMs frequently hallucinate tool names (e.g. `bash` instead of `run_command`,
-//! `grep` instead of `grep_search`). The **alias system** in [`ToolSpec`] provides
-//! zero-cost name resolution without an extra LLM round-trip:
-//!
-//! - [`ToolSpec::resolve_alias`] — resolves alias → canonical name (aliases only)
-//! - [`ToolSpec::resolve`] — resolves any name (canonical or alias)
-//! - [`ToolSpec::get_resolved`] — looks up a [`ToolDefinition`] by canonical or alias name
-//!
-//! This is used by the application layer's `resolve_tool_call()` and
-//! `resolve_plan_aliases()` to transparently correct LLM mistakes.
+//! LLMs occasionally hallucinate tool names (e.g. `read_files` instead of
+//! `read_file`, `grp` instead of `grep_search`). [`ToolSpec::resolve_fuzzy`]
+//! maps such near-misses to a registered tool by edit distance, without an
+//! extra LLM round-trip. The application layer applies it to each turn's tool
+//! calls before risk routing and reports corrections via `on_tool_resolved`.
 //!
 //! # Risk-Based Execution
 //!
@@ -45,12 +40,13 @@
 //!
 //! # Key Types
 //!
-//! - [`ToolSpec`] — Registry of available tools + alias mappings
+//! - [`ToolSpec`] — Registry of available tools with fuzzy name resolution
 //! - [`ToolDefinition`] — Schema for a single tool (name, params, risk level)
 //! - [`ToolCall`] — An invocation request with arguments
 //! - [`ToolResult`] — Execution outcome with structured [`ToolResultMetadata`](value_objects::ToolResultMetadata)
 //! - [`ToolValidator`] — Pure domain trait for parameter validation
 //! - [`ToolProvider`] — Abstraction for external tool providers (MCP, etc.)
+//! - [`ToolTimeouts`] — Per-tool execution time limits
 //! - [`looks_like_tool_call_json`] — Detect tool calls leaked as JSON text (#268)
 //!
 //! # Architecture
@@ -70,11 +66,15 @@
 pub mod detection;
 pub mod entities;
 pub mod provider;
+pub mod timeouts;
 pub mod traits;
 pub mod value_objects;
 
 pub use detection::looks_like_tool_call_json;
-pub use entities::{ToolCall, ToolDefinition, ToolSpec, classify_command_risk};
+pub use entities::{
+    ToolCall, ToolDefinition, ToolSpec, classify_command_risk, classify_command_risk_with_overrides,
+};
 pub use provider::{ProviderError, ToolProvider};
-pub use traits::{DefaultToolValidator, ToolValidator};
+pub use timeouts::ToolTimeouts;
+pub use traits::{DefaultToolValidator, ToolValidator, unknown_arguments};
 pub use value_objects::{ErrorCategory, ToolError, ToolResult};
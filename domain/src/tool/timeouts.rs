@@ -0,0 +1,107 @@
+//! Tool execution time limits
+//!
+//! [`ToolTimeouts`] bounds how long any single tool call may run, so a
+//! hanging command (or a pathological search) cannot block the agent loop.
+//! Configured via `tools.default_timeout_secs` and `tools.timeouts`, and
+//! enforced by the tool executor in the infrastructure layer.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Default per-call limit in seconds (matches the historical `run_command` default).
+pub const DEFAULT_TOOL_TIMEOUT_SECS: u64 = 60;
+
+/// Per-tool execution time limits.
+///
+/// Every tool gets [`default_secs`](Self::default_secs) unless it has an
+/// entry in [`per_tool`](Self::per_tool), keyed by canonical tool name.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use quorum_domain::tool::timeouts::ToolTimeouts;
+///
+/// let timeouts = ToolTimeouts::default().with_tool_timeout("run_command", 300);
+///
+/// assert_eq!(timeouts.timeout_for("run_command"), Duration::from_secs(300));
+/// assert_eq!(timeouts.timeout_for("grep_search"), Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolTimeouts {
+    default_secs: u64,
+    #[serde(default)]
+    per_tool: HashMap<String, u64>,
+}
+
+impl Default for ToolTimeouts {
+    fn default() -> Self {
+        Self {
+            default_secs: DEFAULT_TOOL_TIMEOUT_SECS,
+            per_tool: HashMap::new(),
+        }
+    }
+}
+
+impl ToolTimeouts {
+    pub fn with_default_secs(mut self, secs: u64) -> Self {
+        self.default_secs = secs;
+        self
+    }
+
+    pub fn with_tool_timeout(mut self, tool_name: impl Into<String>, secs: u64) -> Self {
+        self.per_tool.insert(tool_name.into(), secs);
+        self
+    }
+
+    pub fn default_secs(&self) -> u64 {
+        self.default_secs
+    }
+
+    pub fn per_tool(&self) -> &HashMap<String, u64> {
+        &self.per_tool
+    }
+
+    pub fn set_default_secs(&mut self, secs: u64) {
+        self.default_secs = secs;
+    }
+
+    pub fn set_per_tool(&mut self, per_tool: HashMap<String, u64>) {
+        self.per_tool = per_tool;
+    }
+
+    /// Timeout in seconds for `tool_name`.
+    pub fn secs_for(&self, tool_name: &str) -> u64 {
+        self.per_tool
+            .get(tool_name)
+            .copied()
+            .unwrap_or(self.default_secs)
+    }
+
+    /// Timeout for `tool_name`.
+    pub fn timeout_for(&self, tool_name: &str) -> Duration {
+        Duration::from_secs(self.secs_for(tool_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_applies_to_every_tool() {
+        let timeouts = ToolTimeouts::default();
+        assert_eq!(timeouts.secs_for("run_command"), DEFAULT_TOOL_TIMEOUT_SECS);
+        assert_eq!(timeouts.secs_for("read_file"), DEFAULT_TOOL_TIMEOUT_SECS);
+    }
+
+    #[test]
+    fn test_per_tool_override_wins() {
+        let timeouts = ToolTimeouts::default()
+            .with_default_secs(10)
+            .with_tool_timeout("run_command", 120);
+        assert_eq!(timeouts.secs_for("run_command"), 120);
+        assert_eq!(timeouts.secs_for("glob_search"), 10);
+    }
+}
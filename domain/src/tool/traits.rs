@@ -17,7 +17,10 @@ use super::entities::{ToolCall, ToolDefinition};
 ///
 /// Validation checks that:
 /// - All **required** parameters are present
-/// - No **unknown** parameters are supplied
+/// - Supplied arguments match their parameter's type hint
+///
+/// Unknown extra arguments are not an error — callers can report them via
+/// [`unknown_arguments`] and still execute the call.
 ///
 /// This trait is part of the **Tool System's** safety pipeline:
 ///
@@ -35,7 +38,7 @@ pub trait ToolValidator {
 }
 
 /// Default implementation of [`ToolValidator`] with required-parameter and
-/// unknown-parameter checks.
+/// type checks.
 ///
 /// Used by `LocalToolExecutor` in the infrastructure layer for all tool
 /// invocations (file, command, search, and web tools).
@@ -54,18 +57,18 @@ impl ToolValidator for DefaultToolValidator {
             }
         }
 
-        // Check that all provided arguments are valid parameters
-        let valid_params: std::collections::HashSet<&str> = definition
-            .parameters
-            .iter()
-            .map(|p| p.name.as_str())
-            .collect();
-
-        for arg_name in call.arguments.keys() {
-            if !valid_params.contains(arg_name.as_str()) {
+        // Check that supplied arguments match their declared types
+        for param in &definition.parameters {
+            let Some(value) = call.arguments.get(&param.name) else {
+                continue;
+            };
+            if !matches_type(value, &param.param_type) {
                 return Err(format!(
-                    "Unknown parameter '{}' for tool '{}'",
-                    arg_name, definition.name
+                    "Parameter '{}' for tool '{}' must be of type {}, got {}",
+                    param.name,
+                    definition.name,
+                    param.param_type,
+                    json_type_name(value)
                 ));
             }
         }
@@ -74,6 +77,52 @@ impl ToolValidator for DefaultToolValidator {
     }
 }
 
+/// Names of arguments in `call` that `definition` does not declare.
+///
+/// These are tolerated by [`DefaultToolValidator`]; executors log them as
+/// warnings so that a sloppy-but-usable call still runs.
+pub fn unknown_arguments<'a>(call: &'a ToolCall, definition: &ToolDefinition) -> Vec<&'a str> {
+    let mut unknown: Vec<&str> = call
+        .arguments
+        .keys()
+        .filter(|name| !definition.parameters.iter().any(|p| &p.name == *name))
+        .map(String::as_str)
+        .collect();
+    unknown.sort_unstable();
+    unknown
+}
+
+/// Whether `value` is acceptable for a parameter with the given type hint.
+///
+/// `null` counts as absent, a single string is accepted where an array is
+/// expected (see [`ToolCall::get_string_list`]), and unrecognised type hints
+/// accept anything.
+fn matches_type(value: &serde_json::Value, param_type: &str) -> bool {
+    use serde_json::Value;
+    match (param_type, value) {
+        (_, Value::Null) => true,
+        ("string" | "path", v) => v.is_string(),
+        ("number", v) => v.is_number(),
+        ("integer", v) => v.is_i64() || v.is_u64(),
+        ("boolean", v) => v.is_boolean(),
+        ("array", v) => v.is_array() || v.is_string(),
+        ("object", v) => v.is_object(),
+        _ => true,
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    use serde_json::Value;
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,15 +142,40 @@ mod tests {
     }
 
     #[test]
-    fn test_validator_unknown_param() {
+    fn test_validator_unknown_param_is_tolerated() {
         let validator = DefaultToolValidator;
         let definition = ToolDefinition::new("test", "test tool", RiskLevel::Low)
             .with_parameter(ToolParameter::new("known_param", "A known param", false));
 
         let call = ToolCall::new("test").with_arg("unknown_param", "value");
-        let result = validator.validate(&call, &definition);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Unknown parameter"));
+        assert!(validator.validate(&call, &definition).is_ok());
+        assert_eq!(unknown_arguments(&call, &definition), vec!["unknown_param"]);
+    }
+
+    #[test]
+    fn test_validator_wrong_type() {
+        let validator = DefaultToolValidator;
+        let definition = ToolDefinition::new("test", "test tool", RiskLevel::Low)
+            .with_parameter(ToolParameter::new("limit", "A limit", false).with_type("number"));
+
+        let call = ToolCall::new("test").with_arg("limit", "ten");
+        let err = validator.validate(&call, &definition).unwrap_err();
+        assert!(err.contains("must be of type number, got string"));
+    }
+
+    #[test]
+    fn test_validator_type_leniency() {
+        let validator = DefaultToolValidator;
+        let definition = ToolDefinition::new("test", "test tool", RiskLevel::Low)
+            .with_parameter(ToolParameter::new("globs", "Globs", false).with_type("array"))
+            .with_parameter(ToolParameter::new("flag", "Flag", false).with_type("boolean"))
+            .with_parameter(ToolParameter::new("raw", "Raw", false).with_type("custom"));
+
+        let call = ToolCall::new("test")
+            .with_arg("globs", "*.rs")
+            .with_arg("flag", serde_json::Value::Null)
+            .with_arg("raw", 3);
+        assert!(validator.validate(&call, &definition).is_ok());
     }
 
     #[test]
@@ -175,6 +175,9 @@ pub struct ToolResult {
 /// | `grep_search` | yes | - | - | - | yes |
 /// | `web_fetch` | yes | yes | - | - | - |
 /// | `web_search` | yes | - | - | - | - |
+///
+/// `write_file` also sets `diff` (or `binary` for non-text contents).
+/// `dry_run` is set instead by the dry-run executor for calls it skipped.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ToolResultMetadata {
     /// Duration of execution in milliseconds
@@ -192,6 +195,24 @@ pub struct ToolResultMetadata {
     /// For search operations: number of matches
     #[serde(skip_serializing_if = "Option::is_none")]
     pub match_count: Option<usize>,
+    /// The call was not executed; the result describes what would have happened
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// For file writes: unified diff of the previous and new contents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    /// For file writes: contents are not UTF-8 text, so no diff was computed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub binary: bool,
+    /// For search operations: more matches existed than the result cap allowed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+    /// For ranged file reads: number of lines in the whole file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_lines: Option<usize>,
+    /// Output was cut to the context budget; its length before truncation in chars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_chars: Option<usize>,
 }
 
 impl ToolResult {
@@ -235,6 +256,30 @@ impl ToolResult {
         self
     }
 
+    /// Cut the output to at most `max_chars` characters.
+    ///
+    /// A truncated output ends with a `[truncated N of M chars]` marker and
+    /// records the original length in [`ToolResultMetadata::original_chars`].
+    /// Outputs within the limit are returned unchanged.
+    pub fn truncate_output(mut self, max_chars: usize) -> Self {
+        let Some(output) = &self.output else {
+            return self;
+        };
+        let total = output.chars().count();
+        if total <= max_chars {
+            return self;
+        }
+        let mut truncated: String = output.chars().take(max_chars).collect();
+        truncated.push_str(&format!(
+            "\n[truncated {} of {} chars]",
+            total - max_chars,
+            total
+        ));
+        self.output = Some(truncated);
+        self.metadata.original_chars = Some(total);
+        self
+    }
+
     /// Check if execution was successful
     pub fn is_success(&self) -> bool {
         self.success
@@ -274,6 +319,23 @@ mod tests {
         assert_eq!(result.metadata.path, Some("/test/file.txt".to_string()));
     }
 
+    #[test]
+    fn test_truncate_output_over_limit() {
+        let result = ToolResult::success("read_file", "abcdefghij").truncate_output(4);
+        assert_eq!(result.output(), Some("abcd\n[truncated 6 of 10 chars]"));
+        assert_eq!(result.metadata.original_chars, Some(10));
+    }
+
+    #[test]
+    fn test_truncate_output_within_limit_is_unchanged() {
+        let result = ToolResult::success("read_file", "日本語").truncate_output(3);
+        assert_eq!(result.output(), Some("日本語"));
+        assert_eq!(result.metadata.original_chars, None);
+
+        let failed = ToolResult::failure("read_file", ToolError::not_found("x")).truncate_output(1);
+        assert!(failed.error().is_some());
+    }
+
     #[test]
     fn test_tool_result_failure() {
         let result = ToolResult::failure("write_file", ToolError::permission_denied("/etc/passwd"));
@@ -74,6 +74,21 @@ pub fn truncate_head_tail(s: &str, max_bytes: usize) -> String {
     format!("{}{}{}", &s[..head_end], MARKER, &s[tail_start..])
 }
 
+/// Levenshtein distance between two strings (by `char`).
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,4 +215,13 @@ mod tests {
         let s = "abc";
         assert_eq!(find_char_boundary_forward(s, 100), 3);
     }
+
+    // ==================== edit_distance tests ====================
+
+    #[test]
+    fn edit_distance_counts_char_edits() {
+        assert_eq!(edit_distance("gpt-5.2-codex", "gpt-5.3-codex"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
 }
@@ -0,0 +1,106 @@
+//! On-disk cache of loaded context files
+//!
+//! [`LocalContextLoader`](super::LocalContextLoader) stores the files it
+//! loaded in `.quorum/cache/context.json`, together with a fingerprint of
+//! every path it looked at (modification time and size, or absence). The
+//! next load in the same working directory reuses the cached files without
+//! reading them as long as the fingerprint still matches; `ProjectContext`
+//! is rebuilt from them as usual.
+//!
+//! The fingerprint only stats files, so a changed, added or removed context
+//! file invalidates the cache. A cache that cannot be read or parsed is
+//! ignored and overwritten.
+
+use quorum_domain::{ContextFileLimits, LoadedContextFile};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tracing::debug;
+
+/// Cache location relative to the project root.
+const CACHE_PATH: &str = ".quorum/cache/context.json";
+
+/// Modification time and size of a path; `None` fields when it is missing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct FileStamp {
+    path: String,
+    modified: Option<(u64, u32)>,
+    len: Option<u64>,
+}
+
+impl FileStamp {
+    pub(super) fn of(path: &Path) -> Self {
+        let metadata = fs::metadata(path).ok().filter(|m| m.is_file());
+        let modified = metadata
+            .as_ref()
+            .and_then(|m| m.modified().ok())
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| (d.as_secs(), d.subsec_nanos()));
+        Self {
+            path: path.to_string_lossy().into_owned(),
+            modified,
+            len: metadata.map(|m| m.len()),
+        }
+    }
+}
+
+/// Everything a cached load depends on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct CacheKey {
+    pub(super) working_dir: String,
+    pub(super) limits: ContextFileLimits,
+    pub(super) pinned: Vec<PathBuf>,
+    pub(super) stamps: Vec<FileStamp>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    key: CacheKey,
+    files: Vec<LoadedContextFile>,
+}
+
+pub(super) fn cache_path(project_root: &Path) -> PathBuf {
+    project_root.join(CACHE_PATH)
+}
+
+/// The cached files for `key`, or `None` when the cache is missing,
+/// corrupt or stale.
+pub(super) fn load(project_root: &Path, key: &CacheKey) -> Option<Vec<LoadedContextFile>> {
+    let path = cache_path(project_root);
+    let raw = fs::read_to_string(&path).ok()?;
+    let entry: CacheEntry = match serde_json::from_str(&raw) {
+        Ok(entry) => entry,
+        Err(e) => {
+            debug!("Ignoring corrupt context cache {:?}: {}", path, e);
+            return None;
+        }
+    };
+    if entry.key != *key {
+        debug!("Context cache {:?} is stale", path);
+        return None;
+    }
+    Some(entry.files)
+}
+
+/// Write the cache. Failures (read-only checkout, etc.) are logged and
+/// otherwise ignored — the cache is only an optimization.
+pub(super) fn store(project_root: &Path, key: CacheKey, files: &[LoadedContextFile]) {
+    let path = cache_path(project_root);
+    let entry = CacheEntry {
+        key,
+        files: files.to_vec(),
+    };
+    let result = (|| -> std::io::Result<()> {
+        let dir = path.parent().expect("cache path has a parent");
+        fs::create_dir_all(dir)?;
+        let gitignore = dir.join(".gitignore");
+        if !gitignore.exists() {
+            fs::write(gitignore, "*\n")?;
+        }
+        fs::write(&path, serde_json::to_string(&entry)?)
+    })();
+    if let Err(e) = result {
+        debug!("Could not write context cache {:?}: {}", path, e);
+    }
+}
@@ -21,7 +21,26 @@
 //! 3. `~/.claude/CLAUDE.md` - Global Claude configuration
 //! 4. `README.md` - Project readme
 //! 5. `docs/**/*.md` - All markdown in docs/ directory
-//! 6. `Cargo.toml`, `package.json`, `pyproject.toml` - Build configs
+//! 6. `Cargo.toml`, `package.json`, `pyproject.toml`, `go.mod`, `pom.xml`,
+//!    `build.gradle`, `Gemfile`, `composer.json` - Build configs
+//!
+//! Files pinned via [`LocalContextLoader::with_pinned_files`] are loaded
+//! alongside these as [`KnownContextFile::Pinned`]. A pinned path that
+//! doesn't exist is logged and skipped.
+//!
+//! # Size Limits
+//!
+//! Loaded files are capped by [`ContextFileLimits`] (per-file and total
+//! bytes). Oversized files are truncated; once the total budget is spent,
+//! lower-priority files are skipped. Both cases leave a note in
+//! [`ProjectContext::notes`](quorum_domain::ProjectContext::notes).
+//!
+//! # Caching
+//!
+//! Loaded files are cached in `.quorum/cache/context.json` (see the `cache`
+//! module). A later load in the same working directory skips the file reads
+//! while every candidate path keeps its modification time and size; any
+//! change, or a corrupt cache, falls back to a fresh load.
 //!
 //! # Example
 //!
@@ -44,10 +63,11 @@
 //! }
 //! ```
 
+use super::cache::{self, CacheKey, FileStamp};
 use quorum_application::ContextLoaderPort;
-use quorum_domain::{KnownContextFile, LoadedContextFile};
+use quorum_domain::{ContextFileLimits, KnownContextFile, LoadedContextFile};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
 /// Context loader that reads from the local file system.
@@ -69,7 +89,10 @@ use tracing::{debug, warn};
 /// let loader = LocalContextLoader::new();
 /// ```
 #[derive(Debug, Clone, Default)]
-pub struct LocalContextLoader;
+pub struct LocalContextLoader {
+    limits: ContextFileLimits,
+    pinned_files: Vec<PathBuf>,
+}
 
 impl LocalContextLoader {
     /// Creates a new local context loader.
@@ -82,7 +105,44 @@ impl LocalContextLoader {
     /// let loader = LocalContextLoader::new();
     /// ```
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Sets the per-file and total size caps (`context.*` config keys).
+    pub fn with_limits(mut self, limits: ContextFileLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets files to always load (`context.pinned_files` / `--context-file`).
+    ///
+    /// Relative paths are resolved against the project root.
+    pub fn with_pinned_files(
+        mut self,
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        self.pinned_files = paths.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Loads every pinned file, warning about any that are missing.
+    fn load_pinned_files(
+        &self,
+        project_root: &Path,
+        on_loaded: &mut dyn FnMut(&LoadedContextFile),
+    ) -> Vec<LoadedContextFile> {
+        self.pinned_files
+            .iter()
+            .filter_map(|pinned| {
+                let path = project_root.join(pinned);
+                if !path.is_file() {
+                    warn!("Pinned context file not found: {:?}", path);
+                    return None;
+                }
+                self.load_single_file(KnownContextFile::Pinned, &path)
+            })
+            .inspect(|file| on_loaded(file))
+            .collect()
     }
 
     /// Attempts to load a single known file type.
@@ -161,6 +221,74 @@ impl LocalContextLoader {
         }
     }
 
+    /// Every path a load looks at, for the cache fingerprint: pinned files,
+    /// each known file (present or not) and the markdown under `docs/`.
+    fn candidate_paths(&self, project_root: &Path) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self
+            .pinned_files
+            .iter()
+            .map(|p| project_root.join(p))
+            .collect();
+        for file_type in KnownContextFile::all() {
+            match file_type {
+                KnownContextFile::DocsMarkdown => {
+                    let mut docs: Vec<PathBuf> = walkdir(project_root.join("docs"))
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+                        .collect();
+                    docs.sort();
+                    paths.extend(docs);
+                }
+                KnownContextFile::ClaudeMdGlobal => {
+                    if let Some(home) = dirs::home_dir() {
+                        paths.push(home.join(".claude/CLAUDE.md"));
+                    }
+                }
+                _ => paths.push(project_root.join(file_type.relative_path())),
+            }
+        }
+        paths
+    }
+
+    /// Cache key for a load from `project_root` with the current settings.
+    fn cache_key(&self, project_root: &Path) -> CacheKey {
+        let working_dir = fs::canonicalize(project_root).unwrap_or(project_root.to_path_buf());
+        CacheKey {
+            working_dir: working_dir.to_string_lossy().into_owned(),
+            limits: self.limits.clone(),
+            pinned: self.pinned_files.clone(),
+            stamps: self
+                .candidate_paths(project_root)
+                .iter()
+                .map(|p| FileStamp::of(p))
+                .collect(),
+        }
+    }
+
+    /// Reads every known and pinned file, sorted by priority and capped.
+    ///
+    /// `on_loaded` is called as each file is read, before the size limits
+    /// are applied.
+    fn read_known_files(
+        &self,
+        project_root: &Path,
+        on_loaded: &mut dyn FnMut(&LoadedContextFile),
+    ) -> Vec<LoadedContextFile> {
+        let mut files = self.load_pinned_files(project_root, on_loaded);
+
+        for file_type in KnownContextFile::all() {
+            if let Some(loaded) = self.try_load_file(project_root, *file_type) {
+                on_loaded(&loaded);
+                files.push(loaded);
+            }
+        }
+
+        // Sort by priority, then cap sizes so high-priority files win the budget
+        files.sort_by_key(|f| f.file_type.priority());
+        self.limits.apply(files)
+    }
+
     /// Loads the global CLAUDE.md from the user's home directory.
     ///
     /// Looks for `~/.claude/CLAUDE.md` and loads it if present.
@@ -279,19 +407,42 @@ impl ContextLoaderPort for LocalContextLoader {
     ///
     /// # Returns
     ///
-    /// A list of loaded context files, sorted by priority (highest first).
+    /// A list of loaded context files, sorted by priority (highest first)
+    /// and capped by the loader's [`ContextFileLimits`]. Served from
+    /// `.quorum/cache/context.json` when no candidate file changed.
     fn load_known_files(&self, project_root: &Path) -> Vec<LoadedContextFile> {
-        let mut files = Vec::new();
+        self.load_known_files_with_progress(project_root, &mut |_| {})
+    }
 
-        for file_type in KnownContextFile::all() {
-            if let Some(loaded) = self.try_load_file(project_root, *file_type) {
-                files.push(loaded);
+    /// Loads all known context files, calling `on_loaded` as each one is
+    /// read from disk.
+    ///
+    /// On a cache hit nothing is read, so every cached file that was not
+    /// skipped by the size limits is reported instead.
+    fn load_known_files_with_progress(
+        &self,
+        project_root: &Path,
+        on_loaded: &mut dyn FnMut(&LoadedContextFile),
+    ) -> Vec<LoadedContextFile> {
+        let key = self.cache_key(project_root);
+        let files = match cache::load(project_root, &key) {
+            Some(files) => {
+                debug!("Context cache hit for {:?}", project_root);
+                for file in files.iter().filter(|f| !f.is_skipped()) {
+                    on_loaded(file);
+                }
+                files
+            }
+            None => {
+                let files = self.read_known_files(project_root, on_loaded);
+                cache::store(project_root, key, &files);
+                files
             }
+        };
+        for note in files.iter().filter_map(LoadedContextFile::size_note) {
+            warn!("Context file size limit: {}", note);
         }
 
-        // Sort by priority
-        files.sort_by_key(|f| f.file_type.priority());
-
         debug!(
             "Loaded {} context files from {:?}",
             files.len(),
@@ -424,6 +575,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_oversized_file_truncated_and_noted() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+
+        fs::write(root.join("CLAUDE.md"), "# Instructions\n".repeat(100)).unwrap();
+        fs::write(root.join("README.md"), "readme ".repeat(10)).unwrap();
+
+        let loader =
+            LocalContextLoader::new().with_limits(ContextFileLimits::try_new(200, 250).unwrap());
+        let files = loader.load_known_files(root);
+
+        let claude = files
+            .iter()
+            .find(|f| f.file_type == KnownContextFile::ClaudeMdLocal)
+            .unwrap();
+        assert!(claude.is_truncated());
+        assert!(claude.content.len() <= 200);
+
+        let ctx = loader.build_project_context(files);
+        assert_eq!(
+            ctx.source_description(),
+            "CLAUDE.md (truncated from 1500 bytes)"
+        );
+        assert!(ctx.notes.iter().any(|n| n.contains("CLAUDE.md truncated")));
+    }
+
+    #[test]
+    fn test_files_beyond_total_cap_skipped() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+
+        fs::write(root.join("CLAUDE.md"), "c".repeat(100)).unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]").unwrap();
+
+        let loader =
+            LocalContextLoader::new().with_limits(ContextFileLimits::try_new(100, 100).unwrap());
+        let ctx = loader.build_project_context(loader.load_known_files(root));
+
+        // CLAUDE.md fits exactly; Cargo.toml is skipped and leaves a note
+        assert_eq!(ctx.source_description(), "CLAUDE.md");
+        assert!(ctx.project_type.is_none());
+        assert!(ctx.notes.iter().any(|n| n.contains("Cargo.toml skipped")));
+    }
+
     #[test]
     fn test_build_project_context() {
         let dir = tempdir().unwrap();
@@ -439,4 +635,124 @@ mod tests {
         assert!(ctx.has_sufficient_context());
         assert_eq!(ctx.project_type, Some("rust".to_string()));
     }
+
+    #[test]
+    fn test_pinned_files_loaded_and_missing_skipped() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/auth.rs"), "fn login() {}").unwrap();
+
+        let loader = LocalContextLoader::new().with_pinned_files(["src/auth.rs", "missing.rs"]);
+        let files = loader.load_known_files(root);
+
+        let pinned: Vec<_> = files
+            .iter()
+            .filter(|f| f.file_type == KnownContextFile::Pinned)
+            .collect();
+        assert_eq!(pinned.len(), 1);
+        assert_eq!(pinned[0].content, "fn login() {}");
+        assert!(pinned[0].path.ends_with("auth.rs"));
+    }
+
+    fn claude_md_content(files: &[LoadedContextFile]) -> &str {
+        &files
+            .iter()
+            .find(|f| f.file_type == KnownContextFile::ClaudeMdLocal)
+            .unwrap()
+            .content
+    }
+
+    /// Rewrite the cached CLAUDE.md content so a cache hit is observable.
+    fn tamper_cached_content(root: &Path, from: &str, to: &str) {
+        let path = cache::cache_path(root);
+        let raw = fs::read_to_string(&path).unwrap();
+        assert!(raw.contains(from));
+        fs::write(&path, raw.replace(from, to)).unwrap();
+    }
+
+    #[test]
+    fn test_cache_hit_skips_file_reads() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("CLAUDE.md"), "# Instructions").unwrap();
+        let loader = LocalContextLoader::new();
+
+        let first = loader.load_known_files(root);
+        assert_eq!(claude_md_content(&first), "# Instructions");
+        assert!(cache::cache_path(root).is_file());
+
+        // The second load returns what the cache holds, not what is on disk.
+        tamper_cached_content(root, "# Instructions", "# From cache");
+        let second = loader.load_known_files(root);
+        assert_eq!(claude_md_content(&second), "# From cache");
+    }
+
+    #[test]
+    fn test_load_with_progress_reports_each_file() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("CLAUDE.md"), "# Instructions").unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]").unwrap();
+        fs::write(root.join("notes.md"), "pinned").unwrap();
+        let loader = LocalContextLoader::new().with_pinned_files(["notes.md"]);
+
+        for _ in 0..2 {
+            // Second pass is a cache hit and must report the same files
+            let mut reported = Vec::new();
+            let files =
+                loader.load_known_files_with_progress(root, &mut |f| reported.push(f.file_type));
+            assert_eq!(reported.len(), files.len());
+            // ~/.claude/CLAUDE.md depends on the machine running the test
+            reported.retain(|t| *t != KnownContextFile::ClaudeMdGlobal);
+            assert_eq!(
+                reported,
+                vec![
+                    KnownContextFile::Pinned,
+                    KnownContextFile::ClaudeMdLocal,
+                    KnownContextFile::CargoToml,
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_cache_invalidated_by_mtime_change() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let claude_md = root.join("CLAUDE.md");
+        fs::write(&claude_md, "# Instructions").unwrap();
+        let loader = LocalContextLoader::new();
+        loader.load_known_files(root);
+        tamper_cached_content(root, "# Instructions", "# From cache");
+
+        fs::write(&claude_md, "# Updated").unwrap();
+        let later = std::time::SystemTime::now() + std::time::Duration::from_secs(10);
+        fs::File::options()
+            .write(true)
+            .open(&claude_md)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        let files = loader.load_known_files(root);
+        assert_eq!(claude_md_content(&files), "# Updated");
+    }
+
+    #[test]
+    fn test_corrupt_cache_is_rebuilt() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("CLAUDE.md"), "# Instructions").unwrap();
+        let cache_file = cache::cache_path(root);
+        fs::create_dir_all(cache_file.parent().unwrap()).unwrap();
+        fs::write(&cache_file, "{ not json").unwrap();
+
+        let files = LocalContextLoader::new().load_known_files(root);
+
+        assert_eq!(claude_md_content(&files), "# Instructions");
+        let raw = fs::read_to_string(&cache_file).unwrap();
+        assert!(serde_json::from_str::<serde_json::Value>(&raw).is_ok());
+    }
 }
@@ -7,6 +7,7 @@
 //! # Components
 //!
 //! - [`LocalContextLoader`] - Reads context files from the local file system
+//! - [`DecisionLog`] - Append-only log of approved plans (`.quorum/decisions.jsonl`)
 //!
 //! # Usage
 //!
@@ -26,6 +27,9 @@
 //!
 //! [`ContextLoaderPort`]: quorum_application::ContextLoaderPort
 
+mod cache;
+mod decision_log;
 mod loader;
 
+pub use decision_log::DecisionLog;
 pub use loader::LocalContextLoader;
@@ -5,9 +5,12 @@
 //!
 //! These errors surface in every feature that uses the Copilot backend
 //! (Solo, Quorum Discussion, Ensemble Planning, Agent / Tool Use) and are
-//! mapped to [`GatewayError`](quorum_application::ports::llm_gateway::GatewayError)
-//! at the `CopilotSession` boundary.
+//! mapped to [`GatewayError`] at the `CopilotSession` boundary, where
+//! failures are classified so retry and fallback logic can tell auth errors,
+//! rate limits and timeouts apart.
 
+use quorum_application::ports::llm_gateway::GatewayError;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for Copilot operations.
@@ -84,3 +87,176 @@ impl CopilotError {
         matches!(self, CopilotError::Cancelled)
     }
 }
+
+/// Classify a Copilot failure into the port-level [`GatewayError`].
+///
+/// Copilot CLI reports backend failures as free-form text (usually inside a
+/// JSON-RPC error), so auth failures, rate limits, timeouts and missing
+/// models are recognised by their message. Anything unrecognised stays a
+/// catch-all [`GatewayError::RequestFailed`].
+impl From<CopilotError> for GatewayError {
+    fn from(err: CopilotError) -> Self {
+        match err {
+            CopilotError::Cancelled => GatewayError::Cancelled,
+            CopilotError::Timeout(_) => GatewayError::Timeout,
+            CopilotError::InvalidModel(msg) => GatewayError::ModelNotAvailable(msg),
+            CopilotError::TransportClosed | CopilotError::RouterStopped => {
+                GatewayError::TransportClosed
+            }
+            other => classify_failure_message(&other.to_string()),
+        }
+    }
+}
+
+/// Classify a backend failure message (see [`GatewayError::from`]).
+pub fn classify_failure_message(message: &str) -> GatewayError {
+    let lower = message.to_lowercase();
+    if has_status_code(&lower, "429")
+        || lower.contains("rate limit")
+        || lower.contains("rate-limit")
+        || lower.contains("too many requests")
+    {
+        GatewayError::RateLimited {
+            retry_after: parse_retry_after(&lower),
+        }
+    } else if has_status_code(&lower, "401")
+        || has_status_code(&lower, "403")
+        || lower.contains("unauthorized")
+        || lower.contains("not authenticated")
+        || lower.contains("authentication failed")
+        || lower.contains("forbidden")
+    {
+        GatewayError::Unauthorized(message.to_string())
+    } else if lower.contains("timed out") || lower.contains("timeout") {
+        GatewayError::Timeout
+    } else if lower.contains("model")
+        && (lower.contains("not available")
+            || lower.contains("not supported")
+            || lower.contains("not found"))
+    {
+        GatewayError::ModelNotAvailable(message.to_string())
+    } else {
+        GatewayError::RequestFailed(message.to_string())
+    }
+}
+
+/// Whether `code` appears in `message` as a standalone number (so `429` does
+/// not match inside `14290`).
+fn has_status_code(message: &str, code: &str) -> bool {
+    message
+        .split(|c: char| !c.is_ascii_digit())
+        .any(|token| token == code)
+}
+
+/// Extract the wait from phrases like "retry after 30 seconds",
+/// "Retry-After: 2" or "try again in 1.5s".
+fn parse_retry_after(lower: &str) -> Option<Duration> {
+    ["retry after", "retry-after", "try again in"]
+        .iter()
+        .find_map(|marker| {
+            let rest = &lower[lower.find(marker)? + marker.len()..];
+            let number: String = rest
+                .trim_start_matches([':', ' '])
+                .chars()
+                .take_while(|c| c.is_ascii_digit() || *c == '.')
+                .collect();
+            number.parse::<f64>().ok()
+        })
+        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classifies_rate_limit_with_retry_after() {
+        let err = GatewayError::from(CopilotError::RpcError {
+            code: -32000,
+            message: "429 Too Many Requests: retry after 30 seconds".to_string(),
+        });
+        match err {
+            GatewayError::RateLimited { retry_after } => {
+                assert_eq!(retry_after, Some(Duration::from_secs(30)))
+            }
+            other => panic!("expected RateLimited, got {other:?}"),
+        }
+
+        let err = classify_failure_message("Rate limit exceeded. Please try again in 1.5s");
+        assert_eq!(err.retry_after(), Some(Duration::from_millis(1500)));
+
+        let err = classify_failure_message("rate limit exceeded");
+        assert!(matches!(
+            err,
+            GatewayError::RateLimited { retry_after: None }
+        ));
+    }
+
+    #[test]
+    fn test_classifies_auth_failures() {
+        for message in [
+            "HTTP 401: Bad credentials",
+            "Unauthorized: token expired",
+            "Not authenticated. Run `copilot auth login`",
+            "403 Forbidden",
+        ] {
+            assert!(
+                matches!(
+                    classify_failure_message(message),
+                    GatewayError::Unauthorized(_)
+                ),
+                "{message}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_classifies_timeouts() {
+        assert!(matches!(
+            GatewayError::from(CopilotError::Timeout("session.send".to_string())),
+            GatewayError::Timeout
+        ));
+        assert!(matches!(
+            classify_failure_message("upstream request timed out"),
+            GatewayError::Timeout
+        ));
+    }
+
+    #[test]
+    fn test_classifies_unavailable_models() {
+        assert!(matches!(
+            GatewayError::from(CopilotError::InvalidModel("gpt-5.2-codex".to_string())),
+            GatewayError::ModelNotAvailable(_)
+        ));
+        assert!(matches!(
+            classify_failure_message("Model \"gpt-5.2-codex\" is not available."),
+            GatewayError::ModelNotAvailable(_)
+        ));
+    }
+
+    #[test]
+    fn test_preserves_cancellation_and_closed_transport() {
+        assert!(matches!(
+            GatewayError::from(CopilotError::Cancelled),
+            GatewayError::Cancelled
+        ));
+        assert!(matches!(
+            GatewayError::from(CopilotError::RouterStopped),
+            GatewayError::TransportClosed
+        ));
+    }
+
+    #[test]
+    fn test_unknown_failures_fall_back_to_request_failed() {
+        assert!(matches!(
+            classify_failure_message("502 Bad Gateway"),
+            GatewayError::RequestFailed(_)
+        ));
+        // A status code embedded in a longer number is not a match
+        assert!(matches!(
+            classify_failure_message("request id 14290 failed"),
+            GatewayError::RequestFailed(_)
+        ));
+    }
+}
@@ -13,9 +13,12 @@
 //!
 //! Internally, the gateway owns an [`Arc<MessageRouter>`](super::router::MessageRouter)
 //! which is shared with all sessions. The router handles the actual TCP
-//! communication and demultiplexing.
+//! communication and demultiplexing. An optional
+//! [`RateLimiter`](super::rate_limiter::RateLimiter) is shared the same way,
+//! so the request rate is bounded across every session the gateway creates.
 
 use crate::copilot::error::CopilotError;
+use crate::copilot::rate_limiter::RateLimiter;
 use crate::copilot::router::MessageRouter;
 use crate::copilot::session::CopilotSession;
 use async_trait::async_trait;
@@ -34,10 +37,13 @@ use tracing::info;
 /// preserve that distinction as [`GatewayError::ModelNotAvailable`] so callers
 /// (`/init`, Quorum Discussion, Plan Review) can surface a clear "利用不可モデル"
 /// hint instead of a generic session error that silently drops the model.
+/// Cancellation, auth failures, rate limits and timeouts keep their
+/// classified kind (so the routing gateway never falls back to another
+/// provider after the user cancelled); anything else is a session error.
 fn map_session_error(err: CopilotError) -> GatewayError {
-    match err {
-        CopilotError::InvalidModel(msg) => GatewayError::ModelNotAvailable(msg),
-        other => GatewayError::SessionError(other.to_string()),
+    match GatewayError::from(err) {
+        GatewayError::RequestFailed(msg) => GatewayError::SessionError(msg),
+        other => other,
     }
 }
 
@@ -79,6 +85,7 @@ fn known_available_models() -> Vec<Model> {
 /// A single gateway instance is shared across the entire application lifetime.
 pub struct CopilotLlmGateway {
     router: Arc<MessageRouter>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl CopilotLlmGateway {
@@ -93,7 +100,10 @@ impl CopilotLlmGateway {
 
         info!("CopilotLlmGateway initialized");
 
-        Ok(Self { router })
+        Ok(Self {
+            router,
+            rate_limiter: None,
+        })
     }
 
     /// Create a new gateway with a conversation logger for recording internal tool executions.
@@ -115,7 +125,10 @@ impl CopilotLlmGateway {
 
         info!("CopilotLlmGateway initialized (with conversation logger)");
 
-        Ok(Self { router })
+        Ok(Self {
+            router,
+            rate_limiter: None,
+        })
     }
 
     /// Create a gateway with a custom command (for testing)
@@ -124,12 +137,28 @@ impl CopilotLlmGateway {
             .await
             .map_err(|e| GatewayError::ConnectionError(e.to_string()))?;
 
-        Ok(Self { router })
+        Ok(Self {
+            router,
+            rate_limiter: None,
+        })
     }
 
     /// Create a gateway with a pre-built router (useful for shared test setups).
     pub fn with_router(router: Arc<MessageRouter>) -> Self {
-        Self { router }
+        Self {
+            router,
+            rate_limiter: None,
+        }
+    }
+
+    /// Limit prompts across all sessions to `max_rps` per second
+    /// (`None` = unlimited).
+    pub fn with_rate_limit(mut self, max_rps: Option<f64>) -> Self {
+        self.rate_limiter = max_rps.map(|rps| Arc::new(RateLimiter::new(rps)));
+        if let Some(rps) = max_rps {
+            info!("Copilot requests limited to {} per second", rps);
+        }
+        self
     }
 
     /// Get a reference to the underlying router
@@ -143,7 +172,8 @@ impl LlmGateway for CopilotLlmGateway {
     async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
         let session = CopilotSession::new(Arc::clone(&self.router), model.clone())
             .await
-            .map_err(map_session_error)?;
+            .map_err(map_session_error)?
+            .with_rate_limiter(self.rate_limiter.clone());
 
         Ok(Box::new(session))
     }
@@ -159,7 +189,8 @@ impl LlmGateway for CopilotLlmGateway {
             Some(system_prompt.to_string()),
         )
         .await
-        .map_err(map_session_error)?;
+        .map_err(map_session_error)?
+        .with_rate_limiter(self.rate_limiter.clone());
 
         Ok(Box::new(session))
     }
@@ -177,7 +208,8 @@ impl LlmGateway for CopilotLlmGateway {
             observer,
         )
         .await
-        .map_err(map_session_error)?;
+        .map_err(map_session_error)?
+        .with_rate_limiter(self.rate_limiter.clone());
 
         Ok(Box::new(session))
     }
@@ -193,7 +225,8 @@ impl LlmGateway for CopilotLlmGateway {
             Some(system_prompt.to_string()),
         )
         .await
-        .map_err(map_session_error)?;
+        .map_err(map_session_error)?
+        .with_rate_limiter(self.rate_limiter.clone());
 
         Ok(Box::new(session))
     }
@@ -218,6 +251,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn maps_cancelled_to_cancelled() {
+        let err = map_session_error(CopilotError::Cancelled);
+        assert!(matches!(err, GatewayError::Cancelled));
+    }
+
     #[test]
     fn maps_other_errors_to_session_error() {
         let err = map_session_error(CopilotError::SessionNotInitialized);
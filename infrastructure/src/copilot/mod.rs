@@ -40,6 +40,7 @@
 //! - [`gateway`] — Entry point implementing `LlmGateway`; shared by Solo / Ensemble / Quorum
 //! - [`session`] — Active conversation with an LLM implementing [`LlmSession`](quorum_application::ports::llm_gateway::LlmSession)
 //! - [`router`] — Transport demultiplexer for concurrent sessions (Quorum Discussion, Ensemble Planning)
+//! - [`rate_limiter`] — Token bucket shared by all sessions (`quorum.providers.copilot({ max_rps })`)
 //! - [`transport`] — Message classification types used by the router's background reader task
 //! - [`protocol`] — JSON-RPC message types and tool-call structures (Native Tool Use)
 //! - [`error`] — Error types for Copilot operations
@@ -47,6 +48,7 @@
 pub mod error;
 pub mod gateway;
 pub mod protocol;
+pub mod rate_limiter;
 pub mod router;
 pub mod session;
 pub mod transport;
@@ -0,0 +1,162 @@
+//! Request rate limiting shared by all Copilot sessions.
+//!
+//! Ensemble Planning can fire N² prompts at once, which trips backend rate
+//! limits. [`RateLimiter`] is a token bucket owned by the
+//! [`CopilotLlmGateway`](super::gateway::CopilotLlmGateway) and shared with
+//! every [`CopilotSession`](super::session::CopilotSession); each prompt
+//! takes a token before it reaches the [`MessageRouter`](super::router::MessageRouter).
+//!
+//! Configured via `quorum.providers.copilot({ max_rps = 2 })`.
+
+use crate::copilot::error::{CopilotError, Result};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+/// Token bucket allowing `max_rps` requests per second on average.
+///
+/// The bucket holds up to one second's worth of tokens (at least one), so a
+/// burst of `max_rps` requests goes out immediately and the rest are spaced
+/// `1 / max_rps` seconds apart. A token is only taken when a waiter is
+/// actually admitted, so dropping a pending [`acquire`](Self::acquire)
+/// future (or cancelling it) never leaks capacity.
+#[derive(Debug)]
+pub struct RateLimiter {
+    max_rps: f64,
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter for `max_rps` requests per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rps` is not a positive finite number.
+    pub fn new(max_rps: f64) -> Self {
+        assert!(
+            max_rps.is_finite() && max_rps > 0.0,
+            "max_rps must be positive, got {}",
+            max_rps
+        );
+        let capacity = max_rps.max(1.0);
+        Self {
+            max_rps,
+            capacity,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn max_rps(&self) -> f64 {
+        self.max_rps
+    }
+
+    /// Wait until a request may be sent.
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_take() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Like [`acquire`](Self::acquire), but gives up with
+    /// [`CopilotError::Cancelled`] as soon as `cancellation` fires.
+    pub async fn acquire_with_cancellation(&self, cancellation: &CancellationToken) -> Result<()> {
+        tokio::select! {
+            _ = cancellation.cancelled() => Err(CopilotError::Cancelled),
+            _ = self.acquire() => Ok(()),
+        }
+    }
+
+    /// Take a token, or report how long until one is available.
+    fn try_take(&self) -> std::result::Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.max_rps).min(self.capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.max_rps,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_simultaneous_acquires_are_spaced_out() {
+        // 10 rps: the first 10 go out at once, the remaining 5 are spaced
+        // 100ms apart, so all 15 need at least ~500ms.
+        let limiter = Arc::new(RateLimiter::new(10.0));
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..15)
+            .map(|_| {
+                let limiter = Arc::clone(&limiter);
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed >= Duration::from_millis(450),
+            "15 acquires at 10 rps finished in {:?}",
+            elapsed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_burst_within_capacity_is_immediate() {
+        let limiter = RateLimiter::new(5.0);
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_interrupts_waiting_acquire() {
+        let limiter = RateLimiter::new(0.1); // one request per 10 seconds
+        limiter.acquire().await;
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            canceller.cancel();
+        });
+
+        let start = Instant::now();
+        let result = limiter.acquire_with_cancellation(&token).await;
+
+        assert!(matches!(result, Err(CopilotError::Cancelled)));
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "max_rps must be positive")]
+    fn test_rejects_non_positive_rate() {
+        RateLimiter::new(0.0);
+    }
+}
@@ -27,6 +27,7 @@ use crate::copilot::transport::{MessageKind, StreamingOutcome, classify_message}
 use quorum_application::ports::conversation_logger::{
     ConversationEvent, ConversationLogger, NoConversationLogger,
 };
+use quorum_domain::session::response::TokenUsage;
 use std::collections::HashMap;
 use std::process::Stdio;
 use std::sync::Arc;
@@ -215,6 +216,9 @@ pub struct SessionChannel {
     /// Maps `toolCallId` → `toolName` from `tool.execution_start` events,
     /// used to resolve the tool name when `tool.execution_complete` arrives.
     tool_names: HashMap<String, String>,
+    /// Token usage from `assistant.usage` events since the last
+    /// [`take_usage`](Self::take_usage).
+    usage: Option<TokenUsage>,
 }
 
 /// Extract tool name directly from event fields (without toolCallId correlation).
@@ -245,6 +249,24 @@ fn extract_tool_name_from_event(event: &serde_json::Value) -> Option<&str> {
         })
 }
 
+/// Extract token counts from an `assistant.usage` event.
+///
+/// The Copilot CLI reports `data.inputTokens` / `data.outputTokens` once per
+/// model call; a missing field counts as zero, but an event with neither
+/// field yields `None`.
+fn extract_usage(event: &serde_json::Value) -> Option<TokenUsage> {
+    let data = event.get("data")?;
+    let prompt = data.get("inputTokens").and_then(|v| v.as_u64());
+    let completion = data.get("outputTokens").and_then(|v| v.as_u64());
+    if prompt.is_none() && completion.is_none() {
+        return None;
+    }
+    Some(TokenUsage::new(
+        prompt.unwrap_or(0),
+        completion.unwrap_or(0),
+    ))
+}
+
 /// Extract `data.toolCallId` from a tool event.
 fn extract_tool_call_id(event: &serde_json::Value) -> Option<&str> {
     event
@@ -281,6 +303,19 @@ fn parse_external_tool_requested(
 }
 
 impl SessionChannel {
+    /// Add the token counts of an `assistant.usage` event to the running total.
+    fn record_usage(&mut self, event: &serde_json::Value) {
+        if let Some(usage) = extract_usage(event) {
+            *self.usage.get_or_insert_with(TokenUsage::default) += usage;
+        }
+    }
+
+    /// Take the usage accumulated since the last call, or `None` if the CLI
+    /// reported none.
+    pub fn take_usage(&mut self) -> Option<TokenUsage> {
+        self.usage.take()
+    }
+
     /// Record tool name from a `tool.execution_start` event for later correlation.
     ///
     /// The Copilot CLI sends `toolName` in the start event but not in the
@@ -600,11 +635,14 @@ impl SessionChannel {
                         );
                         return Ok(StreamingOutcome::Idle(full_content));
                     }
+                    "assistant.usage" => {
+                        self.record_usage(&event);
+                        trace!("Tool stream: assistant.usage");
+                    }
                     // Known informational events
                     "pending_messages.modified"
                     | "user.message"
                     | "session.usage_info"
-                    | "assistant.usage"
                     | "assistant.reasoning"
                     | "tool.execution_partial_result"
                     | "permission.requested"
@@ -1503,6 +1541,7 @@ impl MessageRouter {
             router: Arc::clone(self),
             conversation_logger: Arc::clone(&self.conversation_logger),
             tool_names: HashMap::new(),
+            usage: None,
         };
 
         Ok((session_id, channel))
@@ -1843,6 +1882,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extract_usage_reads_token_counts() {
+        let event = serde_json::json!({
+            "type": "assistant.usage",
+            "data": { "model": "gpt-5", "inputTokens": 1200, "outputTokens": 340 }
+        });
+        assert_eq!(extract_usage(&event), Some(TokenUsage::new(1200, 340)));
+    }
+
+    #[test]
+    fn extract_usage_returns_none_without_counts() {
+        let event = serde_json::json!({
+            "type": "assistant.usage",
+            "data": { "model": "gpt-5" }
+        });
+        assert_eq!(extract_usage(&event), None);
+    }
+
     // Tests for extract_tool_name_from_event (Issue #181)
 
     #[test]
@@ -30,6 +30,7 @@ use crate::copilot::protocol::{
     CopilotToolDefinition, CreateSessionParams, JsonRpcRequest, JsonRpcResponseOut, SendParams,
     SystemMessageConfig, ToolCallResult,
 };
+use crate::copilot::rate_limiter::RateLimiter;
 use crate::copilot::router::{MessageRouter, SessionChannel};
 use crate::copilot::transport::StreamingOutcome;
 use async_trait::async_trait;
@@ -37,7 +38,7 @@ use quorum_application::ports::llm_gateway::{
     GatewayError, LlmSession, StreamObserver, ToolResultMessage,
 };
 use quorum_domain::Model;
-use quorum_domain::session::response::{ContentBlock, LlmResponse, StopReason};
+use quorum_domain::session::response::{ContentBlock, LlmResponse, StopReason, TokenUsage};
 use quorum_domain::util::truncate_str;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -112,6 +113,11 @@ pub struct CopilotSession {
     /// Injected at construction time via [`new_with_observer`](Self::new_with_observer),
     /// immutable thereafter (no Mutex needed).
     stream_observer: Option<StreamObserver>,
+    /// Created with `availableTools: []`; tool sessions keep the built-in
+    /// tools disabled and allow only the tools passed to `send_with_tools`.
+    builtin_tools_disabled: bool,
+    /// Gateway-wide limiter every prompt acquires from (None = unlimited).
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl CopilotSession {
@@ -155,6 +161,8 @@ impl CopilotSession {
             system_prompt,
             tool_session: Mutex::new(None),
             stream_observer: None,
+            builtin_tools_disabled: false,
+            rate_limiter: None,
         })
     }
 
@@ -166,7 +174,8 @@ impl CopilotSession {
     /// Sends `availableTools: []` to disable Copilot CLI built-in tools,
     /// preventing the model from executing commands during streaming sessions
     /// (e.g., Ensemble Planning, Quorum Discussion). User-defined tools
-    /// passed via `send_with_tools()` still work normally.
+    /// passed via `send_with_tools()` still work normally; they are the only
+    /// tools its tool sessions allow.
     pub async fn new_with_observer(
         router: Arc<MessageRouter>,
         model: Model,
@@ -199,6 +208,8 @@ impl CopilotSession {
             system_prompt,
             tool_session: Mutex::new(None),
             stream_observer: Some(observer),
+            builtin_tools_disabled: true,
+            rate_limiter: None,
         })
     }
 
@@ -238,9 +249,24 @@ impl CopilotSession {
             system_prompt,
             tool_session: Mutex::new(None),
             stream_observer: None,
+            builtin_tools_disabled: true,
+            rate_limiter: None,
         })
     }
 
+    /// Share the gateway's [`RateLimiter`] with this session.
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Wait for the rate limiter (if any) before sending to the router.
+    async fn throttle(&self) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+    }
+
     /// Returns the Copilot session ID.
     pub fn session_id(&self) -> &str {
         &self.session_id
@@ -274,6 +300,7 @@ impl CopilotSession {
 
         let request = JsonRpcRequest::new("session.send", Some(serde_json::to_value(&params)?));
 
+        self.throttle().await;
         let response = self.router.request(&request).await?;
 
         if let Some(error) = response.error {
@@ -324,6 +351,9 @@ impl CopilotSession {
 
         let request = JsonRpcRequest::new("session.send", Some(serde_json::to_value(&params)?));
 
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire_with_cancellation(&cancellation).await?;
+        }
         let response = self.router.request(&request).await?;
 
         if let Some(error) = response.error {
@@ -391,29 +421,17 @@ impl CopilotSession {
 
         if copilot_tools.is_empty() {
             warn!("No valid tools converted, falling back to text-only session");
-            let text = self
-                .ask(content)
-                .await
-                .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+            let text = self.ask(content).await.map_err(GatewayError::from)?;
             return Ok(LlmResponse::from_text(text));
         }
 
         // Create a new session with tools
-        let system_message = self
-            .system_prompt
-            .as_ref()
-            .map(|content| SystemMessageConfig {
-                mode: "append".to_string(),
-                content: content.clone(),
-            });
-
-        let params = CreateSessionParams {
-            model: Some(self.model.to_string()),
-            system_prompt: self.system_prompt.clone(),
-            system_message,
-            tools: Some(copilot_tools),
-            available_tools: None,
-        };
+        let params = tool_session_params(
+            &self.model,
+            self.system_prompt.as_ref(),
+            copilot_tools,
+            self.builtin_tools_disabled,
+        );
 
         let payload_size = serde_json::to_string(&params).map(|s| s.len()).unwrap_or(0);
         debug!("session.create payload: {} bytes", payload_size);
@@ -422,7 +440,7 @@ impl CopilotSession {
             .router
             .create_session(params)
             .await
-            .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+            .map_err(GatewayError::from)?;
 
         debug!("Tool session created: {}", tool_session_id);
 
@@ -444,17 +462,18 @@ impl CopilotSession {
             ),
         );
 
+        self.throttle().await;
         let response = self
             .router
             .request(&send_request)
             .await
-            .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+            .map_err(GatewayError::from)?;
 
         if let Some(error) = response.error {
-            return Err(GatewayError::RequestFailed(format!(
-                "RPC error ({}): {}",
-                error.code, error.message
-            )));
+            return Err(GatewayError::from(CopilotError::RpcError {
+                code: error.code,
+                message: error.message,
+            }));
         }
 
         // Read streaming and build response — forward chunks to observer if present
@@ -466,10 +485,12 @@ impl CopilotSession {
                 }
             })
             .await
-            .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+            .map_err(GatewayError::from)?;
 
+        let usage = tool_channel.take_usage();
         self.build_response_from_outcome(outcome, tool_session_id, tool_channel)
             .await
+            .map(|response| with_reported_usage(response, usage))
     }
 
     /// Build an [`LlmResponse`] from a streaming outcome, stashing tool session
@@ -493,6 +514,7 @@ impl CopilotSession {
                     content: vec![ContentBlock::Text(text)],
                     stop_reason: Some(StopReason::EndTurn),
                     model: Some(self.model.to_string()),
+                    usage: None,
                 })
             }
             StreamingOutcome::ToolCall {
@@ -592,6 +614,7 @@ fn build_tool_use_response(
         content,
         stop_reason: Some(StopReason::ToolUse),
         model: Some(model),
+        usage: None,
     }
 }
 
@@ -622,9 +645,7 @@ impl LlmSession for CopilotSession {
     }
 
     async fn send(&self, content: &str) -> std::result::Result<String, GatewayError> {
-        self.ask(content)
-            .await
-            .map_err(|e| GatewayError::RequestFailed(e.to_string()))
+        self.ask(content).await.map_err(GatewayError::from)
     }
 
     // send_streaming: uses default impl (delegates to send())
@@ -653,6 +674,9 @@ impl LlmSession for CopilotSession {
             GatewayError::RequestFailed("No pending tool call to respond to".to_string())
         })?;
 
+        // Tool results start the model's next turn, so they count as requests
+        self.throttle().await;
+
         let first = results.first();
         let first_tool_name = first.map(|r| r.tool_name.as_str()).unwrap_or("unknown");
         let first_output_bytes = first.map(|r| r.output.len()).unwrap_or(0);
@@ -675,7 +699,7 @@ impl LlmSession for CopilotSession {
                 self.router
                     .send_response(&response)
                     .await
-                    .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+                    .map_err(GatewayError::from)?;
 
                 debug!(
                     "Tool result sent for request_id={}: tool={}, type={}, output_bytes={}",
@@ -696,7 +720,7 @@ impl LlmSession for CopilotSession {
                 self.router
                     .respond_to_external_tool(&session_id, &request_id, outcome)
                     .await
-                    .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+                    .map_err(GatewayError::from)?;
 
                 debug!(
                     "External tool result sent for request_id={}: tool={}, output_bytes={}",
@@ -715,15 +739,17 @@ impl LlmSession for CopilotSession {
                 }
             })
             .await
-            .map_err(|e| GatewayError::RequestFailed(e.to_string()))?;
+            .map_err(GatewayError::from)?;
 
-        match outcome {
+        let usage = state.channel.take_usage();
+        let response = match outcome {
             StreamingOutcome::Idle(text) => {
                 debug!("Tool session idle, text response received");
                 Ok(LlmResponse {
                     content: vec![ContentBlock::Text(text)],
                     stop_reason: Some(StopReason::EndTurn),
                     model: Some(self.model.to_string()),
+                    usage: None,
                 })
             }
             StreamingOutcome::ToolCall {
@@ -770,7 +796,43 @@ impl LlmSession for CopilotSession {
                     self.model.to_string(),
                 ))
             }
-        }
+        };
+        response.map(|response| with_reported_usage(response, usage))
+    }
+}
+
+/// Attach the usage collected from `assistant.usage` events, if any.
+fn with_reported_usage(response: LlmResponse, usage: Option<TokenUsage>) -> LlmResponse {
+    match usage {
+        Some(usage) => response.with_usage(usage),
+        None => response,
+    }
+}
+
+/// `session.create` params for the tool session behind `send_with_tools`.
+///
+/// With `builtin_tools_disabled` (text-only and streaming sessions, e.g.
+/// reviewers) only `tools` are allowed, so the model cannot fall back on the
+/// CLI's built-in shell and file tools.
+fn tool_session_params(
+    model: &Model,
+    system_prompt: Option<&String>,
+    tools: Vec<CopilotToolDefinition>,
+    builtin_tools_disabled: bool,
+) -> CreateSessionParams {
+    let system_message = system_prompt.map(|content| SystemMessageConfig {
+        mode: "append".to_string(),
+        content: content.clone(),
+    });
+    let available_tools =
+        builtin_tools_disabled.then(|| tools.iter().map(|t| t.name.clone()).collect());
+
+    CreateSessionParams {
+        model: Some(model.to_string()),
+        system_prompt: system_prompt.cloned(),
+        system_message,
+        tools: Some(tools),
+        available_tools,
     }
 }
 
@@ -788,6 +850,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reviewer_tool_session_allows_only_submit_review() {
+        let review_tool = quorum_domain::AgentPromptTemplate::review_tool_schema();
+        let tools: Vec<CopilotToolDefinition> = CopilotToolDefinition::from_api_tool(&review_tool)
+            .into_iter()
+            .collect();
+        let prompt = "You are a reviewer".to_string();
+
+        let params =
+            tool_session_params(&Model::ClaudeSonnet45, Some(&prompt), tools.clone(), true);
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["availableTools"], serde_json::json!(["submit_review"]));
+        assert_eq!(json["tools"][0]["name"], "submit_review");
+
+        // Agent sessions keep the CLI defaults
+        let params = tool_session_params(&Model::ClaudeSonnet45, Some(&prompt), tools, false);
+        assert!(params.available_tools.is_none());
+    }
+
     #[test]
     fn external_outcome_success() {
         let results = vec![msg("plan saved", false, false)];
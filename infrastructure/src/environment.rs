@@ -0,0 +1,16 @@
+//! Host environment probes.
+//!
+//! Detects the external CLIs the adapters shell out to, for diagnostics
+//! such as `--about`.
+
+use quorum_application::ExternalClis;
+
+/// Check which external CLIs are on `PATH` (presence only, no auth check).
+pub fn detect_external_clis() -> ExternalClis {
+    ExternalClis {
+        gh: which::which("gh").is_ok(),
+        copilot: which::which("copilot").is_ok(),
+        glab: which::which("glab").is_ok(),
+        jira: which::which("jira").is_ok(),
+    }
+}
@@ -7,6 +7,7 @@ pub mod clipboard;
 pub mod config;
 pub mod context;
 pub mod copilot;
+pub mod environment;
 pub mod logging;
 pub mod providers;
 pub mod reference;
@@ -17,23 +18,34 @@ pub mod tools;
 
 // Re-export commonly used types
 pub use clipboard::{ArboardClipboard, FallbackClipboard, Osc52Clipboard};
-pub use context::LocalContextLoader;
+pub use context::{DecisionLog, LocalContextLoader};
 pub use copilot::{
     error::{CopilotError, Result},
     gateway::CopilotLlmGateway,
     router::MessageRouter,
     session::CopilotSession,
 };
-pub use logging::JsonlConversationLogger;
+pub use environment::detect_external_clis;
+pub use logging::{
+    JsonlConversationLogger, RedactingConversationLogger, Redactor, read_conversation_log,
+};
 #[cfg(feature = "bedrock")]
 pub use providers::bedrock::BedrockProviderAdapter;
 pub use providers::{
     ProviderAdapter, ProviderKind, copilot_adapter::CopilotProviderAdapter, routing::RoutingGateway,
 };
-pub use reference::GitHubReferenceResolver;
+#[cfg(feature = "gitlab")]
+pub use reference::GitLabReferenceResolver;
+#[cfg(feature = "jira")]
+pub use reference::JiraReferenceResolver;
+pub use reference::{
+    CachingReferenceResolver, GitHubReferenceResolver, LocalFileReferenceResolver,
+    RoutingReferenceResolver,
+};
 #[cfg(feature = "scripting")]
 pub use scripting::LuaScriptingEngine;
 pub use supervisor::HerdrReporterAdapter;
 pub use tools::{
-    JsonSchemaToolConverter, LocalToolExecutor, default_tool_spec, read_only_tool_spec,
+    DryRunToolExecutor, JsonSchemaToolConverter, LocalToolExecutor, default_tool_spec,
+    read_only_tool_spec,
 };
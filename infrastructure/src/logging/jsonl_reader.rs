@@ -0,0 +1,121 @@
+//! JSONL reader for conversation logs written by
+//! [`JsonlConversationLogger`](super::JsonlConversationLogger).
+//!
+//! Each line is parsed back into a [`ConversationRecord`]. Malformed lines
+//! (truncated writes after a crash, hand edits) are skipped with a warning so
+//! the rest of the transcript stays readable.
+
+use quorum_application::ConversationRecord;
+use serde_json::Value;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use tracing::warn;
+
+/// Read every event from the conversation log at `path`.
+///
+/// Fails only if the file cannot be opened or read.
+pub fn read_conversation_log(path: impl AsRef<Path>) -> io::Result<Vec<ConversationRecord>> {
+    let path = path.as_ref();
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_record(&line) {
+            Some(record) => records.push(record),
+            None => warn!(
+                "Skipping malformed line {} in {}",
+                index + 1,
+                path.display()
+            ),
+        }
+    }
+    Ok(records)
+}
+
+/// Parse one JSONL line; `None` if it is not an object with a string `type`.
+fn parse_record(line: &str) -> Option<ConversationRecord> {
+    let Value::Object(mut map) = serde_json::from_str(line).ok()? else {
+        return None;
+    };
+    let Value::String(event_type) = map.remove("type")? else {
+        return None;
+    };
+    let timestamp = match map.remove("timestamp") {
+        Some(Value::String(ts)) => Some(ts),
+        _ => None,
+    };
+    // Non-object payloads are logged under "data"
+    let payload = match map.remove("data") {
+        Some(data) if map.is_empty() => data,
+        Some(data) => {
+            map.insert("data".to_string(), data);
+            Value::Object(map)
+        }
+        None => Value::Object(map),
+    };
+    Some(ConversationRecord {
+        event_type,
+        timestamp,
+        payload,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::JsonlConversationLogger;
+    use quorum_application::{ConversationEvent, ConversationLogger};
+    use std::io::Write;
+
+    #[test]
+    fn test_round_trips_logged_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.conversation.jsonl");
+        let logger = JsonlConversationLogger::new(&path).unwrap();
+        logger.log(ConversationEvent::new(
+            "agent_start",
+            serde_json::json!({ "request": "Fix the login bug" }),
+        ));
+        logger.log(ConversationEvent::new(
+            "simple_event",
+            serde_json::json!("just a string"),
+        ));
+        drop(logger);
+
+        let records = read_conversation_log(&path).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].event_type, "agent_start");
+        assert_eq!(records[0].payload["request"], "Fix the login bug");
+        assert!(records[0].timestamp.is_some());
+        assert!(records[0].payload.get("type").is_none());
+        assert_eq!(records[1].payload, serde_json::json!("just a string"));
+    }
+
+    #[test]
+    fn test_skips_malformed_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.conversation.jsonl");
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, r#"{{"type":"ask_response","text":"42"}}"#).unwrap();
+        writeln!(file, r#"{{"type":"ask_resp"#).unwrap();
+        writeln!(file, r#"{{"no_type":true}}"#).unwrap();
+        writeln!(file, r#"[1, 2, 3]"#).unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, r#"{{"type":"agent_complete","summary":"done"}}"#).unwrap();
+        drop(file);
+
+        let records = read_conversation_log(&path).unwrap();
+        let types: Vec<_> = records.iter().map(|r| r.event_type.as_str()).collect();
+        assert_eq!(types, vec!["ask_response", "agent_complete"]);
+    }
+
+    #[test]
+    fn test_missing_file_is_an_error() {
+        assert!(read_conversation_log("/nonexistent/session.conversation.jsonl").is_err());
+    }
+}
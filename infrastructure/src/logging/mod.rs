@@ -1,8 +1,15 @@
 //! Logging infrastructure — structured conversation logging.
 //!
 //! Provides [`JsonlConversationLogger`], a JSONL file writer that implements
-//! the [`ConversationLogger`](quorum_application::ConversationLogger) port.
+//! the [`ConversationLogger`](quorum_application::ConversationLogger) port,
+//! [`RedactingConversationLogger`], a decorator that strips secrets before
+//! they are written, and [`read_conversation_log`] to load such a file back
+//! (`--replay`).
 
 mod jsonl_logger;
+mod jsonl_reader;
+mod redacting_logger;
 
 pub use jsonl_logger::JsonlConversationLogger;
+pub use jsonl_reader::read_conversation_log;
+pub use redacting_logger::{RedactingConversationLogger, Redactor};
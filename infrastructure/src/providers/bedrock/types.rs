@@ -65,6 +65,7 @@ pub fn convert_converse_output(
         content,
         stop_reason: Some(convert_stop_reason(stop_reason)),
         model: Some(model_id.to_string()),
+        usage: None,
     }
 }
 
@@ -178,8 +179,8 @@ pub fn convert_converse_error(
     match err {
         aws_sdk_bedrockruntime::error::SdkError::ServiceError(service_err) => {
             match service_err.err() {
-                ConverseError::ThrottlingException(e) => {
-                    GatewayError::RequestFailed(format!("Bedrock throttled: {}", e))
+                ConverseError::ThrottlingException(_) => {
+                    GatewayError::RateLimited { retry_after: None }
                 }
                 ConverseError::ModelNotReadyException(e) => {
                     GatewayError::ModelNotAvailable(format!("Bedrock model not ready: {}", e))
@@ -188,6 +189,9 @@ pub fn convert_converse_error(
                     GatewayError::RequestFailed(format!("Bedrock validation error: {}", e))
                 }
                 ConverseError::ModelTimeoutException(_) => GatewayError::Timeout,
+                ConverseError::AccessDeniedException(e) => {
+                    GatewayError::Unauthorized(format!("Bedrock access denied: {}", e))
+                }
                 other => GatewayError::RequestFailed(format!("Bedrock error: {:?}", other)),
             }
         }
@@ -1,7 +1,9 @@
 use super::{ProviderAdapter, ProviderKind};
 use crate::CopilotLlmGateway;
 use async_trait::async_trait;
-use quorum_application::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession};
+use quorum_application::ports::llm_gateway::{
+    GatewayError, LlmGateway, LlmSession, StreamObserver,
+};
 use quorum_domain::Model;
 
 pub struct CopilotProviderAdapter {
@@ -53,6 +55,17 @@ impl ProviderAdapter for CopilotProviderAdapter {
             .await
     }
 
+    async fn create_streaming_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+        observer: StreamObserver,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        self.inner
+            .create_streaming_session(model, system_prompt, observer)
+            .await
+    }
+
     async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
         self.inner.available_models().await
     }
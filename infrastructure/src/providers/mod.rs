@@ -5,7 +5,7 @@ pub mod routing;
 pub mod bedrock;
 
 use async_trait::async_trait;
-use quorum_application::ports::llm_gateway::{GatewayError, LlmSession};
+use quorum_application::ports::llm_gateway::{GatewayError, LlmSession, StreamObserver};
 use quorum_domain::Model;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -33,5 +33,17 @@ pub trait ProviderAdapter: Send + Sync {
         model: &Model,
         system_prompt: &str,
     ) -> Result<Box<dyn LlmSession>, GatewayError>;
+    /// Text-only session that reports each chunk to `observer`.
+    ///
+    /// Providers without streaming support fall back to a text-only session
+    /// and never call the observer.
+    async fn create_streaming_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+        _observer: StreamObserver,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        self.create_text_only_session(model, system_prompt).await
+    }
     async fn available_models(&self) -> Result<Vec<Model>, GatewayError>;
 }
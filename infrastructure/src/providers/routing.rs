@@ -1,50 +1,69 @@
 use super::{ProviderAdapter, ProviderKind};
 use async_trait::async_trait;
-use quorum_application::ports::llm_gateway::{GatewayError, LlmGateway, LlmSession};
+use futures::future::BoxFuture;
+use quorum_application::ports::llm_gateway::{
+    GatewayError, LlmGateway, LlmSession, StreamObserver,
+};
 use quorum_domain::{Model, ProviderConfig};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::warn;
 
 pub struct RoutingGateway {
     providers: Vec<Arc<dyn ProviderAdapter>>,
     /// Model name to provider name mapping, used for routing requests to the correct provider
     /// based on the model specified in the request.
     explicit_model_routing: HashMap<String, usize>,
+    /// Model name to ordered provider indices tried when the routed provider
+    /// fails to create a session (config `quorum.providers.fallback`).
+    model_fallbacks: HashMap<String, Vec<usize>>,
     default_kind: ProviderKind,
 }
 
+/// Parse a provider name from config into a [`ProviderKind`].
+fn parse_provider_kind(name: &str) -> Option<ProviderKind> {
+    match name {
+        "copilot" => Some(ProviderKind::Copilot),
+        "anthropic" => Some(ProviderKind::Anthropic),
+        "openai" => Some(ProviderKind::OpenAi),
+        "bedrock" => Some(ProviderKind::Bedrock),
+        "azure" => Some(ProviderKind::Azure),
+        _ => None,
+    }
+}
+
 impl RoutingGateway {
     pub fn new(providers: Vec<Arc<dyn ProviderAdapter>>, config: &ProviderConfig) -> Self {
-        let mut explicit_model_routing = HashMap::new();
+        let position = |name: &str| {
+            let kind = parse_provider_kind(name)?;
+            providers.iter().position(|p| p.kind() == kind)
+        };
 
+        let mut explicit_model_routing = HashMap::new();
         for (model_name, provider_name) in &config.routing {
-            // provider_name
-            // からProviderKindを特定するロジックprovidersの中からprovider_nameに一致するものを探す
-            let target_kind = match provider_name.as_str() {
-                "copilot" => ProviderKind::Copilot,
-                "anthropic" => ProviderKind::Anthropic,
-                "openai" => ProviderKind::OpenAi,
-                "bedrock" => ProviderKind::Bedrock,
-                "azure" => ProviderKind::Azure,
-                _ => continue, // Skip unknown provider names
-            };
-
-            if let Some(idx) = providers.iter().position(|p| p.kind() == target_kind) {
+            // Unknown or unregistered provider names are skipped
+            if let Some(idx) = position(provider_name) {
                 explicit_model_routing.insert(model_name.clone(), idx);
             }
         }
 
+        let mut model_fallbacks = HashMap::new();
+        for (model_name, provider_names) in &config.routing_fallback {
+            let indices: Vec<usize> = provider_names.iter().filter_map(|n| position(n)).collect();
+            if !indices.is_empty() {
+                model_fallbacks.insert(model_name.clone(), indices);
+            }
+        }
+
         Self {
             providers,
             explicit_model_routing,
-            default_kind: match config.default.as_deref().unwrap_or("copilot") {
-                "copilot" => ProviderKind::Copilot,
-                "anthropic" => ProviderKind::Anthropic,
-                "openai" => ProviderKind::OpenAi,
-                "bedrock" => ProviderKind::Bedrock,
-                "azure" => ProviderKind::Azure,
-                _ => ProviderKind::Copilot,
-            },
+            model_fallbacks,
+            default_kind: config
+                .default
+                .as_deref()
+                .and_then(parse_provider_kind)
+                .unwrap_or(ProviderKind::Copilot),
         }
     }
     /// モデルに基づいて適切なプロバイダーを解決するロジック
@@ -92,12 +111,75 @@ impl RoutingGateway {
                 "No providers available".to_string(),
             ))
     }
+
+    /// Providers to try for `model`, in order: the one chosen by
+    /// [`resolve_provider`](Self::resolve_provider), then the model's
+    /// configured fallbacks that [`supports_model`](ProviderAdapter::supports_model).
+    fn candidate_providers(
+        &self,
+        model: &Model,
+    ) -> Result<Vec<&dyn ProviderAdapter>, GatewayError> {
+        let primary = self.resolve_provider(model)?;
+        let mut candidates = vec![primary];
+        for &idx in self
+            .model_fallbacks
+            .get(model.as_str())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+        {
+            let provider = self.providers[idx].as_ref();
+            let already_listed = candidates.iter().any(|c| c.kind() == provider.kind());
+            if !already_listed && provider.supports_model(model) {
+                candidates.push(provider);
+            }
+        }
+        Ok(candidates)
+    }
+
+    /// Run `create` against each candidate provider until one succeeds.
+    ///
+    /// Each failure before the last candidate is logged and the next provider
+    /// is tried; if all fail, the last error is returned.
+    /// [`GatewayError::Cancelled`] is returned immediately — a cancelled
+    /// request must not be retried elsewhere.
+    async fn create_with_fallback<'a, F>(
+        &'a self,
+        model: &Model,
+        create: F,
+    ) -> Result<Box<dyn LlmSession>, GatewayError>
+    where
+        F: Fn(&'a dyn ProviderAdapter) -> BoxFuture<'a, Result<Box<dyn LlmSession>, GatewayError>>
+            + Send,
+    {
+        let candidates = self.candidate_providers(model)?;
+        let mut last_error = None;
+        for (i, provider) in candidates.iter().enumerate() {
+            match create(*provider).await {
+                Ok(session) => return Ok(session),
+                Err(GatewayError::Cancelled) => return Err(GatewayError::Cancelled),
+                Err(e) => {
+                    if let Some(next) = candidates.get(i + 1) {
+                        warn!(
+                            "Provider {:?} failed to create session for {}: {}; falling back to {:?}",
+                            provider.kind(),
+                            model,
+                            e,
+                            next.kind()
+                        );
+                    }
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.expect("candidate_providers never returns an empty list"))
+    }
 }
 
 #[async_trait]
 impl LlmGateway for RoutingGateway {
     async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
-        self.resolve_provider(model)?.create_session(model).await
+        self.create_with_fallback(model, |p| p.create_session(model))
+            .await
     }
 
     async fn create_session_with_system_prompt(
@@ -105,9 +187,10 @@ impl LlmGateway for RoutingGateway {
         model: &Model,
         system_prompt: &str,
     ) -> Result<Box<dyn LlmSession>, GatewayError> {
-        self.resolve_provider(model)?
-            .create_session_with_system_prompt(model, system_prompt)
-            .await
+        self.create_with_fallback(model, |p| {
+            p.create_session_with_system_prompt(model, system_prompt)
+        })
+        .await
     }
 
     async fn create_text_only_session(
@@ -115,17 +198,28 @@ impl LlmGateway for RoutingGateway {
         model: &Model,
         system_prompt: &str,
     ) -> Result<Box<dyn LlmSession>, GatewayError> {
-        self.resolve_provider(model)?
-            .create_text_only_session(model, system_prompt)
+        self.create_with_fallback(model, |p| p.create_text_only_session(model, system_prompt))
             .await
     }
 
+    async fn create_streaming_session(
+        &self,
+        model: &Model,
+        system_prompt: &str,
+        observer: StreamObserver,
+    ) -> Result<Box<dyn LlmSession>, GatewayError> {
+        self.create_with_fallback(model, |p| {
+            p.create_streaming_session(model, system_prompt, observer.clone())
+        })
+        .await
+    }
+
+    /// Models of every provider. Fails if any provider cannot list its
+    /// models, so a partial list is never mistaken for the full one.
     async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
         let mut all_models = Vec::new();
         for provider in &self.providers {
-            if let Ok(models) = provider.available_models().await {
-                all_models.extend(models);
-            }
+            all_models.extend(provider.available_models().await?);
         }
         Ok(all_models)
     }
@@ -196,8 +290,172 @@ mod tests {
         }
     }
 
+    // -- Scripted ProviderAdapter (fallback tests) -----------------------------
+
+    #[derive(Clone, Copy)]
+    enum Outcome {
+        Succeed,
+        Fail,
+        Cancel,
+    }
+
+    struct NullSession(Model);
+
+    #[async_trait]
+    impl LlmSession for NullSession {
+        fn model(&self) -> &Model {
+            &self.0
+        }
+
+        async fn send(&self, _content: &str) -> Result<String, GatewayError> {
+            Ok(String::new())
+        }
+    }
+
+    struct ScriptedProvider {
+        kind: ProviderKind,
+        outcome: Outcome,
+        supports: bool,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ScriptedProvider {
+        fn new(kind: ProviderKind, outcome: Outcome) -> Arc<Self> {
+            Arc::new(Self {
+                kind,
+                outcome,
+                supports: true,
+                calls: Default::default(),
+            })
+        }
+
+        fn unsupported(kind: ProviderKind) -> Arc<Self> {
+            Arc::new(Self {
+                kind,
+                outcome: Outcome::Succeed,
+                supports: false,
+                calls: Default::default(),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+
+        fn respond(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            match self.outcome {
+                Outcome::Succeed => Ok(Box::new(NullSession(model.clone()))),
+                Outcome::Fail => Err(GatewayError::SessionError(format!("{:?} down", self.kind))),
+                Outcome::Cancel => Err(GatewayError::Cancelled),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ProviderAdapter for ScriptedProvider {
+        fn kind(&self) -> ProviderKind {
+            self.kind.clone()
+        }
+
+        fn supports_model(&self, _model: &Model) -> bool {
+            self.supports
+        }
+
+        async fn create_session(&self, model: &Model) -> Result<Box<dyn LlmSession>, GatewayError> {
+            self.respond(model)
+        }
+
+        async fn create_session_with_system_prompt(
+            &self,
+            model: &Model,
+            _system_prompt: &str,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            self.respond(model)
+        }
+
+        async fn create_text_only_session(
+            &self,
+            model: &Model,
+            _system_prompt: &str,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            self.respond(model)
+        }
+
+        async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
+            match self.outcome {
+                Outcome::Fail => Err(GatewayError::SessionError(format!("{:?} down", self.kind))),
+                Outcome::Succeed | Outcome::Cancel => Ok(vec![]),
+            }
+        }
+    }
+
+    /// Provider whose only working session type is streaming; it reports a
+    /// chunk to the observer as soon as the session is created.
+    struct StreamingProvider;
+
+    #[async_trait]
+    impl ProviderAdapter for StreamingProvider {
+        fn kind(&self) -> ProviderKind {
+            ProviderKind::Copilot
+        }
+
+        fn supports_model(&self, _model: &Model) -> bool {
+            true
+        }
+
+        async fn create_session(
+            &self,
+            _model: &Model,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            Err(GatewayError::RequestFailed("built-in tools".into()))
+        }
+
+        async fn create_session_with_system_prompt(
+            &self,
+            _model: &Model,
+            _system_prompt: &str,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            Err(GatewayError::RequestFailed("built-in tools".into()))
+        }
+
+        async fn create_text_only_session(
+            &self,
+            _model: &Model,
+            _system_prompt: &str,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            Err(GatewayError::RequestFailed("no observer".into()))
+        }
+
+        async fn create_streaming_session(
+            &self,
+            model: &Model,
+            _system_prompt: &str,
+            observer: StreamObserver,
+        ) -> Result<Box<dyn LlmSession>, GatewayError> {
+            observer("chunk");
+            Ok(Box::new(NullSession(model.clone())))
+        }
+
+        async fn available_models(&self) -> Result<Vec<Model>, GatewayError> {
+            Ok(vec![])
+        }
+    }
+
     // -- Helpers ---------------------------------------------------------------
 
+    fn config_with_fallback(model: &str, providers: &[&str]) -> ProviderConfig {
+        let mut routing_fallback = HashMap::new();
+        routing_fallback.insert(
+            model.to_string(),
+            providers.iter().map(|p| p.to_string()).collect(),
+        );
+        ProviderConfig {
+            routing_fallback,
+            ..Default::default()
+        }
+    }
+
     fn default_config() -> ProviderConfig {
         ProviderConfig::default()
     }
@@ -331,4 +589,142 @@ mod tests {
         assert!(models.contains(&Model::Gpt41));
         assert!(models.contains(&Model::ClaudeSonnet45));
     }
+
+    #[tokio::test]
+    async fn available_models_fails_when_a_provider_fails() {
+        let providers: Vec<Arc<dyn ProviderAdapter>> = vec![
+            MockProvider::with_models(ProviderKind::Copilot, vec![Model::Gpt41]),
+            ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Fail),
+        ];
+        let gw = RoutingGateway::new(providers, &default_config());
+
+        assert!(matches!(
+            gw.available_models().await,
+            Err(GatewayError::SessionError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn streaming_session_reaches_provider_with_observer() {
+        let gw = RoutingGateway::new(vec![Arc::new(StreamingProvider)], &default_config());
+        let chunks = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let sink = chunks.clone();
+        let observer: StreamObserver = Arc::new(move |chunk| {
+            sink.lock().unwrap().push(chunk.to_string());
+        });
+
+        let session = gw
+            .create_streaming_session(&Model::ClaudeSonnet45, "sys", observer)
+            .await
+            .unwrap();
+        assert_eq!(session.model(), &Model::ClaudeSonnet45);
+        assert_eq!(*chunks.lock().unwrap(), vec!["chunk"]);
+    }
+
+    // -- Fallback tests ----------------------------------------------------------
+
+    #[tokio::test]
+    async fn falls_back_to_next_provider_when_primary_fails() {
+        let primary = ScriptedProvider::new(ProviderKind::Copilot, Outcome::Fail);
+        let backup = ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Succeed);
+        let gw = RoutingGateway::new(
+            vec![primary.clone(), backup.clone()],
+            &config_with_fallback("gemini-3-pro-preview", &["anthropic"]),
+        );
+
+        let session = gw.create_session(&Model::Gemini3Pro).await.unwrap();
+        assert_eq!(session.model(), &Model::Gemini3Pro);
+        assert_eq!(primary.calls(), 1);
+        assert_eq!(backup.calls(), 1);
+
+        gw.create_session_with_system_prompt(&Model::Gemini3Pro, "sys")
+            .await
+            .unwrap();
+        gw.create_text_only_session(&Model::Gemini3Pro, "sys")
+            .await
+            .unwrap();
+        assert_eq!(primary.calls(), 3);
+        assert_eq!(backup.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn no_fallback_without_config() {
+        let primary = ScriptedProvider::new(ProviderKind::Copilot, Outcome::Fail);
+        let backup = ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Succeed);
+        let gw = RoutingGateway::new(vec![primary.clone(), backup.clone()], &default_config());
+
+        let result = gw.create_session(&Model::Gemini3Pro).await;
+        assert!(matches!(result, Err(GatewayError::SessionError(_))));
+        assert_eq!(backup.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn returns_last_error_when_all_providers_fail() {
+        let primary = ScriptedProvider::new(ProviderKind::Copilot, Outcome::Fail);
+        let backup = ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Fail);
+        let gw = RoutingGateway::new(
+            vec![primary.clone(), backup.clone()],
+            &config_with_fallback("gemini-3-pro-preview", &["anthropic"]),
+        );
+
+        match gw.create_session(&Model::Gemini3Pro).await {
+            Err(GatewayError::SessionError(msg)) => assert!(msg.contains("Anthropic")),
+            Err(other) => panic!("expected last SessionError, got {other:?}"),
+            Ok(_) => panic!("expected failure"),
+        }
+        assert_eq!(primary.calls(), 1);
+        assert_eq!(backup.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn cancellation_is_not_masked_by_fallback() {
+        let primary = ScriptedProvider::new(ProviderKind::Copilot, Outcome::Cancel);
+        let backup = ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Succeed);
+        let gw = RoutingGateway::new(
+            vec![primary.clone(), backup.clone()],
+            &config_with_fallback("gemini-3-pro-preview", &["anthropic"]),
+        );
+
+        let result = gw.create_session(&Model::Gemini3Pro).await;
+        assert!(matches!(result, Err(GatewayError::Cancelled)));
+        assert_eq!(backup.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn fallback_skips_providers_that_do_not_support_model() {
+        let primary = ScriptedProvider::new(ProviderKind::Copilot, Outcome::Fail);
+        let unsupported = ScriptedProvider::unsupported(ProviderKind::Bedrock);
+        let backup = ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Succeed);
+        let gw = RoutingGateway::new(
+            vec![primary.clone(), unsupported.clone(), backup.clone()],
+            &config_with_fallback("gemini-3-pro-preview", &["bedrock", "anthropic"]),
+        );
+
+        gw.create_session(&Model::Gemini3Pro).await.unwrap();
+        assert_eq!(unsupported.calls(), 0);
+        assert_eq!(backup.calls(), 1);
+    }
+
+    #[test]
+    fn fallback_ignores_unknown_and_primary_providers() {
+        let providers: Vec<Arc<dyn ProviderAdapter>> = vec![
+            ScriptedProvider::new(ProviderKind::Copilot, Outcome::Fail),
+            ScriptedProvider::new(ProviderKind::Anthropic, Outcome::Succeed),
+        ];
+        let gw = RoutingGateway::new(
+            providers,
+            &config_with_fallback(
+                "gemini-3-pro-preview",
+                &["nonexistent", "copilot", "anthropic"],
+            ),
+        );
+
+        let kinds: Vec<_> = gw
+            .candidate_providers(&Model::Gemini3Pro)
+            .unwrap()
+            .iter()
+            .map(|p| p.kind())
+            .collect();
+        assert_eq!(kinds, vec![ProviderKind::Copilot, ProviderKind::Anthropic]);
+    }
 }
@@ -0,0 +1,217 @@
+//! Session-scoped cache for resolved references.
+//!
+//! [`CachingReferenceResolver`] wraps any [`ReferenceResolverPort`] so that
+//! mentioning `#42` several times in one session invokes `gh` (or `glab`,
+//! `jira`) only once per TTL.
+
+use async_trait::async_trait;
+use quorum_application::ports::reference_resolver::{
+    ReferenceError, ReferenceResolverPort, ResolvedReference,
+};
+use quorum_domain::ResourceReference;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// How long failures stay cached by default (capped at the success TTL).
+const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(30);
+
+struct CacheEntry {
+    result: Result<ResolvedReference, ReferenceError>,
+    expires_at: Instant,
+}
+
+/// In-memory TTL cache decorator for a [`ReferenceResolverPort`].
+///
+/// Successful resolutions are kept for `ttl`; failures are kept for the
+/// (shorter) negative TTL so a broken CLI is not hammered on every mention.
+/// A zero TTL disables the corresponding cache.
+pub struct CachingReferenceResolver {
+    inner: Arc<dyn ReferenceResolverPort>,
+    ttl: Duration,
+    negative_ttl: Duration,
+    entries: Mutex<HashMap<ResourceReference, CacheEntry>>,
+}
+
+impl CachingReferenceResolver {
+    pub fn new(inner: Arc<dyn ReferenceResolverPort>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            negative_ttl: DEFAULT_NEGATIVE_TTL.min(ttl),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Override how long failed resolutions are cached.
+    pub fn with_negative_ttl(mut self, negative_ttl: Duration) -> Self {
+        self.negative_ttl = negative_ttl;
+        self
+    }
+
+    fn cached(
+        &self,
+        reference: &ResourceReference,
+    ) -> Option<Result<ResolvedReference, ReferenceError>> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(reference) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.result.clone()),
+            Some(_) => {
+                entries.remove(reference);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(
+        &self,
+        reference: &ResourceReference,
+        result: &Result<ResolvedReference, ReferenceError>,
+    ) {
+        let ttl = if result.is_ok() {
+            self.ttl
+        } else {
+            self.negative_ttl
+        };
+        if ttl.is_zero() {
+            return;
+        }
+        self.entries.lock().unwrap().insert(
+            reference.clone(),
+            CacheEntry {
+                result: result.clone(),
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl ReferenceResolverPort for CachingReferenceResolver {
+    async fn resolve(
+        &self,
+        reference: &ResourceReference,
+    ) -> Result<ResolvedReference, ReferenceError> {
+        if let Some(result) = self.cached(reference) {
+            debug!("Reference cache hit: {}", reference);
+            return result;
+        }
+        let result = self.inner.resolve(reference).await;
+        self.store(reference, &result);
+        result
+    }
+
+    /// Resolve each distinct reference once, then fan results back out.
+    ///
+    /// Without this, identical references in one batch would all miss the
+    /// cache because they resolve concurrently.
+    async fn resolve_all(&self, references: &[ResourceReference]) -> Vec<ResolvedReference> {
+        use futures::future::join_all;
+
+        let mut unique: Vec<&ResourceReference> = Vec::new();
+        for reference in references {
+            if !unique.contains(&reference) {
+                unique.push(reference);
+            }
+        }
+        let results = join_all(unique.iter().map(|r| self.resolve(r))).await;
+        let resolved: HashMap<&ResourceReference, ResolvedReference> = unique
+            .into_iter()
+            .zip(results)
+            .filter_map(|(reference, result)| match result {
+                Ok(r) => Some((reference, r)),
+                Err(e) => {
+                    debug!("Skipping {}: {}", reference, e);
+                    None
+                }
+            })
+            .collect();
+
+        references
+            .iter()
+            .filter_map(|r| resolved.get(r).cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts calls; fails when `fail` is set.
+    #[derive(Default)]
+    struct CountingResolver {
+        calls: AtomicUsize,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl ReferenceResolverPort for CountingResolver {
+        async fn resolve(
+            &self,
+            reference: &ResourceReference,
+        ) -> Result<ResolvedReference, ReferenceError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail {
+                return Err(ReferenceError::ResolutionFailed("gh is broken".to_string()));
+            }
+            Ok(ResolvedReference {
+                reference: reference.clone(),
+                title: format!("Title of {}", reference),
+                content: String::new(),
+            })
+        }
+    }
+
+    fn issue(number: u64) -> ResourceReference {
+        ResourceReference::GitHubIssue { repo: None, number }
+    }
+
+    #[tokio::test]
+    async fn identical_references_resolve_once_within_ttl() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = CachingReferenceResolver::new(inner.clone(), Duration::from_secs(60));
+
+        let first = cache.resolve(&issue(42)).await.unwrap();
+        let second = cache.resolve(&issue(42)).await.unwrap();
+        let batch = cache.resolve_all(&[issue(7), issue(7)]).await;
+
+        assert_eq!(first.title, second.title);
+        assert_eq!(batch.len(), 2);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn entries_expire_after_ttl() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = CachingReferenceResolver::new(inner.clone(), Duration::from_millis(20));
+
+        cache.resolve(&issue(42)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        cache.resolve(&issue(42)).await.unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn failures_are_cached_for_the_negative_ttl() {
+        let inner = Arc::new(CountingResolver {
+            fail: true,
+            ..Default::default()
+        });
+        let cache = CachingReferenceResolver::new(inner.clone(), Duration::from_secs(60));
+
+        assert!(cache.resolve(&issue(42)).await.is_err());
+        assert!(cache.resolve(&issue(42)).await.is_err());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        let uncached = CachingReferenceResolver::new(inner.clone(), Duration::from_secs(60))
+            .with_negative_ttl(Duration::ZERO);
+        assert!(uncached.resolve(&issue(42)).await.is_err());
+        assert!(uncached.resolve(&issue(42)).await.is_err());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -108,6 +108,12 @@ impl ReferenceResolverPort for GitHubReferenceResolver {
         let (repo, number) = match reference {
             ResourceReference::GitHubIssue { repo, number } => (repo.as_deref(), *number),
             ResourceReference::GitHubPullRequest { repo, number } => (repo.as_deref(), *number),
+            other => {
+                return Err(ReferenceError::Unsupported(format!(
+                    "{} is not a GitHub reference",
+                    other
+                )));
+            }
         };
 
         debug!(
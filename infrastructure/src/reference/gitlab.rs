@@ -0,0 +1,132 @@
+//! GitLab reference resolver using the `glab` CLI.
+//!
+//! Resolves GitLab Issue and Merge Request references by invoking
+//! `glab issue view` / `glab mr view`.
+
+use async_trait::async_trait;
+use quorum_application::ports::reference_resolver::{
+    ReferenceError, ReferenceResolverPort, ResolvedReference,
+};
+use quorum_domain::ResourceReference;
+use tokio::process::Command;
+use tracing::{debug, info};
+
+/// Resolves GitLab references using the `glab` CLI.
+///
+/// Created via `try_new()` which validates that `glab` is installed and authenticated.
+/// If either check fails, `try_new()` returns `None` for graceful degradation.
+pub struct GitLabReferenceResolver {
+    working_dir: Option<String>,
+}
+
+impl GitLabReferenceResolver {
+    /// Try to create a new resolver.
+    ///
+    /// Returns `None` if `glab` CLI is not installed or not authenticated.
+    pub async fn try_new(working_dir: Option<String>) -> Option<Self> {
+        if which::which("glab").is_err() {
+            debug!("glab CLI not found, GitLab reference resolution disabled");
+            return None;
+        }
+
+        let mut cmd = std::process::Command::new("glab");
+        cmd.arg("auth").arg("status");
+        cmd.stdout(std::process::Stdio::null());
+        cmd.stderr(std::process::Stdio::null());
+        if let Some(ref dir) = working_dir {
+            cmd.current_dir(dir);
+        }
+        match cmd.status() {
+            Ok(status) if status.success() => {
+                info!("GitLab reference resolver initialized");
+                Some(Self { working_dir })
+            }
+            _ => {
+                debug!("glab CLI not authenticated, GitLab reference resolution disabled");
+                None
+            }
+        }
+    }
+
+    /// Run `glab {kind} view N --output json [-R project]`.
+    async fn glab_view(
+        &self,
+        kind: &str,
+        number: u64,
+        project: Option<&str>,
+    ) -> Result<(String, String), ReferenceError> {
+        let mut cmd = Command::new("glab");
+        cmd.arg(kind)
+            .arg("view")
+            .arg(number.to_string())
+            .arg("--output")
+            .arg("json");
+
+        if let Some(project) = project {
+            cmd.arg("--repo").arg(project);
+        }
+
+        if let Some(ref dir) = self.working_dir {
+            cmd.current_dir(dir);
+        }
+
+        let output = cmd.output().await.map_err(|e| {
+            ReferenceError::ResolutionFailed(format!("Failed to execute glab: {}", e))
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ReferenceError::ResolutionFailed(format!(
+                "glab {} view failed: {}",
+                kind,
+                stderr.trim()
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value = serde_json::from_str(&stdout).map_err(|e| {
+            ReferenceError::ResolutionFailed(format!("Failed to parse glab output: {}", e))
+        })?;
+
+        let title = json["title"].as_str().unwrap_or("(no title)").to_string();
+        let body = json["description"].as_str().unwrap_or("").to_string();
+
+        Ok((title, body))
+    }
+}
+
+#[async_trait]
+impl ReferenceResolverPort for GitLabReferenceResolver {
+    async fn resolve(
+        &self,
+        reference: &ResourceReference,
+    ) -> Result<ResolvedReference, ReferenceError> {
+        let (kind, project, number) = match reference {
+            ResourceReference::GitLabIssue { project, number } => {
+                ("issue", project.as_deref(), *number)
+            }
+            ResourceReference::GitLabMergeRequest { project, number } => {
+                ("mr", project.as_deref(), *number)
+            }
+            other => {
+                return Err(ReferenceError::Unsupported(format!(
+                    "{} is not a GitLab reference",
+                    other
+                )));
+            }
+        };
+
+        debug!(
+            "Resolving {} (number={}, project={:?})",
+            reference, number, project
+        );
+
+        let (title, body) = self.glab_view(kind, number, project).await?;
+
+        Ok(ResolvedReference {
+            reference: reference.clone(),
+            title,
+            content: body,
+        })
+    }
+}
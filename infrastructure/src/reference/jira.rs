@@ -0,0 +1,164 @@
+//! Jira reference resolver using the `jira` CLI (jira-cli).
+//!
+//! Resolves Jira issue keys by invoking `jira issue view KEY --raw`.
+
+use async_trait::async_trait;
+use quorum_application::ports::reference_resolver::{
+    ReferenceError, ReferenceResolverPort, ResolvedReference,
+};
+use quorum_domain::ResourceReference;
+use tokio::process::Command;
+use tracing::{debug, info};
+
+/// Resolves Jira references using the `jira` CLI.
+///
+/// Created via `try_new()` which validates that `jira` is installed and configured.
+/// If either check fails, `try_new()` returns `None` for graceful degradation.
+pub struct JiraReferenceResolver {
+    working_dir: Option<String>,
+}
+
+impl JiraReferenceResolver {
+    /// Try to create a new resolver.
+    ///
+    /// Returns `None` if `jira` CLI is not installed or has no working login.
+    pub async fn try_new(working_dir: Option<String>) -> Option<Self> {
+        if which::which("jira").is_err() {
+            debug!("jira CLI not found, Jira reference resolution disabled");
+            return None;
+        }
+
+        // `jira me` succeeds only with a valid server + token configuration
+        let mut cmd = std::process::Command::new("jira");
+        cmd.arg("me");
+        cmd.stdout(std::process::Stdio::null());
+        cmd.stderr(std::process::Stdio::null());
+        if let Some(ref dir) = working_dir {
+            cmd.current_dir(dir);
+        }
+        match cmd.status() {
+            Ok(status) if status.success() => {
+                info!("Jira reference resolver initialized");
+                Some(Self { working_dir })
+            }
+            _ => {
+                debug!("jira CLI not configured, Jira reference resolution disabled");
+                None
+            }
+        }
+    }
+
+    /// Run `jira issue view KEY --raw` and extract summary/description.
+    async fn jira_issue_view(&self, key: &str) -> Result<(String, String), ReferenceError> {
+        let mut cmd = Command::new("jira");
+        cmd.arg("issue").arg("view").arg(key).arg("--raw");
+
+        if let Some(ref dir) = self.working_dir {
+            cmd.current_dir(dir);
+        }
+
+        let output = cmd.output().await.map_err(|e| {
+            ReferenceError::ResolutionFailed(format!("Failed to execute jira: {}", e))
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ReferenceError::ResolutionFailed(format!(
+                "jira issue view failed: {}",
+                stderr.trim()
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value = serde_json::from_str(&stdout).map_err(|e| {
+            ReferenceError::ResolutionFailed(format!("Failed to parse jira output: {}", e))
+        })?;
+
+        let fields = &json["fields"];
+        let title = fields["summary"]
+            .as_str()
+            .unwrap_or("(no title)")
+            .to_string();
+        let body = description_text(&fields["description"]);
+
+        Ok((title, body))
+    }
+}
+
+/// Flatten a Jira description to plain text.
+///
+/// Jira Server returns a string; Jira Cloud returns an Atlassian Document
+/// Format tree whose `text` leaves are joined here (one line per block).
+fn description_text(description: &serde_json::Value) -> String {
+    fn collect(node: &serde_json::Value, out: &mut String) {
+        if let Some(text) = node["text"].as_str() {
+            out.push_str(text);
+        }
+        if let Some(children) = node["content"].as_array() {
+            for child in children {
+                collect(child, out);
+            }
+            if node["type"].as_str() != Some("doc") && !out.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+    }
+
+    match description {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Object(_) => {
+            let mut out = String::new();
+            collect(description, &mut out);
+            out.trim_end().to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+#[async_trait]
+impl ReferenceResolverPort for JiraReferenceResolver {
+    async fn resolve(
+        &self,
+        reference: &ResourceReference,
+    ) -> Result<ResolvedReference, ReferenceError> {
+        let ResourceReference::JiraIssue { key } = reference else {
+            return Err(ReferenceError::Unsupported(format!(
+                "{} is not a Jira reference",
+                reference
+            )));
+        };
+
+        debug!("Resolving {} (key={})", reference, key);
+
+        let (title, body) = self.jira_issue_view(key).await?;
+
+        Ok(ResolvedReference {
+            reference: reference.clone(),
+            title,
+            content: body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn description_text_flattens_cloud_documents() {
+        let adf = json!({
+            "type": "doc",
+            "content": [
+                {"type": "paragraph", "content": [
+                    {"type": "text", "text": "Login "},
+                    {"type": "text", "text": "fails"}
+                ]},
+                {"type": "paragraph", "content": [{"type": "text", "text": "on Safari"}]}
+            ]
+        });
+        assert_eq!(description_text(&adf), "Login fails\non Safari");
+        assert_eq!(description_text(&json!("plain")), "plain");
+        assert_eq!(description_text(&serde_json::Value::Null), "");
+    }
+}
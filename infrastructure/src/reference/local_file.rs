@@ -0,0 +1,185 @@
+//! Local file reference resolver for `@path` mentions.
+//!
+//! Reads the mentioned file relative to the working directory, capped at the
+//! per-file context budget (`context.max_file_bytes`). Paths that resolve
+//! outside the working directory (`../`, absolute paths, symlinks) are
+//! rejected.
+
+use async_trait::async_trait;
+use quorum_application::ports::reference_resolver::{
+    ReferenceError, ReferenceResolverPort, ResolvedReference,
+};
+use quorum_domain::util::truncate_str;
+use quorum_domain::{ContextFileLimits, ResourceReference};
+use std::path::PathBuf;
+use tokio::io::AsyncReadExt;
+use tracing::debug;
+
+/// Resolves [`ResourceReference::LocalFile`] by reading the file from disk.
+pub struct LocalFileReferenceResolver {
+    working_dir: PathBuf,
+    limits: ContextFileLimits,
+}
+
+impl LocalFileReferenceResolver {
+    pub fn new(working_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            working_dir: working_dir.into(),
+            limits: ContextFileLimits::default(),
+        }
+    }
+
+    /// Sets the size cap; only `max_file_bytes` applies to a single mention.
+    pub fn with_limits(mut self, limits: ContextFileLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Resolve `path` against the working directory, refusing escapes.
+    fn contained_path(&self, path: &str) -> Result<PathBuf, ReferenceError> {
+        let root = self.working_dir.canonicalize().map_err(|e| {
+            ReferenceError::NotAvailable(format!(
+                "working directory {} is not accessible: {}",
+                self.working_dir.display(),
+                e
+            ))
+        })?;
+        let resolved = root.join(path).canonicalize().map_err(|e| {
+            ReferenceError::ResolutionFailed(format!("cannot read {}: {}", path, e))
+        })?;
+        if !resolved.starts_with(&root) {
+            return Err(ReferenceError::ResolutionFailed(format!(
+                "{} is outside the working directory",
+                path
+            )));
+        }
+        if !resolved.is_file() {
+            return Err(ReferenceError::ResolutionFailed(format!(
+                "{} is not a file",
+                path
+            )));
+        }
+        Ok(resolved)
+    }
+}
+
+#[async_trait]
+impl ReferenceResolverPort for LocalFileReferenceResolver {
+    async fn resolve(
+        &self,
+        reference: &ResourceReference,
+    ) -> Result<ResolvedReference, ReferenceError> {
+        let ResourceReference::LocalFile { path } = reference else {
+            return Err(ReferenceError::Unsupported(format!(
+                "{} is not a local file reference",
+                reference
+            )));
+        };
+
+        let full_path = self.contained_path(path)?;
+        debug!("Resolving {} ({})", reference, full_path.display());
+
+        // Read at most one byte past the cap so truncation can be reported
+        let max_bytes = self.limits.max_file_bytes();
+        let file = tokio::fs::File::open(&full_path).await.map_err(|e| {
+            ReferenceError::ResolutionFailed(format!("cannot read {}: {}", path, e))
+        })?;
+        let mut bytes = Vec::new();
+        file.take(max_bytes as u64 + 1)
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| {
+                ReferenceError::ResolutionFailed(format!("cannot read {}: {}", path, e))
+            })?;
+
+        let text = String::from_utf8_lossy(&bytes);
+        let mut content = truncate_str(&text, max_bytes).to_string();
+        if bytes.len() > max_bytes {
+            content.push_str(&format!("\n[truncated at {} bytes]", max_bytes));
+        }
+
+        Ok(ResolvedReference {
+            reference: reference.clone(),
+            title: path.clone(),
+            content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_ref(path: &str) -> ResourceReference {
+        ResourceReference::LocalFile {
+            path: path.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_relative_path_within_working_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/foo.rs"), "fn foo() {}\n").unwrap();
+        let resolver = LocalFileReferenceResolver::new(dir.path());
+
+        let resolved = resolver.resolve(&file_ref("src/foo.rs")).await.unwrap();
+
+        assert_eq!(resolved.title, "src/foo.rs");
+        assert_eq!(resolved.content, "fn foo() {}\n");
+    }
+
+    #[tokio::test]
+    async fn missing_file_is_a_resolution_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = LocalFileReferenceResolver::new(dir.path());
+
+        let err = resolver.resolve(&file_ref("nope.rs")).await.unwrap_err();
+
+        assert!(
+            matches!(err, ReferenceError::ResolutionFailed(_)),
+            "{}",
+            err
+        );
+        // resolve_all skips it instead of failing the whole batch
+        assert!(
+            resolver
+                .resolve_all(&[file_ref("nope.rs")])
+                .await
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn parent_directory_escape_is_rejected() {
+        let outer = tempfile::tempdir().unwrap();
+        std::fs::write(outer.path().join("secret.txt"), "token").unwrap();
+        let project = outer.path().join("project");
+        std::fs::create_dir(&project).unwrap();
+        let resolver = LocalFileReferenceResolver::new(&project);
+
+        let err = resolver
+            .resolve(&file_ref("../secret.txt"))
+            .await
+            .unwrap_err();
+
+        assert!(
+            err.to_string().contains("outside the working directory"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn content_is_capped_at_max_file_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("big.txt"), "x".repeat(100)).unwrap();
+        let resolver = LocalFileReferenceResolver::new(dir.path())
+            .with_limits(ContextFileLimits::try_new(10, 10).unwrap());
+
+        let resolved = resolver.resolve(&file_ref("big.txt")).await.unwrap();
+
+        assert!(resolved.content.starts_with(&"x".repeat(10)));
+        assert!(resolved.content.contains("[truncated at 10 bytes]"));
+    }
+}
@@ -1,8 +1,26 @@
 //! Reference resolution adapters.
 //!
 //! Provides infrastructure implementations for resolving resource references
-//! (GitHub Issues, PRs) to their content.
+//! (GitHub Issues / PRs, GitLab Issues / MRs, Jira issues) to their content.
+//! [`RoutingReferenceResolver`] picks the adapter by the reference's provider;
+//! the GitLab and Jira adapters are behind the `gitlab` / `jira` features.
+//! [`CachingReferenceResolver`] memoizes any resolver for the session, and
+//! [`LocalFileReferenceResolver`] reads `@path` mentions from the working directory.
 
+mod caching;
 mod github;
+#[cfg(feature = "gitlab")]
+mod gitlab;
+#[cfg(feature = "jira")]
+mod jira;
+mod local_file;
+mod routing;
 
+pub use caching::CachingReferenceResolver;
 pub use github::GitHubReferenceResolver;
+#[cfg(feature = "gitlab")]
+pub use gitlab::GitLabReferenceResolver;
+#[cfg(feature = "jira")]
+pub use jira::JiraReferenceResolver;
+pub use local_file::LocalFileReferenceResolver;
+pub use routing::RoutingReferenceResolver;
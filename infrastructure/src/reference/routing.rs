@@ -0,0 +1,157 @@
+//! Provider-routing reference resolver.
+//!
+//! Dispatches each [`ResourceReference`] to the resolver registered for its
+//! [`ReferenceProvider`] (GitHub, GitLab, Jira), so a single
+//! [`ReferenceResolverPort`] can serve mixed references.
+
+use super::GitHubReferenceResolver;
+use async_trait::async_trait;
+use quorum_application::ports::reference_resolver::{
+    ReferenceError, ReferenceResolverPort, ResolvedReference,
+};
+use quorum_domain::{ReferenceProvider, ResourceReference};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Routes references to a per-provider resolver.
+///
+/// References whose provider has no registered resolver go to the fallback
+/// if one is set, and otherwise fail with [`ReferenceError::NotAvailable`]
+/// (and are skipped by `resolve_all`).
+#[derive(Default)]
+pub struct RoutingReferenceResolver {
+    resolvers: HashMap<ReferenceProvider, Arc<dyn ReferenceResolverPort>>,
+    fallback: Option<Arc<dyn ReferenceResolverPort>>,
+}
+
+impl RoutingReferenceResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `resolver` for references of `provider`.
+    pub fn with_resolver(
+        mut self,
+        provider: ReferenceProvider,
+        resolver: Arc<dyn ReferenceResolverPort>,
+    ) -> Self {
+        self.resolvers.insert(provider, resolver);
+        self
+    }
+
+    /// Resolver for providers without a dedicated one.
+    pub fn with_fallback(mut self, resolver: Arc<dyn ReferenceResolverPort>) -> Self {
+        self.fallback = Some(resolver);
+        self
+    }
+
+    /// Whether no resolver (dedicated or fallback) is registered.
+    pub fn is_empty(&self) -> bool {
+        self.resolvers.is_empty() && self.fallback.is_none()
+    }
+
+    /// Register every resolver whose CLI is installed and authenticated.
+    ///
+    /// GitHub (`gh`) is always probed; GitLab (`glab`) and Jira (`jira`)
+    /// only when the `gitlab` / `jira` features are enabled.
+    /// Returns `None` if no provider is available.
+    pub async fn detect(working_dir: Option<String>) -> Option<Self> {
+        let mut routing = Self::new();
+
+        if let Some(github) = GitHubReferenceResolver::try_new(working_dir.clone()).await {
+            routing = routing.with_resolver(ReferenceProvider::GitHub, Arc::new(github));
+        }
+        #[cfg(feature = "gitlab")]
+        if let Some(gitlab) = super::GitLabReferenceResolver::try_new(working_dir.clone()).await {
+            routing = routing.with_resolver(ReferenceProvider::GitLab, Arc::new(gitlab));
+        }
+        #[cfg(feature = "jira")]
+        if let Some(jira) = super::JiraReferenceResolver::try_new(working_dir.clone()).await {
+            routing = routing.with_resolver(ReferenceProvider::Jira, Arc::new(jira));
+        }
+
+        (!routing.is_empty()).then_some(routing)
+    }
+}
+
+#[async_trait]
+impl ReferenceResolverPort for RoutingReferenceResolver {
+    async fn resolve(
+        &self,
+        reference: &ResourceReference,
+    ) -> Result<ResolvedReference, ReferenceError> {
+        let provider = reference.provider();
+        match self.resolvers.get(&provider).or(self.fallback.as_ref()) {
+            Some(resolver) => resolver.resolve(reference).await,
+            None => Err(ReferenceError::NotAvailable(format!(
+                "no {} resolver configured",
+                provider
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quorum_domain::extract_references;
+
+    /// Resolves everything, titling it with the provider name.
+    struct EchoResolver(&'static str);
+
+    #[async_trait]
+    impl ReferenceResolverPort for EchoResolver {
+        async fn resolve(
+            &self,
+            reference: &ResourceReference,
+        ) -> Result<ResolvedReference, ReferenceError> {
+            Ok(ResolvedReference {
+                reference: reference.clone(),
+                title: self.0.to_string(),
+                content: String::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn routes_each_reference_to_its_provider() {
+        let routing = RoutingReferenceResolver::new()
+            .with_resolver(ReferenceProvider::GitHub, Arc::new(EchoResolver("github")))
+            .with_resolver(ReferenceProvider::Jira, Arc::new(EchoResolver("jira")));
+        let refs = extract_references("Fixes #1 and PROJ-2, see !3");
+
+        let resolved = routing.resolve_all(&refs).await;
+
+        // No GitLab resolver: `!3` is skipped
+        assert_eq!(resolved.len(), 2);
+        for r in &resolved {
+            assert_eq!(r.title, r.reference.provider().as_str());
+        }
+        let mr = refs
+            .iter()
+            .find(|r| r.provider() == ReferenceProvider::GitLab)
+            .unwrap();
+        assert!(matches!(
+            routing.resolve(mr).await,
+            Err(ReferenceError::NotAvailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn fallback_handles_unregistered_providers() {
+        let routing = RoutingReferenceResolver::new()
+            .with_resolver(ReferenceProvider::LocalFile, Arc::new(EchoResolver("file")))
+            .with_fallback(Arc::new(EchoResolver("fallback")));
+        let refs = extract_references("@src/lib.rs and #1");
+
+        let mut titles: Vec<String> = routing
+            .resolve_all(&refs)
+            .await
+            .into_iter()
+            .map(|r| r.title)
+            .collect();
+        titles.sort();
+
+        assert_eq!(titles, vec!["fallback", "file"]);
+    }
+}
@@ -9,13 +9,25 @@
 //! quorum.config.set("agent.strategy", "debate")
 //! quorum.config["agent.strategy"] = "debate"  -- metatable shortcut
 //! quorum.config.keys()                        -- list all keys
+//! quorum.config.profile("fast", { ["agent.phase_scope"] = "fast" })
 //! ```
 
 use mlua::prelude::*;
-use quorum_application::{ConfigAccessorPort, ConfigValue};
+use quorum_application::{ConfigAccessorPort, ConfigProfiles, ConfigValue};
+use quorum_domain::ConfigIssue;
 use std::sync::{Arc, Mutex};
+use tracing::warn;
 
 use super::event_bus::EventBus;
+use std::collections::BTreeMap;
+
+/// Log the issues a `config_set` reported (deprecated keys, suspicious
+/// combinations); the value itself was applied.
+fn warn_issues(issues: &[ConfigIssue]) {
+    for issue in issues {
+        warn!("config: {}", issue.message);
+    }
+}
 
 /// Register the `quorum.config` table on the given `quorum` global.
 ///
@@ -64,9 +76,10 @@ pub fn register_config_api(
                 let mut guard = config
                     .lock()
                     .map_err(|e| LuaError::external(format!("config lock poisoned: {}", e)))?;
-                guard
+                let issues = guard
                     .config_set(&key, lua_value.clone())
                     .map_err(|e| LuaError::external(e.to_string()))?;
+                warn_issues(&issues);
             }
 
             // Fire ConfigChanged event
@@ -112,8 +125,8 @@ pub fn register_config_api(
     {
         let config = Arc::clone(&config);
         let index_fn = lua.create_function(move |lua, (_table, key): (LuaTable, String)| {
-            // Skip method names so config.get/set/keys still work
-            if key == "get" || key == "set" || key == "keys" {
+            // Skip method names so config.get/set/keys/profile still work
+            if key == "get" || key == "set" || key == "keys" || key == "profile" {
                 return Ok(LuaValue::Nil);
             }
             let guard = config
@@ -146,9 +159,10 @@ pub fn register_config_api(
                     let mut guard = config_for_newindex
                         .lock()
                         .map_err(|e| LuaError::external(format!("config lock poisoned: {}", e)))?;
-                    guard
+                    let issues = guard
                         .config_set(&key, lua_value.clone())
                         .map_err(|e| LuaError::external(e.to_string()))?;
+                    warn_issues(&issues);
                 }
 
                 // Fire ConfigChanged event
@@ -176,6 +190,34 @@ pub fn register_config_api(
     Ok(())
 }
 
+/// Register `quorum.config.profile(name, overrides)` on an already
+/// registered `quorum.config` table.
+///
+/// Profiles are only recorded here; the one selected with `--profile` is
+/// applied by the CLI after all scripts have run. Values are validated when
+/// applied, so a profile that is never selected cannot fail startup.
+pub fn register_profile_api(
+    lua: &Lua,
+    quorum: &LuaTable,
+    profiles: Arc<Mutex<ConfigProfiles>>,
+) -> LuaResult<()> {
+    let config_table: LuaTable = quorum.get("config")?;
+    let profile_fn = lua.create_function(move |_, (name, overrides): (String, LuaTable)| {
+        let mut values = BTreeMap::new();
+        for pair in overrides.pairs::<String, LuaValue>() {
+            let (key, value) = pair?;
+            values.insert(key, lua_to_config_value(value)?);
+        }
+        profiles
+            .lock()
+            .map_err(|e| LuaError::external(format!("profiles lock poisoned: {}", e)))?
+            .insert(name, values);
+        Ok(())
+    })?;
+    // raw_set: a plain set would go through __newindex as a config key
+    config_table.raw_set("profile", profile_fn)
+}
+
 /// Convert a `ConfigValue` into a Lua value within a Lua context.
 pub(crate) fn push_config_value(lua: &Lua, value: ConfigValue) -> LuaResult<LuaValue> {
     match value {
@@ -189,6 +231,13 @@ pub(crate) fn push_config_value(lua: &Lua, value: ConfigValue) -> LuaResult<LuaV
             }
             Ok(LuaValue::Table(table))
         }
+        ConfigValue::StringMap(map) => {
+            let table = lua.create_table()?;
+            for (k, v) in &map {
+                table.set(k.as_str(), v.as_str())?;
+            }
+            Ok(LuaValue::Table(table))
+        }
     }
 }
 
@@ -199,6 +248,18 @@ fn lua_to_config_value(value: LuaValue) -> LuaResult<ConfigValue> {
         LuaValue::Integer(n) => Ok(ConfigValue::Integer(n)),
         LuaValue::Boolean(b) => Ok(ConfigValue::Boolean(b)),
         LuaValue::Number(n) => Ok(ConfigValue::Integer(n as i64)),
+        LuaValue::Table(table) if table.raw_len() == 0 && !table.is_empty() => {
+            let mut map = BTreeMap::new();
+            for pair in table.pairs::<LuaString, LuaString>() {
+                let (k, v) = pair.map_err(|_| {
+                    LuaError::external(
+                        "table keys and values must be strings for string map config",
+                    )
+                })?;
+                map.insert(k.to_str()?.to_string(), v.to_str()?.to_string());
+            }
+            Ok(ConfigValue::StringMap(map))
+        }
         LuaValue::Table(table) => {
             let mut list = Vec::new();
             for pair in table.sequence_values::<LuaString>() {
@@ -225,7 +286,6 @@ fn lua_to_config_value(value: LuaValue) -> LuaResult<ConfigValue> {
 mod tests {
     use super::*;
     use quorum_application::ConfigAccessError;
-    use quorum_domain::ConfigIssue;
 
     /// Test-only ConfigAccessor that stores values in a HashMap.
     struct MockConfig {
@@ -247,6 +307,14 @@ mod tests {
                 "models.review".to_string(),
                 ConfigValue::StringList(vec!["model-a".to_string(), "model-b".to_string()]),
             );
+            data.insert(
+                "models.prompts".to_string(),
+                ConfigValue::StringMap(BTreeMap::new()),
+            );
+            data.insert(
+                "tools.timeouts".to_string(),
+                ConfigValue::StringMap(BTreeMap::new()),
+            );
             Self { data }
         }
     }
@@ -426,4 +494,96 @@ mod tests {
             .unwrap();
         assert_eq!(result, vec!["model-a", "model-b"]);
     }
+
+    #[test]
+    fn test_config_set_string_map_from_keyed_table() {
+        let lua = Lua::new();
+        let quorum = lua.create_table().unwrap();
+        let config: Arc<Mutex<dyn ConfigAccessorPort>> = Arc::new(Mutex::new(MockConfig::new()));
+        let event_bus = Arc::new(Mutex::new(EventBus::new()));
+
+        register_config_api(&lua, &quorum, config.clone(), event_bus).unwrap();
+        lua.globals().set("quorum", &quorum).unwrap();
+
+        lua.load(r#"quorum.config.set("models.prompts", { ["gpt-5.3"] = "Be terse." })"#)
+            .exec()
+            .unwrap();
+
+        let value = config.lock().unwrap().config_get("models.prompts").unwrap();
+        assert_eq!(
+            value,
+            ConfigValue::StringMap(BTreeMap::from([(
+                "gpt-5.3".to_string(),
+                "Be terse.".to_string()
+            )]))
+        );
+
+        let prompt: String = lua
+            .load(r#"quorum.config.get("models.prompts")["gpt-5.3"]"#)
+            .eval()
+            .unwrap();
+        assert_eq!(prompt, "Be terse.");
+    }
+
+    #[test]
+    fn test_config_set_string_map_coerces_numbers() {
+        let lua = Lua::new();
+        let quorum = lua.create_table().unwrap();
+        let config: Arc<Mutex<dyn ConfigAccessorPort>> = Arc::new(Mutex::new(MockConfig::new()));
+        let event_bus = Arc::new(Mutex::new(EventBus::new()));
+
+        register_config_api(&lua, &quorum, config.clone(), event_bus).unwrap();
+        lua.globals().set("quorum", &quorum).unwrap();
+
+        lua.load(r#"quorum.config.set("tools.timeouts", { run_command = 300 })"#)
+            .exec()
+            .unwrap();
+
+        let value = config.lock().unwrap().config_get("tools.timeouts").unwrap();
+        assert_eq!(
+            value,
+            ConfigValue::StringMap(BTreeMap::from([(
+                "run_command".to_string(),
+                "300".to_string()
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_config_profile_records_without_applying() {
+        let lua = Lua::new();
+        let quorum = lua.create_table().unwrap();
+        let config: Arc<Mutex<dyn ConfigAccessorPort>> = Arc::new(Mutex::new(MockConfig::new()));
+        let event_bus = Arc::new(Mutex::new(EventBus::new()));
+        let profiles = Arc::new(Mutex::new(ConfigProfiles::new()));
+
+        register_config_api(&lua, &quorum, config.clone(), event_bus).unwrap();
+        register_profile_api(&lua, &quorum, profiles.clone()).unwrap();
+        lua.globals().set("quorum", &quorum).unwrap();
+
+        lua.load(
+            r#"quorum.config.profile("review", {
+                ["agent.strategy"] = "debate",
+                ["models.review"] = { "model-c" },
+            })"#,
+        )
+        .exec()
+        .unwrap();
+
+        let profiles = profiles.lock().unwrap();
+        let review = &profiles["review"];
+        assert_eq!(
+            review["agent.strategy"],
+            ConfigValue::String("debate".to_string())
+        );
+        assert_eq!(
+            review["models.review"],
+            ConfigValue::StringList(vec!["model-c".to_string()])
+        );
+        // Nothing is applied until --profile selects it
+        assert_eq!(
+            config.lock().unwrap().config_get("agent.strategy").unwrap(),
+            ConfigValue::String("quorum".to_string())
+        );
+    }
 }
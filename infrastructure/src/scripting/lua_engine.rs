@@ -5,21 +5,26 @@
 
 use mlua::prelude::*;
 use quorum_application::{
-    ConfigAccessorPort, CustomToolDef, EventOutcome, KeymapAction, ScriptError,
+    ConfigAccessorPort, ConfigProfiles, CustomToolDef, EventOutcome, KeymapAction, ScriptError,
     ScriptingEnginePort, TuiAccessorPort,
 };
 use quorum_domain::scripting::{ScriptEventData, ScriptEventType, ScriptValue};
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use super::command_api::{CommandRegistry, register_command_api};
-use super::config_api::register_config_api;
+use super::config_api::{register_config_api, register_profile_api};
 use super::event_bus::EventBus;
 use super::keymap_api::{KeymapBinding, KeymapRegistry, register_keymap_api};
 use super::sandbox::apply_sandbox;
-use super::tools_api::register_tools_api;
+use super::tools_api::{json_to_lua, register_tools_api};
 use super::tui_api::register_tui_api;
 
+/// Number of VM instructions between deadline checks in a tool handler.
+const HANDLER_DEADLINE_CHECK_INTERVAL: u32 = 1000;
+
 /// Lua 5.4 scripting engine implementing `ScriptingEnginePort`.
 ///
 /// Owns the Lua VM and all associated registries (events, keymaps).
@@ -31,7 +36,10 @@ pub struct LuaScriptingEngine {
     command_registry: Arc<Mutex<CommandRegistry>>,
     callback_store: Arc<Mutex<Vec<(u64, LuaRegistryKey)>>>,
     pending_custom_tools: Arc<Mutex<Vec<CustomToolDef>>>,
+    tool_handlers: Arc<Mutex<HashMap<String, LuaRegistryKey>>>,
     provider_config: Arc<Mutex<quorum_domain::ProviderConfig>>,
+    /// Config profiles defined via `quorum.config.profile()`
+    config_profiles: Arc<Mutex<ConfigProfiles>>,
 }
 
 impl LuaScriptingEngine {
@@ -55,8 +63,11 @@ impl LuaScriptingEngine {
         let callback_store: Arc<Mutex<Vec<(u64, LuaRegistryKey)>>> =
             Arc::new(Mutex::new(Vec::new()));
         let pending_custom_tools: Arc<Mutex<Vec<CustomToolDef>>> = Arc::new(Mutex::new(Vec::new()));
+        let tool_handlers: Arc<Mutex<HashMap<String, LuaRegistryKey>>> =
+            Arc::new(Mutex::new(HashMap::new()));
         let provider_config: Arc<Mutex<quorum_domain::ProviderConfig>> =
             Arc::new(Mutex::new(quorum_domain::ProviderConfig::default()));
+        let config_profiles = Arc::new(Mutex::new(ConfigProfiles::new()));
 
         // Apply sandbox
         apply_sandbox(&lua).map_err(|e| ScriptError {
@@ -74,7 +85,7 @@ impl LuaScriptingEngine {
                     // Validate event name
                     if event_name.parse::<ScriptEventType>().is_err() {
                         return Err(LuaError::external(format!(
-                            "unknown event: '{}'. Valid events: ScriptLoading, ScriptLoaded, ConfigChanged, ModeChanged, SessionStarted, PaneCreated, LayoutChanged, ToolCallBefore, ToolCallAfter, PhaseChanged, PlanCreated, QuorumResult",
+                            "unknown event: '{}'. Valid events: ScriptLoading, ScriptLoaded, ConfigChanged, ModeChanged, SessionStarted, PaneCreated, LayoutChanged, ToolCallBefore, ToolCallAfter, PhaseChanged, PlanCreated, PlanReviewed, QuorumResult",
                             event_name
                         )));
                     }
@@ -93,6 +104,8 @@ impl LuaScriptingEngine {
         // Register quorum.config API
         register_config_api(&lua, &quorum, config, Arc::clone(&event_bus))
             .map_err(lua_to_script_error)?;
+        register_profile_api(&lua, &quorum, Arc::clone(&config_profiles))
+            .map_err(lua_to_script_error)?;
 
         // Register quorum.keymap API
         register_keymap_api(
@@ -117,8 +130,13 @@ impl LuaScriptingEngine {
         .map_err(lua_to_script_error)?;
 
         // Register quorum.tools API
-        register_tools_api(&lua, &quorum, Arc::clone(&pending_custom_tools))
-            .map_err(lua_to_script_error)?;
+        register_tools_api(
+            &lua,
+            &quorum,
+            Arc::clone(&pending_custom_tools),
+            Arc::clone(&tool_handlers),
+        )
+        .map_err(lua_to_script_error)?;
 
         // Register quorum.providers API
         super::providers_api::register_providers_api(&lua, &quorum, Arc::clone(&provider_config))
@@ -136,7 +154,9 @@ impl LuaScriptingEngine {
             command_registry,
             callback_store,
             pending_custom_tools,
+            tool_handlers,
             provider_config,
+            config_profiles,
         })
     }
 
@@ -186,28 +206,10 @@ impl ScriptingEnginePort for LuaScriptingEngine {
         // Convert ScriptEventData to a Lua table
         let data_table = lua.create_table().map_err(lua_to_script_error)?;
         for (key, value) in data.fields() {
-            match value {
-                ScriptValue::String(s) => {
-                    data_table
-                        .set(key.as_str(), s.as_str())
-                        .map_err(lua_to_script_error)?;
-                }
-                ScriptValue::Integer(n) => {
-                    data_table
-                        .set(key.as_str(), *n)
-                        .map_err(lua_to_script_error)?;
-                }
-                ScriptValue::Boolean(b) => {
-                    data_table
-                        .set(key.as_str(), *b)
-                        .map_err(lua_to_script_error)?;
-                }
-                ScriptValue::Nil => {
-                    data_table
-                        .set(key.as_str(), LuaValue::Nil)
-                        .map_err(lua_to_script_error)?;
-                }
-            }
+            let value = script_value_to_lua(&lua, value).map_err(lua_to_script_error)?;
+            data_table
+                .set(key.as_str(), value)
+                .map_err(lua_to_script_error)?;
         }
 
         let continued = bus
@@ -330,9 +332,86 @@ impl ScriptingEnginePort for LuaScriptingEngine {
             .unwrap_or_default()
     }
 
+    fn call_tool_handler(
+        &self,
+        tool_name: &str,
+        args_json: &str,
+        timeout: Duration,
+    ) -> Result<String, ScriptError> {
+        let args: serde_json::Value = serde_json::from_str(args_json).map_err(|e| ScriptError {
+            message: format!("invalid arguments for {}: {}", tool_name, e),
+        })?;
+        let lua = self.lua.lock().map_err(|e| ScriptError {
+            message: format!("lua lock poisoned: {}", e),
+        })?;
+        let handlers = self.tool_handlers.lock().map_err(|e| ScriptError {
+            message: format!("tool handlers lock poisoned: {}", e),
+        })?;
+
+        let Some(key) = handlers.get(tool_name) else {
+            return Err(ScriptError {
+                message: format!("no Lua handler for tool: {}", tool_name),
+            });
+        };
+        let func: LuaFunction = lua.registry_value(key).map_err(lua_to_script_error)?;
+        let args = json_to_lua(&lua, &args).map_err(lua_to_script_error)?;
+
+        // Abort an overrunning handler from inside the VM so the Lua lock is
+        // released at the deadline instead of whenever the handler returns.
+        let deadline = Instant::now() + timeout;
+        let name = tool_name.to_string();
+        lua.set_hook(
+            LuaHookTriggers::new().every_nth_instruction(HANDLER_DEADLINE_CHECK_INTERVAL),
+            move |_, _| {
+                if Instant::now() >= deadline {
+                    Err(LuaError::runtime(format!(
+                        "Lua handler for `{}` timed out",
+                        name
+                    )))
+                } else {
+                    Ok(LuaVmState::Continue)
+                }
+            },
+        );
+        let result = func.call::<String>(args).map_err(lua_to_script_error);
+        lua.remove_hook();
+        result
+    }
+
     fn provider_config(&self) -> Option<quorum_domain::ProviderConfig> {
         self.provider_config.lock().ok().map(|cfg| cfg.clone())
     }
+
+    fn config_profiles(&self) -> ConfigProfiles {
+        self.config_profiles
+            .lock()
+            .map(|profiles| profiles.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Convert event data into a Lua value, turning `Table`/`Array` into nested tables.
+fn script_value_to_lua(lua: &Lua, value: &ScriptValue) -> LuaResult<LuaValue> {
+    Ok(match value {
+        ScriptValue::String(s) => LuaValue::String(lua.create_string(s)?),
+        ScriptValue::Integer(n) => LuaValue::Integer(*n),
+        ScriptValue::Boolean(b) => LuaValue::Boolean(*b),
+        ScriptValue::Table(fields) => {
+            let table = lua.create_table()?;
+            for (key, field) in fields {
+                table.set(key.as_str(), script_value_to_lua(lua, field)?)?;
+            }
+            LuaValue::Table(table)
+        }
+        ScriptValue::Array(items) => {
+            let table = lua.create_table()?;
+            for (i, item) in items.iter().enumerate() {
+                table.set(i + 1, script_value_to_lua(lua, item)?)?;
+            }
+            LuaValue::Table(table)
+        }
+        ScriptValue::Nil => LuaValue::Nil,
+    })
 }
 
 /// Convert an mlua error to a ScriptError.
@@ -686,4 +765,125 @@ mod tests {
         let loaded: Option<String> = lua.globals().get("loaded_plugins").unwrap();
         assert_eq!(loaded.unwrap(), "good,also_good,");
     }
+
+    #[tokio::test]
+    async fn test_lua_handler_tool_is_callable() {
+        use crate::tools::CustomToolProvider;
+        use quorum_domain::tool::entities::{RiskLevel, ToolCall};
+        use quorum_domain::tool::provider::ToolProvider;
+
+        let engine = make_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("init.lua");
+        std::fs::write(
+            &script,
+            r#"
+            quorum.tools.register("greet", {
+                description = "Greet someone",
+                risk_level = "low",
+                parameters = { name = { type = "string", required = true } },
+                handler = function(args) return "hello, " .. args.name end,
+            })
+            quorum.tools.register("deploy", {
+                description = "Deploy",
+                handler = function(args) return "deployed" end,
+            })
+        "#,
+        )
+        .unwrap();
+        engine.load_script(&script).unwrap();
+
+        let engine: Arc<dyn ScriptingEnginePort> = Arc::new(engine);
+        let provider = CustomToolProvider::from_custom_tool_defs(&engine.registered_custom_tools())
+            .with_script_engine(Arc::clone(&engine));
+
+        let result = provider
+            .execute(&ToolCall::new("greet").with_arg("name", "quorum"))
+            .await;
+        assert!(result.is_success());
+        assert_eq!(result.output(), Some("hello, quorum"));
+
+        // High-risk Lua tools keep their risk level, so they still go through review
+        let tools = provider.discover_tools().await.unwrap();
+        let deploy = tools.iter().find(|t| t.name == "deploy").unwrap();
+        assert_eq!(deploy.risk_level, RiskLevel::High);
+    }
+
+    #[test]
+    fn test_overrunning_handler_releases_lua_state() {
+        let engine = make_engine();
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("init.lua");
+        std::fs::write(
+            &script,
+            r#"
+            quorum.tools.register("spin", {
+                description = "Never returns",
+                handler = function(args) while true do end end,
+            })
+            quorum.tools.register("echo", {
+                description = "Echo",
+                handler = function(args) return "ok" end,
+            })
+        "#,
+        )
+        .unwrap();
+        engine.load_script(&script).unwrap();
+
+        let err = engine
+            .call_tool_handler("spin", "{}", Duration::from_millis(50))
+            .unwrap_err();
+        assert!(err.message.contains("timed out"), "{}", err.message);
+
+        // The Lua state is free again, and the deadline hook is gone
+        let output = engine
+            .call_tool_handler("echo", "{}", Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(output, "ok");
+        assert!(engine.lua.try_lock().is_ok());
+    }
+
+    #[test]
+    fn test_nested_event_data_becomes_lua_tables() {
+        let engine = make_engine();
+        {
+            let lua = engine.lua.lock().unwrap();
+            lua.load(
+                r#"
+                quorum.on("PlanReviewed", function(data)
+                    _G.first_model = data.votes[1].model
+                    _G.second_approved = data.votes[2].approved
+                    _G.vote_count = #data.votes
+                end)
+            "#,
+            )
+            .exec()
+            .unwrap();
+        }
+
+        let votes = ScriptValue::Array(vec![
+            ScriptValue::table([
+                ("model", ScriptValue::String("gpt".into())),
+                ("approved", ScriptValue::Boolean(true)),
+            ]),
+            ScriptValue::table([
+                ("model", ScriptValue::String("claude".into())),
+                ("approved", ScriptValue::Boolean(false)),
+            ]),
+        ]);
+        engine
+            .emit_event(
+                ScriptEventType::PlanReviewed,
+                ScriptEventData::new().with_field("votes", votes),
+            )
+            .unwrap();
+
+        let lua = engine.lua.lock().unwrap();
+        let first: String = lua.globals().get("first_model").unwrap();
+        let second: bool = lua.globals().get("second_approved").unwrap();
+        let count: i64 = lua.globals().get("vote_count").unwrap();
+        assert_eq!(first, "gpt");
+        assert!(!second);
+        assert_eq!(count, 2);
+    }
 }
@@ -6,10 +6,16 @@
 //! ```lua
 //! quorum.providers.set_default("bedrock")
 //! quorum.providers.route("claude-sonnet-4.6", "bedrock")
+//! quorum.providers.fallback("claude-sonnet-4.6", { "anthropic", "copilot" })
+//! quorum.providers.copilot({ max_rps = 2 })
 //! quorum.providers.bedrock({ region = "us-west-2", profile = "dev-ai" })
 //! quorum.providers.anthropic({ api_key = os.getenv("ANTHROPIC_API_KEY") })
 //! quorum.providers.openai({ api_key = os.getenv("OPENAI_API_KEY") })
 //! ```
+//!
+//! `api_key` and `base_url` also expand `${VAR}` / `${VAR:-default}`
+//! (e.g. `base_url = "${OPENAI_BASE_URL:-https://api.openai.com}"`); an unset
+//! variable without a default is a Lua error.
 
 use mlua::prelude::*;
 use std::sync::{Arc, Mutex};
@@ -53,6 +59,41 @@ pub fn register_providers_api(
         providers.set("route", route_fn)?;
     }
 
+    // quorum.providers.fallback(model_name, { provider_name, ... })
+    {
+        let cfg = Arc::clone(&config);
+        let fallback_fn =
+            lua.create_function(move |_, (model, chain): (String, Vec<String>)| {
+                let mut guard = cfg.lock().map_err(|e| {
+                    LuaError::external(format!("providers config lock poisoned: {}", e))
+                })?;
+                guard.routing_fallback.insert(model, chain);
+                Ok(())
+            })?;
+        providers.set("fallback", fallback_fn)?;
+    }
+
+    // quorum.providers.copilot({ max_rps })
+    {
+        let cfg = Arc::clone(&config);
+        let copilot_fn = lua.create_function(move |_, table: LuaTable| {
+            let mut guard = cfg.lock().map_err(|e| {
+                LuaError::external(format!("providers config lock poisoned: {}", e))
+            })?;
+            if let Ok(Some(v)) = table.get::<Option<f64>>("max_rps") {
+                if !(v.is_finite() && v > 0.0) {
+                    return Err(LuaError::external(format!(
+                        "copilot max_rps must be > 0, got {}",
+                        v
+                    )));
+                }
+                guard.copilot.max_rps = Some(v);
+            }
+            Ok(())
+        })?;
+        providers.set("copilot", copilot_fn)?;
+    }
+
     // quorum.providers.bedrock({ region, profile, max_tokens, cross_region })
     {
         let cfg = Arc::clone(&config);
@@ -85,13 +126,13 @@ pub fn register_providers_api(
                 LuaError::external(format!("providers config lock poisoned: {}", e))
             })?;
             if let Ok(v) = table.get::<String>("api_key") {
-                guard.anthropic.api_key = Some(v);
+                guard.anthropic.api_key = Some(expand_env("api_key", &v)?);
             }
             if let Ok(v) = table.get::<String>("api_key_env") {
                 guard.anthropic.api_key_env = v;
             }
             if let Ok(v) = table.get::<String>("base_url") {
-                guard.anthropic.base_url = v;
+                guard.anthropic.base_url = expand_env("base_url", &v)?;
             }
             if let Ok(v) = table.get::<u32>("max_tokens") {
                 guard.anthropic.max_tokens = v;
@@ -112,13 +153,13 @@ pub fn register_providers_api(
                 LuaError::external(format!("providers config lock poisoned: {}", e))
             })?;
             if let Ok(v) = table.get::<String>("api_key") {
-                guard.openai.api_key = Some(v);
+                guard.openai.api_key = Some(expand_env("api_key", &v)?);
             }
             if let Ok(v) = table.get::<String>("api_key_env") {
                 guard.openai.api_key_env = v;
             }
             if let Ok(v) = table.get::<String>("base_url") {
-                guard.openai.base_url = v;
+                guard.openai.base_url = expand_env("base_url", &v)?;
             }
             if let Ok(v) = table.get::<u32>("max_tokens") {
                 guard.openai.max_tokens = v;
@@ -132,6 +173,16 @@ pub fn register_providers_api(
     Ok(())
 }
 
+/// Expand `${VAR}` references in a provider string field.
+fn expand_env(field: &str, value: &str) -> LuaResult<String> {
+    quorum_domain::config::interpolate_env(value, |var| std::env::var(var).ok()).map_err(|var| {
+        LuaError::external(format!(
+            "{}: environment variable '{}' is not set (use ${{{}:-default}} for a fallback)",
+            field, var, var
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +219,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fallback() {
+        let (lua, config) = make_lua_with_providers();
+        lua.load(r#"quorum.providers.fallback("claude-sonnet-4.6", { "anthropic", "copilot" })"#)
+            .exec()
+            .unwrap();
+        let guard = config.lock().unwrap();
+        assert_eq!(
+            guard.routing_fallback.get("claude-sonnet-4.6"),
+            Some(&vec!["anthropic".to_string(), "copilot".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_copilot_config() {
+        let (lua, config) = make_lua_with_providers();
+        assert_eq!(config.lock().unwrap().copilot.max_rps, None);
+        lua.load(r#"quorum.providers.copilot({ max_rps = 2.5 })"#)
+            .exec()
+            .unwrap();
+        assert_eq!(config.lock().unwrap().copilot.max_rps, Some(2.5));
+    }
+
+    #[test]
+    fn test_copilot_config_rejects_non_positive_rps() {
+        let (lua, config) = make_lua_with_providers();
+        assert!(
+            lua.load(r#"quorum.providers.copilot({ max_rps = 0 })"#)
+                .exec()
+                .is_err()
+        );
+        assert_eq!(config.lock().unwrap().copilot.max_rps, None);
+    }
+
     #[test]
     fn test_bedrock_config() {
         let (lua, config) = make_lua_with_providers();
@@ -218,6 +303,25 @@ mod tests {
         assert_eq!(guard.openai.max_tokens, 32000);
     }
 
+    #[test]
+    fn test_openai_config_expands_env_vars() {
+        let (lua, config) = make_lua_with_providers();
+        lua.load(r#"quorum.providers.openai({ base_url = "${QUORUM_TEST_UNSET_URL:-https://proxy.local}/v1" })"#)
+            .exec()
+            .unwrap();
+        assert_eq!(
+            config.lock().unwrap().openai.base_url,
+            "https://proxy.local/v1"
+        );
+
+        let err = lua
+            .load(r#"quorum.providers.openai({ api_key = "${QUORUM_TEST_UNSET_KEY}" })"#)
+            .exec()
+            .unwrap_err();
+        assert!(err.to_string().contains("QUORUM_TEST_UNSET_KEY"), "{}", err);
+        assert!(config.lock().unwrap().openai.api_key.is_none());
+    }
+
     #[test]
     fn test_multiple_calls_compound() {
         let (lua, config) = make_lua_with_providers();
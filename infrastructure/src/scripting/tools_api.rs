@@ -14,10 +14,23 @@
 //! })
 //! ```
 //!
+//! Instead of a `command`, a tool may supply a Lua `handler` that receives the
+//! arguments table and returns the tool output as a string:
+//!
+//! ```lua
+//! quorum.tools.register("shout", {
+//!     description = "Upper-case a message",
+//!     risk_level = "low",
+//!     parameters = { message = { type = "string", required = true } },
+//!     handler = function(args) return args.message:upper() end,
+//! })
+//! ```
+//!
 //! Registered tools are discovered by `ScriptingEnginePort::registered_custom_tools()`
 //! and merged into the executor's tool spec at startup.
 
 use mlua::prelude::*;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use quorum_application::ports::scripting_engine::{CustomToolDef, CustomToolParam};
@@ -27,19 +40,26 @@ pub fn register_tools_api(
     lua: &Lua,
     quorum_table: &LuaTable,
     pending_tools: Arc<Mutex<Vec<CustomToolDef>>>,
+    tool_handlers: Arc<Mutex<HashMap<String, LuaRegistryKey>>>,
 ) -> LuaResult<()> {
     let tools = lua.create_table()?;
 
     let pt = pending_tools.clone();
     tools.set(
         "register",
-        lua.create_function(move |_, (name, opts): (String, LuaTable)| {
+        lua.create_function(move |lua, (name, opts): (String, LuaTable)| {
             if name.is_empty() {
                 return Err(LuaError::external("tool name cannot be empty"));
             }
 
             let description: String = opts.get("description")?;
-            let command: String = opts.get("command")?;
+            let handler: Option<LuaFunction> = opts.get("handler")?;
+            let command: String = match handler {
+                Some(_) => String::new(),
+                None => opts.get("command").map_err(|_| {
+                    LuaError::external("tool opts must include 'command' or 'handler'")
+                })?,
+            };
             let risk_level: String = opts
                 .get("risk_level")
                 .unwrap_or_else(|_| "high".to_string());
@@ -68,8 +88,21 @@ pub fn register_tools_api(
                 command,
                 risk_level,
                 parameters: params,
+                handler: handler.is_some(),
             };
 
+            let mut handlers = tool_handlers
+                .lock()
+                .map_err(|e| LuaError::external(format!("tool_handlers lock poisoned: {}", e)))?;
+            match handler {
+                Some(func) => {
+                    handlers.insert(tool_def.name.clone(), lua.create_registry_value(func)?);
+                }
+                None => {
+                    handlers.remove(&tool_def.name);
+                }
+            }
+
             pt.lock()
                 .map_err(|e| LuaError::external(format!("pending_tools lock poisoned: {}", e)))?
                 .push(tool_def);
@@ -81,6 +114,34 @@ pub fn register_tools_api(
     Ok(())
 }
 
+/// Convert a JSON value (tool call arguments) into a Lua value.
+pub fn json_to_lua(lua: &Lua, value: &serde_json::Value) -> LuaResult<LuaValue> {
+    use serde_json::Value;
+    Ok(match value {
+        Value::Null => LuaValue::Nil,
+        Value::Bool(b) => LuaValue::Boolean(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => LuaValue::Integer(i),
+            None => LuaValue::Number(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => LuaValue::String(lua.create_string(s)?),
+        Value::Array(items) => {
+            let table = lua.create_table()?;
+            for (i, item) in items.iter().enumerate() {
+                table.set(i + 1, json_to_lua(lua, item)?)?;
+            }
+            LuaValue::Table(table)
+        }
+        Value::Object(map) => {
+            let table = lua.create_table()?;
+            for (key, item) in map {
+                table.set(key.as_str(), json_to_lua(lua, item)?)?;
+            }
+            LuaValue::Table(table)
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,13 +150,17 @@ mod tests {
         Arc::new(Mutex::new(Vec::new()))
     }
 
+    fn make_handlers() -> Arc<Mutex<HashMap<String, LuaRegistryKey>>> {
+        Arc::new(Mutex::new(HashMap::new()))
+    }
+
     #[test]
     fn test_register_simple_tool() {
         let lua = Lua::new();
         let quorum = lua.create_table().unwrap();
         let pending = make_pending();
 
-        register_tools_api(&lua, &quorum, Arc::clone(&pending)).unwrap();
+        register_tools_api(&lua, &quorum, Arc::clone(&pending), make_handlers()).unwrap();
         lua.globals().set("quorum", &quorum).unwrap();
 
         lua.load(
@@ -131,7 +196,7 @@ mod tests {
         let quorum = lua.create_table().unwrap();
         let pending = make_pending();
 
-        register_tools_api(&lua, &quorum, Arc::clone(&pending)).unwrap();
+        register_tools_api(&lua, &quorum, Arc::clone(&pending), make_handlers()).unwrap();
         lua.globals().set("quorum", &quorum).unwrap();
 
         lua.load(
@@ -156,7 +221,7 @@ mod tests {
         let quorum = lua.create_table().unwrap();
         let pending = make_pending();
 
-        register_tools_api(&lua, &quorum, pending).unwrap();
+        register_tools_api(&lua, &quorum, pending, make_handlers()).unwrap();
         lua.globals().set("quorum", &quorum).unwrap();
 
         let result = lua
@@ -178,7 +243,7 @@ mod tests {
         let quorum = lua.create_table().unwrap();
         let pending = make_pending();
 
-        register_tools_api(&lua, &quorum, Arc::clone(&pending)).unwrap();
+        register_tools_api(&lua, &quorum, Arc::clone(&pending), make_handlers()).unwrap();
         lua.globals().set("quorum", &quorum).unwrap();
 
         lua.load(
@@ -209,7 +274,7 @@ mod tests {
         let quorum = lua.create_table().unwrap();
         let pending = make_pending();
 
-        register_tools_api(&lua, &quorum, Arc::clone(&pending)).unwrap();
+        register_tools_api(&lua, &quorum, Arc::clone(&pending), make_handlers()).unwrap();
         lua.globals().set("quorum", &quorum).unwrap();
 
         lua.load(
@@ -246,7 +311,7 @@ mod tests {
         let quorum = lua.create_table().unwrap();
         let pending = make_pending();
 
-        register_tools_api(&lua, &quorum, Arc::clone(&pending)).unwrap();
+        register_tools_api(&lua, &quorum, Arc::clone(&pending), make_handlers()).unwrap();
         lua.globals().set("quorum", &quorum).unwrap();
 
         lua.load(
@@ -267,4 +332,50 @@ mod tests {
         assert_eq!(tools[0].parameters[0].param_type, "string");
         assert!(!tools[0].parameters[0].required);
     }
+
+    #[test]
+    fn test_register_tool_with_handler() {
+        let lua = Lua::new();
+        let quorum = lua.create_table().unwrap();
+        let pending = make_pending();
+        let handlers = make_handlers();
+
+        register_tools_api(&lua, &quorum, Arc::clone(&pending), Arc::clone(&handlers)).unwrap();
+        lua.globals().set("quorum", &quorum).unwrap();
+
+        lua.load(
+            r#"
+            quorum.tools.register("shout", {
+                description = "Upper-case a message",
+                handler = function(args) return args.message:upper() end,
+            })
+        "#,
+        )
+        .exec()
+        .unwrap();
+
+        let tools = pending.lock().unwrap();
+        assert!(tools[0].handler);
+        assert!(tools[0].command.is_empty());
+        assert_eq!(tools[0].risk_level, "high");
+
+        let handlers = handlers.lock().unwrap();
+        let func: LuaFunction = lua.registry_value(&handlers["shout"]).unwrap();
+        let args = json_to_lua(&lua, &serde_json::json!({ "message": "hi" })).unwrap();
+        assert_eq!(func.call::<String>(args).unwrap(), "HI");
+    }
+
+    #[test]
+    fn test_register_tool_without_command_or_handler_errors() {
+        let lua = Lua::new();
+        let quorum = lua.create_table().unwrap();
+
+        register_tools_api(&lua, &quorum, make_pending(), make_handlers()).unwrap();
+        lua.globals().set("quorum", &quorum).unwrap();
+
+        let result = lua
+            .load(r#"quorum.tools.register("nothing", { description = "Nothing" })"#)
+            .exec();
+        assert!(result.is_err());
+    }
 }
@@ -15,6 +15,7 @@
 //! quorum.tui.layout.register_preset("my_layout", {
 //!     splits = {40, 30, 30},
 //!     direction = "horizontal",
+//!     slots = {"conversation", "progress", "tool_log"},  -- optional
 //! })
 //! quorum.tui.layout.presets()                   --> {"default", "minimal", ...}
 //!
@@ -173,8 +174,13 @@ fn register_layout_api(
                 let direction: String = config_table
                     .get("direction")
                     .unwrap_or_else(|_| "horizontal".to_string());
+                let slots = parse_slots(&config_table)?;
 
-                let config = CustomPresetConfig { splits, direction };
+                let config = CustomPresetConfig {
+                    splits,
+                    direction,
+                    slots,
+                };
 
                 let mut guard = accessor.lock().map_err(|e| {
                     LuaError::external(format!("tui_accessor lock poisoned: {}", e))
@@ -306,6 +312,14 @@ fn parse_splits(config_table: &LuaTable) -> LuaResult<Vec<u16>> {
     Ok(splits)
 }
 
+/// Parse the optional `slots` field: content slot names in pane order.
+fn parse_slots(config_table: &LuaTable) -> LuaResult<Vec<String>> {
+    match config_table.get::<Option<LuaTable>>("slots")? {
+        Some(slots_table) => slots_table.sequence_values::<String>().collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -467,6 +481,31 @@ mod tests {
         assert_eq!(result, "triple");
     }
 
+    #[test]
+    fn test_layout_register_preset_with_slots() {
+        let (lua, accessor) = setup();
+        register(&lua, Arc::clone(&accessor)).unwrap();
+
+        lua.load(
+            r#"
+            quorum.tui.layout.register_preset("review", {
+                splits = {30, 70},
+                direction = "vertical",
+                slots = {"progress", "conversation"},
+            })
+        "#,
+        )
+        .exec()
+        .unwrap();
+
+        let changes = accessor.lock().unwrap().take_pending_changes();
+        let (name, config) = &changes.new_presets[0];
+        assert_eq!(name, "review");
+        assert_eq!(config.splits, vec![30, 70]);
+        assert_eq!(config.direction, "vertical");
+        assert_eq!(config.slots, vec!["progress", "conversation"]);
+    }
+
     #[test]
     fn test_layout_presets_includes_custom() {
         let (lua, accessor) = setup();
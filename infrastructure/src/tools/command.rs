@@ -2,17 +2,15 @@
 
 use quorum_domain::tool::{
     entities::{RiskLevel, ToolCall, ToolDefinition, ToolParameter},
+    timeouts::DEFAULT_TOOL_TIMEOUT_SECS,
     value_objects::{ToolError, ToolResult, ToolResultMetadata},
 };
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, Output, Stdio};
 use std::time::{Duration, Instant};
 
 /// Tool name constant
 pub const RUN_COMMAND: &str = "run_command";
 
-/// Default timeout for command execution (60 seconds)
-const DEFAULT_TIMEOUT_SECS: u64 = 60;
-
 /// Maximum output size (1 MB)
 const MAX_OUTPUT_SIZE: usize = 1024 * 1024;
 
@@ -31,8 +29,12 @@ pub fn run_command_definition() -> ToolDefinition {
             .with_type("path"),
     )
     .with_parameter(
-        ToolParameter::new("timeout_secs", "Timeout in seconds (default: 60)", false)
-            .with_type("number"),
+        ToolParameter::new(
+            "timeout_secs",
+            "Timeout in seconds (default and maximum: the configured tool timeout)",
+            false,
+        )
+        .with_type("number"),
     )
 }
 
@@ -52,18 +54,9 @@ pub fn execute_run_command(call: &ToolCall) -> ToolResult {
     // Get timeout
     let timeout_secs = call
         .get_i64("timeout_secs")
-        .unwrap_or(DEFAULT_TIMEOUT_SECS as i64) as u64;
+        .unwrap_or(DEFAULT_TOOL_TIMEOUT_SECS as i64) as u64;
 
-    // Build the command
-    let mut cmd = if cfg!(target_os = "windows") {
-        let mut c = Command::new("cmd");
-        c.args(["/C", command_str]);
-        c
-    } else {
-        let mut c = Command::new("sh");
-        c.args(["-c", command_str]);
-        c
-    };
+    let mut cmd = shell_command(command_str);
 
     // Set working directory if specified
     if let Some(dir) = working_dir {
@@ -83,10 +76,6 @@ pub fn execute_run_command(call: &ToolCall) -> ToolResult {
         cmd.current_dir(path);
     }
 
-    // Configure stdio
-    cmd.stdout(Stdio::piped());
-    cmd.stderr(Stdio::piped());
-
     // Spawn the process
     let child = match cmd.spawn() {
         Ok(c) => c,
@@ -98,18 +87,24 @@ pub fn execute_run_command(call: &ToolCall) -> ToolResult {
         }
     };
 
-    // Wait for the command with timeout
+    // Wait for the command with timeout (the process group is killed on expiry)
     let output = match wait_with_timeout(child, Duration::from_secs(timeout_secs)) {
-        Ok(o) => o,
-        Err(e) => {
+        Ok(Some(o)) => o,
+        Ok(None) => {
             return ToolResult::failure(
                 RUN_COMMAND,
                 ToolError::timeout(format!(
-                    "Command timed out after {} seconds: {}",
-                    timeout_secs, e
+                    "`{}` exceeded {} seconds and was killed",
+                    command_str, timeout_secs
                 )),
             );
         }
+        Err(e) => {
+            return ToolResult::failure(
+                RUN_COMMAND,
+                ToolError::execution_failed(format!("Failed to wait for process: {}", e)),
+            );
+        }
     };
 
     let duration_ms = start.elapsed().as_millis() as u64;
@@ -161,17 +156,45 @@ pub fn execute_run_command(call: &ToolCall) -> ToolResult {
     }
 }
 
-/// Wait for a child process with timeout
-fn wait_with_timeout(
-    mut child: std::process::Child,
+/// Build a shell invocation of `command_str` ready to spawn.
+///
+/// stdin is closed so a command that prompts for input fails fast instead of
+/// hanging, stdout/stderr are piped, and (on Linux) the shell leads its own
+/// process group so [`wait_with_timeout`] can kill everything it started.
+pub(crate) fn shell_command(command_str: &str) -> Command {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command_str]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", command_str]);
+        c
+    };
+
+    cmd.stdin(Stdio::null());
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    #[cfg(target_os = "linux")]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+    cmd
+}
+
+/// Wait for a child process with timeout.
+///
+/// Returns `Ok(None)` when `timeout` elapsed; the child (and, on Linux, its
+/// whole process group) has then been killed and reaped.
+pub(crate) fn wait_with_timeout(
+    mut child: Child,
     timeout: Duration,
-) -> Result<std::process::Output, String> {
-    // Simple implementation: try to wait and kill if timeout
+) -> std::io::Result<Option<Output>> {
     let start = Instant::now();
 
     loop {
-        match child.try_wait() {
-            Ok(Some(status)) => {
+        match child.try_wait()? {
+            Some(status) => {
                 // Process has exited
                 let stdout = child
                     .stdout
@@ -193,30 +216,37 @@ fn wait_with_timeout(
                     })
                     .unwrap_or_default();
 
-                return Ok(std::process::Output {
+                return Ok(Some(Output {
                     status,
                     stdout,
                     stderr,
-                });
+                }));
             }
-            Ok(None) => {
+            None => {
                 // Process still running
                 if start.elapsed() > timeout {
-                    // Kill the process
-                    let _ = child.kill();
-                    let _ = child.wait();
-                    return Err("Command timed out".to_string());
+                    kill_process_tree(&mut child);
+                    return Ok(None);
                 }
                 // Sleep a bit before checking again
                 std::thread::sleep(Duration::from_millis(100));
             }
-            Err(e) => {
-                return Err(format!("Failed to wait for process: {}", e));
-            }
         }
     }
 }
 
+/// Kill `child` and anything it spawned, then reap it so no zombie is left.
+fn kill_process_tree(child: &mut Child) {
+    // The child leads its own process group (see `shell_command`), so a
+    // negative pid signals the shell together with its descendants.
+    #[cfg(target_os = "linux")]
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,6 +298,75 @@ mod tests {
         assert_eq!(result.error().unwrap().code, "NOT_FOUND");
     }
 
+    #[test]
+    fn test_run_command_timeout_kills_process() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let pid_file = temp_dir.path().join("pid");
+        // `exec` keeps the shell's pid, so the recorded pid is our direct child
+        let call = ToolCall::new(RUN_COMMAND)
+            .with_arg(
+                "command",
+                format!("echo $$ > {}; exec sleep 30", pid_file.display()).as_str(),
+            )
+            .with_arg("timeout_secs", 1i64);
+
+        let start = Instant::now();
+        let result = execute_run_command(&call);
+
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert!(!result.is_success());
+        let error = result.error().unwrap();
+        assert_eq!(error.code, "TIMEOUT");
+        assert_eq!(
+            quorum_domain::ErrorCategory::from_error_code(&error.code),
+            quorum_domain::ErrorCategory::ExecutionError
+        );
+        assert!(error.message.contains("exceeded 1 seconds"));
+
+        // Killed and reaped: no zombie left behind under /proc
+        #[cfg(target_os = "linux")]
+        {
+            let pid = std::fs::read_to_string(&pid_file).unwrap();
+            assert!(!std::path::Path::new(&format!("/proc/{}", pid.trim())).exists());
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_run_command_timeout_kills_background_children() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let pid_file = temp_dir.path().join("pid");
+        let call = ToolCall::new(RUN_COMMAND)
+            .with_arg(
+                "command",
+                format!("sleep 30 & echo $! > {}; wait", pid_file.display()).as_str(),
+            )
+            .with_arg("timeout_secs", 1i64);
+
+        let result = execute_run_command(&call);
+        assert_eq!(result.error().unwrap().code, "TIMEOUT");
+
+        // The grandchild was in the shell's process group, so it got SIGKILL
+        // too; it may linger briefly as a zombie until its new parent reaps it.
+        let pid = std::fs::read_to_string(&pid_file).unwrap();
+        let stat = format!("/proc/{}/stat", pid.trim());
+        let running = std::fs::read_to_string(&stat)
+            .map(|s| !s.contains(") Z "))
+            .unwrap_or(false);
+        assert!(!running, "background child still running");
+    }
+
+    #[test]
+    fn test_run_command_stdin_is_closed() {
+        // `cat` with no file argument reads stdin: it must see EOF, not hang
+        let call = ToolCall::new(RUN_COMMAND)
+            .with_arg("command", "cat")
+            .with_arg("timeout_secs", 5i64);
+        let result = execute_run_command(&call);
+
+        assert!(result.is_success());
+    }
+
     #[test]
     fn test_run_command_missing_command() {
         let call = ToolCall::new(RUN_COMMAND);
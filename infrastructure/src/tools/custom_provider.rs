@@ -1,7 +1,8 @@
 //! Custom tool provider — user-defined CLI commands as tools.
 //!
 //! Custom tool definitions come from Lua scripting (`quorum.tools.register()`).
-//! Each custom tool wraps a shell command template with `{param_name}` placeholders.
+//! Each custom tool wraps a shell command template with `{param_name}` placeholders,
+//! or is dispatched back to its Lua `handler` through the [`ScriptingEnginePort`].
 //!
 //! # Security
 //!
@@ -13,13 +14,16 @@ use async_trait::async_trait;
 use quorum_domain::tool::{
     entities::{RiskLevel, ToolCall, ToolDefinition, ToolParameter},
     provider::{ProviderError, ToolProvider},
+    timeouts::ToolTimeouts,
     value_objects::{ToolError, ToolResult, ToolResultMetadata},
 };
 use std::collections::HashMap;
-use std::process::Command;
+use std::sync::Arc;
 use std::time::Instant;
 
-use quorum_application::ports::scripting_engine::CustomToolDef;
+use quorum_application::ports::scripting_engine::{CustomToolDef, ScriptingEnginePort};
+
+use super::command::{shell_command, wait_with_timeout};
 
 /// Priority for the custom tool provider (between CLI and MCP)
 pub const CUSTOM_PRIORITY: i32 = 75;
@@ -34,16 +38,33 @@ struct CustomTool {
     definition: ToolDefinition,
     /// Command template with `{param_name}` placeholders
     command_template: String,
+    /// Run the Lua handler instead of `command_template`
+    handler: bool,
 }
 
 /// Provider for user-defined custom tools (registered via Lua).
 ///
 /// Custom tools are shell commands with typed parameters. The provider
 /// handles parameter substitution (with escaping) and execution.
-#[derive(Debug, Clone)]
+/// Handler-backed tools are called through the scripting engine set with
+/// [`with_script_engine()`](Self::with_script_engine).
+#[derive(Clone)]
 pub struct CustomToolProvider {
     tools: HashMap<String, CustomTool>,
     working_dir: Option<String>,
+    timeouts: ToolTimeouts,
+    script_engine: Option<Arc<dyn ScriptingEnginePort>>,
+}
+
+impl std::fmt::Debug for CustomToolProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomToolProvider")
+            .field("tools", &self.tools)
+            .field("working_dir", &self.working_dir)
+            .field("timeouts", &self.timeouts)
+            .field("script_engine", &self.script_engine.is_some())
+            .finish()
+    }
 }
 
 impl CustomToolProvider {
@@ -76,6 +97,7 @@ impl CustomToolProvider {
                 CustomTool {
                     definition,
                     command_template: def.command.clone(),
+                    handler: def.handler,
                 },
             );
         }
@@ -83,15 +105,29 @@ impl CustomToolProvider {
         Self {
             tools,
             working_dir: None,
+            timeouts: ToolTimeouts::default(),
+            script_engine: None,
         }
     }
 
+    /// Set the scripting engine that runs Lua `handler` tools.
+    pub fn with_script_engine(mut self, engine: Arc<dyn ScriptingEnginePort>) -> Self {
+        self.script_engine = Some(engine);
+        self
+    }
+
     /// Set the working directory for command execution.
     pub fn with_working_dir(mut self, dir: impl Into<String>) -> Self {
         self.working_dir = Some(dir.into());
         self
     }
 
+    /// Set the time limits; a command exceeding its tool's limit is killed.
+    pub fn with_timeouts(mut self, timeouts: ToolTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
     /// Build the final command string by substituting parameters.
     ///
     /// `{param_name}` placeholders are replaced with shell-escaped argument values.
@@ -149,19 +185,64 @@ impl CustomToolProvider {
         result
     }
 
+    /// Execute a handler-backed tool through the scripting engine.
+    ///
+    /// The engine aborts a handler that overruns the tool timeout, which
+    /// frees the Lua state. The handler also runs on the blocking pool, so
+    /// the call still times out if it cannot even get the Lua state.
+    async fn execute_handler(&self, call: &ToolCall) -> ToolResult {
+        let Some(engine) = self.script_engine.clone() else {
+            return ToolResult::failure(
+                &call.tool_name,
+                ToolError::execution_failed("Lua tool handlers are not available"),
+            );
+        };
+        let start = Instant::now();
+        let args_json = serde_json::to_string(&call.arguments).unwrap_or_default();
+        let tool_name = call.tool_name.clone();
+        let timeout = self.timeouts.timeout_for(&call.tool_name);
+
+        let handler = tokio::task::spawn_blocking(move || {
+            engine.call_tool_handler(&tool_name, &args_json, timeout)
+        });
+        let outcome = match tokio::time::timeout(timeout, handler).await {
+            Ok(joined) => joined,
+            Err(_) => {
+                return ToolResult::failure(
+                    &call.tool_name,
+                    ToolError::timeout(format!(
+                        "Lua handler for `{}` exceeded {} seconds",
+                        call.tool_name,
+                        self.timeouts.secs_for(&call.tool_name)
+                    )),
+                );
+            }
+        };
+
+        match outcome {
+            Ok(Ok(output)) => {
+                let metadata = ToolResultMetadata {
+                    duration_ms: Some(start.elapsed().as_millis() as u64),
+                    bytes: Some(output.len()),
+                    ..Default::default()
+                };
+                ToolResult::success(&call.tool_name, output).with_metadata(metadata)
+            }
+            Ok(Err(e)) => {
+                ToolResult::failure(&call.tool_name, ToolError::execution_failed(e.message))
+            }
+            Err(e) => ToolResult::failure(
+                &call.tool_name,
+                ToolError::execution_failed(format!("Lua handler panicked: {}", e)),
+            ),
+        }
+    }
+
     /// Execute a custom tool command.
     fn execute_command(&self, tool_name: &str, command_str: &str) -> ToolResult {
         let start = Instant::now();
 
-        let mut cmd = if cfg!(target_os = "windows") {
-            let mut c = Command::new("cmd");
-            c.args(["/C", command_str]);
-            c
-        } else {
-            let mut c = Command::new("sh");
-            c.args(["-c", command_str]);
-            c
-        };
+        let mut cmd = shell_command(command_str);
 
         // Set working directory if specified
         if let Some(dir) = &self.working_dir {
@@ -171,11 +252,21 @@ impl CustomToolProvider {
             }
         }
 
-        cmd.stdout(std::process::Stdio::piped());
-        cmd.stderr(std::process::Stdio::piped());
-
-        let output = match cmd.output() {
-            Ok(output) => output,
+        let timeout_secs = self.timeouts.secs_for(tool_name);
+        let output = match cmd
+            .spawn()
+            .and_then(|child| wait_with_timeout(child, self.timeouts.timeout_for(tool_name)))
+        {
+            Ok(Some(output)) => output,
+            Ok(None) => {
+                return ToolResult::failure(
+                    tool_name,
+                    ToolError::timeout(format!(
+                        "`{}` exceeded {} seconds and was killed",
+                        command_str, timeout_secs
+                    )),
+                );
+            }
             Err(e) => {
                 return ToolResult::failure(
                     tool_name,
@@ -334,6 +425,10 @@ impl ToolProvider for CustomToolProvider {
             }
         }
 
+        if tool.handler {
+            return self.execute_handler(call).await;
+        }
+
         let command_str = self.build_command(&tool.command_template, call);
         self.execute_command(&call.tool_name, &command_str)
     }
@@ -342,7 +437,10 @@ impl ToolProvider for CustomToolProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use quorum_application::ports::scripting_engine::CustomToolParam;
+    use quorum_application::ports::scripting_engine::{
+        CustomToolParam, EventOutcome, KeymapAction, ScriptError,
+    };
+    use quorum_domain::scripting::{ScriptEventData, ScriptEventType};
 
     fn make_def(
         name: &str,
@@ -365,6 +463,7 @@ mod tests {
                     required: r,
                 })
                 .collect(),
+            handler: false,
         }
     }
 
@@ -537,6 +636,73 @@ mod tests {
         assert!(names.contains(&"tool_b"));
     }
 
+    #[tokio::test]
+    async fn test_provider_execute_times_out() {
+        let defs = vec![make_def("slow", "Slow tool", "sleep 30", "low", vec![])];
+        let provider = CustomToolProvider::from_custom_tool_defs(&defs)
+            .with_timeouts(ToolTimeouts::default().with_tool_timeout("slow", 1));
+
+        let start = Instant::now();
+        let result = provider.execute(&ToolCall::new("slow")).await;
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+        assert_eq!(result.error().unwrap().code, "TIMEOUT");
+    }
+
+    /// Scripting engine whose tool handler never returns in time.
+    struct StuckHandlerEngine;
+
+    impl ScriptingEnginePort for StuckHandlerEngine {
+        fn emit_event(
+            &self,
+            _event: ScriptEventType,
+            _data: ScriptEventData,
+        ) -> Result<EventOutcome, ScriptError> {
+            Ok(EventOutcome::Continue)
+        }
+
+        fn load_script(&self, _path: &std::path::Path) -> Result<(), ScriptError> {
+            Ok(())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn registered_keymaps(&self) -> Vec<(String, String, KeymapAction)> {
+            Vec::new()
+        }
+
+        fn execute_callback(&self, _callback_id: u64) -> Result<(), ScriptError> {
+            Ok(())
+        }
+
+        fn call_tool_handler(
+            &self,
+            _tool_name: &str,
+            _args_json: &str,
+            _timeout: std::time::Duration,
+        ) -> Result<String, ScriptError> {
+            std::thread::sleep(std::time::Duration::from_secs(3));
+            Ok("too late".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_provider_handler_times_out() {
+        let mut def = make_def("stuck", "Stuck handler", "", "low", vec![]);
+        def.handler = true;
+        let provider = CustomToolProvider::from_custom_tool_defs(&[def])
+            .with_script_engine(Arc::new(StuckHandlerEngine))
+            .with_timeouts(ToolTimeouts::default().with_tool_timeout("stuck", 1));
+
+        let start = Instant::now();
+        let result = provider.execute(&ToolCall::new("stuck")).await;
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(3));
+        assert_eq!(result.error().unwrap().code, "TIMEOUT");
+    }
+
     #[tokio::test]
     async fn test_provider_execute_echo() {
         let defs = vec![make_def(
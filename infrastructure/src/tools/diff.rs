@@ -0,0 +1,210 @@
+//! Line-based unified diff for `write_file` results.
+//!
+//! Produces the familiar `--- / +++ / @@` format with 3 lines of context.
+//! Common leading/trailing lines are stripped first; the remaining middle is
+//! diffed with an LCS table, falling back to "replace everything" when the
+//! middle is too large for the table.
+
+/// Lines of unchanged context around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// Upper bound on LCS table cells (old × new lines) before falling back.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// One line of the edit script, with the old/new line index it sits at.
+#[derive(Debug, Clone, Copy)]
+struct Edit {
+    op: Op,
+    old_index: usize,
+    new_index: usize,
+}
+
+/// Unified diff from `old` to `new` for the file at `path`.
+///
+/// `old` is `None` for a newly created file, which diffs against `/dev/null`.
+/// Returns `None` when the contents are identical.
+pub(crate) fn unified_diff(path: &str, old: Option<&str>, new: &str) -> Option<String> {
+    let old_lines: Vec<&str> = old.map(|s| s.lines().collect()).unwrap_or_default();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    let edits = edit_script(&old_lines, &new_lines);
+    if edits.iter().all(|e| e.op == Op::Equal) {
+        return None;
+    }
+
+    let mut out = String::new();
+    match old {
+        Some(_) => out.push_str(&format!("--- a/{}\n", path)),
+        None => out.push_str("--- /dev/null\n"),
+    }
+    out.push_str(&format!("+++ b/{}\n", path));
+
+    for (start, end) in hunk_ranges(&edits) {
+        let hunk = &edits[start..end];
+        let old_count = hunk.iter().filter(|e| e.op != Op::Insert).count();
+        let new_count = hunk.iter().filter(|e| e.op != Op::Delete).count();
+        // Unified diff numbers lines from 1; an empty range names the line before it.
+        let old_start = hunk[0].old_index + usize::from(old_count > 0);
+        let new_start = hunk[0].new_index + usize::from(new_count > 0);
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start, old_count, new_start, new_count
+        ));
+        for edit in hunk {
+            let (marker, line) = match edit.op {
+                Op::Equal => (' ', old_lines[edit.old_index]),
+                Op::Delete => ('-', old_lines[edit.old_index]),
+                Op::Insert => ('+', new_lines[edit.new_index]),
+            };
+            out.push(marker);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+
+    Some(out)
+}
+
+/// Shortest-ish edit script turning `old` into `new`.
+fn edit_script(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut edits = Vec::with_capacity(old.len() + new.len());
+    for i in 0..prefix {
+        edits.push(Edit {
+            op: Op::Equal,
+            old_index: i,
+            new_index: i,
+        });
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut push = |op: Op, i: usize, j: usize| {
+        edits.push(Edit {
+            op,
+            old_index: prefix + i,
+            new_index: prefix + j,
+        });
+    };
+    if old_mid.len().saturating_mul(new_mid.len()) <= MAX_LCS_CELLS {
+        // lcs[i][j] = LCS length of old_mid[i..] and new_mid[j..]
+        let width = new_mid.len() + 1;
+        let mut lcs = vec![0u32; (old_mid.len() + 1) * width];
+        for i in (0..old_mid.len()).rev() {
+            for j in (0..new_mid.len()).rev() {
+                lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+        while i < old_mid.len() && j < new_mid.len() {
+            if old_mid[i] == new_mid[j] {
+                push(Op::Equal, i, j);
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                push(Op::Delete, i, j);
+                i += 1;
+            } else {
+                push(Op::Insert, i, j);
+                j += 1;
+            }
+        }
+    }
+    while i < old_mid.len() {
+        push(Op::Delete, i, j);
+        i += 1;
+    }
+    while j < new_mid.len() {
+        push(Op::Insert, i, j);
+        j += 1;
+    }
+
+    let (old_end, new_end) = (old.len() - suffix, new.len() - suffix);
+    for k in 0..suffix {
+        edits.push(Edit {
+            op: Op::Equal,
+            old_index: old_end + k,
+            new_index: new_end + k,
+        });
+    }
+    edits
+}
+
+/// `[start, end)` ranges of `edits` forming hunks: each change plus up to
+/// [`CONTEXT_LINES`] on either side, merging changes whose context overlaps.
+fn hunk_ranges(edits: &[Edit]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (index, _) in edits.iter().enumerate().filter(|(_, e)| e.op != Op::Equal) {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + 1 + CONTEXT_LINES).min(edits.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_file_is_all_additions() {
+        let diff = unified_diff("notes.txt", None, "one\ntwo\n").unwrap();
+        assert_eq!(
+            diff,
+            "--- /dev/null\n+++ b/notes.txt\n@@ -0,0 +1,2 @@\n+one\n+two\n"
+        );
+    }
+
+    #[test]
+    fn test_modified_line_has_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\n";
+        let diff = unified_diff("x.txt", Some(old), new).unwrap();
+        assert_eq!(
+            diff,
+            "--- a/x.txt\n+++ b/x.txt\n@@ -2,7 +2,7 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n"
+        );
+    }
+
+    #[test]
+    fn test_distant_changes_produce_separate_hunks() {
+        let old: String = (1..=20).map(|n| format!("{}\n", n)).collect();
+        let new = old.replace("2\n", "two\n").replace("19\n", "nineteen\n");
+        let diff = unified_diff("n.txt", Some(&old), &new).unwrap();
+        assert_eq!(diff.matches("@@ -").count(), 2, "{}", diff);
+        assert!(diff.contains("-2\n+two\n"));
+        assert!(diff.contains("-19\n+nineteen\n"));
+    }
+
+    #[test]
+    fn test_identical_contents_have_no_diff() {
+        assert_eq!(unified_diff("x", Some("same\n"), "same\n"), None);
+    }
+
+    #[test]
+    fn test_emptying_a_file() {
+        let diff = unified_diff("x", Some("gone\n"), "").unwrap();
+        assert!(diff.ends_with("@@ -1,1 +0,0 @@\n-gone\n"), "{}", diff);
+    }
+}
@@ -0,0 +1,265 @@
+//! Dry-run tool executor — previews high-risk tool calls without running them.
+//!
+//! [`DryRunToolExecutor`] decorates another [`ToolExecutorPort`]. Low-risk
+//! calls (reads, searches, read-only commands) are forwarded to the inner
+//! executor so the agent still sees accurate context, while high-risk calls
+//! return a synthetic success describing what *would* have happened.
+//!
+//! ```text
+//! DryRunToolExecutor::execute(call)
+//!   ├─ risk == Low  → inner.execute(call)
+//!   └─ risk == High → ToolResult::success("[dry-run] ...")  (metadata.dry_run = true)
+//! ```
+//!
+//! Risk is classified the same way as action review: `run_command` uses
+//! [`classify_command_risk_with_overrides`] on the actual command (with the
+//! configured `tools.risk_overrides`), other tools use their
+//! [`ToolDefinition`](quorum_domain::ToolDefinition) risk level, and unknown
+//! tools are treated as high-risk.
+
+use async_trait::async_trait;
+use quorum_application::ports::tool_executor::ToolExecutorPort;
+use quorum_domain::tool::{
+    entities::{RiskLevel, ToolCall, ToolSpec, classify_command_risk_with_overrides},
+    value_objects::{ToolResult, ToolResultMetadata},
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::command::RUN_COMMAND;
+use super::file::WRITE_FILE;
+
+/// [`ToolExecutorPort`] decorator that skips high-risk tool calls.
+pub struct DryRunToolExecutor {
+    inner: Arc<dyn ToolExecutorPort>,
+    risk_overrides: HashMap<String, RiskLevel>,
+}
+
+impl DryRunToolExecutor {
+    pub fn new(inner: Arc<dyn ToolExecutorPort>) -> Self {
+        Self {
+            inner,
+            risk_overrides: HashMap::new(),
+        }
+    }
+
+    /// Classify `run_command` calls with these command prefix overrides.
+    pub fn with_risk_overrides(mut self, overrides: HashMap<String, RiskLevel>) -> Self {
+        self.risk_overrides = overrides;
+        self
+    }
+
+    /// Effective risk of `call`, taking the command into account for `run_command`.
+    fn risk_level(&self, call: &ToolCall) -> RiskLevel {
+        if call.tool_name == RUN_COMMAND {
+            return match call.get_string("command") {
+                Some(command) => {
+                    classify_command_risk_with_overrides(command, &self.risk_overrides)
+                }
+                None => RiskLevel::High,
+            };
+        }
+        self.inner
+            .get_tool(&call.tool_name)
+            .map_or(RiskLevel::High, |definition| definition.risk_level)
+    }
+
+    /// Synthetic result describing the skipped call.
+    fn preview(call: &ToolCall) -> ToolResult {
+        let (description, path) = match call.tool_name.as_str() {
+            WRITE_FILE => {
+                let path = call.get_string("path").unwrap_or("<unknown>");
+                let bytes = call.get_string("content").map_or(0, str::len);
+                (
+                    format!("would write {} bytes to {}", bytes, path),
+                    Some(path.to_string()),
+                )
+            }
+            RUN_COMMAND => (
+                format!(
+                    "would run `{}`",
+                    call.get_string("command").unwrap_or("<missing command>")
+                ),
+                None,
+            ),
+            name => {
+                let args = serde_json::to_string(&call.arguments).unwrap_or_default();
+                (format!("would call {} with {}", name, args), None)
+            }
+        };
+
+        ToolResult::success(
+            &call.tool_name,
+            format!("[dry-run] Not executed: {}", description),
+        )
+        .with_metadata(ToolResultMetadata {
+            path,
+            dry_run: true,
+            ..Default::default()
+        })
+    }
+}
+
+#[async_trait]
+impl ToolExecutorPort for DryRunToolExecutor {
+    fn tool_spec(&self) -> &ToolSpec {
+        self.inner.tool_spec()
+    }
+
+    async fn execute(&self, call: &ToolCall) -> ToolResult {
+        match self.risk_level(call) {
+            RiskLevel::Low => self.inner.execute(call).await,
+            RiskLevel::High => Self::preview(call),
+        }
+    }
+
+    fn execute_sync(&self, call: &ToolCall) -> ToolResult {
+        match self.risk_level(call) {
+            RiskLevel::Low => self.inner.execute_sync(call),
+            RiskLevel::High => Self::preview(call),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::default_tool_spec;
+    use std::sync::Mutex;
+
+    /// Inner executor that records dispatched tool names.
+    struct RecordingExecutor {
+        spec: ToolSpec,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl RecordingExecutor {
+        fn new() -> Self {
+            Self {
+                spec: default_tool_spec(),
+                calls: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ToolExecutorPort for RecordingExecutor {
+        fn tool_spec(&self) -> &ToolSpec {
+            &self.spec
+        }
+
+        async fn execute(&self, call: &ToolCall) -> ToolResult {
+            self.execute_sync(call)
+        }
+
+        fn execute_sync(&self, call: &ToolCall) -> ToolResult {
+            self.calls.lock().unwrap().push(call.tool_name.clone());
+            ToolResult::success(&call.tool_name, "executed")
+        }
+    }
+
+    fn dry_run() -> (Arc<RecordingExecutor>, DryRunToolExecutor) {
+        let inner = Arc::new(RecordingExecutor::new());
+        let executor = DryRunToolExecutor::new(inner.clone());
+        (inner, executor)
+    }
+
+    #[tokio::test]
+    async fn test_write_file_is_never_dispatched() {
+        let (inner, executor) = dry_run();
+        let call = ToolCall::new("write_file")
+            .with_arg("path", "src/main.rs")
+            .with_arg("content", "fn main() {}");
+
+        let result = executor.execute(&call).await;
+        let sync_result = executor.execute_sync(&call);
+
+        assert!(inner.calls().is_empty());
+        for result in [result, sync_result] {
+            assert!(result.is_success());
+            assert!(result.metadata.dry_run);
+            assert_eq!(result.metadata.path.as_deref(), Some("src/main.rs"));
+            assert!(result.output().unwrap().contains("would write 12 bytes"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_tools_still_execute() {
+        let (inner, executor) = dry_run();
+
+        let read = executor
+            .execute(&ToolCall::new("read_file").with_arg("path", "README.md"))
+            .await;
+        let search = executor
+            .execute(&ToolCall::new("grep_search").with_arg("pattern", "fn"))
+            .await;
+
+        assert_eq!(inner.calls(), vec!["read_file", "grep_search"]);
+        assert!(!read.metadata.dry_run);
+        assert!(!search.metadata.dry_run);
+    }
+
+    #[tokio::test]
+    async fn test_run_command_classified_by_command() {
+        let (inner, executor) = dry_run();
+
+        let ls = executor
+            .execute(&ToolCall::new("run_command").with_arg("command", "ls -la"))
+            .await;
+        let rm = executor
+            .execute(&ToolCall::new("run_command").with_arg("command", "rm -rf target"))
+            .await;
+
+        assert_eq!(inner.calls(), vec!["run_command"]);
+        assert!(!ls.metadata.dry_run);
+        assert!(rm.metadata.dry_run);
+        assert!(rm.output().unwrap().contains("would run `rm -rf target`"));
+    }
+
+    #[tokio::test]
+    async fn test_run_command_honours_risk_overrides() {
+        let inner = Arc::new(RecordingExecutor::new());
+        let executor = DryRunToolExecutor::new(inner.clone()).with_risk_overrides(HashMap::from([
+            ("make".to_string(), RiskLevel::Low),
+            ("git log".to_string(), RiskLevel::High),
+        ]));
+
+        let make = executor
+            .execute(&ToolCall::new("run_command").with_arg("command", "make lint"))
+            .await;
+        let log = executor
+            .execute(&ToolCall::new("run_command").with_arg("command", "git log -5"))
+            .await;
+
+        assert_eq!(inner.calls(), vec!["run_command"]);
+        assert!(!make.metadata.dry_run);
+        assert!(log.metadata.dry_run);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_tool_is_treated_as_high_risk() {
+        let (inner, executor) = dry_run();
+
+        let result = executor.execute(&ToolCall::new("deploy")).await;
+
+        assert!(inner.calls().is_empty());
+        assert!(result.metadata.dry_run);
+    }
+
+    #[test]
+    fn test_dry_run_flag_omitted_from_json_when_false() {
+        let json = serde_json::to_value(ToolResultMetadata::default()).unwrap();
+        assert!(json.get("dry_run").is_none());
+
+        let json = serde_json::to_value(ToolResultMetadata {
+            dry_run: true,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(json["dry_run"], true);
+    }
+}
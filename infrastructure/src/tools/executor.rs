@@ -8,14 +8,26 @@
 //!
 //! ```text
 //! ToolExecutorPort::execute()
+//!   ├─ validate_call() → INVALID_ARGUMENT / NOT_FOUND before anything runs
 //!   ├─ is_async_tool?  → execute_async()   (web_fetch, web_search via reqwest)
-//!   └─ otherwise       → execute_internal() (file, command, search — synchronous)
+//!   ├─ custom tool?    → CustomToolProvider::execute()
+//!   ├─ run_command     → execute_internal() (kills the process on timeout)
+//!   └─ otherwise       → spawn_blocking(execute_internal())  (file, search)
 //!
 //! ToolExecutorPort::execute_sync()
+//!   ├─ validate_call()
 //!   ├─ is_async_tool?  → block_in_place(execute_async())  (tokio bridge)
 //!   └─ otherwise       → execute_internal()
 //! ```
 //!
+//! # Timeouts
+//!
+//! Every call made through `execute()` is bounded by [`ToolTimeouts`]
+//! (`tools.default_timeout_secs` / `tools.timeouts`). Command tools
+//! (`run_command` and custom tools) kill their process group on expiry; other
+//! tools are abandoned and reported as failed. Either way the agent gets a
+//! `TIMEOUT` [`ToolError`] instead of blocking.
+//!
 //! # Web Tools (`web-tools` feature)
 //!
 //! When the `web-tools` feature is enabled, the executor holds a shared `reqwest::Client`
@@ -27,10 +39,13 @@ use quorum_domain::RiskLevel;
 use quorum_domain::tool::{
     entities::{ToolCall, ToolDefinition, ToolParameter, ToolSpec},
     provider::ToolProvider,
+    timeouts::ToolTimeouts,
+    traits::{DefaultToolValidator, ToolValidator, unknown_arguments},
     value_objects::{ToolError, ToolResult},
 };
 
-use quorum_application::ports::scripting_engine::CustomToolDef;
+use quorum_application::ports::scripting_engine::{CustomToolDef, ScriptingEnginePort};
+use std::sync::Arc;
 
 use super::{command, custom_provider::CustomToolProvider, file, search};
 
@@ -65,6 +80,8 @@ pub struct LocalToolExecutor {
     working_dir: Option<String>,
     /// Custom tool provider for user-defined tools
     custom_provider: Option<CustomToolProvider>,
+    /// Per-tool execution time limits
+    timeouts: ToolTimeouts,
     /// HTTP client for web tools (only available with web-tools feature)
     #[cfg(feature = "web-tools")]
     http_client: reqwest::Client,
@@ -80,6 +97,7 @@ impl LocalToolExecutor {
             tool_spec: super::default_tool_spec(),
             working_dir: None,
             custom_provider: None,
+            timeouts: ToolTimeouts::default(),
             #[cfg(feature = "web-tools")]
             http_client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(30))
@@ -97,6 +115,7 @@ impl LocalToolExecutor {
             tool_spec: super::read_only_tool_spec(),
             working_dir: None,
             custom_provider: None,
+            timeouts: ToolTimeouts::default(),
             #[cfg(feature = "web-tools")]
             http_client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(30))
@@ -111,6 +130,7 @@ impl LocalToolExecutor {
             tool_spec,
             working_dir: None,
             custom_provider: None,
+            timeouts: ToolTimeouts::default(),
             #[cfg(feature = "web-tools")]
             http_client: reqwest::Client::builder()
                 .timeout(std::time::Duration::from_secs(30))
@@ -125,13 +145,23 @@ impl LocalToolExecutor {
         self
     }
 
+    /// Set the per-tool time limits (also applied to custom tools).
+    pub fn with_timeouts(mut self, timeouts: ToolTimeouts) -> Self {
+        self.custom_provider = self
+            .custom_provider
+            .map(|provider| provider.with_timeouts(timeouts.clone()));
+        self.timeouts = timeouts;
+        self
+    }
+
     /// Register custom tools from Lua definitions.
     pub fn with_custom_tool_defs(mut self, defs: &[CustomToolDef]) -> Self {
         if defs.is_empty() {
             return self;
         }
 
-        let mut provider = CustomToolProvider::from_custom_tool_defs(defs);
+        let mut provider =
+            CustomToolProvider::from_custom_tool_defs(defs).with_timeouts(self.timeouts.clone());
         if let Some(dir) = &self.working_dir {
             provider = provider.with_working_dir(dir.clone());
         }
@@ -157,46 +187,55 @@ impl LocalToolExecutor {
         self
     }
 
-    /// Internal execute implementation for built-in synchronous tools (file, command, search).
+    /// Route Lua `handler` custom tools to `engine`.
     ///
-    /// Routes calls by exact canonical name. Custom tools are handled in the
-    /// async `execute()` path and never reach this method.
-    fn execute_internal(&self, call: &ToolCall) -> ToolResult {
-        // Check if tool exists
-        let definition = match self.tool_spec.get(&call.tool_name) {
-            Some(d) => d,
-            None => {
-                return ToolResult::failure(
-                    &call.tool_name,
-                    ToolError::not_found(format!("Unknown tool: {}", call.tool_name)),
-                );
-            }
+    /// Call after [`with_custom_tool_defs()`](Self::with_custom_tool_defs).
+    pub fn with_script_engine(mut self, engine: Arc<dyn ScriptingEnginePort>) -> Self {
+        self.custom_provider = self
+            .custom_provider
+            .map(|provider| provider.with_script_engine(engine));
+        self
+    }
+
+    /// Check that the call names a known tool and that its arguments satisfy
+    /// the tool's definition.
+    ///
+    /// Runs before every execution path (built-in, web, and custom tools), so
+    /// malformed calls fail with a retryable `INVALID_ARGUMENT` error instead of
+    /// reaching the tool. Unknown extra arguments are only logged.
+    fn validate_call(&self, call: &ToolCall) -> Result<(), ToolError> {
+        let Some(definition) = self.tool_spec.get(&call.tool_name) else {
+            return Err(ToolError::not_found(format!(
+                "Unknown tool: {}",
+                call.tool_name
+            )));
         };
 
-        // Validate the call
-        let validator = quorum_domain::tool::traits::DefaultToolValidator;
-        if let Err(e) =
-            quorum_domain::tool::traits::ToolValidator::validate(&validator, call, definition)
-        {
-            return ToolResult::failure(&call.tool_name, ToolError::invalid_argument(e));
+        DefaultToolValidator
+            .validate(call, definition)
+            .map_err(ToolError::invalid_argument)?;
+
+        let unknown = unknown_arguments(call, definition);
+        if !unknown.is_empty() {
+            tracing::warn!(
+                "Ignoring unknown arguments for tool '{}': {}",
+                call.tool_name,
+                unknown.join(", ")
+            );
         }
+        Ok(())
+    }
 
+    /// Internal execute implementation for built-in synchronous tools (file, command, search).
+    ///
+    /// Routes calls by exact canonical name. Custom tools are handled in the
+    /// async `execute()` path and never reach this method.
+    fn execute_internal(&self, call: &ToolCall) -> ToolResult {
         // Execute the appropriate tool
         match call.tool_name.as_str() {
             file::READ_FILE => file::execute_read_file(call),
             file::WRITE_FILE => file::execute_write_file(call),
-            command::RUN_COMMAND => {
-                // Inject working directory if set and not already specified
-                if self.working_dir.is_some() && call.get_string("working_dir").is_none() {
-                    let mut modified_call = call.clone();
-                    if let Some(dir) = &self.working_dir {
-                        modified_call = modified_call.with_arg("working_dir", dir.as_str());
-                    }
-                    command::execute_run_command(&modified_call)
-                } else {
-                    command::execute_run_command(call)
-                }
-            }
+            command::RUN_COMMAND => command::execute_run_command(&self.prepare_run_command(call)),
             search::GLOB_SEARCH => search::execute_glob_search(call),
             search::GREP_SEARCH => search::execute_grep_search(call),
             _ => ToolResult::failure(
@@ -209,6 +248,50 @@ impl LocalToolExecutor {
         }
     }
 
+    /// Fill in the configured working directory and time limit for `run_command`.
+    ///
+    /// A `timeout_secs` argument from the model may shorten the configured
+    /// limit but never extend it.
+    fn prepare_run_command(&self, call: &ToolCall) -> ToolCall {
+        let mut call = call.clone();
+        if let Some(dir) = &self.working_dir
+            && call.get_string("working_dir").is_none()
+        {
+            call = call.with_arg("working_dir", dir.as_str());
+        }
+        let limit = self.timeouts.secs_for(command::RUN_COMMAND);
+        let requested = call
+            .get_i64("timeout_secs")
+            .filter(|secs| *secs > 0)
+            .map_or(limit, |secs| (secs as u64).min(limit));
+        call.with_arg("timeout_secs", requested as i64)
+    }
+
+    /// Run a synchronous built-in tool off the async runtime, bounded by its
+    /// configured timeout.
+    async fn execute_blocking_bounded(&self, call: &ToolCall) -> ToolResult {
+        let limit = self.timeouts.timeout_for(&call.tool_name);
+        let executor = self.clone();
+        let owned_call = call.clone();
+        let task = tokio::task::spawn_blocking(move || executor.execute_internal(&owned_call));
+        match tokio::time::timeout(limit, task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => ToolResult::failure(
+                &call.tool_name,
+                ToolError::execution_failed(format!("Tool task failed: {}", e)),
+            ),
+            Err(_) => Self::timed_out(call, limit.as_secs()),
+        }
+    }
+
+    /// `TIMEOUT` failure for a call that exceeded `secs`.
+    fn timed_out(call: &ToolCall, secs: u64) -> ToolResult {
+        ToolResult::failure(
+            &call.tool_name,
+            ToolError::timeout(format!("{} exceeded {} seconds", call.tool_name, secs)),
+        )
+    }
+
     /// Check if a tool name belongs to a built-in tool (not custom).
     fn is_builtin_tool(&self, name: &str) -> bool {
         matches!(
@@ -244,25 +327,6 @@ impl LocalToolExecutor {
     /// to the appropriate web tool executor.
     #[cfg(feature = "web-tools")]
     async fn execute_async(&self, call: &ToolCall) -> ToolResult {
-        // Check if tool exists
-        let definition = match self.tool_spec.get(&call.tool_name) {
-            Some(d) => d,
-            None => {
-                return ToolResult::failure(
-                    &call.tool_name,
-                    ToolError::not_found(format!("Unknown tool: {}", call.tool_name)),
-                );
-            }
-        };
-
-        // Validate the call
-        let validator = quorum_domain::tool::traits::DefaultToolValidator;
-        if let Err(e) =
-            quorum_domain::tool::traits::ToolValidator::validate(&validator, call, definition)
-        {
-            return ToolResult::failure(&call.tool_name, ToolError::invalid_argument(e));
-        }
-
         match call.tool_name.as_str() {
             super::web::WEB_FETCH => super::web::execute_web_fetch(&self.http_client, call).await,
             super::web::WEB_SEARCH => super::web::execute_web_search(&self.http_client, call).await,
@@ -290,10 +354,16 @@ impl ToolExecutorPort for LocalToolExecutor {
     }
 
     async fn execute(&self, call: &ToolCall) -> ToolResult {
+        if let Err(error) = self.validate_call(call) {
+            return ToolResult::failure(&call.tool_name, error);
+        }
         #[cfg(feature = "web-tools")]
         {
             if Self::is_async_tool(&call.tool_name) {
-                return self.execute_async(call).await;
+                let limit = self.timeouts.timeout_for(&call.tool_name);
+                return tokio::time::timeout(limit, self.execute_async(call))
+                    .await
+                    .unwrap_or_else(|_| Self::timed_out(call, limit.as_secs()));
             }
         }
         // Check custom tools first (can await directly in async context)
@@ -303,10 +373,17 @@ impl ToolExecutorPort for LocalToolExecutor {
         {
             return provider.execute(call).await;
         }
-        self.execute_internal(call)
+        // run_command enforces its own timeout by killing the process
+        if call.tool_name == command::RUN_COMMAND {
+            return self.execute_internal(call);
+        }
+        self.execute_blocking_bounded(call).await
     }
 
     fn execute_sync(&self, call: &ToolCall) -> ToolResult {
+        if let Err(error) = self.validate_call(call) {
+            return ToolResult::failure(&call.tool_name, error);
+        }
         #[cfg(feature = "web-tools")]
         {
             if Self::is_async_tool(&call.tool_name) {
@@ -332,6 +409,7 @@ impl ToolExecutorPort for LocalToolExecutor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use quorum_domain::tool::value_objects::ErrorCategory;
     use std::fs;
     use std::io::Write;
     use tempfile::{NamedTempFile, tempdir};
@@ -366,6 +444,38 @@ mod tests {
         assert_eq!(result.error().unwrap().code, "NOT_FOUND");
     }
 
+    #[tokio::test]
+    async fn test_executor_applies_configured_command_timeout() {
+        let executor = LocalToolExecutor::new()
+            .with_timeouts(ToolTimeouts::default().with_tool_timeout("run_command", 1));
+        let call = ToolCall::new("run_command").with_arg("command", "sleep 30");
+
+        let start = std::time::Instant::now();
+        let result = executor.execute(&call).await;
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(10));
+        assert_eq!(result.error().unwrap().code, "TIMEOUT");
+    }
+
+    #[tokio::test]
+    async fn test_executor_caps_requested_command_timeout() {
+        let executor = LocalToolExecutor::new()
+            .with_timeouts(ToolTimeouts::default().with_tool_timeout("run_command", 1));
+        let call = ToolCall::new("run_command")
+            .with_arg("command", "sleep 30")
+            .with_arg("timeout_secs", 600i64);
+
+        let result = executor.execute(&call).await;
+
+        assert!(
+            result
+                .error()
+                .unwrap()
+                .message
+                .contains("exceeded 1 seconds")
+        );
+    }
+
     #[test]
     fn test_executor_read_file() {
         let mut temp_file = NamedTempFile::new().unwrap();
@@ -421,6 +531,37 @@ mod tests {
         assert_eq!(result.error().unwrap().code, "INVALID_ARGUMENT");
     }
 
+    #[tokio::test]
+    async fn test_executor_rejects_wrong_typed_argument() {
+        let executor = LocalToolExecutor::new();
+        let call = ToolCall::new("read_file")
+            .with_arg("path", "Cargo.toml")
+            .with_arg("limit", "ten");
+        let result = executor.execute(&call).await;
+
+        let error = result.error().unwrap();
+        assert_eq!(
+            ErrorCategory::from_error_code(&error.code),
+            ErrorCategory::ValidationError
+        );
+        assert!(error.message.contains("limit"));
+    }
+
+    #[tokio::test]
+    async fn test_executor_tolerates_unknown_argument() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "extra args").unwrap();
+        let path = temp_file.path().to_str().unwrap();
+
+        let executor = LocalToolExecutor::new();
+        let call = ToolCall::new("read_file")
+            .with_arg("path", path)
+            .with_arg("encoding", "utf-8");
+        let result = executor.execute(&call).await;
+
+        assert!(result.is_success());
+    }
+
     #[tokio::test]
     async fn test_executor_async() {
         let mut temp_file = NamedTempFile::new().unwrap();
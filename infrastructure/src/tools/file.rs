@@ -8,6 +8,8 @@ use std::fs;
 use std::path::Path;
 use std::time::Instant;
 
+use super::diff::unified_diff;
+
 /// Tool name constants
 pub const READ_FILE: &str = "read_file";
 pub const WRITE_FILE: &str = "write_file";
@@ -34,6 +36,22 @@ pub fn read_file_definition() -> ToolDefinition {
     .with_parameter(
         ToolParameter::new("limit", "Maximum number of lines to read", false).with_type("number"),
     )
+    .with_parameter(
+        ToolParameter::new(
+            "start_line",
+            "First line to read (1-indexed, inclusive; default: 1)",
+            false,
+        )
+        .with_type("number"),
+    )
+    .with_parameter(
+        ToolParameter::new(
+            "end_line",
+            "Last line to read (1-indexed, inclusive; default: end of file)",
+            false,
+        )
+        .with_type("number"),
+    )
 }
 
 /// Get the tool definition for write_file
@@ -112,6 +130,12 @@ pub fn execute_read_file(call: &ToolCall) -> ToolResult {
         }
     };
 
+    let start_line = call.get_i64("start_line");
+    let end_line = call.get_i64("end_line");
+    if start_line.is_some() || end_line.is_some() {
+        return read_line_range(path_str, &content, start_line, end_line, start);
+    }
+
     // Handle offset and limit
     let offset = call.get_i64("offset").unwrap_or(0) as usize;
     let limit = call.get_i64("limit");
@@ -144,6 +168,58 @@ pub fn execute_read_file(call: &ToolCall) -> ToolResult {
     })
 }
 
+/// Return lines `start_line..=end_line` (1-indexed) of `content`.
+///
+/// Missing bounds default to the start/end of the file. A range reaching past
+/// the end is clamped and noted in the output; `total_lines` in the metadata
+/// tells the caller how much of the file remains.
+fn read_line_range(
+    path_str: &str,
+    content: &str,
+    start_line: Option<i64>,
+    end_line: Option<i64>,
+    started: Instant,
+) -> ToolResult {
+    let lines: Vec<&str> = content.lines().collect();
+    let total_lines = lines.len();
+
+    let start = start_line.unwrap_or(1).max(1) as usize;
+    let end = end_line.map_or(total_lines, |n| n.max(0) as usize);
+    if end_line.is_some() && end < start {
+        return ToolResult::failure(
+            READ_FILE,
+            ToolError::invalid_argument(format!(
+                "end_line ({}) is before start_line ({})",
+                end, start
+            )),
+        );
+    }
+
+    let mut output = if start <= end.min(total_lines) {
+        lines[start - 1..end.min(total_lines)].join("\n")
+    } else {
+        String::new()
+    };
+    if start > total_lines || end > total_lines {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "... (line range clamped: the file has {} lines)",
+            total_lines
+        ));
+    }
+
+    let bytes = output.len();
+    ToolResult::success(READ_FILE, output).with_metadata(ToolResultMetadata {
+        duration_ms: Some(started.elapsed().as_millis() as u64),
+        bytes: Some(bytes),
+        path: Some(path_str.to_string()),
+        total_lines: Some(total_lines),
+        ..Default::default()
+    })
+}
+
 /// Execute the write_file tool
 pub fn execute_write_file(call: &ToolCall) -> ToolResult {
     let start = Instant::now();
@@ -188,6 +264,9 @@ pub fn execute_write_file(call: &ToolCall) -> ToolResult {
         );
     }
 
+    // Capture the previous contents for the diff (absent for new files)
+    let previous = fs::read(path).ok();
+
     // Write the file
     let bytes = content.len();
     if let Err(e) = fs::write(path, content) {
@@ -206,12 +285,44 @@ pub fn execute_write_file(call: &ToolCall) -> ToolResult {
         WRITE_FILE,
         format!("Successfully wrote {} bytes to {}", bytes, path_str),
     )
-    .with_metadata(ToolResultMetadata {
+    .with_metadata(write_metadata(
+        path_str,
+        previous.as_deref(),
+        content,
+        duration_ms,
+    ))
+}
+
+/// Metadata for a successful write, including a diff against `previous`.
+///
+/// Non-UTF-8 or NUL-containing contents (on either side) are reported as
+/// `binary` with no diff.
+fn write_metadata(
+    path_str: &str,
+    previous: Option<&[u8]>,
+    content: &str,
+    duration_ms: u64,
+) -> ToolResultMetadata {
+    let is_binary = |text: &str| text.contains('\0');
+    let previous_text = previous.map(std::str::from_utf8);
+    let binary = is_binary(content)
+        || matches!(previous_text, Some(Err(_)))
+        || matches!(previous_text, Some(Ok(text)) if is_binary(text));
+    let diff = if binary {
+        None
+    } else {
+        let old = previous_text.and_then(Result::ok);
+        unified_diff(path_str, old, content)
+    };
+
+    ToolResultMetadata {
         duration_ms: Some(duration_ms),
-        bytes: Some(bytes),
+        bytes: Some(content.len()),
         path: Some(path_str.to_string()),
+        diff,
+        binary,
         ..Default::default()
-    })
+    }
 }
 
 #[cfg(test)]
@@ -262,6 +373,112 @@ mod tests {
         assert!(!output.contains("line4"));
     }
 
+    fn numbered_file(lines: usize) -> NamedTempFile {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        for n in 1..=lines {
+            writeln!(temp_file, "line{}", n).unwrap();
+        }
+        temp_file
+    }
+
+    #[test]
+    fn test_read_file_line_range_mid_file() {
+        let temp_file = numbered_file(10);
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", temp_file.path().to_str().unwrap())
+            .with_arg("start_line", 3i64)
+            .with_arg("end_line", 5i64);
+        let result = execute_read_file(&call);
+
+        assert!(result.is_success());
+        assert_eq!(result.output(), Some("line3\nline4\nline5"));
+        assert_eq!(result.metadata.total_lines, Some(10));
+    }
+
+    #[test]
+    fn test_read_file_line_range_open_ended() {
+        let temp_file = numbered_file(4);
+        let path = temp_file.path().to_str().unwrap();
+
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", path)
+            .with_arg("start_line", 3i64);
+        assert_eq!(execute_read_file(&call).output(), Some("line3\nline4"));
+
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", path)
+            .with_arg("end_line", 2i64);
+        assert_eq!(execute_read_file(&call).output(), Some("line1\nline2"));
+    }
+
+    #[test]
+    fn test_read_file_line_range_clamped_past_end() {
+        let temp_file = numbered_file(4);
+        let path = temp_file.path().to_str().unwrap();
+
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", path)
+            .with_arg("start_line", 3i64)
+            .with_arg("end_line", 50i64);
+        let result = execute_read_file(&call);
+        assert!(result.is_success());
+        assert_eq!(
+            result.output(),
+            Some("line3\nline4\n... (line range clamped: the file has 4 lines)")
+        );
+        assert_eq!(result.metadata.total_lines, Some(4));
+
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", path)
+            .with_arg("start_line", 9i64)
+            .with_arg("end_line", 12i64);
+        let result = execute_read_file(&call);
+        assert!(result.is_success());
+        assert_eq!(
+            result.output(),
+            Some("... (line range clamped: the file has 4 lines)")
+        );
+    }
+
+    #[test]
+    fn test_read_file_line_range_empty_file() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", temp_file.path().to_str().unwrap())
+            .with_arg("start_line", 1i64);
+        let result = execute_read_file(&call);
+
+        assert!(result.is_success());
+        assert_eq!(
+            result.output(),
+            Some("... (line range clamped: the file has 0 lines)")
+        );
+        assert_eq!(result.metadata.total_lines, Some(0));
+    }
+
+    #[test]
+    fn test_read_file_line_range_reversed_is_invalid() {
+        let temp_file = numbered_file(4);
+        let call = ToolCall::new(READ_FILE)
+            .with_arg("path", temp_file.path().to_str().unwrap())
+            .with_arg("start_line", 3i64)
+            .with_arg("end_line", 2i64);
+        let result = execute_read_file(&call);
+
+        assert!(!result.is_success());
+        assert_eq!(result.error().unwrap().code, "INVALID_ARGUMENT");
+    }
+
+    #[test]
+    fn test_read_file_without_range_has_no_total_lines() {
+        let temp_file = numbered_file(2);
+        let call = ToolCall::new(READ_FILE).with_arg("path", temp_file.path().to_str().unwrap());
+        let result = execute_read_file(&call);
+
+        assert_eq!(result.output(), Some("line1\nline2\n"));
+        assert_eq!(result.metadata.total_lines, None);
+    }
+
     #[test]
     fn test_write_file_success() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -277,6 +494,58 @@ mod tests {
         assert_eq!(fs::read_to_string(&path).unwrap(), "Hello, World!");
     }
 
+    #[test]
+    fn test_write_file_new_file_diff_is_all_additions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("new.txt");
+        let path_str = path.to_str().unwrap();
+
+        let call = ToolCall::new(WRITE_FILE)
+            .with_arg("path", path_str)
+            .with_arg("content", "first\nsecond\n");
+        let result = execute_write_file(&call);
+
+        let diff = result.metadata.diff.as_deref().unwrap();
+        assert!(diff.starts_with("--- /dev/null\n"));
+        assert!(diff.ends_with("@@ -0,0 +1,2 @@\n+first\n+second\n"));
+        assert!(!result.metadata.binary);
+    }
+
+    #[test]
+    fn test_write_file_modified_file_diff() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("edit.txt");
+        let path_str = path.to_str().unwrap();
+        fs::write(&path, "keep\nold line\nkeep too\n").unwrap();
+
+        let call = ToolCall::new(WRITE_FILE)
+            .with_arg("path", path_str)
+            .with_arg("content", "keep\nnew line\nkeep too\n");
+        let result = execute_write_file(&call);
+
+        let diff = result.metadata.diff.as_deref().unwrap();
+        assert!(diff.contains(&format!("--- a/{}\n", path_str)));
+        assert!(diff.contains("@@ -1,3 +1,3 @@\n keep\n-old line\n+new line\n keep too\n"));
+        assert!(!result.metadata.binary);
+    }
+
+    #[test]
+    fn test_write_file_over_binary_file_has_no_diff() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("image.bin");
+        let path_str = path.to_str().unwrap();
+        fs::write(&path, [0x89, b'P', b'N', b'G', 0xff, 0xfe]).unwrap();
+
+        let call = ToolCall::new(WRITE_FILE)
+            .with_arg("path", path_str)
+            .with_arg("content", "now text");
+        let result = execute_write_file(&call);
+
+        assert!(result.is_success());
+        assert!(result.metadata.binary);
+        assert!(result.metadata.diff.is_none());
+    }
+
     #[test]
     fn test_write_file_create_dirs() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -0,0 +1,242 @@
+//! Minimal `.gitignore` matcher for `glob_search` results.
+//!
+//! Rules are loaded from every `.gitignore` between the repository root (the
+//! nearest ancestor containing `.git`, or the filesystem root) and the search
+//! base, plus nested `.gitignore` files below the base as paths are checked.
+//! Outer files are evaluated first and the last matching rule wins, so a
+//! nested `!keep.txt` re-includes a file excluded further up. As in git, a
+//! file inside an ignored directory stays ignored, and `.git` itself is
+//! always skipped.
+
+use glob::{MatchOptions, Pattern};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// One parsed ignore line, relative to the directory that declared it.
+#[derive(Debug)]
+struct Rule {
+    pattern: Pattern,
+    negated: bool,
+    dir_only: bool,
+    /// Pattern contains a `/` and matches from the declaring directory only
+    anchored: bool,
+}
+
+impl Rule {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let line = line.strip_prefix('/').unwrap_or(line);
+        if line.is_empty() {
+            return None;
+        }
+        Some(Self {
+            pattern: Pattern::new(line).ok()?,
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    /// Whether the rule matches `relative` (relative to the declaring dir).
+    fn matches(&self, relative: &Path, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            return self.pattern.matches_path_with(relative, MATCH_OPTIONS);
+        }
+        relative.file_name().is_some_and(|name| {
+            self.pattern
+                .matches_with(&name.to_string_lossy(), MATCH_OPTIONS)
+        })
+    }
+}
+
+/// Ignore rules for one search, loaded lazily per directory.
+pub(crate) struct GitIgnore {
+    base: PathBuf,
+    /// Rules from `.gitignore` files at or above `base`, outermost first
+    inherited: Vec<(PathBuf, Vec<Rule>)>,
+    /// Explicit exclusions, evaluated last as if declared in `base`
+    excludes: Vec<Rule>,
+    use_gitignore: bool,
+    nested: HashMap<PathBuf, Vec<Rule>>,
+}
+
+impl GitIgnore {
+    /// Rules for a search rooted at `base_dir`.
+    ///
+    /// `excludes` are gitignore-style patterns relative to `base_dir`.
+    /// With `use_gitignore == false` only `excludes` apply.
+    pub(crate) fn new(base_dir: &Path, excludes: &[String], use_gitignore: bool) -> Self {
+        let base = absolute(base_dir);
+        let mut inherited = Vec::new();
+        if use_gitignore {
+            for dir in base.ancestors() {
+                let rules = read_rules(dir);
+                if !rules.is_empty() {
+                    inherited.push((dir.to_path_buf(), rules));
+                }
+                if dir.join(".git").exists() {
+                    break;
+                }
+            }
+            inherited.reverse();
+        }
+        Self {
+            base,
+            inherited,
+            excludes: excludes.iter().filter_map(|p| Rule::parse(p)).collect(),
+            use_gitignore,
+            nested: HashMap::new(),
+        }
+    }
+
+    /// Whether `path` is excluded by `.gitignore` or an explicit exclusion.
+    pub(crate) fn is_ignored(&mut self, path: &Path) -> bool {
+        let path = absolute(path);
+        // Check each directory between the base and the path first: git
+        // never descends into an ignored directory.
+        for dir in self.dirs_below_base(&path) {
+            if self.matches(&dir, true) {
+                return true;
+            }
+        }
+        let is_dir = path.is_dir();
+        self.matches(&path, is_dir)
+    }
+
+    /// Directories strictly between the base and `path`, outermost first.
+    fn dirs_below_base(&self, path: &Path) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| dir.starts_with(&self.base) && *dir != self.base)
+            .map(Path::to_path_buf)
+            .collect();
+        dirs.reverse();
+        dirs
+    }
+
+    fn matches(&mut self, path: &Path, is_dir: bool) -> bool {
+        if self.use_gitignore && path.file_name().is_some_and(|name| name == ".git") {
+            return true;
+        }
+        let mut ignored = false;
+        let mut apply = |dir: &Path, rules: &[Rule]| {
+            let Ok(relative) = path.strip_prefix(dir) else {
+                return;
+            };
+            for rule in rules {
+                if rule.matches(relative, is_dir) {
+                    ignored = !rule.negated;
+                }
+            }
+        };
+
+        for (dir, rules) in &self.inherited {
+            apply(dir, rules);
+        }
+        if self.use_gitignore {
+            for dir in self.dirs_below_base(path) {
+                let rules = self
+                    .nested
+                    .entry(dir.clone())
+                    .or_insert_with(|| read_rules(&dir));
+                apply(&dir, rules);
+            }
+        }
+        apply(&self.base, &self.excludes);
+        ignored
+    }
+}
+
+fn read_rules(dir: &Path) -> Vec<Rule> {
+    fs::read_to_string(dir.join(".gitignore"))
+        .map(|content| content.lines().filter_map(Rule::parse).collect())
+        .unwrap_or_default()
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn touch(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn test_rule_parsing() {
+        assert!(Rule::parse("# comment").is_none());
+        assert!(Rule::parse("   ").is_none());
+        let rule = Rule::parse("!build/").unwrap();
+        assert!(rule.negated && rule.dir_only && !rule.anchored);
+        assert!(Rule::parse("/target").unwrap().anchored);
+        assert!(Rule::parse("docs/*.md").unwrap().anchored);
+    }
+
+    #[test]
+    fn test_unanchored_pattern_matches_at_any_depth() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::write(dir.path().join(".gitignore"), "*.log\n/only_root.txt\n").unwrap();
+        touch(dir.path(), "a/b/debug.log");
+        touch(dir.path(), "only_root.txt");
+        touch(dir.path(), "a/only_root.txt");
+
+        let mut ignore = GitIgnore::new(dir.path(), &[], true);
+        assert!(ignore.is_ignored(&dir.path().join("a/b/debug.log")));
+        assert!(ignore.is_ignored(&dir.path().join("only_root.txt")));
+        assert!(!ignore.is_ignored(&dir.path().join("a/only_root.txt")));
+    }
+
+    #[test]
+    fn test_gitignore_above_base_applies() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::write(dir.path().join(".gitignore"), "generated/\n").unwrap();
+        touch(dir.path(), "src/generated/out.rs");
+        touch(dir.path(), "src/lib.rs");
+
+        let mut ignore = GitIgnore::new(&dir.path().join("src"), &[], true);
+        assert!(ignore.is_ignored(&dir.path().join("src/generated/out.rs")));
+        assert!(!ignore.is_ignored(&dir.path().join("src/lib.rs")));
+    }
+
+    #[test]
+    fn test_dir_only_rule_skips_files() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::write(dir.path().join(".gitignore"), "cache/\n").unwrap();
+        touch(dir.path(), "cache");
+
+        let mut ignore = GitIgnore::new(dir.path(), &[], true);
+        assert!(!ignore.is_ignored(&dir.path().join("cache")));
+    }
+}
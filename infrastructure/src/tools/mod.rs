@@ -10,6 +10,7 @@
 //! infrastructure/src/tools/
 //! ├── mod.rs              ← Tool registry setup (this file)
 //! ├── executor.rs         ← LocalToolExecutor (implements ToolExecutorPort)
+//! ├── dry_run.rs          ← DryRunToolExecutor (decorator, skips high-risk calls)
 //! ├── registry.rs         ← ToolRegistry for dynamic provider management
 //! ├── builtin.rs          ← BuiltinProvider (wraps all built-in tools)
 //! ├── cli.rs              ← CliToolProvider (external CLI tools)
@@ -47,7 +48,10 @@ pub mod search;
 #[cfg(feature = "web-tools")]
 pub mod web;
 
+mod diff;
+mod dry_run;
 mod executor;
+mod gitignore;
 mod registry;
 
 pub mod custom_provider;
@@ -56,6 +60,7 @@ pub mod schema;
 pub use builtin::BuiltinProvider;
 pub use cli::CliToolProvider;
 pub use custom_provider::CustomToolProvider;
+pub use dry_run::DryRunToolExecutor;
 pub use executor::LocalToolExecutor;
 pub use registry::{RegistryStats, ToolRegistry};
 pub use schema::JsonSchemaToolConverter;
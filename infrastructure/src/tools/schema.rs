@@ -13,6 +13,7 @@ use quorum_domain::tool::entities::{ToolDefinition, ToolSpec};
 /// - `"number"` → `"number"`
 /// - `"integer"` → `"integer"`
 /// - `"boolean"` → `"boolean"`
+/// - `"array"` → `"array"` of strings
 /// - anything else → `"string"`
 pub struct JsonSchemaToolConverter;
 
@@ -27,11 +28,15 @@ impl ToolSchemaPort for JsonSchemaToolConverter {
                 "number" => "number",
                 "integer" => "integer",
                 "boolean" => "boolean",
+                "array" => "array",
                 _ => "string",
             };
 
             let mut prop = serde_json::Map::new();
             prop.insert("type".to_string(), serde_json::json!(schema_type));
+            if schema_type == "array" {
+                prop.insert("items".to_string(), serde_json::json!({"type": "string"}));
+            }
             prop.insert(
                 "description".to_string(),
                 serde_json::json!(param.description),
@@ -102,6 +107,18 @@ mod tests {
         assert_eq!(required[0], "path");
     }
 
+    #[test]
+    fn test_array_param_has_string_items() {
+        let converter = JsonSchemaToolConverter;
+        let tool = ToolDefinition::new("glob_search", "Find files", RiskLevel::Low)
+            .with_parameter(ToolParameter::new("exclude", "Patterns", false).with_type("array"));
+
+        let schema = converter.tool_to_schema(&tool);
+        let prop = &schema["input_schema"]["properties"]["exclude"];
+        assert_eq!(prop["type"], "array");
+        assert_eq!(prop["items"]["type"], "string");
+    }
+
     #[test]
     fn test_all_tools_schema() {
         let converter = JsonSchemaToolConverter;
@@ -1,11 +1,13 @@
 //! Search tools: glob_search, grep_search
 
+use super::gitignore::GitIgnore;
 use glob::glob;
 use quorum_domain::tool::{
     entities::{RiskLevel, ToolCall, ToolDefinition, ToolParameter},
     value_objects::{ToolError, ToolResult, ToolResultMetadata},
 };
 use regex::Regex;
+use serde::Serialize;
 use std::fs;
 use std::path::Path;
 use std::time::Instant;
@@ -17,6 +19,9 @@ pub const GREP_SEARCH: &str = "grep_search";
 /// Maximum number of results to return
 const MAX_RESULTS: usize = 1000;
 
+/// Default cap on grep_search matches
+const DEFAULT_GREP_MAX_RESULTS: usize = 200;
+
 /// Maximum file size for grep (5 MB)
 const MAX_GREP_FILE_SIZE: u64 = 5 * 1024 * 1024;
 
@@ -46,6 +51,22 @@ pub fn glob_search_definition() -> ToolDefinition {
         )
         .with_type("number"),
     )
+    .with_parameter(
+        ToolParameter::new(
+            "exclude",
+            "Gitignore-style patterns to leave out of the results (e.g., ['target/', '*.lock'])",
+            false,
+        )
+        .with_type("array"),
+    )
+    .with_parameter(
+        ToolParameter::new(
+            "no_ignore",
+            "Include files excluded by .gitignore (default: false)",
+            false,
+        )
+        .with_type("boolean"),
+    )
 }
 
 /// Get the tool definition for grep_search
@@ -81,6 +102,33 @@ pub fn grep_search_definition() -> ToolDefinition {
         ToolParameter::new("case_insensitive", "Perform case-insensitive search", false)
             .with_type("boolean"),
     )
+    .with_parameter(
+        ToolParameter::new(
+            "structured",
+            "Return a JSON array of {file, line, column, text} matches instead of text (ignores context_lines)",
+            false,
+        )
+        .with_type("boolean"),
+    )
+    .with_parameter(
+        ToolParameter::new(
+            "max_results",
+            "Maximum number of matches to return (default: 200)",
+            false,
+        )
+        .with_type("number"),
+    )
+}
+
+/// One grep_search match in structured mode.
+#[derive(Debug, Serialize)]
+struct GrepMatch {
+    file: String,
+    /// 1-based line number
+    line: usize,
+    /// 1-based character column of the first match on the line
+    column: usize,
+    text: String,
 }
 
 /// Execute the glob_search tool
@@ -103,6 +151,10 @@ pub fn execute_glob_search(call: &ToolCall) -> ToolResult {
         .unwrap_or(MAX_RESULTS)
         .min(MAX_RESULTS);
 
+    let excludes = call.get_string_list("exclude");
+    let use_gitignore = !call.get_bool("no_ignore").unwrap_or(false);
+    let mut ignore = GitIgnore::new(Path::new(base_dir), &excludes, use_gitignore);
+
     // Build the full pattern
     let full_pattern = if pattern.starts_with('/') || pattern.starts_with("./") {
         pattern.to_string()
@@ -130,6 +182,7 @@ pub fn execute_glob_search(call: &ToolCall) -> ToolResult {
         }
 
         match entry {
+            Ok(path) if ignore.is_ignored(&path) => {}
             Ok(path) => {
                 results.push(path.display().to_string());
             }
@@ -186,6 +239,11 @@ pub fn execute_grep_search(call: &ToolCall) -> ToolResult {
     let file_pattern = call.get_string("file_pattern");
     let context_lines = call.get_i64("context_lines").unwrap_or(0) as usize;
     let case_insensitive = call.get_bool("case_insensitive").unwrap_or(false);
+    let structured = call.get_bool("structured").unwrap_or(false);
+    let max_results = call
+        .get_i64("max_results")
+        .map(|n| n.max(1) as usize)
+        .unwrap_or(DEFAULT_GREP_MAX_RESULTS);
 
     // Build regex
     let regex_pattern = if case_insensitive {
@@ -212,13 +270,10 @@ pub fn execute_grep_search(call: &ToolCall) -> ToolResult {
     };
 
     let mut results = Vec::new();
-    let mut total_matches = 0;
-
-    for file_path in files {
-        if results.len() >= MAX_RESULTS {
-            break;
-        }
+    let mut matches = Vec::new();
+    let mut truncated = false;
 
+    'files: for file_path in files {
         // Check file size
         if let Ok(metadata) = fs::metadata(&file_path)
             && metadata.len() > MAX_GREP_FILE_SIZE
@@ -232,14 +287,20 @@ pub fn execute_grep_search(call: &ToolCall) -> ToolResult {
             let file_display = file_path.display().to_string();
 
             for (line_num, line) in lines.iter().enumerate() {
-                if results.len() >= MAX_RESULTS {
-                    break;
-                }
-
-                if regex.is_match(line) {
-                    total_matches += 1;
+                if let Some(found) = regex.find(line) {
+                    if results.len() + matches.len() >= max_results {
+                        truncated = true;
+                        break 'files;
+                    }
 
-                    if context_lines > 0 {
+                    if structured {
+                        matches.push(GrepMatch {
+                            file: file_display.clone(),
+                            line: line_num + 1,
+                            column: line[..found.start()].chars().count() + 1,
+                            text: line.to_string(),
+                        });
+                    } else if context_lines > 0 {
                         // Add context
                         let start_line = line_num.saturating_sub(context_lines);
                         let end_line = (line_num + context_lines + 1).min(lines.len());
@@ -267,20 +328,25 @@ pub fn execute_grep_search(call: &ToolCall) -> ToolResult {
     }
 
     let duration_ms = start.elapsed().as_millis() as u64;
+    let match_count = results.len() + matches.len();
 
-    let mut output = results.join("\n");
-    if total_matches >= MAX_RESULTS {
-        output.push_str(&format!("\n... (limited to {} matches)", MAX_RESULTS));
-    }
-
-    if results.is_empty() {
-        output = "No matches found".to_string();
-    }
+    let output = if structured {
+        serde_json::to_string(&matches).unwrap_or_else(|_| "[]".to_string())
+    } else if results.is_empty() {
+        "No matches found".to_string()
+    } else {
+        let mut output = results.join("\n");
+        if truncated {
+            output.push_str(&format!("\n... (limited to {} matches)", max_results));
+        }
+        output
+    };
 
     ToolResult::success(GREP_SEARCH, output).with_metadata(ToolResultMetadata {
         duration_ms: Some(duration_ms),
-        match_count: Some(total_matches),
+        match_count: Some(match_count),
         path: Some(path_str.to_string()),
+        truncated,
         ..Default::default()
     })
 }
@@ -328,6 +394,77 @@ mod tests {
         assert!(output.contains("test2.txt"));
     }
 
+    /// Repo with a root `.gitignore`, a nested one re-including a file,
+    /// and a mix of tracked and ignored files.
+    fn ignore_fixture() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir(root.join(".git")).unwrap();
+        fs::write(root.join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+        fs::write(root.join(".gitignore"), "target/\n*.log\n").unwrap();
+        for dir in ["src", "target/debug", "logs"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/main.rs"), "").unwrap();
+        fs::write(root.join("src/Cargo.lock"), "").unwrap();
+        fs::write(root.join("target/debug/app.txt"), "").unwrap();
+        fs::write(root.join("logs/.gitignore"), "!keep.log\n").unwrap();
+        fs::write(root.join("logs/drop.log"), "").unwrap();
+        fs::write(root.join("logs/keep.log"), "").unwrap();
+        dir
+    }
+
+    fn glob_paths(call: &ToolCall) -> Vec<String> {
+        let result = execute_glob_search(call);
+        assert!(result.is_success());
+        let output = result.output().unwrap();
+        if output == "No files found matching the pattern" {
+            return Vec::new();
+        }
+        output.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_glob_search_honors_gitignore() {
+        let dir = ignore_fixture();
+        let call = ToolCall::new(GLOB_SEARCH)
+            .with_arg("pattern", "**/*")
+            .with_arg("base_dir", dir.path().to_str().unwrap());
+        let paths = glob_paths(&call);
+
+        assert!(paths.iter().any(|p| p.ends_with("src/main.rs")));
+        assert!(paths.iter().any(|p| p.ends_with("logs/keep.log")));
+        assert!(!paths.iter().any(|p| p.contains("target")));
+        assert!(!paths.iter().any(|p| p.ends_with("drop.log")));
+        assert!(!paths.iter().any(|p| p.contains(".git/")));
+    }
+
+    #[test]
+    fn test_glob_search_explicit_exclude() {
+        let dir = ignore_fixture();
+        let call = ToolCall::new(GLOB_SEARCH)
+            .with_arg("pattern", "src/*")
+            .with_arg("base_dir", dir.path().to_str().unwrap())
+            .with_arg("exclude", serde_json::json!(["*.lock"]));
+        let paths = glob_paths(&call);
+
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("src/main.rs"));
+    }
+
+    #[test]
+    fn test_glob_search_no_ignore_includes_ignored_files() {
+        let dir = ignore_fixture();
+        let call = ToolCall::new(GLOB_SEARCH)
+            .with_arg("pattern", "**/*.*")
+            .with_arg("base_dir", dir.path().to_str().unwrap())
+            .with_arg("no_ignore", true);
+        let paths = glob_paths(&call);
+
+        assert!(paths.iter().any(|p| p.ends_with("target/debug/app.txt")));
+        assert!(paths.iter().any(|p| p.ends_with("logs/drop.log")));
+    }
+
     #[test]
     fn test_glob_search_no_matches() {
         let temp_dir = tempdir().unwrap();
@@ -438,6 +575,100 @@ mod tests {
         assert!(result.output().unwrap().contains("No matches found"));
     }
 
+    #[test]
+    fn test_grep_search_structured_matches_across_lines() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "fn main() {{").unwrap();
+        writeln!(temp_file, "    let todo = 1; // TODO: rename").unwrap();
+        writeln!(temp_file, "}}").unwrap();
+        writeln!(temp_file, "// TODO: tests").unwrap();
+        let path = temp_file.path().to_str().unwrap();
+
+        let call = ToolCall::new(GREP_SEARCH)
+            .with_arg("pattern", "TODO")
+            .with_arg("path", path)
+            .with_arg("structured", true);
+        let result = execute_grep_search(&call);
+
+        assert!(result.is_success());
+        assert_eq!(result.metadata.match_count, Some(2));
+        assert!(!result.metadata.truncated);
+        let matches: Vec<serde_json::Value> =
+            serde_json::from_str(result.output().unwrap()).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0]["file"], path);
+        assert_eq!(matches[0]["line"], 2);
+        assert_eq!(matches[0]["column"], 22);
+        assert_eq!(matches[0]["text"], "    let todo = 1; // TODO: rename");
+        assert_eq!(matches[1]["line"], 4);
+        assert_eq!(matches[1]["column"], 4);
+    }
+
+    #[test]
+    fn test_grep_search_structured_no_matches_is_empty_array() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "some content").unwrap();
+
+        let call = ToolCall::new(GREP_SEARCH)
+            .with_arg("pattern", "nonexistent")
+            .with_arg("path", temp_file.path().to_str().unwrap())
+            .with_arg("structured", true);
+        let result = execute_grep_search(&call);
+
+        assert_eq!(result.output(), Some("[]"));
+        assert_eq!(result.metadata.match_count, Some(0));
+    }
+
+    #[test]
+    fn test_grep_search_caps_results_and_marks_truncation() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        for i in 0..(DEFAULT_GREP_MAX_RESULTS + 5) {
+            writeln!(temp_file, "hit {}", i).unwrap();
+        }
+        let path = temp_file.path().to_str().unwrap();
+
+        let call = ToolCall::new(GREP_SEARCH)
+            .with_arg("pattern", "hit")
+            .with_arg("path", path)
+            .with_arg("structured", true);
+        let result = execute_grep_search(&call);
+        let matches: Vec<serde_json::Value> =
+            serde_json::from_str(result.output().unwrap()).unwrap();
+        assert_eq!(matches.len(), DEFAULT_GREP_MAX_RESULTS);
+        assert_eq!(result.metadata.match_count, Some(DEFAULT_GREP_MAX_RESULTS));
+        assert!(result.metadata.truncated);
+
+        let call = ToolCall::new(GREP_SEARCH)
+            .with_arg("pattern", "hit")
+            .with_arg("path", path)
+            .with_arg("max_results", 3i64);
+        let result = execute_grep_search(&call);
+        assert_eq!(result.metadata.match_count, Some(3));
+        assert!(result.metadata.truncated);
+        assert!(
+            result
+                .output()
+                .unwrap()
+                .ends_with("... (limited to 3 matches)")
+        );
+    }
+
+    #[test]
+    fn test_grep_search_exact_cap_is_not_truncated() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "hit").unwrap();
+        writeln!(temp_file, "hit").unwrap();
+
+        let call = ToolCall::new(GREP_SEARCH)
+            .with_arg("pattern", "hit")
+            .with_arg("path", temp_file.path().to_str().unwrap())
+            .with_arg("max_results", 2i64);
+        let result = execute_grep_search(&call);
+
+        assert_eq!(result.metadata.match_count, Some(2));
+        assert!(!result.metadata.truncated);
+    }
+
     #[test]
     fn test_grep_search_invalid_regex() {
         let temp_file = NamedTempFile::new().unwrap();
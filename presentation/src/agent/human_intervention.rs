@@ -53,16 +53,33 @@
 //! `/reject` does depends on whether a next round exists: it declines the
 //! early settle and continues the debate in a non-final round, or aborts
 //! the debate entirely at the final round.
+//!
+//! # Timeout
+//!
+//! Built with [`with_config`](InteractiveHumanIntervention::with_config),
+//! `agent.hil_timeout_secs` is read from the shared config at every prompt,
+//! so runtime changes apply. Each prompt counts down in place
+//! (`agent-hil [ 29s]>`) and returns `HumanInterventionError::TimedOut`
+//! when nothing is entered in time; the agent then takes
+//! `agent.hil_timeout_default`. In batch review the timeout applies to each
+//! action separately, as in the TUI. Lines typed before a prompt appears
+//! are discarded rather than answering it.
 
 use async_trait::async_trait;
 use colored::Colorize;
+use quorum_application::QuorumConfig;
 use quorum_application::ports::human_intervention::{
     HumanInterventionError, HumanInterventionPort,
 };
 use quorum_domain::core::string::truncate;
 use quorum_domain::quorum::{Objection, ObjectionSeverity};
-use quorum_domain::{HumanDecision, Plan, ReviewRound};
+use quorum_domain::{HumanDecision, Plan, QueuedAction, ReviewRound, Task};
 use std::io::{self, Write};
+use std::sync::{Arc, Mutex, OnceLock, mpsc};
+use std::time::{Duration, Instant};
+
+/// One result of the background stdin reader: a line, or `None` at EOF.
+type StdinLine = io::Result<Option<String>>;
 
 /// Interactive human intervention handler for CLI.
 ///
@@ -79,11 +96,65 @@ use std::io::{self, Write};
 /// let use_case = RunAgentUseCase::new(gateway, executor, tool_schema)
 ///     .with_human_intervention(intervention);
 /// ```
-pub struct InteractiveHumanIntervention;
+pub struct InteractiveHumanIntervention {
+    /// Read per prompt for `agent.hil_timeout_secs`; `None` waits forever.
+    config: Option<Arc<Mutex<QuorumConfig>>>,
+    /// Lines from a background stdin reader, started by the first timed
+    /// prompt so a read can be abandoned when the prompt times out.
+    lines: OnceLock<Mutex<mpsc::Receiver<StdinLine>>>,
+}
 
 impl InteractiveHumanIntervention {
     pub fn new() -> Self {
-        Self
+        Self {
+            config: None,
+            lines: OnceLock::new(),
+        }
+    }
+
+    /// Give up on prompts after `agent.hil_timeout_secs`, read from
+    /// `config` each time a prompt starts.
+    pub fn with_config(mut self, config: Arc<Mutex<QuorumConfig>>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.lock().unwrap().policy().hil_timeout())
+    }
+
+    /// Start a prompt: its answer deadline, if any.
+    ///
+    /// Discards lines typed while no prompt was shown, so a late answer to
+    /// a timed-out prompt cannot answer this one.
+    fn start_prompt(&self) -> Option<Instant> {
+        let timeout = self.timeout()?;
+        let lines = self.stdin_lines().lock().unwrap();
+        while lines.try_recv().is_ok() {}
+        Some(Instant::now() + timeout)
+    }
+
+    fn stdin_lines(&self) -> &Mutex<mpsc::Receiver<StdinLine>> {
+        self.lines.get_or_init(|| {
+            let (tx, rx) = mpsc::channel();
+            std::thread::spawn(move || {
+                loop {
+                    let mut line = String::new();
+                    let result = match io::stdin().read_line(&mut line) {
+                        Ok(0) => Ok(None),
+                        Ok(_) => Ok(Some(line)),
+                        Err(e) => Err(e),
+                    };
+                    let done = !matches!(result, Ok(Some(_)));
+                    if tx.send(result).is_err() || done {
+                        break;
+                    }
+                }
+            });
+            Mutex::new(rx)
+        })
     }
 
     /// Display the intervention prompt UI
@@ -261,8 +332,152 @@ impl InteractiveHumanIntervention {
         println!();
     }
 
-    /// Read user command
-    fn read_command(&self) -> Result<String, HumanInterventionError> {
+    /// Display the prompt for a high-risk action below the reviewer floor
+    fn display_action_approval_prompt(&self, task: &Task, tool_call_json: &str, reason: &str) {
+        println!();
+        println!(
+            "{}",
+            "═══════════════════════════════════════════════════════════════"
+                .yellow()
+                .bold()
+        );
+        println!(
+            "{}",
+            "  ⚠️  High-Risk Action Needs Approval".yellow().bold()
+        );
+        println!(
+            "{}",
+            "═══════════════════════════════════════════════════════════════"
+                .yellow()
+                .bold()
+        );
+        println!();
+        println!("{}.", reason);
+        println!();
+
+        println!("{}", "Task:".cyan().bold());
+        println!("  {}", task.description);
+        println!();
+
+        println!("{}", "Action:".cyan().bold());
+        for line in tool_call_json.lines() {
+            println!("  {}", line.dimmed());
+        }
+        println!();
+
+        println!("{}", "Commands:".cyan().bold());
+        println!("  {}  - Run this action", "/approve".green());
+        println!("  {}   - Skip this action", "/reject".red());
+        println!();
+    }
+
+    fn display_batch_review_prompt(&self, request: &str, actions: &[QueuedAction]) {
+        println!();
+        println!(
+            "{}",
+            "═══════════════════════════════════════════════════════════════"
+                .yellow()
+                .bold()
+        );
+        println!(
+            "{}",
+            format!("  📋 Batch Review: {} Queued Action(s)", actions.len())
+                .yellow()
+                .bold()
+        );
+        println!(
+            "{}",
+            "═══════════════════════════════════════════════════════════════"
+                .yellow()
+                .bold()
+        );
+        println!();
+        println!("{}", "Request:".cyan().bold());
+        println!("  {}", request);
+        println!();
+
+        println!("{}", "Queued actions:".cyan().bold());
+        for action in actions {
+            let args = serde_json::to_string(&action.call.arguments).unwrap_or_default();
+            println!(
+                "  {}. {} (task {}) {}",
+                action.index + 1,
+                action.call.tool_name.cyan(),
+                action.task_id,
+                truncate(&args, 80).dimmed()
+            );
+            if let Some(reason) = &action.escalation {
+                println!("     {} {}", "⚠ Escalated:".yellow(), reason);
+            }
+        }
+        println!();
+        println!("Rejecting an action also skips later actions of the same or dependent tasks.");
+        println!();
+
+        println!("{}", "Commands (per action):".cyan().bold());
+        println!("  {}      - Run this action", "/approve".green());
+        println!("  {}       - Skip this action", "/reject".red());
+        println!(
+            "  {}  - Run this and all remaining actions",
+            "/approve-all".green()
+        );
+        println!(
+            "  {}   - Skip this and all remaining actions",
+            "/reject-all".red()
+        );
+        println!();
+    }
+
+    /// Read user command, giving up at `deadline`
+    fn read_command(&self, deadline: Option<Instant>) -> Result<String, HumanInterventionError> {
+        let Some(deadline) = deadline else {
+            return self.read_command_blocking();
+        };
+
+        let lines = self.stdin_lines().lock().unwrap();
+        let mut first = true;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                println!();
+                println!("{}", "⏱  No answer in time".yellow());
+                return Err(HumanInterventionError::TimedOut {
+                    answered: Vec::new(),
+                });
+            }
+
+            // Redraw only the prompt, keeping whatever the user has typed
+            let prompt = format!("agent-hil [{:>3}s]>", remaining.as_secs_f64().ceil() as u64);
+            if first {
+                print!("{} ", prompt.magenta().bold());
+                first = false;
+            } else {
+                print!("\x1b7\r{}\x1b8", prompt.magenta().bold());
+            }
+            io::stdout().flush().map_err(|e| {
+                HumanInterventionError::IoError(format!("Failed to flush stdout: {}", e))
+            })?;
+
+            match lines.recv_timeout(remaining.min(Duration::from_secs(1))) {
+                Ok(Ok(Some(input))) => return Ok(input.trim().to_string()),
+                Ok(Ok(None)) | Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(HumanInterventionError::IoError(
+                        "stdin closed (EOF) — cannot prompt for human intervention".to_string(),
+                    ));
+                }
+                Ok(Err(e)) => {
+                    return Err(HumanInterventionError::IoError(format!(
+                        "Failed to read input: {}",
+                        e
+                    )));
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+            }
+        }
+    }
+
+    /// Read user command without a deadline
+    fn read_command_blocking(&self) -> Result<String, HumanInterventionError> {
         print!("{} ", "agent-hil>".magenta().bold());
         io::stdout().flush().map_err(|e| {
             HumanInterventionError::IoError(format!("Failed to flush stdout: {}", e))
@@ -300,9 +515,10 @@ impl HumanInterventionPort for InteractiveHumanIntervention {
         review_history: &[ReviewRound],
     ) -> Result<HumanDecision, HumanInterventionError> {
         self.display_intervention_prompt(request, plan, review_history);
+        let deadline = self.start_prompt();
 
         loop {
-            let input = self.read_command()?;
+            let input = self.read_command(deadline)?;
 
             match input.to_lowercase().as_str() {
                 "/approve" | "approve" | "a" => {
@@ -373,9 +589,10 @@ impl HumanInterventionPort for InteractiveHumanIntervention {
         println!("  {}  - Execute this plan", "/approve".green());
         println!("  {}   - Cancel execution (keep plan)", "/reject".red());
         println!();
+        let deadline = self.start_prompt();
 
         loop {
-            let input = self.read_command()?;
+            let input = self.read_command(deadline)?;
 
             match input.to_lowercase().as_str() {
                 "/approve" | "approve" | "a" | "y" | "yes" => {
@@ -412,9 +629,10 @@ impl HumanInterventionPort for InteractiveHumanIntervention {
             transcript_summary,
             can_continue,
         );
+        let deadline = self.start_prompt();
 
         loop {
-            let input = self.read_command()?;
+            let input = self.read_command(deadline)?;
 
             match input.to_lowercase().as_str() {
                 "/approve" | "approve" | "a" => {
@@ -444,4 +662,108 @@ impl HumanInterventionPort for InteractiveHumanIntervention {
             }
         }
     }
+
+    async fn request_batch_approval(
+        &self,
+        request: &str,
+        actions: &[QueuedAction],
+    ) -> Result<Vec<HumanDecision>, HumanInterventionError> {
+        self.display_batch_review_prompt(request, actions);
+        let mut deadline = self.start_prompt();
+        let mut prompted = 0;
+
+        let mut decisions = Vec::with_capacity(actions.len());
+        while decisions.len() < actions.len() {
+            // Every action gets the full timeout; type-ahead is kept
+            if decisions.len() > prompted {
+                deadline = self.timeout().map(|t| Instant::now() + t);
+                prompted = decisions.len();
+            }
+            let action = &actions[decisions.len()];
+            println!(
+                "{} {}/{}: {} (task {})",
+                "Action".bold(),
+                action.index + 1,
+                actions.len(),
+                action.call.tool_name.cyan(),
+                action.task_id
+            );
+            let input = match self.read_command(deadline) {
+                Ok(input) => input,
+                Err(HumanInterventionError::TimedOut { .. }) => {
+                    return Err(HumanInterventionError::TimedOut {
+                        answered: decisions,
+                    });
+                }
+                Err(e) => return Err(e),
+            };
+
+            match input.to_lowercase().as_str() {
+                "/approve" | "approve" | "a" | "y" | "yes" => {
+                    decisions.push(HumanDecision::Approve);
+                }
+                "/reject" | "reject" | "r" | "n" | "no" => {
+                    decisions.push(HumanDecision::Reject);
+                }
+                "/approve-all" | "approve-all" | "aa" => {
+                    decisions.resize(actions.len(), HumanDecision::Approve);
+                }
+                "/reject-all" | "reject-all" | "ra" | "q" => {
+                    decisions.resize(actions.len(), HumanDecision::Reject);
+                }
+                "" => continue,
+                _ => {
+                    println!();
+                    println!("{} Unknown command: {}", "⚠️".yellow(), input.red());
+                    println!("Available commands: /approve, /reject, /approve-all, /reject-all");
+                    println!();
+                }
+            }
+        }
+
+        let approved = decisions
+            .iter()
+            .filter(|d| matches!(d, HumanDecision::Approve))
+            .count();
+        println!();
+        println!(
+            "{}",
+            format!("✓ {}/{} action(s) approved", approved, actions.len()).green()
+        );
+        Ok(decisions)
+    }
+
+    async fn request_action_approval(
+        &self,
+        task: &Task,
+        tool_call_json: &str,
+        reason: &str,
+    ) -> Result<HumanDecision, HumanInterventionError> {
+        self.display_action_approval_prompt(task, tool_call_json, reason);
+        let deadline = self.start_prompt();
+
+        loop {
+            let input = self.read_command(deadline)?;
+
+            match input.to_lowercase().as_str() {
+                "/approve" | "approve" | "a" | "y" | "yes" => {
+                    println!();
+                    println!("{}", "✓ Action approved by human".green());
+                    return Ok(HumanDecision::Approve);
+                }
+                "/reject" | "reject" | "r" | "n" | "no" | "q" => {
+                    println!();
+                    println!("{}", "✗ Action rejected by human".red());
+                    return Ok(HumanDecision::Reject);
+                }
+                "" => continue,
+                _ => {
+                    println!();
+                    println!("{} Unknown command: {}", "⚠️".yellow(), input.red());
+                    println!("Available commands: /approve, /reject");
+                    println!();
+                }
+            }
+        }
+    }
 }
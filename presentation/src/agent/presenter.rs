@@ -113,6 +113,9 @@ impl ReplPresenter {
                 println!("{} {}", "Error:".red().bold(), error);
                 println!();
             }
+            UiEvent::TranscriptSaved { path } => {
+                println!("{} Discussion transcript saved to {}", "✓".green(), path);
+            }
             UiEvent::ContextInitStarting { model_count } => {
                 println!();
                 println!(
@@ -149,6 +152,15 @@ impl ReplPresenter {
                 println!("Use {} to regenerate.", "/init! (or /init --force)".cyan());
                 println!();
             }
+            UiEvent::SessionSummary { summary, .. } => {
+                println!();
+                println!("{}", "TL;DR".bold());
+                println!("{}", summary);
+                println!();
+            }
+            UiEvent::SessionSummaryError { error, .. } => {
+                println!("{} {}", "Summarize:".yellow().bold(), error);
+            }
             UiEvent::CommandError { message } => {
                 println!("{} {}", "Error:".red().bold(), message);
             }
@@ -253,6 +265,7 @@ impl ReplPresenter {
         println!("{}", "Interaction Commands:".bold().magenta());
         println!("  /ask <question>      - Ask (lightweight Q&A with read-only tools)");
         println!("  /discuss <question>  - Discuss (quorum discussion, consult multiple models)");
+        println!("  /discuss --save <p>  - Save the last discussion transcript as Markdown");
         println!("  /agent <task>        - Agent (autonomous task execution)");
         println!();
         println!("{}", "Other Commands:".bold());
@@ -518,6 +531,11 @@ impl ReplPresenter {
 
     fn render_quorum_result(&self, result: &QuorumResultEvent) {
         println!();
+        if result.synthesis_streamed {
+            // Already printed chunk by chunk as it streamed in.
+            println!();
+            return;
+        }
         println!("{}", "━".repeat(60).dimmed());
         println!();
         println!("{}", "Quorum Synthesis:".bold().magenta());
@@ -530,12 +548,23 @@ impl ReplPresenter {
         println!();
         println!("{}", "━".repeat(60).dimmed());
         println!();
-        println!(
-            "{} {} {}",
-            "✅".green(),
-            "Created:".green().bold(),
-            result.path.cyan()
-        );
+        if let Some(error) = &result.write_error {
+            println!(
+                "{} {} {} ({})",
+                "⚠️".yellow(),
+                "Not saved:".yellow().bold(),
+                result.path.cyan(),
+                error
+            );
+            println!("   The generated context is still used for this session.");
+        } else {
+            println!(
+                "{} {} {}",
+                "✅".green(),
+                "Created:".green().bold(),
+                result.path.cyan()
+            );
+        }
         println!();
         println!("{}", "Contributing models:".bold());
         for model in &result.contributing_models {
@@ -4,7 +4,7 @@ use colored::Colorize;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use quorum_application::{AgentProgressNotifier, ErrorCategory};
 use quorum_domain::core::string::truncate;
-use quorum_domain::{AgentPhase, Model, Task, Thought, Vote, VoteVerdict};
+use quorum_domain::{AgentPhase, Model, Plan, Task, Thought, Vote, VoteVerdict};
 use std::io::Write;
 use std::sync::Mutex;
 
@@ -16,6 +16,7 @@ pub struct AgentProgressReporter {
     quorum_bar: Mutex<Option<ProgressBar>>,
     verbose: bool,
     show_votes: bool,
+    explain_plan: bool,
 }
 
 impl AgentProgressReporter {
@@ -28,6 +29,7 @@ impl AgentProgressReporter {
             quorum_bar: Mutex::new(None),
             verbose: false,
             show_votes: false,
+            explain_plan: false,
         }
     }
 
@@ -40,6 +42,7 @@ impl AgentProgressReporter {
             quorum_bar: Mutex::new(None),
             verbose: true,
             show_votes: false,
+            explain_plan: false,
         }
     }
 
@@ -52,9 +55,16 @@ impl AgentProgressReporter {
             quorum_bar: Mutex::new(None),
             verbose,
             show_votes,
+            explain_plan: false,
         }
     }
 
+    /// Print each plan as a dependency tree as soon as it is created
+    pub fn with_explain_plan(mut self, explain_plan: bool) -> Self {
+        self.explain_plan = explain_plan;
+        self
+    }
+
     fn phase_style() -> ProgressStyle {
         ProgressStyle::default_spinner()
             .template("{spinner:.green} {prefix:.bold.cyan} {msg}")
@@ -286,6 +296,12 @@ impl AgentProgressNotifier for AgentProgressReporter {
         }
     }
 
+    fn on_context_file_loaded(&self, path: &str, _source: &str) {
+        if let Some(pb) = self.phase_bar.lock().unwrap().as_ref() {
+            pb.set_message(format!("Reading {}…", display_file_name(path)));
+        }
+    }
+
     fn on_quorum_start(&self, phase: &str, model_count: usize) {
         let pb = self.multi.add(ProgressBar::new(model_count as u64));
         pb.set_style(Self::quorum_style());
@@ -414,6 +430,12 @@ impl AgentProgressNotifier for AgentProgressReporter {
         }
     }
 
+    fn on_plan_created(&self, plan: &Plan) {
+        if self.explain_plan {
+            PlanTreePrinter.on_plan_created(plan);
+        }
+    }
+
     fn on_plan_revision(&self, revision: usize, feedback: &str) {
         println!();
         println!(
@@ -460,8 +482,14 @@ impl AgentProgressNotifier for AgentProgressReporter {
         );
     }
 
-    fn on_execution_confirmation_required(&self, _request: &str, _plan: &quorum_domain::Plan) {
+    fn on_execution_confirmation_required(
+        &self,
+        _request: &str,
+        _plan: &quorum_domain::Plan,
+        impact: &quorum_domain::PlanImpact,
+    ) {
         self.finish_current_phase();
+        println!("    {} Impact: {}", "📋".cyan(), impact);
     }
 
     // ==================== Ensemble Planning Callbacks ====================
@@ -499,6 +527,13 @@ impl AgentProgressNotifier for AgentProgressReporter {
         }
     }
 
+    fn on_ensemble_model_timeout(&self, model: &quorum_domain::Model) {
+        if let Some(pb) = self.quorum_bar.lock().unwrap().as_ref() {
+            pb.set_message(format!("{} timed out", model).yellow().to_string());
+            pb.inc(1);
+        }
+    }
+
     fn on_ensemble_complete(&self, selected_model: &quorum_domain::Model, score: f64) {
         if let Some(pb) = self.quorum_bar.lock().unwrap().take() {
             pb.finish_with_message(format!(
@@ -510,6 +545,17 @@ impl AgentProgressNotifier for AgentProgressReporter {
         }
     }
 
+    fn on_ensemble_merged(&self, moderator: &quorum_domain::Model, source_count: usize) {
+        if let Some(pb) = self.quorum_bar.lock().unwrap().take() {
+            pb.finish_with_message(format!(
+                "{} top {} plans via {}",
+                "Merged:".green().bold(),
+                source_count,
+                moderator.to_string().cyan()
+            ));
+        }
+    }
+
     fn on_ensemble_fallback(&self, reason: &str) {
         if let Some(pb) = self.quorum_bar.lock().unwrap().take() {
             pb.finish_with_message(
@@ -546,6 +592,25 @@ impl AgentProgressNotifier for AgentProgressReporter {
     }
 }
 
+/// Last path component of `path`, for "Reading Cargo.toml…" style messages.
+pub(crate) fn display_file_name(path: &str) -> &str {
+    std::path::Path::new(path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(path)
+}
+
+/// Prints each plan as a dependency tree when it is created, and nothing
+/// else (`--explain-plan` without progress output)
+pub struct PlanTreePrinter;
+
+impl AgentProgressNotifier for PlanTreePrinter {
+    fn on_plan_created(&self, plan: &Plan) {
+        println!();
+        println!("{}", plan.to_tree_string());
+    }
+}
+
 /// Simple text-based progress (no spinners)
 pub struct SimpleAgentProgress {
     verbose: bool,
@@ -637,6 +702,12 @@ impl AgentProgressNotifier for SimpleAgentProgress {
         println!("    ✓ {} → {}", original_name, resolved_name);
     }
 
+    fn on_context_file_loaded(&self, path: &str, _source: &str) {
+        if self.verbose {
+            println!("  📄 Reading {}…", display_file_name(path));
+        }
+    }
+
     fn on_quorum_start(&self, phase: &str, model_count: usize) {
         println!("  🗳️  {} ({} models)", phase, model_count);
     }
@@ -742,10 +813,18 @@ impl AgentProgressNotifier for SimpleAgentProgress {
         println!("    ✗ {} failed: {}", model, truncate(error, 40));
     }
 
+    fn on_ensemble_model_timeout(&self, model: &quorum_domain::Model) {
+        println!("    ⏱ {} timed out", model);
+    }
+
     fn on_ensemble_complete(&self, selected_model: &quorum_domain::Model, score: f64) {
         println!("  ✓ Selected: {} (score: {:.1}/10)", selected_model, score);
     }
 
+    fn on_ensemble_merged(&self, moderator: &quorum_domain::Model, source_count: usize) {
+        println!("  ✓ Merged: top {} plans via {}", source_count, moderator);
+    }
+
     fn on_ensemble_fallback(&self, reason: &str) {
         println!(
             "  ⚠ Ensemble failed, falling back to solo: {}",
@@ -1,7 +1,7 @@
 //! CLI command definitions
 
 use clap::{Parser, Subcommand, ValueEnum};
-use quorum_domain::OutputFormat;
+use quorum_domain::{AgentPhase, OutputFormat};
 use std::path::PathBuf;
 
 /// Subcommands (#300 `review` / #302 `rpc`, RFC Discussion #304 D4).
@@ -84,6 +84,33 @@ impl From<CliOutputFormat> for OutputFormat {
     }
 }
 
+/// Agent phases that `--only-phase` can replay.
+///
+/// Wrapper over the replayable subset of [`AgentPhase`] for clap's
+/// [`ValueEnum`] (same orphan-rule reason as [`CliOutputFormat`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CliPhase {
+    /// Re-gather project context for the saved request
+    ContextGathering,
+    /// Re-plan the saved request against the saved context
+    Planning,
+    /// Re-run quorum review of the saved plan
+    PlanReview,
+    /// Re-run final review of the saved task results
+    FinalReview,
+}
+
+impl From<CliPhase> for AgentPhase {
+    fn from(phase: CliPhase) -> Self {
+        match phase {
+            CliPhase::ContextGathering => AgentPhase::ContextGathering,
+            CliPhase::Planning => AgentPhase::Planning,
+            CliPhase::PlanReview => AgentPhase::PlanReview,
+            CliPhase::FinalReview => AgentPhase::FinalReview,
+        }
+    }
+}
+
 /// CLI arguments for copilot-quorum
 #[derive(Parser, Debug)]
 #[command(name = "copilot-quorum")]
@@ -164,14 +191,62 @@ pub struct Cli {
     #[arg(short, long, value_name = "MODEL")]
     pub model: Vec<String>,
 
+    /// Skip context gathering and plan with an empty context (overrides agent.gather_context)
+    #[arg(long)]
+    pub no_context: bool,
+
     /// Enable final review in agent mode
     #[arg(long)]
     pub final_review: bool,
 
+    /// Require at least two reviewers for high-risk actions, even in auto-approve mode
+    #[arg(long)]
+    pub safe: bool,
+
+    /// Preview high-risk tool calls (write_file, mutating run_command) without executing them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Stop the agent gracefully once cumulative token usage exceeds N (overrides agent.max_cost_tokens)
+    #[arg(long, value_name = "N")]
+    pub max_cost_tokens: Option<u64>,
+
+    /// Write the final agent state as JSON to PATH after a single-request run (for debugging)
+    #[arg(long, value_name = "PATH")]
+    pub dump_state: Option<PathBuf>,
+
+    /// Debug: re-run only PHASE against a state saved with --dump-state, then exit
+    #[arg(long, value_enum, value_name = "PHASE", requires = "load_state")]
+    pub only_phase: Option<CliPhase>,
+
+    /// State file (from --dump-state) to load for --only-phase
+    #[arg(long, value_name = "PATH", requires = "only_phase")]
+    pub load_state: Option<PathBuf>,
+
+    /// Execute the plan in PATH (plan JSON) instead of gathering context and planning
+    #[arg(long, value_name = "PATH", requires = "question")]
+    pub plan_file: Option<PathBuf>,
+
+    /// Print the plan as a dependency tree as soon as it is created, before execution starts
+    #[arg(long, requires = "question")]
+    pub explain_plan: bool,
+
     /// Working directory for agent mode
     #[arg(short, long, value_name = "PATH")]
     pub working_dir: Option<PathBuf>,
 
+    /// Always load PATH into the project context (repeatable; adds to `context.pinned_files`)
+    #[arg(long = "context-file", value_name = "PATH")]
+    pub context_files: Vec<PathBuf>,
+
+    /// Append TEXT to the agent context before planning, without a file (repeatable)
+    #[arg(
+        long = "context",
+        visible_alias = "append-context",
+        value_name = "TEXT"
+    )]
+    pub context: Vec<String>,
+
     /// Output format (default: synthesis, or from config file)
     #[arg(short, long, value_enum)]
     pub output: Option<CliOutputFormat>,
@@ -188,6 +263,10 @@ pub struct Cli {
     #[arg(short, long)]
     pub quiet: bool,
 
+    /// Render the TUI with ASCII borders and no color (same as `tui.ascii_mode`)
+    #[arg(long)]
+    pub no_unicode: bool,
+
     /// Override log file directory (default: ~/.local/share/copilot-quorum/logs/)
     #[arg(long, value_name = "PATH")]
     pub log_dir: Option<PathBuf>,
@@ -196,10 +275,30 @@ pub struct Cli {
     #[arg(long)]
     pub no_log_file: bool,
 
+    /// Apply the config profile NAME (defined with `quorum.config.profile`) over init.lua
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
     /// Show init.lua and plugin paths and exit
     #[arg(long)]
     pub show_config: bool,
 
+    /// Show enabled features, the default provider and detected CLIs, then exit
+    #[arg(long)]
+    pub about: bool,
+
+    /// Check that every configured model is offered by a provider, then exit
+    #[arg(long)]
+    pub check_models: bool,
+
+    /// Open a `.conversation.jsonl` log in the TUI, read-only (no providers are started)
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["question", "only_phase", "check_models"]
+    )]
+    pub replay: Option<PathBuf>,
+
     /// Expose a JSON-RPC remote control socket at PATH (TUI mode only)
     ///
     /// External processes (e.g. coding agents) can inspect panes and
@@ -234,6 +333,90 @@ mod tests {
         assert_eq!(cli.listen, Some(PathBuf::from("/tmp/q.sock")));
     }
 
+    #[test]
+    fn replay_parses_and_conflicts_with_question() {
+        let cli =
+            Cli::try_parse_from(["copilot-quorum", "--replay", "a.conversation.jsonl"]).unwrap();
+        assert_eq!(cli.replay, Some(PathBuf::from("a.conversation.jsonl")));
+
+        let err =
+            Cli::try_parse_from(["copilot-quorum", "--replay", "a.jsonl", "question"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn only_phase_requires_load_state() {
+        let err =
+            Cli::try_parse_from(["copilot-quorum", "--only-phase", "plan-review"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn only_phase_with_load_state_parses() {
+        let cli = Cli::try_parse_from([
+            "copilot-quorum",
+            "--only-phase",
+            "plan-review",
+            "--load-state",
+            "state.json",
+        ])
+        .unwrap();
+        assert_eq!(cli.only_phase, Some(CliPhase::PlanReview));
+        assert_eq!(cli.load_state, Some(PathBuf::from("state.json")));
+        assert_eq!(cli.question, None);
+        assert_eq!(
+            AgentPhase::from(CliPhase::PlanReview),
+            AgentPhase::PlanReview
+        );
+    }
+
+    #[test]
+    fn plan_file_requires_question() {
+        let err = Cli::try_parse_from(["copilot-quorum", "--plan-file", "plan.json"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+
+        let cli =
+            Cli::try_parse_from(["copilot-quorum", "--plan-file", "plan.json", "Do it"]).unwrap();
+        assert_eq!(cli.plan_file, Some(PathBuf::from("plan.json")));
+    }
+
+    #[test]
+    fn context_text_is_repeatable() {
+        let cli = Cli::try_parse_from([
+            "copilot-quorum",
+            "--context",
+            "Prefer small PRs",
+            "--append-context",
+            "Keep the public API stable",
+            "Fix login",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.context,
+            vec!["Prefer small PRs", "Keep the public API stable"]
+        );
+    }
+
+    #[test]
+    fn context_file_is_repeatable() {
+        let cli = Cli::try_parse_from([
+            "copilot-quorum",
+            "--context-file",
+            "src/auth.rs",
+            "--context-file",
+            "docs/design.md",
+            "Fix login",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.context_files,
+            vec![
+                PathBuf::from("src/auth.rs"),
+                PathBuf::from("docs/design.md")
+            ]
+        );
+    }
+
     #[test]
     fn plain_question_has_no_subcommand() {
         let cli = Cli::try_parse_from(["copilot-quorum", "Fix the bug"]).unwrap();
@@ -11,7 +11,9 @@ pub mod progress;
 pub mod tui;
 
 // Re-export commonly used types
-pub use cli::commands::{Cli, CliOutputFormat, Command, ReviewArgs, ReviewOutputFormat, RpcArgs};
+pub use cli::commands::{
+    Cli, CliOutputFormat, CliPhase, Command, ReviewArgs, ReviewOutputFormat, RpcArgs,
+};
 pub use cli::rpc_client::run_rpc;
 pub use config::{OutputConfig, ReplConfig};
 pub use output::console::ConsoleFormatter;
@@ -20,7 +22,7 @@ pub use progress::reporter::{ProgressReporter, SimpleProgress};
 // Agent-related exports (used by one-shot mode)
 pub use agent::human_intervention::InteractiveHumanIntervention;
 pub use agent::presenter::ReplPresenter;
-pub use agent::progress::{AgentProgressReporter, SimpleAgentProgress};
+pub use agent::progress::{AgentProgressReporter, PlanTreePrinter, SimpleAgentProgress};
 pub use agent::thought::{ThoughtStream, format_thoughts, summarize_thoughts};
 
 // TUI exports
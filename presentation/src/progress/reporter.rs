@@ -91,6 +91,12 @@ impl ProgressNotifier for ProgressReporter {
             pb.finish_with_message(format!("{} complete!", phase_name.green()));
         }
     }
+
+    fn on_debate_converged(&self, round: usize) {
+        if let Some(pb) = self.phase_bar.lock().unwrap().as_ref() {
+            pb.set_message(format!("positions converged at round {}", round));
+        }
+    }
 }
 
 /// Simple text-based progress (no fancy UI)
@@ -118,4 +124,12 @@ impl ProgressNotifier for SimpleProgress {
     fn on_phase_complete(&self, _phase: &Phase) {
         println!();
     }
+
+    fn on_debate_converged(&self, round: usize) {
+        println!(
+            "  {} positions converged at round {}, settling early",
+            "=".cyan(),
+            round
+        );
+    }
 }
@@ -17,11 +17,13 @@ use super::event::{HilRequest, RoutedTuiEvent, TuiCommand};
 use super::layout::TuiLayoutConfig;
 use super::mode::{self, InputMode, KeyAction};
 use super::presenter::TuiPresenter;
-use super::state::{TuiInputConfig, TuiState};
+use super::state::{DisplayMessage, TuiInputConfig, TuiState};
 
 /// Side-effect that requires main loop intervention (e.g. terminal suspend)
 pub(super) enum SideEffect {
     LaunchEditor,
+    /// Open the HiL prompt's plan draft in $EDITOR
+    EditPlan,
 }
 
 /// Outcome of awaiting a specific interaction via [`TuiApp::run_headless_until`].
@@ -58,13 +60,35 @@ pub(super) fn dispatch_terminal_event(
     state: &mut TuiState,
     event: crossterm::event::Event,
     deps: &InputDeps<'_>,
+) -> Option<SideEffect> {
+    let old_mode = state.mode;
+    let side_effect = dispatch_event(state, event, deps);
+    emit_mode_changed(deps.scripting_engine.as_ref(), old_mode, state.mode);
+    side_effect
+}
+
+/// Fire the `ModeChanged` Lua event if the input mode actually changed.
+pub(super) fn emit_mode_changed(
+    engine: &dyn quorum_application::ScriptingEnginePort,
+    old_mode: InputMode,
+    new_mode: InputMode,
+) {
+    if old_mode != new_mode {
+        let data = ScriptEventData::mode_changed(old_mode.name(), new_mode.name());
+        let _ = engine.emit_event(ScriptEventType::ModeChanged, data);
+    }
+}
+
+fn dispatch_event(
+    state: &mut TuiState,
+    event: crossterm::event::Event,
+    deps: &InputDeps<'_>,
 ) -> Option<SideEffect> {
     match event {
         crossterm::event::Event::Key(key) => {
             // If HiL modal is showing, handle y/n/Esc
             if state.hil_prompt.is_some() {
-                super::app_hil::handle_hil_key(state, deps.pending_hil_tx, key);
-                return None;
+                return super::app_hil::handle_hil_key(state, deps.pending_hil_tx, key);
             }
 
             // If help is showing, handle close + scroll keys (other keys fall through)
@@ -140,11 +164,12 @@ use crossterm::{
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use futures::stream::StreamExt;
-use quorum_application::QuorumConfig;
 use quorum_application::{
     AgentController, ClipboardPort, ContextLoaderPort, ConversationLogger, LlmGateway, NoClipboard,
     NoConversationLogger, ToolExecutorPort, ToolSchemaPort, TuiAccessorPort, UiEvent,
 };
+use quorum_application::{ConversationRecord, QuorumConfig};
+use quorum_domain::scripting::{ScriptEventData, ScriptEventType};
 use quorum_domain::{
     ConsensusLevel, HumanDecision, InteractionForm, InteractionId, InteractionResult, Model,
 };
@@ -187,6 +212,12 @@ pub struct TuiApp {
     // -- Layout configuration --
     layout_config: TuiLayoutConfig,
 
+    // -- Accessibility (ASCII borders, no color) --
+    ascii_mode: bool,
+
+    // -- Intent routing for untyped input --
+    intent_routing: quorum_domain::IntentRouting,
+
     // -- Content registry (registry-driven rendering) --
     // RefCell for interior mutability: dynamic model stream renderers are
     // registered during event handling (&self) but consumed during render (&self).
@@ -212,6 +243,12 @@ pub struct TuiApp {
     // cloned before the move so `dispatch()` can reach `ConfigAccessorPort`
     // without a round-trip through the controller task.
     shared_config: Arc<Mutex<QuorumConfig>>,
+
+    // -- Default `:export` directory (the log dir) --
+    export_dir: Option<std::path::PathBuf>,
+
+    // -- Replayed conversation log (--replay), shown read-only instead of the welcome --
+    replay: Option<Vec<DisplayMessage>>,
 }
 
 impl TuiApp {
@@ -260,7 +297,8 @@ impl TuiApp {
         let (hil_tx, hil_rx) = mpsc::unbounded_channel::<HilRequest>();
 
         // Human intervention port (sends to hil_rx)
-        let human_intervention = Arc::new(TuiHumanIntervention::new(hil_tx));
+        let human_intervention =
+            Arc::new(TuiHumanIntervention::new(hil_tx).with_config(Arc::clone(&config)));
 
         // Progress bridge (sends TuiEvents to tui_event_rx)
         let progress_tx = tui_event_tx.clone();
@@ -304,6 +342,8 @@ impl TuiApp {
             _controller_handle: controller_handle,
             tui_config: TuiInputConfig::default(),
             layout_config: TuiLayoutConfig::default(),
+            ascii_mode: false,
+            intent_routing: quorum_domain::IntentRouting::default(),
             content_registry: std::cell::RefCell::new(super::app_render::build_default_registry()),
             scripting_engine: Arc::new(quorum_application::NoScriptingEngine),
             custom_keymap: mode::CustomKeymap::new(),
@@ -311,6 +351,8 @@ impl TuiApp {
             clipboard: Arc::new(NoClipboard),
             listen_path: None,
             shared_config,
+            export_dir: None,
+            replay: None,
         }
     }
 
@@ -365,6 +407,15 @@ impl TuiApp {
         self
     }
 
+    /// Set the decision log for the agent controller
+    pub fn with_decision_log(
+        self,
+        log: std::sync::Arc<dyn quorum_application::DecisionLogPort>,
+    ) -> Self {
+        let _ = self.cmd_tx.send(TuiCommand::SetDecisionLog(log));
+        self
+    }
+
     /// Feed `request` to the root interaction as if it had been typed into
     /// the active pane, right after construction.
     ///
@@ -410,6 +461,22 @@ impl TuiApp {
             .map_err(|_| io::Error::other("controller task dropped without responding"))
     }
 
+    /// Directory `:export` writes to when no path is given.
+    pub fn with_export_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.export_dir = Some(dir.into());
+        self
+    }
+
+    /// Show a conversation log read back from disk (`--replay`).
+    ///
+    /// The records are rendered in the conversation pane in place of the
+    /// welcome message, and the input is read-only: submitted text is not
+    /// sent to the controller.
+    pub fn with_replay(mut self, records: &[ConversationRecord]) -> Self {
+        self.replay = Some(super::replay::replay_messages(records));
+        self
+    }
+
     pub fn with_tui_config(mut self, config: TuiInputConfig) -> Self {
         self.tui_config = config;
         self
@@ -420,6 +487,16 @@ impl TuiApp {
         self
     }
 
+    pub fn with_ascii_mode(mut self, ascii_mode: bool) -> Self {
+        self.ascii_mode = ascii_mode;
+        self
+    }
+
+    pub fn with_intent_routing(mut self, routing: quorum_domain::IntentRouting) -> Self {
+        self.intent_routing = routing;
+        self
+    }
+
     /// Set the scripting engine and build custom keymaps from its registrations.
     pub fn with_scripting_engine(
         mut self,
@@ -461,6 +538,24 @@ impl TuiApp {
         super::app_tui_changes::apply_pending_tui_changes(changes, state, &self.content_registry);
     }
 
+    /// Populate a replayed log, or ask the controller for the welcome message.
+    fn start_session(&mut self, state: &mut TuiState) {
+        match self.replay.take() {
+            Some(messages) => {
+                state.read_only = true;
+                for msg in messages {
+                    state.push_message(msg);
+                }
+            }
+            None => {
+                let _ = self.cmd_tx.send(TuiCommand::HandleCommand {
+                    interaction_id: None,
+                    command: "__welcome".into(),
+                });
+            }
+        }
+    }
+
     /// Run the TUI main loop
     pub async fn run(&mut self) -> io::Result<()> {
         // Validate the remote-control socket path (--listen) up front, before
@@ -507,10 +602,10 @@ impl TuiApp {
         }
         state.tui_config = self.tui_config.clone();
         state.layout_config = self.layout_config.clone();
-        state.route = super::route::RouteTable::from_preset_and_overrides(
-            self.layout_config.preset.clone(),
-            &self.layout_config.route_overrides,
-        );
+        state.ascii_mode = self.ascii_mode;
+        state.intent_routing = self.intent_routing;
+        state.export_dir = self.export_dir.clone();
+        state.route = super::route::RouteTable::from_layout_config(&self.layout_config);
         let mut event_stream = EventStream::new();
         let mut tick = tokio::time::interval(Duration::from_millis(250));
 
@@ -524,11 +619,7 @@ impl TuiApp {
             drop(remote_tx);
         }
 
-        // Send welcome
-        let _ = self.cmd_tx.send(TuiCommand::HandleCommand {
-            interaction_id: None,
-            command: "__welcome".into(),
-        });
+        self.start_session(&mut state);
 
         loop {
             // Apply pending Lua scripting changes before rendering
@@ -554,7 +645,21 @@ impl TuiApp {
                     if let Some(side_effect) = self.handle_terminal_event(&mut state, term_event) {
                         match side_effect {
                             SideEffect::LaunchEditor => {
+                                let old_mode = state.mode;
                                 Self::run_editor(&mut terminal, &mut state, keyboard_enhanced)?;
+                                emit_mode_changed(
+                                    self.scripting_engine.as_ref(),
+                                    old_mode,
+                                    state.mode,
+                                );
+                            }
+                            SideEffect::EditPlan => {
+                                Self::run_plan_editor(
+                                    &mut terminal,
+                                    &mut state,
+                                    keyboard_enhanced,
+                                    &self.pending_hil_tx,
+                                )?;
                             }
                         }
                     }
@@ -599,6 +704,7 @@ impl TuiApp {
                 // Tick for flash expiry, spinner animation, etc.
                 _ = tick.tick() => {
                     state.expire_flash(Duration::from_secs(5));
+                    super::app_hil::expire_hil_prompt(&mut state, &self.pending_hil_tx);
                 }
             }
         }
@@ -645,10 +751,10 @@ impl TuiApp {
         let mut state = TuiState::new();
         state.tui_config = self.tui_config.clone();
         state.layout_config = self.layout_config.clone();
-        state.route = super::route::RouteTable::from_preset_and_overrides(
-            self.layout_config.preset.clone(),
-            &self.layout_config.route_overrides,
-        );
+        state.ascii_mode = self.ascii_mode;
+        state.intent_routing = self.intent_routing;
+        state.export_dir = self.export_dir.clone();
+        state.route = super::route::RouteTable::from_layout_config(&self.layout_config);
 
         let mut tick = tokio::time::interval(Duration::from_millis(250));
 
@@ -663,11 +769,7 @@ impl TuiApp {
         let mut sigterm =
             tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())?;
 
-        // Send welcome
-        let _ = self.cmd_tx.send(TuiCommand::HandleCommand {
-            interaction_id: None,
-            command: "__welcome".into(),
-        });
+        self.start_session(&mut state);
 
         loop {
             // Apply pending Lua scripting changes (same as `run()`, minus the draw)
@@ -716,6 +818,7 @@ impl TuiApp {
                 // Tick for flash expiry
                 _ = tick.tick() => {
                     state.expire_flash(Duration::from_secs(5));
+                    super::app_hil::expire_hil_prompt(&mut state, &self.pending_hil_tx);
                 }
 
                 // Graceful shutdown — no keyboard, so these are the only
@@ -759,10 +862,10 @@ impl TuiApp {
         let mut state = TuiState::new();
         state.tui_config = self.tui_config.clone();
         state.layout_config = self.layout_config.clone();
-        state.route = super::route::RouteTable::from_preset_and_overrides(
-            self.layout_config.preset.clone(),
-            &self.layout_config.route_overrides,
-        );
+        state.ascii_mode = self.ascii_mode;
+        state.intent_routing = self.intent_routing;
+        state.export_dir = self.export_dir.clone();
+        state.route = super::route::RouteTable::from_layout_config(&self.layout_config);
 
         let mut tick = tokio::time::interval(Duration::from_millis(250));
 
@@ -834,6 +937,7 @@ impl TuiApp {
 
                 _ = tick.tick() => {
                     state.expire_flash(Duration::from_secs(5));
+                    super::app_hil::expire_hil_prompt(&mut state, &self.pending_hil_tx);
                 }
 
                 _ = tokio::signal::ctrl_c() => {
@@ -864,6 +968,60 @@ impl TuiApp {
             strategy: "Quorum".to_string(),
         };
 
+        let result = Self::suspended(terminal, keyboard_enhanced, || {
+            editor::launch_editor_with_options(
+                &initial_text,
+                &context,
+                state.tui_config.context_header,
+            )
+        })?;
+
+        // Apply result
+        match result {
+            EditorResult::Saved(text) => {
+                let pane = state.tabs.active_pane_mut();
+                pane.input = text;
+                pane.cursor_pos = pane.input.len();
+                state.mode = InputMode::Insert;
+                state.set_flash("Editor: content loaded into input buffer");
+            }
+            EditorResult::Cancelled => {
+                state.set_flash("Editor: cancelled");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Suspend the TUI, open the HiL plan draft in $EDITOR, and resume.
+    ///
+    /// The saved text is applied by [`app_hil::apply_plan_edit`], which
+    /// answers the prompt with `HumanDecision::Edit` or re-prompts on a
+    /// parse error.
+    ///
+    /// [`app_hil::apply_plan_edit`]: super::app_hil::apply_plan_edit
+    fn run_plan_editor(
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        state: &mut TuiState,
+        keyboard_enhanced: bool,
+        pending_hil_tx: &Arc<Mutex<Option<oneshot::Sender<HumanDecision>>>>,
+    ) -> io::Result<()> {
+        let Some(draft) = state.hil_prompt.as_ref().and_then(|p| p.plan_draft.clone()) else {
+            return Ok(());
+        };
+        let result = Self::suspended(terminal, keyboard_enhanced, || {
+            editor::launch_plan_editor(&draft)
+        })?;
+        super::app_hil::apply_plan_edit(state, pending_hil_tx, result);
+        Ok(())
+    }
+
+    /// Run `f` (a blocking editor) with the terminal restored to cooked mode.
+    fn suspended<T>(
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        keyboard_enhanced: bool,
+        f: impl FnOnce() -> T,
+    ) -> io::Result<T> {
         // Suspend TUI
         disable_raw_mode()?;
         if keyboard_enhanced {
@@ -876,12 +1034,7 @@ impl TuiApp {
         )?;
         terminal.show_cursor()?;
 
-        // Launch editor (blocking)
-        let result = editor::launch_editor_with_options(
-            &initial_text,
-            &context,
-            state.tui_config.context_header,
-        );
+        let result = f();
 
         // Resume TUI
         enable_raw_mode()?;
@@ -897,22 +1050,7 @@ impl TuiApp {
             );
         }
         terminal.clear()?;
-
-        // Apply result
-        match result {
-            EditorResult::Saved(text) => {
-                let pane = state.tabs.active_pane_mut();
-                pane.input = text;
-                pane.cursor_pos = pane.input.len();
-                state.mode = InputMode::Insert;
-                state.set_flash("Editor: content loaded into input buffer");
-            }
-            EditorResult::Cancelled => {
-                state.set_flash("Editor: cancelled");
-            }
-        }
-
-        Ok(())
+        Ok(result)
     }
 
     /// Borrow the dependencies needed for terminal-event dispatch.
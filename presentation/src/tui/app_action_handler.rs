@@ -4,8 +4,11 @@ use super::command_completion;
 use super::content::ContentRegistry;
 use super::event::TuiCommand;
 use super::mode::{CompletionDirection, InputMode, KeyAction, VisualDirection};
+use super::search::ConversationSearch;
 use super::state::{DisplayMessage, TuiState, VisualSelection, YankMode, content_slot_label};
 use super::tab::PaneKind;
+use quorum_domain::interaction::InteractionForm;
+use quorum_domain::{IntentRouting, Question, classify_intent};
 use std::cell::RefCell;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -44,6 +47,14 @@ pub(super) fn handle_action(
                 state.command_input = completion.original_input;
                 state.command_cursor = state.command_input.len();
             } else {
+                match state.mode {
+                    // Esc in Normal mode clears search highlights, like `:noh`.
+                    InputMode::Normal => state.search = None,
+                    InputMode::Search => {
+                        state.take_search();
+                    }
+                    _ => {}
+                }
                 state.mode = InputMode::Normal;
                 state.visual_selection = None;
             }
@@ -67,7 +78,9 @@ pub(super) fn handle_action(
         // Submit
         KeyAction::SubmitInput => {
             let input = state.take_input();
-            if !input.is_empty() {
+            if state.read_only {
+                state.set_flash("Read-only replay — input is not sent");
+            } else if !input.is_empty() {
                 state.tabs.active_pane_mut().set_title_if_empty(&input);
                 match state.tabs.active_pane().kind {
                     // Bound tab: run the request against its interaction.
@@ -88,6 +101,7 @@ pub(super) fn handle_action(
                     // InteractionSpawned, so don't push it locally here (that
                     // would duplicate it).
                     PaneKind::Interaction(form, None) => {
+                        let form = route_untyped_input(state, form, &input);
                         let _ = cmd_tx.send(TuiCommand::SpawnInteraction {
                             form,
                             query: input,
@@ -167,9 +181,59 @@ pub(super) fn handle_action(
         KeyAction::ScrollToTop => state.scroll_to_top(),
         KeyAction::ScrollToBottom => state.scroll_to_bottom(),
 
+        KeyAction::ToggleFold => state.tabs.active_pane_mut().progress.toggle_folds(),
+
+        // Conversation search
+        KeyAction::Search => {
+            state.mode = InputMode::Search;
+            state.search_input.clear();
+            state.search_cursor = 0;
+        }
+        KeyAction::ToggleSearchRegex => {
+            state.search_regex = !state.search_regex;
+            state.set_flash(if state.search_regex {
+                "Search: regex"
+            } else {
+                "Search: substring"
+            });
+        }
+        KeyAction::SubmitSearch => {
+            let query = state.take_search();
+            state.mode = InputMode::Normal;
+            // Empty `/` + Enter repeats the last search, as in vim.
+            let query = match (&state.search, query.is_empty()) {
+                (Some(last), true) => last.query.clone(),
+                _ => query,
+            };
+            if !query.is_empty() {
+                let messages = &state.tabs.active_pane().conversation.messages;
+                match ConversationSearch::new(query, state.search_regex, messages) {
+                    Ok(search) => {
+                        state.set_flash(search.position_label());
+                        state.search = Some(search);
+                    }
+                    Err(e) => state.set_flash(format!("Invalid regex: {}", e)),
+                }
+            }
+        }
+        KeyAction::SearchNext | KeyAction::SearchPrev => {
+            let messages = &state.tabs.active_pane().conversation.messages;
+            if let Some(search) = &mut state.search {
+                search.refresh(messages);
+                if action == KeyAction::SearchNext {
+                    search.next();
+                } else {
+                    search.prev();
+                }
+                let label = search.position_label();
+                state.set_flash(label);
+            }
+        }
+
         // Tabs
         KeyAction::NextTab => {
             state.tabs.next_tab();
+            state.search = None;
             if let PaneKind::Interaction(_, Some(id)) = state.tabs.active_pane().kind {
                 let _ = cmd_tx.send(TuiCommand::ActivateInteraction(id));
             }
@@ -181,6 +245,7 @@ pub(super) fn handle_action(
         }
         KeyAction::PrevTab => {
             state.tabs.prev_tab();
+            state.search = None;
             if let PaneKind::Interaction(_, Some(id)) = state.tabs.active_pane().kind {
                 let _ = cmd_tx.send(TuiCommand::ActivateInteraction(id));
             }
@@ -266,6 +331,49 @@ pub(super) fn handle_action(
     None
 }
 
+/// Apply `tui.intent_routing` to input submitted on a placeholder tab.
+///
+/// Only the default Agent placeholder is classified — a tab opened as
+/// `:tabnew ask` keeps its form. In `Suggest` mode the Agent still starts
+/// and a flash hints at the better-fitting command; in `Auto` mode the tab
+/// is re-formed to the suggested interaction. Explicit `:ask` / `:agent` /
+/// `:discuss` commands never reach this path, so they always override.
+fn route_untyped_input(
+    state: &mut TuiState,
+    form: InteractionForm,
+    input: &str,
+) -> InteractionForm {
+    if form != InteractionForm::Agent || state.intent_routing == IntentRouting::Off {
+        return form;
+    }
+    let Some(question) = Question::try_new(input) else {
+        return form;
+    };
+    let suggested = classify_intent(&question);
+    if suggested == form {
+        return form;
+    }
+
+    let command = suggested.to_string();
+    match state.intent_routing {
+        IntentRouting::Auto => {
+            state.tabs.active_pane_mut().kind = PaneKind::Interaction(suggested, None);
+            state.set_flash(format!(
+                "Started as {} (use :agent <request> to run as Agent)",
+                command
+            ));
+            suggested
+        }
+        _ => {
+            state.set_flash(format!(
+                "Tip: this looks like {} — use :{} <query>",
+                command, command
+            ));
+            form
+        }
+    }
+}
+
 /// Advance Command-mode wildmenu completion by one Tab/Shift+Tab press (#326).
 /// No-op outside Command mode (Tab/BackTab only dispatch this action from
 /// `handle_command()`, so this guard should never actually trigger).
@@ -413,6 +521,20 @@ mod tests {
         assert_eq!(state.tabs.active_pane().conversation.messages.len(), 1);
     }
 
+    #[test]
+    fn submit_in_read_only_replay_sends_nothing() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = TuiState::new();
+        state.read_only = true;
+        state.tabs.active_pane_mut().input = "hello".into();
+
+        submit(&mut state, &tx);
+
+        assert!(rx.try_recv().is_err());
+        assert!(state.tabs.active_pane().conversation.messages.is_empty());
+        assert!(state.flash_message.is_some());
+    }
+
     fn run(state: &mut TuiState, action: KeyAction, cmd_tx: &mpsc::UnboundedSender<TuiCommand>) {
         let scripting: Arc<dyn quorum_application::ScriptingEnginePort> =
             Arc::new(NoScriptingEngine);
@@ -493,6 +615,73 @@ mod tests {
         assert!(state.tabs.active_pane().conversation.messages.is_empty());
     }
 
+    fn submit_on_placeholder(
+        routing: quorum_domain::IntentRouting,
+        form: InteractionForm,
+        input: &str,
+    ) -> (TuiState, InteractionForm) {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = TuiState {
+            intent_routing: routing,
+            ..TuiState::new()
+        };
+        state.tabs.create_tab(PaneKind::Interaction(form, None));
+        state.tabs.active_pane_mut().input = input.into();
+
+        submit(&mut state, &tx);
+
+        match rx.try_recv() {
+            Ok(TuiCommand::SpawnInteraction { form, .. }) => (state, form),
+            other => panic!("expected SpawnInteraction, got ok={:?}", other.is_ok()),
+        }
+    }
+
+    #[test]
+    fn intent_suggest_keeps_agent_and_hints() {
+        let (state, form) = submit_on_placeholder(
+            quorum_domain::IntentRouting::Suggest,
+            InteractionForm::Agent,
+            "what does main do?",
+        );
+        assert_eq!(form, InteractionForm::Agent);
+        let (flash, _) = state.flash_message.expect("hint flash");
+        assert!(flash.contains(":ask"), "got: {}", flash);
+    }
+
+    #[test]
+    fn intent_auto_starts_suggested_form() {
+        let (state, form) = submit_on_placeholder(
+            quorum_domain::IntentRouting::Auto,
+            InteractionForm::Agent,
+            "compare tokio and async-std",
+        );
+        assert_eq!(form, InteractionForm::Discuss);
+        // The placeholder is re-formed so InteractionSpawned binds to it.
+        assert_eq!(
+            state.tabs.active_pane().kind,
+            PaneKind::Interaction(InteractionForm::Discuss, None)
+        );
+    }
+
+    #[test]
+    fn intent_routing_leaves_explicit_and_off_alone() {
+        // `:tabnew ask` placeholder keeps its form even for change requests
+        let (_, form) = submit_on_placeholder(
+            quorum_domain::IntentRouting::Auto,
+            InteractionForm::Ask,
+            "add logging to main",
+        );
+        assert_eq!(form, InteractionForm::Ask);
+
+        let (state, form) = submit_on_placeholder(
+            quorum_domain::IntentRouting::Off,
+            InteractionForm::Agent,
+            "what does main do?",
+        );
+        assert_eq!(form, InteractionForm::Agent);
+        assert!(state.flash_message.is_none());
+    }
+
     // -- Command-mode completion (#326) --
 
     fn enter_command(state: &mut TuiState, cmd_tx: &mpsc::UnboundedSender<TuiCommand>, text: &str) {
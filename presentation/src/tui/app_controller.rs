@@ -56,7 +56,36 @@ pub(super) async fn controller_task(
                 match res {
                     Ok((iid, generation, mut completion)) => {
                         let cancelled_state = completion.cancelled_state.take();
+                        let escalation = completion.escalation.take();
                         controller.finalize(completion);
+                        // An Ask handed its request over: run it as an Agent child
+                        if let Some(request) = escalation {
+                            match controller.prepare_escalation(iid, &request) {
+                                Ok(prepared) => spawn_child(
+                                    &mut controller,
+                                    &mut tasks,
+                                    &mut scheduler,
+                                    &progress_tx,
+                                    InteractionForm::Agent,
+                                    prepared,
+                                ),
+                                Err(e) => {
+                                    let _ = progress_tx.send(RoutedTuiEvent::global(TuiEvent::Flash(
+                                        format!("Failed to escalate to an agent: {}", e)
+                                    )));
+                                }
+                            }
+                        }
+                        // Summarize history that outgrew the context budget.
+                        // Runs detached so the model call doesn't block other
+                        // commands; until it lands, requests use the digest.
+                        if let Some(compaction) = controller.history_compaction() {
+                            let tx = progress_tx.clone();
+                            tokio::spawn(async move {
+                                let outcome = compaction.await;
+                                let _ = tx.send(RoutedTuiEvent::global(TuiEvent::HistoryCompacted(outcome)));
+                            });
+                        }
                         if let Some((new_generation, pending)) = scheduler.complete(iid, generation) {
                             spawn_pending(
                                 &mut controller,
@@ -152,6 +181,9 @@ pub(super) async fn controller_task(
                     TuiCommand::SetReferenceResolver(resolver) => {
                         controller.set_reference_resolver(resolver);
                     }
+                    TuiCommand::SetDecisionLog(log) => {
+                        controller.set_decision_log(log);
+                    }
                     TuiCommand::SetScriptingEngine(engine) => {
                         controller.set_scripting_engine(engine);
                     }
@@ -161,34 +193,14 @@ pub(super) async fn controller_task(
                         context_mode_override,
                     } => {
                         match controller.prepare_spawn(form, &query, context_mode_override) {
-                            Ok((child_id, clean_query, full_query)) => {
-                                // Register with the scheduler before spawning: `child_id`
-                                // is freshly allocated so this always resolves to
-                                // `SpawnNow(1)`, but registering is what populates the
-                                // scheduler's generation map — without it, a request
-                                // arriving at this (now bound) tab while the spawn task
-                                // is still running would see the interaction as idle and
-                                // race a second concurrent task for it (issue #318).
-                                let generation = match scheduler.request(child_id, form, clean_query.clone()) {
-                                    RequestAction::SpawnNow(generation) => generation,
-                                    RequestAction::Deferred => unreachable!(
-                                        "child_id is freshly allocated by InteractionTree; the scheduler can't already track it"
-                                    ),
-                                };
-                                let context = controller.build_spawn_context_for(child_id);
-                                spawn_guarded(
-                                    &mut tasks,
-                                    &progress_tx,
-                                    child_id,
-                                    generation,
-                                    context,
-                                    Some(child_id),
-                                    form,
-                                    clean_query,
-                                    full_query,
-                                    None,
-                                );
-                            }
+                            Ok(prepared) => spawn_child(
+                                &mut controller,
+                                &mut tasks,
+                                &mut scheduler,
+                                &progress_tx,
+                                form,
+                                prepared,
+                            ),
                             Err(e) => {
                                 let _ = progress_tx.send(RoutedTuiEvent::global(TuiEvent::Flash(
                                     format!("Failed to prepare spawn: {}", e)
@@ -206,7 +218,7 @@ pub(super) async fn controller_task(
                             controller.prepare_root_spawn(form, label, material);
                         let _ = respond_to.send(root_id);
 
-                        // See the `SpawnInteraction` arm above: registering here is what
+                        // See `spawn_child`: registering here is what
                         // makes a later request to this same root interaction defer
                         // instead of racing a second concurrent task (issue #318).
                         let generation = match scheduler.request(root_id, form, label.clone()) {
@@ -229,12 +241,27 @@ pub(super) async fn controller_task(
                             None,
                         );
                     }
+                    TuiCommand::Summarize { interaction_id, messages } => {
+                        let iid = interaction_id.unwrap_or_else(|| controller.active_interaction_id());
+                        // Runs detached so a slow model doesn't block other commands
+                        // (including the tab close that cancels it).
+                        tokio::spawn(controller.summarize_session(iid, messages));
+                    }
                     TuiCommand::ActivateInteraction(id) => {
                         controller.set_active_interaction(id);
                     }
                     TuiCommand::CancelInteraction(id) => {
                         controller.cancel_interaction(id);
                     }
+                    TuiCommand::CancelEnsembleBranch { interaction_id, model } => {
+                        let iid = interaction_id.unwrap_or_else(|| controller.active_interaction_id());
+                        let flash = match controller.cancel_ensemble_branch(iid, &model) {
+                            Ok(true) => format!("Cancelled {}'s plan", model),
+                            Ok(false) => format!("{} is not generating a plan", model),
+                            Err(e) => e.to_string(),
+                        };
+                        let _ = progress_tx.send(RoutedTuiEvent::for_interaction(iid, TuiEvent::Flash(flash)));
+                    }
                     TuiCommand::Quit => {
                         break;
                     }
@@ -258,6 +285,43 @@ fn notify_deferred(progress_tx: &mpsc::UnboundedSender<RoutedTuiEvent>, iid: Int
     ));
 }
 
+/// Spawn a freshly prepared child interaction (`prepare_spawn` /
+/// `prepare_escalation` output) as its own task.
+fn spawn_child(
+    controller: &mut AgentController,
+    tasks: &mut ControllerJoinSet,
+    scheduler: &mut InteractionScheduler,
+    progress_tx: &mpsc::UnboundedSender<RoutedTuiEvent>,
+    form: InteractionForm,
+    (child_id, clean_query, full_query): (InteractionId, String, String),
+) {
+    // Register with the scheduler before spawning: `child_id` is freshly
+    // allocated so this always resolves to `SpawnNow(1)`, but registering is
+    // what populates the scheduler's generation map — without it, a request
+    // arriving at this (now bound) tab while the spawn task is still running
+    // would see the interaction as idle and race a second concurrent task
+    // for it (issue #318).
+    let generation = match scheduler.request(child_id, form, clean_query.clone()) {
+        RequestAction::SpawnNow(generation) => generation,
+        RequestAction::Deferred => unreachable!(
+            "child_id is freshly allocated by InteractionTree; the scheduler can't already track it"
+        ),
+    };
+    let context = controller.build_spawn_context_for(child_id);
+    spawn_guarded(
+        tasks,
+        progress_tx,
+        child_id,
+        generation,
+        context,
+        Some(child_id),
+        form,
+        clean_query,
+        full_query,
+        None,
+    );
+}
+
 /// Spawn an inline (no tree node) or command-execute task for `iid`, tagging
 /// its completion with `generation` so `join_next` can route it back through
 /// the [`InteractionScheduler`] via [`InteractionScheduler::complete`].
@@ -417,6 +481,7 @@ async fn catch_panic(
                 query,
                 result: None,
                 cancelled_state: None,
+                escalation: None,
             },
             // `&*payload`, not `&payload`: `Box<dyn Any + Send>` is itself
             // `Any + Send` (blanket impl), so `&payload` would coerce to
@@ -457,6 +522,7 @@ mod tests {
             query: "hello".to_string(),
             result: None,
             cancelled_state: None,
+            escalation: None,
         };
         let fut = async { completion };
 
@@ -478,6 +544,7 @@ mod tests {
                 query: String::new(),
                 result: None,
                 cancelled_state: None,
+                escalation: None,
             }
         };
 
@@ -524,6 +591,7 @@ mod tests {
                 query: String::new(),
                 result: None,
                 cancelled_state: None,
+                escalation: None,
             }
         };
         let (completion, message) =
@@ -570,6 +638,7 @@ mod tests {
                 query: String::new(),
                 result: None,
                 cancelled_state: None,
+                escalation: None,
             }
         };
         let (completion, _message) =
@@ -10,6 +10,7 @@ use super::state::{
 };
 use super::surface::SurfaceId;
 use super::widgets::model_stream::ModelStreamRenderer;
+use quorum_application::CompactionOutcome;
 use quorum_domain::core::string::truncate;
 use quorum_domain::interaction::InteractionId;
 use std::cell::RefCell;
@@ -59,6 +60,7 @@ fn apply_tui_event_to_interaction(
         TuiEvent::PhaseChange { phase, name } => {
             if let Some(pane) = state.tabs.pane_for_interaction_mut(id) {
                 let progress = &mut pane.progress;
+                progress.enter_phase(phase.clone(), name.clone());
                 progress.current_phase = Some(phase);
                 progress.phase_name = name;
             }
@@ -82,6 +84,7 @@ fn apply_tui_event_to_interaction(
                     completed_tasks,
                     active_tool_executions: Vec::new(),
                 });
+                progress.record_event(format!("Task {}/{}: {}", index, total, description));
             }
             state.push_message_to(
                 id,
@@ -105,6 +108,8 @@ fn apply_tui_event_to_interaction(
                 } else {
                     Vec::new()
                 };
+                let icon = if success { "✓" } else { "✗" };
+                progress.record_event(format!("{} Task {}: {}", icon, index, description));
                 if let Some(ref mut tp) = progress.task_progress {
                     tp.completed_tasks.push(TaskSummary {
                         index,
@@ -219,12 +224,18 @@ fn apply_tui_event_to_interaction(
             feedback: _,
         } => {
             let status = if approved { "APPROVED" } else { "REJECTED" };
-            state.set_flash(format!("{}: {}", phase, status));
             if let Some(pane) = state.tabs.pane_for_interaction_mut(id) {
                 pane.progress.quorum_status = None;
+                pane.progress
+                    .record_event(format!("Quorum {}: {}", phase, status));
             }
+            state.set_flash(format!("{}: {}", phase, status));
         }
         TuiEvent::PlanRevision { revision, feedback } => {
+            if let Some(pane) = state.tabs.pane_for_interaction_mut(id) {
+                pane.progress
+                    .record_event(format!("Plan revision #{}", revision));
+            }
             state.push_message_to(
                 id,
                 DisplayMessage::system(format!("Plan revision #{}: {}", revision, feedback)),
@@ -240,6 +251,7 @@ fn apply_tui_event_to_interaction(
                     voting_started: false,
                     plan_count: None,
                     selected: None,
+                    merged: None,
                 });
             }
         }
@@ -248,7 +260,8 @@ fn apply_tui_event_to_interaction(
                 && let Some(ref mut ep) = pane.progress.ensemble_progress
             {
                 ep.plans_generated += 1;
-                ep.models_completed.push(model);
+                ep.models_completed.push(model.clone());
+                pane.progress.record_event(format!("Plan from {}", model));
             }
         }
         TuiEvent::EnsembleVotingStart(plan_count) => {
@@ -283,6 +296,17 @@ fn apply_tui_event_to_interaction(
                 )),
             );
         }
+        TuiEvent::EnsembleMerged { moderator, sources } => {
+            if let Some(pane) = state.tabs.pane_for_interaction_mut(id)
+                && let Some(ref mut ep) = pane.progress.ensemble_progress
+            {
+                ep.merged = Some((moderator.clone(), sources));
+            }
+            state.push_message_to(
+                id,
+                DisplayMessage::system(format!("Merged top {} plans via {}", sources, moderator)),
+            );
+        }
         TuiEvent::EnsembleFallback(reason) => {
             state.push_message_to(
                 id,
@@ -343,6 +367,7 @@ fn apply_tui_event_to_interaction(
                 progress.quorum_status = None;
                 progress.task_progress = None;
                 progress.ensemble_progress = None;
+                progress.phase_groups.clear();
             }
         }
         TuiEvent::AgentResult {
@@ -370,9 +395,24 @@ fn apply_tui_event_to_interaction(
             state.set_flash(msg);
         }
         TuiEvent::HistoryCleared => {}
+        TuiEvent::HistoryCompacted(outcome) => flash_compaction(state, outcome),
         TuiEvent::Exit => {
             state.should_quit = true;
         }
+        TuiEvent::ToolExecutionDiff { execution_id, diff } => {
+            if let Some(exec) = state
+                .tabs
+                .pane_for_interaction_mut(id)
+                .and_then(|pane| pane.progress.task_progress.as_mut())
+                .and_then(|tp| {
+                    tp.active_tool_executions
+                        .iter_mut()
+                        .find(|e| e.execution_id == execution_id)
+                })
+            {
+                exec.diff = Some(diff);
+            }
+        }
         TuiEvent::ToolExecutionUpdate {
             task_index: _,
             execution_id,
@@ -413,6 +453,28 @@ fn apply_tui_event_to_interaction(
                     }
                 };
 
+                // Finished executions go into the phase history; updates
+                // after the first usually omit the args preview.
+                let finished_line = match &display_status {
+                    ToolExecutionDisplayStatus::Completed { .. } => {
+                        let args = args_preview.clone().or_else(|| {
+                            tp.active_tool_executions
+                                .iter()
+                                .find(|e| e.execution_id == execution_id)
+                                .and_then(|e| e.args_preview.clone())
+                        });
+                        Some(format!(
+                            "✓ {}{}",
+                            tool_name,
+                            args.map(|p| format!("  {}", p)).unwrap_or_default()
+                        ))
+                    }
+                    ToolExecutionDisplayStatus::Error { message } => {
+                        Some(format!("✗ {} — {}", tool_name, message))
+                    }
+                    _ => None,
+                };
+
                 if let Some(existing) = tp
                     .active_tool_executions
                     .iter_mut()
@@ -427,8 +489,12 @@ fn apply_tui_event_to_interaction(
                         state: display_status,
                         duration_ms,
                         args_preview,
+                        diff: None,
                     });
                 }
+                if let Some(line) = finished_line {
+                    pane.progress.record_event(line);
+                }
             }
 
             if let Some(msg) = flash_msg {
@@ -465,6 +531,7 @@ fn apply_tui_event(
             state.should_quit = true;
         }
         TuiEvent::HistoryCleared => {}
+        TuiEvent::HistoryCompacted(outcome) => flash_compaction(state, outcome),
         TuiEvent::Welcome { .. }
         | TuiEvent::ConfigDisplay(_)
         | TuiEvent::ModeChanged { .. }
@@ -475,6 +542,17 @@ fn apply_tui_event(
     }
 }
 
+/// Tell the user how the background history compaction ended.
+fn flash_compaction(state: &mut TuiState, outcome: CompactionOutcome) {
+    match outcome {
+        CompactionOutcome::Summarized => state.set_flash("Earlier conversation summarized"),
+        CompactionOutcome::Failed(error) => {
+            state.set_flash(format!("History summary failed, using digest: {}", error))
+        }
+        CompactionOutcome::Cancelled => {}
+    }
+}
+
 /// Extract the meaningful LLM analysis text from task output.
 ///
 /// Task output contains interleaved tool results and LLM text separated by `\n---\n`.
@@ -1,10 +1,13 @@
 //! Human-in-the-Loop (HiL) modal handling.
 
+use super::app::SideEffect;
+use super::editor::{EditorResult, parse_plan_edit, plan_edit_text};
 use super::event::{HilKind, HilRequest};
 use super::state::{HilPrompt, TuiState};
 use quorum_domain::HumanDecision;
 use quorum_domain::quorum::ObjectionSeverity;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tokio::sync::oneshot;
 
 /// Handle HiL request — show modal, store response channel.
@@ -13,6 +16,18 @@ pub(super) fn handle_hil_request(
     pending_hil_tx: &Arc<Mutex<Option<oneshot::Sender<HumanDecision>>>>,
     request: HilRequest,
 ) {
+    // Nothing can be approved while viewing a replayed log
+    if state.read_only {
+        let _ = request.response_tx.send(HumanDecision::Reject);
+        state.set_flash("Read-only replay — approval request rejected");
+        return;
+    }
+
+    // Only plan intervention acts on HumanDecision::Edit
+    let plan_draft = match &request.kind {
+        HilKind::PlanIntervention { plan, .. } => Some(plan_edit_text(plan)),
+        _ => None,
+    };
     let (title, objective, tasks, message) = match &request.kind {
         HilKind::PlanIntervention {
             request: _req,
@@ -25,7 +40,7 @@ pub(super) fn handle_hil_request(
                 plan.objective.clone(),
                 plan.tasks.iter().map(|t| t.description.clone()).collect(),
                 format!(
-                    "Revision limit ({}) exceeded. Approve or reject?",
+                    "Revision limit ({}) exceeded. Approve, reject, or edit the plan?",
                     rev_count
                 ),
             )
@@ -34,7 +49,7 @@ pub(super) fn handle_hil_request(
             "Ready to Execute Plan".to_string(),
             plan.objective.clone(),
             plan.tasks.iter().map(|t| t.description.clone()).collect(),
-            "Approve execution?".to_string(),
+            format!("Impact: {}. Approve execution?", plan.impact_summary()),
         ),
         HilKind::DebateEscalation {
             question,
@@ -69,6 +84,16 @@ pub(super) fn handle_hil_request(
                 )
             },
         ),
+        HilKind::ActionApproval {
+            task,
+            tool_call_json,
+            reason,
+        } => (
+            "High-Risk Action Needs Approval".to_string(),
+            task.clone(),
+            tool_call_json.lines().map(str::to_string).collect(),
+            format!("{}. Run this action?", reason),
+        ),
     };
 
     state.hil_prompt = Some(HilPrompt {
@@ -76,23 +101,47 @@ pub(super) fn handle_hil_request(
         objective,
         tasks,
         message,
+        plan_draft,
+        deadline: request.deadline,
     });
 
     // Store the response sender — will be consumed when user presses y/n
     *pending_hil_tx.lock().unwrap() = Some(request.response_tx);
 }
 
+/// Close the modal once its deadline has passed.
+///
+/// The requester has already stopped waiting and taken the timeout
+/// default, so the stored response sender is just dropped.
+pub(super) fn expire_hil_prompt(
+    state: &mut TuiState,
+    pending_hil_tx: &Arc<Mutex<Option<oneshot::Sender<HumanDecision>>>>,
+) {
+    let expired = state
+        .hil_prompt
+        .as_ref()
+        .and_then(|p| p.deadline)
+        .is_some_and(|deadline| Instant::now() >= deadline);
+    if expired {
+        state.pending_key = None;
+        state.hil_prompt = None;
+        pending_hil_tx.lock().unwrap().take();
+        state.set_flash("No answer in time — default decision taken");
+    }
+}
+
 /// Handle key press while HiL modal is shown.
 ///
-/// Decision keys (y/n/Esc) answer the prompt. All other keys are resolved
-/// through the Normal-mode keymap and scroll actions are delegated to the
-/// conversation pane, so the user can read the Quorum review feedback
-/// behind the modal before deciding (#269).
+/// Decision keys (y/n/Esc) answer the prompt; `e` asks the main loop to
+/// open the plan in $EDITOR when the prompt has a plan draft. All other keys
+/// are resolved through the Normal-mode keymap and scroll actions are
+/// delegated to the conversation pane, so the user can read the Quorum
+/// review feedback behind the modal before deciding (#269).
 pub(super) fn handle_hil_key(
     state: &mut TuiState,
     pending_hil_tx: &Arc<Mutex<Option<oneshot::Sender<HumanDecision>>>>,
     key: crossterm::event::KeyEvent,
-) {
+) -> Option<SideEffect> {
     use super::mode::{self, InputMode, KeyAction};
     use crossterm::event::KeyCode;
     match key.code {
@@ -108,12 +157,21 @@ pub(super) fn handle_hil_key(
             state.set_flash("Plan rejected");
             send_hil_response(pending_hil_tx, HumanDecision::Reject);
         }
+        KeyCode::Char('e') | KeyCode::Char('E')
+            if state
+                .hil_prompt
+                .as_ref()
+                .is_some_and(|p| p.plan_draft.is_some()) =>
+        {
+            state.pending_key = None;
+            return Some(SideEffect::EditPlan);
+        }
         _ => {
             // Delegate scroll keys (j/k/gg/G/arrows) to the conversation pane.
             let action = mode::handle_key_event(InputMode::Normal, key, state.pending_key);
             if let KeyAction::PendingKey(c) = action {
                 state.pending_key = Some(c);
-                return;
+                return None;
             }
             state.pending_key = None;
             match action {
@@ -125,6 +183,40 @@ pub(super) fn handle_hil_key(
             }
         }
     }
+    None
+}
+
+/// Apply the result of editing the prompt's plan draft in $EDITOR.
+///
+/// A plan that parses answers the prompt with [`HumanDecision::Edit`]. One
+/// that does not keeps the prompt open with the user's text as the new
+/// draft, so pressing `e` again resumes the edit instead of starting over.
+pub(super) fn apply_plan_edit(
+    state: &mut TuiState,
+    pending_hil_tx: &Arc<Mutex<Option<oneshot::Sender<HumanDecision>>>>,
+    result: EditorResult,
+) {
+    let EditorResult::Saved(text) = result else {
+        state.set_flash("Plan edit cancelled");
+        return;
+    };
+    match parse_plan_edit(&text) {
+        Ok(plan) => {
+            state.hil_prompt = None;
+            state.set_flash("Edited plan submitted");
+            send_hil_response(pending_hil_tx, HumanDecision::Edit(plan));
+        }
+        Err(error) => {
+            if let Some(prompt) = state.hil_prompt.as_mut() {
+                prompt.message = format!(
+                    "Edited plan could not be parsed ({}). Press e to fix it, or y/n to decide.",
+                    error
+                );
+                prompt.plan_draft = Some(text);
+            }
+            state.set_flash("Edited plan could not be parsed");
+        }
+    }
 }
 
 /// Send the stored HiL response (consumes the oneshot sender).
@@ -172,6 +264,7 @@ mod tests {
                 transcript_summary: "3 rounds, 1 objection remains".to_string(),
                 can_continue: false,
             },
+            deadline: None,
             response_tx,
         };
 
@@ -203,6 +296,7 @@ mod tests {
                 transcript_summary: "1 round, 1 objection remains".to_string(),
                 can_continue: true,
             },
+            deadline: None,
             response_tx,
         };
 
@@ -214,6 +308,76 @@ mod tests {
         assert!(pending_hil_tx.lock().unwrap().is_some());
     }
 
+    #[test]
+    fn handle_hil_request_builds_prompt_for_action_approval() {
+        let mut state = TuiState::new();
+        let (response_tx, _response_rx) = oneshot::channel();
+        let pending_hil_tx = Arc::new(Mutex::new(None));
+
+        let request = HilRequest {
+            kind: HilKind::ActionApproval {
+                task: "Clean build".to_string(),
+                tool_call_json: "{\n  \"tool\": \"run_command\"\n}".to_string(),
+                reason: "Only 1 reviewer(s) configured; high-risk actions require at least 2"
+                    .to_string(),
+            },
+            deadline: None,
+            response_tx,
+        };
+
+        handle_hil_request(&mut state, &pending_hil_tx, request);
+
+        let prompt = state.hil_prompt.expect("hil_prompt should be set");
+        assert_eq!(prompt.title, "High-Risk Action Needs Approval");
+        assert_eq!(prompt.objective, "Clean build");
+        assert_eq!(prompt.tasks.len(), 3);
+        assert!(prompt.message.contains("require at least 2"));
+        assert!(pending_hil_tx.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn handle_hil_request_rejects_in_read_only_replay() {
+        let mut state = TuiState::new();
+        state.read_only = true;
+        let (response_tx, mut response_rx) = oneshot::channel();
+        let pending_hil_tx = Arc::new(Mutex::new(None));
+
+        let request = HilRequest {
+            kind: HilKind::ActionApproval {
+                task: "Clean build".to_string(),
+                tool_call_json: "{}".to_string(),
+                reason: "High-risk".to_string(),
+            },
+            deadline: None,
+            response_tx,
+        };
+
+        handle_hil_request(&mut state, &pending_hil_tx, request);
+
+        assert!(state.hil_prompt.is_none());
+        assert!(pending_hil_tx.lock().unwrap().is_none());
+        assert!(matches!(
+            response_rx.try_recv().unwrap(),
+            HumanDecision::Reject
+        ));
+    }
+
+    #[test]
+    fn expire_hil_prompt_closes_modal_past_deadline() {
+        let pending_hil_tx = Arc::new(Mutex::new(None));
+        let (response_tx, _response_rx) = oneshot::channel();
+        *pending_hil_tx.lock().unwrap() = Some(response_tx);
+
+        let mut state = state_with_modal();
+        expire_hil_prompt(&mut state, &pending_hil_tx);
+        assert!(state.hil_prompt.is_some(), "no deadline, stays open");
+
+        state.hil_prompt.as_mut().unwrap().deadline = Some(Instant::now());
+        expire_hil_prompt(&mut state, &pending_hil_tx);
+        assert!(state.hil_prompt.is_none());
+        assert!(pending_hil_tx.lock().unwrap().is_none());
+    }
+
     fn state_with_modal() -> TuiState {
         let mut state = TuiState::new();
         state.hil_prompt = Some(HilPrompt {
@@ -221,10 +385,97 @@ mod tests {
             objective: "Test objective".into(),
             tasks: vec!["task 1".into()],
             message: "Approve or reject?".into(),
+            plan_draft: Some(plan_edit_text(&sample_plan())),
+            deadline: None,
         });
         state
     }
 
+    fn sample_plan() -> quorum_domain::Plan {
+        let mut plan = quorum_domain::Plan::new("Test objective", "");
+        plan.add_task(quorum_domain::Task::new("1", "task 1"));
+        plan
+    }
+
+    #[test]
+    fn plan_intervention_prompt_offers_plan_draft() {
+        let mut state = TuiState::new();
+        let (response_tx, _response_rx) = oneshot::channel();
+        let pending_hil_tx = Arc::new(Mutex::new(None));
+
+        let request = HilRequest {
+            kind: HilKind::PlanIntervention {
+                request: "fix it".to_string(),
+                plan: sample_plan(),
+                review_history: vec![],
+            },
+            deadline: None,
+            response_tx,
+        };
+        handle_hil_request(&mut state, &pending_hil_tx, request);
+
+        let prompt = state.hil_prompt.expect("hil_prompt should be set");
+        let draft = prompt.plan_draft.expect("plan intervention is editable");
+        assert!(draft.contains("\"task 1\""));
+        assert!(prompt.message.contains("edit the plan"));
+    }
+
+    #[test]
+    fn e_requests_plan_editor_only_with_a_draft() {
+        let mut state = state_with_modal();
+        let tx = hil_tx();
+        let effect = handle_hil_key(&mut state, &tx, key(KeyCode::Char('e')));
+        assert!(matches!(effect, Some(SideEffect::EditPlan)));
+        assert!(state.hil_prompt.is_some());
+
+        state.hil_prompt.as_mut().unwrap().plan_draft = None;
+        assert!(handle_hil_key(&mut state, &tx, key(KeyCode::Char('e'))).is_none());
+        assert!(tx.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn parsed_plan_edit_answers_with_edit() {
+        let mut state = state_with_modal();
+        let (tx, rx) = oneshot::channel();
+        let tx = Arc::new(Mutex::new(Some(tx)));
+        let text = plan_edit_text(&sample_plan()).replace("task 1", "renamed task");
+
+        apply_plan_edit(&mut state, &tx, EditorResult::Saved(text));
+
+        assert!(state.hil_prompt.is_none());
+        match rx.blocking_recv() {
+            Ok(HumanDecision::Edit(plan)) => assert_eq!(plan.tasks[0].description, "renamed task"),
+            other => panic!("expected Edit, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unparsable_plan_edit_reprompts_with_draft() {
+        let mut state = state_with_modal();
+        let tx = hil_tx();
+        let broken = "```plan\n{\"objective\": \"Test objective\", \"tasks\": [\n```".to_string();
+
+        apply_plan_edit(&mut state, &tx, EditorResult::Saved(broken.clone()));
+
+        let prompt = state.hil_prompt.as_ref().expect("prompt stays open");
+        assert_eq!(prompt.plan_draft.as_deref(), Some(broken.as_str()));
+        assert!(prompt.message.contains("could not be parsed"));
+        assert!(tx.lock().unwrap().is_some(), "no decision is sent");
+
+        // Editing again resumes from the draft
+        let effect = handle_hil_key(&mut state, &tx, key(KeyCode::Char('e')));
+        assert!(matches!(effect, Some(SideEffect::EditPlan)));
+    }
+
+    #[test]
+    fn cancelled_plan_edit_keeps_prompt() {
+        let mut state = state_with_modal();
+        let tx = hil_tx();
+        apply_plan_edit(&mut state, &tx, EditorResult::Cancelled);
+        assert!(state.hil_prompt.is_some());
+        assert!(tx.lock().unwrap().is_some());
+    }
+
     fn hil_tx() -> Arc<Mutex<Option<oneshot::Sender<HumanDecision>>>> {
         let (tx, _rx) = oneshot::channel();
         Arc::new(Mutex::new(Some(tx)))
@@ -4,22 +4,23 @@ use super::content::ContentRegistry;
 use super::state::TuiState;
 use super::surface::SurfaceId;
 use super::widgets::{
-    MainLayout, header::HeaderWidget, input::InputWidget, status_bar::StatusBarWidget,
-    tab_bar::TabBarWidget,
+    MainLayout, bordered_block, header::HeaderWidget, input::InputWidget,
+    status_bar::StatusBarWidget, strip_colors, tab_bar::TabBarWidget,
 };
 use std::cell::RefCell;
 
 /// Build the default content registry with all built-in renderers.
 pub(super) fn build_default_registry() -> ContentRegistry {
     use super::widgets::{
-        conversation::ConversationRenderer, progress_panel::ProgressRenderer,
-        tool_log::ToolLogRenderer,
+        conversation::ConversationRenderer, council_columns::CouncilColumnsRenderer,
+        progress_panel::ProgressRenderer, tool_log::ToolLogRenderer,
     };
 
     ContentRegistry::new()
         .register(Box::new(ConversationRenderer))
         .register(Box::new(ProgressRenderer))
         .register(Box::new(ToolLogRenderer))
+        .register(Box::new(CouncilColumnsRenderer))
 }
 
 /// Compute the frame layout for `state` at `area`.
@@ -32,28 +33,14 @@ pub(super) fn compute_layout(
 ) -> (MainLayout, Vec<super::surface::SurfaceId>) {
     let pane_surfaces = state.route.required_pane_surfaces();
     let show_tab_bar = state.tabs.len() > 1;
-    let layout = if state.layout_config.preset.is_builtin() {
-        MainLayout::compute_with_layout(
-            area,
-            state.input_line_count() as u16,
-            state.tui_config.max_input_height,
-            show_tab_bar,
-            state.layout_config.preset.clone(),
-            state.layout_config.flex_threshold,
-            pane_surfaces.len(),
-        )
-    } else {
-        let splits = state.layout_config.resolve_splits(pane_surfaces.len());
-        let direction = state.layout_config.resolve_direction();
-        MainLayout::compute_with_splits(
-            area,
-            state.input_line_count() as u16,
-            state.tui_config.max_input_height,
-            show_tab_bar,
-            &splits,
-            direction,
-        )
-    };
+    let layout = MainLayout::compute_with_layout(
+        area,
+        state.input_line_count() as u16,
+        state.tui_config.max_input_height,
+        show_tab_bar,
+        &state.layout_config,
+        pane_surfaces.len(),
+    );
     (layout, pane_surfaces)
 }
 
@@ -100,6 +87,11 @@ pub(super) fn render(
         frame.render_widget(ratatui::widgets::Clear, modal_area);
         render_hil_modal(frame, modal_area, state);
     }
+
+    if state.ascii_mode {
+        let area = frame.area();
+        strip_colors(frame.buffer_mut(), area);
+    }
 }
 
 /// Help overlay content — shared by the renderer and the scroll clamp logic.
@@ -126,6 +118,7 @@ fn help_lines() -> Vec<ratatui::text::Line<'static>> {
         Line::from("  j/k    Scroll down/up"),
         Line::from("  gg/G   Scroll to top/bottom"),
         Line::from("  gt/gT  Next/prev tab"),
+        Line::from("  za     Fold/unfold earlier progress phases"),
         Line::from("  ?      Toggle this help"),
         Line::from("  Ctrl+C Quit"),
         Line::from(""),
@@ -145,6 +138,12 @@ fn help_lines() -> Vec<ratatui::text::Line<'static>> {
         Line::from("  y/Enter   Yank selection"),
         Line::from("  Esc/v     Exit to Normal"),
         Line::from(""),
+        Line::from("Search:"),
+        Line::from("  /      Search conversation (Enter to run)"),
+        Line::from("  Ctrl+r Toggle regex while typing"),
+        Line::from("  n/N    Next/prev match"),
+        Line::from("  Esc    Clear highlights (Normal mode)"),
+        Line::from(""),
         Line::from("Insert Mode:"),
         Line::from("  Enter        Send message"),
         Line::from("  Shift+Enter  Insert newline (multiline)"),
@@ -193,7 +192,7 @@ pub(super) fn help_max_scroll(term_size: (u16, u16)) -> u16 {
 
 fn render_help(frame: &mut ratatui::Frame, area: ratatui::layout::Rect, state: &TuiState) {
     use ratatui::style::{Color, Style};
-    use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+    use ratatui::widgets::{Paragraph, Wrap};
 
     let lines = help_lines();
     let content_width = area.width.saturating_sub(2); // borders
@@ -206,8 +205,7 @@ fn render_help(frame: &mut ratatui::Frame, area: ratatui::layout::Rect, state: &
     let max_scroll = total_lines.saturating_sub(visible_height);
     let offset = state.help_scroll.min(max_scroll);
 
-    let block = Block::default()
-        .borders(Borders::ALL)
+    let block = bordered_block(state)
         .title(" Help ")
         .style(Style::default().fg(Color::Cyan));
 
@@ -217,7 +215,7 @@ fn render_help(frame: &mut ratatui::Frame, area: ratatui::layout::Rect, state: &
 fn render_hil_modal(frame: &mut ratatui::Frame, area: ratatui::layout::Rect, state: &TuiState) {
     use ratatui::style::{Color, Modifier, Style};
     use ratatui::text::{Line, Span};
-    use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+    use ratatui::widgets::{Paragraph, Wrap};
 
     let hil = state.hil_prompt.as_ref().unwrap();
     let mut lines = vec![
@@ -238,14 +236,28 @@ fn render_hil_modal(frame: &mut ratatui::Frame, area: ratatui::layout::Rect, sta
 
     lines.push(Line::from(""));
     lines.push(Line::from(&*hil.message));
+    if let Some(deadline) = hil.deadline {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        lines.push(Line::from(Span::styled(
+            format!(
+                "No answer in {}s takes the default decision",
+                remaining.as_secs_f64().ceil() as u64
+            ),
+            Style::default().fg(Color::Red),
+        )));
+    }
     lines.push(Line::from(""));
+    let hint = if hil.plan_draft.is_some() {
+        "y: approve  n: reject  e: edit plan  Esc: reject  j/k: scroll conversation"
+    } else {
+        "y: approve  n: reject  Esc: reject  j/k: scroll conversation"
+    };
     lines.push(Line::from(Span::styled(
-        "y: approve  n: reject  Esc: reject  j/k: scroll conversation",
+        hint,
         Style::default().fg(Color::DarkGray),
     )));
 
-    let block = Block::default()
-        .borders(Borders::ALL)
+    let block = bordered_block(state)
         .title(" Human Intervention ")
         .style(Style::default().fg(Color::Yellow));
 
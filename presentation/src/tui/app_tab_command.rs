@@ -79,6 +79,11 @@ pub(super) fn handle_tab_command(
     let trimmed = cmd.trim();
     let normalized = trimmed.strip_prefix(':').unwrap_or(trimmed);
 
+    // `council` spawns a Discuss interaction through the controller
+    if state.read_only && (normalized == "council" || normalized.starts_with("council ")) {
+        return Some("Read-only replay — nothing is started".into());
+    }
+
     if normalized == "ask"
         || normalized.starts_with("ask ")
         || normalized == "discuss"
@@ -91,6 +96,14 @@ pub(super) fn handle_tab_command(
             if rest.is_empty() {
                 return Some(format!("Usage: {} <query>", cmd_name));
             }
+            // `:discuss --save <path>` exports the last transcript — that's a
+            // controller command, not a new Discuss tab.
+            if form == InteractionForm::Discuss && rest.trim_start().starts_with("--save") {
+                return None;
+            }
+            if state.read_only {
+                return Some("Read-only replay — nothing is started".into());
+            }
             let query = rest.trim().to_string();
 
             // Fix A: Create placeholder tab immediately so the user sees it
@@ -109,6 +122,43 @@ pub(super) fn handle_tab_command(
         }
     }
 
+    if trimmed == "export" || trimmed.starts_with("export ") {
+        let path = trimmed.strip_prefix("export").unwrap().trim();
+        return Some(super::export::export_conversation(state, path));
+    }
+
+    if trimmed == "summarize" {
+        let pane = state.tabs.active_pane();
+        let PaneKind::Interaction(_, interaction_id) = pane.kind;
+        let messages: Vec<_> = pane
+            .conversation
+            .messages
+            .iter()
+            .filter_map(DisplayMessage::to_message)
+            .collect();
+        if messages.is_empty() {
+            return Some("Nothing to summarize yet".into());
+        }
+        let _ = cmd_tx.send(TuiCommand::Summarize {
+            interaction_id,
+            messages,
+        });
+        return Some("Summarizing conversation...".into());
+    }
+
+    if trimmed == "cancel" || trimmed.starts_with("cancel ") {
+        let model = trimmed.strip_prefix("cancel").unwrap().trim();
+        if model.is_empty() {
+            return Some("Usage: cancel <model>".into());
+        }
+        let PaneKind::Interaction(_, interaction_id) = state.tabs.active_pane().kind;
+        let _ = cmd_tx.send(TuiCommand::CancelEnsembleBranch {
+            interaction_id,
+            model: model.to_string(),
+        });
+        return Some(format!("Cancelling {}...", model));
+    }
+
     if trimmed == "tabs" {
         // List all tabs
         let summary = state.tabs.tab_list_summary();
@@ -211,6 +261,31 @@ mod tests {
         assert_eq!(state.tabs.len(), 2);
     }
 
+    #[test]
+    fn cancel_sends_ensemble_branch_command() {
+        use quorum_domain::interaction::InteractionId;
+        let (mut state, tx, mut rx) = setup();
+        state.tabs.create_tab(PaneKind::Interaction(
+            InteractionForm::Agent,
+            Some(InteractionId(5)),
+        ));
+
+        assert!(handle_tab_command(&mut state, "cancel", &tx).is_some());
+        assert!(rx.try_recv().is_err());
+
+        handle_tab_command(&mut state, "cancel claude-haiku-4.5", &tx);
+        match rx.try_recv() {
+            Ok(TuiCommand::CancelEnsembleBranch {
+                interaction_id,
+                model,
+            }) => {
+                assert_eq!(interaction_id, Some(InteractionId(5)));
+                assert_eq!(model, "claude-haiku-4.5");
+            }
+            _ => panic!("expected CancelEnsembleBranch"),
+        }
+    }
+
     #[test]
     fn closing_bound_tab_cancels_its_interaction() {
         use quorum_domain::interaction::InteractionId;
@@ -245,6 +320,30 @@ mod tests {
         assert!(rx.try_recv().is_err());
     }
 
+    #[test]
+    fn discuss_save_is_left_to_the_controller() {
+        let (mut state, tx, mut rx) = setup();
+        assert!(handle_tab_command(&mut state, "discuss --save out.md", &tx).is_none());
+        assert_eq!(state.tabs.len(), 1);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn read_only_replay_spawns_nothing() {
+        let (mut state, tx, mut rx) = setup();
+        state.read_only = true;
+        for cmd in [
+            "agent fix it",
+            "ask why?",
+            "discuss which?",
+            "council which?",
+        ] {
+            assert!(handle_tab_command(&mut state, cmd, &tx).is_some());
+        }
+        assert_eq!(state.tabs.len(), 1);
+        assert!(rx.try_recv().is_err());
+    }
+
     #[test]
     fn non_quit_commands_pass_through() {
         let (mut state, tx, _rx) = setup();
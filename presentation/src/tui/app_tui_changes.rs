@@ -19,7 +19,7 @@ pub(super) fn apply_pending_tui_changes(
 
     // 1. Register custom presets (before preset_switch may reference them)
     for (name, config) in changes.new_presets {
-        state.layout_config.custom_presets.insert(name, config);
+        state.layout_config.register_custom_preset(&name, &config);
     }
 
     // 2. Switch preset (changes the route table base)
@@ -31,10 +31,7 @@ pub(super) fn apply_pending_tui_changes(
             "stacked" => LayoutPreset::Stacked,
             _ => LayoutPreset::Custom(preset_name),
         };
-        state.route = super::route::RouteTable::from_preset_and_overrides(
-            state.layout_config.preset.clone(),
-            &state.layout_config.route_overrides,
-        );
+        state.route = super::route::RouteTable::from_layout_config(&state.layout_config);
     }
 
     // 3. Apply route overrides (on top of the current preset)
@@ -50,10 +47,7 @@ pub(super) fn apply_pending_tui_changes(
                     .push(super::layout::RouteOverride { content, surface });
             }
         }
-        state.route = super::route::RouteTable::from_preset_and_overrides(
-            state.layout_config.preset.clone(),
-            &state.layout_config.route_overrides,
-        );
+        state.route = super::route::RouteTable::from_layout_config(&state.layout_config);
     }
 
     // 4. Register new Lua content slots
@@ -117,6 +111,7 @@ mod tests {
                 CustomPresetConfig {
                     splits: vec![70, 30],
                     direction: "horizontal".to_string(),
+                    ..Default::default()
                 },
             )],
             preset_switch: Some("my_layout".to_string()),
@@ -279,12 +279,15 @@ mod tests {
 
     #[test]
     fn advance_extends_to_longest_common_prefix() {
-        // "s" matches scope/solo/strategy among builtins. Their LCP is just
+        // "s" matches scope/solo/strategy/summarize among builtins. Their LCP is just
         // "s" again (no shared second character), so this press can't
         // extend the text — it falls straight into cycling (see module doc
         // deviation from vim).
         let (new_input, state) = advance("s", None, &[], CompletionDirection::Forward).unwrap();
-        assert_eq!(state.matches, names(&["scope", "solo", "strategy"]));
+        assert_eq!(
+            state.matches,
+            names(&["scope", "solo", "strategy", "summarize"])
+        );
         assert_eq!(new_input, "scope");
         assert_eq!(state.cycle_index, Some(0));
     }
@@ -377,8 +380,13 @@ mod tests {
 
     #[test]
     fn advance_completes_config_key_argument() {
-        let (new_input, _state) =
-            advance("config agent.cons", None, &[], CompletionDirection::Forward).unwrap();
+        let (new_input, _state) = advance(
+            "config agent.consensus_l",
+            None,
+            &[],
+            CompletionDirection::Forward,
+        )
+        .unwrap();
         assert_eq!(new_input, "config agent.consensus_level");
     }
 
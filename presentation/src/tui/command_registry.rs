@@ -98,8 +98,8 @@ static COMMANDS: &[CommandInfo] = &[
     CommandInfo {
         name: "discuss",
         aliases: &[],
-        usage: ":discuss <question>",
-        description: "Open a new Discuss tab (multi-model Quorum Discussion)",
+        usage: ":discuss <question> | :discuss --save <path>",
+        description: "Open a new Discuss tab (multi-model Quorum Discussion), or save the last discussion as Markdown",
     },
     CommandInfo {
         name: "council",
@@ -107,6 +107,24 @@ static COMMANDS: &[CommandInfo] = &[
         usage: ":council <question>",
         description: "Run a Quorum Discussion inline in the active tab (no new tab)",
     },
+    CommandInfo {
+        name: "export",
+        aliases: &[],
+        usage: ":export [path]",
+        description: "Save this tab's conversation as Markdown",
+    },
+    CommandInfo {
+        name: "summarize",
+        aliases: &[],
+        usage: ":summarize",
+        description: "Append a TL;DR of this tab's conversation (decision model)",
+    },
+    CommandInfo {
+        name: "cancel",
+        aliases: &[],
+        usage: ":cancel <model>",
+        description: "Stop one model's plan generation during ensemble planning",
+    },
     CommandInfo {
         name: "tabnew",
         aliases: &[],
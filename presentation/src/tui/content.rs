@@ -8,6 +8,8 @@
 
 use std::collections::HashMap;
 
+use quorum_domain::quorum::diff_synthesis;
+use quorum_domain::{ModelResponse, SynthesisResult};
 use ratatui::{buffer::Buffer, layout::Rect};
 
 use super::state::{DisplayMessage, ProgressState, TuiState};
@@ -22,6 +24,8 @@ pub enum ContentSlot {
     Help,
     /// Tool execution log — separable from Progress for independent routing.
     ToolLog,
+    /// Last multi-model discussion: per-model responses side by side.
+    Council,
     /// Dynamic: Ensemble model output stream (keyed by model name).
     ModelStream(String),
     /// Dynamic: Lua-registered custom content slot (keyed by slot name).
@@ -119,6 +123,53 @@ impl Default for ConversationContent {
     }
 }
 
+/// Council content — the last `/discuss` result of a pane.
+#[derive(Debug, Clone, Default)]
+pub struct CouncilContent {
+    pub responses: Vec<ModelResponse>,
+    pub moderator: String,
+    pub synthesis: String,
+    /// "Sources: gpt 2 · claude 1 · new 1 · dropped 3", computed once by
+    /// [`new`](Self::new) so rendering does not re-run [`diff_synthesis`].
+    pub attribution: Option<String>,
+}
+
+impl CouncilContent {
+    pub fn new(
+        responses: Vec<ModelResponse>,
+        moderator: impl Into<String>,
+        synthesis: impl Into<String>,
+    ) -> Self {
+        let mut council = Self {
+            responses,
+            moderator: moderator.into(),
+            synthesis: synthesis.into(),
+            attribution: None,
+        };
+        council.attribution = council.attribution_summary();
+        council
+    }
+
+    /// Attribution line for the synthesis, or `None` when it has no
+    /// sentences to attribute.
+    fn attribution_summary(&self) -> Option<String> {
+        let synthesis = SynthesisResult::new(self.moderator.clone(), self.synthesis.clone());
+        let diff = diff_synthesis(&self.responses, &synthesis);
+        if diff.sentences.is_empty() {
+            return None;
+        }
+        let mut parts: Vec<String> = self
+            .responses
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| format!("{} {}", r.model, diff.attributed_to(&r.model)))
+            .collect();
+        parts.push(format!("new {}", diff.novel().count()));
+        parts.push(format!("dropped {}", diff.dropped.len()));
+        Some(format!("Sources: {}", parts.join(" · ")))
+    }
+}
+
 /// Progress content — type alias for existing `ProgressState`.
 pub type ProgressContent = ProgressState;
 
@@ -135,6 +186,21 @@ mod tests {
         assert!(content.auto_scroll);
     }
 
+    #[test]
+    fn test_council_content_attribution_computed_once() {
+        let responses = vec![ModelResponse::success("gpt", "Use option B.")];
+        let council = CouncilContent::new(responses.clone(), "judge", "Use option B.");
+        assert_eq!(
+            council.attribution.as_deref(),
+            Some("Sources: gpt 1 · new 0 · dropped 0")
+        );
+        // Nothing to attribute
+        assert_eq!(
+            CouncilContent::new(responses, "judge", "").attribution,
+            None
+        );
+    }
+
     #[test]
     fn test_content_slot_eq() {
         assert_eq!(ContentSlot::Conversation, ContentSlot::Conversation);
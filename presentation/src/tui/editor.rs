@@ -3,7 +3,12 @@
 //! Launches the user's preferred editor as a subprocess with a temporary file.
 //! The TUI suspends while the editor runs (raw mode disabled, alternate screen left).
 //! On save (:wq), the file content is returned; on cancel (:q!), `Cancelled` is returned.
+//!
+//! Besides prompts, the editor is used to edit a plan during Human
+//! Intervention: [`plan_edit_text`] renders it as a ` ```plan ` JSON block and
+//! [`parse_plan_edit`] reads the saved text back through [`parse_plan`].
 
+use quorum_domain::{Plan, parse_plan};
 use std::process::Command;
 
 /// RAII guard that removes a temporary file on drop.
@@ -75,13 +80,35 @@ pub fn launch_editor_with_options(
         initial_text.to_string()
     };
 
-    if let Err(e) = std::fs::write(&temp_path, &content) {
+    edit_temp_file(&temp_path, &content)
+}
+
+/// Launch the user's $EDITOR on a plan rendered by [`plan_edit_text`].
+///
+/// `initial_text` is usually `plan_edit_text(plan)`, or the user's previous
+/// draft when it failed to parse.
+pub fn launch_plan_editor(initial_text: &str) -> EditorResult {
+    let temp_path = std::env::temp_dir().join(format!(".quorum-plan-{}.md", std::process::id()));
+    let content = format!(
+        "# --- Quorum Plan Edit ---\n\
+         # Edit task descriptions, reorder or remove tasks in the JSON below.\n\
+         # Lines starting with # are ignored.\n\
+         # Save and quit to submit, quit without saving to cancel.\n\
+         # ------------------------\n\n{}",
+        initial_text
+    );
+    edit_temp_file(&temp_path, &content)
+}
+
+/// Write `content` to `temp_path`, open it in the editor, and read it back.
+fn edit_temp_file(temp_path: &std::path::Path, content: &str) -> EditorResult {
+    if let Err(e) = std::fs::write(temp_path, content) {
         eprintln!("Failed to create temp file: {}", e);
         return EditorResult::Cancelled;
     }
 
     // RAII guard ensures cleanup even on panic
-    let _guard = TempFileGuard(temp_path.clone());
+    let _guard = TempFileGuard(temp_path.to_path_buf());
 
     // 3. Detect editor: $VISUAL → $EDITOR → vi
     let editor = std::env::var("VISUAL")
@@ -90,7 +117,7 @@ pub fn launch_editor_with_options(
 
     // 4. Launch editor subprocess
     let status = Command::new(&editor)
-        .arg(&temp_path)
+        .arg(temp_path)
         .stdin(std::process::Stdio::inherit())
         .stdout(std::process::Stdio::inherit())
         .stderr(std::process::Stdio::inherit())
@@ -98,7 +125,7 @@ pub fn launch_editor_with_options(
 
     // 5. Read back file content (guard drops and cleans up on return)
     match status {
-        Ok(exit_status) if exit_status.success() => match std::fs::read_to_string(&temp_path) {
+        Ok(exit_status) if exit_status.success() => match std::fs::read_to_string(temp_path) {
             Ok(raw) => {
                 let filtered = filter_comments(&raw);
                 if filtered.is_empty() {
@@ -113,6 +140,69 @@ pub fn launch_editor_with_options(
     }
 }
 
+/// Render `plan` as an editable ` ```plan ` JSON block.
+///
+/// Uses the `create_plan` schema understood by [`parse_plan`], so tool
+/// arguments, dependencies and context settings survive a round trip.
+pub fn plan_edit_text(plan: &Plan) -> String {
+    let tasks: Vec<serde_json::Value> = plan
+        .tasks
+        .iter()
+        .map(|task| {
+            let mut json = serde_json::json!({
+                "id": task.id.as_str(),
+                "description": task.description,
+            });
+            if let Some(tool) = &task.tool_name {
+                json["tool"] = serde_json::json!(tool);
+            }
+            if !task.tool_args.is_empty() {
+                json["args"] = serde_json::json!(task.tool_args);
+            }
+            if !task.depends_on.is_empty() {
+                let deps: Vec<&str> = task.depends_on.iter().map(|d| d.as_str()).collect();
+                json["depends_on"] = serde_json::json!(deps);
+            }
+            if let Some(mode) = task.context_mode {
+                json["context_mode"] = serde_json::json!(mode.as_str());
+            }
+            if let Some(brief) = &task.context_brief {
+                json["context_brief"] = serde_json::json!(brief);
+            }
+            json
+        })
+        .collect();
+    let json = serde_json::json!({
+        "objective": plan.objective,
+        "reasoning": plan.reasoning,
+        "tasks": tasks,
+    });
+    format!(
+        "```plan\n{}\n```\n",
+        serde_json::to_string_pretty(&json).unwrap_or_default()
+    )
+}
+
+/// Parse a plan edited in the editor, explaining why it was rejected.
+pub fn parse_plan_edit(text: &str) -> Result<Plan, String> {
+    if let Some(plan) = parse_plan(text) {
+        return Ok(plan);
+    }
+    let json = plan_block(text).unwrap_or(text);
+    match serde_json::from_str::<serde_json::Value>(json) {
+        Err(e) => Err(format!("invalid plan JSON: {}", e)),
+        Ok(_) => Err("plan needs an \"objective\" and at least one task".to_string()),
+    }
+}
+
+/// Contents of the first ` ```plan ` block in `text`, if any.
+fn plan_block(text: &str) -> Option<&str> {
+    let start = text.find("```plan")? + "```plan".len();
+    let rest = &text[start..];
+    let end = rest.find("```").unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
 /// Strip lines starting with `#` (comment lines) and trim the result
 pub fn filter_comments(text: &str) -> String {
     let filtered: Vec<&str> = text.lines().filter(|line| !line.starts_with('#')).collect();
@@ -126,6 +216,53 @@ pub fn filter_comments(text: &str) -> String {
 mod tests {
     use super::*;
 
+    fn sample_plan() -> Plan {
+        let mut plan = Plan::new("Fix the login bug", "Read first, then patch");
+        plan.add_task(
+            quorum_domain::Task::new("1", "Read auth.rs")
+                .with_tool("read_file")
+                .with_arg("path", serde_json::json!("src/auth.rs")),
+        );
+        plan.add_task(quorum_domain::Task::new("2", "Patch the check").with_dependency("1"));
+        plan
+    }
+
+    #[test]
+    fn test_plan_edit_text_round_trips() {
+        let plan = sample_plan();
+        let parsed = parse_plan_edit(&plan_edit_text(&plan)).unwrap();
+        assert_eq!(parsed.objective, plan.objective);
+        assert_eq!(parsed.tasks.len(), 2);
+        assert_eq!(parsed.tasks[0].tool_name.as_deref(), Some("read_file"));
+        assert_eq!(
+            parsed.tasks[0].tool_args["path"],
+            serde_json::json!("src/auth.rs")
+        );
+        assert_eq!(parsed.tasks[1].depends_on[0].as_str(), "1");
+    }
+
+    #[test]
+    fn test_parse_plan_edit_picks_up_edits() {
+        let text =
+            plan_edit_text(&sample_plan()).replace("Patch the check", "Patch the expiry check");
+        let parsed = parse_plan_edit(&text).unwrap();
+        assert_eq!(parsed.tasks[1].description, "Patch the expiry check");
+    }
+
+    #[test]
+    fn test_parse_plan_edit_reports_broken_json() {
+        let text = plan_edit_text(&sample_plan()).replace("\"tasks\": [", "\"tasks\": [,");
+        let err = parse_plan_edit(&text).unwrap_err();
+        assert!(err.starts_with("invalid plan JSON"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_plan_edit_reports_empty_plan() {
+        let err =
+            parse_plan_edit("```plan\n{\"objective\": \"x\", \"tasks\": []}\n```").unwrap_err();
+        assert!(err.contains("at least one task"), "{}", err);
+    }
+
     #[test]
     fn test_filter_comments_removes_header() {
         let input = "# --- Quorum Prompt ---\n\
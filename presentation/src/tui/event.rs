@@ -3,6 +3,7 @@
 //! Defines the commands sent TO the controller task and the events
 //! coming FROM it (via UiEvent channel and progress bridge).
 
+use quorum_application::CompactionOutcome;
 use quorum_domain::quorum::Objection;
 use quorum_domain::{
     AgentPhase, ConsensusLevel, ContextMode, HumanDecision, InteractionForm, InteractionId, Plan,
@@ -51,6 +52,8 @@ pub enum TuiCommand {
     SetCancellation(tokio_util::sync::CancellationToken),
     /// Set reference resolver for automatic reference resolution
     SetReferenceResolver(std::sync::Arc<dyn quorum_application::ReferenceResolverPort>),
+    /// Set the decision log for cross-session prior decisions
+    SetDecisionLog(std::sync::Arc<dyn quorum_application::DecisionLogPort>),
     /// Set scripting engine for Lua command dispatch
     SetScriptingEngine(std::sync::Arc<dyn quorum_application::ScriptingEnginePort>),
     /// Spawn a new interaction
@@ -70,11 +73,22 @@ pub enum TuiCommand {
         material: String,
         respond_to: oneshot::Sender<InteractionId>,
     },
+    /// Summarize a tab's conversation (`:summarize`) with the decision model
+    Summarize {
+        interaction_id: Option<InteractionId>,
+        messages: Vec<quorum_domain::Message>,
+    },
     /// Activate an existing interaction
     ActivateInteraction(InteractionId),
     /// Cancel the in-flight interaction bound to a (now closed) tab.
     /// Cancels only that interaction's agent, leaving other tabs running.
     CancelInteraction(InteractionId),
+    /// Cancel one model's plan generation during ensemble planning (`:cancel <model>`),
+    /// leaving the other models running.
+    CancelEnsembleBranch {
+        interaction_id: Option<InteractionId>,
+        model: String,
+    },
     /// Graceful shutdown
     #[allow(dead_code)]
     Quit,
@@ -166,6 +180,10 @@ pub enum TuiEvent {
         selected_model: String,
         score: f64,
     },
+    EnsembleMerged {
+        moderator: String,
+        sources: usize,
+    },
     EnsembleFallback(String),
 
     // -- Model stream (per-model live output for Ensemble, Quorum, etc.) --
@@ -192,9 +210,16 @@ pub enum TuiEvent {
         duration_ms: Option<u64>,
         args_preview: Option<String>,
     },
+    /// Unified diff of a file change made by a completed tool execution
+    ToolExecutionDiff {
+        execution_id: String,
+        diff: String,
+    },
 
     // -- Other --
     HistoryCleared,
+    /// Background compaction of the conversation history finished
+    HistoryCompacted(CompactionOutcome),
     CommandError(String),
     Flash(String),
     Exit,
@@ -212,6 +237,8 @@ pub enum ToolExecutionDisplayState {
 /// Request for human intervention, sent from HumanIntervention port to TUI
 pub struct HilRequest {
     pub kind: HilKind,
+    /// When the requester stops waiting (`agent.hil_timeout_secs`).
+    pub deadline: Option<std::time::Instant>,
     pub response_tx: oneshot::Sender<HumanDecision>,
 }
 
@@ -238,6 +265,13 @@ pub enum HilKind {
         /// round: rejecting aborts the debate entirely.
         can_continue: bool,
     },
+    /// A high-risk tool call approved by fewer reviewers than the policy's
+    /// floor (see `HumanInterventionPort::request_action_approval`).
+    ActionApproval {
+        task: String,
+        tool_call_json: String,
+        reason: String,
+    },
 }
 
 #[cfg(test)]
@@ -0,0 +1,135 @@
+//! Conversation export (`:export [path]`).
+//!
+//! Serializes the active pane's [`DisplayMessage`]s to Markdown. Without a
+//! path the file goes to the log directory, named after the current time.
+
+use super::state::{DisplayMessage, MessageRole, TuiState};
+use std::path::{Path, PathBuf};
+
+/// Write the active pane's conversation to `path` (or a timestamped file in
+/// [`TuiState::export_dir`] when `path` is empty). Returns the flash message.
+pub(super) fn export_conversation(state: &TuiState, path: &str) -> String {
+    let path = if path.is_empty() {
+        match &state.export_dir {
+            Some(dir) => default_export_path(dir, chrono::Local::now()),
+            None => return "Usage: :export <path> (no log directory configured)".into(),
+        }
+    } else {
+        PathBuf::from(path)
+    };
+
+    let messages = &state.tabs.active_pane().conversation.messages;
+    if messages.is_empty() {
+        return "Nothing to export yet".into();
+    }
+
+    let result = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => std::fs::create_dir_all(parent),
+        _ => Ok(()),
+    }
+    .and_then(|()| std::fs::write(&path, messages_to_markdown(messages)));
+    match result {
+        Ok(()) => format!("Exported {} messages to {}", messages.len(), path.display()),
+        Err(e) => format!("Failed to export to {}: {}", path.display(), e),
+    }
+}
+
+/// `<dir>/conversation-<timestamp>.md`
+pub(super) fn default_export_path(
+    dir: &Path,
+    now: chrono::DateTime<impl chrono::TimeZone<Offset: std::fmt::Display>>,
+) -> PathBuf {
+    dir.join(format!(
+        "conversation-{}.md",
+        now.format("%Y-%m-%dT%H-%M-%S")
+    ))
+}
+
+/// Render messages as a Markdown document, one `##` section per message.
+///
+/// User and assistant content is already Markdown and is kept verbatim;
+/// system messages (status notes, tool log lines) become blockquotes so they
+/// read as asides.
+pub fn messages_to_markdown(messages: &[DisplayMessage]) -> String {
+    let mut out = String::from("# Quorum Conversation\n");
+    for msg in messages {
+        out.push_str(&format!("\n## {}\n\n", msg.role.label()));
+        match msg.role {
+            MessageRole::User | MessageRole::Assistant => {
+                out.push_str(msg.content.trim_end());
+                out.push('\n');
+            }
+            MessageRole::System => {
+                for line in msg.content.trim_end().lines() {
+                    if line.is_empty() {
+                        out.push_str(">\n");
+                    } else {
+                        out.push_str(&format!("> {}\n", line));
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_markdown_for_mixed_messages() {
+        let messages = vec![
+            DisplayMessage::user("Fix the login bug"),
+            DisplayMessage::system("Plan: Fix login (2 tasks)"),
+            DisplayMessage::system(
+                "Tool call: read_file {\"path\":\"login.rs\"}\n\nTool result: read_file ok",
+            ),
+            DisplayMessage::assistant("Patched `login.rs`:\n\n```rust\nfn login() {}\n```\n"),
+        ];
+
+        assert_eq!(
+            messages_to_markdown(&messages),
+            "# Quorum Conversation\n\
+             \n## You\n\nFix the login bug\n\
+             \n## System\n\n> Plan: Fix login (2 tasks)\n\
+             \n## System\n\n> Tool call: read_file {\"path\":\"login.rs\"}\n>\n> Tool result: read_file ok\n\
+             \n## Agent\n\nPatched `login.rs`:\n\n```rust\nfn login() {}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_default_export_path_is_timestamped() {
+        let now = chrono::Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+        assert_eq!(
+            default_export_path(Path::new("/logs"), now),
+            PathBuf::from("/logs/conversation-2026-03-04T05-06-07.md")
+        );
+    }
+
+    #[test]
+    fn test_export_writes_active_pane() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = TuiState::new();
+        state.push_message(DisplayMessage::user("hello"));
+
+        let path = dir.path().join("out/chat.md");
+        let flash = export_conversation(&state, path.to_str().unwrap());
+        assert!(flash.starts_with("Exported 1 messages"), "{}", flash);
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("## You\n\nhello\n")
+        );
+
+        state.export_dir = Some(dir.path().to_path_buf());
+        export_conversation(&state, "");
+        let exported = std::fs::read_dir(dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().starts_with("conversation-"))
+            .count();
+        assert_eq!(exported, 1);
+    }
+}
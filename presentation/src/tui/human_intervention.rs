@@ -2,14 +2,24 @@
 //!
 //! Instead of blocking on stdin, sends an HilRequest through a channel
 //! to the TUI event loop, which shows a modal and sends back the decision.
+//! For plan intervention the modal can also return `HumanDecision::Edit`
+//! with a plan edited in $EDITOR (see `app_hil::apply_plan_edit`).
+//! Batch review reuses the action approval modal, once per queued action.
+//!
+//! With `agent.hil_timeout_secs` set, each request carries a deadline the
+//! modal counts down to; an unanswered request returns
+//! `HumanInterventionError::TimedOut` and the modal closes on the next tick.
 
 use super::event::{HilKind, HilRequest};
 use async_trait::async_trait;
+use quorum_application::QuorumConfig;
 use quorum_application::ports::human_intervention::{
     HumanInterventionError, HumanInterventionPort,
 };
 use quorum_domain::quorum::Objection;
-use quorum_domain::{HumanDecision, Plan, ReviewRound};
+use quorum_domain::{HumanDecision, Plan, QueuedAction, ReviewRound, Task};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, oneshot};
 
 /// Channel-based HumanInterventionPort for TUI
@@ -18,30 +28,37 @@ use tokio::sync::{mpsc, oneshot};
 /// then awaits the decision on a oneshot channel.
 pub struct TuiHumanIntervention {
     hil_tx: mpsc::UnboundedSender<HilRequest>,
+    /// Read per request for `agent.hil_timeout_secs`, so runtime changes apply.
+    config: Option<Arc<Mutex<QuorumConfig>>>,
 }
 
 impl TuiHumanIntervention {
     pub fn new(hil_tx: mpsc::UnboundedSender<HilRequest>) -> Self {
-        Self { hil_tx }
+        Self {
+            hil_tx,
+            config: None,
+        }
     }
-}
 
-#[async_trait]
-impl HumanInterventionPort for TuiHumanIntervention {
-    async fn request_intervention(
-        &self,
-        request: &str,
-        plan: &Plan,
-        review_history: &[ReviewRound],
-    ) -> Result<HumanDecision, HumanInterventionError> {
+    pub fn with_config(mut self, config: Arc<Mutex<QuorumConfig>>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.lock().unwrap().policy().hil_timeout())
+    }
+
+    /// Send `kind` to the TUI loop and wait for the modal's answer.
+    async fn ask(&self, kind: HilKind) -> Result<HumanDecision, HumanInterventionError> {
         let (response_tx, response_rx) = oneshot::channel();
+        let timeout = self.timeout();
 
         let hil_request = HilRequest {
-            kind: HilKind::PlanIntervention {
-                request: request.to_string(),
-                plan: plan.clone(),
-                review_history: review_history.to_vec(),
-            },
+            kind,
+            deadline: timeout.map(|t| Instant::now() + t),
             response_tx,
         };
 
@@ -49,33 +66,46 @@ impl HumanInterventionPort for TuiHumanIntervention {
             .send(hil_request)
             .map_err(|_| HumanInterventionError::IoError("TUI channel closed".to_string()))?;
 
-        response_rx.await.map_err(|_| {
+        let response = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, response_rx)
+                .await
+                .map_err(|_| HumanInterventionError::TimedOut {
+                    answered: Vec::new(),
+                })?,
+            None => response_rx.await,
+        };
+        response.map_err(|_| {
             HumanInterventionError::IoError("TUI response channel dropped".to_string())
         })
     }
+}
 
-    async fn request_execution_confirmation(
+#[async_trait]
+impl HumanInterventionPort for TuiHumanIntervention {
+    async fn request_intervention(
         &self,
         request: &str,
         plan: &Plan,
+        review_history: &[ReviewRound],
     ) -> Result<HumanDecision, HumanInterventionError> {
-        let (response_tx, response_rx) = oneshot::channel();
-
-        let hil_request = HilRequest {
-            kind: HilKind::ExecutionConfirmation {
-                request: request.to_string(),
-                plan: plan.clone(),
-            },
-            response_tx,
-        };
-
-        self.hil_tx
-            .send(hil_request)
-            .map_err(|_| HumanInterventionError::IoError("TUI channel closed".to_string()))?;
+        self.ask(HilKind::PlanIntervention {
+            request: request.to_string(),
+            plan: plan.clone(),
+            review_history: review_history.to_vec(),
+        })
+        .await
+    }
 
-        response_rx.await.map_err(|_| {
-            HumanInterventionError::IoError("TUI response channel dropped".to_string())
+    async fn request_execution_confirmation(
+        &self,
+        request: &str,
+        plan: &Plan,
+    ) -> Result<HumanDecision, HumanInterventionError> {
+        self.ask(HilKind::ExecutionConfirmation {
+            request: request.to_string(),
+            plan: plan.clone(),
         })
+        .await
     }
 
     async fn request_debate_escalation(
@@ -85,24 +115,88 @@ impl HumanInterventionPort for TuiHumanIntervention {
         transcript_summary: &str,
         can_continue: bool,
     ) -> Result<HumanDecision, HumanInterventionError> {
-        let (response_tx, response_rx) = oneshot::channel();
-
-        let hil_request = HilRequest {
-            kind: HilKind::DebateEscalation {
-                question: question.to_string(),
-                unresolved: unresolved.to_vec(),
-                transcript_summary: transcript_summary.to_string(),
-                can_continue,
-            },
-            response_tx,
-        };
+        self.ask(HilKind::DebateEscalation {
+            question: question.to_string(),
+            unresolved: unresolved.to_vec(),
+            transcript_summary: transcript_summary.to_string(),
+            can_continue,
+        })
+        .await
+    }
 
-        self.hil_tx
-            .send(hil_request)
-            .map_err(|_| HumanInterventionError::IoError("TUI channel closed".to_string()))?;
+    async fn request_batch_approval(
+        &self,
+        _request: &str,
+        actions: &[QueuedAction],
+    ) -> Result<Vec<HumanDecision>, HumanInterventionError> {
+        let mut decisions = Vec::with_capacity(actions.len());
+        for action in actions {
+            let tool_call_json = serde_json::to_string_pretty(&serde_json::json!({
+                "tool": action.call.tool_name,
+                "args": action.call.arguments,
+            }))
+            .unwrap_or_default();
+            let task = Task::new(action.task_id.clone(), format!("Task {}", action.task_id));
+            let mut reason = format!(
+                "Batch review {}/{}: queued during the run, runs only if approved",
+                action.index + 1,
+                actions.len()
+            );
+            if let Some(escalation) = &action.escalation {
+                reason.push_str(&format!(" (escalated: {})", escalation));
+            }
+            match self
+                .request_action_approval(&task, &tool_call_json, &reason)
+                .await
+            {
+                Ok(decision) => decisions.push(decision),
+                Err(HumanInterventionError::TimedOut { .. }) => {
+                    return Err(HumanInterventionError::TimedOut {
+                        answered: decisions,
+                    });
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(decisions)
+    }
 
-        response_rx.await.map_err(|_| {
-            HumanInterventionError::IoError("TUI response channel dropped".to_string())
+    async fn request_action_approval(
+        &self,
+        task: &Task,
+        tool_call_json: &str,
+        reason: &str,
+    ) -> Result<HumanDecision, HumanInterventionError> {
+        self.ask(HilKind::ActionApproval {
+            task: task.description.clone(),
+            tool_call_json: tool_call_json.to_string(),
+            reason: reason.to_string(),
         })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn unanswered_request_times_out() {
+        let (hil_tx, mut hil_rx) = mpsc::unbounded_channel();
+        let mut config = QuorumConfig::default();
+        config.policy_mut().hil_timeout_secs = Some(1);
+        let hil = TuiHumanIntervention::new(hil_tx).with_config(Arc::new(Mutex::new(config)));
+
+        let result = hil
+            .request_execution_confirmation("do it", &Plan::new("objective", ""))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(HumanInterventionError::TimedOut { .. })
+        ));
+        // The modal got the deadline it counts down to
+        let request = hil_rx.recv().await.expect("request sent to the TUI");
+        assert!(request.deadline.is_some());
     }
 }
@@ -165,6 +165,30 @@ static KEYMAPS: &[KeymapInfo] = &[
         action: "cycle_focus",
         description: "Cycle which content slot has yank focus",
     },
+    KeymapInfo {
+        mode: "normal",
+        key: "za",
+        action: "toggle_fold",
+        description: "Expand/collapse earlier phases in the progress panel (z prefix chord)",
+    },
+    KeymapInfo {
+        mode: "normal",
+        key: "/",
+        action: "search",
+        description: "Search the conversation pane",
+    },
+    KeymapInfo {
+        mode: "normal",
+        key: "n",
+        action: "search_next",
+        description: "Jump to the next search match",
+    },
+    KeymapInfo {
+        mode: "normal",
+        key: "N",
+        action: "search_prev",
+        description: "Jump to the previous search match",
+    },
     KeymapInfo {
         mode: "normal",
         key: "?",
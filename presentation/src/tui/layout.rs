@@ -8,7 +8,9 @@ use std::fmt;
 use std::str::FromStr;
 
 use quorum_application::CustomPresetConfig;
+use quorum_domain::interaction::InteractionForm;
 use ratatui::layout::Direction;
+use tracing::warn;
 
 use super::content::ContentSlot;
 use super::surface::SurfaceId;
@@ -260,6 +262,7 @@ pub fn parse_content_slot(name: &str) -> Option<ContentSlot> {
         "hil_prompt" => Some(ContentSlot::HilPrompt),
         "help" => Some(ContentSlot::Help),
         "tool_log" => Some(ContentSlot::ToolLog),
+        "council" => Some(ContentSlot::Council),
         s if s.starts_with("model_stream:") => Some(ContentSlot::ModelStream(
             s["model_stream:".len()..].to_string(),
         )),
@@ -277,6 +280,7 @@ pub fn content_slot_to_string(slot: &ContentSlot) -> String {
         ContentSlot::HilPrompt => "hil_prompt".to_string(),
         ContentSlot::Help => "help".to_string(),
         ContentSlot::ToolLog => "tool_log".to_string(),
+        ContentSlot::Council => "council".to_string(),
         ContentSlot::ModelStream(name) => format!("model_stream:{}", name),
         ContentSlot::LuaSlot(name) => format!("lua:{}", name),
     }
@@ -289,6 +293,91 @@ pub struct RouteOverride {
     pub surface: SurfaceId,
 }
 
+/// Tolerance for custom preset splits: percentages summing to within this
+/// many points of 100 are accepted (e.g. `{33, 33, 33}`).
+const SPLIT_SUM_TOLERANCE: u16 = 5;
+
+/// One pane of a [`CustomLayout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomPane {
+    pub percent: u16,
+    /// Content shown in this pane; `None` keeps the default routes.
+    pub slot: Option<ContentSlot>,
+}
+
+/// Validated custom layout spec, parsed from a Lua-registered
+/// [`CustomPresetConfig`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomLayout {
+    pub direction: Direction,
+    pub panes: Vec<CustomPane>,
+}
+
+impl CustomLayout {
+    /// Parse and validate a preset registered via
+    /// `quorum.tui.layout.register_preset`.
+    ///
+    /// Splits must sum to 100 (± [`SPLIT_SUM_TOLERANCE`]); `slots`, when
+    /// given, must name a known content slot for every pane.
+    pub fn parse(config: &CustomPresetConfig) -> Result<Self, String> {
+        if config.splits.is_empty() {
+            return Err("splits must have at least one entry".to_string());
+        }
+        let sum: u16 = config.splits.iter().sum();
+        if sum.abs_diff(100) > SPLIT_SUM_TOLERANCE {
+            return Err(format!("splits must sum to 100, got {}", sum));
+        }
+
+        let direction = match config.direction.as_str() {
+            "horizontal" => Direction::Horizontal,
+            "vertical" => Direction::Vertical,
+            other => {
+                return Err(format!(
+                    "direction must be 'horizontal' or 'vertical', got '{}'",
+                    other
+                ));
+            }
+        };
+
+        let slots = if config.slots.is_empty() {
+            vec![None; config.splits.len()]
+        } else if config.slots.len() != config.splits.len() {
+            return Err(format!(
+                "slots has {} entries but splits has {}",
+                config.slots.len(),
+                config.splits.len()
+            ));
+        } else {
+            config
+                .slots
+                .iter()
+                .map(|name| {
+                    parse_content_slot(name)
+                        .map(Some)
+                        .ok_or_else(|| format!("unknown content slot '{}'", name))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        let panes = config
+            .splits
+            .iter()
+            .zip(slots)
+            .map(|(&percent, slot)| CustomPane { percent, slot })
+            .collect();
+        Ok(Self { direction, panes })
+    }
+
+    pub fn splits(&self) -> Vec<u16> {
+        self.panes.iter().map(|p| p.percent).collect()
+    }
+
+    /// Whether every pane names its content (otherwise default routes apply).
+    pub fn has_slots(&self) -> bool {
+        self.panes.iter().all(|p| p.slot.is_some())
+    }
+}
+
 /// Complete TUI layout configuration.
 ///
 /// Assembled from `[tui.layout]` TOML section and used by the render loop.
@@ -304,6 +393,10 @@ pub struct RouteOverride {
 ///
 /// When an orchestration strategy activates, the TUI switches to the
 /// corresponding preset. If no override is configured, `preset` is used.
+///
+/// `form_presets` does the same per interaction form (`tui.layout.discuss_preset`):
+/// the TUI switches when such an interaction spawns and switches back when
+/// it completes. See [`TuiLayoutConfig::preset_for_form`].
 #[derive(Debug, Clone)]
 pub struct TuiLayoutConfig {
     pub preset: LayoutPreset,
@@ -312,8 +405,10 @@ pub struct TuiLayoutConfig {
     pub route_overrides: Vec<RouteOverride>,
     /// Per-strategy layout preset overrides (e.g., "quorum" → Stacked).
     pub strategy_presets: HashMap<String, LayoutPreset>,
+    /// Per-interaction-form layout presets (e.g., Discuss → Wide).
+    pub form_presets: HashMap<InteractionForm, LayoutPreset>,
     /// Custom layout presets registered from Lua scripting.
-    pub custom_presets: HashMap<String, CustomPresetConfig>,
+    pub custom_presets: HashMap<String, CustomLayout>,
 }
 
 impl TuiLayoutConfig {
@@ -325,27 +420,66 @@ impl TuiLayoutConfig {
             .unwrap_or_else(|| self.preset.clone())
     }
 
+    /// Preset to auto-switch to while an interaction of `form` runs.
+    ///
+    /// A `form_presets` entry wins; Discuss otherwise falls back to the
+    /// `"quorum"` strategy preset, since it runs the Quorum council.
+    pub fn preset_for_form(&self, form: InteractionForm) -> Option<LayoutPreset> {
+        if let Some(preset) = self.form_presets.get(&form) {
+            return Some(preset.clone());
+        }
+        match form {
+            InteractionForm::Discuss => self.strategy_presets.get("quorum").cloned(),
+            _ => None,
+        }
+    }
+
+    /// Parse and store a Lua-registered custom preset.
+    ///
+    /// An invalid preset is logged and dropped, so switching to it falls
+    /// back to the default layout. Returns whether it was registered.
+    pub fn register_custom_preset(&mut self, name: &str, config: &CustomPresetConfig) -> bool {
+        match CustomLayout::parse(config) {
+            Ok(layout) => {
+                self.custom_presets.insert(name.to_string(), layout);
+                true
+            }
+            Err(e) => {
+                warn!(
+                    "Ignoring custom layout preset '{}': {}; falling back to default",
+                    name, e
+                );
+                self.custom_presets.remove(name);
+                false
+            }
+        }
+    }
+
+    /// The custom layout for the current preset, if it is a registered custom preset.
+    pub fn custom_layout(&self) -> Option<&CustomLayout> {
+        match &self.preset {
+            LayoutPreset::Custom(name) => self.custom_presets.get(name),
+            _ => None,
+        }
+    }
+
     /// Resolve splits for the current preset (handles both built-in and custom).
+    ///
+    /// An unregistered custom preset falls back to the default splits.
     pub fn resolve_splits(&self, pane_count: usize) -> Vec<u16> {
-        if let LayoutPreset::Custom(name) = &self.preset
-            && let Some(config) = self.custom_presets.get(name)
-        {
-            return config.splits.clone();
+        match (&self.preset, self.custom_layout()) {
+            (_, Some(custom)) => custom.splits(),
+            (LayoutPreset::Custom(_), None) => LayoutPreset::Default.default_splits(pane_count),
+            (preset, None) => preset.default_splits(pane_count),
         }
-        self.preset.default_splits(pane_count)
     }
 
     /// Resolve split direction for the current preset.
     pub fn resolve_direction(&self) -> Direction {
-        if let LayoutPreset::Custom(name) = &self.preset
-            && let Some(config) = self.custom_presets.get(name)
-        {
-            return match config.direction.as_str() {
-                "vertical" => Direction::Vertical,
-                _ => Direction::Horizontal,
-            };
+        match self.custom_layout() {
+            Some(custom) => custom.direction,
+            None => self.preset.split_direction(),
         }
-        self.preset.split_direction()
     }
 }
 
@@ -357,6 +491,7 @@ impl Default for TuiLayoutConfig {
             surface_config: SurfaceConfig::default(),
             route_overrides: Vec::new(),
             strategy_presets: HashMap::new(),
+            form_presets: HashMap::new(),
             custom_presets: HashMap::new(),
         }
     }
@@ -486,6 +621,29 @@ mod tests {
         assert_eq!(config.preset_for_strategy("debate"), LayoutPreset::Default);
     }
 
+    #[test]
+    fn test_preset_for_form() {
+        let mut config = TuiLayoutConfig::default();
+        assert_eq!(config.preset_for_form(InteractionForm::Discuss), None);
+
+        config
+            .strategy_presets
+            .insert("quorum".to_string(), LayoutPreset::Stacked);
+        assert_eq!(
+            config.preset_for_form(InteractionForm::Discuss),
+            Some(LayoutPreset::Stacked)
+        );
+        assert_eq!(config.preset_for_form(InteractionForm::Ask), None);
+
+        config
+            .form_presets
+            .insert(InteractionForm::Discuss, LayoutPreset::Wide);
+        assert_eq!(
+            config.preset_for_form(InteractionForm::Discuss),
+            Some(LayoutPreset::Wide)
+        );
+    }
+
     #[test]
     fn test_default_splits() {
         assert_eq!(LayoutPreset::Default.default_splits(2), vec![70, 30]);
@@ -497,6 +655,69 @@ mod tests {
         assert_eq!(LayoutPreset::Default.default_splits(3), vec![34, 33, 33]);
     }
 
+    fn preset_config(splits: &[u16], direction: &str, slots: &[&str]) -> CustomPresetConfig {
+        CustomPresetConfig {
+            splits: splits.to_vec(),
+            direction: direction.to_string(),
+            slots: slots.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_custom_layout() {
+        let layout = CustomLayout::parse(&preset_config(
+            &[25, 50, 25],
+            "horizontal",
+            &["progress", "conversation", "tool_log"],
+        ))
+        .unwrap();
+        assert_eq!(layout.direction, Direction::Horizontal);
+        assert_eq!(layout.splits(), vec![25, 50, 25]);
+        assert_eq!(layout.panes[1].slot, Some(ContentSlot::Conversation));
+        assert!(layout.has_slots());
+
+        // Slots are optional; near-100 sums are accepted
+        let layout = CustomLayout::parse(&preset_config(&[33, 33, 33], "vertical", &[])).unwrap();
+        assert_eq!(layout.direction, Direction::Vertical);
+        assert!(!layout.has_slots());
+    }
+
+    #[test]
+    fn test_parse_custom_layout_rejects_invalid() {
+        for (config, expected) in [
+            (preset_config(&[50, 30], "horizontal", &[]), "sum to 100"),
+            (preset_config(&[], "horizontal", &[]), "at least one"),
+            (preset_config(&[50, 50], "diagonal", &[]), "direction"),
+            (
+                preset_config(&[50, 50], "horizontal", &["conversation"]),
+                "slots has 1",
+            ),
+            (
+                preset_config(&[50, 50], "horizontal", &["conversation", "bogus"]),
+                "unknown content slot",
+            ),
+        ] {
+            let err = CustomLayout::parse(&config).unwrap_err();
+            assert!(err.contains(expected), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_invalid_custom_preset_falls_back_to_default() {
+        let mut config = TuiLayoutConfig::default();
+        assert!(!config.register_custom_preset("bad", &preset_config(&[90, 90], "vertical", &[])));
+        config.preset = LayoutPreset::Custom("bad".to_string());
+
+        assert!(config.custom_layout().is_none());
+        assert_eq!(config.resolve_splits(2), vec![70, 30]);
+        assert_eq!(config.resolve_direction(), Direction::Horizontal);
+
+        assert!(config.register_custom_preset("good", &preset_config(&[40, 60], "vertical", &[])));
+        config.preset = LayoutPreset::Custom("good".to_string());
+        assert_eq!(config.resolve_splits(2), vec![40, 60]);
+        assert_eq!(config.resolve_direction(), Direction::Vertical);
+    }
+
     #[test]
     fn test_split_direction() {
         use ratatui::layout::Direction;
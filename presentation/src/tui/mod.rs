@@ -17,6 +17,7 @@ mod command_registry;
 pub mod content;
 pub mod editor;
 mod event;
+mod export;
 mod human_intervention;
 mod keymap_registry;
 pub mod layout;
@@ -25,7 +26,9 @@ mod presenter;
 mod progress;
 mod remote;
 mod remote_view;
+mod replay;
 mod route;
+mod search;
 mod state;
 mod surface;
 pub mod tab;
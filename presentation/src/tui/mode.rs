@@ -17,9 +17,22 @@ pub enum InputMode {
     Command,
     /// Visual mode — range selection for yank.
     Visual,
+    /// Search mode (`/` prefix) — typing a conversation search query.
+    Search,
 }
 
 impl InputMode {
+    /// Mode name passed to Lua (`ModeChanged` event)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Insert => "Insert",
+            Self::Command => "Command",
+            Self::Visual => "Visual",
+            Self::Search => "Search",
+        }
+    }
+
     /// Status-bar indicator text
     pub fn indicator(&self) -> &'static str {
         match self {
@@ -27,6 +40,7 @@ impl InputMode {
             Self::Insert => "-- INSERT --",
             Self::Command => ":",
             Self::Visual => "-- VISUAL --",
+            Self::Search => "/",
         }
     }
 
@@ -38,6 +52,7 @@ impl InputMode {
             Self::Insert => Color::Green,
             Self::Command => Color::Yellow,
             Self::Visual => Color::Magenta,
+            Self::Search => Color::Cyan,
         }
     }
 }
@@ -114,9 +129,25 @@ pub enum KeyAction {
     /// `Ctrl+w` — cycle which content slot has yank focus.
     CycleFocus,
 
+    // -- Progress panel --
+    /// `za` — expand/collapse the earlier phase groups in the progress panel.
+    ToggleFold,
+
     // -- Lua scripting --
     LuaCallback(u64),
 
+    // -- Conversation search --
+    /// `/` — enter Search mode to type a query.
+    Search,
+    /// `Enter` in Search mode — run the query and jump to the latest match.
+    SubmitSearch,
+    /// `Ctrl+r` in Search mode — toggle between substring and regex matching.
+    ToggleSearchRegex,
+    /// `n` — jump to the next match.
+    SearchNext,
+    /// `N` — jump to the previous match.
+    SearchPrev,
+
     // -- Command-mode completion (#326) --
     /// `Tab` / `Shift+Tab` in Command mode — advance wildmenu-style
     /// completion one step in the given direction.
@@ -224,6 +255,10 @@ fn builtin_action_by_name(name: &str) -> KeyAction {
         "yank_last_assistant" => KeyAction::YankLastAssistant,
         "enter_visual" => KeyAction::EnterVisual,
         "cycle_focus" => KeyAction::CycleFocus,
+        "search" => KeyAction::Search,
+        "search_next" => KeyAction::SearchNext,
+        "search_prev" => KeyAction::SearchPrev,
+        "toggle_fold" => KeyAction::ToggleFold,
         _ => KeyAction::None,
     }
 }
@@ -309,6 +344,7 @@ pub fn handle_key_event(mode: InputMode, key: KeyEvent, pending_key: Option<char
         InputMode::Insert => handle_insert(key),
         InputMode::Command => handle_command(key),
         InputMode::Visual => handle_visual(key),
+        InputMode::Search => handle_search(key),
     }
 }
 
@@ -332,6 +368,14 @@ fn handle_normal(key: KeyEvent, pending_key: Option<char>) -> KeyAction {
         };
     }
 
+    // Handle pending `z` prefix (folds)
+    if pending_key == Some('z') {
+        return match key.code {
+            KeyCode::Char('a') => KeyAction::ToggleFold, // za
+            _ => KeyAction::None,                        // unknown z-combo, discard
+        };
+    }
+
     // Ctrl+w cycles yank focus between content slots.
     if key.code == KeyCode::Char('w') && key.modifiers.contains(KeyModifiers::CONTROL) {
         return KeyAction::CycleFocus;
@@ -365,6 +409,14 @@ fn handle_normal(key: KeyEvent, pending_key: Option<char>) -> KeyAction {
         KeyCode::Char('Y') => KeyAction::YankLastAssistant,
         KeyCode::Char('v') => KeyAction::EnterVisual,
 
+        // Folds
+        KeyCode::Char('z') => KeyAction::PendingKey('z'), // z prefix
+
+        // Search
+        KeyCode::Char('/') => KeyAction::Search,
+        KeyCode::Char('n') => KeyAction::SearchNext,
+        KeyCode::Char('N') => KeyAction::SearchPrev,
+
         // Help
         KeyCode::Char('?') => KeyAction::ShowHelp,
 
@@ -442,6 +494,23 @@ fn handle_command(key: KeyEvent) -> KeyAction {
     }
 }
 
+fn handle_search(key: KeyEvent) -> KeyAction {
+    if key.code == KeyCode::Char('r') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return KeyAction::ToggleSearchRegex;
+    }
+    match key.code {
+        KeyCode::Esc => KeyAction::ExitToNormal,
+        KeyCode::Enter => KeyAction::SubmitSearch,
+        KeyCode::Backspace => KeyAction::DeleteChar,
+        KeyCode::Left => KeyAction::CursorLeft,
+        KeyCode::Right => KeyAction::CursorRight,
+        KeyCode::Home => KeyAction::CursorHome,
+        KeyCode::End => KeyAction::CursorEnd,
+        KeyCode::Char(c) if is_text_input(&key) => KeyAction::InsertChar(c),
+        _ => KeyAction::None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -670,6 +739,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_z_prefix_za_toggles_fold() {
+        let key_z = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::NONE);
+        assert_eq!(
+            handle_key_event(InputMode::Normal, key_z, None),
+            KeyAction::PendingKey('z')
+        );
+        let key_a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        assert_eq!(
+            handle_key_event(InputMode::Normal, key_a, Some('z')),
+            KeyAction::ToggleFold
+        );
+        assert_eq!(
+            handle_key_event(InputMode::Normal, key_z, Some('z')),
+            KeyAction::None
+        );
+    }
+
+    #[test]
+    fn test_search_keys() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(
+            handle_key_event(InputMode::Normal, key(KeyCode::Char('/')), None),
+            KeyAction::Search
+        );
+        assert_eq!(
+            handle_key_event(InputMode::Normal, key(KeyCode::Char('n')), None),
+            KeyAction::SearchNext
+        );
+        assert_eq!(
+            handle_key_event(
+                InputMode::Normal,
+                KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT),
+                None
+            ),
+            KeyAction::SearchPrev
+        );
+
+        assert_eq!(
+            handle_key_event(InputMode::Search, key(KeyCode::Char('n')), None),
+            KeyAction::InsertChar('n')
+        );
+        assert_eq!(
+            handle_key_event(InputMode::Search, key(KeyCode::Enter), None),
+            KeyAction::SubmitSearch
+        );
+        assert_eq!(
+            handle_key_event(InputMode::Search, key(KeyCode::Esc), None),
+            KeyAction::ExitToNormal
+        );
+        assert_eq!(
+            handle_key_event(
+                InputMode::Search,
+                KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+                None
+            ),
+            KeyAction::ToggleSearchRegex
+        );
+    }
+
     #[test]
     fn test_g_prefix_gg_scroll_to_top() {
         // First `g` press → PendingKey
@@ -3,6 +3,7 @@
 //! Pure state-update logic with no terminal I/O.
 //! Each UiEvent is mapped to one or more TuiState changes and/or TuiEvent emissions.
 
+use super::content::CouncilContent;
 use super::event::{RoutedTuiEvent, TuiEvent};
 use super::state::{DisplayMessage, TuiState};
 use super::tab::PaneKind;
@@ -84,6 +85,19 @@ impl TuiPresenter {
                 state.push_message(DisplayMessage::system(format!("Review error: {}", error)));
                 self.emit(TuiEvent::AgentError(error.clone()));
             }
+            UiEvent::SessionSummary {
+                interaction_id,
+                summary,
+            } => {
+                state.push_message_to(
+                    *interaction_id,
+                    DisplayMessage::system(format!("TL;DR\n{}", summary)),
+                );
+                state.set_flash("Summary added");
+            }
+            UiEvent::SessionSummaryError { error, .. } => {
+                state.set_flash(format!("Summarize: {}", error));
+            }
             UiEvent::InteractionSpawned(event) => {
                 // Fix A: Try to bind the interaction_id to an existing placeholder tab
                 // (created immediately by handle_tab_command). If no placeholder exists
@@ -102,6 +116,7 @@ impl TuiPresenter {
                 if !event.query.is_empty() {
                     state.push_message_to(event.id, DisplayMessage::user(event.query.clone()));
                 }
+                state.enter_form_layout(event.form, event.id);
             }
             UiEvent::InteractionCompleted(event) => {
                 // Route to the parent's tab so a spawned child's result shows
@@ -110,6 +125,7 @@ impl TuiPresenter {
                 // of dropping the event, so headless callers (#300's `review`)
                 // can observe root completion via `pane.read` / `state.get`
                 // instead of it being silently swallowed (#303, RFC #304 D3).
+                state.leave_form_layout(event.id);
                 let target = event.parent_id.unwrap_or(event.id);
                 let _ = self.event_tx.send(RoutedTuiEvent::for_interaction(
                     target,
@@ -132,6 +148,9 @@ impl TuiPresenter {
                 state.push_message(DisplayMessage::system(format!("Quorum error: {}", error)));
                 self.emit(TuiEvent::AgentError(error.clone()));
             }
+            UiEvent::TranscriptSaved { path } => {
+                state.set_flash(format!("Transcript saved: {}", path));
+            }
             UiEvent::ContextInitStarting { model_count } => {
                 // Mark the pane as running so the header shows the live phase
                 // instead of "Ready" while context generation is in flight.
@@ -248,7 +267,16 @@ impl TuiPresenter {
     }
 
     fn handle_quorum_result(&self, state: &mut TuiState, result: &QuorumResultEvent) {
-        state.push_message(DisplayMessage::assistant(result.formatted_output.clone()));
+        state.tabs.active_pane_mut().council = Some(CouncilContent::new(
+            result.responses.clone(),
+            result.synthesis.moderator.clone(),
+            result.synthesis.conclusion.clone(),
+        ));
+        // A streamed synthesis was already finalized into an assistant
+        // message by StreamEnd — pushing it again would duplicate it.
+        if !result.synthesis_streamed {
+            state.push_message(DisplayMessage::assistant(result.formatted_output.clone()));
+        }
         self.emit(TuiEvent::AgentResult {
             success: true,
             summary: "Quorum discussion complete".into(),
@@ -256,11 +284,19 @@ impl TuiPresenter {
     }
 
     fn handle_context_init_result(&self, state: &mut TuiState, result: &ContextInitResultEvent) {
-        state.push_message(DisplayMessage::system(format!(
-            "Context saved to: {}",
-            result.path
-        )));
-        state.set_flash("Context initialized successfully");
+        if let Some(error) = &result.write_error {
+            state.push_message(DisplayMessage::system(format!(
+                "Context generated but not saved to {}: {} (kept for this session)",
+                result.path, error
+            )));
+            state.set_flash("Context initialized (not saved)");
+        } else {
+            state.push_message(DisplayMessage::system(format!(
+                "Context saved to: {}",
+                result.path
+            )));
+            state.set_flash("Context initialized successfully");
+        }
         Self::reset_progress(state);
     }
 
@@ -455,6 +491,83 @@ mod tests {
         assert!(pane.conversation.messages.is_empty());
     }
 
+    #[test]
+    fn test_discuss_spawn_switches_layout_until_completed() {
+        use super::super::layout::LayoutPreset;
+        use super::super::surface::SurfaceId;
+        use quorum_application::{InteractionCompletedEvent, InteractionSpawnedEvent};
+        use quorum_domain::interaction::{InteractionForm, InteractionId};
+
+        let (presenter, _rx, mut state) = setup();
+        state
+            .layout_config
+            .form_presets
+            .insert(InteractionForm::Discuss, LayoutPreset::Wide);
+        let spawn = |id, form| {
+            UiEvent::InteractionSpawned(InteractionSpawnedEvent {
+                id: InteractionId(id),
+                form,
+                parent_id: Some(InteractionId(0)),
+                query: "Which approach?".into(),
+            })
+        };
+
+        // Ask has no preset configured
+        presenter.apply(&mut state, &spawn(1, InteractionForm::Ask));
+        assert_eq!(state.layout_config.preset, LayoutPreset::Default);
+
+        presenter.apply(&mut state, &spawn(2, InteractionForm::Discuss));
+        assert_eq!(state.layout_config.preset, LayoutPreset::Wide);
+        assert!(
+            state
+                .route
+                .required_pane_surfaces()
+                .contains(&SurfaceId::ToolPane)
+        );
+
+        let complete = |id, form| {
+            UiEvent::InteractionCompleted(InteractionCompletedEvent {
+                id: InteractionId(id),
+                form,
+                parent_id: Some(InteractionId(0)),
+                result_text: "done".into(),
+                result: None,
+            })
+        };
+        presenter.apply(&mut state, &complete(1, InteractionForm::Ask));
+        assert_eq!(state.layout_config.preset, LayoutPreset::Wide);
+
+        presenter.apply(&mut state, &complete(2, InteractionForm::Discuss));
+        assert_eq!(state.layout_config.preset, LayoutPreset::Default);
+        assert!(state.layout_restore.is_none());
+    }
+
+    #[test]
+    fn test_quorum_result_fills_council() {
+        use quorum_application::QuorumResultEvent;
+        use quorum_domain::{ModelResponse, OutputFormat, SynthesisResult};
+
+        let (presenter, _rx, mut state) = setup();
+        presenter.apply(
+            &mut state,
+            &UiEvent::QuorumResult(QuorumResultEvent {
+                formatted_output: "Use option B".into(),
+                output_format: OutputFormat::Synthesis,
+                synthesis_streamed: false,
+                responses: vec![
+                    ModelResponse::success("gpt", "A"),
+                    ModelResponse::failure("claude", "timeout"),
+                ],
+                synthesis: SynthesisResult::new("judge", "Use option B"),
+            }),
+        );
+
+        let council = state.tabs.active_pane().council.as_ref().unwrap();
+        assert_eq!(council.responses.len(), 2);
+        assert_eq!(council.moderator, "judge");
+        assert_eq!(council.synthesis, "Use option B");
+    }
+
     #[test]
     fn test_config_display_groups_by_section() {
         use quorum_application::ConfigEntry;
@@ -683,6 +796,7 @@ mod tests {
                 path: ".quorum/context.md".into(),
                 content: "ctx".into(),
                 contributing_models: vec!["m".into()],
+                write_error: None,
             }),
         );
         assert!(
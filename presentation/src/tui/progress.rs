@@ -5,16 +5,21 @@
 //! everything goes through the channel for ratatui rendering.
 
 use super::event::{RoutedTuiEvent, TuiEvent};
+use crate::agent::progress::display_file_name;
 use quorum_application::AgentProgressNotifier;
 use quorum_domain::{
-    AgentPhase, InteractionId, Model, Plan, ReviewRound, StreamContext, Task, Thought,
+    AgentPhase, InteractionId, Model, Plan, PlanImpact, ReviewRound, StreamContext, Task, Thought,
 };
+use std::collections::HashSet;
+use std::sync::Mutex;
 use tokio::sync::mpsc;
 
 /// Bridge from AgentProgressNotifier callbacks to TuiEvent channel
 pub struct TuiProgressBridge {
     tx: mpsc::UnboundedSender<RoutedTuiEvent>,
     interaction_id: Option<InteractionId>,
+    /// Reviewers whose streamed reasoning has an open model stream pane
+    review_streams: Mutex<HashSet<String>>,
 }
 
 impl TuiProgressBridge {
@@ -22,6 +27,7 @@ impl TuiProgressBridge {
         Self {
             tx,
             interaction_id: None,
+            review_streams: Mutex::new(HashSet::new()),
         }
     }
 
@@ -29,6 +35,7 @@ impl TuiProgressBridge {
         Self {
             tx,
             interaction_id: Some(id),
+            review_streams: Mutex::new(HashSet::new()),
         }
     }
 
@@ -66,6 +73,13 @@ impl AgentProgressNotifier for TuiProgressBridge {
         // Thoughts are displayed via streaming for now
     }
 
+    fn on_context_file_loaded(&self, path: &str, _source: &str) {
+        self.emit(TuiEvent::Flash(format!(
+            "Reading {}…",
+            display_file_name(path)
+        )));
+    }
+
     fn on_task_start(&self, task: &Task, index: usize, total: usize) {
         self.emit(TuiEvent::TaskStart {
             description: task.description.clone(),
@@ -142,6 +156,13 @@ impl AgentProgressNotifier for TuiProgressBridge {
         });
     }
 
+    fn on_tool_execution_diff(&self, _task_id: &str, execution_id: &str, diff: &str) {
+        self.emit(TuiEvent::ToolExecutionDiff {
+            execution_id: execution_id.to_string(),
+            diff: diff.to_string(),
+        });
+    }
+
     fn on_tool_execution_failed(
         &self,
         _task_id: &str,
@@ -212,7 +233,29 @@ impl AgentProgressNotifier for TuiProgressBridge {
         });
     }
 
+    fn on_quorum_model_chunk(&self, model: &Model, chunk: &str) {
+        let model = model.to_string();
+        if self.review_streams.lock().unwrap().insert(model.clone()) {
+            self.emit(TuiEvent::ModelStreamStart {
+                model: model.clone(),
+                context: StreamContext::PlanReview,
+            });
+        }
+        self.emit(TuiEvent::ModelStreamChunk {
+            model,
+            chunk: chunk.to_string(),
+        });
+    }
+
     fn on_quorum_model_complete(&self, model: &Model, approved: bool) {
+        if self
+            .review_streams
+            .lock()
+            .unwrap()
+            .remove(&model.to_string())
+        {
+            self.emit(TuiEvent::ModelStreamEnd(model.to_string()));
+        }
         self.emit(TuiEvent::QuorumModelVote {
             model: model.to_string(),
             approved,
@@ -261,10 +304,16 @@ impl AgentProgressNotifier for TuiProgressBridge {
         )));
     }
 
-    fn on_execution_confirmation_required(&self, _request: &str, _plan: &Plan) {
-        self.emit(TuiEvent::Flash(
-            "Execution confirmation required".to_string(),
-        ));
+    fn on_execution_confirmation_required(
+        &self,
+        _request: &str,
+        _plan: &Plan,
+        impact: &PlanImpact,
+    ) {
+        self.emit(TuiEvent::Flash(format!(
+            "Execution confirmation required ({})",
+            impact
+        )));
     }
 
     fn on_ensemble_start(&self, model_count: usize) {
@@ -286,6 +335,13 @@ impl AgentProgressNotifier for TuiProgressBridge {
         });
     }
 
+    fn on_ensemble_model_timeout(&self, model: &Model) {
+        self.emit(TuiEvent::EnsembleModelFailed {
+            model: model.to_string(),
+            error: "timed out".to_string(),
+        });
+    }
+
     fn on_ensemble_complete(&self, selected_model: &Model, score: f64) {
         self.emit(TuiEvent::EnsembleComplete {
             selected_model: selected_model.to_string(),
@@ -293,6 +349,13 @@ impl AgentProgressNotifier for TuiProgressBridge {
         });
     }
 
+    fn on_ensemble_merged(&self, moderator: &Model, source_count: usize) {
+        self.emit(TuiEvent::EnsembleMerged {
+            moderator: moderator.to_string(),
+            sources: source_count,
+        });
+    }
+
     fn on_ensemble_fallback(&self, reason: &str) {
         self.emit(TuiEvent::EnsembleFallback(reason.to_string()));
     }
@@ -371,6 +434,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_quorum_model_chunk_opens_and_closes_review_stream() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let bridge = TuiProgressBridge::new(tx);
+        let model = Model::ClaudeSonnet45;
+
+        bridge.on_quorum_model_chunk(&model, "Looks ");
+        bridge.on_quorum_model_chunk(&model, "good. APPROVE");
+        bridge.on_quorum_model_complete(&model, true);
+
+        assert!(matches!(
+            rx.try_recv().unwrap().event,
+            TuiEvent::ModelStreamStart {
+                context: StreamContext::PlanReview,
+                ..
+            }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap().event,
+            TuiEvent::ModelStreamChunk { .. }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap().event,
+            TuiEvent::ModelStreamChunk { .. }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap().event,
+            TuiEvent::ModelStreamEnd(_)
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap().event,
+            TuiEvent::QuorumModelVote { approved: true, .. }
+        ));
+    }
+
     #[test]
     fn test_stream_chunk_emits_event() {
         let (tx, mut rx) = mpsc::unbounded_channel();
@@ -391,6 +489,32 @@ mod tests {
         assert!(matches!(rx.try_recv().unwrap().event, TuiEvent::StreamEnd));
     }
 
+    #[test]
+    fn test_quorum_synthesis_deltas_stream_into_conversation() {
+        use quorum_application::ports::progress::{ProgressNotifier, QuorumProgressAdapter};
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let bridge = TuiProgressBridge::for_interaction(tx, InteractionId(7));
+        let adapter = QuorumProgressAdapter::new(&bridge);
+
+        for chunk in ["Use ", "write-", "through."] {
+            adapter.on_synthesis_chunk(chunk);
+        }
+        adapter.on_synthesis_end();
+        assert!(adapter.streamed_synthesis());
+
+        let mut chunks = Vec::new();
+        while let Ok(routed) = rx.try_recv() {
+            assert_eq!(routed.interaction_id, Some(InteractionId(7)));
+            match routed.event {
+                TuiEvent::StreamChunk(chunk) => chunks.push(chunk),
+                TuiEvent::StreamEnd => chunks.push("<end>".to_string()),
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        assert_eq!(chunks, vec!["Use ", "write-", "through.", "<end>"]);
+    }
+
     #[test]
     fn test_routed_event_includes_interaction_id() {
         let (tx, mut rx) = mpsc::unbounded_channel();
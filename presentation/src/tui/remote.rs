@@ -72,6 +72,7 @@ use quorum_application::{ConfigAccessorPort, ConfigValue, QuorumConfig};
 use quorum_domain::HumanDecision;
 use quorum_domain::interaction::InteractionForm;
 use serde_json::{Value, json};
+use std::collections::BTreeMap;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -704,12 +705,17 @@ fn config_value_to_json(value: &ConfigValue) -> Value {
         ConfigValue::StringList(list) => {
             Value::Array(list.iter().map(|s| Value::String(s.clone())).collect())
         }
+        ConfigValue::StringMap(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+                .collect(),
+        ),
     }
 }
 
 /// JSON → `ConfigValue`, mirroring `config_api::lua_to_config_value` (Lua
 /// path): string/bool/integer map directly, an array of strings becomes a
-/// `StringList`, anything else is rejected up front instead of surfacing a
+/// `StringList`, an object of strings a `StringMap`, anything else is rejected up front instead of surfacing a
 /// confusing type error from deep inside `config_set`.
 fn json_to_config_value(value: &Value) -> Result<ConfigValue, RemoteError> {
     match value {
@@ -733,6 +739,14 @@ fn json_to_config_value(value: &Value) -> Result<ConfigValue, RemoteError> {
                 .map(ConfigValue::StringList)
                 .ok_or_else(|| RemoteError::invalid_params("'value' list elements must be strings"))
         }
+        Value::Object(entries) => {
+            let map: Option<BTreeMap<String, String>> = entries
+                .iter()
+                .map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                .collect();
+            map.map(ConfigValue::StringMap)
+                .ok_or_else(|| RemoteError::invalid_params("'value' object values must be strings"))
+        }
         other => Err(RemoteError::invalid_params(format!(
             "unsupported 'value' type: {other}"
         ))),
@@ -772,8 +786,8 @@ fn keys_feed(
         if state.should_quit {
             break;
         }
-        if let Some(super::app::SideEffect::LaunchEditor) =
-            super::app::dispatch_terminal_event(state, crossterm::event::Event::Key(key), &ctx.deps)
+        if super::app::dispatch_terminal_event(state, crossterm::event::Event::Key(key), &ctx.deps)
+            .is_some()
         {
             // The terminal cannot be suspended from a remote request — swallow.
             editor_suppressed = true;
@@ -793,10 +807,7 @@ fn keys_feed(
 /// Rebuild the route table after a preset/override change — the live
 /// mutation path shared with the Lua accessor (see `app_tui_changes`).
 fn rebuild_route(state: &mut TuiState) {
-    state.route = super::route::RouteTable::from_preset_and_overrides(
-        state.layout_config.preset.clone(),
-        &state.layout_config.route_overrides,
-    );
+    state.route = super::route::RouteTable::from_layout_config(&state.layout_config);
 }
 
 /// Current routes with visibility at the live layout — shared by the
@@ -983,6 +994,9 @@ fn state_snapshot(state: &TuiState) -> Value {
             "objective": h.objective,
             "tasks": h.tasks,
             "message": h.message,
+            "remaining_ms": h.deadline.map(|d| {
+                d.saturating_duration_since(std::time::Instant::now()).as_millis() as u64
+            }),
         })),
         "pending_key": state.pending_key.map(String::from),
         "show_help": state.show_help,
@@ -1093,6 +1107,9 @@ fn input_send(
     if text.is_empty() {
         return Err(RemoteError::invalid_params("'text' must not be empty"));
     }
+    if state.read_only {
+        return Err(RemoteError::failed("read-only replay: input is not sent"));
+    }
 
     state.tabs.active_pane_mut().set_title_if_empty(&text);
     // Mirror KeyAction::SubmitInput (app_action_handler): a bound tab processes
@@ -1183,7 +1200,7 @@ fn parse_form(s: &str) -> Result<InteractionForm, RemoteError> {
 }
 
 fn interaction_spawn(
-    _state: &mut TuiState,
+    state: &mut TuiState,
     ctx: &RemoteContext<'_>,
     params: &Value,
 ) -> Result<Value, RemoteError> {
@@ -1199,6 +1216,9 @@ fn interaction_spawn(
         .and_then(|v| v.as_str())
         .ok_or_else(|| RemoteError::invalid_params("missing 'query' (string)"))?
         .to_string();
+    if state.read_only {
+        return Err(RemoteError::failed("read-only replay: nothing is started"));
+    }
 
     cmd_tx
         .send(TuiCommand::SpawnInteraction {
@@ -1461,6 +1481,84 @@ mod tests {
         assert!(state.show_help);
     }
 
+    #[test]
+    fn keys_feed_mode_transition_emits_mode_changed() {
+        use quorum_domain::scripting::{ScriptEventData, ScriptEventType, ScriptValue};
+
+        #[derive(Default)]
+        struct RecordingEngine {
+            events: Mutex<Vec<(ScriptEventType, ScriptEventData)>>,
+        }
+        impl ScriptingEnginePort for RecordingEngine {
+            fn emit_event(
+                &self,
+                event: ScriptEventType,
+                data: ScriptEventData,
+            ) -> Result<quorum_application::EventOutcome, quorum_application::ScriptError>
+            {
+                self.events.lock().unwrap().push((event, data));
+                Ok(quorum_application::EventOutcome::Continue)
+            }
+            fn load_script(
+                &self,
+                _path: &std::path::Path,
+            ) -> Result<(), quorum_application::ScriptError> {
+                Ok(())
+            }
+            fn is_available(&self) -> bool {
+                true
+            }
+            fn registered_keymaps(
+                &self,
+            ) -> Vec<(String, String, quorum_application::KeymapAction)> {
+                Vec::new()
+            }
+            fn execute_callback(
+                &self,
+                _callback_id: u64,
+            ) -> Result<(), quorum_application::ScriptError> {
+                Ok(())
+            }
+        }
+
+        let engine = Arc::new(RecordingEngine::default());
+        let mut harness = TestHarness::new();
+        harness.engine = engine.clone();
+        let mut state = TuiState::new();
+
+        // Insert → Normal → Command; typing "h" changes no mode
+        dispatch(
+            &mut state,
+            &harness.ctx(),
+            "keys.feed",
+            &json!({"keys": ["h", "Esc", ":"]}),
+        )
+        .unwrap();
+
+        let events = engine.events.lock().unwrap();
+        let modes: Vec<(String, String)> = events
+            .iter()
+            .map(|(event, data)| {
+                assert_eq!(*event, ScriptEventType::ModeChanged);
+                let mut keys: Vec<&String> = data.fields().keys().collect();
+                keys.sort();
+                assert_eq!(keys, ["new_mode", "old_mode"]);
+                let field = |key: &str| match data.fields().get(key) {
+                    Some(ScriptValue::String(s)) => s.clone(),
+                    other => panic!("{key} should be a string, got {other:?}"),
+                };
+                (field("old_mode"), field("new_mode"))
+            })
+            .collect();
+        assert_eq!(
+            modes,
+            vec![
+                ("Insert".to_string(), "Normal".to_string()),
+                ("Normal".to_string(), "Command".to_string()),
+            ]
+        );
+    }
+
     /// Regression test for #269 — mirrors the issue's repro steps:
     /// HiL modal shown → `keys.feed` j/k → `state.get` scroll_offset must change.
     #[test]
@@ -1473,6 +1571,8 @@ mod tests {
             objective: "obj".into(),
             tasks: vec!["task".into()],
             message: "Approve or reject?".into(),
+            plan_draft: None,
+            deadline: None,
         });
         let (tx, _rx) = oneshot::channel();
         *harness.pending_hil_tx.lock().unwrap() = Some(tx);
@@ -161,20 +161,12 @@ pub(super) fn layout_snapshot(state: &TuiState, area: Rect) -> Value {
     let (layout, pane_surfaces) = app_render::compute_layout(state, area);
     let surfaces = SurfaceLayout::from_main_layout(&layout, &pane_surfaces);
 
-    // Mirrors the fallback logic in MainLayout::compute_with_layout.
+    let (splits, direction) =
+        MainLayout::resolve_splits(area, &state.layout_config, pane_surfaces.len());
     let flex_fallback_active = state.layout_config.preset.is_builtin()
         && state.layout_config.flex_threshold > 0
         && area.width < state.layout_config.flex_threshold;
 
-    let (splits, direction) = if flex_fallback_active {
-        (vec![100u16], ratatui::layout::Direction::Horizontal)
-    } else {
-        (
-            state.layout_config.resolve_splits(pane_surfaces.len()),
-            state.layout_config.resolve_direction(),
-        )
-    };
-
     let mut surface_map = serde_json::Map::new();
     let chrome = [
         SurfaceId::Header,
@@ -285,6 +277,8 @@ mod tests {
             objective: "Fix the bug".into(),
             tasks: vec!["read file".into()],
             message: "Approve?".into(),
+            plan_draft: None,
+            deadline: None,
         });
         let (lines, _) = capture_screen(&state, &registry(), 80, 24, false).unwrap();
         assert!(lines.iter().any(|l| l.contains("Human Intervention")));
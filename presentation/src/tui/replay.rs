@@ -0,0 +1,112 @@
+//! Replay of conversation logs (`--replay`).
+//!
+//! Maps [`ConversationRecord`]s read back from a `.conversation.jsonl` file to
+//! the [`DisplayMessage`]s shown in the conversation pane. Events with no
+//! conversational content (prompts, interaction bookkeeping) are dropped.
+
+use super::state::DisplayMessage;
+use quorum_application::ConversationRecord;
+use serde_json::Value;
+
+impl DisplayMessage {
+    /// Display form of a logged event, or `None` if it is not shown on replay.
+    pub fn from_conversation_record(record: &ConversationRecord) -> Option<Self> {
+        let payload = &record.payload;
+        match record.event_type.as_str() {
+            "agent_start" => text(payload, "request").map(DisplayMessage::user),
+            "ask_response" | "llm_response" => text(payload, "text").map(DisplayMessage::assistant),
+            "agent_complete" => text(payload, "summary").map(DisplayMessage::assistant),
+            "plan_generated" => Some(DisplayMessage::system(format!(
+                "Plan: {} ({} tasks)",
+                text(payload, "objective").unwrap_or_default(),
+                payload["task_count"].as_u64().unwrap_or(0)
+            ))),
+            "tool_call" => text(payload, "tool").map(|tool| {
+                DisplayMessage::system(format!("Tool call: {} {}", tool, payload["args"]))
+            }),
+            "tool_result" => text(payload, "tool").map(|tool| {
+                let status = if payload["success"].as_bool().unwrap_or(false) {
+                    "ok"
+                } else {
+                    "failed"
+                };
+                DisplayMessage::system(format!("Tool result: {} {}", tool, status))
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Display messages for a replayed log, in order.
+pub fn replay_messages(records: &[ConversationRecord]) -> Vec<DisplayMessage> {
+    records
+        .iter()
+        .filter_map(DisplayMessage::from_conversation_record)
+        .collect()
+}
+
+fn text(payload: &Value, key: &str) -> Option<String> {
+    payload[key]
+        .as_str()
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::state::MessageRole;
+    use quorum_application::ConversationEvent;
+    use serde_json::json;
+
+    #[test]
+    fn test_logged_events_replay_with_roles() {
+        let events = vec![
+            ConversationEvent::new("agent_start", json!({ "request": "Fix the login bug" })),
+            ConversationEvent::new("llm_prompt", json!({ "text": "system prompt" })),
+            ConversationEvent::new(
+                "plan_generated",
+                json!({ "model": "m", "objective": "Fix login", "task_count": 2 }),
+            ),
+            ConversationEvent::new(
+                "tool_call",
+                json!({ "task_id": "1", "tool": "read_file", "args": { "path": "a.rs" } }),
+            ),
+            ConversationEvent::new(
+                "tool_result",
+                json!({ "tool": "read_file", "success": true }),
+            ),
+            ConversationEvent::new("llm_response", json!({ "task_id": "1", "text": "Patched" })),
+            ConversationEvent::new(
+                "agent_complete",
+                json!({ "summary": "Done", "success": true }),
+            ),
+        ];
+        let records: Vec<ConversationRecord> = events.into_iter().map(Into::into).collect();
+
+        let messages = replay_messages(&records);
+        let roles: Vec<_> = messages.iter().map(|m| m.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                MessageRole::User,
+                MessageRole::System,
+                MessageRole::System,
+                MessageRole::System,
+                MessageRole::Assistant,
+                MessageRole::Assistant,
+            ]
+        );
+        assert_eq!(messages[0].content, "Fix the login bug");
+        assert_eq!(messages[1].content, "Plan: Fix login (2 tasks)");
+        assert_eq!(messages[3].content, "Tool result: read_file ok");
+        assert_eq!(messages[5].content, "Done");
+    }
+
+    #[test]
+    fn test_events_without_text_are_skipped() {
+        let record: ConversationRecord =
+            ConversationEvent::new("ask_response", json!({ "model": "m" })).into();
+        assert!(DisplayMessage::from_conversation_record(&record).is_none());
+    }
+}
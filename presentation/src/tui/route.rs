@@ -6,7 +6,7 @@
 use std::collections::HashSet;
 
 use super::content::ContentSlot;
-use super::layout::{LayoutPreset, RouteOverride};
+use super::layout::{LayoutPreset, RouteOverride, TuiLayoutConfig};
 use super::surface::SurfaceId;
 
 /// A single route entry mapping content to a surface.
@@ -80,6 +80,9 @@ impl RouteTable {
     }
 
     /// Wide layout: 3-pane with tool log in ToolPane.
+    ///
+    /// The council shares the ToolPane and draws over the tool log once a
+    /// discussion result is available.
     pub fn wide_layout() -> Self {
         Self {
             entries: vec![
@@ -95,6 +98,10 @@ impl RouteTable {
                     content: ContentSlot::ToolLog,
                     surface: SurfaceId::ToolPane,
                 },
+                RouteEntry {
+                    content: ContentSlot::Council,
+                    surface: SurfaceId::ToolPane,
+                },
                 RouteEntry {
                     content: ContentSlot::HilPrompt,
                     surface: SurfaceId::Overlay,
@@ -155,6 +162,43 @@ impl RouteTable {
         table
     }
 
+    /// Build the route table for a full layout config.
+    ///
+    /// A custom preset that names a slot for every pane routes pane `i` to
+    /// the i-th content surface (MainPane, Sidebar, ToolPane, then dynamic
+    /// panes), keeping the default overlay/status routes. Everything else
+    /// goes through [`Self::from_preset_and_overrides`].
+    pub fn from_layout_config(config: &TuiLayoutConfig) -> Self {
+        let Some(custom) = config.custom_layout().filter(|c| c.has_slots()) else {
+            return Self::from_preset_and_overrides(config.preset.clone(), &config.route_overrides);
+        };
+
+        let mut entries: Vec<RouteEntry> = custom
+            .panes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pane)| {
+                pane.slot.clone().map(|content| RouteEntry {
+                    content,
+                    surface: custom_pane_surface(i),
+                })
+            })
+            .collect();
+        let chrome: Vec<RouteEntry> = Self::default_layout()
+            .entries
+            .into_iter()
+            .filter(|e| !e.surface.is_content_pane())
+            .filter(|e| !entries.iter().any(|p| p.content == e.content))
+            .collect();
+        entries.extend(chrome);
+
+        let mut table = Self { entries };
+        for ov in &config.route_overrides {
+            table.set_route(ov.content.clone(), ov.surface.clone());
+        }
+        table
+    }
+
     /// Set or replace a route entry for the given content slot.
     pub fn set_route(&mut self, content: ContentSlot, surface: SurfaceId) {
         if let Some(entry) = self.entries.iter_mut().find(|e| e.content == content) {
@@ -208,6 +252,16 @@ impl Default for RouteTable {
     }
 }
 
+/// Content surface for pane `index` of a custom layout.
+fn custom_pane_surface(index: usize) -> SurfaceId {
+    match index {
+        0 => SurfaceId::MainPane,
+        1 => SurfaceId::Sidebar,
+        2 => SurfaceId::ToolPane,
+        n => SurfaceId::DynamicPane(format!("pane{}", n + 1)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +407,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_layout_config_custom_slots() {
+        use quorum_application::CustomPresetConfig;
+
+        let mut config = TuiLayoutConfig::default();
+        config.register_custom_preset(
+            "review",
+            &CustomPresetConfig {
+                splits: vec![20, 20, 30, 30],
+                direction: "horizontal".to_string(),
+                slots: vec![
+                    "progress".to_string(),
+                    "tool_log".to_string(),
+                    "conversation".to_string(),
+                    "lua:notes".to_string(),
+                ],
+            },
+        );
+        config.preset = LayoutPreset::Custom("review".to_string());
+
+        let table = RouteTable::from_layout_config(&config);
+        assert_eq!(
+            table.required_pane_surfaces(),
+            vec![
+                SurfaceId::MainPane,
+                SurfaceId::Sidebar,
+                SurfaceId::ToolPane,
+                SurfaceId::DynamicPane("pane4".to_string()),
+            ]
+        );
+        assert_eq!(
+            table.surface_for(&ContentSlot::Progress),
+            Some(SurfaceId::MainPane)
+        );
+        assert_eq!(
+            table.surface_for(&ContentSlot::Conversation),
+            Some(SurfaceId::ToolPane)
+        );
+        assert_eq!(
+            table.surface_for(&ContentSlot::Help),
+            Some(SurfaceId::Overlay)
+        );
+
+        // Without slots a custom preset keeps the default routes
+        config.register_custom_preset(
+            "plain",
+            &CustomPresetConfig {
+                splits: vec![50, 50],
+                direction: "vertical".to_string(),
+                slots: vec![],
+            },
+        );
+        config.preset = LayoutPreset::Custom("plain".to_string());
+        let table = RouteTable::from_layout_config(&config);
+        assert_eq!(
+            table.surface_for(&ContentSlot::Conversation),
+            Some(SurfaceId::MainPane)
+        );
+    }
+
     #[test]
     fn test_required_pane_surfaces_default() {
         let route = RouteTable::default_layout();
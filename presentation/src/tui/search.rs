@@ -0,0 +1,235 @@
+//! Conversation search (`/`, `n`, `N` in Normal mode).
+//!
+//! Matches are computed per content line of each [`DisplayMessage`], the same
+//! lines the conversation widget renders, so a match can be highlighted and
+//! scrolled to without re-deriving the layout.
+
+use super::state::DisplayMessage;
+use regex::{Regex, RegexBuilder};
+use std::cell::Cell;
+use std::ops::Range;
+
+/// One occurrence of the search pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Index into the pane's messages.
+    pub message: usize,
+    /// Index into `content.lines()` of that message.
+    pub line: usize,
+    /// Byte range within that line.
+    pub range: Range<usize>,
+}
+
+/// Active search over the conversation pane.
+#[derive(Debug, Clone)]
+pub struct ConversationSearch {
+    pub query: String,
+    /// Treat `query` as a regular expression instead of a literal substring.
+    pub regex: bool,
+    pub matches: Vec<SearchMatch>,
+    /// Index into `matches` of the match jumped to last.
+    pub current: usize,
+    /// Keep the current match in view; cleared by manual scrolling.
+    pub follow: bool,
+    /// Scroll offset (lines from the bottom) the widget last showed the
+    /// current match at, so manual scrolling continues from there.
+    pub rendered_offset: Cell<Option<usize>>,
+}
+
+impl ConversationSearch {
+    /// Run `query` over `messages`, starting at the most recent match.
+    ///
+    /// Fails only on an invalid regex.
+    pub fn new(
+        query: impl Into<String>,
+        regex: bool,
+        messages: &[DisplayMessage],
+    ) -> Result<Self, regex::Error> {
+        let query = query.into();
+        let matches = find_matches(messages, &query, regex)?;
+        Ok(Self {
+            current: matches.len().saturating_sub(1),
+            query,
+            regex,
+            matches,
+            follow: true,
+            rendered_offset: Cell::new(None),
+        })
+    }
+
+    /// Recompute matches after new messages arrived, keeping `current`.
+    pub fn refresh(&mut self, messages: &[DisplayMessage]) {
+        if let Ok(matches) = find_matches(messages, &self.query, self.regex) {
+            self.matches = matches;
+            self.current = self.current.min(self.matches.len().saturating_sub(1));
+        }
+    }
+
+    /// Move to the next match (towards newer messages), wrapping around.
+    pub fn next(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + 1) % self.matches.len();
+            self.follow = true;
+        }
+    }
+
+    /// Move to the previous match (towards older messages), wrapping around.
+    pub fn prev(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + self.matches.len() - 1) % self.matches.len();
+            self.follow = true;
+        }
+    }
+
+    pub fn current_match(&self) -> Option<&SearchMatch> {
+        self.matches.get(self.current)
+    }
+
+    /// Status text such as `[2/5] login`.
+    pub fn position_label(&self) -> String {
+        if self.matches.is_empty() {
+            format!("Pattern not found: {}", self.query)
+        } else {
+            format!(
+                "[{}/{}] {}",
+                self.current + 1,
+                self.matches.len(),
+                self.query
+            )
+        }
+    }
+}
+
+/// Find every occurrence of `query` in the content lines of `messages`.
+///
+/// Matching is case-insensitive. Without `regex` the query is a literal
+/// substring. An empty query matches nothing.
+pub fn find_matches(
+    messages: &[DisplayMessage],
+    query: &str,
+    regex: bool,
+) -> Result<Vec<SearchMatch>, regex::Error> {
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let pattern = build_pattern(query, regex)?;
+
+    let mut matches = Vec::new();
+    for (message, msg) in messages.iter().enumerate() {
+        for (line, text) in msg.content.lines().enumerate() {
+            matches.extend(
+                pattern
+                    .find_iter(text)
+                    .filter(|m| !m.range().is_empty())
+                    .map(|m| SearchMatch {
+                        message,
+                        line,
+                        range: m.range(),
+                    }),
+            );
+        }
+    }
+    Ok(matches)
+}
+
+fn build_pattern(query: &str, regex: bool) -> Result<Regex, regex::Error> {
+    let pattern = if regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    RegexBuilder::new(&pattern).case_insensitive(true).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages() -> Vec<DisplayMessage> {
+        vec![
+            DisplayMessage::user("Fix the Login bug"),
+            DisplayMessage::assistant("Looking at login.rs\nThe LOGIN handler drops the session"),
+            DisplayMessage::system("Plan: 2 tasks"),
+        ]
+    }
+
+    #[test]
+    fn test_substring_matches_are_case_insensitive() {
+        let matches = find_matches(&messages(), "login", false).unwrap();
+        assert_eq!(
+            matches,
+            vec![
+                SearchMatch {
+                    message: 0,
+                    line: 0,
+                    range: 8..13
+                },
+                SearchMatch {
+                    message: 1,
+                    line: 0,
+                    range: 11..16
+                },
+                SearchMatch {
+                    message: 1,
+                    line: 1,
+                    range: 4..9
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_substring_escapes_regex_metacharacters() {
+        let matches = find_matches(&messages(), "login.rs", false).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].range, 11..19);
+
+        assert!(find_matches(&messages(), "(", false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_regex_mode() {
+        let matches = find_matches(&messages(), r"\d+ tasks", true).unwrap();
+        assert_eq!(
+            matches,
+            vec![SearchMatch {
+                message: 2,
+                line: 0,
+                range: 6..13
+            }]
+        );
+        assert!(find_matches(&messages(), "(", true).is_err());
+    }
+
+    #[test]
+    fn test_empty_query_and_empty_matches_are_ignored() {
+        assert!(find_matches(&messages(), "", false).unwrap().is_empty());
+        assert!(find_matches(&messages(), "q*", true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_navigation_starts_at_latest_and_wraps() {
+        let mut search = ConversationSearch::new("login", false, &messages()).unwrap();
+        assert_eq!(search.current, 2);
+        assert_eq!(search.position_label(), "[3/3] login");
+
+        search.next();
+        assert_eq!(search.current, 0);
+        search.prev();
+        search.prev();
+        assert_eq!(search.current, 1);
+        assert_eq!(search.current_match().unwrap().line, 0);
+    }
+
+    #[test]
+    fn test_refresh_picks_up_new_messages() {
+        let mut msgs = messages();
+        let mut search = ConversationSearch::new("session", false, &msgs).unwrap();
+        assert_eq!(search.matches.len(), 1);
+
+        msgs.push(DisplayMessage::assistant("Session restored"));
+        search.refresh(&msgs);
+        assert_eq!(search.matches.len(), 2);
+        assert_eq!(search.current, 0);
+    }
+}
@@ -7,11 +7,12 @@ use std::collections::HashMap;
 
 use super::command_completion::CommandCompletion;
 use super::content::{ContentRegistry, ContentSlot};
-use super::layout::TuiLayoutConfig;
+use super::layout::{LayoutPreset, TuiLayoutConfig};
 use super::mode::InputMode;
 use super::route::RouteTable;
+use super::search::ConversationSearch;
 use super::tab::TabManager;
-use quorum_domain::{AgentPhase, ConsensusLevel, PhaseScope};
+use quorum_domain::{AgentPhase, ConsensusLevel, IntentRouting, PhaseScope};
 
 /// Central TUI state — owned by the TuiApp select! loop
 pub struct TuiState {
@@ -59,6 +60,17 @@ pub struct TuiState {
 
     // -- Layout config --
     pub layout_config: TuiLayoutConfig,
+    /// Preset to restore when the interaction that triggered a per-form
+    /// layout switch completes.
+    pub layout_restore: Option<(quorum_domain::InteractionId, LayoutPreset)>,
+
+    // -- Accessibility --
+    /// ASCII borders and no color styling (`tui.ascii_mode` / `--no-unicode`).
+    pub ascii_mode: bool,
+
+    // -- Intent routing --
+    /// How untyped input on a placeholder tab picks its form (`tui.intent_routing`).
+    pub intent_routing: IntentRouting,
 
     // -- Lua content --
     /// Text content for Lua-registered content slots.
@@ -71,6 +83,24 @@ pub struct TuiState {
     /// Active Visual-mode selection. `Some` only when `mode == InputMode::Visual`.
     pub visual_selection: Option<VisualSelection>,
 
+    // -- Conversation search --
+    /// Query being typed in Search mode (`/`).
+    pub search_input: String,
+    pub search_cursor: usize,
+    /// Regex matching for the next search (toggled with Ctrl+r in Search mode).
+    pub search_regex: bool,
+    /// Last submitted search, with its match positions in the active pane.
+    pub search: Option<ConversationSearch>,
+
+    // -- Export --
+    /// Where `:export` with no path writes (the log directory).
+    pub export_dir: Option<std::path::PathBuf>,
+
+    // -- Replay --
+    /// Viewing a replayed conversation log (`--replay`): input is not
+    /// submitted, no interaction is spawned and HiL requests are rejected.
+    pub read_only: bool,
+
     // -- Lifecycle --
     pub should_quit: bool,
 }
@@ -95,9 +125,18 @@ impl Default for TuiState {
             hil_prompt: None,
             tui_config: TuiInputConfig::default(),
             layout_config: TuiLayoutConfig::default(),
+            layout_restore: None,
+            ascii_mode: false,
+            intent_routing: IntentRouting::default(),
             lua_content: HashMap::new(),
             focused_slot: ContentSlot::Conversation,
             visual_selection: None,
+            search_input: String::new(),
+            search_cursor: 0,
+            search_regex: false,
+            search: None,
+            export_dir: None,
+            read_only: false,
             should_quit: false,
         }
     }
@@ -177,6 +216,7 @@ impl TuiState {
     pub fn input_line_count(&self) -> usize {
         let input = match self.mode {
             InputMode::Command => &self.command_input,
+            InputMode::Search => &self.search_input,
             _ => &self.tabs.active_pane().input,
         };
         input.lines().count().max(1) + if input.ends_with('\n') { 1 } else { 0 }
@@ -189,6 +229,12 @@ impl TuiState {
         std::mem::take(&mut pane.input)
     }
 
+    /// Take the search buffer contents and clear it
+    pub fn take_search(&mut self) -> String {
+        self.search_cursor = 0;
+        std::mem::take(&mut self.search_input)
+    }
+
     /// Take the command buffer contents and clear it
     pub fn take_command(&mut self) -> String {
         self.command_cursor = 0;
@@ -201,6 +247,7 @@ impl TuiState {
     fn active_input(&self) -> &str {
         match self.mode {
             InputMode::Command => &self.command_input,
+            InputMode::Search => &self.search_input,
             _ => &self.tabs.active_pane().input,
         }
     }
@@ -208,6 +255,7 @@ impl TuiState {
     fn active_input_mut(&mut self) -> &mut String {
         match self.mode {
             InputMode::Command => &mut self.command_input,
+            InputMode::Search => &mut self.search_input,
             _ => &mut self.tabs.active_pane_mut().input,
         }
     }
@@ -215,6 +263,7 @@ impl TuiState {
     fn active_cursor(&self) -> usize {
         match self.mode {
             InputMode::Command => self.command_cursor,
+            InputMode::Search => self.search_cursor,
             _ => self.tabs.active_pane().cursor_pos,
         }
     }
@@ -222,6 +271,7 @@ impl TuiState {
     fn active_cursor_mut(&mut self) -> &mut usize {
         match self.mode {
             InputMode::Command => &mut self.command_cursor,
+            InputMode::Search => &mut self.search_cursor,
             _ => &mut self.tabs.active_pane_mut().cursor_pos,
         }
     }
@@ -246,6 +296,47 @@ impl TuiState {
         }
     }
 
+    /// Switch to `preset` and rebuild the route table for it.
+    pub fn set_layout_preset(&mut self, preset: LayoutPreset) {
+        self.layout_config.preset = preset;
+        self.route = RouteTable::from_layout_config(&self.layout_config);
+    }
+
+    /// Switch to the configured preset for a newly spawned interaction's
+    /// form, remembering the current one for [`Self::leave_form_layout`].
+    ///
+    /// While a switch is active, nested spawns leave the layout alone.
+    pub fn enter_form_layout(
+        &mut self,
+        form: quorum_domain::interaction::InteractionForm,
+        id: quorum_domain::InteractionId,
+    ) {
+        if self.layout_restore.is_some() {
+            return;
+        }
+        let Some(preset) = self.layout_config.preset_for_form(form) else {
+            return;
+        };
+        if preset == self.layout_config.preset {
+            return;
+        }
+        self.layout_restore = Some((id, self.layout_config.preset.clone()));
+        self.set_layout_preset(preset);
+    }
+
+    /// Restore the layout saved by [`Self::enter_form_layout`] once the
+    /// interaction that triggered it completes.
+    pub fn leave_form_layout(&mut self, id: quorum_domain::InteractionId) {
+        if self
+            .layout_restore
+            .as_ref()
+            .is_some_and(|(owner, _)| *owner == id)
+            && let Some((_, previous)) = self.layout_restore.take()
+        {
+            self.set_layout_preset(previous);
+        }
+    }
+
     /// Finalize streaming text into a message
     pub fn finalize_stream(&mut self) {
         let pane = self.tabs.active_pane_mut();
@@ -276,12 +367,14 @@ impl TuiState {
     // -- Scrolling --
 
     pub fn scroll_up(&mut self) {
+        self.stop_search_follow();
         let conv = &mut self.tabs.active_pane_mut().conversation;
         conv.auto_scroll = false;
         conv.scroll_offset = conv.scroll_offset.saturating_add(1);
     }
 
     pub fn scroll_down(&mut self) {
+        self.stop_search_follow();
         let conv = &mut self.tabs.active_pane_mut().conversation;
         if conv.scroll_offset > 0 {
             conv.scroll_offset = conv.scroll_offset.saturating_sub(1);
@@ -291,17 +384,35 @@ impl TuiState {
     }
 
     pub fn scroll_to_top(&mut self) {
+        self.stop_search_follow();
         let conv = &mut self.tabs.active_pane_mut().conversation;
         conv.auto_scroll = false;
         conv.scroll_offset = usize::MAX; // Will be clamped during render
     }
 
     pub fn scroll_to_bottom(&mut self) {
+        self.stop_search_follow();
         let conv = &mut self.tabs.active_pane_mut().conversation;
         conv.scroll_offset = 0;
         conv.auto_scroll = true;
     }
 
+    /// Hand scrolling back to `scroll_offset` after jumping to a match,
+    /// starting from where the match was shown.
+    fn stop_search_follow(&mut self) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        if !std::mem::take(&mut search.follow) {
+            return;
+        }
+        if let Some(offset) = search.rendered_offset.take() {
+            let conv = &mut self.tabs.active_pane_mut().conversation;
+            conv.scroll_offset = offset;
+            conv.auto_scroll = false;
+        }
+    }
+
     // -- Help overlay --
 
     pub fn toggle_help(&mut self) {
@@ -364,6 +475,10 @@ impl TuiState {
             .entries()
             .iter()
             .filter(|e| e.surface.is_content_pane())
+            // The council only draws once a discussion has finished
+            .filter(|e| {
+                e.content != ContentSlot::Council || self.tabs.active_pane().council.is_some()
+            })
             .map(|e| e.content.clone())
             .collect()
     }
@@ -444,6 +559,7 @@ pub fn content_slot_label(slot: &ContentSlot) -> String {
         ContentSlot::HilPrompt => "HiL Prompt".to_string(),
         ContentSlot::Help => "Help".to_string(),
         ContentSlot::ToolLog => "Tool Log".to_string(),
+        ContentSlot::Council => "Council".to_string(),
         ContentSlot::ModelStream(name) => format!("Model: {}", name),
         ContentSlot::LuaSlot(name) => format!("Lua: {}", name),
     }
@@ -477,6 +593,16 @@ impl DisplayMessage {
             content: content.into(),
         }
     }
+
+    /// The conversation part of this message, for feeding back to a model.
+    /// System notices (mode changes, progress lines) are UI-only and map to `None`.
+    pub fn to_message(&self) -> Option<quorum_domain::Message> {
+        match self.role {
+            MessageRole::User => Some(quorum_domain::Message::user(self.content.clone())),
+            MessageRole::Assistant => Some(quorum_domain::Message::assistant(self.content.clone())),
+            MessageRole::System => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -516,6 +642,58 @@ pub struct ProgressState {
     /// Per-model streaming state, shared across Ensemble and Quorum Discussion.
     pub model_streams: std::collections::HashMap<String, ModelStreamState>,
     pub is_running: bool,
+    /// Run history bucketed by phase, oldest first (see [`PhaseGroup`]).
+    pub phase_groups: Vec<PhaseGroup>,
+}
+
+impl ProgressState {
+    /// Start a new group when the phase changes. Earlier groups collapse so
+    /// the panel stays focused on the phase in progress.
+    pub fn enter_phase(&mut self, phase: AgentPhase, name: impl Into<String>) {
+        if self.phase_groups.last().is_some_and(|g| g.phase == phase) {
+            return;
+        }
+        for group in &mut self.phase_groups {
+            group.collapsed = true;
+        }
+        self.phase_groups.push(PhaseGroup {
+            phase,
+            name: name.into(),
+            events: Vec::new(),
+            collapsed: false,
+        });
+    }
+
+    /// Append an event line to the current phase's group. Events before the
+    /// first phase change are dropped — there is no group to put them in.
+    pub fn record_event(&mut self, event: impl Into<String>) {
+        if let Some(group) = self.phase_groups.last_mut() {
+            group.events.push(event.into());
+        }
+    }
+
+    /// Expand every earlier group if any is collapsed, otherwise collapse
+    /// them all. The current (last) group always stays expanded.
+    pub fn toggle_folds(&mut self) {
+        let Some((_, earlier)) = self.phase_groups.split_last_mut() else {
+            return;
+        };
+        let collapse = earlier.iter().all(|g| !g.collapsed);
+        for group in earlier {
+            group.collapsed = collapse;
+        }
+    }
+}
+
+/// Events of one contiguous run of a phase, shown under a foldable header
+/// such as `▾ Planning (12 events)`.
+#[derive(Debug, Clone)]
+pub struct PhaseGroup {
+    pub phase: AgentPhase,
+    pub name: String,
+    pub events: Vec<String>,
+    /// Collapsed groups render as a single summary line.
+    pub collapsed: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -558,6 +736,8 @@ pub struct ToolExecutionDisplay {
     pub state: ToolExecutionDisplayStatus,
     pub duration_ms: Option<u64>,
     pub args_preview: Option<String>,
+    /// Unified diff of the file change, for tools that report one (write_file)
+    pub diff: Option<String>,
 }
 
 /// Status of a tool execution for display
@@ -579,6 +759,8 @@ pub struct EnsembleProgress {
     pub voting_started: bool,
     pub plan_count: Option<usize>,
     pub selected: Option<(String, f64)>,
+    /// Moderator and number of plans, when the top plans were merged
+    pub merged: Option<(String, usize)>,
 }
 
 /// Per-model streaming state during Ensemble planning.
@@ -627,6 +809,12 @@ pub struct HilPrompt {
     pub objective: String,
     pub tasks: Vec<String>,
     pub message: String,
+    /// Editable plan text opened by `e` (plan intervention only). After a
+    /// failed parse this holds the user's draft so the next edit resumes it.
+    pub plan_draft: Option<String>,
+    /// When the requester stops waiting; the modal counts down to it and
+    /// closes once it passes.
+    pub deadline: Option<std::time::Instant>,
 }
 
 #[cfg(test)]
@@ -930,4 +1118,50 @@ mod tests {
         state.cursor_right(); // Already at end
         assert_eq!(state.tabs.active_pane().cursor_pos, 3);
     }
+
+    #[test]
+    fn test_progress_events_bucket_by_phase() {
+        let mut progress = ProgressState::default();
+        progress.record_event("before any phase");
+        progress.enter_phase(AgentPhase::ContextGathering, "Gathering Context");
+        progress.record_event("✓ glob_search  **/*.rs");
+        progress.enter_phase(AgentPhase::Planning, "Planning");
+        progress.record_event("Plan from gpt");
+        progress.enter_phase(AgentPhase::Planning, "Planning");
+        progress.record_event("Plan from claude");
+        progress.enter_phase(AgentPhase::PlanReview, "Plan Review");
+        progress.record_event("Plan Review: REJECTED");
+        progress.enter_phase(AgentPhase::Planning, "Planning");
+        progress.record_event("Plan revision #1");
+
+        let buckets: Vec<(AgentPhase, usize, bool)> = progress
+            .phase_groups
+            .iter()
+            .map(|g| (g.phase.clone(), g.events.len(), g.collapsed))
+            .collect();
+        assert_eq!(
+            buckets,
+            vec![
+                (AgentPhase::ContextGathering, 1, true),
+                (AgentPhase::Planning, 2, true),
+                (AgentPhase::PlanReview, 1, true),
+                (AgentPhase::Planning, 1, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toggle_folds_keeps_current_group_open() {
+        let mut progress = ProgressState::default();
+        progress.toggle_folds(); // no groups: no-op
+        progress.enter_phase(AgentPhase::Planning, "Planning");
+        progress.enter_phase(AgentPhase::Executing, "Executing");
+
+        progress.toggle_folds();
+        assert!(progress.phase_groups.iter().all(|g| !g.collapsed));
+
+        progress.toggle_folds();
+        assert!(progress.phase_groups[0].collapsed);
+        assert!(!progress.phase_groups[1].collapsed);
+    }
 }
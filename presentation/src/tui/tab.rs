@@ -7,7 +7,7 @@
 //!
 //! Phase 1: each Tab contains exactly one Pane (no splits).
 
-use super::content::{ConversationContent, ProgressContent};
+use super::content::{ConversationContent, CouncilContent, ProgressContent};
 use super::state::DisplayMessage;
 use quorum_domain::core::string::truncate;
 use quorum_domain::interaction::{InteractionForm, InteractionId};
@@ -56,6 +56,9 @@ pub struct Pane {
 
     // -- Progress (per-pane, ready for future parallel execution) --
     pub progress: ProgressContent,
+
+    // -- Council (last multi-model discussion result) --
+    pub council: Option<CouncilContent>,
 }
 
 impl Pane {
@@ -68,6 +71,7 @@ impl Pane {
             input: String::new(),
             cursor_pos: 0,
             progress: ProgressContent::default(),
+            council: None,
         }
     }
 
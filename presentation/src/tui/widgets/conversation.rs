@@ -1,6 +1,7 @@
 //! Conversation widget — message history + streaming text
 
 use crate::tui::content::{ContentRenderer, ContentSlot};
+use crate::tui::search::{ConversationSearch, SearchMatch};
 use crate::tui::state::{MessageRole, TuiState};
 use ratatui::{
     buffer::Buffer,
@@ -76,11 +77,16 @@ impl<'a> ConversationWidget<'a> {
         Self { state }
     }
 
-    fn format_messages(&self) -> Text<'_> {
+    /// Build the pane text, plus the line index of the current search match
+    /// when the view should follow it.
+    fn format_messages(&self) -> (Text<'_>, Option<usize>) {
         let mut lines: Vec<Line> = Vec::new();
+        let mut target_line = None;
 
         let pane = self.state.tabs.active_pane();
-        for msg in &pane.conversation.messages {
+        let search = self.state.search.as_ref();
+        let current = search.and_then(|s| s.current_match());
+        for (msg_idx, msg) in pane.conversation.messages.iter().enumerate() {
             let role_style = Style::default()
                 .fg(msg.role.color())
                 .add_modifier(Modifier::BOLD);
@@ -90,8 +96,23 @@ impl<'a> ConversationWidget<'a> {
                 role_style,
             )));
 
-            for content_line in msg.content.lines() {
-                lines.push(Line::from(format!("  {}", content_line)));
+            for (line_idx, content_line) in msg.content.lines().enumerate() {
+                let Some(search) = search else {
+                    lines.push(Line::from(format!("  {}", content_line)));
+                    continue;
+                };
+                if search.follow
+                    && current.is_some_and(|m| m.message == msg_idx && m.line == line_idx)
+                {
+                    target_line = Some(lines.len());
+                }
+                lines.push(highlight_line(
+                    content_line,
+                    search,
+                    current,
+                    msg_idx,
+                    line_idx,
+                ));
             }
             lines.push(Line::from(""));
         }
@@ -115,16 +136,61 @@ impl<'a> ConversationWidget<'a> {
 
         super::apply_visual_highlight(&mut lines, self.state, &ContentSlot::Conversation);
 
-        Text::from(lines)
+        (Text::from(lines), target_line)
+    }
+}
+
+/// Render one content line with search matches highlighted; the current
+/// match stands out from the others.
+fn highlight_line(
+    content_line: &str,
+    search: &ConversationSearch,
+    current: Option<&SearchMatch>,
+    msg_idx: usize,
+    line_idx: usize,
+) -> Line<'static> {
+    let match_style = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let current_style = match_style.bg(Color::LightRed).add_modifier(Modifier::BOLD);
+
+    let mut spans = vec![Span::raw("  ")];
+    let mut pos = 0;
+    for m in search
+        .matches
+        .iter()
+        .filter(|m| m.message == msg_idx && m.line == line_idx)
+    {
+        let Some(matched) = content_line.get(m.range.clone()) else {
+            continue;
+        };
+        if m.range.start < pos {
+            continue;
+        }
+        spans.push(Span::raw(content_line[pos..m.range.start].to_string()));
+        let style = if current == Some(m) {
+            current_style
+        } else {
+            match_style
+        };
+        spans.push(Span::styled(matched.to_string(), style));
+        pos = m.range.end;
     }
+    spans.push(Span::raw(content_line[pos..].to_string()));
+    Line::from(spans)
 }
 
 impl<'a> Widget for ConversationWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let text = self.format_messages();
+        let (text, target_line) = self.format_messages();
         let visible_height = area.height.saturating_sub(2); // borders
         let content_width = area.width.saturating_sub(2); // borders
 
+        // Wrapped row of the search match to follow, if any.
+        let target_row = target_line.map(|i| {
+            Paragraph::new(Text::from(text.lines[..i].to_vec()))
+                .wrap(Wrap { trim: false })
+                .line_count(content_width) as u16
+        });
+
         // Use Paragraph's own line_count() which uses WordWrapper internally,
         // matching the exact wrapping algorithm used during rendering.
         // Built without block so line_count returns pure content lines.
@@ -135,8 +201,22 @@ impl<'a> Widget for ConversationWidget<'a> {
         let pane = self.state.tabs.active_pane();
         let scroll = if total_lines > visible_height {
             let max_scroll = total_lines - visible_height;
-            let offset = (pane.conversation.scroll_offset as u16).min(max_scroll);
-            max_scroll - offset
+            match target_row {
+                // Show the match a third of the way down the pane.
+                Some(row) => {
+                    let top = row.saturating_sub(visible_height / 3).min(max_scroll);
+                    if let Some(search) = &self.state.search {
+                        search
+                            .rendered_offset
+                            .set(Some((max_scroll - top) as usize));
+                    }
+                    top
+                }
+                None => {
+                    let offset = (pane.conversation.scroll_offset as u16).min(max_scroll);
+                    max_scroll - offset
+                }
+            }
         } else {
             0
         };
@@ -0,0 +1,257 @@
+//! Council columns widget — the last `/discuss` result, one column per model.
+//!
+//! Each participating model's Phase 1 response gets its own column, with the
+//! moderator synthesis spanning below. When the area is too narrow for one
+//! column per model, the responses are stacked vertically instead.
+//!
+//! Routed to the ToolPane in the Wide layout, where it draws over the tool
+//! log once the active pane has a discussion result.
+//!
+//! Under the synthesis, a one-line attribution summary
+//! ([`CouncilContent::attribution`], computed when the result is stored)
+//! counts how many synthesis sentences came from each
+//! model, how many are new, and how many response points were dropped.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph, Widget, Wrap},
+};
+
+use super::super::content::{ContentRenderer, ContentSlot, CouncilContent};
+use super::super::state::TuiState;
+use quorum_domain::ModelResponse;
+
+/// Narrowest column (borders included) a model response is laid out in.
+const MIN_COLUMN_WIDTH: u16 = 24;
+
+/// Share of the council area given to the model responses; the synthesis
+/// takes the rest.
+const RESPONSES_PERCENT: u16 = 65;
+
+/// How model responses are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CouncilArrangement {
+    /// Model `i` in column `i`, this many columns.
+    Columns(usize),
+    /// More models than fit side by side: one after another.
+    Stacked,
+}
+
+/// Assign `model_count` responses to at most `max_columns` columns.
+pub fn assign_columns(model_count: usize, max_columns: usize) -> CouncilArrangement {
+    if model_count == 0 || model_count > max_columns {
+        CouncilArrangement::Stacked
+    } else {
+        CouncilArrangement::Columns(model_count)
+    }
+}
+
+/// ContentRenderer for the council view.
+pub struct CouncilColumnsRenderer;
+
+impl ContentRenderer for CouncilColumnsRenderer {
+    fn slot(&self) -> ContentSlot {
+        ContentSlot::Council
+    }
+
+    fn render_content(&self, state: &TuiState, area: Rect, buf: &mut Buffer) {
+        if let Some(council) = &state.tabs.active_pane().council {
+            CouncilColumnsWidget::new(state, council).render(area, buf);
+        }
+    }
+
+    fn get_text_content(&self, state: &TuiState) -> String {
+        let Some(council) = &state.tabs.active_pane().council else {
+            return String::new();
+        };
+        let mut sections: Vec<String> = council
+            .responses
+            .iter()
+            .map(|r| format!("── {} ──\n{}", r.model, response_text(r)))
+            .collect();
+        let mut synthesis = format!(
+            "── Synthesis ({}) ──\n{}",
+            council.moderator, council.synthesis
+        );
+        if let Some(summary) = &council.attribution {
+            synthesis.push_str(&format!("\n\n{}", summary));
+        }
+        sections.push(synthesis);
+        sections.join("\n\n")
+    }
+}
+
+pub struct CouncilColumnsWidget<'a> {
+    state: &'a TuiState,
+    council: &'a CouncilContent,
+}
+
+impl<'a> CouncilColumnsWidget<'a> {
+    pub fn new(state: &'a TuiState, council: &'a CouncilContent) -> Self {
+        Self { state, council }
+    }
+
+    fn render_columns(&self, area: Rect, buf: &mut Buffer, count: usize) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, count as u32); count])
+            .split(area);
+        for (response, column) in self.council.responses.iter().zip(columns.iter()) {
+            Paragraph::new(response_text(response))
+                .block(super::bordered_block(self.state).title(response_title(response)))
+                .wrap(Wrap { trim: false })
+                .render(*column, buf);
+        }
+    }
+
+    fn render_stacked(&self, area: Rect, buf: &mut Buffer) {
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, response) in self.council.responses.iter().enumerate() {
+            if i > 0 {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(response_title(response)));
+            lines.extend(
+                response_text(response)
+                    .lines()
+                    .map(|l| Line::from(l.to_string())),
+            );
+        }
+        Paragraph::new(lines)
+            .block(super::bordered_block(self.state).title(" Responses "))
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+}
+
+impl Widget for CouncilColumnsWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let block = super::focus_block(self.state, &ContentSlot::Council, " Council ");
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let [responses, synthesis] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(RESPONSES_PERCENT),
+                Constraint::Fill(1),
+            ])
+            .areas(inner);
+
+        let max_columns = (responses.width / MIN_COLUMN_WIDTH) as usize;
+        match assign_columns(self.council.responses.len(), max_columns) {
+            CouncilArrangement::Columns(count) => self.render_columns(responses, buf, count),
+            CouncilArrangement::Stacked => self.render_stacked(responses, buf),
+        }
+
+        let mut lines: Vec<Line> = self
+            .council
+            .synthesis
+            .lines()
+            .map(|l| Line::from(l.to_string()))
+            .collect();
+        if let Some(summary) = &self.council.attribution {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                summary.as_str(),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        Paragraph::new(lines)
+            .block(
+                super::bordered_block(self.state)
+                    .title(format!(" Synthesis ({}) ", self.council.moderator)),
+            )
+            .wrap(Wrap { trim: false })
+            .render(synthesis, buf);
+    }
+}
+
+fn response_title(response: &ModelResponse) -> Span<'static> {
+    let style = if response.success {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    };
+    Span::styled(format!(" {} ", response.model), style)
+}
+
+fn response_text(response: &ModelResponse) -> String {
+    if response.success {
+        response.content.clone()
+    } else {
+        format!(
+            "Error: {}",
+            response.error.as_deref().unwrap_or("no response")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_columns() {
+        // Wide ToolPane room for three columns
+        assert_eq!(assign_columns(2, 3), CouncilArrangement::Columns(2));
+        assert_eq!(assign_columns(3, 3), CouncilArrangement::Columns(3));
+        assert_eq!(assign_columns(5, 3), CouncilArrangement::Stacked);
+        // Too narrow for even one column, or nothing to show
+        assert_eq!(assign_columns(2, 0), CouncilArrangement::Stacked);
+        assert_eq!(assign_columns(0, 3), CouncilArrangement::Stacked);
+    }
+
+    fn council(models: &[&str]) -> CouncilContent {
+        CouncilContent::new(
+            models
+                .iter()
+                .map(|m| ModelResponse::success(*m, format!("answer from {}", m)))
+                .collect(),
+            "judge",
+            "Use option B",
+        )
+    }
+
+    fn render(council: &CouncilContent, width: u16) -> Buffer {
+        let area = Rect::new(0, 0, width, 20);
+        let mut buf = Buffer::empty(area);
+        CouncilColumnsWidget::new(&TuiState::default(), council).render(area, &mut buf);
+        buf
+    }
+
+    fn row_text(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_columns_share_a_row() {
+        let buf = render(&council(&["gpt", "claude"]), 80);
+        // Column titles sit on the same border row, side by side
+        let titles = row_text(&buf, 1);
+        assert!(
+            titles.contains("gpt") && titles.contains("claude"),
+            "{}",
+            titles
+        );
+        let all: String = (0..20).map(|y| row_text(&buf, y)).collect();
+        assert!(all.contains("Synthesis (judge)"));
+        assert!(all.contains("Use option B"));
+        assert!(all.contains("Sources: gpt 0 · claude 0 · new 1"), "{}", all);
+    }
+
+    #[test]
+    fn test_too_many_models_stack() {
+        let buf = render(&council(&["a1", "b2", "c3", "d4", "e5"]), 60);
+        let titles = row_text(&buf, 1);
+        assert!(titles.contains("Responses"), "{}", titles);
+        let all: String = (0..20).map(|y| row_text(&buf, y)).collect();
+        assert!(all.contains("answer from a1") && all.contains("answer from b2"));
+    }
+}
@@ -6,7 +6,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget},
+    widgets::{Paragraph, Widget},
 };
 
 pub struct HeaderWidget<'a> {
@@ -51,8 +51,7 @@ impl<'a> Widget for HeaderWidget<'a> {
             Span::styled(phase_text, Style::default().fg(Color::Yellow)),
         ]);
 
-        let block = Block::default()
-            .borders(Borders::ALL)
+        let block = super::bordered_block(self.state)
             .title(" Copilot Quorum ")
             .style(Style::default().fg(Color::White));
 
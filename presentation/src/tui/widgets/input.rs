@@ -11,7 +11,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget},
+    widgets::{Paragraph, Widget},
 };
 
 pub struct InputWidget<'a> {
@@ -49,6 +49,13 @@ impl<'a> Widget for InputWidget<'a> {
                 Color::Yellow,
                 true,
             ),
+            InputMode::Search => (
+                if self.state.search_regex { "re/" } else { "/" },
+                &self.state.search_input,
+                self.state.search_cursor,
+                Color::Cyan,
+                true,
+            ),
             InputMode::Normal => (
                 mode_prompt.as_str(),
                 &pane.input,
@@ -77,8 +84,7 @@ impl<'a> Widget for InputWidget<'a> {
             Style::default().fg(Color::DarkGray)
         };
 
-        let block = Block::default()
-            .borders(Borders::ALL)
+        let block = super::bordered_block(self.state)
             .title(wildmenu_title(self.state))
             .style(border_style);
 
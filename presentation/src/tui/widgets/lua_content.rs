@@ -7,7 +7,7 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Paragraph, Wrap},
 };
 
 use super::super::content::{ContentRenderer, ContentSlot};
@@ -36,9 +36,7 @@ impl ContentRenderer for LuaContentRenderer {
             .map(|s| s.as_str())
             .unwrap_or("");
 
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(format!(" {} ", self.slot_name));
+        let block = super::bordered_block(state).title(format!(" {} ", self.slot_name));
 
         Paragraph::new(text)
             .block(block)
@@ -53,9 +51,8 @@ impl LuaContentRenderer {
     /// Render as a standalone widget (for testing).
     #[cfg(test)]
     fn render_standalone(&self, text: &str, area: Rect, buf: &mut Buffer) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(format!(" {} ", self.slot_name));
+        let block =
+            super::bordered_block(&TuiState::default()).title(format!(" {} ", self.slot_name));
 
         Paragraph::new(text)
             .block(block)
@@ -7,6 +7,7 @@
 //! └── StatusBar (1) ─────────────────────────────────┘
 
 pub mod conversation;
+pub mod council_columns;
 pub mod header;
 pub mod input;
 pub mod lua_content;
@@ -16,16 +17,58 @@ pub mod status_bar;
 pub mod tab_bar;
 pub mod tool_log;
 
+use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::border;
 use ratatui::text::Span;
 use ratatui::widgets::{Block, Borders};
 
 use super::content::ContentSlot;
-use super::layout::LayoutPreset;
+use super::layout::{LayoutPreset, TuiLayoutConfig};
 use super::mode::InputMode;
 use super::state::TuiState;
 
+/// Plain-ASCII border set used when `TuiState::ascii_mode` is on.
+const ASCII_BORDER: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+/// Build a fully bordered `Block`, honoring ASCII mode.
+///
+/// Every bordered widget should start from this helper instead of
+/// `Block::default().borders(Borders::ALL)` so the border glyphs follow
+/// `TuiState::ascii_mode`.
+pub(super) fn bordered_block<'a>(state: &TuiState) -> Block<'a> {
+    let block = Block::default().borders(Borders::ALL);
+    if state.ascii_mode {
+        block.border_set(ASCII_BORDER)
+    } else {
+        block
+    }
+}
+
+/// Reset foreground/background colors of every cell in `area`.
+///
+/// Applied once per frame in ASCII mode so no widget has to special-case
+/// its own color choices. Modifiers (bold, reversed) are kept — they still
+/// convey focus and Visual selection without color.
+pub(super) fn strip_colors(buf: &mut Buffer, area: Rect) {
+    let area = area.intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buf[(x, y)].set_fg(Color::Reset).set_bg(Color::Reset);
+        }
+    }
+}
+
 /// Build a bordered `Block` for a content pane with focus-aware styling.
 ///
 /// All pane widgets should route through this helper so pane focus is
@@ -38,8 +81,7 @@ pub(super) fn focus_block<'a>(state: &TuiState, slot: &ContentSlot, title: &'a s
     let in_visual = state.mode == InputMode::Visual;
     let (border_style, title_style) = focus_styles(is_focused, in_visual);
 
-    Block::default()
-        .borders(Borders::ALL)
+    bordered_block(state)
         .border_style(border_style)
         .title(Span::styled(title, title_style))
 }
@@ -149,70 +191,64 @@ impl MainLayout {
             input_lines,
             max_input_height,
             show_tab_bar,
-            LayoutPreset::Default,
-            0,
+            &TuiLayoutConfig {
+                flex_threshold: 0,
+                ..Default::default()
+            },
             2,
         )
     }
 
-    /// Compute layout with a preset, flex threshold, and dynamic pane count.
+    /// Compute layout for a layout config's preset and a dynamic pane count.
     ///
-    /// If `area.width < flex_threshold`, the preset falls back to Minimal.
-    /// `pane_count` determines how many content panes to split the main area into.
-    /// For custom presets, use [`compute_with_splits`] instead.
+    /// If `area.width < flex_threshold`, a built-in preset falls back to Minimal.
+    /// A registered `LayoutPreset::Custom` uses its own splits and direction
+    /// (ignoring `pane_count`); an unregistered one falls back to Default.
     pub fn compute_with_layout(
         area: Rect,
         input_lines: u16,
         max_input_height: u16,
         show_tab_bar: bool,
-        preset: LayoutPreset,
-        flex_threshold: u16,
+        layout: &TuiLayoutConfig,
         pane_count: usize,
     ) -> Self {
-        // Responsive fallback: narrow terminal → Minimal
-        let effective_preset = if flex_threshold > 0 && area.width < flex_threshold {
-            LayoutPreset::Minimal
-        } else {
-            preset
-        };
-
-        let (header, tab_bar, main_area, input, status_bar) =
-            Self::compute_outer_regions(area, input_lines, max_input_height, show_tab_bar);
+        let (splits, direction) = Self::resolve_splits(area, layout, pane_count);
+        Self::compute_with_splits(
+            area,
+            input_lines,
+            max_input_height,
+            show_tab_bar,
+            &splits,
+            direction,
+        )
+    }
 
-        let pane_count = if effective_preset == LayoutPreset::Minimal {
+    /// Splits and direction the main area is divided into for `layout` at `area`.
+    pub fn resolve_splits(
+        area: Rect,
+        layout: &TuiLayoutConfig,
+        pane_count: usize,
+    ) -> (Vec<u16>, Direction) {
+        if let Some(custom) = layout.custom_layout() {
+            return (custom.splits(), custom.direction);
+        }
+        let preset = match &layout.preset {
+            LayoutPreset::Custom(_) => LayoutPreset::Default,
+            _ if layout.flex_threshold > 0 && area.width < layout.flex_threshold => {
+                // Responsive fallback: narrow terminal → Minimal
+                LayoutPreset::Minimal
+            }
+            preset => preset.clone(),
+        };
+        let pane_count = if preset == LayoutPreset::Minimal {
             1
         } else {
             pane_count.max(1)
         };
-
-        let splits = effective_preset.default_splits(pane_count);
-        let direction = effective_preset.split_direction();
-
-        let constraints: Vec<Constraint> = splits
-            .iter()
-            .map(|&pct| Constraint::Percentage(pct))
-            .collect();
-
-        let panes = if constraints.is_empty() {
-            vec![main_area]
-        } else {
-            Layout::default()
-                .direction(direction)
-                .constraints(constraints)
-                .split(main_area)
-                .to_vec()
-        };
-
-        Self {
-            header,
-            tab_bar,
-            panes,
-            input,
-            status_bar,
-        }
+        (preset.default_splits(pane_count), preset.split_direction())
     }
 
-    /// Compute layout with explicit splits and direction (for custom presets).
+    /// Compute layout with explicit splits and direction.
     pub fn compute_with_splits(
         area: Rect,
         input_lines: u16,
@@ -343,6 +379,63 @@ mod tests {
         }
     }
 
+    fn render_header(state: &TuiState) -> Buffer {
+        use ratatui::widgets::Widget;
+        let area = Rect::new(0, 0, 40, 3);
+        let mut buf = Buffer::empty(area);
+        header::HeaderWidget::new(state).render(area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn bordered_block_uses_box_drawing_by_default() {
+        let buf = render_header(&TuiState::default());
+        assert_eq!(buf[(0, 0)].symbol(), "┌");
+        assert_eq!(buf[(1, 2)].symbol(), "─");
+    }
+
+    #[test]
+    fn ascii_mode_renders_ascii_borders() {
+        let state = TuiState {
+            ascii_mode: true,
+            ..Default::default()
+        };
+        let buf = render_header(&state);
+
+        assert_eq!(buf[(0, 0)].symbol(), "+");
+        assert_eq!(buf[(39, 2)].symbol(), "+");
+        assert_eq!(buf[(0, 1)].symbol(), "|");
+        assert_eq!(buf[(1, 2)].symbol(), "-");
+        // No box-drawing glyphs anywhere in the frame
+        assert!(buf.content().iter().all(|c| {
+            !c.symbol()
+                .chars()
+                .any(|ch| ('\u{2500}'..='\u{257F}').contains(&ch))
+        }));
+    }
+
+    #[test]
+    fn strip_colors_resets_fg_and_bg_but_keeps_modifiers() {
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(
+            0,
+            0,
+            "ab",
+            Style::default()
+                .fg(Color::Cyan)
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        strip_colors(&mut buf, area);
+
+        let cell = &buf[(0, 0)];
+        assert_eq!(cell.fg, Color::Reset);
+        assert_eq!(cell.bg, Color::Reset);
+        assert!(cell.modifier.contains(Modifier::BOLD));
+    }
+
     #[test]
     fn visual_highlight_noop_when_not_visual() {
         let mut lines = make_lines(3);
@@ -398,4 +491,37 @@ mod tests {
         // Last line gets the cursor marker
         assert_eq!(lines[2].spans[0].content.as_ref(), "▸");
     }
+
+    #[test]
+    fn compute_with_layout_honors_custom_preset() {
+        use quorum_application::CustomPresetConfig;
+
+        let mut config = TuiLayoutConfig::default();
+        config.register_custom_preset(
+            "review",
+            &CustomPresetConfig {
+                splits: vec![25, 75],
+                direction: "vertical".to_string(),
+                slots: vec!["progress".to_string(), "conversation".to_string()],
+            },
+        );
+        config.preset = LayoutPreset::Custom("review".to_string());
+
+        // Narrower than flex_threshold: custom presets do not fall back to Minimal
+        let area = Rect::new(0, 0, 100, 48);
+        let layout = MainLayout::compute_with_layout(area, 1, 10, false, &config, 3);
+        // header 3 + input 3 + status 1 leave 41 rows for the panes
+        assert_eq!(
+            layout.panes,
+            vec![Rect::new(0, 3, 100, 10), Rect::new(0, 13, 100, 31)]
+        );
+
+        // Unregistered custom preset falls back to the Default 70/30 split
+        config.preset = LayoutPreset::Custom("missing".to_string());
+        let layout =
+            MainLayout::compute_with_layout(Rect::new(0, 0, 200, 48), 1, 10, false, &config, 2);
+        assert_eq!(layout.panes.len(), 2);
+        assert_eq!(layout.panes[0].width, 140);
+        assert_eq!(layout.panes[1].width, 60);
+    }
 }
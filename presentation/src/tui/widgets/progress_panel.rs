@@ -2,7 +2,7 @@
 
 use crate::tui::content::{ContentRenderer, ContentSlot};
 use crate::tui::state::{
-    ProgressState, ToolExecutionDisplay, ToolExecutionDisplayStatus, TuiState,
+    PhaseGroup, ProgressState, ToolExecutionDisplay, ToolExecutionDisplayStatus, TuiState,
 };
 use quorum_domain::AgentPhase;
 use ratatui::{
@@ -85,7 +85,12 @@ pub(super) fn format_progress_plain(progress: &ProgressState) -> String {
     }
 
     if let Some(ref ep) = progress.ensemble_progress {
-        if let Some((ref model, score)) = ep.selected {
+        if let Some((ref moderator, sources)) = ep.merged {
+            lines.push(format!(
+                "Ensemble: Merged {} plans via {}",
+                sources, moderator
+            ));
+        } else if let Some((ref model, score)) = ep.selected {
             lines.push(format!("Ensemble: Selected {} ({:.1}/10)", model, score));
         } else if ep.voting_started {
             let plan_count = ep.plan_count.unwrap_or(0);
@@ -120,6 +125,14 @@ pub(super) fn format_progress_plain(progress: &ProgressState) -> String {
         ));
     }
 
+    // Yank copies the full history, folded or not
+    for group in &progress.phase_groups {
+        lines.push(group_header(group).0);
+        for event in &group.events {
+            lines.push(format!("    {}", event));
+        }
+    }
+
     lines.join("\n")
 }
 
@@ -294,7 +307,17 @@ impl<'a> Widget for ProgressPanelWidget<'a> {
 
         // Ensemble progress
         if let Some(ref ep) = progress.ensemble_progress {
-            if let Some((ref model, score)) = ep.selected {
+            if let Some((ref moderator, sources)) = ep.merged {
+                lines.push(Line::from(vec![
+                    Span::styled("Ensemble: ", Style::default().fg(Color::White)),
+                    Span::styled(
+                        format!("Merged {} plans via {}", sources, moderator),
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ]));
+            } else if let Some((ref model, score)) = ep.selected {
                 lines.push(Line::from(vec![
                     Span::styled("Ensemble: ", Style::default().fg(Color::White)),
                     Span::styled(
@@ -369,6 +392,14 @@ impl<'a> Widget for ProgressPanelWidget<'a> {
             )));
         }
 
+        // Phase history (foldable with `za`)
+        if !progress.phase_groups.is_empty() {
+            lines.push(Line::from(""));
+            for group in &progress.phase_groups {
+                render_phase_group(&mut lines, group);
+            }
+        }
+
         super::apply_visual_highlight(&mut lines, self.state, &ContentSlot::Progress);
         let block = super::focus_block(self.state, &ContentSlot::Progress, " Progress ");
 
@@ -376,6 +407,56 @@ impl<'a> Widget for ProgressPanelWidget<'a> {
     }
 }
 
+/// Header text of a phase group, e.g. `▾ 📝 Planning (12 events)`, and
+/// the one-line summary shown in its place while collapsed.
+fn group_header(group: &PhaseGroup) -> (String, Option<String>) {
+    let marker = if group.collapsed { "▸" } else { "▾" };
+    let count = match group.events.len() {
+        1 => "1 event".to_string(),
+        n => format!("{} events", n),
+    };
+    let header = format!(
+        "{} {} {} ({})",
+        marker,
+        phase_emoji(&group.phase),
+        group.name,
+        count
+    );
+    let summary = group
+        .events
+        .last()
+        .filter(|_| group.collapsed)
+        .map(|last| truncate_str(last, 30));
+    (header, summary)
+}
+
+/// Render a phase group: the fold header, then its events unless collapsed.
+fn render_phase_group<'a>(lines: &mut Vec<Line<'a>>, group: &PhaseGroup) {
+    let (header, summary) = group_header(group);
+    let mut spans = vec![Span::styled(
+        header,
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(summary) = summary {
+        spans.push(Span::styled(
+            format!(" — {}", summary),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    lines.push(Line::from(spans));
+
+    if !group.collapsed {
+        for event in &group.events {
+            lines.push(Line::from(Span::styled(
+                format!("    {}", event),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+}
+
 /// Render a single tool execution line in the progress panel.
 ///
 /// Format: `    ▸ read_file  src/main.rs`
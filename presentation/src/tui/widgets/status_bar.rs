@@ -61,7 +61,8 @@ impl<'a> Widget for StatusBarWidget<'a> {
         } else {
             match self.state.mode {
                 crate::tui::mode::InputMode::Normal => {
-                    "i:insert  yy/ya/Y:yank  v:visual  Ctrl+w:focus  gt:tab  ?:help".into()
+                    "i:insert  /:search  yy/ya/Y:yank  v:visual  Ctrl+w:focus  gt:tab  ?:help"
+                        .into()
                 }
                 crate::tui::mode::InputMode::Insert => "Enter:send  Esc:normal  Ctrl+C:quit".into(),
                 crate::tui::mode::InputMode::Command => {
@@ -70,6 +71,9 @@ impl<'a> Widget for StatusBarWidget<'a> {
                 crate::tui::mode::InputMode::Visual => {
                     "hjkl/wb:extend  y/Enter:yank  Esc/v:cancel".into()
                 }
+                crate::tui::mode::InputMode::Search => {
+                    "Enter:search  Ctrl+r:regex  Esc:cancel".into()
+                }
             }
         };
 
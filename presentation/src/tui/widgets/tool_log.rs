@@ -43,7 +43,7 @@ impl ContentRenderer for ToolLogRenderer {
                     icon, summary.index, summary.description, dur
                 ));
                 for exec in &summary.tool_executions {
-                    lines.push(format_tool_execution_plain(exec));
+                    push_tool_execution_plain(&mut lines, exec);
                 }
             }
 
@@ -53,7 +53,7 @@ impl ContentRenderer for ToolLogRenderer {
                 }
                 lines.push(format!("▸ Task {}: {}", tp.current_index, tp.description));
                 for exec in &tp.active_tool_executions {
-                    lines.push(format_tool_execution_plain(exec));
+                    push_tool_execution_plain(&mut lines, exec);
                 }
             }
         }
@@ -65,6 +65,57 @@ impl ContentRenderer for ToolLogRenderer {
     }
 }
 
+/// Maximum diff lines shown per tool execution before the rest is elided.
+const MAX_DIFF_LINES: usize = 40;
+
+/// Diff lines to display, plus how many were elided.
+fn visible_diff_lines(diff: &str) -> (impl Iterator<Item = &str>, usize) {
+    let total = diff.lines().count();
+    (
+        diff.lines().take(MAX_DIFF_LINES),
+        total.saturating_sub(MAX_DIFF_LINES),
+    )
+}
+
+fn push_tool_execution_plain(lines: &mut Vec<String>, exec: &ToolExecutionDisplay) {
+    lines.push(format_tool_execution_plain(exec));
+    if let Some(diff) = &exec.diff {
+        let (visible, elided) = visible_diff_lines(diff);
+        lines.extend(visible.map(|line| format!("      {}", line)));
+        if elided > 0 {
+            lines.push(format!("      … {} more diff lines", elided));
+        }
+    }
+}
+
+/// Render a unified diff below its tool execution line.
+fn render_diff_lines<'a>(lines: &mut Vec<Line<'a>>, diff: &str) {
+    let (visible, elided) = visible_diff_lines(diff);
+    for line in visible {
+        let color = if line.starts_with("+++") || line.starts_with("---") {
+            Color::DarkGray
+        } else if line.starts_with('+') {
+            Color::Green
+        } else if line.starts_with('-') {
+            Color::Red
+        } else if line.starts_with("@@") {
+            Color::Cyan
+        } else {
+            Color::Gray
+        };
+        lines.push(Line::from(Span::styled(
+            format!("      {}", line),
+            Style::default().fg(color),
+        )));
+    }
+    if elided > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("      … {} more diff lines", elided),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+}
+
 fn format_tool_execution_plain(exec: &ToolExecutionDisplay) -> String {
     let (icon, suffix) = match &exec.state {
         ToolExecutionDisplayStatus::Pending => ("…", String::new()),
@@ -125,6 +176,9 @@ impl<'a> Widget for ToolLogWidget<'a> {
                 // All tool executions for this task (no limit)
                 for exec in &summary.tool_executions {
                     render_tool_execution_line(&mut lines, exec);
+                    if let Some(diff) = &exec.diff {
+                        render_diff_lines(&mut lines, diff);
+                    }
                 }
             }
 
@@ -145,6 +199,9 @@ impl<'a> Widget for ToolLogWidget<'a> {
                 )));
                 for exec in &tp.active_tool_executions {
                     render_tool_execution_line(&mut lines, exec);
+                    if let Some(diff) = &exec.diff {
+                        render_diff_lines(&mut lines, diff);
+                    }
                 }
             }
         }
@@ -162,3 +219,69 @@ impl<'a> Widget for ToolLogWidget<'a> {
         Paragraph::new(lines).block(block).render(area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::state::TaskProgress;
+
+    fn state_with_exec(diff: Option<&str>) -> TuiState {
+        let mut state = TuiState::default();
+        state.tabs.active_pane_mut().progress.task_progress = Some(TaskProgress {
+            current_index: 1,
+            total: 1,
+            description: "Edit file".to_string(),
+            completed_tasks: Vec::new(),
+            active_tool_executions: vec![ToolExecutionDisplay {
+                execution_id: "e1".to_string(),
+                tool_name: "write_file".to_string(),
+                state: ToolExecutionDisplayStatus::Completed {
+                    preview: String::new(),
+                },
+                duration_ms: Some(5),
+                args_preview: Some("notes.txt".to_string()),
+                diff: diff.map(str::to_string),
+            }],
+        });
+        state
+    }
+
+    #[test]
+    fn test_text_content_includes_diff() {
+        let state = state_with_exec(Some(
+            "--- /dev/null\n+++ b/notes.txt\n@@ -0,0 +1,1 @@\n+hello",
+        ));
+        let text = ToolLogRenderer.get_text_content(&state);
+        assert!(text.contains("write_file  notes.txt"), "{}", text);
+        assert!(text.contains("      @@ -0,0 +1,1 @@"), "{}", text);
+        assert!(text.contains("      +hello"), "{}", text);
+    }
+
+    #[test]
+    fn test_long_diff_is_elided() {
+        let diff: String = (0..MAX_DIFF_LINES + 5)
+            .map(|n| format!("+line {}\n", n))
+            .collect();
+        let state = state_with_exec(Some(&diff));
+        let text = ToolLogRenderer.get_text_content(&state);
+        assert!(text.contains("… 5 more diff lines"), "{}", text);
+        assert!(!text.contains(&format!("+line {}", MAX_DIFF_LINES)));
+    }
+
+    #[test]
+    fn test_diff_lines_are_colored_by_kind() {
+        let mut lines = Vec::new();
+        render_diff_lines(&mut lines, "--- a/x\n+++ b/x\n@@ -1,1 +1,1 @@\n-old\n+new");
+        let colors: Vec<_> = lines.iter().map(|l| l.spans[0].style.fg).collect();
+        assert_eq!(
+            colors,
+            vec![
+                Some(Color::DarkGray),
+                Some(Color::DarkGray),
+                Some(Color::Cyan),
+                Some(Color::Red),
+                Some(Color::Green),
+            ]
+        );
+    }
+}
@@ -11,12 +11,23 @@
 -- quorum.config.set("models.exploration", "gpt-5.3-codex")       -- Context gathering + low-risk tools
 -- quorum.config.set("models.decision", "claude-sonnet-4.5")      -- Planning + high-risk tools
 -- quorum.config.set("models.review", { "claude-opus-4.5", "gpt-5.3-codex", "gemini-3.1-pro-preview" })
+-- Deciding vote when an even-sized review quorum splits exactly (default: none)
+-- quorum.config.set("models.tie_breaker", "claude-opus-4.5")
 
 -- Interaction roles (used in Ensemble/Quorum mode)
 -- quorum.config.set("models.participants", { "claude-opus-4.5", "gpt-5.3-codex", "gemini-3.1-pro-preview" })
 -- quorum.config.set("models.moderator", "claude-opus-4.5")       -- Quorum Synthesis
 -- quorum.config.set("models.ask", "claude-sonnet-4.5")           -- Ask (Q&A) interaction
 
+-- Per-model system prompt prefixes, prepended to every session for that model
+-- quorum.config.set("models.prompts", {
+--   ["gpt-5.3-codex"] = "Be terse. Prefer bullet points over prose.",
+-- })
+
+-- Model aliases (built-in: sonnet, opus, haiku, gpt, gemini). Set these before
+-- the keys that use them; entries here override the built-in aliases.
+-- quorum.config.set("models.aliases", { sonnet = "claude-sonnet-4.5" })
+
 -- ==================== Agent Behavior ====================
 -- Controls autonomous agent execution behavior.
 
@@ -30,9 +41,55 @@ quorum.config.set("agent.strategy", "quorum")
 --   - interactive: Prompt user for decisions
 --   - auto_reject: Automatically abort if revision limit exceeded
 --   - auto_approve: Automatically approve last plan (use with caution!)
+--   - batch_review: Like interactive, but queue high-risk actions and approve them all at the end
 quorum.config.set("agent.hil_mode", "interactive")
+-- Seconds a HiL prompt waits before taking the default decision (default: 0 = forever)
+-- quorum.config.set("agent.hil_timeout_secs", 300)
+-- Decision on timeout: "reject" (default) or "approve"
+-- quorum.config.set("agent.hil_timeout_default", "reject")
+-- Gather project context before planning (default: true). --no-context sets false.
+-- quorum.config.set("agent.gather_context", true)
 -- Maximum plan revisions before human intervention (default: 3)
 -- quorum.config.set("agent.max_plan_revisions", 3)
+-- Reject plans with more tasks than this and ask for a consolidated plan (default: 0 = no limit)
+-- quorum.config.set("agent.max_tasks_per_plan", 15)
+-- How ensemble planning picks the plan to execute (default: "winner")
+--   - "winner": execute the top-scored candidate
+--   - "merge": the moderator merges the top candidates into one plan
+-- quorum.config.set("agent.ensemble_selection", "merge")
+-- After a task fails (default: "continue_all")
+--   - "abort": stop the run, skipping every task not yet started
+--   - "continue_independent": skip only the tasks that depend on the failed one
+--   - "continue_all": run every remaining task
+-- quorum.config.set("agent.on_task_failure", "continue_independent")
+-- Retries per task when review rejects its actions; a task gets at most
+-- N + 1 attempts (default: 1, 0 = fail on the first rejection)
+-- quorum.config.set("agent.max_action_retries", 2)
+-- Corrective execution cycles a rejected final review may trigger (default: 1)
+-- quorum.config.set("agent.max_final_review_fixes", 1)
+-- Stop the agent once cumulative token usage exceeds this (default: 0 = no limit)
+-- quorum.config.set("agent.max_cost_tokens", 200000)
+-- Vote rule for plan/action review (default: "majority")
+--   - "unanimous": a single reject fails the review
+--   - "supermajority" (2/3) or "supermajority:3/4"
+-- quorum.config.set("agent.quorum_rule", "majority")
+-- Plan review decision (default: "binary" = count votes with agent.quorum_rule)
+--   - "score": approve when the mean 1-10 reviewer score is above 6.0
+--   - "score:7.5": same with a custom threshold
+-- quorum.config.set("agent.consensus_mode", "binary")
+-- Minimum distinct reviewers for high-risk actions (default: 0 = no floor)
+-- Approvals from fewer reviewers escalate per agent.hil_mode. --safe raises it to 2.
+-- quorum.config.set("agent.min_reviewers_high_risk", 2)
+-- Shell command guard for run_command, checked before review (glob: * and ?)
+-- A denylist match never runs; a non-empty allowlist blocks everything it doesn't match.
+-- quorum.config.set("agent.command_denylist", { "*rm -rf*", "git push*" })
+-- quorum.config.set("agent.command_allowlist", { "cargo *", "git status", "git diff*" })
+-- Risk level per run_command prefix, overriding the built-in classification.
+-- The longest matching prefix wins; "low" only applies to single commands (no &&, |, >).
+-- quorum.config.set("tools.risk_overrides", { ["make"] = "low", ["git log"] = "high" })
+-- Time limit per tool call in seconds; commands are killed when they exceed it
+-- quorum.config.set("tools.default_timeout_secs", 60)
+-- quorum.config.set("tools.timeouts", { run_command = 300 })
 
 -- ==================== Debate Strategy ====================
 -- Roster/parameters for the Debate strategy (agent.strategy = "debate", #325).
@@ -62,6 +119,8 @@ quorum.config.set("output.color", true)
 
 -- quorum.config.set("execution.max_iterations", 20)     -- Max planning iterations (default: 20)
 -- quorum.config.set("execution.max_tool_turns", 10)     -- Max tool turns per task (default: 10)
+-- quorum.config.set("execution.ask_max_tool_turns", 3)  -- Max tool turns for Ask (default: execution.max_tool_turns)
+-- quorum.config.set("execution.max_parallel_tasks", 4)  -- Independent tasks run concurrently (default: 4, 1 = sequential)
 
 -- ==================== REPL ====================
 
@@ -101,6 +160,21 @@ quorum.config.set("tui.layout.preset", "default")
 -- Terminal width threshold for responsive fallback to Minimal (default: 120)
 -- Set to 0 to disable responsive fallback.
 -- quorum.config.set("tui.layout.flex_threshold", 120)
+-- Preset to switch to while a Discuss interaction runs, restored when it
+-- completes (default: "" = keep the current layout)
+-- quorum.config.set("tui.layout.discuss_preset", "wide")
+
+-- ==================== TUI Accessibility ====================
+-- ASCII borders and no color styling, for limited terminals and screen readers.
+-- Same as the --no-unicode CLI flag (default: false)
+-- quorum.config.set("tui.ascii_mode", true)
+
+-- ==================== TUI Intent Routing ====================
+-- Form for messages typed without :ask / :agent / :discuss (default: "suggest")
+--   - off:     always start an Agent
+--   - suggest: start an Agent, but hint when Ask/Discuss looks like a better fit
+--   - auto:    start the suggested form directly
+-- quorum.config.set("tui.intent_routing", "suggest")
 
 -- ==================== Context Budget ====================
 -- Controls how much task result context is retained between executions.
@@ -109,6 +183,39 @@ quorum.config.set("tui.layout.preset", "default")
 -- quorum.config.set("context_budget.max_entry_bytes", 20000)    -- Max bytes per single task result
 -- quorum.config.set("context_budget.max_total_bytes", 60000)    -- Max total bytes for all previous results
 -- quorum.config.set("context_budget.recent_full_count", 3)      -- Recent results kept in full
+-- quorum.config.set("context_budget.per_tool_output_chars", 10000) -- Max chars per tool output sent to the LLM
+
+-- ==================== Context Files ====================
+-- Size caps for known context files (CLAUDE.md, README.md, docs/) loaded at startup.
+-- Oversized files are truncated; files past the total budget are skipped.
+
+-- quorum.config.set("context.max_file_bytes", 100000)    -- Max bytes per context file
+-- quorum.config.set("context.max_total_bytes", 400000)   -- Max bytes across all context files
+-- Files always loaded, even when discovery would miss them (also: --context-file <path>)
+-- quorum.config.set("context.pinned_files", { "src/auth/session.rs" })
+-- quorum.config.set("context.reference_cache_ttl_secs", 300)  -- Reuse resolved #N / PROJ-N refs (0 = off)
+-- quorum.config.set("context.decision_log", false)  -- Don't record approved plans in .quorum/decisions.jsonl
+
+-- ==================== Conversation Log ====================
+-- API keys, GitHub tokens, bearer credentials and base64 blobs are always redacted.
+-- Extra regexes to redact as [REDACTED:custom]:
+
+-- quorum.config.set("logging.redact_patterns", { "corp-[0-9]{6}" })
+
+-- ==================== Interaction Defaults ====================
+-- How many levels of child interactions may nest below the root (0 = none)
+-- quorum.config.set("interaction.max_nesting_depth", 3)
+-- Context mode per interaction form: "full", "projected" or "fresh"
+-- (defaults: agent = full, ask = projected, discuss = full, review = fresh)
+-- quorum.config.set("interaction.ask.context_mode", "full")
+
+-- ==================== Profiles ====================
+-- Named overrides selected with `copilot-quorum --profile <name>`.
+-- Only the listed keys change; everything else keeps the values above.
+-- quorum.config.profile("careful", {
+--     ["agent.consensus_level"] = "ensemble",
+--     ["agent.max_plan_revisions"] = 5,
+-- })
 
 -- ==================== Providers ====================
 -- Provider-specific configuration for direct API access.
@@ -116,6 +223,9 @@ quorum.config.set("tui.layout.preset", "default")
 
 quorum.providers.set_default("copilot")
 
+-- Copilot CLI: cap prompts per second across all sessions (default: unlimited)
+-- quorum.providers.copilot({ max_rps = 2 })
+
 -- AWS Bedrock provider (requires `bedrock` feature: cargo build --features bedrock)
 -- Uses IAM authentication — no API key needed, just configured AWS credentials.
 -- quorum.providers.bedrock({ region = "us-east-1", profile = "default", max_tokens = 8192, cross_region = false })
@@ -124,6 +234,9 @@ quorum.providers.set_default("copilot")
 -- Maps model names to provider backends ("copilot", "anthropic", "openai", "bedrock", "azure").
 -- quorum.providers.route("claude-sonnet-4.5", "bedrock")
 
+-- Providers to try, in order, when the routed provider fails to create a session.
+-- quorum.providers.fallback("claude-sonnet-4.5", { "anthropic", "copilot" })
+
 -- Direct API providers (requires API keys)
 -- quorum.providers.anthropic({ api_key = os.getenv("ANTHROPIC_API_KEY") })
 -- quorum.providers.openai({ api_key = os.getenv("OPENAI_API_KEY") })